@@ -70,6 +70,8 @@ function createLiteSVMRPC(svm: LiteSVM): RuntimeRPC {
   );
   const slotHashesAccount = svm.getAccount(slotHashesAddress)!;
   const slotHashesBuffer = new SlotHashesBuffer();
+  // litesvm cannot enumerate accounts, so remember every address touched by sent transactions for getProgramAccounts
+  const knownAddresses = new Set<Address>();
 
   const rpcMethods: RuntimeRPCMethods & RuntimeRPCOptionalMethods = {
     getAccountInfo(address, config) {
@@ -115,6 +117,56 @@ function createLiteSVMRPC(svm: LiteSVM): RuntimeRPC {
       };
       return res;
     },
+    getProgramAccounts(programAddress, config) {
+      assertBase64Encoding(config);
+
+      const items: { pubkey: Address; account: AccountInfoBase & any }[] = [];
+      for (const address of knownAddresses) {
+        const account = svm.getAccount(web3Compat.toPublicKey(address));
+        if (
+          !account ||
+          web3Compat.fromLegacyPublicKey(account.owner) != programAddress
+        ) {
+          continue;
+        }
+
+        const data = Buffer.from(account.data);
+        const matched = (config?.filters ?? []).every((filter) => {
+          if ('dataSize' in filter) {
+            return BigInt(data.length) == BigInt(filter.dataSize);
+          }
+          const offset = Number(filter.memcmp.offset);
+          const bytes =
+            filter.memcmp.encoding == 'base64'
+              ? Buffer.from(filter.memcmp.bytes, 'base64')
+              : Buffer.from(getBase58Encoder().encode(filter.memcmp.bytes));
+          return data.subarray(offset, offset + bytes.length).equals(bytes);
+        });
+        if (!matched) continue;
+
+        const dataSlice = config?.dataSlice;
+        const slicedData = dataSlice
+          ? data.subarray(dataSlice.offset, dataSlice.offset + dataSlice.length)
+          : data;
+        items.push({
+          pubkey: address,
+          account: {
+            data: [
+              slicedData.toString('base64') as Base64EncodedBytes,
+              'base64',
+            ],
+            executable: account.executable,
+            lamports: web3Compat.fromLegacyLamports(account.lamports),
+            owner: programAddress,
+            rentEpoch: account.rentEpoch
+              ? BigInt(account.rentEpoch)
+              : BigInt('18446744073709551615'),
+            space: BigInt(account.data.length),
+          },
+        });
+      }
+      return items as any;
+    },
     getTransaction(signature, config) {
       assertBase64Encoding(config);
       const result = svm.getTransaction(
@@ -277,6 +329,9 @@ function createLiteSVMRPC(svm: LiteSVM): RuntimeRPC {
       }
 
       const res = svm.sendTransaction(tx);
+      for (const key of tx.message.staticAccountKeys) {
+        knownAddresses.add(web3Compat.fromLegacyPublicKey(key));
+      }
 
       const signature = getBase58Decoder().decode(
         'signature' in res ? res.signature() : tx.signatures[0]
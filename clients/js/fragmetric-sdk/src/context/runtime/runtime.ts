@@ -4,6 +4,7 @@ import {
   GetLatestBlockhashApi,
   GetMinimumBalanceForRentExemptionApi,
   GetMultipleAccountsApi,
+  GetProgramAccountsApi,
   GetSignatureStatusesApi,
   GetSlotApi,
   GetTransactionApi,
@@ -34,6 +35,7 @@ export type RuntimeCluster = (typeof runtimeClusters)[number];
 
 export type RuntimeRPCMethods = GetAccountInfoApi &
   GetMultipleAccountsApi &
+  GetProgramAccountsApi &
   GetTransactionApi &
   SimulateTransactionApi &
   SendTransactionApi &
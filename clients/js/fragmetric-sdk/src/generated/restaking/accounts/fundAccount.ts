@@ -47,12 +47,30 @@ import {
 import {
   getAssetStateDecoder,
   getAssetStateEncoder,
+  getInKindWithdrawalStateDecoder,
+  getInKindWithdrawalStateEncoder,
+  getInstantWithdrawalStateDecoder,
+  getInstantWithdrawalStateEncoder,
   getNormalizedTokenDecoder,
   getNormalizedTokenEncoder,
+  getOperationFailureStateDecoder,
+  getOperationFailureStateEncoder,
+  getOperationPolicyStateDecoder,
+  getOperationPolicyStateEncoder,
   getOperationStateDecoder,
   getOperationStateEncoder,
+  getOperatorAllowlistDecoder,
+  getOperatorAllowlistEncoder,
+  getPriceDeviationCircuitBreakerDecoder,
+  getPriceDeviationCircuitBreakerEncoder,
+  getReceiptTokenPriceHistoryDecoder,
+  getReceiptTokenPriceHistoryEncoder,
+  getReconciliationStateDecoder,
+  getReconciliationStateEncoder,
   getRestakingVaultDecoder,
   getRestakingVaultEncoder,
+  getRevenueSplitDecoder,
+  getRevenueSplitEncoder,
   getSupportedTokenDecoder,
   getSupportedTokenEncoder,
   getTokenSwapStrategyDecoder,
@@ -63,12 +81,30 @@ import {
   getWrappedTokenEncoder,
   type AssetState,
   type AssetStateArgs,
+  type InKindWithdrawalState,
+  type InKindWithdrawalStateArgs,
+  type InstantWithdrawalState,
+  type InstantWithdrawalStateArgs,
   type NormalizedToken,
   type NormalizedTokenArgs,
+  type OperationFailureState,
+  type OperationFailureStateArgs,
+  type OperationPolicyState,
+  type OperationPolicyStateArgs,
   type OperationState,
   type OperationStateArgs,
+  type OperatorAllowlist,
+  type OperatorAllowlistArgs,
+  type PriceDeviationCircuitBreaker,
+  type PriceDeviationCircuitBreakerArgs,
+  type ReceiptTokenPriceHistory,
+  type ReceiptTokenPriceHistoryArgs,
+  type ReconciliationState,
+  type ReconciliationStateArgs,
   type RestakingVault,
   type RestakingVaultArgs,
+  type RevenueSplit,
+  type RevenueSplitArgs,
   type SupportedToken,
   type SupportedTokenArgs,
   type TokenSwapStrategy,
@@ -161,6 +197,51 @@ export type FundAccount = {
    */
   performanceFeeLastHarvestedAt: bigint;
   performanceFeeRateBps: number;
+  /**
+   * Timelock for material configuration changes, see [`FundConfigurationChangeAccount`].
+   * - `configuration_change_delay_seconds`: delay between queueing and executing a change (0 = disabled)
+   * - `next_configuration_change_id`: id of the next change to queue
+   */
+  padding7: ReadonlyUint8Array;
+  configurationChangeDelaySeconds: bigint;
+  nextConfigurationChangeId: bigint;
+  /** optional allowlist of operators and their operation stats */
+  operatorAllowlist: OperatorAllowlist;
+  /** Management fee rate in annual basis points (bps), prorated by `performance_fee_last_harvested_at` */
+  managementFeeRateBps: number;
+  padding8: ReadonlyUint8Array;
+  /** recipients of fund revenue, see [`RevenueSplit`] */
+  revenueSplit: RevenueSplit;
+  /** receipt token price samples for TWAP, see [`ReceiptTokenPriceHistory`] */
+  receiptTokenPriceHistory: ReceiptTokenPriceHistory;
+  /** circuit breaker against bad token prices, see [`PriceDeviationCircuitBreaker`] */
+  priceDeviationCircuitBreaker: PriceDeviationCircuitBreaker;
+  /**
+   * Price staleness bound for user instructions.
+   * - `max_price_age_slots`: max age of refreshed token prices (0 = disabled)
+   * - `token_prices_updated_slot`: slot of the last token prices refresh
+   */
+  maxPriceAgeSlots: bigint;
+  tokenPricesUpdatedSlot: bigint;
+  /** soft failure of operation commands, see [`OperationFailureState`] */
+  operationFailure: OperationFailureState;
+  /**
+   * Paginated history of operation commands, see [`FundOperationHistoryAccount`].
+   * - `operation_history_num_pages`: number of initialized pages (0 = disabled)
+   * - `operation_history_num_entries`: number of recorded entries of all pages
+   */
+  operationHistoryNumPages: bigint;
+  operationHistoryNumEntries: bigint;
+  /** scheduling policy of operation commands, see [`OperationPolicyState`] */
+  operationPolicy: OperationPolicyState;
+  /** reconciliation of the bookkeeping against the reserve accounts, see [`ReconciliationState`] */
+  reconciliation: ReconciliationState;
+  /** instant withdrawal from the operation reserve, see [`InstantWithdrawalState`] */
+  instantWithdrawal: InstantWithdrawalState;
+  /** in-kind withdrawal of the assets held by the fund reserve, see [`InKindWithdrawalState`] */
+  inKindWithdrawal: InKindWithdrawalState;
+  /** Management fee settled at previous rates on rate change, charged on the next fee harvest */
+  managementFeeAccruedAmountAsSol: bigint;
   reserved1: ReadonlyUint8Array;
 };
 
@@ -235,6 +316,51 @@ export type FundAccountArgs = {
    */
   performanceFeeLastHarvestedAt: number | bigint;
   performanceFeeRateBps: number;
+  /**
+   * Timelock for material configuration changes, see [`FundConfigurationChangeAccount`].
+   * - `configuration_change_delay_seconds`: delay between queueing and executing a change (0 = disabled)
+   * - `next_configuration_change_id`: id of the next change to queue
+   */
+  padding7: ReadonlyUint8Array;
+  configurationChangeDelaySeconds: number | bigint;
+  nextConfigurationChangeId: number | bigint;
+  /** optional allowlist of operators and their operation stats */
+  operatorAllowlist: OperatorAllowlistArgs;
+  /** Management fee rate in annual basis points (bps), prorated by `performance_fee_last_harvested_at` */
+  managementFeeRateBps: number;
+  padding8: ReadonlyUint8Array;
+  /** recipients of fund revenue, see [`RevenueSplit`] */
+  revenueSplit: RevenueSplitArgs;
+  /** receipt token price samples for TWAP, see [`ReceiptTokenPriceHistory`] */
+  receiptTokenPriceHistory: ReceiptTokenPriceHistoryArgs;
+  /** circuit breaker against bad token prices, see [`PriceDeviationCircuitBreaker`] */
+  priceDeviationCircuitBreaker: PriceDeviationCircuitBreakerArgs;
+  /**
+   * Price staleness bound for user instructions.
+   * - `max_price_age_slots`: max age of refreshed token prices (0 = disabled)
+   * - `token_prices_updated_slot`: slot of the last token prices refresh
+   */
+  maxPriceAgeSlots: number | bigint;
+  tokenPricesUpdatedSlot: number | bigint;
+  /** soft failure of operation commands, see [`OperationFailureState`] */
+  operationFailure: OperationFailureStateArgs;
+  /**
+   * Paginated history of operation commands, see [`FundOperationHistoryAccount`].
+   * - `operation_history_num_pages`: number of initialized pages (0 = disabled)
+   * - `operation_history_num_entries`: number of recorded entries of all pages
+   */
+  operationHistoryNumPages: number | bigint;
+  operationHistoryNumEntries: number | bigint;
+  /** scheduling policy of operation commands, see [`OperationPolicyState`] */
+  operationPolicy: OperationPolicyStateArgs;
+  /** reconciliation of the bookkeeping against the reserve accounts, see [`ReconciliationState`] */
+  reconciliation: ReconciliationStateArgs;
+  /** instant withdrawal from the operation reserve, see [`InstantWithdrawalState`] */
+  instantWithdrawal: InstantWithdrawalStateArgs;
+  /** in-kind withdrawal of the assets held by the fund reserve, see [`InKindWithdrawalState`] */
+  inKindWithdrawal: InKindWithdrawalStateArgs;
+  /** Management fee settled at previous rates on rate change, charged on the next fee harvest */
+  managementFeeAccruedAmountAsSol: number | bigint;
   reserved1: ReadonlyUint8Array;
 };
 
@@ -303,7 +429,29 @@ export function getFundAccountEncoder(): FixedSizeEncoder<FundAccountArgs> {
       ['feeHarvestedOneReceiptTokenAsSol', getU64Encoder()],
       ['performanceFeeLastHarvestedAt', getI64Encoder()],
       ['performanceFeeRateBps', getU16Encoder()],
-      ['reserved1', fixEncoderSize(getBytesEncoder(), 3590)],
+      ['padding7', fixEncoderSize(getBytesEncoder(), 6)],
+      ['configurationChangeDelaySeconds', getI64Encoder()],
+      ['nextConfigurationChangeId', getU64Encoder()],
+      ['operatorAllowlist', getOperatorAllowlistEncoder()],
+      ['managementFeeRateBps', getU16Encoder()],
+      ['padding8', fixEncoderSize(getBytesEncoder(), 6)],
+      ['revenueSplit', getRevenueSplitEncoder()],
+      ['receiptTokenPriceHistory', getReceiptTokenPriceHistoryEncoder()],
+      [
+        'priceDeviationCircuitBreaker',
+        getPriceDeviationCircuitBreakerEncoder(),
+      ],
+      ['maxPriceAgeSlots', getU64Encoder()],
+      ['tokenPricesUpdatedSlot', getU64Encoder()],
+      ['operationFailure', getOperationFailureStateEncoder()],
+      ['operationHistoryNumPages', getU64Encoder()],
+      ['operationHistoryNumEntries', getU64Encoder()],
+      ['operationPolicy', getOperationPolicyStateEncoder()],
+      ['reconciliation', getReconciliationStateEncoder()],
+      ['instantWithdrawal', getInstantWithdrawalStateEncoder()],
+      ['inKindWithdrawal', getInKindWithdrawalStateEncoder()],
+      ['managementFeeAccruedAmountAsSol', getU64Encoder()],
+      ['reserved1', fixEncoderSize(getBytesEncoder(), 384)],
     ]),
     (value) => ({ ...value, discriminator: FUND_ACCOUNT_DISCRIMINATOR })
   );
@@ -373,7 +521,26 @@ export function getFundAccountDecoder(): FixedSizeDecoder<FundAccount> {
     ['feeHarvestedOneReceiptTokenAsSol', getU64Decoder()],
     ['performanceFeeLastHarvestedAt', getI64Decoder()],
     ['performanceFeeRateBps', getU16Decoder()],
-    ['reserved1', fixDecoderSize(getBytesDecoder(), 3590)],
+    ['padding7', fixDecoderSize(getBytesDecoder(), 6)],
+    ['configurationChangeDelaySeconds', getI64Decoder()],
+    ['nextConfigurationChangeId', getU64Decoder()],
+    ['operatorAllowlist', getOperatorAllowlistDecoder()],
+    ['managementFeeRateBps', getU16Decoder()],
+    ['padding8', fixDecoderSize(getBytesDecoder(), 6)],
+    ['revenueSplit', getRevenueSplitDecoder()],
+    ['receiptTokenPriceHistory', getReceiptTokenPriceHistoryDecoder()],
+    ['priceDeviationCircuitBreaker', getPriceDeviationCircuitBreakerDecoder()],
+    ['maxPriceAgeSlots', getU64Decoder()],
+    ['tokenPricesUpdatedSlot', getU64Decoder()],
+    ['operationFailure', getOperationFailureStateDecoder()],
+    ['operationHistoryNumPages', getU64Decoder()],
+    ['operationHistoryNumEntries', getU64Decoder()],
+    ['operationPolicy', getOperationPolicyStateDecoder()],
+    ['reconciliation', getReconciliationStateDecoder()],
+    ['instantWithdrawal', getInstantWithdrawalStateDecoder()],
+    ['inKindWithdrawal', getInKindWithdrawalStateDecoder()],
+    ['managementFeeAccruedAmountAsSol', getU64Decoder()],
+    ['reserved1', fixDecoderSize(getBytesDecoder(), 384)],
   ]);
 }
 
@@ -6,18 +6,30 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './adminProposedRoleTransfer';
 export * from './fundAccount';
+export * from './fundConfigurationChangeAccount';
+export * from './fundManagerExecutedFundConfigurationChange';
+export * from './fundManagerQueuedFundConfigurationChange';
 export * from './fundManagerUpdatedFund';
 export * from './fundManagerUpdatedRewardPool';
+export * from './fundOperationHistoryAccount';
 export * from './fundWithdrawalBatchAccount';
+export * from './fundWithdrawalRequestAccount';
+export * from './guardianCanceledFundConfigurationChange';
 export * from './normalizedTokenPoolAccount';
 export * from './normalizedTokenWithdrawalAccount';
+export * from './operatorClaimedRemainingReward';
 export * from './operatorDonatedToFund';
 export * from './operatorRanFundCommand';
+export * from './operatorReconciledFund';
+export * from './operatorTrippedFundPriceCircuitBreaker';
 export * from './operatorUpdatedFundPrices';
 export * from './operatorUpdatedNormalizedTokenPoolPrices';
 export * from './operatorUpdatedRewardPools';
 export * from './rewardAccount';
+export * from './roleAccount';
+export * from './roleAuthorityAcceptedRoleTransfer';
 export * from './userCanceledWithdrawalRequestFromFund';
 export * from './userClaimedReward';
 export * from './userClosedFundAccount';
@@ -28,10 +40,14 @@ export * from './userDelegatedRewardAccount';
 export * from './userDepositedToFund';
 export * from './userDepositedToVault';
 export * from './userFundAccount';
+export * from './userMigratedFundWithdrawalRequests';
 export * from './userRequestedWithdrawalFromFund';
 export * from './userRewardAccount';
+export * from './userTransferredFundWithdrawalRequest';
 export * from './userTransferredReceiptToken';
 export * from './userUnwrappedReceiptToken';
 export * from './userUpdatedRewardPool';
 export * from './userWithdrewFromFund';
+export * from './userWithdrewInKindFromFund';
+export * from './userWithdrewInstantlyFromFund';
 export * from './userWrappedReceiptToken';
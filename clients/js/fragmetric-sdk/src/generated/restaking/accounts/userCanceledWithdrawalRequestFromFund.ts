@@ -61,6 +61,7 @@ export type UserCanceledWithdrawalRequestFromFund = {
   user: Address;
   userReceiptTokenAccount: Address;
   userFundAccount: Address;
+  fundWithdrawalRequestAccount: Address;
   batchId: bigint;
   requestId: bigint;
   requestedReceiptTokenAmount: bigint;
@@ -75,6 +76,7 @@ export type UserCanceledWithdrawalRequestFromFundArgs = {
   user: Address;
   userReceiptTokenAccount: Address;
   userFundAccount: Address;
+  fundWithdrawalRequestAccount: Address;
   batchId: number | bigint;
   requestId: number | bigint;
   requestedReceiptTokenAmount: number | bigint;
@@ -91,6 +93,7 @@ export function getUserCanceledWithdrawalRequestFromFundEncoder(): Encoder<UserC
       ['user', getAddressEncoder()],
       ['userReceiptTokenAccount', getAddressEncoder()],
       ['userFundAccount', getAddressEncoder()],
+      ['fundWithdrawalRequestAccount', getAddressEncoder()],
       ['batchId', getU64Encoder()],
       ['requestId', getU64Encoder()],
       ['requestedReceiptTokenAmount', getU64Encoder()],
@@ -114,6 +117,7 @@ export function getUserCanceledWithdrawalRequestFromFundDecoder(): Decoder<UserC
     ['user', getAddressDecoder()],
     ['userReceiptTokenAccount', getAddressDecoder()],
     ['userFundAccount', getAddressDecoder()],
+    ['fundWithdrawalRequestAccount', getAddressDecoder()],
     ['batchId', getU64Decoder()],
     ['requestId', getU64Decoder()],
     ['requestedReceiptTokenAmount', getU64Decoder()],
@@ -67,6 +67,10 @@ export type UserFundAccount = {
   user: Address;
   receiptTokenAmount: bigint;
   reserved: ReadonlyUint8Array;
+  /**
+   * deprecated: withdrawal requests are stored as `FundWithdrawalRequestAccount`s,
+   * the requests created before are kept until migrated.
+   */
   withdrawalRequests: Array<WithdrawalRequest>;
 };
 
@@ -77,6 +81,10 @@ export type UserFundAccountArgs = {
   user: Address;
   receiptTokenAmount: number | bigint;
   reserved: ReadonlyUint8Array;
+  /**
+   * deprecated: withdrawal requests are stored as `FundWithdrawalRequestAccount`s,
+   * the requests created before are kept until migrated.
+   */
   withdrawalRequests: Array<WithdrawalRequestArgs>;
 };
 
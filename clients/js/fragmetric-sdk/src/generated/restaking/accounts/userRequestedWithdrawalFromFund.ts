@@ -62,6 +62,7 @@ export type UserRequestedWithdrawalFromFund = {
   user: Address;
   userReceiptTokenAccount: Address;
   userFundAccount: Address;
+  fundWithdrawalRequestAccount: Address;
   batchId: bigint;
   requestId: bigint;
   requestedReceiptTokenAmount: bigint;
@@ -76,6 +77,7 @@ export type UserRequestedWithdrawalFromFundArgs = {
   user: Address;
   userReceiptTokenAccount: Address;
   userFundAccount: Address;
+  fundWithdrawalRequestAccount: Address;
   batchId: number | bigint;
   requestId: number | bigint;
   requestedReceiptTokenAmount: number | bigint;
@@ -92,6 +94,7 @@ export function getUserRequestedWithdrawalFromFundEncoder(): Encoder<UserRequest
       ['user', getAddressEncoder()],
       ['userReceiptTokenAccount', getAddressEncoder()],
       ['userFundAccount', getAddressEncoder()],
+      ['fundWithdrawalRequestAccount', getAddressEncoder()],
       ['batchId', getU64Encoder()],
       ['requestId', getU64Encoder()],
       ['requestedReceiptTokenAmount', getU64Encoder()],
@@ -115,6 +118,7 @@ export function getUserRequestedWithdrawalFromFundDecoder(): Decoder<UserRequest
     ['user', getAddressDecoder()],
     ['userReceiptTokenAccount', getAddressDecoder()],
     ['userFundAccount', getAddressDecoder()],
+    ['fundWithdrawalRequestAccount', getAddressDecoder()],
     ['batchId', getU64Decoder()],
     ['requestId', getU64Decoder()],
     ['requestedReceiptTokenAmount', getU64Decoder()],
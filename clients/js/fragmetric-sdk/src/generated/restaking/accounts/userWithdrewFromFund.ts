@@ -61,6 +61,7 @@ export type UserWithdrewFromFund = {
   userFundAccount: Address;
   userSupportedTokenAccount: Option<Address>;
   fundWithdrawalBatchAccount: Address;
+  fundWithdrawalRequestAccount: Address;
   batchId: bigint;
   requestId: bigint;
   burntReceiptTokenAmount: bigint;
@@ -79,6 +80,7 @@ export type UserWithdrewFromFundArgs = {
   userFundAccount: Address;
   userSupportedTokenAccount: OptionOrNullable<Address>;
   fundWithdrawalBatchAccount: Address;
+  fundWithdrawalRequestAccount: Address;
   batchId: number | bigint;
   requestId: number | bigint;
   burntReceiptTokenAmount: number | bigint;
@@ -99,6 +101,7 @@ export function getUserWithdrewFromFundEncoder(): Encoder<UserWithdrewFromFundAr
       ['userFundAccount', getAddressEncoder()],
       ['userSupportedTokenAccount', getOptionEncoder(getAddressEncoder())],
       ['fundWithdrawalBatchAccount', getAddressEncoder()],
+      ['fundWithdrawalRequestAccount', getAddressEncoder()],
       ['batchId', getU64Encoder()],
       ['requestId', getU64Encoder()],
       ['burntReceiptTokenAmount', getU64Encoder()],
@@ -125,6 +128,7 @@ export function getUserWithdrewFromFundDecoder(): Decoder<UserWithdrewFromFund>
     ['userFundAccount', getAddressDecoder()],
     ['userSupportedTokenAccount', getOptionDecoder(getAddressDecoder())],
     ['fundWithdrawalBatchAccount', getAddressDecoder()],
+    ['fundWithdrawalRequestAccount', getAddressDecoder()],
     ['batchId', getU64Decoder()],
     ['requestId', getU64Decoder()],
     ['burntReceiptTokenAmount', getU64Decoder()],
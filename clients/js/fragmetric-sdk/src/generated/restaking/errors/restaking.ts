@@ -224,25 +224,107 @@ export const RESTAKING_ERROR__REWARD_USER_REWARD_ACCOUNT_NOT_SYNCED_ERROR = 0x17
 export const RESTAKING_ERROR__REWARD_USER_HAS_UNCLAIMED_REWARD_ERROR = 0x17d7; // 6103
 /** RewardSettlementBlockNotFoundError: reward: settlement block not found */
 export const RESTAKING_ERROR__REWARD_SETTLEMENT_BLOCK_NOT_FOUND_ERROR = 0x17d8; // 6104
+/** TokenSwapSlippageExceededError: swap: swapped amount is below the minimum expected amount */
+export const RESTAKING_ERROR__TOKEN_SWAP_SLIPPAGE_EXCEEDED_ERROR = 0x17d9; // 6105
+/** FundInvalidTokenSwapStrategyMaxSlippageError: fund: invalid token swap strategy max slippage */
+export const RESTAKING_ERROR__FUND_INVALID_TOKEN_SWAP_STRATEGY_MAX_SLIPPAGE_ERROR = 0x17da; // 6106
+/** RoleUnauthorizedError: role: signer is not authorized for the role */
+export const RESTAKING_ERROR__ROLE_UNAUTHORIZED_ERROR = 0x17db; // 6107
+/** RoleInvalidAuthorityError: role: invalid role authority */
+export const RESTAKING_ERROR__ROLE_INVALID_AUTHORITY_ERROR = 0x17dc; // 6108
+/** FundConfigurationChangeTimelockedError: fund: configuration change must be queued and executed after the timelock */
+export const RESTAKING_ERROR__FUND_CONFIGURATION_CHANGE_TIMELOCKED_ERROR = 0x17dd; // 6109
+/** FundConfigurationChangeNotExecutableYetError: fund: configuration change is not executable yet */
+export const RESTAKING_ERROR__FUND_CONFIGURATION_CHANGE_NOT_EXECUTABLE_YET_ERROR = 0x17de; // 6110
+/** FundInvalidConfigurationChangeError: fund: invalid configuration change */
+export const RESTAKING_ERROR__FUND_INVALID_CONFIGURATION_CHANGE_ERROR = 0x17df; // 6111
+/** FundOperatorUnauthorizedError: fund: operator is not allowed to operate the fund */
+export const RESTAKING_ERROR__FUND_OPERATOR_UNAUTHORIZED_ERROR = 0x17e0; // 6112
+/** FundOperatorAlreadyRegisteredError: fund: operator already registered */
+export const RESTAKING_ERROR__FUND_OPERATOR_ALREADY_REGISTERED_ERROR = 0x17e1; // 6113
+/** FundOperatorNotFoundError: fund: operator not found */
+export const RESTAKING_ERROR__FUND_OPERATOR_NOT_FOUND_ERROR = 0x17e2; // 6114
+/** FundExceededMaxOperatorsError: fund: exceeded max operators */
+export const RESTAKING_ERROR__FUND_EXCEEDED_MAX_OPERATORS_ERROR = 0x17e3; // 6115
+/** FundDepositReceiptTokenAmountBelowMinimumError: fund: deposit receipt token amount is below the minimum */
+export const RESTAKING_ERROR__FUND_DEPOSIT_RECEIPT_TOKEN_AMOUNT_BELOW_MINIMUM_ERROR = 0x17e4; // 6116
+/** FundWithdrawalAssetAmountBelowMinimumError: fund: withdrawal asset amount is below the minimum */
+export const RESTAKING_ERROR__FUND_WITHDRAWAL_ASSET_AMOUNT_BELOW_MINIMUM_ERROR = 0x17e5; // 6117
+/** FundInvalidManagementFeeRateError: fund: invalid management fee rate */
+export const RESTAKING_ERROR__FUND_INVALID_MANAGEMENT_FEE_RATE_ERROR = 0x17e6; // 6118
+/** FundExceededMaxRevenueRecipientsError: fund: exceeded max revenue recipients */
+export const RESTAKING_ERROR__FUND_EXCEEDED_MAX_REVENUE_RECIPIENTS_ERROR = 0x17e7; // 6119
+/** FundInvalidRevenueSplitError: fund: invalid revenue split */
+export const RESTAKING_ERROR__FUND_INVALID_REVENUE_SPLIT_ERROR = 0x17e8; // 6120
+/** FundReceiptTokenPriceHistoryInsufficientError: fund: receipt token price history is insufficient for the window */
+export const RESTAKING_ERROR__FUND_RECEIPT_TOKEN_PRICE_HISTORY_INSUFFICIENT_ERROR = 0x17e9; // 6121
+/** FundPriceDeviationCircuitBreakerTrippedError: fund: price deviation circuit breaker is tripped */
+export const RESTAKING_ERROR__FUND_PRICE_DEVIATION_CIRCUIT_BREAKER_TRIPPED_ERROR = 0x17ea; // 6122
+/** FundPriceStaleError: fund: token prices are stale */
+export const RESTAKING_ERROR__FUND_PRICE_STALE_ERROR = 0x17eb; // 6123
+/** FundInvalidOperationFallbackCommandError: fund: invalid operation fallback command */
+export const RESTAKING_ERROR__FUND_INVALID_OPERATION_FALLBACK_COMMAND_ERROR = 0x17ec; // 6124
+/** FundInvalidOperationHistoryAccountError: fund: invalid operation history account */
+export const RESTAKING_ERROR__FUND_INVALID_OPERATION_HISTORY_ACCOUNT_ERROR = 0x17ed; // 6125
+/** FundOperationHistoryAccountNotFoundError: fund: operation history account not found */
+export const RESTAKING_ERROR__FUND_OPERATION_HISTORY_ACCOUNT_NOT_FOUND_ERROR = 0x17ee; // 6126
+/** FundInvalidOperationCommandPolicyError: fund: invalid operation command policy */
+export const RESTAKING_ERROR__FUND_INVALID_OPERATION_COMMAND_POLICY_ERROR = 0x17ef; // 6127
+/** FundInstantWithdrawalDisabledError: fund: instant withdrawal is disabled */
+export const RESTAKING_ERROR__FUND_INSTANT_WITHDRAWAL_DISABLED_ERROR = 0x17f0; // 6128
+/** FundInstantWithdrawalLiquidityExceededError: fund: instant withdrawal exceeds the reserve above the floor */
+export const RESTAKING_ERROR__FUND_INSTANT_WITHDRAWAL_LIQUIDITY_EXCEEDED_ERROR = 0x17f1; // 6129
+/** FundInKindWithdrawalDisabledError: fund: in-kind withdrawal is disabled */
+export const RESTAKING_ERROR__FUND_IN_KIND_WITHDRAWAL_DISABLED_ERROR = 0x17f2; // 6130
+/** FundInKindWithdrawalLiquidityExceededError: fund: in-kind withdrawal exceeds the assets held by the fund reserve */
+export const RESTAKING_ERROR__FUND_IN_KIND_WITHDRAWAL_LIQUIDITY_EXCEEDED_ERROR = 0x17f3; // 6131
+/** FundInvalidInKindWithdrawalMinAssetAmountsError: fund: in-kind withdrawal minimum asset amounts do not match the withdrawn assets */
+export const RESTAKING_ERROR__FUND_INVALID_IN_KIND_WITHDRAWAL_MIN_ASSET_AMOUNTS_ERROR = 0x17f4; // 6132
+/** RestakingVaultPausedError: restaking: vault is paused */
+export const RESTAKING_ERROR__RESTAKING_VAULT_PAUSED_ERROR = 0x17f5; // 6133
+/** RestakingVaultDepositCapacityExceededError: restaking: vault deposit capacity is exceeded */
+export const RESTAKING_ERROR__RESTAKING_VAULT_DEPOSIT_CAPACITY_EXCEEDED_ERROR = 0x17f6; // 6134
+/** StakingSPLSolDepositNotPermittedError: staking: spl stake pool does not permit SOL deposit */
+export const RESTAKING_ERROR__STAKING_S_P_L_SOL_DEPOSIT_NOT_PERMITTED_ERROR = 0x17f7; // 6135
+/** FundConfigurationChangeGuardianNotSetError: fund: configuration change delay requires the guardian to cancel changes */
+export const RESTAKING_ERROR__FUND_CONFIGURATION_CHANGE_GUARDIAN_NOT_SET_ERROR = 0x17f8; // 6136
 
 export type RestakingError =
   | typeof RESTAKING_ERROR__CALCULATION_ARITHMETIC_EXCEPTION
   | typeof RESTAKING_ERROR__FUND_ALREADY_SUPPORTED_TOKEN_ERROR
+  | typeof RESTAKING_ERROR__FUND_CONFIGURATION_CHANGE_GUARDIAN_NOT_SET_ERROR
+  | typeof RESTAKING_ERROR__FUND_CONFIGURATION_CHANGE_NOT_EXECUTABLE_YET_ERROR
+  | typeof RESTAKING_ERROR__FUND_CONFIGURATION_CHANGE_TIMELOCKED_ERROR
   | typeof RESTAKING_ERROR__FUND_DEPOSIT_DISABLED_ERROR
   | typeof RESTAKING_ERROR__FUND_DEPOSIT_METADATA_SIGNATURE_EXPIRED_ERROR
   | typeof RESTAKING_ERROR__FUND_DEPOSIT_NOT_SUPPORTED_ASSET
+  | typeof RESTAKING_ERROR__FUND_DEPOSIT_RECEIPT_TOKEN_AMOUNT_BELOW_MINIMUM_ERROR
   | typeof RESTAKING_ERROR__FUND_DONATION_DISABLED_ERROR
   | typeof RESTAKING_ERROR__FUND_EXCEEDED_DEPOSIT_CAPACITY_AMOUNT_ERROR
+  | typeof RESTAKING_ERROR__FUND_EXCEEDED_MAX_OPERATORS_ERROR
   | typeof RESTAKING_ERROR__FUND_EXCEEDED_MAX_PRICING_SOURCES_ERROR
   | typeof RESTAKING_ERROR__FUND_EXCEEDED_MAX_RESTAKING_VAULT_COMPOUNDING_REWARD_TOKENS_ERROR
   | typeof RESTAKING_ERROR__FUND_EXCEEDED_MAX_RESTAKING_VAULT_DELEGATIONS_ERROR
   | typeof RESTAKING_ERROR__FUND_EXCEEDED_MAX_RESTAKING_VAULT_DISTRIBUTING_REWARD_TOKENS_ERROR
   | typeof RESTAKING_ERROR__FUND_EXCEEDED_MAX_RESTAKING_VAULTS_ERROR
+  | typeof RESTAKING_ERROR__FUND_EXCEEDED_MAX_REVENUE_RECIPIENTS_ERROR
   | typeof RESTAKING_ERROR__FUND_EXCEEDED_MAX_SUPPORTED_TOKENS_ERROR
   | typeof RESTAKING_ERROR__FUND_EXCEEDED_MAX_TOKEN_SWAP_STRATEGIES_ERROR
   | typeof RESTAKING_ERROR__FUND_EXCEEDED_MAX_WITHDRAWAL_REQUEST_ERROR
   | typeof RESTAKING_ERROR__FUND_EXCEEDED_MAX_WRAPPED_TOKEN_HOLDERS_ERROR
+  | typeof RESTAKING_ERROR__FUND_IN_KIND_WITHDRAWAL_DISABLED_ERROR
+  | typeof RESTAKING_ERROR__FUND_IN_KIND_WITHDRAWAL_LIQUIDITY_EXCEEDED_ERROR
+  | typeof RESTAKING_ERROR__FUND_INSTANT_WITHDRAWAL_DISABLED_ERROR
+  | typeof RESTAKING_ERROR__FUND_INSTANT_WITHDRAWAL_LIQUIDITY_EXCEEDED_ERROR
+  | typeof RESTAKING_ERROR__FUND_INVALID_CONFIGURATION_CHANGE_ERROR
   | typeof RESTAKING_ERROR__FUND_INVALID_CONFIGURATION_UPDATE_ERROR
+  | typeof RESTAKING_ERROR__FUND_INVALID_IN_KIND_WITHDRAWAL_MIN_ASSET_AMOUNTS_ERROR
+  | typeof RESTAKING_ERROR__FUND_INVALID_MANAGEMENT_FEE_RATE_ERROR
+  | typeof RESTAKING_ERROR__FUND_INVALID_OPERATION_COMMAND_POLICY_ERROR
+  | typeof RESTAKING_ERROR__FUND_INVALID_OPERATION_FALLBACK_COMMAND_ERROR
+  | typeof RESTAKING_ERROR__FUND_INVALID_OPERATION_HISTORY_ACCOUNT_ERROR
+  | typeof RESTAKING_ERROR__FUND_INVALID_REVENUE_SPLIT_ERROR
+  | typeof RESTAKING_ERROR__FUND_INVALID_TOKEN_SWAP_STRATEGY_MAX_SLIPPAGE_ERROR
   | typeof RESTAKING_ERROR__FUND_INVALID_WITHDRAWAL_FEE_RATE_ERROR
   | typeof RESTAKING_ERROR__FUND_NORMALIZED_TOKEN_ALREADY_SET_ERROR
   | typeof RESTAKING_ERROR__FUND_NORMALIZED_TOKEN_NOT_SET_ERROR
@@ -250,7 +332,14 @@ export type RestakingError =
   | typeof RESTAKING_ERROR__FUND_OPERATION_COMMAND_ACCOUNT_COMPUTATION_EXCEPTION
   | typeof RESTAKING_ERROR__FUND_OPERATION_COMMAND_EXECUTION_FAILED_EXCEPTION
   | typeof RESTAKING_ERROR__FUND_OPERATION_DISABLED_ERROR
+  | typeof RESTAKING_ERROR__FUND_OPERATION_HISTORY_ACCOUNT_NOT_FOUND_ERROR
   | typeof RESTAKING_ERROR__FUND_OPERATION_UNAUTHORIZED_COMMAND_ERROR
+  | typeof RESTAKING_ERROR__FUND_OPERATOR_ALREADY_REGISTERED_ERROR
+  | typeof RESTAKING_ERROR__FUND_OPERATOR_NOT_FOUND_ERROR
+  | typeof RESTAKING_ERROR__FUND_OPERATOR_UNAUTHORIZED_ERROR
+  | typeof RESTAKING_ERROR__FUND_PRICE_DEVIATION_CIRCUIT_BREAKER_TRIPPED_ERROR
+  | typeof RESTAKING_ERROR__FUND_PRICE_STALE_ERROR
+  | typeof RESTAKING_ERROR__FUND_RECEIPT_TOKEN_PRICE_HISTORY_INSUFFICIENT_ERROR
   | typeof RESTAKING_ERROR__FUND_RESTAKING_NOT_SUPPORTED_VAULT_ERROR
   | typeof RESTAKING_ERROR__FUND_RESTAKING_VAULT_ALREADY_REGISTERED_ERROR
   | typeof RESTAKING_ERROR__FUND_RESTAKING_VAULT_COMPOUNDING_REWARD_TOKEN_ALREADY_REGISTERED_ERROR
@@ -266,6 +355,7 @@ export type RestakingError =
   | typeof RESTAKING_ERROR__FUND_TOKEN_SWAP_STRATEGY_NOT_FOUND_ERROR
   | typeof RESTAKING_ERROR__FUND_TOKEN_SWAP_STRATEGY_VALIDATION_ERROR
   | typeof RESTAKING_ERROR__FUND_USER_HAS_PENDING_WITHDRAWAL_REQUESTS_ERROR
+  | typeof RESTAKING_ERROR__FUND_WITHDRAWAL_ASSET_AMOUNT_BELOW_MINIMUM_ERROR
   | typeof RESTAKING_ERROR__FUND_WITHDRAWAL_DISABLED_ERROR
   | typeof RESTAKING_ERROR__FUND_WITHDRAWAL_NOT_SUPPORTED_ASSET
   | typeof RESTAKING_ERROR__FUND_WITHDRAWAL_REQUEST_ALREADY_QUEUED_ERROR
@@ -286,6 +376,8 @@ export type RestakingError =
   | typeof RESTAKING_ERROR__NORMALIZED_TOKEN_POOL_NOT_ENOUGH_SUPPORTED_TOKEN_EXCEPTION
   | typeof RESTAKING_ERROR__NORMALIZED_TOKEN_POOL_NOT_SUPPORTED_TOKEN_ERROR
   | typeof RESTAKING_ERROR__RESTAKING_VAULT_AUTHORITY_NOT_MATCHED_ERROR
+  | typeof RESTAKING_ERROR__RESTAKING_VAULT_DEPOSIT_CAPACITY_EXCEEDED_ERROR
+  | typeof RESTAKING_ERROR__RESTAKING_VAULT_PAUSED_ERROR
   | typeof RESTAKING_ERROR__RESTAKING_VAULT_WITHDRAWAL_TICKET_ALREADY_INITIALIZED_ERROR
   | typeof RESTAKING_ERROR__RESTAKING_VAULT_WITHDRAWAL_TICKET_NOT_WITHDRAWABLE_ERROR
   | typeof RESTAKING_ERROR__RESTAKING_VAULT_WITHDRAWAL_TICKETS_EXHAUSTED_ERROR
@@ -323,12 +415,16 @@ export type RestakingError =
   | typeof RESTAKING_ERROR__REWARD_USER_POOL_NOT_FOUND_ERROR
   | typeof RESTAKING_ERROR__REWARD_USER_REWARD_ACCOUNT_NOT_SYNCED_ERROR
   | typeof RESTAKING_ERROR__REWARD_USER_TOKEN_ACCOUNT_NOT_INITIALIZED_ERROR
+  | typeof RESTAKING_ERROR__ROLE_INVALID_AUTHORITY_ERROR
+  | typeof RESTAKING_ERROR__ROLE_UNAUTHORIZED_ERROR
   | typeof RESTAKING_ERROR__STAKING_ACCOUNT_NOT_MATCHED_EXCEPTION
   | typeof RESTAKING_ERROR__STAKING_S_P_L_ACTIVE_STAKE_NOT_AVAILABLE_EXCEPTION
+  | typeof RESTAKING_ERROR__STAKING_S_P_L_SOL_DEPOSIT_NOT_PERMITTED_ERROR
   | typeof RESTAKING_ERROR__STAKING_UNINITIALIZED_WITHDRAW_TICKET_NOT_FOUND_EXCEPTION
   | typeof RESTAKING_ERROR__TOKEN_NOT_TRANSFERABLE_ERROR
   | typeof RESTAKING_ERROR__TOKEN_NOT_TRANSFERRING_EXCEPTION
   | typeof RESTAKING_ERROR__TOKEN_PRICING_SOURCE_ACCOUNT_NOT_FOUND_ERROR
+  | typeof RESTAKING_ERROR__TOKEN_SWAP_SLIPPAGE_EXCEEDED_ERROR
   | typeof RESTAKING_ERROR__UNEXPECTED_PRICING_SOURCE_ERROR
   | typeof RESTAKING_ERROR__U_T_F8_DECODING_EXCEPTION;
 
@@ -337,21 +433,39 @@ if (process.env.NODE_ENV !== 'production') {
   restakingErrorMessages = {
     [RESTAKING_ERROR__CALCULATION_ARITHMETIC_EXCEPTION]: `calculation arithmetic exception`,
     [RESTAKING_ERROR__FUND_ALREADY_SUPPORTED_TOKEN_ERROR]: `fund: already supported token`,
+    [RESTAKING_ERROR__FUND_CONFIGURATION_CHANGE_GUARDIAN_NOT_SET_ERROR]: `fund: configuration change delay requires the guardian to cancel changes`,
+    [RESTAKING_ERROR__FUND_CONFIGURATION_CHANGE_NOT_EXECUTABLE_YET_ERROR]: `fund: configuration change is not executable yet`,
+    [RESTAKING_ERROR__FUND_CONFIGURATION_CHANGE_TIMELOCKED_ERROR]: `fund: configuration change must be queued and executed after the timelock`,
     [RESTAKING_ERROR__FUND_DEPOSIT_DISABLED_ERROR]: `fund: deposit is currently disabled`,
     [RESTAKING_ERROR__FUND_DEPOSIT_METADATA_SIGNATURE_EXPIRED_ERROR]: `fund: deposit metadata signature has expired`,
     [RESTAKING_ERROR__FUND_DEPOSIT_NOT_SUPPORTED_ASSET]: `fund: deposit is not supported for the given asset`,
+    [RESTAKING_ERROR__FUND_DEPOSIT_RECEIPT_TOKEN_AMOUNT_BELOW_MINIMUM_ERROR]: `fund: deposit receipt token amount is below the minimum`,
     [RESTAKING_ERROR__FUND_DONATION_DISABLED_ERROR]: `fund: donation is currently disabled`,
     [RESTAKING_ERROR__FUND_EXCEEDED_DEPOSIT_CAPACITY_AMOUNT_ERROR]: `fund: exceeded deposit capacity amount`,
+    [RESTAKING_ERROR__FUND_EXCEEDED_MAX_OPERATORS_ERROR]: `fund: exceeded max operators`,
     [RESTAKING_ERROR__FUND_EXCEEDED_MAX_PRICING_SOURCES_ERROR]: `fund: exceeded max pricing sources`,
     [RESTAKING_ERROR__FUND_EXCEEDED_MAX_RESTAKING_VAULT_COMPOUNDING_REWARD_TOKENS_ERROR]: `fund: exceeded max restaking vault compounding reward tokens`,
     [RESTAKING_ERROR__FUND_EXCEEDED_MAX_RESTAKING_VAULT_DELEGATIONS_ERROR]: `fund: exceeded max restaking vault delegations`,
     [RESTAKING_ERROR__FUND_EXCEEDED_MAX_RESTAKING_VAULT_DISTRIBUTING_REWARD_TOKENS_ERROR]: `fund: exceeded max restaking vault distributing reward tokens`,
     [RESTAKING_ERROR__FUND_EXCEEDED_MAX_RESTAKING_VAULTS_ERROR]: `fund: exceeded max restaking vaults`,
+    [RESTAKING_ERROR__FUND_EXCEEDED_MAX_REVENUE_RECIPIENTS_ERROR]: `fund: exceeded max revenue recipients`,
     [RESTAKING_ERROR__FUND_EXCEEDED_MAX_SUPPORTED_TOKENS_ERROR]: `fund: exceeded max supported tokens`,
     [RESTAKING_ERROR__FUND_EXCEEDED_MAX_TOKEN_SWAP_STRATEGIES_ERROR]: `fund: exceeded max token swap strategies`,
     [RESTAKING_ERROR__FUND_EXCEEDED_MAX_WITHDRAWAL_REQUEST_ERROR]: `fund: exceeded max withdrawal request per user`,
     [RESTAKING_ERROR__FUND_EXCEEDED_MAX_WRAPPED_TOKEN_HOLDERS_ERROR]: `fund: exceeded max wrapped token holders`,
+    [RESTAKING_ERROR__FUND_IN_KIND_WITHDRAWAL_DISABLED_ERROR]: `fund: in-kind withdrawal is disabled`,
+    [RESTAKING_ERROR__FUND_IN_KIND_WITHDRAWAL_LIQUIDITY_EXCEEDED_ERROR]: `fund: in-kind withdrawal exceeds the assets held by the fund reserve`,
+    [RESTAKING_ERROR__FUND_INSTANT_WITHDRAWAL_DISABLED_ERROR]: `fund: instant withdrawal is disabled`,
+    [RESTAKING_ERROR__FUND_INSTANT_WITHDRAWAL_LIQUIDITY_EXCEEDED_ERROR]: `fund: instant withdrawal exceeds the reserve above the floor`,
+    [RESTAKING_ERROR__FUND_INVALID_CONFIGURATION_CHANGE_ERROR]: `fund: invalid configuration change`,
     [RESTAKING_ERROR__FUND_INVALID_CONFIGURATION_UPDATE_ERROR]: `fund: cannot apply invalid configuration update`,
+    [RESTAKING_ERROR__FUND_INVALID_IN_KIND_WITHDRAWAL_MIN_ASSET_AMOUNTS_ERROR]: `fund: in-kind withdrawal minimum asset amounts do not match the withdrawn assets`,
+    [RESTAKING_ERROR__FUND_INVALID_MANAGEMENT_FEE_RATE_ERROR]: `fund: invalid management fee rate`,
+    [RESTAKING_ERROR__FUND_INVALID_OPERATION_COMMAND_POLICY_ERROR]: `fund: invalid operation command policy`,
+    [RESTAKING_ERROR__FUND_INVALID_OPERATION_FALLBACK_COMMAND_ERROR]: `fund: invalid operation fallback command`,
+    [RESTAKING_ERROR__FUND_INVALID_OPERATION_HISTORY_ACCOUNT_ERROR]: `fund: invalid operation history account`,
+    [RESTAKING_ERROR__FUND_INVALID_REVENUE_SPLIT_ERROR]: `fund: invalid revenue split`,
+    [RESTAKING_ERROR__FUND_INVALID_TOKEN_SWAP_STRATEGY_MAX_SLIPPAGE_ERROR]: `fund: invalid token swap strategy max slippage`,
     [RESTAKING_ERROR__FUND_INVALID_WITHDRAWAL_FEE_RATE_ERROR]: `fund: invalid withdrawal fee rate`,
     [RESTAKING_ERROR__FUND_NORMALIZED_TOKEN_ALREADY_SET_ERROR]: `fund: normalized token already set`,
     [RESTAKING_ERROR__FUND_NORMALIZED_TOKEN_NOT_SET_ERROR]: `fund: normalized token is not set`,
@@ -359,7 +473,14 @@ if (process.env.NODE_ENV !== 'production') {
     [RESTAKING_ERROR__FUND_OPERATION_COMMAND_ACCOUNT_COMPUTATION_EXCEPTION]: `fund: failed to compute required accounts for the operation command`,
     [RESTAKING_ERROR__FUND_OPERATION_COMMAND_EXECUTION_FAILED_EXCEPTION]: `fund: failed to execute the operation command`,
     [RESTAKING_ERROR__FUND_OPERATION_DISABLED_ERROR]: `fund: operation is disabled`,
+    [RESTAKING_ERROR__FUND_OPERATION_HISTORY_ACCOUNT_NOT_FOUND_ERROR]: `fund: operation history account not found`,
     [RESTAKING_ERROR__FUND_OPERATION_UNAUTHORIZED_COMMAND_ERROR]: `fund: unauthorized operation command`,
+    [RESTAKING_ERROR__FUND_OPERATOR_ALREADY_REGISTERED_ERROR]: `fund: operator already registered`,
+    [RESTAKING_ERROR__FUND_OPERATOR_NOT_FOUND_ERROR]: `fund: operator not found`,
+    [RESTAKING_ERROR__FUND_OPERATOR_UNAUTHORIZED_ERROR]: `fund: operator is not allowed to operate the fund`,
+    [RESTAKING_ERROR__FUND_PRICE_DEVIATION_CIRCUIT_BREAKER_TRIPPED_ERROR]: `fund: price deviation circuit breaker is tripped`,
+    [RESTAKING_ERROR__FUND_PRICE_STALE_ERROR]: `fund: token prices are stale`,
+    [RESTAKING_ERROR__FUND_RECEIPT_TOKEN_PRICE_HISTORY_INSUFFICIENT_ERROR]: `fund: receipt token price history is insufficient for the window`,
     [RESTAKING_ERROR__FUND_RESTAKING_NOT_SUPPORTED_VAULT_ERROR]: `fund: not supported restaking vault`,
     [RESTAKING_ERROR__FUND_RESTAKING_VAULT_ALREADY_REGISTERED_ERROR]: `fund: restaking vault already registered`,
     [RESTAKING_ERROR__FUND_RESTAKING_VAULT_COMPOUNDING_REWARD_TOKEN_ALREADY_REGISTERED_ERROR]: `fund: restaking vault compounding reward token already registered`,
@@ -375,6 +496,7 @@ if (process.env.NODE_ENV !== 'production') {
     [RESTAKING_ERROR__FUND_TOKEN_SWAP_STRATEGY_NOT_FOUND_ERROR]: `fund: token swap strategy not found`,
     [RESTAKING_ERROR__FUND_TOKEN_SWAP_STRATEGY_VALIDATION_ERROR]: `fund: token swap strategy validation failed`,
     [RESTAKING_ERROR__FUND_USER_HAS_PENDING_WITHDRAWAL_REQUESTS_ERROR]: `fund: user has pending withdrawal requests`,
+    [RESTAKING_ERROR__FUND_WITHDRAWAL_ASSET_AMOUNT_BELOW_MINIMUM_ERROR]: `fund: withdrawal asset amount is below the minimum`,
     [RESTAKING_ERROR__FUND_WITHDRAWAL_DISABLED_ERROR]: `fund: withdrawal is currently disabled`,
     [RESTAKING_ERROR__FUND_WITHDRAWAL_NOT_SUPPORTED_ASSET]: `fund: withdrawal is not supported for the given asset`,
     [RESTAKING_ERROR__FUND_WITHDRAWAL_REQUEST_ALREADY_QUEUED_ERROR]: `fund: withdrawal request is already in progress`,
@@ -395,6 +517,8 @@ if (process.env.NODE_ENV !== 'production') {
     [RESTAKING_ERROR__NORMALIZED_TOKEN_POOL_NOT_ENOUGH_SUPPORTED_TOKEN_EXCEPTION]: `normalization: not enough supported token in the pool`,
     [RESTAKING_ERROR__NORMALIZED_TOKEN_POOL_NOT_SUPPORTED_TOKEN_ERROR]: `normalization: not supported token`,
     [RESTAKING_ERROR__RESTAKING_VAULT_AUTHORITY_NOT_MATCHED_ERROR]: `restaking: vault authority not matched`,
+    [RESTAKING_ERROR__RESTAKING_VAULT_DEPOSIT_CAPACITY_EXCEEDED_ERROR]: `restaking: vault deposit capacity is exceeded`,
+    [RESTAKING_ERROR__RESTAKING_VAULT_PAUSED_ERROR]: `restaking: vault is paused`,
     [RESTAKING_ERROR__RESTAKING_VAULT_WITHDRAWAL_TICKET_ALREADY_INITIALIZED_ERROR]: `restaking: withdrawal ticket is already initialized`,
     [RESTAKING_ERROR__RESTAKING_VAULT_WITHDRAWAL_TICKET_NOT_WITHDRAWABLE_ERROR]: `restaking: withdrawal ticket is not withdrawable`,
     [RESTAKING_ERROR__RESTAKING_VAULT_WITHDRAWAL_TICKETS_EXHAUSTED_ERROR]: `restaking: all withdrawal tickets are already in use`,
@@ -432,12 +556,16 @@ if (process.env.NODE_ENV !== 'production') {
     [RESTAKING_ERROR__REWARD_USER_POOL_NOT_FOUND_ERROR]: `reward: user pool not found`,
     [RESTAKING_ERROR__REWARD_USER_REWARD_ACCOUNT_NOT_SYNCED_ERROR]: `reward: user reward account not synced`,
     [RESTAKING_ERROR__REWARD_USER_TOKEN_ACCOUNT_NOT_INITIALIZED_ERROR]: `reward: user token account not initialized`,
+    [RESTAKING_ERROR__ROLE_INVALID_AUTHORITY_ERROR]: `role: invalid role authority`,
+    [RESTAKING_ERROR__ROLE_UNAUTHORIZED_ERROR]: `role: signer is not authorized for the role`,
     [RESTAKING_ERROR__STAKING_ACCOUNT_NOT_MATCHED_EXCEPTION]: `staking: account not matched`,
     [RESTAKING_ERROR__STAKING_S_P_L_ACTIVE_STAKE_NOT_AVAILABLE_EXCEPTION]: `staking: spl stake pool's active stake not available`,
+    [RESTAKING_ERROR__STAKING_S_P_L_SOL_DEPOSIT_NOT_PERMITTED_ERROR]: `staking: spl stake pool does not permit SOL deposit`,
     [RESTAKING_ERROR__STAKING_UNINITIALIZED_WITHDRAW_TICKET_NOT_FOUND_EXCEPTION]: `staking: failed to find uninitialized withdraw ticket`,
     [RESTAKING_ERROR__TOKEN_NOT_TRANSFERABLE_ERROR]: `token is not transferable currently`,
     [RESTAKING_ERROR__TOKEN_NOT_TRANSFERRING_EXCEPTION]: `token is not transferring currently`,
     [RESTAKING_ERROR__TOKEN_PRICING_SOURCE_ACCOUNT_NOT_FOUND_ERROR]: `pricing: token pricing source is not found`,
+    [RESTAKING_ERROR__TOKEN_SWAP_SLIPPAGE_EXCEEDED_ERROR]: `swap: swapped amount is below the minimum expected amount`,
     [RESTAKING_ERROR__UNEXPECTED_PRICING_SOURCE_ERROR]: `unexpected pricing source`,
     [RESTAKING_ERROR__U_T_F8_DECODING_EXCEPTION]: `utf-8 decoding exception`,
   };
@@ -58,9 +58,8 @@ export function getAdminCreateUserRewardAccountIdempotentDiscriminatorBytes() {
 export type AdminCreateUserRewardAccountIdempotentInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountPayer extends string | AccountMeta<string> = string,
-  TAccountAdmin extends
-    | string
-    | AccountMeta<string> = '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL',
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountUser extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountUserReceiptTokenAccount extends string | AccountMeta<string> = string,
@@ -84,6 +83,9 @@ export type AdminCreateUserRewardAccountIdempotentInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountUser extends string
         ? ReadonlyAccount<TAccountUser>
         : TAccountUser,
@@ -154,6 +156,7 @@ export function getAdminCreateUserRewardAccountIdempotentInstructionDataCodec():
 export type AdminCreateUserRewardAccountIdempotentAsyncInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountUser extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountUserReceiptTokenAccount extends string = string,
@@ -164,7 +167,8 @@ export type AdminCreateUserRewardAccountIdempotentAsyncInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount?: Address<TAccountRoleAccount>;
   user: Address<TAccountUser>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   userReceiptTokenAccount?: Address<TAccountUserReceiptTokenAccount>;
@@ -179,6 +183,7 @@ export type AdminCreateUserRewardAccountIdempotentAsyncInput<
 export async function getAdminCreateUserRewardAccountIdempotentInstructionAsync<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountUser extends string,
   TAccountReceiptTokenMint extends string,
   TAccountUserReceiptTokenAccount extends string,
@@ -192,6 +197,7 @@ export async function getAdminCreateUserRewardAccountIdempotentInstructionAsync<
   input: AdminCreateUserRewardAccountIdempotentAsyncInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountUser,
     TAccountReceiptTokenMint,
     TAccountUserReceiptTokenAccount,
@@ -207,6 +213,7 @@ export async function getAdminCreateUserRewardAccountIdempotentInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountUser,
     TAccountReceiptTokenMint,
     TAccountUserReceiptTokenAccount,
@@ -224,6 +231,7 @@ export async function getAdminCreateUserRewardAccountIdempotentInstructionAsync<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     user: { value: input.user ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -251,9 +259,11 @@ export async function getAdminCreateUserRewardAccountIdempotentInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.userReceiptTokenAccount.value) {
     accounts.userReceiptTokenAccount.value = await getProgramDerivedAddress({
@@ -322,6 +332,7 @@ export async function getAdminCreateUserRewardAccountIdempotentInstructionAsync<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.userReceiptTokenAccount),
@@ -339,6 +350,7 @@ export async function getAdminCreateUserRewardAccountIdempotentInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountUser,
     TAccountReceiptTokenMint,
     TAccountUserReceiptTokenAccount,
@@ -353,6 +365,7 @@ export async function getAdminCreateUserRewardAccountIdempotentInstructionAsync<
 export type AdminCreateUserRewardAccountIdempotentInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountUser extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountUserReceiptTokenAccount extends string = string,
@@ -363,7 +376,8 @@ export type AdminCreateUserRewardAccountIdempotentInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount: Address<TAccountRoleAccount>;
   user: Address<TAccountUser>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   userReceiptTokenAccount: Address<TAccountUserReceiptTokenAccount>;
@@ -378,6 +392,7 @@ export type AdminCreateUserRewardAccountIdempotentInput<
 export function getAdminCreateUserRewardAccountIdempotentInstruction<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountUser extends string,
   TAccountReceiptTokenMint extends string,
   TAccountUserReceiptTokenAccount extends string,
@@ -391,6 +406,7 @@ export function getAdminCreateUserRewardAccountIdempotentInstruction<
   input: AdminCreateUserRewardAccountIdempotentInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountUser,
     TAccountReceiptTokenMint,
     TAccountUserReceiptTokenAccount,
@@ -405,6 +421,7 @@ export function getAdminCreateUserRewardAccountIdempotentInstruction<
   TProgramAddress,
   TAccountPayer,
   TAccountAdmin,
+  TAccountRoleAccount,
   TAccountUser,
   TAccountReceiptTokenMint,
   TAccountUserReceiptTokenAccount,
@@ -421,6 +438,7 @@ export function getAdminCreateUserRewardAccountIdempotentInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     user: { value: input.user ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -448,10 +466,6 @@ export function getAdminCreateUserRewardAccountIdempotentInstruction<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -462,6 +476,7 @@ export function getAdminCreateUserRewardAccountIdempotentInstruction<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.userReceiptTokenAccount),
@@ -479,6 +494,7 @@ export function getAdminCreateUserRewardAccountIdempotentInstruction<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountUser,
     TAccountReceiptTokenMint,
     TAccountUserReceiptTokenAccount,
@@ -498,14 +514,15 @@ export type ParsedAdminCreateUserRewardAccountIdempotentInstruction<
   accounts: {
     payer: TAccountMetas[0];
     admin: TAccountMetas[1];
-    user: TAccountMetas[2];
-    receiptTokenMint: TAccountMetas[3];
-    userReceiptTokenAccount: TAccountMetas[4];
-    rewardAccount: TAccountMetas[5];
-    userRewardAccount: TAccountMetas[6];
-    systemProgram: TAccountMetas[7];
-    eventAuthority: TAccountMetas[8];
-    program: TAccountMetas[9];
+    roleAccount: TAccountMetas[2];
+    user: TAccountMetas[3];
+    receiptTokenMint: TAccountMetas[4];
+    userReceiptTokenAccount: TAccountMetas[5];
+    rewardAccount: TAccountMetas[6];
+    userRewardAccount: TAccountMetas[7];
+    systemProgram: TAccountMetas[8];
+    eventAuthority: TAccountMetas[9];
+    program: TAccountMetas[10];
   };
   data: AdminCreateUserRewardAccountIdempotentInstructionData;
 };
@@ -521,7 +538,7 @@ export function parseAdminCreateUserRewardAccountIdempotentInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -536,6 +553,7 @@ export function parseAdminCreateUserRewardAccountIdempotentInstruction<
     accounts: {
       payer: getNextAccount(),
       admin: getNextAccount(),
+      roleAccount: getNextAccount(),
       user: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       userReceiptTokenAccount: getNextAccount(),
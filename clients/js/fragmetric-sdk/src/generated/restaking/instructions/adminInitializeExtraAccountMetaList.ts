@@ -52,9 +52,8 @@ export function getAdminInitializeExtraAccountMetaListDiscriminatorBytes() {
 export type AdminInitializeExtraAccountMetaListInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountPayer extends string | AccountMeta<string> = string,
-  TAccountAdmin extends
-    | string
-    | AccountMeta<string> = '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL',
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -75,6 +74,9 @@ export type AdminInitializeExtraAccountMetaListInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -129,6 +131,7 @@ export function getAdminInitializeExtraAccountMetaListInstructionDataCodec(): Fi
 export type AdminInitializeExtraAccountMetaListAsyncInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountExtraAccountMetaList extends string = string,
@@ -136,7 +139,8 @@ export type AdminInitializeExtraAccountMetaListAsyncInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount?: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   extraAccountMetaList?: Address<TAccountExtraAccountMetaList>;
@@ -147,6 +151,7 @@ export type AdminInitializeExtraAccountMetaListAsyncInput<
 export async function getAdminInitializeExtraAccountMetaListInstructionAsync<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountExtraAccountMetaList extends string,
@@ -157,6 +162,7 @@ export async function getAdminInitializeExtraAccountMetaListInstructionAsync<
   input: AdminInitializeExtraAccountMetaListAsyncInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountExtraAccountMetaList,
@@ -169,6 +175,7 @@ export async function getAdminInitializeExtraAccountMetaListInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountExtraAccountMetaList,
@@ -183,6 +190,7 @@ export async function getAdminInitializeExtraAccountMetaListInstructionAsync<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -201,9 +209,11 @@ export async function getAdminInitializeExtraAccountMetaListInstructionAsync<
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -244,6 +254,7 @@ export async function getAdminInitializeExtraAccountMetaListInstructionAsync<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.extraAccountMetaList),
@@ -258,6 +269,7 @@ export async function getAdminInitializeExtraAccountMetaListInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountExtraAccountMetaList,
@@ -269,6 +281,7 @@ export async function getAdminInitializeExtraAccountMetaListInstructionAsync<
 export type AdminInitializeExtraAccountMetaListInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountExtraAccountMetaList extends string = string,
@@ -276,7 +289,8 @@ export type AdminInitializeExtraAccountMetaListInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   extraAccountMetaList: Address<TAccountExtraAccountMetaList>;
@@ -287,6 +301,7 @@ export type AdminInitializeExtraAccountMetaListInput<
 export function getAdminInitializeExtraAccountMetaListInstruction<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountExtraAccountMetaList extends string,
@@ -297,6 +312,7 @@ export function getAdminInitializeExtraAccountMetaListInstruction<
   input: AdminInitializeExtraAccountMetaListInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountExtraAccountMetaList,
@@ -308,6 +324,7 @@ export function getAdminInitializeExtraAccountMetaListInstruction<
   TProgramAddress,
   TAccountPayer,
   TAccountAdmin,
+  TAccountRoleAccount,
   TAccountSystemProgram,
   TAccountReceiptTokenMint,
   TAccountExtraAccountMetaList,
@@ -321,6 +338,7 @@ export function getAdminInitializeExtraAccountMetaListInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -339,10 +357,6 @@ export function getAdminInitializeExtraAccountMetaListInstruction<
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -353,6 +367,7 @@ export function getAdminInitializeExtraAccountMetaListInstruction<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.extraAccountMetaList),
@@ -367,6 +382,7 @@ export function getAdminInitializeExtraAccountMetaListInstruction<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountExtraAccountMetaList,
@@ -383,11 +399,12 @@ export type ParsedAdminInitializeExtraAccountMetaListInstruction<
   accounts: {
     payer: TAccountMetas[0];
     admin: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
-    receiptTokenMint: TAccountMetas[3];
-    extraAccountMetaList: TAccountMetas[4];
-    eventAuthority: TAccountMetas[5];
-    program: TAccountMetas[6];
+    roleAccount: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
+    receiptTokenMint: TAccountMetas[4];
+    extraAccountMetaList: TAccountMetas[5];
+    eventAuthority: TAccountMetas[6];
+    program: TAccountMetas[7];
   };
   data: AdminInitializeExtraAccountMetaListInstructionData;
 };
@@ -403,7 +420,7 @@ export function parseAdminInitializeExtraAccountMetaListInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -418,6 +435,7 @@ export function parseAdminInitializeExtraAccountMetaListInstruction<
     accounts: {
       payer: getNextAccount(),
       admin: getNextAccount(),
+      roleAccount: getNextAccount(),
       systemProgram: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       extraAccountMetaList: getNextAccount(),
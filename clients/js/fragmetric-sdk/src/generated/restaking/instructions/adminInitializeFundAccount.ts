@@ -53,9 +53,8 @@ export function getAdminInitializeFundAccountDiscriminatorBytes() {
 export type AdminInitializeFundAccountInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountPayer extends string | AccountMeta<string> = string,
-  TAccountAdmin extends
-    | string
-    | AccountMeta<string> = '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL',
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -84,6 +83,9 @@ export type AdminInitializeFundAccountInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -150,6 +152,7 @@ export function getAdminInitializeFundAccountInstructionDataCodec(): FixedSizeCo
 export type AdminInitializeFundAccountAsyncInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountReceiptTokenProgram extends string = string,
@@ -161,7 +164,8 @@ export type AdminInitializeFundAccountAsyncInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount?: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   /**
    * Mint authority must be admin or fund account,
@@ -181,6 +185,7 @@ export type AdminInitializeFundAccountAsyncInput<
 export async function getAdminInitializeFundAccountInstructionAsync<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountReceiptTokenProgram extends string,
@@ -195,6 +200,7 @@ export async function getAdminInitializeFundAccountInstructionAsync<
   input: AdminInitializeFundAccountAsyncInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountReceiptTokenProgram,
@@ -211,6 +217,7 @@ export async function getAdminInitializeFundAccountInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountReceiptTokenProgram,
@@ -229,6 +236,7 @@ export async function getAdminInitializeFundAccountInstructionAsync<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -260,9 +268,11 @@ export async function getAdminInitializeFundAccountInstructionAsync<
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -349,6 +359,7 @@ export async function getAdminInitializeFundAccountInstructionAsync<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.receiptTokenProgram),
@@ -365,6 +376,7 @@ export async function getAdminInitializeFundAccountInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountReceiptTokenProgram,
@@ -380,6 +392,7 @@ export async function getAdminInitializeFundAccountInstructionAsync<
 export type AdminInitializeFundAccountInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountReceiptTokenProgram extends string = string,
@@ -391,7 +404,8 @@ export type AdminInitializeFundAccountInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   /**
    * Mint authority must be admin or fund account,
@@ -411,6 +425,7 @@ export type AdminInitializeFundAccountInput<
 export function getAdminInitializeFundAccountInstruction<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountReceiptTokenProgram extends string,
@@ -425,6 +440,7 @@ export function getAdminInitializeFundAccountInstruction<
   input: AdminInitializeFundAccountInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountReceiptTokenProgram,
@@ -440,6 +456,7 @@ export function getAdminInitializeFundAccountInstruction<
   TProgramAddress,
   TAccountPayer,
   TAccountAdmin,
+  TAccountRoleAccount,
   TAccountSystemProgram,
   TAccountReceiptTokenMint,
   TAccountReceiptTokenProgram,
@@ -457,6 +474,7 @@ export function getAdminInitializeFundAccountInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -488,10 +506,6 @@ export function getAdminInitializeFundAccountInstruction<
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -506,6 +520,7 @@ export function getAdminInitializeFundAccountInstruction<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.receiptTokenProgram),
@@ -522,6 +537,7 @@ export function getAdminInitializeFundAccountInstruction<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountReceiptTokenProgram,
@@ -542,20 +558,21 @@ export type ParsedAdminInitializeFundAccountInstruction<
   accounts: {
     payer: TAccountMetas[0];
     admin: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
+    roleAccount: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
     /**
      * Mint authority must be admin or fund account,
      * otherwise `set_authority` CPI will fail.
      * Therefore, no extra constraint is needed.
      */
-    receiptTokenMint: TAccountMetas[3];
-    receiptTokenProgram: TAccountMetas[4];
-    fundAccount: TAccountMetas[5];
-    fundReceiptTokenLockAccount: TAccountMetas[6];
-    fundReserveAccount: TAccountMetas[7];
-    fundTreasuryAccount: TAccountMetas[8];
-    eventAuthority: TAccountMetas[9];
-    program: TAccountMetas[10];
+    receiptTokenMint: TAccountMetas[4];
+    receiptTokenProgram: TAccountMetas[5];
+    fundAccount: TAccountMetas[6];
+    fundReceiptTokenLockAccount: TAccountMetas[7];
+    fundReserveAccount: TAccountMetas[8];
+    fundTreasuryAccount: TAccountMetas[9];
+    eventAuthority: TAccountMetas[10];
+    program: TAccountMetas[11];
   };
   data: AdminInitializeFundAccountInstructionData;
 };
@@ -568,7 +585,7 @@ export function parseAdminInitializeFundAccountInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedAdminInitializeFundAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -583,6 +600,7 @@ export function parseAdminInitializeFundAccountInstruction<
     accounts: {
       payer: getNextAccount(),
       admin: getNextAccount(),
+      roleAccount: getNextAccount(),
       systemProgram: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       receiptTokenProgram: getNextAccount(),
@@ -52,9 +52,8 @@ export function getAdminInitializeNormalizedTokenPoolAccountDiscriminatorBytes()
 export type AdminInitializeNormalizedTokenPoolAccountInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountPayer extends string | AccountMeta<string> = string,
-  TAccountAdmin extends
-    | string
-    | AccountMeta<string> = '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL',
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -80,6 +79,9 @@ export type AdminInitializeNormalizedTokenPoolAccountInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -138,6 +140,7 @@ export function getAdminInitializeNormalizedTokenPoolAccountInstructionDataCodec
 export type AdminInitializeNormalizedTokenPoolAccountAsyncInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountNormalizedTokenProgram extends string = string,
   TAccountNormalizedTokenMint extends string = string,
@@ -146,7 +149,8 @@ export type AdminInitializeNormalizedTokenPoolAccountAsyncInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount?: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   normalizedTokenProgram?: Address<TAccountNormalizedTokenProgram>;
   normalizedTokenMint: Address<TAccountNormalizedTokenMint>;
@@ -158,6 +162,7 @@ export type AdminInitializeNormalizedTokenPoolAccountAsyncInput<
 export async function getAdminInitializeNormalizedTokenPoolAccountInstructionAsync<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountNormalizedTokenProgram extends string,
   TAccountNormalizedTokenMint extends string,
@@ -169,6 +174,7 @@ export async function getAdminInitializeNormalizedTokenPoolAccountInstructionAsy
   input: AdminInitializeNormalizedTokenPoolAccountAsyncInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountNormalizedTokenProgram,
     TAccountNormalizedTokenMint,
@@ -182,6 +188,7 @@ export async function getAdminInitializeNormalizedTokenPoolAccountInstructionAsy
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountNormalizedTokenProgram,
     TAccountNormalizedTokenMint,
@@ -197,6 +204,7 @@ export async function getAdminInitializeNormalizedTokenPoolAccountInstructionAsy
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     normalizedTokenProgram: {
       value: input.normalizedTokenProgram ?? null,
@@ -219,9 +227,11 @@ export async function getAdminInitializeNormalizedTokenPoolAccountInstructionAsy
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -263,6 +273,7 @@ export async function getAdminInitializeNormalizedTokenPoolAccountInstructionAsy
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.normalizedTokenProgram),
       getAccountMeta(accounts.normalizedTokenMint),
@@ -278,6 +289,7 @@ export async function getAdminInitializeNormalizedTokenPoolAccountInstructionAsy
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountNormalizedTokenProgram,
     TAccountNormalizedTokenMint,
@@ -290,6 +302,7 @@ export async function getAdminInitializeNormalizedTokenPoolAccountInstructionAsy
 export type AdminInitializeNormalizedTokenPoolAccountInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountNormalizedTokenProgram extends string = string,
   TAccountNormalizedTokenMint extends string = string,
@@ -298,7 +311,8 @@ export type AdminInitializeNormalizedTokenPoolAccountInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   normalizedTokenProgram?: Address<TAccountNormalizedTokenProgram>;
   normalizedTokenMint: Address<TAccountNormalizedTokenMint>;
@@ -310,6 +324,7 @@ export type AdminInitializeNormalizedTokenPoolAccountInput<
 export function getAdminInitializeNormalizedTokenPoolAccountInstruction<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountNormalizedTokenProgram extends string,
   TAccountNormalizedTokenMint extends string,
@@ -321,6 +336,7 @@ export function getAdminInitializeNormalizedTokenPoolAccountInstruction<
   input: AdminInitializeNormalizedTokenPoolAccountInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountNormalizedTokenProgram,
     TAccountNormalizedTokenMint,
@@ -333,6 +349,7 @@ export function getAdminInitializeNormalizedTokenPoolAccountInstruction<
   TProgramAddress,
   TAccountPayer,
   TAccountAdmin,
+  TAccountRoleAccount,
   TAccountSystemProgram,
   TAccountNormalizedTokenProgram,
   TAccountNormalizedTokenMint,
@@ -347,6 +364,7 @@ export function getAdminInitializeNormalizedTokenPoolAccountInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     normalizedTokenProgram: {
       value: input.normalizedTokenProgram ?? null,
@@ -369,10 +387,6 @@ export function getAdminInitializeNormalizedTokenPoolAccountInstruction<
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -387,6 +401,7 @@ export function getAdminInitializeNormalizedTokenPoolAccountInstruction<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.normalizedTokenProgram),
       getAccountMeta(accounts.normalizedTokenMint),
@@ -402,6 +417,7 @@ export function getAdminInitializeNormalizedTokenPoolAccountInstruction<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountNormalizedTokenProgram,
     TAccountNormalizedTokenMint,
@@ -419,12 +435,13 @@ export type ParsedAdminInitializeNormalizedTokenPoolAccountInstruction<
   accounts: {
     payer: TAccountMetas[0];
     admin: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
-    normalizedTokenProgram: TAccountMetas[3];
-    normalizedTokenMint: TAccountMetas[4];
-    normalizedTokenPoolAccount: TAccountMetas[5];
-    eventAuthority: TAccountMetas[6];
-    program: TAccountMetas[7];
+    roleAccount: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
+    normalizedTokenProgram: TAccountMetas[4];
+    normalizedTokenMint: TAccountMetas[5];
+    normalizedTokenPoolAccount: TAccountMetas[6];
+    eventAuthority: TAccountMetas[7];
+    program: TAccountMetas[8];
   };
   data: AdminInitializeNormalizedTokenPoolAccountInstructionData;
 };
@@ -440,7 +457,7 @@ export function parseAdminInitializeNormalizedTokenPoolAccountInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -455,6 +472,7 @@ export function parseAdminInitializeNormalizedTokenPoolAccountInstruction<
     accounts: {
       payer: getNextAccount(),
       admin: getNextAccount(),
+      roleAccount: getNextAccount(),
       systemProgram: getNextAccount(),
       normalizedTokenProgram: getNextAccount(),
       normalizedTokenMint: getNextAccount(),
@@ -53,9 +53,8 @@ export function getAdminInitializeRewardAccountDiscriminatorBytes() {
 export type AdminInitializeRewardAccountInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountPayer extends string | AccountMeta<string> = string,
-  TAccountAdmin extends
-    | string
-    | AccountMeta<string> = '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL',
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -76,6 +75,9 @@ export type AdminInitializeRewardAccountInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -130,6 +132,7 @@ export function getAdminInitializeRewardAccountInstructionDataCodec(): FixedSize
 export type AdminInitializeRewardAccountAsyncInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountRewardAccount extends string = string,
@@ -137,7 +140,8 @@ export type AdminInitializeRewardAccountAsyncInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount?: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   rewardAccount?: Address<TAccountRewardAccount>;
@@ -148,6 +152,7 @@ export type AdminInitializeRewardAccountAsyncInput<
 export async function getAdminInitializeRewardAccountInstructionAsync<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountRewardAccount extends string,
@@ -158,6 +163,7 @@ export async function getAdminInitializeRewardAccountInstructionAsync<
   input: AdminInitializeRewardAccountAsyncInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
@@ -170,6 +176,7 @@ export async function getAdminInitializeRewardAccountInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
@@ -184,6 +191,7 @@ export async function getAdminInitializeRewardAccountInstructionAsync<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -199,9 +207,11 @@ export async function getAdminInitializeRewardAccountInstructionAsync<
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -237,6 +247,7 @@ export async function getAdminInitializeRewardAccountInstructionAsync<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.rewardAccount),
@@ -249,6 +260,7 @@ export async function getAdminInitializeRewardAccountInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
@@ -260,6 +272,7 @@ export async function getAdminInitializeRewardAccountInstructionAsync<
 export type AdminInitializeRewardAccountInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountRewardAccount extends string = string,
@@ -267,7 +280,8 @@ export type AdminInitializeRewardAccountInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   rewardAccount: Address<TAccountRewardAccount>;
@@ -278,6 +292,7 @@ export type AdminInitializeRewardAccountInput<
 export function getAdminInitializeRewardAccountInstruction<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountRewardAccount extends string,
@@ -288,6 +303,7 @@ export function getAdminInitializeRewardAccountInstruction<
   input: AdminInitializeRewardAccountInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
@@ -299,6 +315,7 @@ export function getAdminInitializeRewardAccountInstruction<
   TProgramAddress,
   TAccountPayer,
   TAccountAdmin,
+  TAccountRoleAccount,
   TAccountSystemProgram,
   TAccountReceiptTokenMint,
   TAccountRewardAccount,
@@ -312,6 +329,7 @@ export function getAdminInitializeRewardAccountInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -327,10 +345,6 @@ export function getAdminInitializeRewardAccountInstruction<
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -341,6 +355,7 @@ export function getAdminInitializeRewardAccountInstruction<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.rewardAccount),
@@ -353,6 +368,7 @@ export function getAdminInitializeRewardAccountInstruction<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
@@ -369,11 +385,12 @@ export type ParsedAdminInitializeRewardAccountInstruction<
   accounts: {
     payer: TAccountMetas[0];
     admin: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
-    receiptTokenMint: TAccountMetas[3];
-    rewardAccount: TAccountMetas[4];
-    eventAuthority: TAccountMetas[5];
-    program: TAccountMetas[6];
+    roleAccount: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
+    receiptTokenMint: TAccountMetas[4];
+    rewardAccount: TAccountMetas[5];
+    eventAuthority: TAccountMetas[6];
+    program: TAccountMetas[7];
   };
   data: AdminInitializeRewardAccountInstructionData;
 };
@@ -386,7 +403,7 @@ export function parseAdminInitializeRewardAccountInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedAdminInitializeRewardAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -401,6 +418,7 @@ export function parseAdminInitializeRewardAccountInstruction<
     accounts: {
       payer: getNextAccount(),
       admin: getNextAccount(),
+      roleAccount: getNextAccount(),
       systemProgram: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       rewardAccount: getNextAccount(),
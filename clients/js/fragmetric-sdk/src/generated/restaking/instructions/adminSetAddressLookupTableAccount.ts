@@ -55,9 +55,8 @@ export function getAdminSetAddressLookupTableAccountDiscriminatorBytes() {
 
 export type AdminSetAddressLookupTableAccountInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountAdmin extends
-    | string
-    | AccountMeta<string> = '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL',
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountEventAuthority extends string | AccountMeta<string> = string,
@@ -71,6 +70,9 @@ export type AdminSetAddressLookupTableAccountInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountFundAccount extends string
         ? WritableAccount<TAccountFundAccount>
         : TAccountFundAccount,
@@ -128,12 +130,14 @@ export function getAdminSetAddressLookupTableAccountInstructionDataCodec(): Code
 
 export type AdminSetAddressLookupTableAccountAsyncInput<
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountFundAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount?: Address<TAccountRoleAccount>;
   fundAccount?: Address<TAccountFundAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   eventAuthority?: Address<TAccountEventAuthority>;
@@ -143,6 +147,7 @@ export type AdminSetAddressLookupTableAccountAsyncInput<
 
 export async function getAdminSetAddressLookupTableAccountInstructionAsync<
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountFundAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountEventAuthority extends string,
@@ -151,6 +156,7 @@ export async function getAdminSetAddressLookupTableAccountInstructionAsync<
 >(
   input: AdminSetAddressLookupTableAccountAsyncInput<
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountFundAccount,
     TAccountReceiptTokenMint,
     TAccountEventAuthority,
@@ -161,6 +167,7 @@ export async function getAdminSetAddressLookupTableAccountInstructionAsync<
   AdminSetAddressLookupTableAccountInstruction<
     TProgramAddress,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountFundAccount,
     TAccountReceiptTokenMint,
     TAccountEventAuthority,
@@ -173,6 +180,7 @@ export async function getAdminSetAddressLookupTableAccountInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     fundAccount: { value: input.fundAccount ?? null, isWritable: true },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -190,9 +198,11 @@ export async function getAdminSetAddressLookupTableAccountInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundAccount.value) {
     accounts.fundAccount.value = await getProgramDerivedAddress({
@@ -223,6 +233,7 @@ export async function getAdminSetAddressLookupTableAccountInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.eventAuthority),
@@ -235,6 +246,7 @@ export async function getAdminSetAddressLookupTableAccountInstructionAsync<
   } as AdminSetAddressLookupTableAccountInstruction<
     TProgramAddress,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountFundAccount,
     TAccountReceiptTokenMint,
     TAccountEventAuthority,
@@ -244,12 +256,14 @@ export async function getAdminSetAddressLookupTableAccountInstructionAsync<
 
 export type AdminSetAddressLookupTableAccountInput<
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountFundAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount: Address<TAccountRoleAccount>;
   fundAccount: Address<TAccountFundAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   eventAuthority: Address<TAccountEventAuthority>;
@@ -259,6 +273,7 @@ export type AdminSetAddressLookupTableAccountInput<
 
 export function getAdminSetAddressLookupTableAccountInstruction<
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountFundAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountEventAuthority extends string,
@@ -267,6 +282,7 @@ export function getAdminSetAddressLookupTableAccountInstruction<
 >(
   input: AdminSetAddressLookupTableAccountInput<
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountFundAccount,
     TAccountReceiptTokenMint,
     TAccountEventAuthority,
@@ -276,6 +292,7 @@ export function getAdminSetAddressLookupTableAccountInstruction<
 ): AdminSetAddressLookupTableAccountInstruction<
   TProgramAddress,
   TAccountAdmin,
+  TAccountRoleAccount,
   TAccountFundAccount,
   TAccountReceiptTokenMint,
   TAccountEventAuthority,
@@ -287,6 +304,7 @@ export function getAdminSetAddressLookupTableAccountInstruction<
   // Original accounts.
   const originalAccounts = {
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     fundAccount: { value: input.fundAccount ?? null, isWritable: true },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -303,16 +321,11 @@ export function getAdminSetAddressLookupTableAccountInstruction<
   // Original args.
   const args = { ...input };
 
-  // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.eventAuthority),
@@ -325,6 +338,7 @@ export function getAdminSetAddressLookupTableAccountInstruction<
   } as AdminSetAddressLookupTableAccountInstruction<
     TProgramAddress,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountFundAccount,
     TAccountReceiptTokenMint,
     TAccountEventAuthority,
@@ -339,10 +353,11 @@ export type ParsedAdminSetAddressLookupTableAccountInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     admin: TAccountMetas[0];
-    fundAccount: TAccountMetas[1];
-    receiptTokenMint: TAccountMetas[2];
-    eventAuthority: TAccountMetas[3];
-    program: TAccountMetas[4];
+    roleAccount: TAccountMetas[1];
+    fundAccount: TAccountMetas[2];
+    receiptTokenMint: TAccountMetas[3];
+    eventAuthority: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: AdminSetAddressLookupTableAccountInstructionData;
 };
@@ -355,7 +370,7 @@ export function parseAdminSetAddressLookupTableAccountInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedAdminSetAddressLookupTableAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -369,6 +384,7 @@ export function parseAdminSetAddressLookupTableAccountInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       admin: getNextAccount(),
+      roleAccount: getNextAccount(),
       fundAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       eventAuthority: getNextAccount(),
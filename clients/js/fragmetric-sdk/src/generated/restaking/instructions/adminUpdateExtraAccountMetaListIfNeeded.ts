@@ -50,9 +50,8 @@ export function getAdminUpdateExtraAccountMetaListIfNeededDiscriminatorBytes() {
 
 export type AdminUpdateExtraAccountMetaListIfNeededInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountAdmin extends
-    | string
-    | AccountMeta<string> = '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL',
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountExtraAccountMetaList extends string | AccountMeta<string> = string,
   TAccountEventAuthority extends string | AccountMeta<string> = string,
@@ -66,6 +65,9 @@ export type AdminUpdateExtraAccountMetaListIfNeededInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -117,12 +119,14 @@ export function getAdminUpdateExtraAccountMetaListIfNeededInstructionDataCodec()
 
 export type AdminUpdateExtraAccountMetaListIfNeededAsyncInput<
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountExtraAccountMetaList extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   extraAccountMetaList?: Address<TAccountExtraAccountMetaList>;
   eventAuthority?: Address<TAccountEventAuthority>;
@@ -131,6 +135,7 @@ export type AdminUpdateExtraAccountMetaListIfNeededAsyncInput<
 
 export async function getAdminUpdateExtraAccountMetaListIfNeededInstructionAsync<
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountExtraAccountMetaList extends string,
   TAccountEventAuthority extends string,
@@ -139,6 +144,7 @@ export async function getAdminUpdateExtraAccountMetaListIfNeededInstructionAsync
 >(
   input: AdminUpdateExtraAccountMetaListIfNeededAsyncInput<
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountExtraAccountMetaList,
     TAccountEventAuthority,
@@ -149,6 +155,7 @@ export async function getAdminUpdateExtraAccountMetaListIfNeededInstructionAsync
   AdminUpdateExtraAccountMetaListIfNeededInstruction<
     TProgramAddress,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountExtraAccountMetaList,
     TAccountEventAuthority,
@@ -161,6 +168,7 @@ export async function getAdminUpdateExtraAccountMetaListIfNeededInstructionAsync
   // Original accounts.
   const originalAccounts = {
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -178,9 +186,11 @@ export async function getAdminUpdateExtraAccountMetaListIfNeededInstructionAsync
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.extraAccountMetaList.value) {
     accounts.extraAccountMetaList.value = await getProgramDerivedAddress({
@@ -216,6 +226,7 @@ export async function getAdminUpdateExtraAccountMetaListIfNeededInstructionAsync
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.extraAccountMetaList),
       getAccountMeta(accounts.eventAuthority),
@@ -228,6 +239,7 @@ export async function getAdminUpdateExtraAccountMetaListIfNeededInstructionAsync
   } as AdminUpdateExtraAccountMetaListIfNeededInstruction<
     TProgramAddress,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountExtraAccountMetaList,
     TAccountEventAuthority,
@@ -237,12 +249,14 @@ export async function getAdminUpdateExtraAccountMetaListIfNeededInstructionAsync
 
 export type AdminUpdateExtraAccountMetaListIfNeededInput<
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountExtraAccountMetaList extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   extraAccountMetaList: Address<TAccountExtraAccountMetaList>;
   eventAuthority: Address<TAccountEventAuthority>;
@@ -251,6 +265,7 @@ export type AdminUpdateExtraAccountMetaListIfNeededInput<
 
 export function getAdminUpdateExtraAccountMetaListIfNeededInstruction<
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountExtraAccountMetaList extends string,
   TAccountEventAuthority extends string,
@@ -259,6 +274,7 @@ export function getAdminUpdateExtraAccountMetaListIfNeededInstruction<
 >(
   input: AdminUpdateExtraAccountMetaListIfNeededInput<
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountExtraAccountMetaList,
     TAccountEventAuthority,
@@ -268,6 +284,7 @@ export function getAdminUpdateExtraAccountMetaListIfNeededInstruction<
 ): AdminUpdateExtraAccountMetaListIfNeededInstruction<
   TProgramAddress,
   TAccountAdmin,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountExtraAccountMetaList,
   TAccountEventAuthority,
@@ -279,6 +296,7 @@ export function getAdminUpdateExtraAccountMetaListIfNeededInstruction<
   // Original accounts.
   const originalAccounts = {
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -295,16 +313,11 @@ export function getAdminUpdateExtraAccountMetaListIfNeededInstruction<
     ResolvedAccount
   >;
 
-  // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.extraAccountMetaList),
       getAccountMeta(accounts.eventAuthority),
@@ -317,6 +330,7 @@ export function getAdminUpdateExtraAccountMetaListIfNeededInstruction<
   } as AdminUpdateExtraAccountMetaListIfNeededInstruction<
     TProgramAddress,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountExtraAccountMetaList,
     TAccountEventAuthority,
@@ -331,10 +345,11 @@ export type ParsedAdminUpdateExtraAccountMetaListIfNeededInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     admin: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    extraAccountMetaList: TAccountMetas[2];
-    eventAuthority: TAccountMetas[3];
-    program: TAccountMetas[4];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    extraAccountMetaList: TAccountMetas[3];
+    eventAuthority: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: AdminUpdateExtraAccountMetaListIfNeededInstructionData;
 };
@@ -350,7 +365,7 @@ export function parseAdminUpdateExtraAccountMetaListIfNeededInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -364,6 +379,7 @@ export function parseAdminUpdateExtraAccountMetaListIfNeededInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       admin: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       extraAccountMetaList: getNextAccount(),
       eventAuthority: getNextAccount(),
@@ -59,9 +59,8 @@ export function getAdminUpdateFundAccountIfNeededDiscriminatorBytes() {
 export type AdminUpdateFundAccountIfNeededInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountPayer extends string | AccountMeta<string> = string,
-  TAccountAdmin extends
-    | string
-    | AccountMeta<string> = '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL',
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -82,6 +81,9 @@ export type AdminUpdateFundAccountIfNeededInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -143,6 +145,7 @@ export function getAdminUpdateFundAccountIfNeededInstructionDataCodec(): Codec<
 export type AdminUpdateFundAccountIfNeededAsyncInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
@@ -150,7 +153,8 @@ export type AdminUpdateFundAccountIfNeededAsyncInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount?: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
@@ -162,6 +166,7 @@ export type AdminUpdateFundAccountIfNeededAsyncInput<
 export async function getAdminUpdateFundAccountIfNeededInstructionAsync<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
@@ -172,6 +177,7 @@ export async function getAdminUpdateFundAccountIfNeededInstructionAsync<
   input: AdminUpdateFundAccountIfNeededAsyncInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -184,6 +190,7 @@ export async function getAdminUpdateFundAccountIfNeededInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -198,6 +205,7 @@ export async function getAdminUpdateFundAccountIfNeededInstructionAsync<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -216,9 +224,11 @@ export async function getAdminUpdateFundAccountIfNeededInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -254,6 +264,7 @@ export async function getAdminUpdateFundAccountIfNeededInstructionAsync<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
@@ -268,6 +279,7 @@ export async function getAdminUpdateFundAccountIfNeededInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -279,6 +291,7 @@ export async function getAdminUpdateFundAccountIfNeededInstructionAsync<
 export type AdminUpdateFundAccountIfNeededInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
@@ -286,7 +299,8 @@ export type AdminUpdateFundAccountIfNeededInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
@@ -298,6 +312,7 @@ export type AdminUpdateFundAccountIfNeededInput<
 export function getAdminUpdateFundAccountIfNeededInstruction<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
@@ -308,6 +323,7 @@ export function getAdminUpdateFundAccountIfNeededInstruction<
   input: AdminUpdateFundAccountIfNeededInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -319,6 +335,7 @@ export function getAdminUpdateFundAccountIfNeededInstruction<
   TProgramAddress,
   TAccountPayer,
   TAccountAdmin,
+  TAccountRoleAccount,
   TAccountSystemProgram,
   TAccountReceiptTokenMint,
   TAccountFundAccount,
@@ -332,6 +349,7 @@ export function getAdminUpdateFundAccountIfNeededInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -350,10 +368,6 @@ export function getAdminUpdateFundAccountIfNeededInstruction<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -364,6 +378,7 @@ export function getAdminUpdateFundAccountIfNeededInstruction<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
@@ -378,6 +393,7 @@ export function getAdminUpdateFundAccountIfNeededInstruction<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -394,11 +410,12 @@ export type ParsedAdminUpdateFundAccountIfNeededInstruction<
   accounts: {
     payer: TAccountMetas[0];
     admin: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
-    receiptTokenMint: TAccountMetas[3];
-    fundAccount: TAccountMetas[4];
-    eventAuthority: TAccountMetas[5];
-    program: TAccountMetas[6];
+    roleAccount: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
+    receiptTokenMint: TAccountMetas[4];
+    fundAccount: TAccountMetas[5];
+    eventAuthority: TAccountMetas[6];
+    program: TAccountMetas[7];
   };
   data: AdminUpdateFundAccountIfNeededInstructionData;
 };
@@ -411,7 +428,7 @@ export function parseAdminUpdateFundAccountIfNeededInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedAdminUpdateFundAccountIfNeededInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -426,6 +443,7 @@ export function parseAdminUpdateFundAccountIfNeededInstruction<
     accounts: {
       payer: getNextAccount(),
       admin: getNextAccount(),
+      roleAccount: getNextAccount(),
       systemProgram: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
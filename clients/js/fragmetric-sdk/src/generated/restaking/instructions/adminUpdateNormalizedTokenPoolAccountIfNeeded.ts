@@ -52,9 +52,8 @@ export function getAdminUpdateNormalizedTokenPoolAccountIfNeededDiscriminatorByt
 export type AdminUpdateNormalizedTokenPoolAccountIfNeededInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountPayer extends string | AccountMeta<string> = string,
-  TAccountAdmin extends
-    | string
-    | AccountMeta<string> = '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL',
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -80,6 +79,9 @@ export type AdminUpdateNormalizedTokenPoolAccountIfNeededInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -139,6 +141,7 @@ export function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstructionDataC
 export type AdminUpdateNormalizedTokenPoolAccountIfNeededAsyncInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountNormalizedTokenProgram extends string = string,
   TAccountNormalizedTokenMint extends string = string,
@@ -147,7 +150,8 @@ export type AdminUpdateNormalizedTokenPoolAccountIfNeededAsyncInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount?: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   normalizedTokenProgram?: Address<TAccountNormalizedTokenProgram>;
   normalizedTokenMint: Address<TAccountNormalizedTokenMint>;
@@ -159,6 +163,7 @@ export type AdminUpdateNormalizedTokenPoolAccountIfNeededAsyncInput<
 export async function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstructionAsync<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountNormalizedTokenProgram extends string,
   TAccountNormalizedTokenMint extends string,
@@ -170,6 +175,7 @@ export async function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstructio
   input: AdminUpdateNormalizedTokenPoolAccountIfNeededAsyncInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountNormalizedTokenProgram,
     TAccountNormalizedTokenMint,
@@ -183,6 +189,7 @@ export async function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstructio
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountNormalizedTokenProgram,
     TAccountNormalizedTokenMint,
@@ -198,6 +205,7 @@ export async function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstructio
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     normalizedTokenProgram: {
       value: input.normalizedTokenProgram ?? null,
@@ -220,9 +228,11 @@ export async function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstructio
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -264,6 +274,7 @@ export async function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstructio
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.normalizedTokenProgram),
       getAccountMeta(accounts.normalizedTokenMint),
@@ -279,6 +290,7 @@ export async function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstructio
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountNormalizedTokenProgram,
     TAccountNormalizedTokenMint,
@@ -291,6 +303,7 @@ export async function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstructio
 export type AdminUpdateNormalizedTokenPoolAccountIfNeededInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountNormalizedTokenProgram extends string = string,
   TAccountNormalizedTokenMint extends string = string,
@@ -299,7 +312,8 @@ export type AdminUpdateNormalizedTokenPoolAccountIfNeededInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   normalizedTokenProgram?: Address<TAccountNormalizedTokenProgram>;
   normalizedTokenMint: Address<TAccountNormalizedTokenMint>;
@@ -311,6 +325,7 @@ export type AdminUpdateNormalizedTokenPoolAccountIfNeededInput<
 export function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstruction<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountNormalizedTokenProgram extends string,
   TAccountNormalizedTokenMint extends string,
@@ -322,6 +337,7 @@ export function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstruction<
   input: AdminUpdateNormalizedTokenPoolAccountIfNeededInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountNormalizedTokenProgram,
     TAccountNormalizedTokenMint,
@@ -334,6 +350,7 @@ export function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstruction<
   TProgramAddress,
   TAccountPayer,
   TAccountAdmin,
+  TAccountRoleAccount,
   TAccountSystemProgram,
   TAccountNormalizedTokenProgram,
   TAccountNormalizedTokenMint,
@@ -348,6 +365,7 @@ export function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     normalizedTokenProgram: {
       value: input.normalizedTokenProgram ?? null,
@@ -370,10 +388,6 @@ export function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstruction<
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -388,6 +402,7 @@ export function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstruction<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.normalizedTokenProgram),
       getAccountMeta(accounts.normalizedTokenMint),
@@ -403,6 +418,7 @@ export function getAdminUpdateNormalizedTokenPoolAccountIfNeededInstruction<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountNormalizedTokenProgram,
     TAccountNormalizedTokenMint,
@@ -420,12 +436,13 @@ export type ParsedAdminUpdateNormalizedTokenPoolAccountIfNeededInstruction<
   accounts: {
     payer: TAccountMetas[0];
     admin: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
-    normalizedTokenProgram: TAccountMetas[3];
-    normalizedTokenMint: TAccountMetas[4];
-    normalizedTokenPoolAccount: TAccountMetas[5];
-    eventAuthority: TAccountMetas[6];
-    program: TAccountMetas[7];
+    roleAccount: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
+    normalizedTokenProgram: TAccountMetas[4];
+    normalizedTokenMint: TAccountMetas[5];
+    normalizedTokenPoolAccount: TAccountMetas[6];
+    eventAuthority: TAccountMetas[7];
+    program: TAccountMetas[8];
   };
   data: AdminUpdateNormalizedTokenPoolAccountIfNeededInstructionData;
 };
@@ -441,7 +458,7 @@ export function parseAdminUpdateNormalizedTokenPoolAccountIfNeededInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -456,6 +473,7 @@ export function parseAdminUpdateNormalizedTokenPoolAccountIfNeededInstruction<
     accounts: {
       payer: getNextAccount(),
       admin: getNextAccount(),
+      roleAccount: getNextAccount(),
       systemProgram: getNextAccount(),
       normalizedTokenProgram: getNextAccount(),
       normalizedTokenMint: getNextAccount(),
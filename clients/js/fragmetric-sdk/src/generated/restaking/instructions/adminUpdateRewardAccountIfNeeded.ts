@@ -58,9 +58,8 @@ export function getAdminUpdateRewardAccountIfNeededDiscriminatorBytes() {
 export type AdminUpdateRewardAccountIfNeededInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountPayer extends string | AccountMeta<string> = string,
-  TAccountAdmin extends
-    | string
-    | AccountMeta<string> = '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL',
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -81,6 +80,9 @@ export type AdminUpdateRewardAccountIfNeededInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -142,6 +144,7 @@ export function getAdminUpdateRewardAccountIfNeededInstructionDataCodec(): Codec
 export type AdminUpdateRewardAccountIfNeededAsyncInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountRewardAccount extends string = string,
@@ -149,7 +152,8 @@ export type AdminUpdateRewardAccountIfNeededAsyncInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount?: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   rewardAccount?: Address<TAccountRewardAccount>;
@@ -161,6 +165,7 @@ export type AdminUpdateRewardAccountIfNeededAsyncInput<
 export async function getAdminUpdateRewardAccountIfNeededInstructionAsync<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountRewardAccount extends string,
@@ -171,6 +176,7 @@ export async function getAdminUpdateRewardAccountIfNeededInstructionAsync<
   input: AdminUpdateRewardAccountIfNeededAsyncInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
@@ -183,6 +189,7 @@ export async function getAdminUpdateRewardAccountIfNeededInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
@@ -197,6 +204,7 @@ export async function getAdminUpdateRewardAccountIfNeededInstructionAsync<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -215,9 +223,11 @@ export async function getAdminUpdateRewardAccountIfNeededInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -253,6 +263,7 @@ export async function getAdminUpdateRewardAccountIfNeededInstructionAsync<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.rewardAccount),
@@ -267,6 +278,7 @@ export async function getAdminUpdateRewardAccountIfNeededInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
@@ -278,6 +290,7 @@ export async function getAdminUpdateRewardAccountIfNeededInstructionAsync<
 export type AdminUpdateRewardAccountIfNeededInput<
   TAccountPayer extends string = string,
   TAccountAdmin extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountRewardAccount extends string = string,
@@ -285,7 +298,8 @@ export type AdminUpdateRewardAccountIfNeededInput<
   TAccountProgram extends string = string,
 > = {
   payer: TransactionSigner<TAccountPayer>;
-  admin?: TransactionSigner<TAccountAdmin>;
+  admin: TransactionSigner<TAccountAdmin>;
+  roleAccount: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   rewardAccount: Address<TAccountRewardAccount>;
@@ -297,6 +311,7 @@ export type AdminUpdateRewardAccountIfNeededInput<
 export function getAdminUpdateRewardAccountIfNeededInstruction<
   TAccountPayer extends string,
   TAccountAdmin extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountRewardAccount extends string,
@@ -307,6 +322,7 @@ export function getAdminUpdateRewardAccountIfNeededInstruction<
   input: AdminUpdateRewardAccountIfNeededInput<
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
@@ -318,6 +334,7 @@ export function getAdminUpdateRewardAccountIfNeededInstruction<
   TProgramAddress,
   TAccountPayer,
   TAccountAdmin,
+  TAccountRoleAccount,
   TAccountSystemProgram,
   TAccountReceiptTokenMint,
   TAccountRewardAccount,
@@ -331,6 +348,7 @@ export function getAdminUpdateRewardAccountIfNeededInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -349,10 +367,6 @@ export function getAdminUpdateRewardAccountIfNeededInstruction<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -363,6 +377,7 @@ export function getAdminUpdateRewardAccountIfNeededInstruction<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.rewardAccount),
@@ -377,6 +392,7 @@ export function getAdminUpdateRewardAccountIfNeededInstruction<
     TProgramAddress,
     TAccountPayer,
     TAccountAdmin,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
@@ -393,11 +409,12 @@ export type ParsedAdminUpdateRewardAccountIfNeededInstruction<
   accounts: {
     payer: TAccountMetas[0];
     admin: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
-    receiptTokenMint: TAccountMetas[3];
-    rewardAccount: TAccountMetas[4];
-    eventAuthority: TAccountMetas[5];
-    program: TAccountMetas[6];
+    roleAccount: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
+    receiptTokenMint: TAccountMetas[4];
+    rewardAccount: TAccountMetas[5];
+    eventAuthority: TAccountMetas[6];
+    program: TAccountMetas[7];
   };
   data: AdminUpdateRewardAccountIfNeededInstructionData;
 };
@@ -410,7 +427,7 @@ export function parseAdminUpdateRewardAccountIfNeededInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedAdminUpdateRewardAccountIfNeededInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -425,6 +442,7 @@ export function parseAdminUpdateRewardAccountIfNeededInstruction<
     accounts: {
       payer: getNextAccount(),
       admin: getNextAccount(),
+      roleAccount: getNextAccount(),
       systemProgram: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       rewardAccount: getNextAccount(),
@@ -56,9 +56,8 @@ export function getFundManagerAddNormalizedTokenPoolSupportedTokenDiscriminatorB
 
 export type FundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountNormalizedTokenMint extends string | AccountMeta<string> = string,
   TAccountNormalizedTokenPoolAccount extends
     | string
@@ -82,6 +81,9 @@ export type FundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountNormalizedTokenMint extends string
         ? ReadonlyAccount<TAccountNormalizedTokenMint>
         : TAccountNormalizedTokenMint,
@@ -151,6 +153,7 @@ export function getFundManagerAddNormalizedTokenPoolSupportedTokenInstructionDat
 
 export type FundManagerAddNormalizedTokenPoolSupportedTokenAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountNormalizedTokenMint extends string = string,
   TAccountNormalizedTokenPoolAccount extends string = string,
   TAccountNormalizedTokenProgram extends string = string,
@@ -160,7 +163,8 @@ export type FundManagerAddNormalizedTokenPoolSupportedTokenAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   normalizedTokenMint: Address<TAccountNormalizedTokenMint>;
   normalizedTokenPoolAccount?: Address<TAccountNormalizedTokenPoolAccount>;
   normalizedTokenProgram?: Address<TAccountNormalizedTokenProgram>;
@@ -174,6 +178,7 @@ export type FundManagerAddNormalizedTokenPoolSupportedTokenAsyncInput<
 
 export async function getFundManagerAddNormalizedTokenPoolSupportedTokenInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountNormalizedTokenMint extends string,
   TAccountNormalizedTokenPoolAccount extends string,
   TAccountNormalizedTokenProgram extends string,
@@ -186,6 +191,7 @@ export async function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruct
 >(
   input: FundManagerAddNormalizedTokenPoolSupportedTokenAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountNormalizedTokenMint,
     TAccountNormalizedTokenPoolAccount,
     TAccountNormalizedTokenProgram,
@@ -200,6 +206,7 @@ export async function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruct
   FundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountNormalizedTokenMint,
     TAccountNormalizedTokenPoolAccount,
     TAccountNormalizedTokenProgram,
@@ -216,6 +223,7 @@ export async function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruct
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     normalizedTokenMint: {
       value: input.normalizedTokenMint ?? null,
       isWritable: false,
@@ -252,9 +260,11 @@ export async function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruct
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.normalizedTokenPoolAccount.value) {
     accounts.normalizedTokenPoolAccount.value = await getProgramDerivedAddress({
@@ -309,6 +319,7 @@ export async function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruct
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.normalizedTokenMint),
       getAccountMeta(accounts.normalizedTokenPoolAccount),
       getAccountMeta(accounts.normalizedTokenProgram),
@@ -325,6 +336,7 @@ export async function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruct
   } as FundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountNormalizedTokenMint,
     TAccountNormalizedTokenPoolAccount,
     TAccountNormalizedTokenProgram,
@@ -338,6 +350,7 @@ export async function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruct
 
 export type FundManagerAddNormalizedTokenPoolSupportedTokenInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountNormalizedTokenMint extends string = string,
   TAccountNormalizedTokenPoolAccount extends string = string,
   TAccountNormalizedTokenProgram extends string = string,
@@ -347,7 +360,8 @@ export type FundManagerAddNormalizedTokenPoolSupportedTokenInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   normalizedTokenMint: Address<TAccountNormalizedTokenMint>;
   normalizedTokenPoolAccount: Address<TAccountNormalizedTokenPoolAccount>;
   normalizedTokenProgram?: Address<TAccountNormalizedTokenProgram>;
@@ -361,6 +375,7 @@ export type FundManagerAddNormalizedTokenPoolSupportedTokenInput<
 
 export function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountNormalizedTokenMint extends string,
   TAccountNormalizedTokenPoolAccount extends string,
   TAccountNormalizedTokenProgram extends string,
@@ -373,6 +388,7 @@ export function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
 >(
   input: FundManagerAddNormalizedTokenPoolSupportedTokenInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountNormalizedTokenMint,
     TAccountNormalizedTokenPoolAccount,
     TAccountNormalizedTokenProgram,
@@ -386,6 +402,7 @@ export function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
 ): FundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountNormalizedTokenMint,
   TAccountNormalizedTokenPoolAccount,
   TAccountNormalizedTokenProgram,
@@ -401,6 +418,7 @@ export function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     normalizedTokenMint: {
       value: input.normalizedTokenMint ?? null,
       isWritable: false,
@@ -437,10 +455,6 @@ export function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
   if (!accounts.normalizedTokenProgram.value) {
     accounts.normalizedTokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
@@ -450,6 +464,7 @@ export function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.normalizedTokenMint),
       getAccountMeta(accounts.normalizedTokenPoolAccount),
       getAccountMeta(accounts.normalizedTokenProgram),
@@ -466,6 +481,7 @@ export function getFundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
   } as FundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountNormalizedTokenMint,
     TAccountNormalizedTokenPoolAccount,
     TAccountNormalizedTokenProgram,
@@ -484,14 +500,15 @@ export type ParsedFundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    normalizedTokenMint: TAccountMetas[1];
-    normalizedTokenPoolAccount: TAccountMetas[2];
-    normalizedTokenProgram: TAccountMetas[3];
-    supportedTokenMint: TAccountMetas[4];
-    supportedTokenProgram: TAccountMetas[5];
-    normalizedTokenPoolSupportedTokenAccount: TAccountMetas[6];
-    eventAuthority: TAccountMetas[7];
-    program: TAccountMetas[8];
+    roleAccount: TAccountMetas[1];
+    normalizedTokenMint: TAccountMetas[2];
+    normalizedTokenPoolAccount: TAccountMetas[3];
+    normalizedTokenProgram: TAccountMetas[4];
+    supportedTokenMint: TAccountMetas[5];
+    supportedTokenProgram: TAccountMetas[6];
+    normalizedTokenPoolSupportedTokenAccount: TAccountMetas[7];
+    eventAuthority: TAccountMetas[8];
+    program: TAccountMetas[9];
   };
   data: FundManagerAddNormalizedTokenPoolSupportedTokenInstructionData;
 };
@@ -507,7 +524,7 @@ export function parseFundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -521,6 +538,7 @@ export function parseFundManagerAddNormalizedTokenPoolSupportedTokenInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       normalizedTokenMint: getNextAccount(),
       normalizedTokenPoolAccount: getNextAccount(),
       normalizedTokenProgram: getNextAccount(),
@@ -62,9 +62,8 @@ export function getFundManagerAddRewardDiscriminatorBytes() {
 
 export type FundManagerAddRewardInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountRewardAccount extends string | AccountMeta<string> = string,
   TAccountRewardReserveAccount extends string | AccountMeta<string> = string,
@@ -84,6 +83,9 @@ export type FundManagerAddRewardInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -173,6 +175,7 @@ export function getFundManagerAddRewardInstructionDataCodec(): Codec<
 
 export type FundManagerAddRewardAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountRewardReserveAccount extends string = string,
@@ -182,7 +185,8 @@ export type FundManagerAddRewardAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   rewardAccount?: Address<TAccountRewardAccount>;
   rewardReserveAccount?: Address<TAccountRewardReserveAccount>;
@@ -201,6 +205,7 @@ export type FundManagerAddRewardAsyncInput<
 
 export async function getFundManagerAddRewardInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountRewardAccount extends string,
   TAccountRewardReserveAccount extends string,
@@ -213,6 +218,7 @@ export async function getFundManagerAddRewardInstructionAsync<
 >(
   input: FundManagerAddRewardAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -227,6 +233,7 @@ export async function getFundManagerAddRewardInstructionAsync<
   FundManagerAddRewardInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -243,6 +250,7 @@ export async function getFundManagerAddRewardInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -276,9 +284,11 @@ export async function getFundManagerAddRewardInstructionAsync<
   const args = { ...input, program: input.programArg };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.rewardAccount.value) {
     accounts.rewardAccount.value = await getProgramDerivedAddress({
@@ -324,6 +334,7 @@ export async function getFundManagerAddRewardInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.rewardReserveAccount),
@@ -340,6 +351,7 @@ export async function getFundManagerAddRewardInstructionAsync<
   } as FundManagerAddRewardInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -353,6 +365,7 @@ export async function getFundManagerAddRewardInstructionAsync<
 
 export type FundManagerAddRewardInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountRewardReserveAccount extends string = string,
@@ -362,7 +375,8 @@ export type FundManagerAddRewardInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   rewardAccount: Address<TAccountRewardAccount>;
   rewardReserveAccount: Address<TAccountRewardReserveAccount>;
@@ -381,6 +395,7 @@ export type FundManagerAddRewardInput<
 
 export function getFundManagerAddRewardInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountRewardAccount extends string,
   TAccountRewardReserveAccount extends string,
@@ -393,6 +408,7 @@ export function getFundManagerAddRewardInstruction<
 >(
   input: FundManagerAddRewardInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -406,6 +422,7 @@ export function getFundManagerAddRewardInstruction<
 ): FundManagerAddRewardInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountRewardAccount,
   TAccountRewardReserveAccount,
@@ -421,6 +438,7 @@ export function getFundManagerAddRewardInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -453,16 +471,11 @@ export function getFundManagerAddRewardInstruction<
   // Original args.
   const args = { ...input, program: input.programArg };
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.rewardReserveAccount),
@@ -479,6 +492,7 @@ export function getFundManagerAddRewardInstruction<
   } as FundManagerAddRewardInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -497,14 +511,15 @@ export type ParsedFundManagerAddRewardInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    rewardAccount: TAccountMetas[2];
-    rewardReserveAccount: TAccountMetas[3];
-    rewardTokenMint?: TAccountMetas[4] | undefined;
-    rewardTokenProgram?: TAccountMetas[5] | undefined;
-    rewardTokenReserveAccount?: TAccountMetas[6] | undefined;
-    eventAuthority: TAccountMetas[7];
-    program: TAccountMetas[8];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    rewardAccount: TAccountMetas[3];
+    rewardReserveAccount: TAccountMetas[4];
+    rewardTokenMint?: TAccountMetas[5] | undefined;
+    rewardTokenProgram?: TAccountMetas[6] | undefined;
+    rewardTokenReserveAccount?: TAccountMetas[7] | undefined;
+    eventAuthority: TAccountMetas[8];
+    program: TAccountMetas[9];
   };
   data: FundManagerAddRewardInstructionData;
 };
@@ -517,7 +532,7 @@ export function parseFundManagerAddRewardInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedFundManagerAddRewardInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -537,6 +552,7 @@ export function parseFundManagerAddRewardInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       rewardAccount: getNextAccount(),
       rewardReserveAccount: getNextAccount(),
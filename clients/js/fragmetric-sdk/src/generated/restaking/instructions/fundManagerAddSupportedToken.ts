@@ -57,9 +57,8 @@ export function getFundManagerAddSupportedTokenDiscriminatorBytes() {
 
 export type FundManagerAddSupportedTokenInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountFundReserveAccount extends string | AccountMeta<string> = string,
@@ -83,6 +82,9 @@ export type FundManagerAddSupportedTokenInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -158,6 +160,7 @@ export function getFundManagerAddSupportedTokenInstructionDataCodec(): FixedSize
 
 export type FundManagerAddSupportedTokenAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
@@ -169,7 +172,8 @@ export type FundManagerAddSupportedTokenAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
   fundReserveAccount?: Address<TAccountFundReserveAccount>;
@@ -185,6 +189,7 @@ export type FundManagerAddSupportedTokenAsyncInput<
 
 export async function getFundManagerAddSupportedTokenInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
@@ -199,6 +204,7 @@ export async function getFundManagerAddSupportedTokenInstructionAsync<
 >(
   input: FundManagerAddSupportedTokenAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -215,6 +221,7 @@ export async function getFundManagerAddSupportedTokenInstructionAsync<
   FundManagerAddSupportedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -233,6 +240,7 @@ export async function getFundManagerAddSupportedTokenInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -274,9 +282,11 @@ export async function getFundManagerAddSupportedTokenInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundAccount.value) {
     accounts.fundAccount.value = await getProgramDerivedAddress({
@@ -373,6 +383,7 @@ export async function getFundManagerAddSupportedTokenInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
@@ -391,6 +402,7 @@ export async function getFundManagerAddSupportedTokenInstructionAsync<
   } as FundManagerAddSupportedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -406,6 +418,7 @@ export async function getFundManagerAddSupportedTokenInstructionAsync<
 
 export type FundManagerAddSupportedTokenInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
@@ -417,7 +430,8 @@ export type FundManagerAddSupportedTokenInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
   fundReserveAccount: Address<TAccountFundReserveAccount>;
@@ -433,6 +447,7 @@ export type FundManagerAddSupportedTokenInput<
 
 export function getFundManagerAddSupportedTokenInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
@@ -447,6 +462,7 @@ export function getFundManagerAddSupportedTokenInstruction<
 >(
   input: FundManagerAddSupportedTokenInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -462,6 +478,7 @@ export function getFundManagerAddSupportedTokenInstruction<
 ): FundManagerAddSupportedTokenInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountFundAccount,
   TAccountFundReserveAccount,
@@ -479,6 +496,7 @@ export function getFundManagerAddSupportedTokenInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -519,16 +537,11 @@ export function getFundManagerAddSupportedTokenInstruction<
   // Original args.
   const args = { ...input };
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
@@ -547,6 +560,7 @@ export function getFundManagerAddSupportedTokenInstruction<
   } as FundManagerAddSupportedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -567,16 +581,17 @@ export type ParsedFundManagerAddSupportedTokenInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    fundAccount: TAccountMetas[2];
-    fundReserveAccount: TAccountMetas[3];
-    fundTreasuryAccount: TAccountMetas[4];
-    supportedTokenMint: TAccountMetas[5];
-    supportedTokenProgram: TAccountMetas[6];
-    supportedTokenReserveAccount: TAccountMetas[7];
-    supportedTokenTreasuryAccount: TAccountMetas[8];
-    eventAuthority: TAccountMetas[9];
-    program: TAccountMetas[10];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    fundAccount: TAccountMetas[3];
+    fundReserveAccount: TAccountMetas[4];
+    fundTreasuryAccount: TAccountMetas[5];
+    supportedTokenMint: TAccountMetas[6];
+    supportedTokenProgram: TAccountMetas[7];
+    supportedTokenReserveAccount: TAccountMetas[8];
+    supportedTokenTreasuryAccount: TAccountMetas[9];
+    eventAuthority: TAccountMetas[10];
+    program: TAccountMetas[11];
   };
   data: FundManagerAddSupportedTokenInstructionData;
 };
@@ -589,7 +604,7 @@ export function parseFundManagerAddSupportedTokenInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedFundManagerAddSupportedTokenInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -603,6 +618,7 @@ export function parseFundManagerAddSupportedTokenInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
       fundReserveAccount: getNextAccount(),
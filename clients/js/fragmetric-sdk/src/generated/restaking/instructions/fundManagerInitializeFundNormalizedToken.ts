@@ -50,9 +50,8 @@ export function getFundManagerInitializeFundNormalizedTokenDiscriminatorBytes()
 
 export type FundManagerInitializeFundNormalizedTokenInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -77,6 +76,9 @@ export type FundManagerInitializeFundNormalizedTokenInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -143,6 +145,7 @@ export function getFundManagerInitializeFundNormalizedTokenInstructionDataCodec(
 
 export type FundManagerInitializeFundNormalizedTokenAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
@@ -153,7 +156,8 @@ export type FundManagerInitializeFundNormalizedTokenAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   fundAccount?: Address<TAccountFundAccount>;
   fundReserveAccount?: Address<TAccountFundReserveAccount>;
@@ -167,6 +171,7 @@ export type FundManagerInitializeFundNormalizedTokenAsyncInput<
 
 export async function getFundManagerInitializeFundNormalizedTokenInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
@@ -180,6 +185,7 @@ export async function getFundManagerInitializeFundNormalizedTokenInstructionAsyn
 >(
   input: FundManagerInitializeFundNormalizedTokenAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -195,6 +201,7 @@ export async function getFundManagerInitializeFundNormalizedTokenInstructionAsyn
   FundManagerInitializeFundNormalizedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -212,6 +219,7 @@ export async function getFundManagerInitializeFundNormalizedTokenInstructionAsyn
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     fundAccount: { value: input.fundAccount ?? null, isWritable: true },
     fundReserveAccount: {
@@ -243,9 +251,11 @@ export async function getFundManagerInitializeFundNormalizedTokenInstructionAsyn
   >;
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -330,6 +340,7 @@ export async function getFundManagerInitializeFundNormalizedTokenInstructionAsyn
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
@@ -347,6 +358,7 @@ export async function getFundManagerInitializeFundNormalizedTokenInstructionAsyn
   } as FundManagerInitializeFundNormalizedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -361,6 +373,7 @@ export async function getFundManagerInitializeFundNormalizedTokenInstructionAsyn
 
 export type FundManagerInitializeFundNormalizedTokenInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
@@ -371,7 +384,8 @@ export type FundManagerInitializeFundNormalizedTokenInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   fundAccount: Address<TAccountFundAccount>;
   fundReserveAccount: Address<TAccountFundReserveAccount>;
@@ -385,6 +399,7 @@ export type FundManagerInitializeFundNormalizedTokenInput<
 
 export function getFundManagerInitializeFundNormalizedTokenInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
@@ -398,6 +413,7 @@ export function getFundManagerInitializeFundNormalizedTokenInstruction<
 >(
   input: FundManagerInitializeFundNormalizedTokenInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -412,6 +428,7 @@ export function getFundManagerInitializeFundNormalizedTokenInstruction<
 ): FundManagerInitializeFundNormalizedTokenInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountSystemProgram,
   TAccountFundAccount,
   TAccountFundReserveAccount,
@@ -428,6 +445,7 @@ export function getFundManagerInitializeFundNormalizedTokenInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     fundAccount: { value: input.fundAccount ?? null, isWritable: true },
     fundReserveAccount: {
@@ -459,10 +477,6 @@ export function getFundManagerInitializeFundNormalizedTokenInstruction<
   >;
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -472,6 +486,7 @@ export function getFundManagerInitializeFundNormalizedTokenInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
@@ -489,6 +504,7 @@ export function getFundManagerInitializeFundNormalizedTokenInstruction<
   } as FundManagerInitializeFundNormalizedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -508,15 +524,16 @@ export type ParsedFundManagerInitializeFundNormalizedTokenInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    systemProgram: TAccountMetas[1];
-    fundAccount: TAccountMetas[2];
-    fundReserveAccount: TAccountMetas[3];
-    receiptTokenMint: TAccountMetas[4];
-    normalizedTokenMint: TAccountMetas[5];
-    fundNormalizedTokenReserveAccount: TAccountMetas[6];
-    normalizedTokenPoolAccount: TAccountMetas[7];
-    eventAuthority: TAccountMetas[8];
-    program: TAccountMetas[9];
+    roleAccount: TAccountMetas[1];
+    systemProgram: TAccountMetas[2];
+    fundAccount: TAccountMetas[3];
+    fundReserveAccount: TAccountMetas[4];
+    receiptTokenMint: TAccountMetas[5];
+    normalizedTokenMint: TAccountMetas[6];
+    fundNormalizedTokenReserveAccount: TAccountMetas[7];
+    normalizedTokenPoolAccount: TAccountMetas[8];
+    eventAuthority: TAccountMetas[9];
+    program: TAccountMetas[10];
   };
   data: FundManagerInitializeFundNormalizedTokenInstructionData;
 };
@@ -532,7 +549,7 @@ export function parseFundManagerInitializeFundNormalizedTokenInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -546,6 +563,7 @@ export function parseFundManagerInitializeFundNormalizedTokenInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       systemProgram: getNextAccount(),
       fundAccount: getNextAccount(),
       fundReserveAccount: getNextAccount(),
@@ -56,9 +56,8 @@ export function getFundManagerInitializeFundRestakingVaultDiscriminatorBytes() {
 
 export type FundManagerInitializeFundRestakingVaultInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -88,6 +87,9 @@ export type FundManagerInitializeFundRestakingVaultInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -169,6 +171,7 @@ export function getFundManagerInitializeFundRestakingVaultInstructionDataCodec()
 
 export type FundManagerInitializeFundRestakingVaultAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
@@ -182,7 +185,8 @@ export type FundManagerInitializeFundRestakingVaultAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   fundAccount?: Address<TAccountFundAccount>;
   fundReserveAccount?: Address<TAccountFundReserveAccount>;
@@ -200,6 +204,7 @@ export type FundManagerInitializeFundRestakingVaultAsyncInput<
 
 export async function getFundManagerInitializeFundRestakingVaultInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
@@ -216,6 +221,7 @@ export async function getFundManagerInitializeFundRestakingVaultInstructionAsync
 >(
   input: FundManagerInitializeFundRestakingVaultAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -234,6 +240,7 @@ export async function getFundManagerInitializeFundRestakingVaultInstructionAsync
   FundManagerInitializeFundRestakingVaultInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -254,6 +261,7 @@ export async function getFundManagerInitializeFundRestakingVaultInstructionAsync
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     fundAccount: { value: input.fundAccount ?? null, isWritable: true },
     fundReserveAccount: {
@@ -297,9 +305,11 @@ export async function getFundManagerInitializeFundRestakingVaultInstructionAsync
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -415,6 +425,7 @@ export async function getFundManagerInitializeFundRestakingVaultInstructionAsync
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
@@ -435,6 +446,7 @@ export async function getFundManagerInitializeFundRestakingVaultInstructionAsync
   } as FundManagerInitializeFundRestakingVaultInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -452,6 +464,7 @@ export async function getFundManagerInitializeFundRestakingVaultInstructionAsync
 
 export type FundManagerInitializeFundRestakingVaultInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
@@ -465,7 +478,8 @@ export type FundManagerInitializeFundRestakingVaultInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   fundAccount: Address<TAccountFundAccount>;
   fundReserveAccount: Address<TAccountFundReserveAccount>;
@@ -483,6 +497,7 @@ export type FundManagerInitializeFundRestakingVaultInput<
 
 export function getFundManagerInitializeFundRestakingVaultInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
@@ -499,6 +514,7 @@ export function getFundManagerInitializeFundRestakingVaultInstruction<
 >(
   input: FundManagerInitializeFundRestakingVaultInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -516,6 +532,7 @@ export function getFundManagerInitializeFundRestakingVaultInstruction<
 ): FundManagerInitializeFundRestakingVaultInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountSystemProgram,
   TAccountFundAccount,
   TAccountFundReserveAccount,
@@ -535,6 +552,7 @@ export function getFundManagerInitializeFundRestakingVaultInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     fundAccount: { value: input.fundAccount ?? null, isWritable: true },
     fundReserveAccount: {
@@ -578,10 +596,6 @@ export function getFundManagerInitializeFundRestakingVaultInstruction<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -591,6 +605,7 @@ export function getFundManagerInitializeFundRestakingVaultInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
@@ -611,6 +626,7 @@ export function getFundManagerInitializeFundRestakingVaultInstruction<
   } as FundManagerInitializeFundRestakingVaultInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountFundAccount,
     TAccountFundReserveAccount,
@@ -633,18 +649,19 @@ export type ParsedFundManagerInitializeFundRestakingVaultInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    systemProgram: TAccountMetas[1];
-    fundAccount: TAccountMetas[2];
-    fundReserveAccount: TAccountMetas[3];
-    receiptTokenMint: TAccountMetas[4];
-    vaultAccount: TAccountMetas[5];
-    vaultReceiptTokenMint: TAccountMetas[6];
-    vaultSupportedTokenMint: TAccountMetas[7];
-    fundVaultReceiptTokenAccount: TAccountMetas[8];
-    fundVaultSupportedTokenAccount: TAccountMetas[9];
-    vaultVaultSupportedTokenAccount: TAccountMetas[10];
-    eventAuthority: TAccountMetas[11];
-    program: TAccountMetas[12];
+    roleAccount: TAccountMetas[1];
+    systemProgram: TAccountMetas[2];
+    fundAccount: TAccountMetas[3];
+    fundReserveAccount: TAccountMetas[4];
+    receiptTokenMint: TAccountMetas[5];
+    vaultAccount: TAccountMetas[6];
+    vaultReceiptTokenMint: TAccountMetas[7];
+    vaultSupportedTokenMint: TAccountMetas[8];
+    fundVaultReceiptTokenAccount: TAccountMetas[9];
+    fundVaultSupportedTokenAccount: TAccountMetas[10];
+    vaultVaultSupportedTokenAccount: TAccountMetas[11];
+    eventAuthority: TAccountMetas[12];
+    program: TAccountMetas[13];
   };
   data: FundManagerInitializeFundRestakingVaultInstructionData;
 };
@@ -660,7 +677,7 @@ export function parseFundManagerInitializeFundRestakingVaultInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 13) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -674,6 +691,7 @@ export function parseFundManagerInitializeFundRestakingVaultInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       systemProgram: getNextAccount(),
       fundAccount: getNextAccount(),
       fundReserveAccount: getNextAccount(),
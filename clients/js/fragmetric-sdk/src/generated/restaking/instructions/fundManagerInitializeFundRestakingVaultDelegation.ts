@@ -50,9 +50,8 @@ export function getFundManagerInitializeFundRestakingVaultDelegationDiscriminato
 
 export type FundManagerInitializeFundRestakingVaultDelegationInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountVaultAccount extends string | AccountMeta<string> = string,
@@ -69,6 +68,9 @@ export type FundManagerInitializeFundRestakingVaultDelegationInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountFundAccount extends string
         ? WritableAccount<TAccountFundAccount>
         : TAccountFundAccount,
@@ -130,6 +132,7 @@ export function getFundManagerInitializeFundRestakingVaultDelegationInstructionD
 
 export type FundManagerInitializeFundRestakingVaultDelegationAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountFundAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountVaultAccount extends string = string,
@@ -138,7 +141,8 @@ export type FundManagerInitializeFundRestakingVaultDelegationAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   fundAccount?: Address<TAccountFundAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   vaultAccount: Address<TAccountVaultAccount>;
@@ -150,6 +154,7 @@ export type FundManagerInitializeFundRestakingVaultDelegationAsyncInput<
 
 export async function getFundManagerInitializeFundRestakingVaultDelegationInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountFundAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountVaultAccount extends string,
@@ -161,6 +166,7 @@ export async function getFundManagerInitializeFundRestakingVaultDelegationInstru
 >(
   input: FundManagerInitializeFundRestakingVaultDelegationAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountFundAccount,
     TAccountReceiptTokenMint,
     TAccountVaultAccount,
@@ -174,6 +180,7 @@ export async function getFundManagerInitializeFundRestakingVaultDelegationInstru
   FundManagerInitializeFundRestakingVaultDelegationInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountFundAccount,
     TAccountReceiptTokenMint,
     TAccountVaultAccount,
@@ -189,6 +196,7 @@ export async function getFundManagerInitializeFundRestakingVaultDelegationInstru
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     fundAccount: { value: input.fundAccount ?? null, isWritable: true },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -212,9 +220,11 @@ export async function getFundManagerInitializeFundRestakingVaultDelegationInstru
   >;
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundAccount.value) {
     accounts.fundAccount.value = await getProgramDerivedAddress({
@@ -245,6 +255,7 @@ export async function getFundManagerInitializeFundRestakingVaultDelegationInstru
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.vaultAccount),
@@ -260,6 +271,7 @@ export async function getFundManagerInitializeFundRestakingVaultDelegationInstru
   } as FundManagerInitializeFundRestakingVaultDelegationInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountFundAccount,
     TAccountReceiptTokenMint,
     TAccountVaultAccount,
@@ -272,6 +284,7 @@ export async function getFundManagerInitializeFundRestakingVaultDelegationInstru
 
 export type FundManagerInitializeFundRestakingVaultDelegationInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountFundAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountVaultAccount extends string = string,
@@ -280,7 +293,8 @@ export type FundManagerInitializeFundRestakingVaultDelegationInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   fundAccount: Address<TAccountFundAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   vaultAccount: Address<TAccountVaultAccount>;
@@ -292,6 +306,7 @@ export type FundManagerInitializeFundRestakingVaultDelegationInput<
 
 export function getFundManagerInitializeFundRestakingVaultDelegationInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountFundAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountVaultAccount extends string,
@@ -303,6 +318,7 @@ export function getFundManagerInitializeFundRestakingVaultDelegationInstruction<
 >(
   input: FundManagerInitializeFundRestakingVaultDelegationInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountFundAccount,
     TAccountReceiptTokenMint,
     TAccountVaultAccount,
@@ -315,6 +331,7 @@ export function getFundManagerInitializeFundRestakingVaultDelegationInstruction<
 ): FundManagerInitializeFundRestakingVaultDelegationInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountFundAccount,
   TAccountReceiptTokenMint,
   TAccountVaultAccount,
@@ -329,6 +346,7 @@ export function getFundManagerInitializeFundRestakingVaultDelegationInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     fundAccount: { value: input.fundAccount ?? null, isWritable: true },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -351,16 +369,11 @@ export function getFundManagerInitializeFundRestakingVaultDelegationInstruction<
     ResolvedAccount
   >;
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.vaultAccount),
@@ -376,6 +389,7 @@ export function getFundManagerInitializeFundRestakingVaultDelegationInstruction<
   } as FundManagerInitializeFundRestakingVaultDelegationInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountFundAccount,
     TAccountReceiptTokenMint,
     TAccountVaultAccount,
@@ -393,13 +407,14 @@ export type ParsedFundManagerInitializeFundRestakingVaultDelegationInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    fundAccount: TAccountMetas[1];
-    receiptTokenMint: TAccountMetas[2];
-    vaultAccount: TAccountMetas[3];
-    operatorAccount: TAccountMetas[4];
-    vaultOperatorDelegation: TAccountMetas[5];
-    eventAuthority: TAccountMetas[6];
-    program: TAccountMetas[7];
+    roleAccount: TAccountMetas[1];
+    fundAccount: TAccountMetas[2];
+    receiptTokenMint: TAccountMetas[3];
+    vaultAccount: TAccountMetas[4];
+    operatorAccount: TAccountMetas[5];
+    vaultOperatorDelegation: TAccountMetas[6];
+    eventAuthority: TAccountMetas[7];
+    program: TAccountMetas[8];
   };
   data: FundManagerInitializeFundRestakingVaultDelegationInstructionData;
 };
@@ -415,7 +430,7 @@ export function parseFundManagerInitializeFundRestakingVaultDelegationInstructio
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -429,6 +444,7 @@ export function parseFundManagerInitializeFundRestakingVaultDelegationInstructio
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       fundAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       vaultAccount: getNextAccount(),
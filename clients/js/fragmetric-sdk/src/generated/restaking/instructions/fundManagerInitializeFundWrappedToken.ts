@@ -50,12 +50,9 @@ export function getFundManagerInitializeFundWrappedTokenDiscriminatorBytes() {
 
 export type FundManagerInitializeFundWrappedTokenInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountAdmin extends
-    | string
-    | AccountMeta<string> = '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL',
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountAdmin extends string | AccountMeta<string> = string,
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountFundWrapAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountReceiptTokenWrapAccount extends string | AccountMeta<string> = string,
@@ -83,6 +80,9 @@ export type FundManagerInitializeFundWrappedTokenInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountFundWrapAccount extends string
         ? ReadonlyAccount<TAccountFundWrapAccount>
         : TAccountFundWrapAccount,
@@ -152,6 +152,7 @@ export function getFundManagerInitializeFundWrappedTokenInstructionDataCodec():
 export type FundManagerInitializeFundWrappedTokenAsyncInput<
   TAccountAdmin extends string = string,
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountFundWrapAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountReceiptTokenWrapAccount extends string = string,
@@ -163,8 +164,9 @@ export type FundManagerInitializeFundWrappedTokenAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  admin?: TransactionSigner<TAccountAdmin>;
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  admin: TransactionSigner<TAccountAdmin>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   fundWrapAccount?: Address<TAccountFundWrapAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   receiptTokenWrapAccount?: Address<TAccountReceiptTokenWrapAccount>;
@@ -180,6 +182,7 @@ export type FundManagerInitializeFundWrappedTokenAsyncInput<
 export async function getFundManagerInitializeFundWrappedTokenInstructionAsync<
   TAccountAdmin extends string,
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountFundWrapAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountReceiptTokenWrapAccount extends string,
@@ -195,6 +198,7 @@ export async function getFundManagerInitializeFundWrappedTokenInstructionAsync<
   input: FundManagerInitializeFundWrappedTokenAsyncInput<
     TAccountAdmin,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountFundWrapAccount,
     TAccountReceiptTokenMint,
     TAccountReceiptTokenWrapAccount,
@@ -212,6 +216,7 @@ export async function getFundManagerInitializeFundWrappedTokenInstructionAsync<
     TProgramAddress,
     TAccountAdmin,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountFundWrapAccount,
     TAccountReceiptTokenMint,
     TAccountReceiptTokenWrapAccount,
@@ -231,6 +236,7 @@ export async function getFundManagerInitializeFundWrappedTokenInstructionAsync<
   const originalAccounts = {
     admin: { value: input.admin ?? null, isWritable: false },
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     fundWrapAccount: {
       value: input.fundWrapAccount ?? null,
       isWritable: false,
@@ -266,13 +272,11 @@ export async function getFundManagerInitializeFundWrappedTokenInstructionAsync<
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
-  }
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundWrapAccount.value) {
     accounts.fundWrapAccount.value = await getProgramDerivedAddress({
@@ -372,6 +376,7 @@ export async function getFundManagerInitializeFundWrappedTokenInstructionAsync<
     accounts: [
       getAccountMeta(accounts.admin),
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.fundWrapAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.receiptTokenWrapAccount),
@@ -391,6 +396,7 @@ export async function getFundManagerInitializeFundWrappedTokenInstructionAsync<
     TProgramAddress,
     TAccountAdmin,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountFundWrapAccount,
     TAccountReceiptTokenMint,
     TAccountReceiptTokenWrapAccount,
@@ -407,6 +413,7 @@ export async function getFundManagerInitializeFundWrappedTokenInstructionAsync<
 export type FundManagerInitializeFundWrappedTokenInput<
   TAccountAdmin extends string = string,
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountFundWrapAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountReceiptTokenWrapAccount extends string = string,
@@ -418,8 +425,9 @@ export type FundManagerInitializeFundWrappedTokenInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  admin?: TransactionSigner<TAccountAdmin>;
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  admin: TransactionSigner<TAccountAdmin>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   fundWrapAccount: Address<TAccountFundWrapAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   receiptTokenWrapAccount: Address<TAccountReceiptTokenWrapAccount>;
@@ -435,6 +443,7 @@ export type FundManagerInitializeFundWrappedTokenInput<
 export function getFundManagerInitializeFundWrappedTokenInstruction<
   TAccountAdmin extends string,
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountFundWrapAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountReceiptTokenWrapAccount extends string,
@@ -450,6 +459,7 @@ export function getFundManagerInitializeFundWrappedTokenInstruction<
   input: FundManagerInitializeFundWrappedTokenInput<
     TAccountAdmin,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountFundWrapAccount,
     TAccountReceiptTokenMint,
     TAccountReceiptTokenWrapAccount,
@@ -466,6 +476,7 @@ export function getFundManagerInitializeFundWrappedTokenInstruction<
   TProgramAddress,
   TAccountAdmin,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountFundWrapAccount,
   TAccountReceiptTokenMint,
   TAccountReceiptTokenWrapAccount,
@@ -484,6 +495,7 @@ export function getFundManagerInitializeFundWrappedTokenInstruction<
   const originalAccounts = {
     admin: { value: input.admin ?? null, isWritable: false },
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     fundWrapAccount: {
       value: input.fundWrapAccount ?? null,
       isWritable: false,
@@ -519,14 +531,6 @@ export function getFundManagerInitializeFundWrappedTokenInstruction<
   >;
 
   // Resolve default values.
-  if (!accounts.admin.value) {
-    accounts.admin.value =
-      '9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL' as Address<'9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL'>;
-  }
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
   if (!accounts.wrappedTokenProgram.value) {
     accounts.wrappedTokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
@@ -537,6 +541,7 @@ export function getFundManagerInitializeFundWrappedTokenInstruction<
     accounts: [
       getAccountMeta(accounts.admin),
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.fundWrapAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.receiptTokenWrapAccount),
@@ -556,6 +561,7 @@ export function getFundManagerInitializeFundWrappedTokenInstruction<
     TProgramAddress,
     TAccountAdmin,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountFundWrapAccount,
     TAccountReceiptTokenMint,
     TAccountReceiptTokenWrapAccount,
@@ -577,16 +583,17 @@ export type ParsedFundManagerInitializeFundWrappedTokenInstruction<
   accounts: {
     admin: TAccountMetas[0];
     fundManager: TAccountMetas[1];
-    fundWrapAccount: TAccountMetas[2];
-    receiptTokenMint: TAccountMetas[3];
-    receiptTokenWrapAccount: TAccountMetas[4];
-    wrappedTokenMint: TAccountMetas[5];
-    wrappedTokenProgram: TAccountMetas[6];
-    fundAccount: TAccountMetas[7];
-    rewardAccount: TAccountMetas[8];
-    fundWrapAccountRewardAccount: TAccountMetas[9];
-    eventAuthority: TAccountMetas[10];
-    program: TAccountMetas[11];
+    roleAccount: TAccountMetas[2];
+    fundWrapAccount: TAccountMetas[3];
+    receiptTokenMint: TAccountMetas[4];
+    receiptTokenWrapAccount: TAccountMetas[5];
+    wrappedTokenMint: TAccountMetas[6];
+    wrappedTokenProgram: TAccountMetas[7];
+    fundAccount: TAccountMetas[8];
+    rewardAccount: TAccountMetas[9];
+    fundWrapAccountRewardAccount: TAccountMetas[10];
+    eventAuthority: TAccountMetas[11];
+    program: TAccountMetas[12];
   };
   data: FundManagerInitializeFundWrappedTokenInstructionData;
 };
@@ -602,7 +609,7 @@ export function parseFundManagerInitializeFundWrappedTokenInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 13) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -617,6 +624,7 @@ export function parseFundManagerInitializeFundWrappedTokenInstruction<
     accounts: {
       admin: getNextAccount(),
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       fundWrapAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       receiptTokenWrapAccount: getNextAccount(),
@@ -50,9 +50,8 @@ export function getFundManagerRemoveNormalizedTokenPoolSupportedTokenDiscriminat
 
 export type FundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountNormalizedTokenMint extends string | AccountMeta<string> = string,
   TAccountNormalizedTokenPoolAccount extends
     | string
@@ -76,6 +75,9 @@ export type FundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountNormalizedTokenMint extends string
         ? ReadonlyAccount<TAccountNormalizedTokenMint>
         : TAccountNormalizedTokenMint,
@@ -139,6 +141,7 @@ export function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction
 
 export type FundManagerRemoveNormalizedTokenPoolSupportedTokenAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountNormalizedTokenMint extends string = string,
   TAccountNormalizedTokenPoolAccount extends string = string,
   TAccountNormalizedTokenProgram extends string = string,
@@ -148,7 +151,8 @@ export type FundManagerRemoveNormalizedTokenPoolSupportedTokenAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   normalizedTokenMint: Address<TAccountNormalizedTokenMint>;
   normalizedTokenPoolAccount?: Address<TAccountNormalizedTokenPoolAccount>;
   normalizedTokenProgram?: Address<TAccountNormalizedTokenProgram>;
@@ -161,6 +165,7 @@ export type FundManagerRemoveNormalizedTokenPoolSupportedTokenAsyncInput<
 
 export async function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountNormalizedTokenMint extends string,
   TAccountNormalizedTokenPoolAccount extends string,
   TAccountNormalizedTokenProgram extends string,
@@ -173,6 +178,7 @@ export async function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstr
 >(
   input: FundManagerRemoveNormalizedTokenPoolSupportedTokenAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountNormalizedTokenMint,
     TAccountNormalizedTokenPoolAccount,
     TAccountNormalizedTokenProgram,
@@ -187,6 +193,7 @@ export async function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstr
   FundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountNormalizedTokenMint,
     TAccountNormalizedTokenPoolAccount,
     TAccountNormalizedTokenProgram,
@@ -203,6 +210,7 @@ export async function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstr
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     normalizedTokenMint: {
       value: input.normalizedTokenMint ?? null,
       isWritable: false,
@@ -236,9 +244,11 @@ export async function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstr
   >;
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.normalizedTokenPoolAccount.value) {
     accounts.normalizedTokenPoolAccount.value = await getProgramDerivedAddress({
@@ -293,6 +303,7 @@ export async function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstr
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.normalizedTokenMint),
       getAccountMeta(accounts.normalizedTokenPoolAccount),
       getAccountMeta(accounts.normalizedTokenProgram),
@@ -309,6 +320,7 @@ export async function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstr
   } as FundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountNormalizedTokenMint,
     TAccountNormalizedTokenPoolAccount,
     TAccountNormalizedTokenProgram,
@@ -322,6 +334,7 @@ export async function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstr
 
 export type FundManagerRemoveNormalizedTokenPoolSupportedTokenInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountNormalizedTokenMint extends string = string,
   TAccountNormalizedTokenPoolAccount extends string = string,
   TAccountNormalizedTokenProgram extends string = string,
@@ -331,7 +344,8 @@ export type FundManagerRemoveNormalizedTokenPoolSupportedTokenInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   normalizedTokenMint: Address<TAccountNormalizedTokenMint>;
   normalizedTokenPoolAccount: Address<TAccountNormalizedTokenPoolAccount>;
   normalizedTokenProgram?: Address<TAccountNormalizedTokenProgram>;
@@ -344,6 +358,7 @@ export type FundManagerRemoveNormalizedTokenPoolSupportedTokenInput<
 
 export function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountNormalizedTokenMint extends string,
   TAccountNormalizedTokenPoolAccount extends string,
   TAccountNormalizedTokenProgram extends string,
@@ -356,6 +371,7 @@ export function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction
 >(
   input: FundManagerRemoveNormalizedTokenPoolSupportedTokenInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountNormalizedTokenMint,
     TAccountNormalizedTokenPoolAccount,
     TAccountNormalizedTokenProgram,
@@ -369,6 +385,7 @@ export function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction
 ): FundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountNormalizedTokenMint,
   TAccountNormalizedTokenPoolAccount,
   TAccountNormalizedTokenProgram,
@@ -384,6 +401,7 @@ export function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     normalizedTokenMint: {
       value: input.normalizedTokenMint ?? null,
       isWritable: false,
@@ -417,10 +435,6 @@ export function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction
   >;
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
   if (!accounts.normalizedTokenProgram.value) {
     accounts.normalizedTokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
@@ -430,6 +444,7 @@ export function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.normalizedTokenMint),
       getAccountMeta(accounts.normalizedTokenPoolAccount),
       getAccountMeta(accounts.normalizedTokenProgram),
@@ -446,6 +461,7 @@ export function getFundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction
   } as FundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountNormalizedTokenMint,
     TAccountNormalizedTokenPoolAccount,
     TAccountNormalizedTokenProgram,
@@ -464,14 +480,15 @@ export type ParsedFundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    normalizedTokenMint: TAccountMetas[1];
-    normalizedTokenPoolAccount: TAccountMetas[2];
-    normalizedTokenProgram: TAccountMetas[3];
-    supportedTokenMint: TAccountMetas[4];
-    supportedTokenProgram: TAccountMetas[5];
-    normalizedTokenPoolSupportedTokenAccount: TAccountMetas[6];
-    eventAuthority: TAccountMetas[7];
-    program: TAccountMetas[8];
+    roleAccount: TAccountMetas[1];
+    normalizedTokenMint: TAccountMetas[2];
+    normalizedTokenPoolAccount: TAccountMetas[3];
+    normalizedTokenProgram: TAccountMetas[4];
+    supportedTokenMint: TAccountMetas[5];
+    supportedTokenProgram: TAccountMetas[6];
+    normalizedTokenPoolSupportedTokenAccount: TAccountMetas[7];
+    eventAuthority: TAccountMetas[8];
+    program: TAccountMetas[9];
   };
   data: FundManagerRemoveNormalizedTokenPoolSupportedTokenInstructionData;
 };
@@ -487,7 +504,7 @@ export function parseFundManagerRemoveNormalizedTokenPoolSupportedTokenInstructi
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -501,6 +518,7 @@ export function parseFundManagerRemoveNormalizedTokenPoolSupportedTokenInstructi
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       normalizedTokenMint: getNextAccount(),
       normalizedTokenPoolAccount: getNextAccount(),
       normalizedTokenProgram: getNextAccount(),
@@ -51,9 +51,8 @@ export function getFundManagerRemoveRestakingVaultDistributingRewardTokenDiscrim
 
 export type FundManagerRemoveRestakingVaultDistributingRewardTokenInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountRewardTokenMint extends string | AccountMeta<string> = string,
@@ -68,6 +67,9 @@ export type FundManagerRemoveRestakingVaultDistributingRewardTokenInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -126,13 +128,15 @@ export function getFundManagerRemoveRestakingVaultDistributingRewardTokenInstruc
 
 export type FundManagerRemoveRestakingVaultDistributingRewardTokenAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
   rewardTokenMint: Address<TAccountRewardTokenMint>;
@@ -143,6 +147,7 @@ export type FundManagerRemoveRestakingVaultDistributingRewardTokenAsyncInput<
 
 export async function getFundManagerRemoveRestakingVaultDistributingRewardTokenInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountRewardTokenMint extends string,
@@ -152,6 +157,7 @@ export async function getFundManagerRemoveRestakingVaultDistributingRewardTokenI
 >(
   input: FundManagerRemoveRestakingVaultDistributingRewardTokenAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountRewardTokenMint,
@@ -163,6 +169,7 @@ export async function getFundManagerRemoveRestakingVaultDistributingRewardTokenI
   FundManagerRemoveRestakingVaultDistributingRewardTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountRewardTokenMint,
@@ -176,6 +183,7 @@ export async function getFundManagerRemoveRestakingVaultDistributingRewardTokenI
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -197,9 +205,11 @@ export async function getFundManagerRemoveRestakingVaultDistributingRewardTokenI
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundAccount.value) {
     accounts.fundAccount.value = await getProgramDerivedAddress({
@@ -230,6 +240,7 @@ export async function getFundManagerRemoveRestakingVaultDistributingRewardTokenI
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.rewardTokenMint),
@@ -243,6 +254,7 @@ export async function getFundManagerRemoveRestakingVaultDistributingRewardTokenI
   } as FundManagerRemoveRestakingVaultDistributingRewardTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountRewardTokenMint,
@@ -253,13 +265,15 @@ export async function getFundManagerRemoveRestakingVaultDistributingRewardTokenI
 
 export type FundManagerRemoveRestakingVaultDistributingRewardTokenInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
   rewardTokenMint: Address<TAccountRewardTokenMint>;
@@ -270,6 +284,7 @@ export type FundManagerRemoveRestakingVaultDistributingRewardTokenInput<
 
 export function getFundManagerRemoveRestakingVaultDistributingRewardTokenInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountRewardTokenMint extends string,
@@ -279,6 +294,7 @@ export function getFundManagerRemoveRestakingVaultDistributingRewardTokenInstruc
 >(
   input: FundManagerRemoveRestakingVaultDistributingRewardTokenInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountRewardTokenMint,
@@ -289,6 +305,7 @@ export function getFundManagerRemoveRestakingVaultDistributingRewardTokenInstruc
 ): FundManagerRemoveRestakingVaultDistributingRewardTokenInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountFundAccount,
   TAccountRewardTokenMint,
@@ -301,6 +318,7 @@ export function getFundManagerRemoveRestakingVaultDistributingRewardTokenInstruc
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -321,16 +339,11 @@ export function getFundManagerRemoveRestakingVaultDistributingRewardTokenInstruc
   // Original args.
   const args = { ...input };
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.rewardTokenMint),
@@ -344,6 +357,7 @@ export function getFundManagerRemoveRestakingVaultDistributingRewardTokenInstruc
   } as FundManagerRemoveRestakingVaultDistributingRewardTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountRewardTokenMint,
@@ -359,11 +373,12 @@ export type ParsedFundManagerRemoveRestakingVaultDistributingRewardTokenInstruct
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    fundAccount: TAccountMetas[2];
-    rewardTokenMint: TAccountMetas[3];
-    eventAuthority: TAccountMetas[4];
-    program: TAccountMetas[5];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    fundAccount: TAccountMetas[3];
+    rewardTokenMint: TAccountMetas[4];
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: FundManagerRemoveRestakingVaultDistributingRewardTokenInstructionData;
 };
@@ -379,7 +394,7 @@ export function parseFundManagerRemoveRestakingVaultDistributingRewardTokenInstr
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -393,6 +408,7 @@ export function parseFundManagerRemoveRestakingVaultDistributingRewardTokenInstr
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
       rewardTokenMint: getNextAccount(),
@@ -51,9 +51,8 @@ export function getFundManagerRemoveSupportedTokenDiscriminatorBytes() {
 
 export type FundManagerRemoveSupportedTokenInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountNormalizedTokenMint extends string | AccountMeta<string> = string,
@@ -72,6 +71,9 @@ export type FundManagerRemoveSupportedTokenInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -131,6 +133,7 @@ export function getFundManagerRemoveSupportedTokenInstructionDataCodec(): FixedS
 
 export type FundManagerRemoveSupportedTokenAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountNormalizedTokenMint extends string = string,
@@ -139,7 +142,8 @@ export type FundManagerRemoveSupportedTokenAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
   normalizedTokenMint?: Address<TAccountNormalizedTokenMint>;
@@ -151,6 +155,7 @@ export type FundManagerRemoveSupportedTokenAsyncInput<
 
 export async function getFundManagerRemoveSupportedTokenInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountNormalizedTokenMint extends string,
@@ -162,6 +167,7 @@ export async function getFundManagerRemoveSupportedTokenInstructionAsync<
 >(
   input: FundManagerRemoveSupportedTokenAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountNormalizedTokenMint,
@@ -175,6 +181,7 @@ export async function getFundManagerRemoveSupportedTokenInstructionAsync<
   FundManagerRemoveSupportedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountNormalizedTokenMint,
@@ -190,6 +197,7 @@ export async function getFundManagerRemoveSupportedTokenInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -216,9 +224,11 @@ export async function getFundManagerRemoveSupportedTokenInstructionAsync<
   >;
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundAccount.value) {
     accounts.fundAccount.value = await getProgramDerivedAddress({
@@ -249,6 +259,7 @@ export async function getFundManagerRemoveSupportedTokenInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.normalizedTokenMint),
@@ -262,6 +273,7 @@ export async function getFundManagerRemoveSupportedTokenInstructionAsync<
   } as FundManagerRemoveSupportedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountNormalizedTokenMint,
@@ -274,6 +286,7 @@ export async function getFundManagerRemoveSupportedTokenInstructionAsync<
 
 export type FundManagerRemoveSupportedTokenInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountNormalizedTokenMint extends string = string,
@@ -282,7 +295,8 @@ export type FundManagerRemoveSupportedTokenInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
   normalizedTokenMint?: Address<TAccountNormalizedTokenMint>;
@@ -294,6 +308,7 @@ export type FundManagerRemoveSupportedTokenInput<
 
 export function getFundManagerRemoveSupportedTokenInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountNormalizedTokenMint extends string,
@@ -305,6 +320,7 @@ export function getFundManagerRemoveSupportedTokenInstruction<
 >(
   input: FundManagerRemoveSupportedTokenInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountNormalizedTokenMint,
@@ -317,6 +333,7 @@ export function getFundManagerRemoveSupportedTokenInstruction<
 ): FundManagerRemoveSupportedTokenInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountFundAccount,
   TAccountNormalizedTokenMint,
@@ -331,6 +348,7 @@ export function getFundManagerRemoveSupportedTokenInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -356,16 +374,11 @@ export function getFundManagerRemoveSupportedTokenInstruction<
     ResolvedAccount
   >;
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.normalizedTokenMint),
@@ -379,6 +392,7 @@ export function getFundManagerRemoveSupportedTokenInstruction<
   } as FundManagerRemoveSupportedTokenInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountNormalizedTokenMint,
@@ -396,13 +410,14 @@ export type ParsedFundManagerRemoveSupportedTokenInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    fundAccount: TAccountMetas[2];
-    normalizedTokenMint?: TAccountMetas[3] | undefined;
-    normalizedTokenPoolAccount?: TAccountMetas[4] | undefined;
-    supportedTokenMint: TAccountMetas[5];
-    eventAuthority: TAccountMetas[6];
-    program: TAccountMetas[7];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    fundAccount: TAccountMetas[3];
+    normalizedTokenMint?: TAccountMetas[4] | undefined;
+    normalizedTokenPoolAccount?: TAccountMetas[5] | undefined;
+    supportedTokenMint: TAccountMetas[6];
+    eventAuthority: TAccountMetas[7];
+    program: TAccountMetas[8];
   };
   data: FundManagerRemoveSupportedTokenInstructionData;
 };
@@ -415,7 +430,7 @@ export function parseFundManagerRemoveSupportedTokenInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedFundManagerRemoveSupportedTokenInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -435,6 +450,7 @@ export function parseFundManagerRemoveSupportedTokenInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
       normalizedTokenMint: getNextOptionalAccount(),
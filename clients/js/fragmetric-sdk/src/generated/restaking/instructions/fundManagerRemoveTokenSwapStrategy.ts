@@ -56,9 +56,8 @@ export function getFundManagerRemoveTokenSwapStrategyDiscriminatorBytes() {
 
 export type FundManagerRemoveTokenSwapStrategyInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountFromTokenMint extends string | AccountMeta<string> = string,
@@ -75,6 +74,9 @@ export type FundManagerRemoveTokenSwapStrategyInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -141,6 +143,7 @@ export function getFundManagerRemoveTokenSwapStrategyInstructionDataCodec(): Fix
 
 export type FundManagerRemoveTokenSwapStrategyAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountFromTokenMint extends string = string,
@@ -149,7 +152,8 @@ export type FundManagerRemoveTokenSwapStrategyAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
   fromTokenMint: Address<TAccountFromTokenMint>;
@@ -162,6 +166,7 @@ export type FundManagerRemoveTokenSwapStrategyAsyncInput<
 
 export async function getFundManagerRemoveTokenSwapStrategyInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountFromTokenMint extends string,
@@ -173,6 +178,7 @@ export async function getFundManagerRemoveTokenSwapStrategyInstructionAsync<
 >(
   input: FundManagerRemoveTokenSwapStrategyAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountFromTokenMint,
@@ -186,6 +192,7 @@ export async function getFundManagerRemoveTokenSwapStrategyInstructionAsync<
   FundManagerRemoveTokenSwapStrategyInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountFromTokenMint,
@@ -201,6 +208,7 @@ export async function getFundManagerRemoveTokenSwapStrategyInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -224,9 +232,11 @@ export async function getFundManagerRemoveTokenSwapStrategyInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundAccount.value) {
     accounts.fundAccount.value = await getProgramDerivedAddress({
@@ -257,6 +267,7 @@ export async function getFundManagerRemoveTokenSwapStrategyInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fromTokenMint),
@@ -272,6 +283,7 @@ export async function getFundManagerRemoveTokenSwapStrategyInstructionAsync<
   } as FundManagerRemoveTokenSwapStrategyInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountFromTokenMint,
@@ -284,6 +296,7 @@ export async function getFundManagerRemoveTokenSwapStrategyInstructionAsync<
 
 export type FundManagerRemoveTokenSwapStrategyInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountFromTokenMint extends string = string,
@@ -292,7 +305,8 @@ export type FundManagerRemoveTokenSwapStrategyInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
   fromTokenMint: Address<TAccountFromTokenMint>;
@@ -305,6 +319,7 @@ export type FundManagerRemoveTokenSwapStrategyInput<
 
 export function getFundManagerRemoveTokenSwapStrategyInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountFromTokenMint extends string,
@@ -316,6 +331,7 @@ export function getFundManagerRemoveTokenSwapStrategyInstruction<
 >(
   input: FundManagerRemoveTokenSwapStrategyInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountFromTokenMint,
@@ -328,6 +344,7 @@ export function getFundManagerRemoveTokenSwapStrategyInstruction<
 ): FundManagerRemoveTokenSwapStrategyInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountFundAccount,
   TAccountFromTokenMint,
@@ -342,6 +359,7 @@ export function getFundManagerRemoveTokenSwapStrategyInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -364,16 +382,11 @@ export function getFundManagerRemoveTokenSwapStrategyInstruction<
   // Original args.
   const args = { ...input };
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fromTokenMint),
@@ -389,6 +402,7 @@ export function getFundManagerRemoveTokenSwapStrategyInstruction<
   } as FundManagerRemoveTokenSwapStrategyInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountFromTokenMint,
@@ -406,13 +420,14 @@ export type ParsedFundManagerRemoveTokenSwapStrategyInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    fundAccount: TAccountMetas[2];
-    fromTokenMint: TAccountMetas[3];
-    toTokenMint: TAccountMetas[4];
-    swapSourceAccount: TAccountMetas[5];
-    eventAuthority: TAccountMetas[6];
-    program: TAccountMetas[7];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    fundAccount: TAccountMetas[3];
+    fromTokenMint: TAccountMetas[4];
+    toTokenMint: TAccountMetas[5];
+    swapSourceAccount: TAccountMetas[6];
+    eventAuthority: TAccountMetas[7];
+    program: TAccountMetas[8];
   };
   data: FundManagerRemoveTokenSwapStrategyInstructionData;
 };
@@ -428,7 +443,7 @@ export function parseFundManagerRemoveTokenSwapStrategyInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -442,6 +457,7 @@ export function parseFundManagerRemoveTokenSwapStrategyInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
       fromTokenMint: getNextAccount(),
@@ -50,9 +50,8 @@ export function getFundManagerRemoveWrappedTokenHolderDiscriminatorBytes() {
 
 export type FundManagerRemoveWrappedTokenHolderInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountWrappedTokenMint extends string | AccountMeta<string> = string,
   TAccountFundAccount extends string | AccountMeta<string> = string,
@@ -76,6 +75,9 @@ export type FundManagerRemoveWrappedTokenHolderInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -144,6 +146,7 @@ export function getFundManagerRemoveWrappedTokenHolderInstructionDataCodec(): Fi
 
 export type FundManagerRemoveWrappedTokenHolderAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountWrappedTokenMint extends string = string,
   TAccountFundAccount extends string = string,
@@ -155,7 +158,8 @@ export type FundManagerRemoveWrappedTokenHolderAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   wrappedTokenMint: Address<TAccountWrappedTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
@@ -170,6 +174,7 @@ export type FundManagerRemoveWrappedTokenHolderAsyncInput<
 
 export async function getFundManagerRemoveWrappedTokenHolderInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountWrappedTokenMint extends string,
   TAccountFundAccount extends string,
@@ -184,6 +189,7 @@ export async function getFundManagerRemoveWrappedTokenHolderInstructionAsync<
 >(
   input: FundManagerRemoveWrappedTokenHolderAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountWrappedTokenMint,
     TAccountFundAccount,
@@ -200,6 +206,7 @@ export async function getFundManagerRemoveWrappedTokenHolderInstructionAsync<
   FundManagerRemoveWrappedTokenHolderInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountWrappedTokenMint,
     TAccountFundAccount,
@@ -218,6 +225,7 @@ export async function getFundManagerRemoveWrappedTokenHolderInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -253,9 +261,11 @@ export async function getFundManagerRemoveWrappedTokenHolderInstructionAsync<
   >;
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundAccount.value) {
     accounts.fundAccount.value = await getProgramDerivedAddress({
@@ -348,6 +358,7 @@ export async function getFundManagerRemoveWrappedTokenHolderInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.wrappedTokenMint),
       getAccountMeta(accounts.fundAccount),
@@ -366,6 +377,7 @@ export async function getFundManagerRemoveWrappedTokenHolderInstructionAsync<
   } as FundManagerRemoveWrappedTokenHolderInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountWrappedTokenMint,
     TAccountFundAccount,
@@ -381,6 +393,7 @@ export async function getFundManagerRemoveWrappedTokenHolderInstructionAsync<
 
 export type FundManagerRemoveWrappedTokenHolderInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountWrappedTokenMint extends string = string,
   TAccountFundAccount extends string = string,
@@ -392,7 +405,8 @@ export type FundManagerRemoveWrappedTokenHolderInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   wrappedTokenMint: Address<TAccountWrappedTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
@@ -407,6 +421,7 @@ export type FundManagerRemoveWrappedTokenHolderInput<
 
 export function getFundManagerRemoveWrappedTokenHolderInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountWrappedTokenMint extends string,
   TAccountFundAccount extends string,
@@ -421,6 +436,7 @@ export function getFundManagerRemoveWrappedTokenHolderInstruction<
 >(
   input: FundManagerRemoveWrappedTokenHolderInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountWrappedTokenMint,
     TAccountFundAccount,
@@ -436,6 +452,7 @@ export function getFundManagerRemoveWrappedTokenHolderInstruction<
 ): FundManagerRemoveWrappedTokenHolderInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountWrappedTokenMint,
   TAccountFundAccount,
@@ -453,6 +470,7 @@ export function getFundManagerRemoveWrappedTokenHolderInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -487,16 +505,11 @@ export function getFundManagerRemoveWrappedTokenHolderInstruction<
     ResolvedAccount
   >;
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.wrappedTokenMint),
       getAccountMeta(accounts.fundAccount),
@@ -515,6 +528,7 @@ export function getFundManagerRemoveWrappedTokenHolderInstruction<
   } as FundManagerRemoveWrappedTokenHolderInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountWrappedTokenMint,
     TAccountFundAccount,
@@ -535,16 +549,17 @@ export type ParsedFundManagerRemoveWrappedTokenHolderInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    wrappedTokenMint: TAccountMetas[2];
-    fundAccount: TAccountMetas[3];
-    fundWrapAccount: TAccountMetas[4];
-    wrappedTokenHolder: TAccountMetas[5];
-    rewardAccount: TAccountMetas[6];
-    fundWrapAccountRewardAccount: TAccountMetas[7];
-    wrappedTokenHolderRewardAccount: TAccountMetas[8];
-    eventAuthority: TAccountMetas[9];
-    program: TAccountMetas[10];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    wrappedTokenMint: TAccountMetas[3];
+    fundAccount: TAccountMetas[4];
+    fundWrapAccount: TAccountMetas[5];
+    wrappedTokenHolder: TAccountMetas[6];
+    rewardAccount: TAccountMetas[7];
+    fundWrapAccountRewardAccount: TAccountMetas[8];
+    wrappedTokenHolderRewardAccount: TAccountMetas[9];
+    eventAuthority: TAccountMetas[10];
+    program: TAccountMetas[11];
   };
   data: FundManagerRemoveWrappedTokenHolderInstructionData;
 };
@@ -560,7 +575,7 @@ export function parseFundManagerRemoveWrappedTokenHolderInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -574,6 +589,7 @@ export function parseFundManagerRemoveWrappedTokenHolderInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       wrappedTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
@@ -50,9 +50,8 @@ export function getFundManagerResetFundWrapAccountRewardAccountDelegateDiscrimin
 
 export type FundManagerResetFundWrapAccountRewardAccountDelegateInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountFundWrapAccount extends string | AccountMeta<string> = string,
   TAccountFundWrapAccountRewardAccount extends
@@ -67,6 +66,9 @@ export type FundManagerResetFundWrapAccountRewardAccountDelegateInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -115,11 +117,13 @@ export function getFundManagerResetFundWrapAccountRewardAccountDelegateInstructi
 
 export type FundManagerResetFundWrapAccountRewardAccountDelegateAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundWrapAccount extends string = string,
   TAccountFundWrapAccountRewardAccount extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundWrapAccount?: Address<TAccountFundWrapAccount>;
   fundWrapAccountRewardAccount?: Address<TAccountFundWrapAccountRewardAccount>;
@@ -127,6 +131,7 @@ export type FundManagerResetFundWrapAccountRewardAccountDelegateAsyncInput<
 
 export async function getFundManagerResetFundWrapAccountRewardAccountDelegateInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundWrapAccount extends string,
   TAccountFundWrapAccountRewardAccount extends string,
@@ -134,6 +139,7 @@ export async function getFundManagerResetFundWrapAccountRewardAccountDelegateIns
 >(
   input: FundManagerResetFundWrapAccountRewardAccountDelegateAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundWrapAccount,
     TAccountFundWrapAccountRewardAccount
@@ -143,6 +149,7 @@ export async function getFundManagerResetFundWrapAccountRewardAccountDelegateIns
   FundManagerResetFundWrapAccountRewardAccountDelegateInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundWrapAccount,
     TAccountFundWrapAccountRewardAccount
@@ -154,6 +161,7 @@ export async function getFundManagerResetFundWrapAccountRewardAccountDelegateIns
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -173,9 +181,11 @@ export async function getFundManagerResetFundWrapAccountRewardAccountDelegateIns
   >;
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundWrapAccount.value) {
     accounts.fundWrapAccount.value = await getProgramDerivedAddress({
@@ -214,6 +224,7 @@ export async function getFundManagerResetFundWrapAccountRewardAccountDelegateIns
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundWrapAccount),
       getAccountMeta(accounts.fundWrapAccountRewardAccount),
@@ -225,6 +236,7 @@ export async function getFundManagerResetFundWrapAccountRewardAccountDelegateIns
   } as FundManagerResetFundWrapAccountRewardAccountDelegateInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundWrapAccount,
     TAccountFundWrapAccountRewardAccount
@@ -233,11 +245,13 @@ export async function getFundManagerResetFundWrapAccountRewardAccountDelegateIns
 
 export type FundManagerResetFundWrapAccountRewardAccountDelegateInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundWrapAccount extends string = string,
   TAccountFundWrapAccountRewardAccount extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundWrapAccount: Address<TAccountFundWrapAccount>;
   fundWrapAccountRewardAccount: Address<TAccountFundWrapAccountRewardAccount>;
@@ -245,6 +259,7 @@ export type FundManagerResetFundWrapAccountRewardAccountDelegateInput<
 
 export function getFundManagerResetFundWrapAccountRewardAccountDelegateInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundWrapAccount extends string,
   TAccountFundWrapAccountRewardAccount extends string,
@@ -252,6 +267,7 @@ export function getFundManagerResetFundWrapAccountRewardAccountDelegateInstructi
 >(
   input: FundManagerResetFundWrapAccountRewardAccountDelegateInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundWrapAccount,
     TAccountFundWrapAccountRewardAccount
@@ -260,6 +276,7 @@ export function getFundManagerResetFundWrapAccountRewardAccountDelegateInstructi
 ): FundManagerResetFundWrapAccountRewardAccountDelegateInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountFundWrapAccount,
   TAccountFundWrapAccountRewardAccount
@@ -270,6 +287,7 @@ export function getFundManagerResetFundWrapAccountRewardAccountDelegateInstructi
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -288,16 +306,11 @@ export function getFundManagerResetFundWrapAccountRewardAccountDelegateInstructi
     ResolvedAccount
   >;
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundWrapAccount),
       getAccountMeta(accounts.fundWrapAccountRewardAccount),
@@ -309,6 +322,7 @@ export function getFundManagerResetFundWrapAccountRewardAccountDelegateInstructi
   } as FundManagerResetFundWrapAccountRewardAccountDelegateInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundWrapAccount,
     TAccountFundWrapAccountRewardAccount
@@ -322,9 +336,10 @@ export type ParsedFundManagerResetFundWrapAccountRewardAccountDelegateInstructio
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    fundWrapAccount: TAccountMetas[2];
-    fundWrapAccountRewardAccount: TAccountMetas[3];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    fundWrapAccount: TAccountMetas[3];
+    fundWrapAccountRewardAccount: TAccountMetas[4];
   };
   data: FundManagerResetFundWrapAccountRewardAccountDelegateInstructionData;
 };
@@ -340,7 +355,7 @@ export function parseFundManagerResetFundWrapAccountRewardAccountDelegateInstruc
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -354,6 +369,7 @@ export function parseFundManagerResetFundWrapAccountRewardAccountDelegateInstruc
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       fundWrapAccount: getNextAccount(),
       fundWrapAccountRewardAccount: getNextAccount(),
@@ -50,9 +50,8 @@ export function getFundManagerResetWrappedTokenHolderRewardAccountDelegateDiscri
 
 export type FundManagerResetWrappedTokenHolderRewardAccountDelegateInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountWrappedTokenMint extends string | AccountMeta<string> = string,
   TAccountFundAccount extends string | AccountMeta<string> = string,
@@ -76,6 +75,9 @@ export type FundManagerResetWrappedTokenHolderRewardAccountDelegateInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -145,6 +147,7 @@ export function getFundManagerResetWrappedTokenHolderRewardAccountDelegateInstru
 
 export type FundManagerResetWrappedTokenHolderRewardAccountDelegateAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountWrappedTokenMint extends string = string,
   TAccountFundAccount extends string = string,
@@ -156,7 +159,8 @@ export type FundManagerResetWrappedTokenHolderRewardAccountDelegateAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   wrappedTokenMint: Address<TAccountWrappedTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
@@ -171,6 +175,7 @@ export type FundManagerResetWrappedTokenHolderRewardAccountDelegateAsyncInput<
 
 export async function getFundManagerResetWrappedTokenHolderRewardAccountDelegateInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountWrappedTokenMint extends string,
   TAccountFundAccount extends string,
@@ -185,6 +190,7 @@ export async function getFundManagerResetWrappedTokenHolderRewardAccountDelegate
 >(
   input: FundManagerResetWrappedTokenHolderRewardAccountDelegateAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountWrappedTokenMint,
     TAccountFundAccount,
@@ -201,6 +207,7 @@ export async function getFundManagerResetWrappedTokenHolderRewardAccountDelegate
   FundManagerResetWrappedTokenHolderRewardAccountDelegateInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountWrappedTokenMint,
     TAccountFundAccount,
@@ -219,6 +226,7 @@ export async function getFundManagerResetWrappedTokenHolderRewardAccountDelegate
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -254,9 +262,11 @@ export async function getFundManagerResetWrappedTokenHolderRewardAccountDelegate
   >;
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundAccount.value) {
     accounts.fundAccount.value = await getProgramDerivedAddress({
@@ -349,6 +359,7 @@ export async function getFundManagerResetWrappedTokenHolderRewardAccountDelegate
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.wrappedTokenMint),
       getAccountMeta(accounts.fundAccount),
@@ -367,6 +378,7 @@ export async function getFundManagerResetWrappedTokenHolderRewardAccountDelegate
   } as FundManagerResetWrappedTokenHolderRewardAccountDelegateInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountWrappedTokenMint,
     TAccountFundAccount,
@@ -382,6 +394,7 @@ export async function getFundManagerResetWrappedTokenHolderRewardAccountDelegate
 
 export type FundManagerResetWrappedTokenHolderRewardAccountDelegateInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountWrappedTokenMint extends string = string,
   TAccountFundAccount extends string = string,
@@ -393,7 +406,8 @@ export type FundManagerResetWrappedTokenHolderRewardAccountDelegateInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   wrappedTokenMint: Address<TAccountWrappedTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
@@ -408,6 +422,7 @@ export type FundManagerResetWrappedTokenHolderRewardAccountDelegateInput<
 
 export function getFundManagerResetWrappedTokenHolderRewardAccountDelegateInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountWrappedTokenMint extends string,
   TAccountFundAccount extends string,
@@ -422,6 +437,7 @@ export function getFundManagerResetWrappedTokenHolderRewardAccountDelegateInstru
 >(
   input: FundManagerResetWrappedTokenHolderRewardAccountDelegateInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountWrappedTokenMint,
     TAccountFundAccount,
@@ -437,6 +453,7 @@ export function getFundManagerResetWrappedTokenHolderRewardAccountDelegateInstru
 ): FundManagerResetWrappedTokenHolderRewardAccountDelegateInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountWrappedTokenMint,
   TAccountFundAccount,
@@ -454,6 +471,7 @@ export function getFundManagerResetWrappedTokenHolderRewardAccountDelegateInstru
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -488,16 +506,11 @@ export function getFundManagerResetWrappedTokenHolderRewardAccountDelegateInstru
     ResolvedAccount
   >;
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.wrappedTokenMint),
       getAccountMeta(accounts.fundAccount),
@@ -516,6 +529,7 @@ export function getFundManagerResetWrappedTokenHolderRewardAccountDelegateInstru
   } as FundManagerResetWrappedTokenHolderRewardAccountDelegateInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountWrappedTokenMint,
     TAccountFundAccount,
@@ -536,16 +550,17 @@ export type ParsedFundManagerResetWrappedTokenHolderRewardAccountDelegateInstruc
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    wrappedTokenMint: TAccountMetas[2];
-    fundAccount: TAccountMetas[3];
-    fundWrapAccount: TAccountMetas[4];
-    wrappedTokenHolder: TAccountMetas[5];
-    rewardAccount: TAccountMetas[6];
-    fundWrapAccountRewardAccount: TAccountMetas[7];
-    wrappedTokenHolderRewardAccount: TAccountMetas[8];
-    eventAuthority: TAccountMetas[9];
-    program: TAccountMetas[10];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    wrappedTokenMint: TAccountMetas[3];
+    fundAccount: TAccountMetas[4];
+    fundWrapAccount: TAccountMetas[5];
+    wrappedTokenHolder: TAccountMetas[6];
+    rewardAccount: TAccountMetas[7];
+    fundWrapAccountRewardAccount: TAccountMetas[8];
+    wrappedTokenHolderRewardAccount: TAccountMetas[9];
+    eventAuthority: TAccountMetas[10];
+    program: TAccountMetas[11];
   };
   data: FundManagerResetWrappedTokenHolderRewardAccountDelegateInstructionData;
 };
@@ -561,7 +576,7 @@ export function parseFundManagerResetWrappedTokenHolderRewardAccountDelegateInst
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -575,6 +590,7 @@ export function parseFundManagerResetWrappedTokenHolderRewardAccountDelegateInst
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       wrappedTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
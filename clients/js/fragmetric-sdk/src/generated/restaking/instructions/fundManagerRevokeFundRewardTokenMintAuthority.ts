@@ -50,9 +50,8 @@ export function getFundManagerRevokeFundRewardTokenMintAuthorityDiscriminatorByt
 
 export type FundManagerRevokeFundRewardTokenMintAuthorityInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountRewardTokenMint extends string | AccountMeta<string> = string,
@@ -66,6 +65,9 @@ export type FundManagerRevokeFundRewardTokenMintAuthorityInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -118,12 +120,14 @@ export function getFundManagerRevokeFundRewardTokenMintAuthorityInstructionDataC
 
 export type FundManagerRevokeFundRewardTokenMintAuthorityAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountRewardTokenProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
   rewardTokenMint: Address<TAccountRewardTokenMint>;
@@ -132,6 +136,7 @@ export type FundManagerRevokeFundRewardTokenMintAuthorityAsyncInput<
 
 export async function getFundManagerRevokeFundRewardTokenMintAuthorityInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountRewardTokenMint extends string,
@@ -140,6 +145,7 @@ export async function getFundManagerRevokeFundRewardTokenMintAuthorityInstructio
 >(
   input: FundManagerRevokeFundRewardTokenMintAuthorityAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountRewardTokenMint,
@@ -150,6 +156,7 @@ export async function getFundManagerRevokeFundRewardTokenMintAuthorityInstructio
   FundManagerRevokeFundRewardTokenMintAuthorityInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountRewardTokenMint,
@@ -162,6 +169,7 @@ export async function getFundManagerRevokeFundRewardTokenMintAuthorityInstructio
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -179,9 +187,11 @@ export async function getFundManagerRevokeFundRewardTokenMintAuthorityInstructio
   >;
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundAccount.value) {
     accounts.fundAccount.value = await getProgramDerivedAddress({
@@ -199,6 +209,7 @@ export async function getFundManagerRevokeFundRewardTokenMintAuthorityInstructio
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.rewardTokenMint),
@@ -211,6 +222,7 @@ export async function getFundManagerRevokeFundRewardTokenMintAuthorityInstructio
   } as FundManagerRevokeFundRewardTokenMintAuthorityInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountRewardTokenMint,
@@ -220,12 +232,14 @@ export async function getFundManagerRevokeFundRewardTokenMintAuthorityInstructio
 
 export type FundManagerRevokeFundRewardTokenMintAuthorityInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountRewardTokenProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
   rewardTokenMint: Address<TAccountRewardTokenMint>;
@@ -234,6 +248,7 @@ export type FundManagerRevokeFundRewardTokenMintAuthorityInput<
 
 export function getFundManagerRevokeFundRewardTokenMintAuthorityInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountRewardTokenMint extends string,
@@ -242,6 +257,7 @@ export function getFundManagerRevokeFundRewardTokenMintAuthorityInstruction<
 >(
   input: FundManagerRevokeFundRewardTokenMintAuthorityInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountRewardTokenMint,
@@ -251,6 +267,7 @@ export function getFundManagerRevokeFundRewardTokenMintAuthorityInstruction<
 ): FundManagerRevokeFundRewardTokenMintAuthorityInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountFundAccount,
   TAccountRewardTokenMint,
@@ -262,6 +279,7 @@ export function getFundManagerRevokeFundRewardTokenMintAuthorityInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -278,16 +296,11 @@ export function getFundManagerRevokeFundRewardTokenMintAuthorityInstruction<
     ResolvedAccount
   >;
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.rewardTokenMint),
@@ -300,6 +313,7 @@ export function getFundManagerRevokeFundRewardTokenMintAuthorityInstruction<
   } as FundManagerRevokeFundRewardTokenMintAuthorityInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountRewardTokenMint,
@@ -314,10 +328,11 @@ export type ParsedFundManagerRevokeFundRewardTokenMintAuthorityInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    fundAccount: TAccountMetas[2];
-    rewardTokenMint: TAccountMetas[3];
-    rewardTokenProgram: TAccountMetas[4];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    fundAccount: TAccountMetas[3];
+    rewardTokenMint: TAccountMetas[4];
+    rewardTokenProgram: TAccountMetas[5];
   };
   data: FundManagerRevokeFundRewardTokenMintAuthorityInstructionData;
 };
@@ -333,7 +348,7 @@ export function parseFundManagerRevokeFundRewardTokenMintAuthorityInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -347,6 +362,7 @@ export function parseFundManagerRevokeFundRewardTokenMintAuthorityInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
       rewardTokenMint: getNextAccount(),
@@ -56,9 +56,8 @@ export function getFundManagerSettleRewardDiscriminatorBytes() {
 
 export type FundManagerSettleRewardInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountRewardAccount extends string | AccountMeta<string> = string,
   TAccountRewardReserveAccount extends string | AccountMeta<string> = string,
@@ -78,6 +77,9 @@ export type FundManagerSettleRewardInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -155,6 +157,7 @@ export function getFundManagerSettleRewardInstructionDataCodec(): FixedSizeCodec
 
 export type FundManagerSettleRewardAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountRewardReserveAccount extends string = string,
@@ -164,7 +167,8 @@ export type FundManagerSettleRewardAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   rewardAccount?: Address<TAccountRewardAccount>;
   rewardReserveAccount?: Address<TAccountRewardReserveAccount>;
@@ -180,6 +184,7 @@ export type FundManagerSettleRewardAsyncInput<
 
 export async function getFundManagerSettleRewardInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountRewardAccount extends string,
   TAccountRewardReserveAccount extends string,
@@ -192,6 +197,7 @@ export async function getFundManagerSettleRewardInstructionAsync<
 >(
   input: FundManagerSettleRewardAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -206,6 +212,7 @@ export async function getFundManagerSettleRewardInstructionAsync<
   FundManagerSettleRewardInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -222,6 +229,7 @@ export async function getFundManagerSettleRewardInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -255,9 +263,11 @@ export async function getFundManagerSettleRewardInstructionAsync<
   const args = { ...input, rewardTokenMint: input.rewardTokenMintArg };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.rewardAccount.value) {
     accounts.rewardAccount.value = await getProgramDerivedAddress({
@@ -303,6 +313,7 @@ export async function getFundManagerSettleRewardInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.rewardReserveAccount),
@@ -319,6 +330,7 @@ export async function getFundManagerSettleRewardInstructionAsync<
   } as FundManagerSettleRewardInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -332,6 +344,7 @@ export async function getFundManagerSettleRewardInstructionAsync<
 
 export type FundManagerSettleRewardInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountRewardReserveAccount extends string = string,
@@ -341,7 +354,8 @@ export type FundManagerSettleRewardInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   rewardAccount: Address<TAccountRewardAccount>;
   rewardReserveAccount: Address<TAccountRewardReserveAccount>;
@@ -357,6 +371,7 @@ export type FundManagerSettleRewardInput<
 
 export function getFundManagerSettleRewardInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountRewardAccount extends string,
   TAccountRewardReserveAccount extends string,
@@ -369,6 +384,7 @@ export function getFundManagerSettleRewardInstruction<
 >(
   input: FundManagerSettleRewardInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -382,6 +398,7 @@ export function getFundManagerSettleRewardInstruction<
 ): FundManagerSettleRewardInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountRewardAccount,
   TAccountRewardReserveAccount,
@@ -397,6 +414,7 @@ export function getFundManagerSettleRewardInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -429,16 +447,11 @@ export function getFundManagerSettleRewardInstruction<
   // Original args.
   const args = { ...input, rewardTokenMint: input.rewardTokenMintArg };
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.rewardReserveAccount),
@@ -455,6 +468,7 @@ export function getFundManagerSettleRewardInstruction<
   } as FundManagerSettleRewardInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -473,14 +487,15 @@ export type ParsedFundManagerSettleRewardInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    rewardAccount: TAccountMetas[2];
-    rewardReserveAccount: TAccountMetas[3];
-    rewardTokenMint?: TAccountMetas[4] | undefined;
-    rewardTokenProgram?: TAccountMetas[5] | undefined;
-    rewardTokenReserveAccount?: TAccountMetas[6] | undefined;
-    eventAuthority: TAccountMetas[7];
-    program: TAccountMetas[8];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    rewardAccount: TAccountMetas[3];
+    rewardReserveAccount: TAccountMetas[4];
+    rewardTokenMint?: TAccountMetas[5] | undefined;
+    rewardTokenProgram?: TAccountMetas[6] | undefined;
+    rewardTokenReserveAccount?: TAccountMetas[7] | undefined;
+    eventAuthority: TAccountMetas[8];
+    program: TAccountMetas[9];
   };
   data: FundManagerSettleRewardInstructionData;
 };
@@ -493,7 +508,7 @@ export function parseFundManagerSettleRewardInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedFundManagerSettleRewardInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -513,6 +528,7 @@ export function parseFundManagerSettleRewardInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       rewardAccount: getNextAccount(),
       rewardReserveAccount: getNextAccount(),
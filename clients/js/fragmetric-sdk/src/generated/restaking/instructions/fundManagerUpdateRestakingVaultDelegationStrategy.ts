@@ -53,9 +53,8 @@ export function getFundManagerUpdateRestakingVaultDelegationStrategyDiscriminato
 
 export type FundManagerUpdateRestakingVaultDelegationStrategyInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountEventAuthority extends string | AccountMeta<string> = string,
@@ -69,6 +68,9 @@ export type FundManagerUpdateRestakingVaultDelegationStrategyInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -140,12 +142,14 @@ export function getFundManagerUpdateRestakingVaultDelegationStrategyInstructionD
 
 export type FundManagerUpdateRestakingVaultDelegationStrategyAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
   eventAuthority?: Address<TAccountEventAuthority>;
@@ -158,6 +162,7 @@ export type FundManagerUpdateRestakingVaultDelegationStrategyAsyncInput<
 
 export async function getFundManagerUpdateRestakingVaultDelegationStrategyInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountEventAuthority extends string,
@@ -166,6 +171,7 @@ export async function getFundManagerUpdateRestakingVaultDelegationStrategyInstru
 >(
   input: FundManagerUpdateRestakingVaultDelegationStrategyAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -176,6 +182,7 @@ export async function getFundManagerUpdateRestakingVaultDelegationStrategyInstru
   FundManagerUpdateRestakingVaultDelegationStrategyInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -188,6 +195,7 @@ export async function getFundManagerUpdateRestakingVaultDelegationStrategyInstru
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -205,9 +213,11 @@ export async function getFundManagerUpdateRestakingVaultDelegationStrategyInstru
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundAccount.value) {
     accounts.fundAccount.value = await getProgramDerivedAddress({
@@ -238,6 +248,7 @@ export async function getFundManagerUpdateRestakingVaultDelegationStrategyInstru
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.eventAuthority),
@@ -250,6 +261,7 @@ export async function getFundManagerUpdateRestakingVaultDelegationStrategyInstru
   } as FundManagerUpdateRestakingVaultDelegationStrategyInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -259,12 +271,14 @@ export async function getFundManagerUpdateRestakingVaultDelegationStrategyInstru
 
 export type FundManagerUpdateRestakingVaultDelegationStrategyInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
   eventAuthority: Address<TAccountEventAuthority>;
@@ -277,6 +291,7 @@ export type FundManagerUpdateRestakingVaultDelegationStrategyInput<
 
 export function getFundManagerUpdateRestakingVaultDelegationStrategyInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountEventAuthority extends string,
@@ -285,6 +300,7 @@ export function getFundManagerUpdateRestakingVaultDelegationStrategyInstruction<
 >(
   input: FundManagerUpdateRestakingVaultDelegationStrategyInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -294,6 +310,7 @@ export function getFundManagerUpdateRestakingVaultDelegationStrategyInstruction<
 ): FundManagerUpdateRestakingVaultDelegationStrategyInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountFundAccount,
   TAccountEventAuthority,
@@ -305,6 +322,7 @@ export function getFundManagerUpdateRestakingVaultDelegationStrategyInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -321,16 +339,11 @@ export function getFundManagerUpdateRestakingVaultDelegationStrategyInstruction<
   // Original args.
   const args = { ...input };
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.eventAuthority),
@@ -343,6 +356,7 @@ export function getFundManagerUpdateRestakingVaultDelegationStrategyInstruction<
   } as FundManagerUpdateRestakingVaultDelegationStrategyInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -357,10 +371,11 @@ export type ParsedFundManagerUpdateRestakingVaultDelegationStrategyInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    fundAccount: TAccountMetas[2];
-    eventAuthority: TAccountMetas[3];
-    program: TAccountMetas[4];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    fundAccount: TAccountMetas[3];
+    eventAuthority: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: FundManagerUpdateRestakingVaultDelegationStrategyInstructionData;
 };
@@ -376,7 +391,7 @@ export function parseFundManagerUpdateRestakingVaultDelegationStrategyInstructio
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -390,6 +405,7 @@ export function parseFundManagerUpdateRestakingVaultDelegationStrategyInstructio
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
       eventAuthority: getNextAccount(),
@@ -55,9 +55,8 @@ export function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdDis
 
 export type FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountEventAuthority extends string | AccountMeta<string> = string,
@@ -71,6 +70,9 @@ export type FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInstructio
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -147,12 +149,14 @@ export function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdIns
 
 export type FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
   eventAuthority?: Address<TAccountEventAuthority>;
@@ -166,6 +170,7 @@ export type FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdAsyncInput
 
 export async function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountEventAuthority extends string,
@@ -174,6 +179,7 @@ export async function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresh
 >(
   input: FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -184,6 +190,7 @@ export async function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresh
   FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -196,6 +203,7 @@ export async function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresh
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -213,9 +221,11 @@ export async function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresh
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundAccount.value) {
     accounts.fundAccount.value = await getProgramDerivedAddress({
@@ -246,6 +256,7 @@ export async function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresh
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.eventAuthority),
@@ -258,6 +269,7 @@ export async function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresh
   } as FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -267,12 +279,14 @@ export async function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresh
 
 export type FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
   eventAuthority: Address<TAccountEventAuthority>;
@@ -286,6 +300,7 @@ export type FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInput<
 
 export function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountEventAuthority extends string,
@@ -294,6 +309,7 @@ export function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdIns
 >(
   input: FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -303,6 +319,7 @@ export function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdIns
 ): FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountFundAccount,
   TAccountEventAuthority,
@@ -314,6 +331,7 @@ export function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdIns
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -330,16 +348,11 @@ export function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdIns
   // Original args.
   const args = { ...input };
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.eventAuthority),
@@ -352,6 +365,7 @@ export function getFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdIns
   } as FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -366,10 +380,11 @@ export type ParsedFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInst
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    fundAccount: TAccountMetas[2];
-    eventAuthority: TAccountMetas[3];
-    program: TAccountMetas[4];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    fundAccount: TAccountMetas[3];
+    eventAuthority: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: FundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInstructionData;
 };
@@ -385,7 +400,7 @@ export function parseFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdI
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -399,6 +414,7 @@ export function parseFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdI
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
       eventAuthority: getNextAccount(),
@@ -57,9 +57,8 @@ export function getFundManagerUpdateRestakingVaultStrategyDiscriminatorBytes() {
 
 export type FundManagerUpdateRestakingVaultStrategyInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountEventAuthority extends string | AccountMeta<string> = string,
@@ -73,6 +72,9 @@ export type FundManagerUpdateRestakingVaultStrategyInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -146,12 +148,14 @@ export function getFundManagerUpdateRestakingVaultStrategyInstructionDataCodec()
 
 export type FundManagerUpdateRestakingVaultStrategyAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
   eventAuthority?: Address<TAccountEventAuthority>;
@@ -165,6 +169,7 @@ export type FundManagerUpdateRestakingVaultStrategyAsyncInput<
 
 export async function getFundManagerUpdateRestakingVaultStrategyInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountEventAuthority extends string,
@@ -173,6 +178,7 @@ export async function getFundManagerUpdateRestakingVaultStrategyInstructionAsync
 >(
   input: FundManagerUpdateRestakingVaultStrategyAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -183,6 +189,7 @@ export async function getFundManagerUpdateRestakingVaultStrategyInstructionAsync
   FundManagerUpdateRestakingVaultStrategyInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -195,6 +202,7 @@ export async function getFundManagerUpdateRestakingVaultStrategyInstructionAsync
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -212,9 +220,11 @@ export async function getFundManagerUpdateRestakingVaultStrategyInstructionAsync
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.fundAccount.value) {
     accounts.fundAccount.value = await getProgramDerivedAddress({
@@ -245,6 +255,7 @@ export async function getFundManagerUpdateRestakingVaultStrategyInstructionAsync
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.eventAuthority),
@@ -257,6 +268,7 @@ export async function getFundManagerUpdateRestakingVaultStrategyInstructionAsync
   } as FundManagerUpdateRestakingVaultStrategyInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -266,12 +278,14 @@ export async function getFundManagerUpdateRestakingVaultStrategyInstructionAsync
 
 export type FundManagerUpdateRestakingVaultStrategyInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
   eventAuthority: Address<TAccountEventAuthority>;
@@ -285,6 +299,7 @@ export type FundManagerUpdateRestakingVaultStrategyInput<
 
 export function getFundManagerUpdateRestakingVaultStrategyInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
   TAccountEventAuthority extends string,
@@ -293,6 +308,7 @@ export function getFundManagerUpdateRestakingVaultStrategyInstruction<
 >(
   input: FundManagerUpdateRestakingVaultStrategyInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -302,6 +318,7 @@ export function getFundManagerUpdateRestakingVaultStrategyInstruction<
 ): FundManagerUpdateRestakingVaultStrategyInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountFundAccount,
   TAccountEventAuthority,
@@ -313,6 +330,7 @@ export function getFundManagerUpdateRestakingVaultStrategyInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -329,16 +347,11 @@ export function getFundManagerUpdateRestakingVaultStrategyInstruction<
   // Original args.
   const args = { ...input };
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.eventAuthority),
@@ -351,6 +364,7 @@ export function getFundManagerUpdateRestakingVaultStrategyInstruction<
   } as FundManagerUpdateRestakingVaultStrategyInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
     TAccountEventAuthority,
@@ -365,10 +379,11 @@ export type ParsedFundManagerUpdateRestakingVaultStrategyInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    fundAccount: TAccountMetas[2];
-    eventAuthority: TAccountMetas[3];
-    program: TAccountMetas[4];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    fundAccount: TAccountMetas[3];
+    eventAuthority: TAccountMetas[4];
+    program: TAccountMetas[5];
   };
   data: FundManagerUpdateRestakingVaultStrategyInstructionData;
 };
@@ -384,7 +399,7 @@ export function parseFundManagerUpdateRestakingVaultStrategyInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -398,6 +413,7 @@ export function parseFundManagerUpdateRestakingVaultStrategyInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
       eventAuthority: getNextAccount(),
@@ -60,9 +60,8 @@ export function getFundManagerUpdateRewardDiscriminatorBytes() {
 
 export type FundManagerUpdateRewardInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
-  TAccountFundManager extends
-    | string
-    | AccountMeta<string> = '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx',
+  TAccountFundManager extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
   TAccountRewardAccount extends string | AccountMeta<string> = string,
   TAccountRewardReserveAccount extends string | AccountMeta<string> = string,
@@ -82,6 +81,9 @@ export type FundManagerUpdateRewardInstruction<
         ? ReadonlySignerAccount<TAccountFundManager> &
             AccountSignerMeta<TAccountFundManager>
         : TAccountFundManager,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
@@ -167,6 +169,7 @@ export function getFundManagerUpdateRewardInstructionDataCodec(): Codec<
 
 export type FundManagerUpdateRewardAsyncInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountRewardReserveAccount extends string = string,
@@ -176,7 +179,8 @@ export type FundManagerUpdateRewardAsyncInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   rewardAccount?: Address<TAccountRewardAccount>;
   rewardReserveAccount?: Address<TAccountRewardReserveAccount>;
@@ -194,6 +198,7 @@ export type FundManagerUpdateRewardAsyncInput<
 
 export async function getFundManagerUpdateRewardInstructionAsync<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountRewardAccount extends string,
   TAccountRewardReserveAccount extends string,
@@ -206,6 +211,7 @@ export async function getFundManagerUpdateRewardInstructionAsync<
 >(
   input: FundManagerUpdateRewardAsyncInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -220,6 +226,7 @@ export async function getFundManagerUpdateRewardInstructionAsync<
   FundManagerUpdateRewardInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -236,6 +243,7 @@ export async function getFundManagerUpdateRewardInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -269,9 +277,11 @@ export async function getFundManagerUpdateRewardInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
   }
   if (!accounts.rewardAccount.value) {
     accounts.rewardAccount.value = await getProgramDerivedAddress({
@@ -317,6 +327,7 @@ export async function getFundManagerUpdateRewardInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.rewardReserveAccount),
@@ -333,6 +344,7 @@ export async function getFundManagerUpdateRewardInstructionAsync<
   } as FundManagerUpdateRewardInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -346,6 +358,7 @@ export async function getFundManagerUpdateRewardInstructionAsync<
 
 export type FundManagerUpdateRewardInput<
   TAccountFundManager extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountRewardReserveAccount extends string = string,
@@ -355,7 +368,8 @@ export type FundManagerUpdateRewardInput<
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
-  fundManager?: TransactionSigner<TAccountFundManager>;
+  fundManager: TransactionSigner<TAccountFundManager>;
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   rewardAccount: Address<TAccountRewardAccount>;
   rewardReserveAccount: Address<TAccountRewardReserveAccount>;
@@ -373,6 +387,7 @@ export type FundManagerUpdateRewardInput<
 
 export function getFundManagerUpdateRewardInstruction<
   TAccountFundManager extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenMint extends string,
   TAccountRewardAccount extends string,
   TAccountRewardReserveAccount extends string,
@@ -385,6 +400,7 @@ export function getFundManagerUpdateRewardInstruction<
 >(
   input: FundManagerUpdateRewardInput<
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -398,6 +414,7 @@ export function getFundManagerUpdateRewardInstruction<
 ): FundManagerUpdateRewardInstruction<
   TProgramAddress,
   TAccountFundManager,
+  TAccountRoleAccount,
   TAccountReceiptTokenMint,
   TAccountRewardAccount,
   TAccountRewardReserveAccount,
@@ -413,6 +430,7 @@ export function getFundManagerUpdateRewardInstruction<
   // Original accounts.
   const originalAccounts = {
     fundManager: { value: input.fundManager ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
       isWritable: false,
@@ -445,16 +463,11 @@ export function getFundManagerUpdateRewardInstruction<
   // Original args.
   const args = { ...input };
 
-  // Resolve default values.
-  if (!accounts.fundManager.value) {
-    accounts.fundManager.value =
-      '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx' as Address<'5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.fundManager),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.rewardReserveAccount),
@@ -471,6 +484,7 @@ export function getFundManagerUpdateRewardInstruction<
   } as FundManagerUpdateRewardInstruction<
     TProgramAddress,
     TAccountFundManager,
+    TAccountRoleAccount,
     TAccountReceiptTokenMint,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
@@ -489,14 +503,15 @@ export type ParsedFundManagerUpdateRewardInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     fundManager: TAccountMetas[0];
-    receiptTokenMint: TAccountMetas[1];
-    rewardAccount: TAccountMetas[2];
-    rewardReserveAccount: TAccountMetas[3];
-    rewardTokenMint?: TAccountMetas[4] | undefined;
-    rewardTokenProgram?: TAccountMetas[5] | undefined;
-    rewardTokenReserveAccount?: TAccountMetas[6] | undefined;
-    eventAuthority: TAccountMetas[7];
-    program: TAccountMetas[8];
+    roleAccount: TAccountMetas[1];
+    receiptTokenMint: TAccountMetas[2];
+    rewardAccount: TAccountMetas[3];
+    rewardReserveAccount: TAccountMetas[4];
+    rewardTokenMint?: TAccountMetas[5] | undefined;
+    rewardTokenProgram?: TAccountMetas[6] | undefined;
+    rewardTokenReserveAccount?: TAccountMetas[7] | undefined;
+    eventAuthority: TAccountMetas[8];
+    program: TAccountMetas[9];
   };
   data: FundManagerUpdateRewardInstructionData;
 };
@@ -509,7 +524,7 @@ export function parseFundManagerUpdateRewardInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedFundManagerUpdateRewardInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -529,6 +544,7 @@ export function parseFundManagerUpdateRewardInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       fundManager: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       rewardAccount: getNextAccount(),
       rewardReserveAccount: getNextAccount(),
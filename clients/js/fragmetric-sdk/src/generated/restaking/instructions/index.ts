@@ -6,28 +6,36 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './adminCloseFundOperationHistoryAccount';
 export * from './adminCreateUserRewardAccountIdempotent';
 export * from './adminInitializeExtraAccountMetaList';
 export * from './adminInitializeFundAccount';
 export * from './adminInitializeNormalizedTokenPoolAccount';
 export * from './adminInitializeRewardAccount';
+export * from './adminInitializeRoleAccount';
+export * from './adminProposeRoleTransfer';
 export * from './adminSetAddressLookupTableAccount';
 export * from './adminUpdateExtraAccountMetaListIfNeeded';
 export * from './adminUpdateFundAccountIfNeeded';
 export * from './adminUpdateNormalizedTokenPoolAccountIfNeeded';
 export * from './adminUpdateRewardAccountIfNeeded';
+export * from './fundManagerAcknowledgePriceDeviation';
 export * from './fundManagerAddNormalizedTokenPoolSupportedToken';
+export * from './fundManagerAddOperator';
 export * from './fundManagerAddRestakingVaultCompoundingRewardToken';
 export * from './fundManagerAddRestakingVaultDistributingRewardToken';
 export * from './fundManagerAddReward';
 export * from './fundManagerAddSupportedToken';
 export * from './fundManagerAddTokenSwapStrategy';
 export * from './fundManagerAddWrappedTokenHolder';
+export * from './fundManagerExecuteFundConfigurationChange';
 export * from './fundManagerInitializeFundNormalizedToken';
 export * from './fundManagerInitializeFundRestakingVault';
 export * from './fundManagerInitializeFundRestakingVaultDelegation';
 export * from './fundManagerInitializeFundWrappedToken';
+export * from './fundManagerQueueFundConfigurationChange';
 export * from './fundManagerRemoveNormalizedTokenPoolSupportedToken';
+export * from './fundManagerRemoveOperator';
 export * from './fundManagerRemoveRestakingVaultCompoundingRewardToken';
 export * from './fundManagerRemoveRestakingVaultDistributingRewardToken';
 export * from './fundManagerRemoveSupportedToken';
@@ -36,22 +44,43 @@ export * from './fundManagerRemoveWrappedTokenHolder';
 export * from './fundManagerResetFundWrapAccountRewardAccountDelegate';
 export * from './fundManagerResetWrappedTokenHolderRewardAccountDelegate';
 export * from './fundManagerRevokeFundRewardTokenMintAuthority';
+export * from './fundManagerSetOperatorAllowlistEnabled';
 export * from './fundManagerSettleReward';
 export * from './fundManagerUpdateFundStrategy';
+export * from './fundManagerUpdateInKindWithdrawalStrategy';
+export * from './fundManagerUpdateInstantWithdrawalStrategy';
+export * from './fundManagerUpdateManagementFeeRate';
+export * from './fundManagerUpdateMaxPriceAgeSlots';
+export * from './fundManagerUpdateOperationCommandPolicy';
+export * from './fundManagerUpdateOperationFallbackCommand';
+export * from './fundManagerUpdatePriceDeviationThreshold';
+export * from './fundManagerUpdateReconciliationThreshold';
 export * from './fundManagerUpdateRestakingVaultDelegationStrategy';
 export * from './fundManagerUpdateRestakingVaultRewardTokenHarvestThreshold';
 export * from './fundManagerUpdateRestakingVaultStrategy';
+export * from './fundManagerUpdateRevenueSplit';
 export * from './fundManagerUpdateReward';
 export * from './fundManagerUpdateSolStrategy';
+export * from './fundManagerUpdateSupportedTokenPriceDeviationThreshold';
 export * from './fundManagerUpdateSupportedTokenStrategy';
+export * from './fundManagerUpdateTokenSwapStrategy';
+export * from './guardianCancelFundConfigurationChange';
 export * from './operatorClaimRemainingReward';
 export * from './operatorDonateSolToFund';
 export * from './operatorDonateSupportedTokenToFund';
+export * from './operatorInitializeFundOperationHistoryAccount';
 export * from './operatorLogMessage';
+export * from './operatorPlanFundCommands';
+export * from './operatorReconcileFund';
 export * from './operatorRunFundCommand';
 export * from './operatorUpdateFundPrices';
 export * from './operatorUpdateNormalizedTokenPoolPrices';
 export * from './operatorUpdateRewardPools';
+export * from './quoteDeposit';
+export * from './quoteReceiptTokenTwap';
+export * from './quoteWithdrawal';
+export * from './quoteWrap';
+export * from './roleAuthorityAcceptRoleTransfer';
 export * from './slasherInitializeNormalizedTokenWithdrawalAccount';
 export * from './slasherWithdrawNormalizedToken';
 export * from './tokenTransferHook';
@@ -65,10 +94,15 @@ export * from './userDelegateRewardAccount';
 export * from './userDepositSol';
 export * from './userDepositSupportedToken';
 export * from './userDepositVaultReceiptToken';
+export * from './userMigrateWithdrawalRequests';
 export * from './userRequestWithdrawal';
+export * from './userTransferWithdrawalRequest';
 export * from './userUnwrapReceiptToken';
 export * from './userUpdateRewardPools';
+export * from './userWithdrawInKind';
+export * from './userWithdrawInstantly';
 export * from './userWithdrawSol';
 export * from './userWithdrawSupportedToken';
+export * from './userWithdrawSupportedTokenInstantly';
 export * from './userWrapReceiptToken';
 export * from './userWrapReceiptTokenIfNeeded';
@@ -53,19 +53,14 @@ export type OperatorClaimRemainingRewardInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountOperator extends string | AccountMeta<string> = string,
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
+  TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountRewardAccount extends string | AccountMeta<string> = string,
   TAccountRewardReserveAccount extends string | AccountMeta<string> = string,
-  TAccountProgramRevenueAccount extends
-    | string
-    | AccountMeta<string> = 'GuSruSKKCmAGuWMeMsiw3mbNhjeiRtNhnh9Eatgz33NA',
   TAccountRewardTokenMint extends string | AccountMeta<string> = string,
   TAccountRewardTokenProgram extends string | AccountMeta<string> = string,
   TAccountRewardTokenReserveAccount extends
     | string
     | AccountMeta<string> = string,
-  TAccountProgramRewardTokenRevenueAccount extends
-    | string
-    | AccountMeta<string> = string,
   TAccountEventAuthority extends string | AccountMeta<string> = string,
   TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
@@ -80,15 +75,15 @@ export type OperatorClaimRemainingRewardInstruction<
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
+      TAccountFundAccount extends string
+        ? ReadonlyAccount<TAccountFundAccount>
+        : TAccountFundAccount,
       TAccountRewardAccount extends string
         ? WritableAccount<TAccountRewardAccount>
         : TAccountRewardAccount,
       TAccountRewardReserveAccount extends string
         ? ReadonlyAccount<TAccountRewardReserveAccount>
         : TAccountRewardReserveAccount,
-      TAccountProgramRevenueAccount extends string
-        ? ReadonlyAccount<TAccountProgramRevenueAccount>
-        : TAccountProgramRevenueAccount,
       TAccountRewardTokenMint extends string
         ? ReadonlyAccount<TAccountRewardTokenMint>
         : TAccountRewardTokenMint,
@@ -98,9 +93,6 @@ export type OperatorClaimRemainingRewardInstruction<
       TAccountRewardTokenReserveAccount extends string
         ? WritableAccount<TAccountRewardTokenReserveAccount>
         : TAccountRewardTokenReserveAccount,
-      TAccountProgramRewardTokenRevenueAccount extends string
-        ? WritableAccount<TAccountProgramRewardTokenRevenueAccount>
-        : TAccountProgramRewardTokenRevenueAccount,
       TAccountEventAuthority extends string
         ? ReadonlyAccount<TAccountEventAuthority>
         : TAccountEventAuthority,
@@ -146,25 +138,23 @@ export function getOperatorClaimRemainingRewardInstructionDataCodec(): FixedSize
 export type OperatorClaimRemainingRewardAsyncInput<
   TAccountOperator extends string = string,
   TAccountReceiptTokenMint extends string = string,
+  TAccountFundAccount extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountRewardReserveAccount extends string = string,
-  TAccountProgramRevenueAccount extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountRewardTokenProgram extends string = string,
   TAccountRewardTokenReserveAccount extends string = string,
-  TAccountProgramRewardTokenRevenueAccount extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
   operator: TransactionSigner<TAccountOperator>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
+  fundAccount?: Address<TAccountFundAccount>;
   rewardAccount?: Address<TAccountRewardAccount>;
   rewardReserveAccount?: Address<TAccountRewardReserveAccount>;
-  programRevenueAccount?: Address<TAccountProgramRevenueAccount>;
   rewardTokenMint: Address<TAccountRewardTokenMint>;
   rewardTokenProgram: Address<TAccountRewardTokenProgram>;
   rewardTokenReserveAccount?: Address<TAccountRewardTokenReserveAccount>;
-  programRewardTokenRevenueAccount?: Address<TAccountProgramRewardTokenRevenueAccount>;
   eventAuthority?: Address<TAccountEventAuthority>;
   program: Address<TAccountProgram>;
 };
@@ -172,13 +162,12 @@ export type OperatorClaimRemainingRewardAsyncInput<
 export async function getOperatorClaimRemainingRewardInstructionAsync<
   TAccountOperator extends string,
   TAccountReceiptTokenMint extends string,
+  TAccountFundAccount extends string,
   TAccountRewardAccount extends string,
   TAccountRewardReserveAccount extends string,
-  TAccountProgramRevenueAccount extends string,
   TAccountRewardTokenMint extends string,
   TAccountRewardTokenProgram extends string,
   TAccountRewardTokenReserveAccount extends string,
-  TAccountProgramRewardTokenRevenueAccount extends string,
   TAccountEventAuthority extends string,
   TAccountProgram extends string,
   TProgramAddress extends Address = typeof RESTAKING_PROGRAM_ADDRESS,
@@ -186,13 +175,12 @@ export async function getOperatorClaimRemainingRewardInstructionAsync<
   input: OperatorClaimRemainingRewardAsyncInput<
     TAccountOperator,
     TAccountReceiptTokenMint,
+    TAccountFundAccount,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
-    TAccountProgramRevenueAccount,
     TAccountRewardTokenMint,
     TAccountRewardTokenProgram,
     TAccountRewardTokenReserveAccount,
-    TAccountProgramRewardTokenRevenueAccount,
     TAccountEventAuthority,
     TAccountProgram
   >,
@@ -202,13 +190,12 @@ export async function getOperatorClaimRemainingRewardInstructionAsync<
     TProgramAddress,
     TAccountOperator,
     TAccountReceiptTokenMint,
+    TAccountFundAccount,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
-    TAccountProgramRevenueAccount,
     TAccountRewardTokenMint,
     TAccountRewardTokenProgram,
     TAccountRewardTokenReserveAccount,
-    TAccountProgramRewardTokenRevenueAccount,
     TAccountEventAuthority,
     TAccountProgram
   >
@@ -223,15 +210,12 @@ export async function getOperatorClaimRemainingRewardInstructionAsync<
       value: input.receiptTokenMint ?? null,
       isWritable: false,
     },
+    fundAccount: { value: input.fundAccount ?? null, isWritable: false },
     rewardAccount: { value: input.rewardAccount ?? null, isWritable: true },
     rewardReserveAccount: {
       value: input.rewardReserveAccount ?? null,
       isWritable: false,
     },
-    programRevenueAccount: {
-      value: input.programRevenueAccount ?? null,
-      isWritable: false,
-    },
     rewardTokenMint: {
       value: input.rewardTokenMint ?? null,
       isWritable: false,
@@ -244,10 +228,6 @@ export async function getOperatorClaimRemainingRewardInstructionAsync<
       value: input.rewardTokenReserveAccount ?? null,
       isWritable: true,
     },
-    programRewardTokenRevenueAccount: {
-      value: input.programRewardTokenRevenueAccount ?? null,
-      isWritable: true,
-    },
     eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
   };
@@ -257,6 +237,17 @@ export async function getOperatorClaimRemainingRewardInstructionAsync<
   >;
 
   // Resolve default values.
+  if (!accounts.fundAccount.value) {
+    accounts.fundAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([102, 117, 110, 100])),
+        getAddressEncoder().encode(
+          expectAddress(accounts.receiptTokenMint.value)
+        ),
+      ],
+    });
+  }
   if (!accounts.rewardAccount.value) {
     accounts.rewardAccount.value = await getProgramDerivedAddress({
       programAddress,
@@ -283,10 +274,6 @@ export async function getOperatorClaimRemainingRewardInstructionAsync<
       ],
     });
   }
-  if (!accounts.programRevenueAccount.value) {
-    accounts.programRevenueAccount.value =
-      'GuSruSKKCmAGuWMeMsiw3mbNhjeiRtNhnh9Eatgz33NA' as Address<'GuSruSKKCmAGuWMeMsiw3mbNhjeiRtNhnh9Eatgz33NA'>;
-  }
   if (!accounts.rewardTokenReserveAccount.value) {
     accounts.rewardTokenReserveAccount.value = await getProgramDerivedAddress({
       programAddress:
@@ -304,24 +291,6 @@ export async function getOperatorClaimRemainingRewardInstructionAsync<
       ],
     });
   }
-  if (!accounts.programRewardTokenRevenueAccount.value) {
-    accounts.programRewardTokenRevenueAccount.value =
-      await getProgramDerivedAddress({
-        programAddress:
-          'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
-        seeds: [
-          getAddressEncoder().encode(
-            expectAddress(accounts.programRevenueAccount.value)
-          ),
-          getAddressEncoder().encode(
-            expectAddress(accounts.rewardTokenProgram.value)
-          ),
-          getAddressEncoder().encode(
-            expectAddress(accounts.rewardTokenMint.value)
-          ),
-        ],
-      });
-  }
   if (!accounts.eventAuthority.value) {
     accounts.eventAuthority.value = await getProgramDerivedAddress({
       programAddress,
@@ -341,13 +310,12 @@ export async function getOperatorClaimRemainingRewardInstructionAsync<
     accounts: [
       getAccountMeta(accounts.operator),
       getAccountMeta(accounts.receiptTokenMint),
+      getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.rewardReserveAccount),
-      getAccountMeta(accounts.programRevenueAccount),
       getAccountMeta(accounts.rewardTokenMint),
       getAccountMeta(accounts.rewardTokenProgram),
       getAccountMeta(accounts.rewardTokenReserveAccount),
-      getAccountMeta(accounts.programRewardTokenRevenueAccount),
       getAccountMeta(accounts.eventAuthority),
       getAccountMeta(accounts.program),
     ],
@@ -357,13 +325,12 @@ export async function getOperatorClaimRemainingRewardInstructionAsync<
     TProgramAddress,
     TAccountOperator,
     TAccountReceiptTokenMint,
+    TAccountFundAccount,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
-    TAccountProgramRevenueAccount,
     TAccountRewardTokenMint,
     TAccountRewardTokenProgram,
     TAccountRewardTokenReserveAccount,
-    TAccountProgramRewardTokenRevenueAccount,
     TAccountEventAuthority,
     TAccountProgram
   >);
@@ -372,25 +339,23 @@ export async function getOperatorClaimRemainingRewardInstructionAsync<
 export type OperatorClaimRemainingRewardInput<
   TAccountOperator extends string = string,
   TAccountReceiptTokenMint extends string = string,
+  TAccountFundAccount extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountRewardReserveAccount extends string = string,
-  TAccountProgramRevenueAccount extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountRewardTokenProgram extends string = string,
   TAccountRewardTokenReserveAccount extends string = string,
-  TAccountProgramRewardTokenRevenueAccount extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
   operator: TransactionSigner<TAccountOperator>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
+  fundAccount: Address<TAccountFundAccount>;
   rewardAccount: Address<TAccountRewardAccount>;
   rewardReserveAccount: Address<TAccountRewardReserveAccount>;
-  programRevenueAccount?: Address<TAccountProgramRevenueAccount>;
   rewardTokenMint: Address<TAccountRewardTokenMint>;
   rewardTokenProgram: Address<TAccountRewardTokenProgram>;
   rewardTokenReserveAccount: Address<TAccountRewardTokenReserveAccount>;
-  programRewardTokenRevenueAccount: Address<TAccountProgramRewardTokenRevenueAccount>;
   eventAuthority: Address<TAccountEventAuthority>;
   program: Address<TAccountProgram>;
 };
@@ -398,13 +363,12 @@ export type OperatorClaimRemainingRewardInput<
 export function getOperatorClaimRemainingRewardInstruction<
   TAccountOperator extends string,
   TAccountReceiptTokenMint extends string,
+  TAccountFundAccount extends string,
   TAccountRewardAccount extends string,
   TAccountRewardReserveAccount extends string,
-  TAccountProgramRevenueAccount extends string,
   TAccountRewardTokenMint extends string,
   TAccountRewardTokenProgram extends string,
   TAccountRewardTokenReserveAccount extends string,
-  TAccountProgramRewardTokenRevenueAccount extends string,
   TAccountEventAuthority extends string,
   TAccountProgram extends string,
   TProgramAddress extends Address = typeof RESTAKING_PROGRAM_ADDRESS,
@@ -412,13 +376,12 @@ export function getOperatorClaimRemainingRewardInstruction<
   input: OperatorClaimRemainingRewardInput<
     TAccountOperator,
     TAccountReceiptTokenMint,
+    TAccountFundAccount,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
-    TAccountProgramRevenueAccount,
     TAccountRewardTokenMint,
     TAccountRewardTokenProgram,
     TAccountRewardTokenReserveAccount,
-    TAccountProgramRewardTokenRevenueAccount,
     TAccountEventAuthority,
     TAccountProgram
   >,
@@ -427,13 +390,12 @@ export function getOperatorClaimRemainingRewardInstruction<
   TProgramAddress,
   TAccountOperator,
   TAccountReceiptTokenMint,
+  TAccountFundAccount,
   TAccountRewardAccount,
   TAccountRewardReserveAccount,
-  TAccountProgramRevenueAccount,
   TAccountRewardTokenMint,
   TAccountRewardTokenProgram,
   TAccountRewardTokenReserveAccount,
-  TAccountProgramRewardTokenRevenueAccount,
   TAccountEventAuthority,
   TAccountProgram
 > {
@@ -447,15 +409,12 @@ export function getOperatorClaimRemainingRewardInstruction<
       value: input.receiptTokenMint ?? null,
       isWritable: false,
     },
+    fundAccount: { value: input.fundAccount ?? null, isWritable: false },
     rewardAccount: { value: input.rewardAccount ?? null, isWritable: true },
     rewardReserveAccount: {
       value: input.rewardReserveAccount ?? null,
       isWritable: false,
     },
-    programRevenueAccount: {
-      value: input.programRevenueAccount ?? null,
-      isWritable: false,
-    },
     rewardTokenMint: {
       value: input.rewardTokenMint ?? null,
       isWritable: false,
@@ -468,10 +427,6 @@ export function getOperatorClaimRemainingRewardInstruction<
       value: input.rewardTokenReserveAccount ?? null,
       isWritable: true,
     },
-    programRewardTokenRevenueAccount: {
-      value: input.programRewardTokenRevenueAccount ?? null,
-      isWritable: true,
-    },
     eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
   };
@@ -480,24 +435,17 @@ export function getOperatorClaimRemainingRewardInstruction<
     ResolvedAccount
   >;
 
-  // Resolve default values.
-  if (!accounts.programRevenueAccount.value) {
-    accounts.programRevenueAccount.value =
-      'GuSruSKKCmAGuWMeMsiw3mbNhjeiRtNhnh9Eatgz33NA' as Address<'GuSruSKKCmAGuWMeMsiw3mbNhjeiRtNhnh9Eatgz33NA'>;
-  }
-
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.operator),
       getAccountMeta(accounts.receiptTokenMint),
+      getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.rewardReserveAccount),
-      getAccountMeta(accounts.programRevenueAccount),
       getAccountMeta(accounts.rewardTokenMint),
       getAccountMeta(accounts.rewardTokenProgram),
       getAccountMeta(accounts.rewardTokenReserveAccount),
-      getAccountMeta(accounts.programRewardTokenRevenueAccount),
       getAccountMeta(accounts.eventAuthority),
       getAccountMeta(accounts.program),
     ],
@@ -507,13 +455,12 @@ export function getOperatorClaimRemainingRewardInstruction<
     TProgramAddress,
     TAccountOperator,
     TAccountReceiptTokenMint,
+    TAccountFundAccount,
     TAccountRewardAccount,
     TAccountRewardReserveAccount,
-    TAccountProgramRevenueAccount,
     TAccountRewardTokenMint,
     TAccountRewardTokenProgram,
     TAccountRewardTokenReserveAccount,
-    TAccountProgramRewardTokenRevenueAccount,
     TAccountEventAuthority,
     TAccountProgram
   >);
@@ -527,15 +474,14 @@ export type ParsedOperatorClaimRemainingRewardInstruction<
   accounts: {
     operator: TAccountMetas[0];
     receiptTokenMint: TAccountMetas[1];
-    rewardAccount: TAccountMetas[2];
-    rewardReserveAccount: TAccountMetas[3];
-    programRevenueAccount: TAccountMetas[4];
+    fundAccount: TAccountMetas[2];
+    rewardAccount: TAccountMetas[3];
+    rewardReserveAccount: TAccountMetas[4];
     rewardTokenMint: TAccountMetas[5];
     rewardTokenProgram: TAccountMetas[6];
     rewardTokenReserveAccount: TAccountMetas[7];
-    programRewardTokenRevenueAccount: TAccountMetas[8];
-    eventAuthority: TAccountMetas[9];
-    program: TAccountMetas[10];
+    eventAuthority: TAccountMetas[8];
+    program: TAccountMetas[9];
   };
   data: OperatorClaimRemainingRewardInstructionData;
 };
@@ -548,7 +494,7 @@ export function parseOperatorClaimRemainingRewardInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedOperatorClaimRemainingRewardInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -563,13 +509,12 @@ export function parseOperatorClaimRemainingRewardInstruction<
     accounts: {
       operator: getNextAccount(),
       receiptTokenMint: getNextAccount(),
+      fundAccount: getNextAccount(),
       rewardAccount: getNextAccount(),
       rewardReserveAccount: getNextAccount(),
-      programRevenueAccount: getNextAccount(),
       rewardTokenMint: getNextAccount(),
       rewardTokenProgram: getNextAccount(),
       rewardTokenReserveAccount: getNextAccount(),
-      programRewardTokenRevenueAccount: getNextAccount(),
       eventAuthority: getNextAccount(),
       program: getNextAccount(),
     },
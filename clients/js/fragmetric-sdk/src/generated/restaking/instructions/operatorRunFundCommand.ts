@@ -18,6 +18,8 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -62,6 +64,7 @@ export function getOperatorRunFundCommandDiscriminatorBytes() {
 export type OperatorRunFundCommandInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountOperator extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -78,6 +81,9 @@ export type OperatorRunFundCommandInstruction<
         ? WritableSignerAccount<TAccountOperator> &
             AccountSignerMeta<TAccountOperator>
         : TAccountOperator,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -100,10 +106,12 @@ export type OperatorRunFundCommandInstruction<
 export type OperatorRunFundCommandInstructionData = {
   discriminator: ReadonlyUint8Array;
   forceResetCommand: Option<OperationCommandEntry>;
+  maxSteps: Option<number>;
 };
 
 export type OperatorRunFundCommandInstructionDataArgs = {
   forceResetCommand: OptionOrNullable<OperationCommandEntryArgs>;
+  maxSteps: OptionOrNullable<number>;
 };
 
 export function getOperatorRunFundCommandInstructionDataEncoder(): Encoder<OperatorRunFundCommandInstructionDataArgs> {
@@ -114,6 +122,7 @@ export function getOperatorRunFundCommandInstructionDataEncoder(): Encoder<Opera
         'forceResetCommand',
         getOptionEncoder(getOperationCommandEntryEncoder()),
       ],
+      ['maxSteps', getOptionEncoder(getU8Encoder())],
     ]),
     (value) => ({
       ...value,
@@ -126,6 +135,7 @@ export function getOperatorRunFundCommandInstructionDataDecoder(): Decoder<Opera
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['forceResetCommand', getOptionDecoder(getOperationCommandEntryDecoder())],
+    ['maxSteps', getOptionDecoder(getU8Decoder())],
   ]);
 }
 
@@ -141,6 +151,7 @@ export function getOperatorRunFundCommandInstructionDataCodec(): Codec<
 
 export type OperatorRunFundCommandAsyncInput<
   TAccountOperator extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
@@ -148,16 +159,19 @@ export type OperatorRunFundCommandAsyncInput<
   TAccountProgram extends string = string,
 > = {
   operator: TransactionSigner<TAccountOperator>;
+  roleAccount?: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
   eventAuthority?: Address<TAccountEventAuthority>;
   program: Address<TAccountProgram>;
   forceResetCommand: OperatorRunFundCommandInstructionDataArgs['forceResetCommand'];
+  maxSteps: OperatorRunFundCommandInstructionDataArgs['maxSteps'];
 };
 
 export async function getOperatorRunFundCommandInstructionAsync<
   TAccountOperator extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
@@ -167,6 +181,7 @@ export async function getOperatorRunFundCommandInstructionAsync<
 >(
   input: OperatorRunFundCommandAsyncInput<
     TAccountOperator,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -178,6 +193,7 @@ export async function getOperatorRunFundCommandInstructionAsync<
   OperatorRunFundCommandInstruction<
     TProgramAddress,
     TAccountOperator,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -191,6 +207,7 @@ export async function getOperatorRunFundCommandInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     operator: { value: input.operator ?? null, isWritable: true },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -209,6 +226,12 @@ export async function getOperatorRunFundCommandInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -242,6 +265,7 @@ export async function getOperatorRunFundCommandInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
@@ -255,6 +279,7 @@ export async function getOperatorRunFundCommandInstructionAsync<
   } as OperatorRunFundCommandInstruction<
     TProgramAddress,
     TAccountOperator,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -265,6 +290,7 @@ export async function getOperatorRunFundCommandInstructionAsync<
 
 export type OperatorRunFundCommandInput<
   TAccountOperator extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
@@ -272,16 +298,19 @@ export type OperatorRunFundCommandInput<
   TAccountProgram extends string = string,
 > = {
   operator: TransactionSigner<TAccountOperator>;
+  roleAccount: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
   eventAuthority: Address<TAccountEventAuthority>;
   program: Address<TAccountProgram>;
   forceResetCommand: OperatorRunFundCommandInstructionDataArgs['forceResetCommand'];
+  maxSteps: OperatorRunFundCommandInstructionDataArgs['maxSteps'];
 };
 
 export function getOperatorRunFundCommandInstruction<
   TAccountOperator extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
@@ -291,6 +320,7 @@ export function getOperatorRunFundCommandInstruction<
 >(
   input: OperatorRunFundCommandInput<
     TAccountOperator,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -301,6 +331,7 @@ export function getOperatorRunFundCommandInstruction<
 ): OperatorRunFundCommandInstruction<
   TProgramAddress,
   TAccountOperator,
+  TAccountRoleAccount,
   TAccountSystemProgram,
   TAccountReceiptTokenMint,
   TAccountFundAccount,
@@ -313,6 +344,7 @@ export function getOperatorRunFundCommandInstruction<
   // Original accounts.
   const originalAccounts = {
     operator: { value: input.operator ?? null, isWritable: true },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -340,6 +372,7 @@ export function getOperatorRunFundCommandInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
@@ -353,6 +386,7 @@ export function getOperatorRunFundCommandInstruction<
   } as OperatorRunFundCommandInstruction<
     TProgramAddress,
     TAccountOperator,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -368,11 +402,12 @@ export type ParsedOperatorRunFundCommandInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     operator: TAccountMetas[0];
-    systemProgram: TAccountMetas[1];
-    receiptTokenMint: TAccountMetas[2];
-    fundAccount: TAccountMetas[3];
-    eventAuthority: TAccountMetas[4];
-    program: TAccountMetas[5];
+    roleAccount: TAccountMetas[1];
+    systemProgram: TAccountMetas[2];
+    receiptTokenMint: TAccountMetas[3];
+    fundAccount: TAccountMetas[4];
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: OperatorRunFundCommandInstructionData;
 };
@@ -385,7 +420,7 @@ export function parseOperatorRunFundCommandInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedOperatorRunFundCommandInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -399,6 +434,7 @@ export function parseOperatorRunFundCommandInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       operator: getNextAccount(),
+      roleAccount: getNextAccount(),
       systemProgram: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
@@ -52,6 +52,7 @@ export function getOperatorUpdateFundPricesDiscriminatorBytes() {
 export type OperatorUpdateFundPricesInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountOperator extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -68,6 +69,9 @@ export type OperatorUpdateFundPricesInstruction<
         ? WritableSignerAccount<TAccountOperator> &
             AccountSignerMeta<TAccountOperator>
         : TAccountOperator,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -121,6 +125,7 @@ export function getOperatorUpdateFundPricesInstructionDataCodec(): FixedSizeCode
 
 export type OperatorUpdateFundPricesAsyncInput<
   TAccountOperator extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
@@ -128,6 +133,7 @@ export type OperatorUpdateFundPricesAsyncInput<
   TAccountProgram extends string = string,
 > = {
   operator: TransactionSigner<TAccountOperator>;
+  roleAccount?: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount?: Address<TAccountFundAccount>;
@@ -137,6 +143,7 @@ export type OperatorUpdateFundPricesAsyncInput<
 
 export async function getOperatorUpdateFundPricesInstructionAsync<
   TAccountOperator extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
@@ -146,6 +153,7 @@ export async function getOperatorUpdateFundPricesInstructionAsync<
 >(
   input: OperatorUpdateFundPricesAsyncInput<
     TAccountOperator,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -157,6 +165,7 @@ export async function getOperatorUpdateFundPricesInstructionAsync<
   OperatorUpdateFundPricesInstruction<
     TProgramAddress,
     TAccountOperator,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -170,6 +179,7 @@ export async function getOperatorUpdateFundPricesInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     operator: { value: input.operator ?? null, isWritable: true },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -185,6 +195,12 @@ export async function getOperatorUpdateFundPricesInstructionAsync<
   >;
 
   // Resolve default values.
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -218,6 +234,7 @@ export async function getOperatorUpdateFundPricesInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
@@ -229,6 +246,7 @@ export async function getOperatorUpdateFundPricesInstructionAsync<
   } as OperatorUpdateFundPricesInstruction<
     TProgramAddress,
     TAccountOperator,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -239,6 +257,7 @@ export async function getOperatorUpdateFundPricesInstructionAsync<
 
 export type OperatorUpdateFundPricesInput<
   TAccountOperator extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
   TAccountFundAccount extends string = string,
@@ -246,6 +265,7 @@ export type OperatorUpdateFundPricesInput<
   TAccountProgram extends string = string,
 > = {
   operator: TransactionSigner<TAccountOperator>;
+  roleAccount: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
   fundAccount: Address<TAccountFundAccount>;
@@ -255,6 +275,7 @@ export type OperatorUpdateFundPricesInput<
 
 export function getOperatorUpdateFundPricesInstruction<
   TAccountOperator extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
   TAccountFundAccount extends string,
@@ -264,6 +285,7 @@ export function getOperatorUpdateFundPricesInstruction<
 >(
   input: OperatorUpdateFundPricesInput<
     TAccountOperator,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -274,6 +296,7 @@ export function getOperatorUpdateFundPricesInstruction<
 ): OperatorUpdateFundPricesInstruction<
   TProgramAddress,
   TAccountOperator,
+  TAccountRoleAccount,
   TAccountSystemProgram,
   TAccountReceiptTokenMint,
   TAccountFundAccount,
@@ -286,6 +309,7 @@ export function getOperatorUpdateFundPricesInstruction<
   // Original accounts.
   const originalAccounts = {
     operator: { value: input.operator ?? null, isWritable: true },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenMint: {
       value: input.receiptTokenMint ?? null,
@@ -310,6 +334,7 @@ export function getOperatorUpdateFundPricesInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
       getAccountMeta(accounts.fundAccount),
@@ -321,6 +346,7 @@ export function getOperatorUpdateFundPricesInstruction<
   } as OperatorUpdateFundPricesInstruction<
     TProgramAddress,
     TAccountOperator,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
     TAccountFundAccount,
@@ -336,11 +362,12 @@ export type ParsedOperatorUpdateFundPricesInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     operator: TAccountMetas[0];
-    systemProgram: TAccountMetas[1];
-    receiptTokenMint: TAccountMetas[2];
-    fundAccount: TAccountMetas[3];
-    eventAuthority: TAccountMetas[4];
-    program: TAccountMetas[5];
+    roleAccount: TAccountMetas[1];
+    systemProgram: TAccountMetas[2];
+    receiptTokenMint: TAccountMetas[3];
+    fundAccount: TAccountMetas[4];
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: OperatorUpdateFundPricesInstructionData;
 };
@@ -353,7 +380,7 @@ export function parseOperatorUpdateFundPricesInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedOperatorUpdateFundPricesInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -367,6 +394,7 @@ export function parseOperatorUpdateFundPricesInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       operator: getNextAccount(),
+      roleAccount: getNextAccount(),
       systemProgram: getNextAccount(),
       receiptTokenMint: getNextAccount(),
       fundAccount: getNextAccount(),
@@ -56,6 +56,7 @@ export type OperatorUpdateRewardPoolsInstruction<
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
   TAccountReceiptTokenMint extends string | AccountMeta<string> = string,
+  TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountRewardAccount extends string | AccountMeta<string> = string,
   TAccountEventAuthority extends string | AccountMeta<string> = string,
   TAccountProgram extends string | AccountMeta<string> = string,
@@ -74,6 +75,9 @@ export type OperatorUpdateRewardPoolsInstruction<
       TAccountReceiptTokenMint extends string
         ? ReadonlyAccount<TAccountReceiptTokenMint>
         : TAccountReceiptTokenMint,
+      TAccountFundAccount extends string
+        ? ReadonlyAccount<TAccountFundAccount>
+        : TAccountFundAccount,
       TAccountRewardAccount extends string
         ? WritableAccount<TAccountRewardAccount>
         : TAccountRewardAccount,
@@ -123,6 +127,7 @@ export type OperatorUpdateRewardPoolsAsyncInput<
   TAccountOperator extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
+  TAccountFundAccount extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
@@ -130,6 +135,7 @@ export type OperatorUpdateRewardPoolsAsyncInput<
   operator: TransactionSigner<TAccountOperator>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
+  fundAccount?: Address<TAccountFundAccount>;
   rewardAccount?: Address<TAccountRewardAccount>;
   eventAuthority?: Address<TAccountEventAuthority>;
   program: Address<TAccountProgram>;
@@ -139,6 +145,7 @@ export async function getOperatorUpdateRewardPoolsInstructionAsync<
   TAccountOperator extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
+  TAccountFundAccount extends string,
   TAccountRewardAccount extends string,
   TAccountEventAuthority extends string,
   TAccountProgram extends string,
@@ -148,6 +155,7 @@ export async function getOperatorUpdateRewardPoolsInstructionAsync<
     TAccountOperator,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
+    TAccountFundAccount,
     TAccountRewardAccount,
     TAccountEventAuthority,
     TAccountProgram
@@ -159,6 +167,7 @@ export async function getOperatorUpdateRewardPoolsInstructionAsync<
     TAccountOperator,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
+    TAccountFundAccount,
     TAccountRewardAccount,
     TAccountEventAuthority,
     TAccountProgram
@@ -175,6 +184,7 @@ export async function getOperatorUpdateRewardPoolsInstructionAsync<
       value: input.receiptTokenMint ?? null,
       isWritable: false,
     },
+    fundAccount: { value: input.fundAccount ?? null, isWritable: false },
     rewardAccount: { value: input.rewardAccount ?? null, isWritable: true },
     eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
@@ -189,6 +199,17 @@ export async function getOperatorUpdateRewardPoolsInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.fundAccount.value) {
+    accounts.fundAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([102, 117, 110, 100])),
+        getAddressEncoder().encode(
+          expectAddress(accounts.receiptTokenMint.value)
+        ),
+      ],
+    });
+  }
   if (!accounts.rewardAccount.value) {
     accounts.rewardAccount.value = await getProgramDerivedAddress({
       programAddress,
@@ -220,6 +241,7 @@ export async function getOperatorUpdateRewardPoolsInstructionAsync<
       getAccountMeta(accounts.operator),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
+      getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.eventAuthority),
       getAccountMeta(accounts.program),
@@ -231,6 +253,7 @@ export async function getOperatorUpdateRewardPoolsInstructionAsync<
     TAccountOperator,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
+    TAccountFundAccount,
     TAccountRewardAccount,
     TAccountEventAuthority,
     TAccountProgram
@@ -241,6 +264,7 @@ export type OperatorUpdateRewardPoolsInput<
   TAccountOperator extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
+  TAccountFundAccount extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
@@ -248,6 +272,7 @@ export type OperatorUpdateRewardPoolsInput<
   operator: TransactionSigner<TAccountOperator>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
+  fundAccount: Address<TAccountFundAccount>;
   rewardAccount: Address<TAccountRewardAccount>;
   eventAuthority: Address<TAccountEventAuthority>;
   program: Address<TAccountProgram>;
@@ -257,6 +282,7 @@ export function getOperatorUpdateRewardPoolsInstruction<
   TAccountOperator extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenMint extends string,
+  TAccountFundAccount extends string,
   TAccountRewardAccount extends string,
   TAccountEventAuthority extends string,
   TAccountProgram extends string,
@@ -266,6 +292,7 @@ export function getOperatorUpdateRewardPoolsInstruction<
     TAccountOperator,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
+    TAccountFundAccount,
     TAccountRewardAccount,
     TAccountEventAuthority,
     TAccountProgram
@@ -276,6 +303,7 @@ export function getOperatorUpdateRewardPoolsInstruction<
   TAccountOperator,
   TAccountSystemProgram,
   TAccountReceiptTokenMint,
+  TAccountFundAccount,
   TAccountRewardAccount,
   TAccountEventAuthority,
   TAccountProgram
@@ -291,6 +319,7 @@ export function getOperatorUpdateRewardPoolsInstruction<
       value: input.receiptTokenMint ?? null,
       isWritable: false,
     },
+    fundAccount: { value: input.fundAccount ?? null, isWritable: false },
     rewardAccount: { value: input.rewardAccount ?? null, isWritable: true },
     eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
@@ -312,6 +341,7 @@ export function getOperatorUpdateRewardPoolsInstruction<
       getAccountMeta(accounts.operator),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenMint),
+      getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.eventAuthority),
       getAccountMeta(accounts.program),
@@ -323,6 +353,7 @@ export function getOperatorUpdateRewardPoolsInstruction<
     TAccountOperator,
     TAccountSystemProgram,
     TAccountReceiptTokenMint,
+    TAccountFundAccount,
     TAccountRewardAccount,
     TAccountEventAuthority,
     TAccountProgram
@@ -338,9 +369,10 @@ export type ParsedOperatorUpdateRewardPoolsInstruction<
     operator: TAccountMetas[0];
     systemProgram: TAccountMetas[1];
     receiptTokenMint: TAccountMetas[2];
-    rewardAccount: TAccountMetas[3];
-    eventAuthority: TAccountMetas[4];
-    program: TAccountMetas[5];
+    fundAccount: TAccountMetas[3];
+    rewardAccount: TAccountMetas[4];
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: OperatorUpdateRewardPoolsInstructionData;
 };
@@ -353,7 +385,7 @@ export function parseOperatorUpdateRewardPoolsInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedOperatorUpdateRewardPoolsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -369,6 +401,7 @@ export function parseOperatorUpdateRewardPoolsInstruction<
       operator: getNextAccount(),
       systemProgram: getNextAccount(),
       receiptTokenMint: getNextAccount(),
+      fundAccount: getNextAccount(),
       rewardAccount: getNextAccount(),
       eventAuthority: getNextAccount(),
       program: getNextAccount(),
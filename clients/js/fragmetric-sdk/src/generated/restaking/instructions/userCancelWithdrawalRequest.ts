@@ -71,6 +71,9 @@ export type UserCancelWithdrawalRequestInstruction<
   TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountFundReserveAccount extends string | AccountMeta<string> = string,
   TAccountUserFundAccount extends string | AccountMeta<string> = string,
+  TAccountFundWithdrawalRequestAccount extends
+    | string
+    | AccountMeta<string> = string,
   TAccountRewardAccount extends string | AccountMeta<string> = string,
   TAccountUserRewardAccount extends string | AccountMeta<string> = string,
   TAccountInstructionsSysvar extends
@@ -110,6 +113,9 @@ export type UserCancelWithdrawalRequestInstruction<
       TAccountUserFundAccount extends string
         ? WritableAccount<TAccountUserFundAccount>
         : TAccountUserFundAccount,
+      TAccountFundWithdrawalRequestAccount extends string
+        ? WritableAccount<TAccountFundWithdrawalRequestAccount>
+        : TAccountFundWithdrawalRequestAccount,
       TAccountRewardAccount extends string
         ? WritableAccount<TAccountRewardAccount>
         : TAccountRewardAccount,
@@ -182,6 +188,7 @@ export type UserCancelWithdrawalRequestAsyncInput<
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
   TAccountUserFundAccount extends string = string,
+  TAccountFundWithdrawalRequestAccount extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountUserRewardAccount extends string = string,
   TAccountInstructionsSysvar extends string = string,
@@ -197,6 +204,7 @@ export type UserCancelWithdrawalRequestAsyncInput<
   fundAccount?: Address<TAccountFundAccount>;
   fundReserveAccount?: Address<TAccountFundReserveAccount>;
   userFundAccount?: Address<TAccountUserFundAccount>;
+  fundWithdrawalRequestAccount: Address<TAccountFundWithdrawalRequestAccount>;
   rewardAccount?: Address<TAccountRewardAccount>;
   userRewardAccount?: Address<TAccountUserRewardAccount>;
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
@@ -216,6 +224,7 @@ export async function getUserCancelWithdrawalRequestInstructionAsync<
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
   TAccountUserFundAccount extends string,
+  TAccountFundWithdrawalRequestAccount extends string,
   TAccountRewardAccount extends string,
   TAccountUserRewardAccount extends string,
   TAccountInstructionsSysvar extends string,
@@ -233,6 +242,7 @@ export async function getUserCancelWithdrawalRequestInstructionAsync<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountUserFundAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountRewardAccount,
     TAccountUserRewardAccount,
     TAccountInstructionsSysvar,
@@ -252,6 +262,7 @@ export async function getUserCancelWithdrawalRequestInstructionAsync<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountUserFundAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountRewardAccount,
     TAccountUserRewardAccount,
     TAccountInstructionsSysvar,
@@ -288,6 +299,10 @@ export async function getUserCancelWithdrawalRequestInstructionAsync<
       isWritable: true,
     },
     userFundAccount: { value: input.userFundAccount ?? null, isWritable: true },
+    fundWithdrawalRequestAccount: {
+      value: input.fundWithdrawalRequestAccount ?? null,
+      isWritable: true,
+    },
     rewardAccount: { value: input.rewardAccount ?? null, isWritable: true },
     userRewardAccount: {
       value: input.userRewardAccount ?? null,
@@ -442,6 +457,7 @@ export async function getUserCancelWithdrawalRequestInstructionAsync<
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
       getAccountMeta(accounts.userFundAccount),
+      getAccountMeta(accounts.fundWithdrawalRequestAccount),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.userRewardAccount),
       getAccountMeta(accounts.instructionsSysvar),
@@ -463,6 +479,7 @@ export async function getUserCancelWithdrawalRequestInstructionAsync<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountUserFundAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountRewardAccount,
     TAccountUserRewardAccount,
     TAccountInstructionsSysvar,
@@ -481,6 +498,7 @@ export type UserCancelWithdrawalRequestInput<
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
   TAccountUserFundAccount extends string = string,
+  TAccountFundWithdrawalRequestAccount extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountUserRewardAccount extends string = string,
   TAccountInstructionsSysvar extends string = string,
@@ -496,6 +514,7 @@ export type UserCancelWithdrawalRequestInput<
   fundAccount: Address<TAccountFundAccount>;
   fundReserveAccount: Address<TAccountFundReserveAccount>;
   userFundAccount: Address<TAccountUserFundAccount>;
+  fundWithdrawalRequestAccount: Address<TAccountFundWithdrawalRequestAccount>;
   rewardAccount: Address<TAccountRewardAccount>;
   userRewardAccount: Address<TAccountUserRewardAccount>;
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
@@ -515,6 +534,7 @@ export function getUserCancelWithdrawalRequestInstruction<
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
   TAccountUserFundAccount extends string,
+  TAccountFundWithdrawalRequestAccount extends string,
   TAccountRewardAccount extends string,
   TAccountUserRewardAccount extends string,
   TAccountInstructionsSysvar extends string,
@@ -532,6 +552,7 @@ export function getUserCancelWithdrawalRequestInstruction<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountUserFundAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountRewardAccount,
     TAccountUserRewardAccount,
     TAccountInstructionsSysvar,
@@ -550,6 +571,7 @@ export function getUserCancelWithdrawalRequestInstruction<
   TAccountFundAccount,
   TAccountFundReserveAccount,
   TAccountUserFundAccount,
+  TAccountFundWithdrawalRequestAccount,
   TAccountRewardAccount,
   TAccountUserRewardAccount,
   TAccountInstructionsSysvar,
@@ -585,6 +607,10 @@ export function getUserCancelWithdrawalRequestInstruction<
       isWritable: true,
     },
     userFundAccount: { value: input.userFundAccount ?? null, isWritable: true },
+    fundWithdrawalRequestAccount: {
+      value: input.fundWithdrawalRequestAccount ?? null,
+      isWritable: true,
+    },
     rewardAccount: { value: input.rewardAccount ?? null, isWritable: true },
     userRewardAccount: {
       value: input.userRewardAccount ?? null,
@@ -631,6 +657,7 @@ export function getUserCancelWithdrawalRequestInstruction<
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
       getAccountMeta(accounts.userFundAccount),
+      getAccountMeta(accounts.fundWithdrawalRequestAccount),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.userRewardAccount),
       getAccountMeta(accounts.instructionsSysvar),
@@ -652,6 +679,7 @@ export function getUserCancelWithdrawalRequestInstruction<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountUserFundAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountRewardAccount,
     TAccountUserRewardAccount,
     TAccountInstructionsSysvar,
@@ -675,11 +703,12 @@ export type ParsedUserCancelWithdrawalRequestInstruction<
     fundAccount: TAccountMetas[6];
     fundReserveAccount: TAccountMetas[7];
     userFundAccount: TAccountMetas[8];
-    rewardAccount: TAccountMetas[9];
-    userRewardAccount: TAccountMetas[10];
-    instructionsSysvar: TAccountMetas[11];
-    eventAuthority: TAccountMetas[12];
-    program: TAccountMetas[13];
+    fundWithdrawalRequestAccount: TAccountMetas[9];
+    rewardAccount: TAccountMetas[10];
+    userRewardAccount: TAccountMetas[11];
+    instructionsSysvar: TAccountMetas[12];
+    eventAuthority: TAccountMetas[13];
+    program: TAccountMetas[14];
   };
   data: UserCancelWithdrawalRequestInstructionData;
 };
@@ -692,7 +721,7 @@ export function parseUserCancelWithdrawalRequestInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUserCancelWithdrawalRequestInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -714,6 +743,7 @@ export function parseUserCancelWithdrawalRequestInstruction<
       fundAccount: getNextAccount(),
       fundReserveAccount: getNextAccount(),
       userFundAccount: getNextAccount(),
+      fundWithdrawalRequestAccount: getNextAccount(),
       rewardAccount: getNextAccount(),
       userRewardAccount: getNextAccount(),
       instructionsSysvar: getNextAccount(),
@@ -64,6 +64,7 @@ export function getUserDepositSolDiscriminatorBytes() {
 export type UserDepositSolInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountUser extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -91,6 +92,9 @@ export type UserDepositSolInstruction<
       TAccountUser extends string
         ? ReadonlySignerAccount<TAccountUser> & AccountSignerMeta<TAccountUser>
         : TAccountUser,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -138,11 +142,13 @@ export type UserDepositSolInstructionData = {
   discriminator: ReadonlyUint8Array;
   amount: bigint;
   metadata: Option<DepositMetadata>;
+  minReceiptTokenAmount: Option<bigint>;
 };
 
 export type UserDepositSolInstructionDataArgs = {
   amount: number | bigint;
   metadata: OptionOrNullable<DepositMetadataArgs>;
+  minReceiptTokenAmount: OptionOrNullable<number | bigint>;
 };
 
 export function getUserDepositSolInstructionDataEncoder(): Encoder<UserDepositSolInstructionDataArgs> {
@@ -151,6 +157,7 @@ export function getUserDepositSolInstructionDataEncoder(): Encoder<UserDepositSo
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['amount', getU64Encoder()],
       ['metadata', getOptionEncoder(getDepositMetadataEncoder())],
+      ['minReceiptTokenAmount', getOptionEncoder(getU64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: USER_DEPOSIT_SOL_DISCRIMINATOR })
   );
@@ -161,6 +168,7 @@ export function getUserDepositSolInstructionDataDecoder(): Decoder<UserDepositSo
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['amount', getU64Decoder()],
     ['metadata', getOptionDecoder(getDepositMetadataDecoder())],
+    ['minReceiptTokenAmount', getOptionDecoder(getU64Decoder())],
   ]);
 }
 
@@ -176,6 +184,7 @@ export function getUserDepositSolInstructionDataCodec(): Codec<
 
 export type UserDepositSolAsyncInput<
   TAccountUser extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
@@ -191,6 +200,8 @@ export type UserDepositSolAsyncInput<
   TAccountProgram extends string = string,
 > = {
   user: TransactionSigner<TAccountUser>;
+  /** admin signs the deposit metadata. */
+  roleAccount?: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
@@ -206,10 +217,12 @@ export type UserDepositSolAsyncInput<
   program: Address<TAccountProgram>;
   amount: UserDepositSolInstructionDataArgs['amount'];
   metadata: UserDepositSolInstructionDataArgs['metadata'];
+  minReceiptTokenAmount: UserDepositSolInstructionDataArgs['minReceiptTokenAmount'];
 };
 
 export async function getUserDepositSolInstructionAsync<
   TAccountUser extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenProgram extends string,
   TAccountReceiptTokenMint extends string,
@@ -227,6 +240,7 @@ export async function getUserDepositSolInstructionAsync<
 >(
   input: UserDepositSolAsyncInput<
     TAccountUser,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenProgram,
     TAccountReceiptTokenMint,
@@ -246,6 +260,7 @@ export async function getUserDepositSolInstructionAsync<
   UserDepositSolInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenProgram,
     TAccountReceiptTokenMint,
@@ -267,6 +282,7 @@ export async function getUserDepositSolInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     user: { value: input.user ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenProgram: {
       value: input.receiptTokenProgram ?? null,
@@ -311,6 +327,12 @@ export async function getUserDepositSolInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
@@ -436,6 +458,7 @@ export async function getUserDepositSolInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.user),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenProgram),
       getAccountMeta(accounts.receiptTokenMint),
@@ -457,6 +480,7 @@ export async function getUserDepositSolInstructionAsync<
   } as UserDepositSolInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenProgram,
     TAccountReceiptTokenMint,
@@ -475,6 +499,7 @@ export async function getUserDepositSolInstructionAsync<
 
 export type UserDepositSolInput<
   TAccountUser extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountReceiptTokenProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
@@ -490,6 +515,8 @@ export type UserDepositSolInput<
   TAccountProgram extends string = string,
 > = {
   user: TransactionSigner<TAccountUser>;
+  /** admin signs the deposit metadata. */
+  roleAccount: Address<TAccountRoleAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
@@ -505,10 +532,12 @@ export type UserDepositSolInput<
   program: Address<TAccountProgram>;
   amount: UserDepositSolInstructionDataArgs['amount'];
   metadata: UserDepositSolInstructionDataArgs['metadata'];
+  minReceiptTokenAmount: UserDepositSolInstructionDataArgs['minReceiptTokenAmount'];
 };
 
 export function getUserDepositSolInstruction<
   TAccountUser extends string,
+  TAccountRoleAccount extends string,
   TAccountSystemProgram extends string,
   TAccountReceiptTokenProgram extends string,
   TAccountReceiptTokenMint extends string,
@@ -526,6 +555,7 @@ export function getUserDepositSolInstruction<
 >(
   input: UserDepositSolInput<
     TAccountUser,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenProgram,
     TAccountReceiptTokenMint,
@@ -544,6 +574,7 @@ export function getUserDepositSolInstruction<
 ): UserDepositSolInstruction<
   TProgramAddress,
   TAccountUser,
+  TAccountRoleAccount,
   TAccountSystemProgram,
   TAccountReceiptTokenProgram,
   TAccountReceiptTokenMint,
@@ -564,6 +595,7 @@ export function getUserDepositSolInstruction<
   // Original accounts.
   const originalAccounts = {
     user: { value: input.user ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenProgram: {
       value: input.receiptTokenProgram ?? null,
@@ -625,6 +657,7 @@ export function getUserDepositSolInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.user),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.receiptTokenProgram),
       getAccountMeta(accounts.receiptTokenMint),
@@ -646,6 +679,7 @@ export function getUserDepositSolInstruction<
   } as UserDepositSolInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountRoleAccount,
     TAccountSystemProgram,
     TAccountReceiptTokenProgram,
     TAccountReceiptTokenMint,
@@ -669,19 +703,21 @@ export type ParsedUserDepositSolInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     user: TAccountMetas[0];
-    systemProgram: TAccountMetas[1];
-    receiptTokenProgram: TAccountMetas[2];
-    receiptTokenMint: TAccountMetas[3];
-    receiptTokenLockAccount: TAccountMetas[4];
-    userReceiptTokenAccount: TAccountMetas[5];
-    fundAccount: TAccountMetas[6];
-    fundReserveAccount: TAccountMetas[7];
-    userFundAccount: TAccountMetas[8];
-    rewardAccount: TAccountMetas[9];
-    userRewardAccount: TAccountMetas[10];
-    instructionsSysvar: TAccountMetas[11];
-    eventAuthority: TAccountMetas[12];
-    program: TAccountMetas[13];
+    /** admin signs the deposit metadata. */
+    roleAccount: TAccountMetas[1];
+    systemProgram: TAccountMetas[2];
+    receiptTokenProgram: TAccountMetas[3];
+    receiptTokenMint: TAccountMetas[4];
+    receiptTokenLockAccount: TAccountMetas[5];
+    userReceiptTokenAccount: TAccountMetas[6];
+    fundAccount: TAccountMetas[7];
+    fundReserveAccount: TAccountMetas[8];
+    userFundAccount: TAccountMetas[9];
+    rewardAccount: TAccountMetas[10];
+    userRewardAccount: TAccountMetas[11];
+    instructionsSysvar: TAccountMetas[12];
+    eventAuthority: TAccountMetas[13];
+    program: TAccountMetas[14];
   };
   data: UserDepositSolInstructionData;
 };
@@ -694,7 +730,7 @@ export function parseUserDepositSolInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUserDepositSolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -708,6 +744,7 @@ export function parseUserDepositSolInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       user: getNextAccount(),
+      roleAccount: getNextAccount(),
       systemProgram: getNextAccount(),
       receiptTokenProgram: getNextAccount(),
       receiptTokenMint: getNextAccount(),
@@ -64,6 +64,7 @@ export function getUserDepositSupportedTokenDiscriminatorBytes() {
 export type UserDepositSupportedTokenInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountUser extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
@@ -95,6 +96,9 @@ export type UserDepositSupportedTokenInstruction<
       TAccountUser extends string
         ? ReadonlySignerAccount<TAccountUser> & AccountSignerMeta<TAccountUser>
         : TAccountUser,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenProgram extends string
         ? ReadonlyAccount<TAccountReceiptTokenProgram>
         : TAccountReceiptTokenProgram,
@@ -148,11 +152,13 @@ export type UserDepositSupportedTokenInstructionData = {
   discriminator: ReadonlyUint8Array;
   amount: bigint;
   metadata: Option<DepositMetadata>;
+  minReceiptTokenAmount: Option<bigint>;
 };
 
 export type UserDepositSupportedTokenInstructionDataArgs = {
   amount: number | bigint;
   metadata: OptionOrNullable<DepositMetadataArgs>;
+  minReceiptTokenAmount: OptionOrNullable<number | bigint>;
 };
 
 export function getUserDepositSupportedTokenInstructionDataEncoder(): Encoder<UserDepositSupportedTokenInstructionDataArgs> {
@@ -161,6 +167,7 @@ export function getUserDepositSupportedTokenInstructionDataEncoder(): Encoder<Us
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['amount', getU64Encoder()],
       ['metadata', getOptionEncoder(getDepositMetadataEncoder())],
+      ['minReceiptTokenAmount', getOptionEncoder(getU64Encoder())],
     ]),
     (value) => ({
       ...value,
@@ -174,6 +181,7 @@ export function getUserDepositSupportedTokenInstructionDataDecoder(): Decoder<Us
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['amount', getU64Decoder()],
     ['metadata', getOptionDecoder(getDepositMetadataDecoder())],
+    ['minReceiptTokenAmount', getOptionDecoder(getU64Decoder())],
   ]);
 }
 
@@ -189,6 +197,7 @@ export function getUserDepositSupportedTokenInstructionDataCodec(): Codec<
 
 export type UserDepositSupportedTokenAsyncInput<
   TAccountUser extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenProgram extends string = string,
   TAccountSupportedTokenProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
@@ -206,6 +215,8 @@ export type UserDepositSupportedTokenAsyncInput<
   TAccountProgram extends string = string,
 > = {
   user: TransactionSigner<TAccountUser>;
+  /** admin signs the deposit metadata. */
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
   supportedTokenProgram: Address<TAccountSupportedTokenProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
@@ -223,10 +234,12 @@ export type UserDepositSupportedTokenAsyncInput<
   program: Address<TAccountProgram>;
   amount: UserDepositSupportedTokenInstructionDataArgs['amount'];
   metadata: UserDepositSupportedTokenInstructionDataArgs['metadata'];
+  minReceiptTokenAmount: UserDepositSupportedTokenInstructionDataArgs['minReceiptTokenAmount'];
 };
 
 export async function getUserDepositSupportedTokenInstructionAsync<
   TAccountUser extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenProgram extends string,
   TAccountSupportedTokenProgram extends string,
   TAccountReceiptTokenMint extends string,
@@ -246,6 +259,7 @@ export async function getUserDepositSupportedTokenInstructionAsync<
 >(
   input: UserDepositSupportedTokenAsyncInput<
     TAccountUser,
+    TAccountRoleAccount,
     TAccountReceiptTokenProgram,
     TAccountSupportedTokenProgram,
     TAccountReceiptTokenMint,
@@ -267,6 +281,7 @@ export async function getUserDepositSupportedTokenInstructionAsync<
   UserDepositSupportedTokenInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountRoleAccount,
     TAccountReceiptTokenProgram,
     TAccountSupportedTokenProgram,
     TAccountReceiptTokenMint,
@@ -290,6 +305,7 @@ export async function getUserDepositSupportedTokenInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     user: { value: input.user ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenProgram: {
       value: input.receiptTokenProgram ?? null,
       isWritable: false,
@@ -345,6 +361,12 @@ export async function getUserDepositSupportedTokenInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
+  }
   if (!accounts.receiptTokenProgram.value) {
     accounts.receiptTokenProgram.value =
       'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
@@ -469,6 +491,7 @@ export async function getUserDepositSupportedTokenInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.user),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenProgram),
       getAccountMeta(accounts.supportedTokenProgram),
       getAccountMeta(accounts.receiptTokenMint),
@@ -492,6 +515,7 @@ export async function getUserDepositSupportedTokenInstructionAsync<
   } as UserDepositSupportedTokenInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountRoleAccount,
     TAccountReceiptTokenProgram,
     TAccountSupportedTokenProgram,
     TAccountReceiptTokenMint,
@@ -512,6 +536,7 @@ export async function getUserDepositSupportedTokenInstructionAsync<
 
 export type UserDepositSupportedTokenInput<
   TAccountUser extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenProgram extends string = string,
   TAccountSupportedTokenProgram extends string = string,
   TAccountReceiptTokenMint extends string = string,
@@ -529,6 +554,8 @@ export type UserDepositSupportedTokenInput<
   TAccountProgram extends string = string,
 > = {
   user: TransactionSigner<TAccountUser>;
+  /** admin signs the deposit metadata. */
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
   supportedTokenProgram: Address<TAccountSupportedTokenProgram>;
   receiptTokenMint: Address<TAccountReceiptTokenMint>;
@@ -546,10 +573,12 @@ export type UserDepositSupportedTokenInput<
   program: Address<TAccountProgram>;
   amount: UserDepositSupportedTokenInstructionDataArgs['amount'];
   metadata: UserDepositSupportedTokenInstructionDataArgs['metadata'];
+  minReceiptTokenAmount: UserDepositSupportedTokenInstructionDataArgs['minReceiptTokenAmount'];
 };
 
 export function getUserDepositSupportedTokenInstruction<
   TAccountUser extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenProgram extends string,
   TAccountSupportedTokenProgram extends string,
   TAccountReceiptTokenMint extends string,
@@ -569,6 +598,7 @@ export function getUserDepositSupportedTokenInstruction<
 >(
   input: UserDepositSupportedTokenInput<
     TAccountUser,
+    TAccountRoleAccount,
     TAccountReceiptTokenProgram,
     TAccountSupportedTokenProgram,
     TAccountReceiptTokenMint,
@@ -589,6 +619,7 @@ export function getUserDepositSupportedTokenInstruction<
 ): UserDepositSupportedTokenInstruction<
   TProgramAddress,
   TAccountUser,
+  TAccountRoleAccount,
   TAccountReceiptTokenProgram,
   TAccountSupportedTokenProgram,
   TAccountReceiptTokenMint,
@@ -611,6 +642,7 @@ export function getUserDepositSupportedTokenInstruction<
   // Original accounts.
   const originalAccounts = {
     user: { value: input.user ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenProgram: {
       value: input.receiptTokenProgram ?? null,
       isWritable: false,
@@ -679,6 +711,7 @@ export function getUserDepositSupportedTokenInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.user),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenProgram),
       getAccountMeta(accounts.supportedTokenProgram),
       getAccountMeta(accounts.receiptTokenMint),
@@ -702,6 +735,7 @@ export function getUserDepositSupportedTokenInstruction<
   } as UserDepositSupportedTokenInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountRoleAccount,
     TAccountReceiptTokenProgram,
     TAccountSupportedTokenProgram,
     TAccountReceiptTokenMint,
@@ -727,21 +761,23 @@ export type ParsedUserDepositSupportedTokenInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     user: TAccountMetas[0];
-    receiptTokenProgram: TAccountMetas[1];
-    supportedTokenProgram: TAccountMetas[2];
-    receiptTokenMint: TAccountMetas[3];
-    userReceiptTokenAccount: TAccountMetas[4];
-    supportedTokenMint: TAccountMetas[5];
-    fundReserveAccount: TAccountMetas[6];
-    fundSupportedTokenReserveAccount: TAccountMetas[7];
-    userSupportedTokenAccount: TAccountMetas[8];
-    fundAccount: TAccountMetas[9];
-    userFundAccount: TAccountMetas[10];
-    rewardAccount: TAccountMetas[11];
-    userRewardAccount: TAccountMetas[12];
-    instructionsSysvar: TAccountMetas[13];
-    eventAuthority: TAccountMetas[14];
-    program: TAccountMetas[15];
+    /** admin signs the deposit metadata. */
+    roleAccount: TAccountMetas[1];
+    receiptTokenProgram: TAccountMetas[2];
+    supportedTokenProgram: TAccountMetas[3];
+    receiptTokenMint: TAccountMetas[4];
+    userReceiptTokenAccount: TAccountMetas[5];
+    supportedTokenMint: TAccountMetas[6];
+    fundReserveAccount: TAccountMetas[7];
+    fundSupportedTokenReserveAccount: TAccountMetas[8];
+    userSupportedTokenAccount: TAccountMetas[9];
+    fundAccount: TAccountMetas[10];
+    userFundAccount: TAccountMetas[11];
+    rewardAccount: TAccountMetas[12];
+    userRewardAccount: TAccountMetas[13];
+    instructionsSysvar: TAccountMetas[14];
+    eventAuthority: TAccountMetas[15];
+    program: TAccountMetas[16];
   };
   data: UserDepositSupportedTokenInstructionData;
 };
@@ -754,7 +790,7 @@ export function parseUserDepositSupportedTokenInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUserDepositSupportedTokenInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 16) {
+  if (instruction.accounts.length < 17) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -768,6 +804,7 @@ export function parseUserDepositSupportedTokenInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       user: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenProgram: getNextAccount(),
       supportedTokenProgram: getNextAccount(),
       receiptTokenMint: getNextAccount(),
@@ -18,6 +18,8 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -62,6 +64,7 @@ export function getUserDepositVaultReceiptTokenDiscriminatorBytes() {
 export type UserDepositVaultReceiptTokenInstruction<
   TProgram extends string = typeof RESTAKING_PROGRAM_ADDRESS,
   TAccountUser extends string | AccountMeta<string> = string,
+  TAccountRoleAccount extends string | AccountMeta<string> = string,
   TAccountReceiptTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
@@ -95,6 +98,9 @@ export type UserDepositVaultReceiptTokenInstruction<
       TAccountUser extends string
         ? ReadonlySignerAccount<TAccountUser> & AccountSignerMeta<TAccountUser>
         : TAccountUser,
+      TAccountRoleAccount extends string
+        ? ReadonlyAccount<TAccountRoleAccount>
+        : TAccountRoleAccount,
       TAccountReceiptTokenProgram extends string
         ? ReadonlyAccount<TAccountReceiptTokenProgram>
         : TAccountReceiptTokenProgram,
@@ -147,10 +153,12 @@ export type UserDepositVaultReceiptTokenInstruction<
 export type UserDepositVaultReceiptTokenInstructionData = {
   discriminator: ReadonlyUint8Array;
   metadata: Option<DepositMetadata>;
+  minReceiptTokenAmount: Option<bigint>;
 };
 
 export type UserDepositVaultReceiptTokenInstructionDataArgs = {
   metadata: OptionOrNullable<DepositMetadataArgs>;
+  minReceiptTokenAmount: OptionOrNullable<number | bigint>;
 };
 
 export function getUserDepositVaultReceiptTokenInstructionDataEncoder(): Encoder<UserDepositVaultReceiptTokenInstructionDataArgs> {
@@ -158,6 +166,7 @@ export function getUserDepositVaultReceiptTokenInstructionDataEncoder(): Encoder
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['metadata', getOptionEncoder(getDepositMetadataEncoder())],
+      ['minReceiptTokenAmount', getOptionEncoder(getU64Encoder())],
     ]),
     (value) => ({
       ...value,
@@ -170,6 +179,7 @@ export function getUserDepositVaultReceiptTokenInstructionDataDecoder(): Decoder
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['metadata', getOptionDecoder(getDepositMetadataDecoder())],
+    ['minReceiptTokenAmount', getOptionDecoder(getU64Decoder())],
   ]);
 }
 
@@ -185,6 +195,7 @@ export function getUserDepositVaultReceiptTokenInstructionDataCodec(): Codec<
 
 export type UserDepositVaultReceiptTokenAsyncInput<
   TAccountUser extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenProgram extends string = string,
   TAccountVaultReceiptTokenProgram extends string = string,
   TAccountFundAccount extends string = string,
@@ -202,6 +213,8 @@ export type UserDepositVaultReceiptTokenAsyncInput<
   TAccountProgram extends string = string,
 > = {
   user: TransactionSigner<TAccountUser>;
+  /** admin signs the deposit metadata. */
+  roleAccount?: Address<TAccountRoleAccount>;
   receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
   vaultReceiptTokenProgram?: Address<TAccountVaultReceiptTokenProgram>;
   fundAccount?: Address<TAccountFundAccount>;
@@ -218,10 +231,12 @@ export type UserDepositVaultReceiptTokenAsyncInput<
   eventAuthority?: Address<TAccountEventAuthority>;
   program: Address<TAccountProgram>;
   metadata: UserDepositVaultReceiptTokenInstructionDataArgs['metadata'];
+  minReceiptTokenAmount: UserDepositVaultReceiptTokenInstructionDataArgs['minReceiptTokenAmount'];
 };
 
 export async function getUserDepositVaultReceiptTokenInstructionAsync<
   TAccountUser extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenProgram extends string,
   TAccountVaultReceiptTokenProgram extends string,
   TAccountFundAccount extends string,
@@ -241,6 +256,7 @@ export async function getUserDepositVaultReceiptTokenInstructionAsync<
 >(
   input: UserDepositVaultReceiptTokenAsyncInput<
     TAccountUser,
+    TAccountRoleAccount,
     TAccountReceiptTokenProgram,
     TAccountVaultReceiptTokenProgram,
     TAccountFundAccount,
@@ -262,6 +278,7 @@ export async function getUserDepositVaultReceiptTokenInstructionAsync<
   UserDepositVaultReceiptTokenInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountRoleAccount,
     TAccountReceiptTokenProgram,
     TAccountVaultReceiptTokenProgram,
     TAccountFundAccount,
@@ -285,6 +302,7 @@ export async function getUserDepositVaultReceiptTokenInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     user: { value: input.user ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenProgram: {
       value: input.receiptTokenProgram ?? null,
       isWritable: false,
@@ -340,6 +358,12 @@ export async function getUserDepositVaultReceiptTokenInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.roleAccount.value) {
+    accounts.roleAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [getBytesEncoder().encode(new Uint8Array([114, 111, 108, 101]))],
+    });
+  }
   if (!accounts.receiptTokenProgram.value) {
     accounts.receiptTokenProgram.value =
       'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb' as Address<'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'>;
@@ -484,6 +508,7 @@ export async function getUserDepositVaultReceiptTokenInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.user),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenProgram),
       getAccountMeta(accounts.vaultReceiptTokenProgram),
       getAccountMeta(accounts.fundAccount),
@@ -507,6 +532,7 @@ export async function getUserDepositVaultReceiptTokenInstructionAsync<
   } as UserDepositVaultReceiptTokenInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountRoleAccount,
     TAccountReceiptTokenProgram,
     TAccountVaultReceiptTokenProgram,
     TAccountFundAccount,
@@ -527,6 +553,7 @@ export async function getUserDepositVaultReceiptTokenInstructionAsync<
 
 export type UserDepositVaultReceiptTokenInput<
   TAccountUser extends string = string,
+  TAccountRoleAccount extends string = string,
   TAccountReceiptTokenProgram extends string = string,
   TAccountVaultReceiptTokenProgram extends string = string,
   TAccountFundAccount extends string = string,
@@ -544,6 +571,8 @@ export type UserDepositVaultReceiptTokenInput<
   TAccountProgram extends string = string,
 > = {
   user: TransactionSigner<TAccountUser>;
+  /** admin signs the deposit metadata. */
+  roleAccount: Address<TAccountRoleAccount>;
   receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
   vaultReceiptTokenProgram?: Address<TAccountVaultReceiptTokenProgram>;
   fundAccount: Address<TAccountFundAccount>;
@@ -560,10 +589,12 @@ export type UserDepositVaultReceiptTokenInput<
   eventAuthority: Address<TAccountEventAuthority>;
   program: Address<TAccountProgram>;
   metadata: UserDepositVaultReceiptTokenInstructionDataArgs['metadata'];
+  minReceiptTokenAmount: UserDepositVaultReceiptTokenInstructionDataArgs['minReceiptTokenAmount'];
 };
 
 export function getUserDepositVaultReceiptTokenInstruction<
   TAccountUser extends string,
+  TAccountRoleAccount extends string,
   TAccountReceiptTokenProgram extends string,
   TAccountVaultReceiptTokenProgram extends string,
   TAccountFundAccount extends string,
@@ -583,6 +614,7 @@ export function getUserDepositVaultReceiptTokenInstruction<
 >(
   input: UserDepositVaultReceiptTokenInput<
     TAccountUser,
+    TAccountRoleAccount,
     TAccountReceiptTokenProgram,
     TAccountVaultReceiptTokenProgram,
     TAccountFundAccount,
@@ -603,6 +635,7 @@ export function getUserDepositVaultReceiptTokenInstruction<
 ): UserDepositVaultReceiptTokenInstruction<
   TProgramAddress,
   TAccountUser,
+  TAccountRoleAccount,
   TAccountReceiptTokenProgram,
   TAccountVaultReceiptTokenProgram,
   TAccountFundAccount,
@@ -625,6 +658,7 @@ export function getUserDepositVaultReceiptTokenInstruction<
   // Original accounts.
   const originalAccounts = {
     user: { value: input.user ?? null, isWritable: false },
+    roleAccount: { value: input.roleAccount ?? null, isWritable: false },
     receiptTokenProgram: {
       value: input.receiptTokenProgram ?? null,
       isWritable: false,
@@ -697,6 +731,7 @@ export function getUserDepositVaultReceiptTokenInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.user),
+      getAccountMeta(accounts.roleAccount),
       getAccountMeta(accounts.receiptTokenProgram),
       getAccountMeta(accounts.vaultReceiptTokenProgram),
       getAccountMeta(accounts.fundAccount),
@@ -720,6 +755,7 @@ export function getUserDepositVaultReceiptTokenInstruction<
   } as UserDepositVaultReceiptTokenInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountRoleAccount,
     TAccountReceiptTokenProgram,
     TAccountVaultReceiptTokenProgram,
     TAccountFundAccount,
@@ -745,21 +781,23 @@ export type ParsedUserDepositVaultReceiptTokenInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     user: TAccountMetas[0];
-    receiptTokenProgram: TAccountMetas[1];
-    vaultReceiptTokenProgram: TAccountMetas[2];
-    fundAccount: TAccountMetas[3];
-    fundReserveAccount: TAccountMetas[4];
-    userFundAccount: TAccountMetas[5];
-    receiptTokenMint: TAccountMetas[6];
-    vaultReceiptTokenMint: TAccountMetas[7];
-    userVaultReceiptTokenAccount: TAccountMetas[8];
-    fundVaultReceiptTokenReserveAccount: TAccountMetas[9];
-    userReceiptTokenAccount: TAccountMetas[10];
-    rewardAccount: TAccountMetas[11];
-    userRewardAccount: TAccountMetas[12];
-    instructionsSysvar: TAccountMetas[13];
-    eventAuthority: TAccountMetas[14];
-    program: TAccountMetas[15];
+    /** admin signs the deposit metadata. */
+    roleAccount: TAccountMetas[1];
+    receiptTokenProgram: TAccountMetas[2];
+    vaultReceiptTokenProgram: TAccountMetas[3];
+    fundAccount: TAccountMetas[4];
+    fundReserveAccount: TAccountMetas[5];
+    userFundAccount: TAccountMetas[6];
+    receiptTokenMint: TAccountMetas[7];
+    vaultReceiptTokenMint: TAccountMetas[8];
+    userVaultReceiptTokenAccount: TAccountMetas[9];
+    fundVaultReceiptTokenReserveAccount: TAccountMetas[10];
+    userReceiptTokenAccount: TAccountMetas[11];
+    rewardAccount: TAccountMetas[12];
+    userRewardAccount: TAccountMetas[13];
+    instructionsSysvar: TAccountMetas[14];
+    eventAuthority: TAccountMetas[15];
+    program: TAccountMetas[16];
   };
   data: UserDepositVaultReceiptTokenInstructionData;
 };
@@ -772,7 +810,7 @@ export function parseUserDepositVaultReceiptTokenInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUserDepositVaultReceiptTokenInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 16) {
+  if (instruction.accounts.length < 17) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -786,6 +824,7 @@ export function parseUserDepositVaultReceiptTokenInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       user: getNextAccount(),
+      roleAccount: getNextAccount(),
       receiptTokenProgram: getNextAccount(),
       vaultReceiptTokenProgram: getNextAccount(),
       fundAccount: getNextAccount(),
@@ -71,6 +71,9 @@ export type UserRequestWithdrawalInstruction<
   TAccountFundAccount extends string | AccountMeta<string> = string,
   TAccountFundReserveAccount extends string | AccountMeta<string> = string,
   TAccountUserFundAccount extends string | AccountMeta<string> = string,
+  TAccountFundWithdrawalRequestAccount extends
+    | string
+    | AccountMeta<string> = string,
   TAccountRewardAccount extends string | AccountMeta<string> = string,
   TAccountUserRewardAccount extends string | AccountMeta<string> = string,
   TAccountInstructionsSysvar extends
@@ -110,6 +113,9 @@ export type UserRequestWithdrawalInstruction<
       TAccountUserFundAccount extends string
         ? WritableAccount<TAccountUserFundAccount>
         : TAccountUserFundAccount,
+      TAccountFundWithdrawalRequestAccount extends string
+        ? WritableAccount<TAccountFundWithdrawalRequestAccount>
+        : TAccountFundWithdrawalRequestAccount,
       TAccountRewardAccount extends string
         ? WritableAccount<TAccountRewardAccount>
         : TAccountRewardAccount,
@@ -133,11 +139,13 @@ export type UserRequestWithdrawalInstructionData = {
   discriminator: ReadonlyUint8Array;
   receiptTokenAmount: bigint;
   supportedTokenMint: Option<Address>;
+  minAssetAmount: Option<bigint>;
 };
 
 export type UserRequestWithdrawalInstructionDataArgs = {
   receiptTokenAmount: number | bigint;
   supportedTokenMint: OptionOrNullable<Address>;
+  minAssetAmount: OptionOrNullable<number | bigint>;
 };
 
 export function getUserRequestWithdrawalInstructionDataEncoder(): Encoder<UserRequestWithdrawalInstructionDataArgs> {
@@ -146,6 +154,7 @@ export function getUserRequestWithdrawalInstructionDataEncoder(): Encoder<UserRe
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['receiptTokenAmount', getU64Encoder()],
       ['supportedTokenMint', getOptionEncoder(getAddressEncoder())],
+      ['minAssetAmount', getOptionEncoder(getU64Encoder())],
     ]),
     (value) => ({
       ...value,
@@ -159,6 +168,7 @@ export function getUserRequestWithdrawalInstructionDataDecoder(): Decoder<UserRe
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['receiptTokenAmount', getU64Decoder()],
     ['supportedTokenMint', getOptionDecoder(getAddressDecoder())],
+    ['minAssetAmount', getOptionDecoder(getU64Decoder())],
   ]);
 }
 
@@ -182,6 +192,7 @@ export type UserRequestWithdrawalAsyncInput<
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
   TAccountUserFundAccount extends string = string,
+  TAccountFundWithdrawalRequestAccount extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountUserRewardAccount extends string = string,
   TAccountInstructionsSysvar extends string = string,
@@ -197,6 +208,7 @@ export type UserRequestWithdrawalAsyncInput<
   fundAccount?: Address<TAccountFundAccount>;
   fundReserveAccount?: Address<TAccountFundReserveAccount>;
   userFundAccount?: Address<TAccountUserFundAccount>;
+  fundWithdrawalRequestAccount: Address<TAccountFundWithdrawalRequestAccount>;
   rewardAccount?: Address<TAccountRewardAccount>;
   userRewardAccount?: Address<TAccountUserRewardAccount>;
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
@@ -204,6 +216,7 @@ export type UserRequestWithdrawalAsyncInput<
   program: Address<TAccountProgram>;
   receiptTokenAmount: UserRequestWithdrawalInstructionDataArgs['receiptTokenAmount'];
   supportedTokenMint: UserRequestWithdrawalInstructionDataArgs['supportedTokenMint'];
+  minAssetAmount: UserRequestWithdrawalInstructionDataArgs['minAssetAmount'];
 };
 
 export async function getUserRequestWithdrawalInstructionAsync<
@@ -216,6 +229,7 @@ export async function getUserRequestWithdrawalInstructionAsync<
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
   TAccountUserFundAccount extends string,
+  TAccountFundWithdrawalRequestAccount extends string,
   TAccountRewardAccount extends string,
   TAccountUserRewardAccount extends string,
   TAccountInstructionsSysvar extends string,
@@ -233,6 +247,7 @@ export async function getUserRequestWithdrawalInstructionAsync<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountUserFundAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountRewardAccount,
     TAccountUserRewardAccount,
     TAccountInstructionsSysvar,
@@ -252,6 +267,7 @@ export async function getUserRequestWithdrawalInstructionAsync<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountUserFundAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountRewardAccount,
     TAccountUserRewardAccount,
     TAccountInstructionsSysvar,
@@ -288,6 +304,10 @@ export async function getUserRequestWithdrawalInstructionAsync<
       isWritable: true,
     },
     userFundAccount: { value: input.userFundAccount ?? null, isWritable: true },
+    fundWithdrawalRequestAccount: {
+      value: input.fundWithdrawalRequestAccount ?? null,
+      isWritable: true,
+    },
     rewardAccount: { value: input.rewardAccount ?? null, isWritable: true },
     userRewardAccount: {
       value: input.userRewardAccount ?? null,
@@ -442,6 +462,7 @@ export async function getUserRequestWithdrawalInstructionAsync<
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
       getAccountMeta(accounts.userFundAccount),
+      getAccountMeta(accounts.fundWithdrawalRequestAccount),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.userRewardAccount),
       getAccountMeta(accounts.instructionsSysvar),
@@ -463,6 +484,7 @@ export async function getUserRequestWithdrawalInstructionAsync<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountUserFundAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountRewardAccount,
     TAccountUserRewardAccount,
     TAccountInstructionsSysvar,
@@ -481,6 +503,7 @@ export type UserRequestWithdrawalInput<
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
   TAccountUserFundAccount extends string = string,
+  TAccountFundWithdrawalRequestAccount extends string = string,
   TAccountRewardAccount extends string = string,
   TAccountUserRewardAccount extends string = string,
   TAccountInstructionsSysvar extends string = string,
@@ -496,6 +519,7 @@ export type UserRequestWithdrawalInput<
   fundAccount: Address<TAccountFundAccount>;
   fundReserveAccount: Address<TAccountFundReserveAccount>;
   userFundAccount: Address<TAccountUserFundAccount>;
+  fundWithdrawalRequestAccount: Address<TAccountFundWithdrawalRequestAccount>;
   rewardAccount: Address<TAccountRewardAccount>;
   userRewardAccount: Address<TAccountUserRewardAccount>;
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
@@ -503,6 +527,7 @@ export type UserRequestWithdrawalInput<
   program: Address<TAccountProgram>;
   receiptTokenAmount: UserRequestWithdrawalInstructionDataArgs['receiptTokenAmount'];
   supportedTokenMint: UserRequestWithdrawalInstructionDataArgs['supportedTokenMint'];
+  minAssetAmount: UserRequestWithdrawalInstructionDataArgs['minAssetAmount'];
 };
 
 export function getUserRequestWithdrawalInstruction<
@@ -515,6 +540,7 @@ export function getUserRequestWithdrawalInstruction<
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
   TAccountUserFundAccount extends string,
+  TAccountFundWithdrawalRequestAccount extends string,
   TAccountRewardAccount extends string,
   TAccountUserRewardAccount extends string,
   TAccountInstructionsSysvar extends string,
@@ -532,6 +558,7 @@ export function getUserRequestWithdrawalInstruction<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountUserFundAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountRewardAccount,
     TAccountUserRewardAccount,
     TAccountInstructionsSysvar,
@@ -550,6 +577,7 @@ export function getUserRequestWithdrawalInstruction<
   TAccountFundAccount,
   TAccountFundReserveAccount,
   TAccountUserFundAccount,
+  TAccountFundWithdrawalRequestAccount,
   TAccountRewardAccount,
   TAccountUserRewardAccount,
   TAccountInstructionsSysvar,
@@ -585,6 +613,10 @@ export function getUserRequestWithdrawalInstruction<
       isWritable: true,
     },
     userFundAccount: { value: input.userFundAccount ?? null, isWritable: true },
+    fundWithdrawalRequestAccount: {
+      value: input.fundWithdrawalRequestAccount ?? null,
+      isWritable: true,
+    },
     rewardAccount: { value: input.rewardAccount ?? null, isWritable: true },
     userRewardAccount: {
       value: input.userRewardAccount ?? null,
@@ -631,6 +663,7 @@ export function getUserRequestWithdrawalInstruction<
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
       getAccountMeta(accounts.userFundAccount),
+      getAccountMeta(accounts.fundWithdrawalRequestAccount),
       getAccountMeta(accounts.rewardAccount),
       getAccountMeta(accounts.userRewardAccount),
       getAccountMeta(accounts.instructionsSysvar),
@@ -652,6 +685,7 @@ export function getUserRequestWithdrawalInstruction<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountUserFundAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountRewardAccount,
     TAccountUserRewardAccount,
     TAccountInstructionsSysvar,
@@ -675,11 +709,12 @@ export type ParsedUserRequestWithdrawalInstruction<
     fundAccount: TAccountMetas[6];
     fundReserveAccount: TAccountMetas[7];
     userFundAccount: TAccountMetas[8];
-    rewardAccount: TAccountMetas[9];
-    userRewardAccount: TAccountMetas[10];
-    instructionsSysvar: TAccountMetas[11];
-    eventAuthority: TAccountMetas[12];
-    program: TAccountMetas[13];
+    fundWithdrawalRequestAccount: TAccountMetas[9];
+    rewardAccount: TAccountMetas[10];
+    userRewardAccount: TAccountMetas[11];
+    instructionsSysvar: TAccountMetas[12];
+    eventAuthority: TAccountMetas[13];
+    program: TAccountMetas[14];
   };
   data: UserRequestWithdrawalInstructionData;
 };
@@ -692,7 +727,7 @@ export function parseUserRequestWithdrawalInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUserRequestWithdrawalInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -714,6 +749,7 @@ export function parseUserRequestWithdrawalInstruction<
       fundAccount: getNextAccount(),
       fundReserveAccount: getNextAccount(),
       userFundAccount: getNextAccount(),
+      fundWithdrawalRequestAccount: getNextAccount(),
       rewardAccount: getNextAccount(),
       userRewardAccount: getNextAccount(),
       instructionsSysvar: getNextAccount(),
@@ -68,6 +68,9 @@ export type UserWithdrawSolInstruction<
   TAccountFundWithdrawalBatchAccount extends
     | string
     | AccountMeta<string> = string,
+  TAccountFundWithdrawalRequestAccount extends
+    | string
+    | AccountMeta<string> = string,
   TAccountFundTreasuryAccount extends string | AccountMeta<string> = string,
   TAccountUserFundAccount extends string | AccountMeta<string> = string,
   TAccountRewardAccount extends string | AccountMeta<string> = string,
@@ -103,6 +106,9 @@ export type UserWithdrawSolInstruction<
       TAccountFundWithdrawalBatchAccount extends string
         ? WritableAccount<TAccountFundWithdrawalBatchAccount>
         : TAccountFundWithdrawalBatchAccount,
+      TAccountFundWithdrawalRequestAccount extends string
+        ? WritableAccount<TAccountFundWithdrawalRequestAccount>
+        : TAccountFundWithdrawalRequestAccount,
       TAccountFundTreasuryAccount extends string
         ? WritableAccount<TAccountFundTreasuryAccount>
         : TAccountFundTreasuryAccount,
@@ -174,6 +180,7 @@ export type UserWithdrawSolAsyncInput<
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
   TAccountFundWithdrawalBatchAccount extends string = string,
+  TAccountFundWithdrawalRequestAccount extends string = string,
   TAccountFundTreasuryAccount extends string = string,
   TAccountUserFundAccount extends string = string,
   TAccountRewardAccount extends string = string,
@@ -190,9 +197,10 @@ export type UserWithdrawSolAsyncInput<
   fundReserveAccount?: Address<TAccountFundReserveAccount>;
   /**
    * Users can derive proper account address with target batch id for each withdrawal requests.
-   * And the batch id can be read from a user fund account which the withdrawal requests belong to.
+   * And the batch id can be read from the withdrawal request account.
    */
   fundWithdrawalBatchAccount?: Address<TAccountFundWithdrawalBatchAccount>;
+  fundWithdrawalRequestAccount?: Address<TAccountFundWithdrawalRequestAccount>;
   fundTreasuryAccount?: Address<TAccountFundTreasuryAccount>;
   userFundAccount?: Address<TAccountUserFundAccount>;
   rewardAccount?: Address<TAccountRewardAccount>;
@@ -212,6 +220,7 @@ export async function getUserWithdrawSolInstructionAsync<
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
   TAccountFundWithdrawalBatchAccount extends string,
+  TAccountFundWithdrawalRequestAccount extends string,
   TAccountFundTreasuryAccount extends string,
   TAccountUserFundAccount extends string,
   TAccountRewardAccount extends string,
@@ -229,6 +238,7 @@ export async function getUserWithdrawSolInstructionAsync<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountFundWithdrawalBatchAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountFundTreasuryAccount,
     TAccountUserFundAccount,
     TAccountRewardAccount,
@@ -248,6 +258,7 @@ export async function getUserWithdrawSolInstructionAsync<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountFundWithdrawalBatchAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountFundTreasuryAccount,
     TAccountUserFundAccount,
     TAccountRewardAccount,
@@ -284,6 +295,10 @@ export async function getUserWithdrawSolInstructionAsync<
       value: input.fundWithdrawalBatchAccount ?? null,
       isWritable: true,
     },
+    fundWithdrawalRequestAccount: {
+      value: input.fundWithdrawalRequestAccount ?? null,
+      isWritable: true,
+    },
     fundTreasuryAccount: {
       value: input.fundTreasuryAccount ?? null,
       isWritable: true,
@@ -378,6 +393,30 @@ export async function getUserWithdrawSolInstructionAsync<
       ],
     });
   }
+  if (!accounts.fundWithdrawalRequestAccount.value) {
+    accounts.fundWithdrawalRequestAccount.value =
+      await getProgramDerivedAddress({
+        programAddress,
+        seeds: [
+          getBytesEncoder().encode(
+            new Uint8Array([
+              119, 105, 116, 104, 100, 114, 97, 119, 97, 108, 95, 114, 101, 113,
+              117, 101, 115, 116,
+            ])
+          ),
+          getAddressEncoder().encode(
+            expectAddress(accounts.receiptTokenMint.value)
+          ),
+          getBytesEncoder().encode(
+            new Uint8Array([
+              0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+              0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            ])
+          ),
+          getU64Encoder().encode(expectSome(args.requestId)),
+        ],
+      });
+  }
   if (!accounts.fundTreasuryAccount.value) {
     accounts.fundTreasuryAccount.value = await getProgramDerivedAddress({
       programAddress,
@@ -457,6 +496,7 @@ export async function getUserWithdrawSolInstructionAsync<
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
       getAccountMeta(accounts.fundWithdrawalBatchAccount),
+      getAccountMeta(accounts.fundWithdrawalRequestAccount),
       getAccountMeta(accounts.fundTreasuryAccount),
       getAccountMeta(accounts.userFundAccount),
       getAccountMeta(accounts.rewardAccount),
@@ -478,6 +518,7 @@ export async function getUserWithdrawSolInstructionAsync<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountFundWithdrawalBatchAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountFundTreasuryAccount,
     TAccountUserFundAccount,
     TAccountRewardAccount,
@@ -496,6 +537,7 @@ export type UserWithdrawSolInput<
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
   TAccountFundWithdrawalBatchAccount extends string = string,
+  TAccountFundWithdrawalRequestAccount extends string = string,
   TAccountFundTreasuryAccount extends string = string,
   TAccountUserFundAccount extends string = string,
   TAccountRewardAccount extends string = string,
@@ -512,9 +554,10 @@ export type UserWithdrawSolInput<
   fundReserveAccount: Address<TAccountFundReserveAccount>;
   /**
    * Users can derive proper account address with target batch id for each withdrawal requests.
-   * And the batch id can be read from a user fund account which the withdrawal requests belong to.
+   * And the batch id can be read from the withdrawal request account.
    */
   fundWithdrawalBatchAccount: Address<TAccountFundWithdrawalBatchAccount>;
+  fundWithdrawalRequestAccount: Address<TAccountFundWithdrawalRequestAccount>;
   fundTreasuryAccount: Address<TAccountFundTreasuryAccount>;
   userFundAccount: Address<TAccountUserFundAccount>;
   rewardAccount: Address<TAccountRewardAccount>;
@@ -534,6 +577,7 @@ export function getUserWithdrawSolInstruction<
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
   TAccountFundWithdrawalBatchAccount extends string,
+  TAccountFundWithdrawalRequestAccount extends string,
   TAccountFundTreasuryAccount extends string,
   TAccountUserFundAccount extends string,
   TAccountRewardAccount extends string,
@@ -551,6 +595,7 @@ export function getUserWithdrawSolInstruction<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountFundWithdrawalBatchAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountFundTreasuryAccount,
     TAccountUserFundAccount,
     TAccountRewardAccount,
@@ -569,6 +614,7 @@ export function getUserWithdrawSolInstruction<
   TAccountFundAccount,
   TAccountFundReserveAccount,
   TAccountFundWithdrawalBatchAccount,
+  TAccountFundWithdrawalRequestAccount,
   TAccountFundTreasuryAccount,
   TAccountUserFundAccount,
   TAccountRewardAccount,
@@ -604,6 +650,10 @@ export function getUserWithdrawSolInstruction<
       value: input.fundWithdrawalBatchAccount ?? null,
       isWritable: true,
     },
+    fundWithdrawalRequestAccount: {
+      value: input.fundWithdrawalRequestAccount ?? null,
+      isWritable: true,
+    },
     fundTreasuryAccount: {
       value: input.fundTreasuryAccount ?? null,
       isWritable: true,
@@ -646,6 +696,7 @@ export function getUserWithdrawSolInstruction<
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
       getAccountMeta(accounts.fundWithdrawalBatchAccount),
+      getAccountMeta(accounts.fundWithdrawalRequestAccount),
       getAccountMeta(accounts.fundTreasuryAccount),
       getAccountMeta(accounts.userFundAccount),
       getAccountMeta(accounts.rewardAccount),
@@ -667,6 +718,7 @@ export function getUserWithdrawSolInstruction<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountFundWithdrawalBatchAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountFundTreasuryAccount,
     TAccountUserFundAccount,
     TAccountRewardAccount,
@@ -691,15 +743,16 @@ export type ParsedUserWithdrawSolInstruction<
     fundReserveAccount: TAccountMetas[6];
     /**
      * Users can derive proper account address with target batch id for each withdrawal requests.
-     * And the batch id can be read from a user fund account which the withdrawal requests belong to.
+     * And the batch id can be read from the withdrawal request account.
      */
     fundWithdrawalBatchAccount: TAccountMetas[7];
-    fundTreasuryAccount: TAccountMetas[8];
-    userFundAccount: TAccountMetas[9];
-    rewardAccount: TAccountMetas[10];
-    userRewardAccount: TAccountMetas[11];
-    eventAuthority: TAccountMetas[12];
-    program: TAccountMetas[13];
+    fundWithdrawalRequestAccount: TAccountMetas[8];
+    fundTreasuryAccount: TAccountMetas[9];
+    userFundAccount: TAccountMetas[10];
+    rewardAccount: TAccountMetas[11];
+    userRewardAccount: TAccountMetas[12];
+    eventAuthority: TAccountMetas[13];
+    program: TAccountMetas[14];
   };
   data: UserWithdrawSolInstructionData;
 };
@@ -712,7 +765,7 @@ export function parseUserWithdrawSolInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUserWithdrawSolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -733,6 +786,7 @@ export function parseUserWithdrawSolInstruction<
       fundAccount: getNextAccount(),
       fundReserveAccount: getNextAccount(),
       fundWithdrawalBatchAccount: getNextAccount(),
+      fundWithdrawalRequestAccount: getNextAccount(),
       fundTreasuryAccount: getNextAccount(),
       userFundAccount: getNextAccount(),
       rewardAccount: getNextAccount(),
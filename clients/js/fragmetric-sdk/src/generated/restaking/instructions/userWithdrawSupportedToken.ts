@@ -29,10 +29,10 @@ import {
   type InstructionWithAccounts,
   type InstructionWithData,
   type ReadonlyAccount,
-  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { RESTAKING_PROGRAM_ADDRESS } from '../programs';
 import {
@@ -73,6 +73,9 @@ export type UserWithdrawSupportedTokenInstruction<
   TAccountFundWithdrawalBatchAccount extends
     | string
     | AccountMeta<string> = string,
+  TAccountFundWithdrawalRequestAccount extends
+    | string
+    | AccountMeta<string> = string,
   TAccountFundSupportedTokenReserveAccount extends
     | string
     | AccountMeta<string> = string,
@@ -91,7 +94,7 @@ export type UserWithdrawSupportedTokenInstruction<
   InstructionWithAccounts<
     [
       TAccountUser extends string
-        ? ReadonlySignerAccount<TAccountUser> & AccountSignerMeta<TAccountUser>
+        ? WritableSignerAccount<TAccountUser> & AccountSignerMeta<TAccountUser>
         : TAccountUser,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
@@ -123,6 +126,9 @@ export type UserWithdrawSupportedTokenInstruction<
       TAccountFundWithdrawalBatchAccount extends string
         ? WritableAccount<TAccountFundWithdrawalBatchAccount>
         : TAccountFundWithdrawalBatchAccount,
+      TAccountFundWithdrawalRequestAccount extends string
+        ? WritableAccount<TAccountFundWithdrawalRequestAccount>
+        : TAccountFundWithdrawalRequestAccount,
       TAccountFundSupportedTokenReserveAccount extends string
         ? WritableAccount<TAccountFundSupportedTokenReserveAccount>
         : TAccountFundSupportedTokenReserveAccount,
@@ -206,6 +212,7 @@ export type UserWithdrawSupportedTokenAsyncInput<
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
   TAccountFundWithdrawalBatchAccount extends string = string,
+  TAccountFundWithdrawalRequestAccount extends string = string,
   TAccountFundSupportedTokenReserveAccount extends string = string,
   TAccountFundTreasuryAccount extends string = string,
   TAccountUserFundAccount extends string = string,
@@ -227,9 +234,10 @@ export type UserWithdrawSupportedTokenAsyncInput<
   fundReserveAccount?: Address<TAccountFundReserveAccount>;
   /**
    * Users can derive proper account address with target batch id for each withdrawal requests.
-   * And the batch id can be read from a user fund account which the withdrawal requests belong to.
+   * And the batch id can be read from the withdrawal request account.
    */
   fundWithdrawalBatchAccount?: Address<TAccountFundWithdrawalBatchAccount>;
+  fundWithdrawalRequestAccount?: Address<TAccountFundWithdrawalRequestAccount>;
   fundSupportedTokenReserveAccount?: Address<TAccountFundSupportedTokenReserveAccount>;
   fundTreasuryAccount?: Address<TAccountFundTreasuryAccount>;
   userFundAccount?: Address<TAccountUserFundAccount>;
@@ -254,6 +262,7 @@ export async function getUserWithdrawSupportedTokenInstructionAsync<
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
   TAccountFundWithdrawalBatchAccount extends string,
+  TAccountFundWithdrawalRequestAccount extends string,
   TAccountFundSupportedTokenReserveAccount extends string,
   TAccountFundTreasuryAccount extends string,
   TAccountUserFundAccount extends string,
@@ -276,6 +285,7 @@ export async function getUserWithdrawSupportedTokenInstructionAsync<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountFundWithdrawalBatchAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountFundSupportedTokenReserveAccount,
     TAccountFundTreasuryAccount,
     TAccountUserFundAccount,
@@ -300,6 +310,7 @@ export async function getUserWithdrawSupportedTokenInstructionAsync<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountFundWithdrawalBatchAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountFundSupportedTokenReserveAccount,
     TAccountFundTreasuryAccount,
     TAccountUserFundAccount,
@@ -315,7 +326,7 @@ export async function getUserWithdrawSupportedTokenInstructionAsync<
 
   // Original accounts.
   const originalAccounts = {
-    user: { value: input.user ?? null, isWritable: false },
+    user: { value: input.user ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenProgram: {
       value: input.receiptTokenProgram ?? null,
@@ -350,6 +361,10 @@ export async function getUserWithdrawSupportedTokenInstructionAsync<
       value: input.fundWithdrawalBatchAccount ?? null,
       isWritable: true,
     },
+    fundWithdrawalRequestAccount: {
+      value: input.fundWithdrawalRequestAccount ?? null,
+      isWritable: true,
+    },
     fundSupportedTokenReserveAccount: {
       value: input.fundSupportedTokenReserveAccount ?? null,
       isWritable: true,
@@ -449,6 +464,27 @@ export async function getUserWithdrawSupportedTokenInstructionAsync<
       ],
     });
   }
+  if (!accounts.fundWithdrawalRequestAccount.value) {
+    accounts.fundWithdrawalRequestAccount.value =
+      await getProgramDerivedAddress({
+        programAddress,
+        seeds: [
+          getBytesEncoder().encode(
+            new Uint8Array([
+              119, 105, 116, 104, 100, 114, 97, 119, 97, 108, 95, 114, 101, 113,
+              117, 101, 115, 116,
+            ])
+          ),
+          getAddressEncoder().encode(
+            expectAddress(accounts.receiptTokenMint.value)
+          ),
+          getAddressEncoder().encode(
+            expectAddress(accounts.supportedTokenMint.value)
+          ),
+          getU64Encoder().encode(expectSome(args.requestId)),
+        ],
+      });
+  }
   if (!accounts.fundSupportedTokenReserveAccount.value) {
     accounts.fundSupportedTokenReserveAccount.value =
       await getProgramDerivedAddress({
@@ -553,6 +589,7 @@ export async function getUserWithdrawSupportedTokenInstructionAsync<
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
       getAccountMeta(accounts.fundWithdrawalBatchAccount),
+      getAccountMeta(accounts.fundWithdrawalRequestAccount),
       getAccountMeta(accounts.fundSupportedTokenReserveAccount),
       getAccountMeta(accounts.fundTreasuryAccount),
       getAccountMeta(accounts.userFundAccount),
@@ -579,6 +616,7 @@ export async function getUserWithdrawSupportedTokenInstructionAsync<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountFundWithdrawalBatchAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountFundSupportedTokenReserveAccount,
     TAccountFundTreasuryAccount,
     TAccountUserFundAccount,
@@ -602,6 +640,7 @@ export type UserWithdrawSupportedTokenInput<
   TAccountFundAccount extends string = string,
   TAccountFundReserveAccount extends string = string,
   TAccountFundWithdrawalBatchAccount extends string = string,
+  TAccountFundWithdrawalRequestAccount extends string = string,
   TAccountFundSupportedTokenReserveAccount extends string = string,
   TAccountFundTreasuryAccount extends string = string,
   TAccountUserFundAccount extends string = string,
@@ -623,9 +662,10 @@ export type UserWithdrawSupportedTokenInput<
   fundReserveAccount: Address<TAccountFundReserveAccount>;
   /**
    * Users can derive proper account address with target batch id for each withdrawal requests.
-   * And the batch id can be read from a user fund account which the withdrawal requests belong to.
+   * And the batch id can be read from the withdrawal request account.
    */
   fundWithdrawalBatchAccount: Address<TAccountFundWithdrawalBatchAccount>;
+  fundWithdrawalRequestAccount: Address<TAccountFundWithdrawalRequestAccount>;
   fundSupportedTokenReserveAccount: Address<TAccountFundSupportedTokenReserveAccount>;
   fundTreasuryAccount: Address<TAccountFundTreasuryAccount>;
   userFundAccount: Address<TAccountUserFundAccount>;
@@ -650,6 +690,7 @@ export function getUserWithdrawSupportedTokenInstruction<
   TAccountFundAccount extends string,
   TAccountFundReserveAccount extends string,
   TAccountFundWithdrawalBatchAccount extends string,
+  TAccountFundWithdrawalRequestAccount extends string,
   TAccountFundSupportedTokenReserveAccount extends string,
   TAccountFundTreasuryAccount extends string,
   TAccountUserFundAccount extends string,
@@ -672,6 +713,7 @@ export function getUserWithdrawSupportedTokenInstruction<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountFundWithdrawalBatchAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountFundSupportedTokenReserveAccount,
     TAccountFundTreasuryAccount,
     TAccountUserFundAccount,
@@ -695,6 +737,7 @@ export function getUserWithdrawSupportedTokenInstruction<
   TAccountFundAccount,
   TAccountFundReserveAccount,
   TAccountFundWithdrawalBatchAccount,
+  TAccountFundWithdrawalRequestAccount,
   TAccountFundSupportedTokenReserveAccount,
   TAccountFundTreasuryAccount,
   TAccountUserFundAccount,
@@ -709,7 +752,7 @@ export function getUserWithdrawSupportedTokenInstruction<
 
   // Original accounts.
   const originalAccounts = {
-    user: { value: input.user ?? null, isWritable: false },
+    user: { value: input.user ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     receiptTokenProgram: {
       value: input.receiptTokenProgram ?? null,
@@ -744,6 +787,10 @@ export function getUserWithdrawSupportedTokenInstruction<
       value: input.fundWithdrawalBatchAccount ?? null,
       isWritable: true,
     },
+    fundWithdrawalRequestAccount: {
+      value: input.fundWithdrawalRequestAccount ?? null,
+      isWritable: true,
+    },
     fundSupportedTokenReserveAccount: {
       value: input.fundSupportedTokenReserveAccount ?? null,
       isWritable: true,
@@ -801,6 +848,7 @@ export function getUserWithdrawSupportedTokenInstruction<
       getAccountMeta(accounts.fundAccount),
       getAccountMeta(accounts.fundReserveAccount),
       getAccountMeta(accounts.fundWithdrawalBatchAccount),
+      getAccountMeta(accounts.fundWithdrawalRequestAccount),
       getAccountMeta(accounts.fundSupportedTokenReserveAccount),
       getAccountMeta(accounts.fundTreasuryAccount),
       getAccountMeta(accounts.userFundAccount),
@@ -827,6 +875,7 @@ export function getUserWithdrawSupportedTokenInstruction<
     TAccountFundAccount,
     TAccountFundReserveAccount,
     TAccountFundWithdrawalBatchAccount,
+    TAccountFundWithdrawalRequestAccount,
     TAccountFundSupportedTokenReserveAccount,
     TAccountFundTreasuryAccount,
     TAccountUserFundAccount,
@@ -856,17 +905,18 @@ export type ParsedUserWithdrawSupportedTokenInstruction<
     fundReserveAccount: TAccountMetas[9];
     /**
      * Users can derive proper account address with target batch id for each withdrawal requests.
-     * And the batch id can be read from a user fund account which the withdrawal requests belong to.
+     * And the batch id can be read from the withdrawal request account.
      */
     fundWithdrawalBatchAccount: TAccountMetas[10];
-    fundSupportedTokenReserveAccount: TAccountMetas[11];
-    fundTreasuryAccount: TAccountMetas[12];
-    userFundAccount: TAccountMetas[13];
-    rewardAccount: TAccountMetas[14];
-    userRewardAccount: TAccountMetas[15];
-    instructionsSysvar: TAccountMetas[16];
-    eventAuthority: TAccountMetas[17];
-    program: TAccountMetas[18];
+    fundWithdrawalRequestAccount: TAccountMetas[11];
+    fundSupportedTokenReserveAccount: TAccountMetas[12];
+    fundTreasuryAccount: TAccountMetas[13];
+    userFundAccount: TAccountMetas[14];
+    rewardAccount: TAccountMetas[15];
+    userRewardAccount: TAccountMetas[16];
+    instructionsSysvar: TAccountMetas[17];
+    eventAuthority: TAccountMetas[18];
+    program: TAccountMetas[19];
   };
   data: UserWithdrawSupportedTokenInstructionData;
 };
@@ -879,7 +929,7 @@ export function parseUserWithdrawSupportedTokenInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUserWithdrawSupportedTokenInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 19) {
+  if (instruction.accounts.length < 20) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -903,6 +953,7 @@ export function parseUserWithdrawSupportedTokenInstruction<
       fundAccount: getNextAccount(),
       fundReserveAccount: getNextAccount(),
       fundWithdrawalBatchAccount: getNextAccount(),
+      fundWithdrawalRequestAccount: getNextAccount(),
       fundSupportedTokenReserveAccount: getNextAccount(),
       fundTreasuryAccount: getNextAccount(),
       userFundAccount: getNextAccount(),
@@ -14,28 +14,36 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAdminCloseFundOperationHistoryAccountInstruction,
   type ParsedAdminCreateUserRewardAccountIdempotentInstruction,
   type ParsedAdminInitializeExtraAccountMetaListInstruction,
   type ParsedAdminInitializeFundAccountInstruction,
   type ParsedAdminInitializeNormalizedTokenPoolAccountInstruction,
   type ParsedAdminInitializeRewardAccountInstruction,
+  type ParsedAdminInitializeRoleAccountInstruction,
+  type ParsedAdminProposeRoleTransferInstruction,
   type ParsedAdminSetAddressLookupTableAccountInstruction,
   type ParsedAdminUpdateExtraAccountMetaListIfNeededInstruction,
   type ParsedAdminUpdateFundAccountIfNeededInstruction,
   type ParsedAdminUpdateNormalizedTokenPoolAccountIfNeededInstruction,
   type ParsedAdminUpdateRewardAccountIfNeededInstruction,
+  type ParsedFundManagerAcknowledgePriceDeviationInstruction,
   type ParsedFundManagerAddNormalizedTokenPoolSupportedTokenInstruction,
+  type ParsedFundManagerAddOperatorInstruction,
   type ParsedFundManagerAddRestakingVaultCompoundingRewardTokenInstruction,
   type ParsedFundManagerAddRestakingVaultDistributingRewardTokenInstruction,
   type ParsedFundManagerAddRewardInstruction,
   type ParsedFundManagerAddSupportedTokenInstruction,
   type ParsedFundManagerAddTokenSwapStrategyInstruction,
   type ParsedFundManagerAddWrappedTokenHolderInstruction,
+  type ParsedFundManagerExecuteFundConfigurationChangeInstruction,
   type ParsedFundManagerInitializeFundNormalizedTokenInstruction,
   type ParsedFundManagerInitializeFundRestakingVaultDelegationInstruction,
   type ParsedFundManagerInitializeFundRestakingVaultInstruction,
   type ParsedFundManagerInitializeFundWrappedTokenInstruction,
+  type ParsedFundManagerQueueFundConfigurationChangeInstruction,
   type ParsedFundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction,
+  type ParsedFundManagerRemoveOperatorInstruction,
   type ParsedFundManagerRemoveRestakingVaultCompoundingRewardTokenInstruction,
   type ParsedFundManagerRemoveRestakingVaultDistributingRewardTokenInstruction,
   type ParsedFundManagerRemoveSupportedTokenInstruction,
@@ -44,22 +52,43 @@ import {
   type ParsedFundManagerResetFundWrapAccountRewardAccountDelegateInstruction,
   type ParsedFundManagerResetWrappedTokenHolderRewardAccountDelegateInstruction,
   type ParsedFundManagerRevokeFundRewardTokenMintAuthorityInstruction,
+  type ParsedFundManagerSetOperatorAllowlistEnabledInstruction,
   type ParsedFundManagerSettleRewardInstruction,
   type ParsedFundManagerUpdateFundStrategyInstruction,
+  type ParsedFundManagerUpdateInKindWithdrawalStrategyInstruction,
+  type ParsedFundManagerUpdateInstantWithdrawalStrategyInstruction,
+  type ParsedFundManagerUpdateManagementFeeRateInstruction,
+  type ParsedFundManagerUpdateMaxPriceAgeSlotsInstruction,
+  type ParsedFundManagerUpdateOperationCommandPolicyInstruction,
+  type ParsedFundManagerUpdateOperationFallbackCommandInstruction,
+  type ParsedFundManagerUpdatePriceDeviationThresholdInstruction,
+  type ParsedFundManagerUpdateReconciliationThresholdInstruction,
   type ParsedFundManagerUpdateRestakingVaultDelegationStrategyInstruction,
   type ParsedFundManagerUpdateRestakingVaultRewardTokenHarvestThresholdInstruction,
   type ParsedFundManagerUpdateRestakingVaultStrategyInstruction,
+  type ParsedFundManagerUpdateRevenueSplitInstruction,
   type ParsedFundManagerUpdateRewardInstruction,
   type ParsedFundManagerUpdateSolStrategyInstruction,
+  type ParsedFundManagerUpdateSupportedTokenPriceDeviationThresholdInstruction,
   type ParsedFundManagerUpdateSupportedTokenStrategyInstruction,
+  type ParsedFundManagerUpdateTokenSwapStrategyInstruction,
+  type ParsedGuardianCancelFundConfigurationChangeInstruction,
   type ParsedOperatorClaimRemainingRewardInstruction,
   type ParsedOperatorDonateSolToFundInstruction,
   type ParsedOperatorDonateSupportedTokenToFundInstruction,
+  type ParsedOperatorInitializeFundOperationHistoryAccountInstruction,
   type ParsedOperatorLogMessageInstruction,
+  type ParsedOperatorPlanFundCommandsInstruction,
+  type ParsedOperatorReconcileFundInstruction,
   type ParsedOperatorRunFundCommandInstruction,
   type ParsedOperatorUpdateFundPricesInstruction,
   type ParsedOperatorUpdateNormalizedTokenPoolPricesInstruction,
   type ParsedOperatorUpdateRewardPoolsInstruction,
+  type ParsedQuoteDepositInstruction,
+  type ParsedQuoteReceiptTokenTwapInstruction,
+  type ParsedQuoteWithdrawalInstruction,
+  type ParsedQuoteWrapInstruction,
+  type ParsedRoleAuthorityAcceptRoleTransferInstruction,
   type ParsedSlasherInitializeNormalizedTokenWithdrawalAccountInstruction,
   type ParsedSlasherWithdrawNormalizedTokenInstruction,
   type ParsedTokenTransferHookInstruction,
@@ -73,10 +102,15 @@ import {
   type ParsedUserDepositSolInstruction,
   type ParsedUserDepositSupportedTokenInstruction,
   type ParsedUserDepositVaultReceiptTokenInstruction,
+  type ParsedUserMigrateWithdrawalRequestsInstruction,
   type ParsedUserRequestWithdrawalInstruction,
+  type ParsedUserTransferWithdrawalRequestInstruction,
   type ParsedUserUnwrapReceiptTokenInstruction,
   type ParsedUserUpdateRewardPoolsInstruction,
+  type ParsedUserWithdrawInKindInstruction,
+  type ParsedUserWithdrawInstantlyInstruction,
   type ParsedUserWithdrawSolInstruction,
+  type ParsedUserWithdrawSupportedTokenInstantlyInstruction,
   type ParsedUserWithdrawSupportedTokenInstruction,
   type ParsedUserWrapReceiptTokenIfNeededInstruction,
   type ParsedUserWrapReceiptTokenInstruction,
@@ -87,19 +121,31 @@ export const RESTAKING_PROGRAM_ADDRESS =
 
 export enum RestakingAccount {
   FundAccount,
+  FundConfigurationChangeAccount,
+  FundOperationHistoryAccount,
   FundWithdrawalBatchAccount,
+  FundWithdrawalRequestAccount,
   NormalizedTokenPoolAccount,
   NormalizedTokenWithdrawalAccount,
   RewardAccount,
+  RoleAccount,
   UserFundAccount,
   UserRewardAccount,
+  AdminProposedRoleTransfer,
+  FundManagerExecutedFundConfigurationChange,
+  FundManagerQueuedFundConfigurationChange,
   FundManagerUpdatedFund,
   FundManagerUpdatedRewardPool,
+  GuardianCanceledFundConfigurationChange,
+  OperatorClaimedRemainingReward,
   OperatorDonatedToFund,
   OperatorRanFundCommand,
+  OperatorReconciledFund,
+  OperatorTrippedFundPriceCircuitBreaker,
   OperatorUpdatedFundPrices,
   OperatorUpdatedNormalizedTokenPoolPrices,
   OperatorUpdatedRewardPools,
+  RoleAuthorityAcceptedRoleTransfer,
   UserCanceledWithdrawalRequestFromFund,
   UserClaimedReward,
   UserClosedFundAccount,
@@ -109,11 +155,15 @@ export enum RestakingAccount {
   UserDelegatedRewardAccount,
   UserDepositedToFund,
   UserDepositedToVault,
+  UserMigratedFundWithdrawalRequests,
   UserRequestedWithdrawalFromFund,
+  UserTransferredFundWithdrawalRequest,
   UserTransferredReceiptToken,
   UserUnwrappedReceiptToken,
   UserUpdatedRewardPool,
   UserWithdrewFromFund,
+  UserWithdrewInKindFromFund,
+  UserWithdrewInstantlyFromFund,
   UserWrappedReceiptToken,
 }
 
@@ -132,6 +182,28 @@ export function identifyRestakingAccount(
   ) {
     return RestakingAccount.FundAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([173, 134, 196, 100, 111, 57, 214, 67])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.FundConfigurationChangeAccount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([222, 114, 54, 94, 122, 250, 147, 24])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.FundOperationHistoryAccount;
+  }
   if (
     containsBytes(
       data,
@@ -143,6 +215,17 @@ export function identifyRestakingAccount(
   ) {
     return RestakingAccount.FundWithdrawalBatchAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([102, 149, 119, 14, 98, 68, 131, 187])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.FundWithdrawalRequestAccount;
+  }
   if (
     containsBytes(
       data,
@@ -176,6 +259,17 @@ export function identifyRestakingAccount(
   ) {
     return RestakingAccount.RewardAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([142, 236, 135, 197, 214, 3, 244, 226])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.RoleAccount;
+  }
   if (
     containsBytes(
       data,
@@ -198,6 +292,39 @@ export function identifyRestakingAccount(
   ) {
     return RestakingAccount.UserRewardAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([93, 98, 138, 176, 11, 20, 23, 35])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.AdminProposedRoleTransfer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([17, 167, 127, 156, 81, 211, 54, 59])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.FundManagerExecutedFundConfigurationChange;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([139, 98, 228, 79, 244, 42, 159, 18])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.FundManagerQueuedFundConfigurationChange;
+  }
   if (
     containsBytes(
       data,
@@ -220,6 +347,28 @@ export function identifyRestakingAccount(
   ) {
     return RestakingAccount.FundManagerUpdatedRewardPool;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([35, 203, 171, 192, 2, 13, 159, 85])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.GuardianCanceledFundConfigurationChange;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([181, 141, 36, 87, 76, 218, 10, 140])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.OperatorClaimedRemainingReward;
+  }
   if (
     containsBytes(
       data,
@@ -242,6 +391,28 @@ export function identifyRestakingAccount(
   ) {
     return RestakingAccount.OperatorRanFundCommand;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([82, 233, 154, 191, 6, 34, 49, 132])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.OperatorReconciledFund;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([188, 232, 52, 84, 56, 195, 89, 11])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.OperatorTrippedFundPriceCircuitBreaker;
+  }
   if (
     containsBytes(
       data,
@@ -275,6 +446,17 @@ export function identifyRestakingAccount(
   ) {
     return RestakingAccount.OperatorUpdatedRewardPools;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([63, 35, 155, 56, 187, 227, 28, 75])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.RoleAuthorityAcceptedRoleTransfer;
+  }
   if (
     containsBytes(
       data,
@@ -374,6 +556,17 @@ export function identifyRestakingAccount(
   ) {
     return RestakingAccount.UserDepositedToVault;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([39, 103, 58, 249, 21, 234, 70, 142])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.UserMigratedFundWithdrawalRequests;
+  }
   if (
     containsBytes(
       data,
@@ -385,6 +578,17 @@ export function identifyRestakingAccount(
   ) {
     return RestakingAccount.UserRequestedWithdrawalFromFund;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([72, 78, 19, 81, 17, 80, 147, 79])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.UserTransferredFundWithdrawalRequest;
+  }
   if (
     containsBytes(
       data,
@@ -429,6 +633,28 @@ export function identifyRestakingAccount(
   ) {
     return RestakingAccount.UserWithdrewFromFund;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([185, 34, 219, 132, 151, 250, 3, 145])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.UserWithdrewInKindFromFund;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([255, 212, 237, 173, 100, 195, 135, 105])
+      ),
+      0
+    )
+  ) {
+    return RestakingAccount.UserWithdrewInstantlyFromFund;
+  }
   if (
     containsBytes(
       data,
@@ -446,28 +672,36 @@ export function identifyRestakingAccount(
 }
 
 export enum RestakingInstruction {
+  AdminCloseFundOperationHistoryAccount,
   AdminCreateUserRewardAccountIdempotent,
   AdminInitializeExtraAccountMetaList,
   AdminInitializeFundAccount,
   AdminInitializeNormalizedTokenPoolAccount,
   AdminInitializeRewardAccount,
+  AdminInitializeRoleAccount,
+  AdminProposeRoleTransfer,
   AdminSetAddressLookupTableAccount,
   AdminUpdateExtraAccountMetaListIfNeeded,
   AdminUpdateFundAccountIfNeeded,
   AdminUpdateNormalizedTokenPoolAccountIfNeeded,
   AdminUpdateRewardAccountIfNeeded,
+  FundManagerAcknowledgePriceDeviation,
   FundManagerAddNormalizedTokenPoolSupportedToken,
+  FundManagerAddOperator,
   FundManagerAddRestakingVaultCompoundingRewardToken,
   FundManagerAddRestakingVaultDistributingRewardToken,
   FundManagerAddReward,
   FundManagerAddSupportedToken,
   FundManagerAddTokenSwapStrategy,
   FundManagerAddWrappedTokenHolder,
+  FundManagerExecuteFundConfigurationChange,
   FundManagerInitializeFundNormalizedToken,
   FundManagerInitializeFundRestakingVault,
   FundManagerInitializeFundRestakingVaultDelegation,
   FundManagerInitializeFundWrappedToken,
+  FundManagerQueueFundConfigurationChange,
   FundManagerRemoveNormalizedTokenPoolSupportedToken,
+  FundManagerRemoveOperator,
   FundManagerRemoveRestakingVaultCompoundingRewardToken,
   FundManagerRemoveRestakingVaultDistributingRewardToken,
   FundManagerRemoveSupportedToken,
@@ -476,22 +710,43 @@ export enum RestakingInstruction {
   FundManagerResetFundWrapAccountRewardAccountDelegate,
   FundManagerResetWrappedTokenHolderRewardAccountDelegate,
   FundManagerRevokeFundRewardTokenMintAuthority,
+  FundManagerSetOperatorAllowlistEnabled,
   FundManagerSettleReward,
   FundManagerUpdateFundStrategy,
+  FundManagerUpdateInKindWithdrawalStrategy,
+  FundManagerUpdateInstantWithdrawalStrategy,
+  FundManagerUpdateManagementFeeRate,
+  FundManagerUpdateMaxPriceAgeSlots,
+  FundManagerUpdateOperationCommandPolicy,
+  FundManagerUpdateOperationFallbackCommand,
+  FundManagerUpdatePriceDeviationThreshold,
+  FundManagerUpdateReconciliationThreshold,
   FundManagerUpdateRestakingVaultDelegationStrategy,
   FundManagerUpdateRestakingVaultRewardTokenHarvestThreshold,
   FundManagerUpdateRestakingVaultStrategy,
+  FundManagerUpdateRevenueSplit,
   FundManagerUpdateReward,
   FundManagerUpdateSolStrategy,
+  FundManagerUpdateSupportedTokenPriceDeviationThreshold,
   FundManagerUpdateSupportedTokenStrategy,
+  FundManagerUpdateTokenSwapStrategy,
+  GuardianCancelFundConfigurationChange,
   OperatorClaimRemainingReward,
   OperatorDonateSolToFund,
   OperatorDonateSupportedTokenToFund,
+  OperatorInitializeFundOperationHistoryAccount,
   OperatorLogMessage,
+  OperatorPlanFundCommands,
+  OperatorReconcileFund,
   OperatorRunFundCommand,
   OperatorUpdateFundPrices,
   OperatorUpdateNormalizedTokenPoolPrices,
   OperatorUpdateRewardPools,
+  QuoteDeposit,
+  QuoteReceiptTokenTwap,
+  QuoteWithdrawal,
+  QuoteWrap,
+  RoleAuthorityAcceptRoleTransfer,
   SlasherInitializeNormalizedTokenWithdrawalAccount,
   SlasherWithdrawNormalizedToken,
   TokenTransferHook,
@@ -505,11 +760,16 @@ export enum RestakingInstruction {
   UserDepositSol,
   UserDepositSupportedToken,
   UserDepositVaultReceiptToken,
+  UserMigrateWithdrawalRequests,
   UserRequestWithdrawal,
+  UserTransferWithdrawalRequest,
   UserUnwrapReceiptToken,
   UserUpdateRewardPools,
+  UserWithdrawInKind,
+  UserWithdrawInstantly,
   UserWithdrawSol,
   UserWithdrawSupportedToken,
+  UserWithdrawSupportedTokenInstantly,
   UserWrapReceiptToken,
   UserWrapReceiptTokenIfNeeded,
 }
@@ -518,6 +778,17 @@ export function identifyRestakingInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): RestakingInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([131, 197, 117, 88, 211, 148, 86, 76])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.AdminCloseFundOperationHistoryAccount;
+  }
   if (
     containsBytes(
       data,
@@ -573,6 +844,28 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.AdminInitializeRewardAccount;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([80, 14, 99, 26, 75, 86, 254, 49])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.AdminInitializeRoleAccount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([226, 89, 63, 225, 57, 2, 21, 164])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.AdminProposeRoleTransfer;
+  }
   if (
     containsBytes(
       data,
@@ -628,6 +921,17 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.AdminUpdateRewardAccountIfNeeded;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([126, 50, 14, 81, 68, 253, 52, 165])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerAcknowledgePriceDeviation;
+  }
   if (
     containsBytes(
       data,
@@ -639,6 +943,17 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.FundManagerAddNormalizedTokenPoolSupportedToken;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([236, 97, 6, 11, 255, 198, 168, 32])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerAddOperator;
+  }
   if (
     containsBytes(
       data,
@@ -705,6 +1020,17 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.FundManagerAddWrappedTokenHolder;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([0, 21, 157, 39, 107, 101, 59, 50])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerExecuteFundConfigurationChange;
+  }
   if (
     containsBytes(
       data,
@@ -749,6 +1075,17 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.FundManagerInitializeFundWrappedToken;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([173, 215, 33, 182, 64, 151, 127, 213])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerQueueFundConfigurationChange;
+  }
   if (
     containsBytes(
       data,
@@ -760,6 +1097,17 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.FundManagerRemoveNormalizedTokenPoolSupportedToken;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([20, 192, 194, 73, 51, 192, 112, 40])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerRemoveOperator;
+  }
   if (
     containsBytes(
       data,
@@ -848,6 +1196,17 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.FundManagerRevokeFundRewardTokenMintAuthority;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([202, 105, 168, 197, 2, 207, 166, 136])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerSetOperatorAllowlistEnabled;
+  }
   if (
     containsBytes(
       data,
@@ -870,6 +1229,94 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.FundManagerUpdateFundStrategy;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([68, 170, 53, 244, 100, 128, 220, 53])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerUpdateInKindWithdrawalStrategy;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([85, 213, 2, 128, 230, 223, 8, 171])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerUpdateInstantWithdrawalStrategy;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([201, 205, 91, 20, 144, 95, 216, 71])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerUpdateManagementFeeRate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([219, 157, 108, 220, 102, 6, 226, 227])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerUpdateMaxPriceAgeSlots;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([194, 147, 78, 232, 140, 45, 135, 237])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerUpdateOperationCommandPolicy;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([23, 147, 58, 103, 114, 251, 214, 174])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerUpdateOperationFallbackCommand;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([21, 251, 122, 149, 81, 67, 43, 98])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerUpdatePriceDeviationThreshold;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([232, 203, 95, 188, 143, 65, 245, 133])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerUpdateReconciliationThreshold;
+  }
   if (
     containsBytes(
       data,
@@ -903,6 +1350,17 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.FundManagerUpdateRestakingVaultStrategy;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([22, 213, 220, 103, 45, 247, 85, 113])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerUpdateRevenueSplit;
+  }
   if (
     containsBytes(
       data,
@@ -925,6 +1383,17 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.FundManagerUpdateSolStrategy;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([107, 219, 4, 142, 199, 161, 121, 239])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerUpdateSupportedTokenPriceDeviationThreshold;
+  }
   if (
     containsBytes(
       data,
@@ -936,6 +1405,28 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.FundManagerUpdateSupportedTokenStrategy;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([217, 166, 76, 177, 171, 234, 228, 203])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.FundManagerUpdateTokenSwapStrategy;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([108, 80, 41, 159, 249, 112, 48, 187])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.GuardianCancelFundConfigurationChange;
+  }
   if (
     containsBytes(
       data,
@@ -969,6 +1460,17 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.OperatorDonateSupportedTokenToFund;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([131, 74, 46, 217, 17, 37, 109, 213])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.OperatorInitializeFundOperationHistoryAccount;
+  }
   if (
     containsBytes(
       data,
@@ -980,6 +1482,28 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.OperatorLogMessage;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([93, 0, 192, 121, 25, 56, 147, 211])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.OperatorPlanFundCommands;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([33, 105, 110, 105, 0, 93, 10, 104])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.OperatorReconcileFund;
+  }
   if (
     containsBytes(
       data,
@@ -1024,6 +1548,61 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.OperatorUpdateRewardPools;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([181, 208, 37, 150, 90, 54, 87, 204])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.QuoteDeposit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([185, 60, 30, 189, 3, 42, 252, 195])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.QuoteReceiptTokenTwap;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([119, 250, 77, 195, 34, 94, 208, 152])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.QuoteWithdrawal;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([110, 241, 142, 83, 75, 165, 199, 19])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.QuoteWrap;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([244, 141, 81, 140, 16, 161, 0, 94])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.RoleAuthorityAcceptRoleTransfer;
+  }
   if (
     containsBytes(
       data,
@@ -1167,6 +1746,17 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.UserDepositVaultReceiptToken;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([163, 78, 114, 102, 156, 170, 65, 34])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.UserMigrateWithdrawalRequests;
+  }
   if (
     containsBytes(
       data,
@@ -1178,6 +1768,17 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.UserRequestWithdrawal;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([164, 254, 92, 118, 59, 64, 165, 106])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.UserTransferWithdrawalRequest;
+  }
   if (
     containsBytes(
       data,
@@ -1200,6 +1801,28 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.UserUpdateRewardPools;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([155, 21, 119, 252, 129, 221, 195, 65])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.UserWithdrawInKind;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([178, 76, 24, 168, 95, 248, 216, 69])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.UserWithdrawInstantly;
+  }
   if (
     containsBytes(
       data,
@@ -1222,6 +1845,17 @@ export function identifyRestakingInstruction(
   ) {
     return RestakingInstruction.UserWithdrawSupportedToken;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([120, 153, 161, 120, 224, 99, 126, 101])
+      ),
+      0
+    )
+  ) {
+    return RestakingInstruction.UserWithdrawSupportedTokenInstantly;
+  }
   if (
     containsBytes(
       data,
@@ -1252,6 +1886,9 @@ export function identifyRestakingInstruction(
 export type ParsedRestakingInstruction<
   TProgram extends string = '4qEHCzsLFUnw8jmhmRSmAK5VhZVoSD1iVqukAf92yHi5',
 > =
+  | ({
+      instructionType: RestakingInstruction.AdminCloseFundOperationHistoryAccount;
+    } & ParsedAdminCloseFundOperationHistoryAccountInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.AdminCreateUserRewardAccountIdempotent;
     } & ParsedAdminCreateUserRewardAccountIdempotentInstruction<TProgram>)
@@ -1267,6 +1904,12 @@ export type ParsedRestakingInstruction<
   | ({
       instructionType: RestakingInstruction.AdminInitializeRewardAccount;
     } & ParsedAdminInitializeRewardAccountInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.AdminInitializeRoleAccount;
+    } & ParsedAdminInitializeRoleAccountInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.AdminProposeRoleTransfer;
+    } & ParsedAdminProposeRoleTransferInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.AdminSetAddressLookupTableAccount;
     } & ParsedAdminSetAddressLookupTableAccountInstruction<TProgram>)
@@ -1282,9 +1925,15 @@ export type ParsedRestakingInstruction<
   | ({
       instructionType: RestakingInstruction.AdminUpdateRewardAccountIfNeeded;
     } & ParsedAdminUpdateRewardAccountIfNeededInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerAcknowledgePriceDeviation;
+    } & ParsedFundManagerAcknowledgePriceDeviationInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.FundManagerAddNormalizedTokenPoolSupportedToken;
     } & ParsedFundManagerAddNormalizedTokenPoolSupportedTokenInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerAddOperator;
+    } & ParsedFundManagerAddOperatorInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.FundManagerAddRestakingVaultCompoundingRewardToken;
     } & ParsedFundManagerAddRestakingVaultCompoundingRewardTokenInstruction<TProgram>)
@@ -1303,6 +1952,9 @@ export type ParsedRestakingInstruction<
   | ({
       instructionType: RestakingInstruction.FundManagerAddWrappedTokenHolder;
     } & ParsedFundManagerAddWrappedTokenHolderInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerExecuteFundConfigurationChange;
+    } & ParsedFundManagerExecuteFundConfigurationChangeInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.FundManagerInitializeFundNormalizedToken;
     } & ParsedFundManagerInitializeFundNormalizedTokenInstruction<TProgram>)
@@ -1315,9 +1967,15 @@ export type ParsedRestakingInstruction<
   | ({
       instructionType: RestakingInstruction.FundManagerInitializeFundWrappedToken;
     } & ParsedFundManagerInitializeFundWrappedTokenInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerQueueFundConfigurationChange;
+    } & ParsedFundManagerQueueFundConfigurationChangeInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.FundManagerRemoveNormalizedTokenPoolSupportedToken;
     } & ParsedFundManagerRemoveNormalizedTokenPoolSupportedTokenInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerRemoveOperator;
+    } & ParsedFundManagerRemoveOperatorInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.FundManagerRemoveRestakingVaultCompoundingRewardToken;
     } & ParsedFundManagerRemoveRestakingVaultCompoundingRewardTokenInstruction<TProgram>)
@@ -1342,12 +2000,39 @@ export type ParsedRestakingInstruction<
   | ({
       instructionType: RestakingInstruction.FundManagerRevokeFundRewardTokenMintAuthority;
     } & ParsedFundManagerRevokeFundRewardTokenMintAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerSetOperatorAllowlistEnabled;
+    } & ParsedFundManagerSetOperatorAllowlistEnabledInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.FundManagerSettleReward;
     } & ParsedFundManagerSettleRewardInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.FundManagerUpdateFundStrategy;
     } & ParsedFundManagerUpdateFundStrategyInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerUpdateInKindWithdrawalStrategy;
+    } & ParsedFundManagerUpdateInKindWithdrawalStrategyInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerUpdateInstantWithdrawalStrategy;
+    } & ParsedFundManagerUpdateInstantWithdrawalStrategyInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerUpdateManagementFeeRate;
+    } & ParsedFundManagerUpdateManagementFeeRateInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerUpdateMaxPriceAgeSlots;
+    } & ParsedFundManagerUpdateMaxPriceAgeSlotsInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerUpdateOperationCommandPolicy;
+    } & ParsedFundManagerUpdateOperationCommandPolicyInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerUpdateOperationFallbackCommand;
+    } & ParsedFundManagerUpdateOperationFallbackCommandInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerUpdatePriceDeviationThreshold;
+    } & ParsedFundManagerUpdatePriceDeviationThresholdInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerUpdateReconciliationThreshold;
+    } & ParsedFundManagerUpdateReconciliationThresholdInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.FundManagerUpdateRestakingVaultDelegationStrategy;
     } & ParsedFundManagerUpdateRestakingVaultDelegationStrategyInstruction<TProgram>)
@@ -1357,15 +2042,27 @@ export type ParsedRestakingInstruction<
   | ({
       instructionType: RestakingInstruction.FundManagerUpdateRestakingVaultStrategy;
     } & ParsedFundManagerUpdateRestakingVaultStrategyInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerUpdateRevenueSplit;
+    } & ParsedFundManagerUpdateRevenueSplitInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.FundManagerUpdateReward;
     } & ParsedFundManagerUpdateRewardInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.FundManagerUpdateSolStrategy;
     } & ParsedFundManagerUpdateSolStrategyInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerUpdateSupportedTokenPriceDeviationThreshold;
+    } & ParsedFundManagerUpdateSupportedTokenPriceDeviationThresholdInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.FundManagerUpdateSupportedTokenStrategy;
     } & ParsedFundManagerUpdateSupportedTokenStrategyInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.FundManagerUpdateTokenSwapStrategy;
+    } & ParsedFundManagerUpdateTokenSwapStrategyInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.GuardianCancelFundConfigurationChange;
+    } & ParsedGuardianCancelFundConfigurationChangeInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.OperatorClaimRemainingReward;
     } & ParsedOperatorClaimRemainingRewardInstruction<TProgram>)
@@ -1375,9 +2072,18 @@ export type ParsedRestakingInstruction<
   | ({
       instructionType: RestakingInstruction.OperatorDonateSupportedTokenToFund;
     } & ParsedOperatorDonateSupportedTokenToFundInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.OperatorInitializeFundOperationHistoryAccount;
+    } & ParsedOperatorInitializeFundOperationHistoryAccountInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.OperatorLogMessage;
     } & ParsedOperatorLogMessageInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.OperatorPlanFundCommands;
+    } & ParsedOperatorPlanFundCommandsInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.OperatorReconcileFund;
+    } & ParsedOperatorReconcileFundInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.OperatorRunFundCommand;
     } & ParsedOperatorRunFundCommandInstruction<TProgram>)
@@ -1390,6 +2096,21 @@ export type ParsedRestakingInstruction<
   | ({
       instructionType: RestakingInstruction.OperatorUpdateRewardPools;
     } & ParsedOperatorUpdateRewardPoolsInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.QuoteDeposit;
+    } & ParsedQuoteDepositInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.QuoteReceiptTokenTwap;
+    } & ParsedQuoteReceiptTokenTwapInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.QuoteWithdrawal;
+    } & ParsedQuoteWithdrawalInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.QuoteWrap;
+    } & ParsedQuoteWrapInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.RoleAuthorityAcceptRoleTransfer;
+    } & ParsedRoleAuthorityAcceptRoleTransferInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.SlasherInitializeNormalizedTokenWithdrawalAccount;
     } & ParsedSlasherInitializeNormalizedTokenWithdrawalAccountInstruction<TProgram>)
@@ -1429,21 +2150,36 @@ export type ParsedRestakingInstruction<
   | ({
       instructionType: RestakingInstruction.UserDepositVaultReceiptToken;
     } & ParsedUserDepositVaultReceiptTokenInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.UserMigrateWithdrawalRequests;
+    } & ParsedUserMigrateWithdrawalRequestsInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.UserRequestWithdrawal;
     } & ParsedUserRequestWithdrawalInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.UserTransferWithdrawalRequest;
+    } & ParsedUserTransferWithdrawalRequestInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.UserUnwrapReceiptToken;
     } & ParsedUserUnwrapReceiptTokenInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.UserUpdateRewardPools;
     } & ParsedUserUpdateRewardPoolsInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.UserWithdrawInKind;
+    } & ParsedUserWithdrawInKindInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.UserWithdrawInstantly;
+    } & ParsedUserWithdrawInstantlyInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.UserWithdrawSol;
     } & ParsedUserWithdrawSolInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.UserWithdrawSupportedToken;
     } & ParsedUserWithdrawSupportedTokenInstruction<TProgram>)
+  | ({
+      instructionType: RestakingInstruction.UserWithdrawSupportedTokenInstantly;
+    } & ParsedUserWithdrawSupportedTokenInstantlyInstruction<TProgram>)
   | ({
       instructionType: RestakingInstruction.UserWrapReceiptToken;
     } & ParsedUserWrapReceiptTokenInstruction<TProgram>)
@@ -10,19 +10,30 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
 } from '@solana/kit';
+import {
+  getFundRevenueShareDecoder,
+  getFundRevenueShareEncoder,
+  type FundRevenueShare,
+  type FundRevenueShareArgs,
+} from '.';
 
 export type HarvestPerformanceFeeCommandResult = {
   receiptTokenMint: Address;
   receiptTokenMintedAmount: bigint;
+  performanceFeeReceiptTokenMintedAmount: bigint;
+  managementFeeReceiptTokenMintedAmount: bigint;
+  receiptTokenMintedShares: Array<FundRevenueShare>;
   oneReceiptTokenAsSolBeforePerformanceFeeHarvested: bigint;
   oneReceiptTokenAsSolAfterPerformanceFeeHarvested: bigint;
 };
@@ -30,29 +41,38 @@ export type HarvestPerformanceFeeCommandResult = {
 export type HarvestPerformanceFeeCommandResultArgs = {
   receiptTokenMint: Address;
   receiptTokenMintedAmount: number | bigint;
+  performanceFeeReceiptTokenMintedAmount: number | bigint;
+  managementFeeReceiptTokenMintedAmount: number | bigint;
+  receiptTokenMintedShares: Array<FundRevenueShareArgs>;
   oneReceiptTokenAsSolBeforePerformanceFeeHarvested: number | bigint;
   oneReceiptTokenAsSolAfterPerformanceFeeHarvested: number | bigint;
 };
 
-export function getHarvestPerformanceFeeCommandResultEncoder(): FixedSizeEncoder<HarvestPerformanceFeeCommandResultArgs> {
+export function getHarvestPerformanceFeeCommandResultEncoder(): Encoder<HarvestPerformanceFeeCommandResultArgs> {
   return getStructEncoder([
     ['receiptTokenMint', getAddressEncoder()],
     ['receiptTokenMintedAmount', getU64Encoder()],
+    ['performanceFeeReceiptTokenMintedAmount', getU64Encoder()],
+    ['managementFeeReceiptTokenMintedAmount', getU64Encoder()],
+    ['receiptTokenMintedShares', getArrayEncoder(getFundRevenueShareEncoder())],
     ['oneReceiptTokenAsSolBeforePerformanceFeeHarvested', getU64Encoder()],
     ['oneReceiptTokenAsSolAfterPerformanceFeeHarvested', getU64Encoder()],
   ]);
 }
 
-export function getHarvestPerformanceFeeCommandResultDecoder(): FixedSizeDecoder<HarvestPerformanceFeeCommandResult> {
+export function getHarvestPerformanceFeeCommandResultDecoder(): Decoder<HarvestPerformanceFeeCommandResult> {
   return getStructDecoder([
     ['receiptTokenMint', getAddressDecoder()],
     ['receiptTokenMintedAmount', getU64Decoder()],
+    ['performanceFeeReceiptTokenMintedAmount', getU64Decoder()],
+    ['managementFeeReceiptTokenMintedAmount', getU64Decoder()],
+    ['receiptTokenMintedShares', getArrayDecoder(getFundRevenueShareDecoder())],
     ['oneReceiptTokenAsSolBeforePerformanceFeeHarvested', getU64Decoder()],
     ['oneReceiptTokenAsSolAfterPerformanceFeeHarvested', getU64Decoder()],
   ]);
 }
 
-export function getHarvestPerformanceFeeCommandResultCodec(): FixedSizeCodec<
+export function getHarvestPerformanceFeeCommandResultCodec(): Codec<
   HarvestPerformanceFeeCommandResultArgs,
   HarvestPerformanceFeeCommandResult
 > {
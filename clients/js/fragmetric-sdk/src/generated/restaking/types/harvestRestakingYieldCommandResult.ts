@@ -10,6 +10,8 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getI128Decoder,
   getI128Encoder,
   getOptionDecoder,
@@ -26,10 +28,18 @@ import {
   type OptionOrNullable,
 } from '@solana/kit';
 import {
+  getFundRevenueShareDecoder,
+  getFundRevenueShareEncoder,
   getRewardSettlementBlockSlotAndContributionDecoder,
   getRewardSettlementBlockSlotAndContributionEncoder,
+  getTokenSwapRejectedReasonDecoder,
+  getTokenSwapRejectedReasonEncoder,
+  type FundRevenueShare,
+  type FundRevenueShareArgs,
   type RewardSettlementBlockSlotAndContribution,
   type RewardSettlementBlockSlotAndContributionArgs,
+  type TokenSwapRejectedReason,
+  type TokenSwapRejectedReasonArgs,
 } from '.';
 
 export type HarvestRestakingYieldCommandResult = {
@@ -37,12 +47,14 @@ export type HarvestRestakingYieldCommandResult = {
   yieldTokenMint: Address;
   yieldTokenTotalHarvestedAmount: bigint;
   yieldTokenCommissionAmount: bigint;
+  yieldTokenCommissionShares: Array<FundRevenueShare>;
   fundSupportedTokenCompoundedAmount: bigint;
   swappedTokenMint: Option<Address>;
   rewardTokenDistributedAmount: bigint;
   updatedRewardAccount: Option<Address>;
   distributingRewardSettlementBlockSlotAndContribution: Option<RewardSettlementBlockSlotAndContribution>;
   vaultSupportedTokenCompoundedAmount: bigint;
+  swapRejectedReason: Option<TokenSwapRejectedReason>;
 };
 
 export type HarvestRestakingYieldCommandResultArgs = {
@@ -50,12 +62,14 @@ export type HarvestRestakingYieldCommandResultArgs = {
   yieldTokenMint: Address;
   yieldTokenTotalHarvestedAmount: number | bigint;
   yieldTokenCommissionAmount: number | bigint;
+  yieldTokenCommissionShares: Array<FundRevenueShareArgs>;
   fundSupportedTokenCompoundedAmount: number | bigint;
   swappedTokenMint: OptionOrNullable<Address>;
   rewardTokenDistributedAmount: number | bigint;
   updatedRewardAccount: OptionOrNullable<Address>;
   distributingRewardSettlementBlockSlotAndContribution: OptionOrNullable<RewardSettlementBlockSlotAndContributionArgs>;
   vaultSupportedTokenCompoundedAmount: number | bigint;
+  swapRejectedReason: OptionOrNullable<TokenSwapRejectedReasonArgs>;
 };
 
 export function getHarvestRestakingYieldCommandResultEncoder(): Encoder<HarvestRestakingYieldCommandResultArgs> {
@@ -64,6 +78,10 @@ export function getHarvestRestakingYieldCommandResultEncoder(): Encoder<HarvestR
     ['yieldTokenMint', getAddressEncoder()],
     ['yieldTokenTotalHarvestedAmount', getI128Encoder()],
     ['yieldTokenCommissionAmount', getU64Encoder()],
+    [
+      'yieldTokenCommissionShares',
+      getArrayEncoder(getFundRevenueShareEncoder()),
+    ],
     ['fundSupportedTokenCompoundedAmount', getU64Encoder()],
     ['swappedTokenMint', getOptionEncoder(getAddressEncoder())],
     ['rewardTokenDistributedAmount', getU64Encoder()],
@@ -73,6 +91,10 @@ export function getHarvestRestakingYieldCommandResultEncoder(): Encoder<HarvestR
       getOptionEncoder(getRewardSettlementBlockSlotAndContributionEncoder()),
     ],
     ['vaultSupportedTokenCompoundedAmount', getI128Encoder()],
+    [
+      'swapRejectedReason',
+      getOptionEncoder(getTokenSwapRejectedReasonEncoder()),
+    ],
   ]);
 }
 
@@ -82,6 +104,10 @@ export function getHarvestRestakingYieldCommandResultDecoder(): Decoder<HarvestR
     ['yieldTokenMint', getAddressDecoder()],
     ['yieldTokenTotalHarvestedAmount', getI128Decoder()],
     ['yieldTokenCommissionAmount', getU64Decoder()],
+    [
+      'yieldTokenCommissionShares',
+      getArrayDecoder(getFundRevenueShareDecoder()),
+    ],
     ['fundSupportedTokenCompoundedAmount', getU64Decoder()],
     ['swappedTokenMint', getOptionDecoder(getAddressDecoder())],
     ['rewardTokenDistributedAmount', getU64Decoder()],
@@ -91,6 +117,10 @@ export function getHarvestRestakingYieldCommandResultDecoder(): Decoder<HarvestR
       getOptionDecoder(getRewardSettlementBlockSlotAndContributionDecoder()),
     ],
     ['vaultSupportedTokenCompoundedAmount', getI128Decoder()],
+    [
+      'swapRejectedReason',
+      getOptionDecoder(getTokenSwapRejectedReasonDecoder()),
+    ],
   ]);
 }
 
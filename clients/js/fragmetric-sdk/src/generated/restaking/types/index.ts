@@ -27,9 +27,16 @@ export * from './denormalizeNTCommandItem';
 export * from './denormalizeNTCommandResult';
 export * from './denormalizeNTCommandState';
 export * from './depositMetadata';
+export * from './depositQuote';
 export * from './enqueueWithdrawalBatchCommand';
 export * from './enqueueWithdrawalBatchCommandResult';
 export * from './enqueueWithdrawalBatchCommandState';
+export * from './fundConfigurationChange';
+export * from './fundInKindWithdrawnAsset';
+export * from './fundOperator';
+export * from './fundReconciliationDiscrepancy';
+export * from './fundRevenueRecipient';
+export * from './fundRevenueShare';
 export * from './harvestPerformanceFeeCommand';
 export * from './harvestPerformanceFeeCommandResult';
 export * from './harvestPerformanceFeeState';
@@ -44,6 +51,8 @@ export * from './initializeCommandResultRestakingVaultUpdated';
 export * from './initializeCommandResultWrappedTokenHolderUpdate';
 export * from './initializeCommandResultWrappedTokenUpdated';
 export * from './initializeCommandState';
+export * from './inKindWithdrawalState';
+export * from './instantWithdrawalState';
 export * from './normalizedClaimableToken';
 export * from './normalizedSupportedToken';
 export * from './normalizedToken';
@@ -56,25 +65,41 @@ export * from './operationCommandAccountMeta';
 export * from './operationCommandAccountMetaPod';
 export * from './operationCommandEntry';
 export * from './operationCommandEntryPod';
+export * from './operationCommandFailure';
+export * from './operationCommandPlan';
+export * from './operationCommandPlanStep';
 export * from './operationCommandPod';
+export * from './operationCommandPolicy';
 export * from './operationCommandResult';
+export * from './operationFailureState';
+export * from './operationHistoryEntry';
+export * from './operationPolicyState';
 export * from './operationState';
+export * from './operatorAllowlist';
+export * from './priceDeviationCircuitBreaker';
 export * from './processWithdrawalBatchCommand';
 export * from './processWithdrawalBatchCommandResult';
 export * from './processWithdrawalBatchCommandResultAssetReceivable';
 export * from './processWithdrawalBatchCommandState';
+export * from './receiptTokenPriceHistory';
+export * from './receiptTokenPriceSample';
+export * from './receiptTokenTWAPQuote';
+export * from './reconciliationState';
 export * from './restakeVSTCommand';
 export * from './restakeVSTCommandItem';
 export * from './restakeVSTCommandResult';
 export * from './restakeVSTCommandState';
 export * from './restakingVault';
 export * from './restakingVaultDelegation';
+export * from './revenueRecipient';
+export * from './revenueSplit';
 export * from './reward';
 export * from './rewardPool';
 export * from './rewardSettlement';
 export * from './rewardSettlementBlock';
 export * from './rewardSettlementBlockSlotAndContribution';
 export * from './rewardToken';
+export * from './role';
 export * from './stakeSOLCommand';
 export * from './stakeSOLCommandItem';
 export * from './stakeSOLCommandResult';
@@ -85,6 +110,7 @@ export * from './tokenAllocatedAmountRecord';
 export * from './tokenExchangeRatio';
 export * from './tokenPricingSource';
 export * from './tokenPricingSourcePod';
+export * from './tokenSwapRejectedReason';
 export * from './tokenSwapSource';
 export * from './tokenSwapSourcePod';
 export * from './tokenSwapStrategy';
@@ -106,7 +132,9 @@ export * from './unstakeLSTCommandState';
 export * from './userRewardPool';
 export * from './userRewardSettlement';
 export * from './withdrawalBatch';
+export * from './withdrawalQuote';
 export * from './withdrawalRequest';
 export * from './withdrawStakeItem';
 export * from './wrappedToken';
 export * from './wrappedTokenHolder';
+export * from './wrapQuote';
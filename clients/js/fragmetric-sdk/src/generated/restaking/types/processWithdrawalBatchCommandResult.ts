@@ -28,8 +28,12 @@ import {
   type OptionOrNullable,
 } from '@solana/kit';
 import {
+  getFundRevenueShareDecoder,
+  getFundRevenueShareEncoder,
   getProcessWithdrawalBatchCommandResultAssetReceivableDecoder,
   getProcessWithdrawalBatchCommandResultAssetReceivableEncoder,
+  type FundRevenueShare,
+  type FundRevenueShareArgs,
   type ProcessWithdrawalBatchCommandResultAssetReceivable,
   type ProcessWithdrawalBatchCommandResultAssetReceivableArgs,
 } from '.';
@@ -44,6 +48,7 @@ export type ProcessWithdrawalBatchCommandResult = {
   deductedAssetFeeAmount: bigint;
   offsetAssetReceivables: Array<ProcessWithdrawalBatchCommandResultAssetReceivable>;
   transferredAssetRevenueAmount: bigint;
+  transferredAssetRevenueShares: Array<FundRevenueShare>;
   withdrawalFeeRateBps: number;
 };
 
@@ -57,6 +62,7 @@ export type ProcessWithdrawalBatchCommandResultArgs = {
   deductedAssetFeeAmount: number | bigint;
   offsetAssetReceivables: Array<ProcessWithdrawalBatchCommandResultAssetReceivableArgs>;
   transferredAssetRevenueAmount: number | bigint;
+  transferredAssetRevenueShares: Array<FundRevenueShareArgs>;
   withdrawalFeeRateBps: number;
 };
 
@@ -76,6 +82,10 @@ export function getProcessWithdrawalBatchCommandResultEncoder(): Encoder<Process
       ),
     ],
     ['transferredAssetRevenueAmount', getU64Encoder()],
+    [
+      'transferredAssetRevenueShares',
+      getArrayEncoder(getFundRevenueShareEncoder()),
+    ],
     ['withdrawalFeeRateBps', getU16Encoder()],
   ]);
 }
@@ -96,6 +106,10 @@ export function getProcessWithdrawalBatchCommandResultDecoder(): Decoder<Process
       ),
     ],
     ['transferredAssetRevenueAmount', getU64Decoder()],
+    [
+      'transferredAssetRevenueShares',
+      getArrayDecoder(getFundRevenueShareDecoder()),
+    ],
     ['withdrawalFeeRateBps', getU16Decoder()],
   ]);
 }
@@ -16,6 +16,8 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -54,6 +56,9 @@ export type SupportedToken = {
   pendingUnstakingAmountAsSol: bigint;
   /** informative */
   oneTokenAsReceiptToken: bigint;
+  /** configuration: max deviation of `one_token_as_sol` between price updates in basis points (0 = disabled). */
+  maxPriceDeviationBps: number;
+  padding3: ReadonlyUint8Array;
   reserved: ReadonlyUint8Array;
 };
 
@@ -74,6 +79,9 @@ export type SupportedTokenArgs = {
   pendingUnstakingAmountAsSol: number | bigint;
   /** informative */
   oneTokenAsReceiptToken: number | bigint;
+  /** configuration: max deviation of `one_token_as_sol` between price updates in basis points (0 = disabled). */
+  maxPriceDeviationBps: number;
+  padding3: ReadonlyUint8Array;
   reserved: ReadonlyUint8Array;
 };
 
@@ -91,7 +99,9 @@ export function getSupportedTokenEncoder(): FixedSizeEncoder<SupportedTokenArgs>
     ['solAllocationCapacityAmount', getU64Encoder()],
     ['pendingUnstakingAmountAsSol', getU64Encoder()],
     ['oneTokenAsReceiptToken', getU64Encoder()],
-    ['reserved', fixEncoderSize(getBytesEncoder(), 48)],
+    ['maxPriceDeviationBps', getU16Encoder()],
+    ['padding3', fixEncoderSize(getBytesEncoder(), 6)],
+    ['reserved', fixEncoderSize(getBytesEncoder(), 40)],
   ]);
 }
 
@@ -109,7 +119,9 @@ export function getSupportedTokenDecoder(): FixedSizeDecoder<SupportedToken> {
     ['solAllocationCapacityAmount', getU64Decoder()],
     ['pendingUnstakingAmountAsSol', getU64Decoder()],
     ['oneTokenAsReceiptToken', getU64Decoder()],
-    ['reserved', fixDecoderSize(getBytesDecoder(), 48)],
+    ['maxPriceDeviationBps', getU16Decoder()],
+    ['padding3', fixDecoderSize(getBytesDecoder(), 6)],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 40)],
   ]);
 }
 
@@ -16,6 +16,8 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -23,8 +25,12 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  getTokenPricingSourcePodDecoder,
+  getTokenPricingSourcePodEncoder,
   getTokenSwapSourcePodDecoder,
   getTokenSwapSourcePodEncoder,
+  type TokenPricingSourcePod,
+  type TokenPricingSourcePodArgs,
   type TokenSwapSourcePod,
   type TokenSwapSourcePodArgs,
 } from '.';
@@ -45,11 +51,20 @@ import {
  * In the future, operation cycle will support multiple-hop swap, for example, A -> B -> C.
  * In this case `to_token` need not be one of fund's supported token.
  * However, to prevent endless swap, token swap strategies must form DAG(vertex = token, edge = strategy).
+ *
+ * The expected output of a swap is quoted by the fund's pricing service,
+ * using `from_token_pricing_source` for `from_token`.
+ * A swap is rejected or shrunk so that the realized output does not fall below
+ * the expected output by more than `max_slippage_bps`.
+ * Swaps are rejected until the pricing source is configured.
  */
 export type TokenSwapStrategy = {
   fromTokenMint: Address;
   toTokenMint: Address;
   swapSource: TokenSwapSourcePod;
+  fromTokenPricingSource: TokenPricingSourcePod;
+  maxSlippageBps: number;
+  padding: ReadonlyUint8Array;
   reserved: ReadonlyUint8Array;
 };
 
@@ -57,6 +72,9 @@ export type TokenSwapStrategyArgs = {
   fromTokenMint: Address;
   toTokenMint: Address;
   swapSource: TokenSwapSourcePodArgs;
+  fromTokenPricingSource: TokenPricingSourcePodArgs;
+  maxSlippageBps: number;
+  padding: ReadonlyUint8Array;
   reserved: ReadonlyUint8Array;
 };
 
@@ -65,7 +83,10 @@ export function getTokenSwapStrategyEncoder(): FixedSizeEncoder<TokenSwapStrateg
     ['fromTokenMint', getAddressEncoder()],
     ['toTokenMint', getAddressEncoder()],
     ['swapSource', getTokenSwapSourcePodEncoder()],
-    ['reserved', fixEncoderSize(getBytesEncoder(), 128)],
+    ['fromTokenPricingSource', getTokenPricingSourcePodEncoder()],
+    ['maxSlippageBps', getU16Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 6)],
+    ['reserved', fixEncoderSize(getBytesEncoder(), 80)],
   ]);
 }
 
@@ -74,7 +95,10 @@ export function getTokenSwapStrategyDecoder(): FixedSizeDecoder<TokenSwapStrateg
     ['fromTokenMint', getAddressDecoder()],
     ['toTokenMint', getAddressDecoder()],
     ['swapSource', getTokenSwapSourcePodDecoder()],
-    ['reserved', fixDecoderSize(getBytesDecoder(), 128)],
+    ['fromTokenPricingSource', getTokenPricingSourcePodDecoder()],
+    ['maxSlippageBps', getU16Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 6)],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 80)],
   ]);
 }
 
@@ -32,6 +32,8 @@ import {
   getUserDepositedToFundDiscriminatorBytes,
   getUserDepositedToVaultDecoder,
   getUserDepositedToVaultDiscriminatorBytes,
+  getUserMigratedFundWithdrawalRequestsDecoder,
+  getUserMigratedFundWithdrawalRequestsDiscriminatorBytes,
   getUserRequestedWithdrawalFromFundDecoder,
   getUserRequestedWithdrawalFromFundDiscriminatorBytes,
   getUserTransferredReceiptTokenDecoder,
@@ -158,6 +160,10 @@ export const restakingAnchorEventDecoders = {
     discriminator: getUserClosedRewardAccountDiscriminatorBytes(),
     decoder: getUserClosedRewardAccountDecoder(),
   },
+  userMigratedFundWithdrawalRequests: {
+    discriminator: getUserMigratedFundWithdrawalRequestsDiscriminatorBytes(),
+    decoder: getUserMigratedFundWithdrawalRequestsDecoder(),
+  },
 } satisfies {
   [k in string]: {
     discriminator: ReadonlyUint8Array;
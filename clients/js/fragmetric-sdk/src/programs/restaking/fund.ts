@@ -459,6 +459,12 @@ export class RestakingFundAccountContext extends AccountContext<
             'to forcibly run a specific command with authorized signer (entire command object or name)'
           )
         ),
+        maxSteps: v.pipe(
+          v.nullish(v.number(), null),
+          v.description(
+            'max number of chained commands to run, the following commands run only if their accounts are already prepared (default is 1)'
+          )
+        ),
       }),
       {}
     ),
@@ -520,6 +526,7 @@ export class RestakingFundAccountContext extends AccountContext<
                     receiptTokenMint: data.receiptTokenMint,
                     program: this.program.address,
                     forceResetCommand: forceResetCommand,
+                    maxSteps: args.maxSteps,
                   },
                   {
                     programAddress: this.program.address,
@@ -635,7 +642,7 @@ export class RestakingFundAccountContext extends AccountContext<
                         programAddress: this.program.address,
                       }
                     );
-                  const fundAccount = ix.accounts[5].address;
+                  const fundAccount = ix.accounts[6].address;
                   return Promise.all([
                     token2022.getCreateAssociatedTokenIdempotentInstructionAsync(
                       {
@@ -838,8 +845,8 @@ export class RestakingFundAccountContext extends AccountContext<
             role: AccountRole.READONLY,
           });
 
-          const fundReserve = ix.accounts[3].address;
-          const fundTreasury = ix.accounts[4].address;
+          const fundReserve = ix.accounts[4].address;
+          const fundTreasury = ix.accounts[5].address;
 
           return Promise.all([
             token.getCreateAssociatedTokenIdempotentInstructionAsync({
@@ -1405,7 +1412,7 @@ export class RestakingFundAccountContext extends AccountContext<
               role: AccountRole.READONLY,
             });
 
-            const fundReserve = ix.accounts[3].address;
+            const fundReserve = ix.accounts[4].address;
             const vaultFeeWallet = vaultAccount.data.feeWallet;
             const vaultProgramFeeWallet =
               vaultContext.knownAddresses.programFeeWallet;
@@ -1485,7 +1492,7 @@ export class RestakingFundAccountContext extends AccountContext<
               role: AccountRole.READONLY,
             });
 
-            const fundReserve = ix.accounts[3].address;
+            const fundReserve = ix.accounts[4].address;
             return Promise.all([
               token.getCreateAssociatedTokenIdempotentInstructionAsync({
                 payer: createNoopSigner(payer as Address),
@@ -1563,7 +1570,7 @@ export class RestakingFundAccountContext extends AccountContext<
               role: AccountRole.READONLY,
             });
 
-            const fundReserve = ix.accounts[3].address;
+            const fundReserve = ix.accounts[4].address;
 
             const rent = await this.runtime.rpc
               .getMinimumBalanceForRentExemption(BigInt(0n))
@@ -2140,7 +2147,7 @@ export class RestakingFundAccountContext extends AccountContext<
                 for (const accountMeta of data.__pricingSources) {
                   ix.accounts.push(accountMeta);
                 }
-                ix.accounts.push(ix.accounts[7]); // ntp
+                ix.accounts.push(ix.accounts[8]); // ntp
                 return ix;
               }),
           ]);
@@ -2190,7 +2197,7 @@ export class RestakingFundAccountContext extends AccountContext<
                 programAddress: this.program.address,
               }
             );
-          const fundWrapAccount = ix.accounts[2].address;
+          const fundWrapAccount = ix.accounts[3].address;
 
           return Promise.all([
             token.getCreateAssociatedTokenIdempotentInstructionAsync({
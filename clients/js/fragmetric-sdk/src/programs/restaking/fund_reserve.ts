@@ -18,7 +18,7 @@ export class RestakingFundReserveAccountContext extends BaseAccountContext<Resta
           programAddress: this.program.address,
         }
       );
-      return ix.accounts[7].address;
+      return ix.accounts[8].address;
     });
   }
 
@@ -20,12 +20,12 @@ export class RestakingFundTreasuryAccountContext extends BaseAccountContext<Rest
               __kind: 'SPLStakePool',
               address: this.program.address,
             },
-          },
+          } as any,
           {
             programAddress: this.program.address,
           }
         );
-      return ix.accounts[4].address;
+      return ix.accounts[5].address;
     });
   }
 
@@ -0,0 +1,45 @@
+import { Account, EncodedAccount, isSome } from '@solana/kit';
+import { AccountContext } from '../../context';
+import * as restaking from '../../generated/restaking';
+import { RestakingUserAccountContext } from './user';
+
+export class RestakingFundWithdrawalRequestAccountContext extends AccountContext<
+  RestakingUserAccountContext,
+  Account<restaking.FundWithdrawalRequestAccount>
+> {
+  async resolve(noCache = false) {
+    const account = await this.resolveAccount(noCache);
+    if (!account) {
+      return null;
+    }
+    const { discriminator, dataVersion, bump, request, reserved, ...props } =
+      account.data;
+    const { createdAt, reserved: _, ...requestProps } = request;
+
+    return {
+      ...props,
+      ...requestProps,
+      createdAt: new Date(Number(createdAt) * 1000),
+    };
+  }
+
+  protected __decodeAccount(account: EncodedAccount) {
+    return restaking.decodeFundWithdrawalRequestAccount(account);
+  }
+
+  toContextDescription() {
+    const desc = super.toContextDescription();
+    const request = this.__account?.data.request;
+    return {
+      ...desc,
+      properties: {
+        ...desc.properties,
+        mint: request
+          ? isSome(request.supportedTokenMint)
+            ? request.supportedTokenMint.value
+            : null
+          : undefined,
+      },
+    };
+  }
+}
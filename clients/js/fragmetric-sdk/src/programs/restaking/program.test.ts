@@ -190,7 +190,7 @@ test('can traverse context graph', async () => {
     │   │   ├── wrappedToken                                  TokenAccount address=undefined, amount=undefined, mint=undefined
     │   │   ├── supportedTokens                               IterativeAccount length=undefined, types=undefined
     │   │   ├── rewardTokens                                  IterativeAccount length=undefined, types=undefined
-    │   │   ├── deposit                                       TransactionTemplate args=assetMint,assetAmount,metadata,skipUserFundAccountCreation,skipUserRewardAccountCreation,applyPresetComputeUnitLimit,
+    │   │   ├── deposit                                       TransactionTemplate args=assetMint,assetAmount,metadata,minReceiptTokenAmount,skipUserFundAccountCreation,skipUserRewardAccountCreation,applyP
     │   │   ├── requestWithdrawal                             TransactionTemplate args=assetMint,receiptTokenAmount,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userRequestedWithdrawa
     │   │   ├── cancelWithdrawalRequest                       TransactionTemplate args=assetMint,requestId,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userCanceledWithdrawalRequestFr
     │   │   ├── withdraw                                      TransactionTemplate args=assetMint,requestId,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userWithdrewFromFund,userCreate
@@ -280,7 +280,7 @@ test('can traverse context graph', async () => {
     │   │   ├── wrappedToken                                  TokenAccount address=undefined, amount=undefined, mint=undefined
     │   │   ├── supportedTokens                               IterativeAccount length=undefined, types=undefined
     │   │   ├── rewardTokens                                  IterativeAccount length=undefined, types=undefined
-    │   │   ├── deposit                                       TransactionTemplate args=assetMint,assetAmount,metadata,skipUserFundAccountCreation,skipUserRewardAccountCreation,applyPresetComputeUnitLimit,
+    │   │   ├── deposit                                       TransactionTemplate args=assetMint,assetAmount,metadata,minReceiptTokenAmount,skipUserFundAccountCreation,skipUserRewardAccountCreation,applyP
     │   │   ├── requestWithdrawal                             TransactionTemplate args=assetMint,receiptTokenAmount,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userRequestedWithdrawa
     │   │   ├── cancelWithdrawalRequest                       TransactionTemplate args=assetMint,requestId,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userCanceledWithdrawalRequestFr
     │   │   ├── withdraw                                      TransactionTemplate args=assetMint,requestId,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userWithdrewFromFund,userCreate
@@ -370,7 +370,7 @@ test('can traverse context graph', async () => {
     │   │   ├── wrappedToken                                  TokenAccount address=undefined, amount=undefined, mint=undefined
     │   │   ├── supportedTokens                               IterativeAccount length=undefined, types=undefined
     │   │   ├── rewardTokens                                  IterativeAccount length=undefined, types=undefined
-    │   │   ├── deposit                                       TransactionTemplate args=assetMint,assetAmount,metadata,skipUserFundAccountCreation,skipUserRewardAccountCreation,applyPresetComputeUnitLimit,
+    │   │   ├── deposit                                       TransactionTemplate args=assetMint,assetAmount,metadata,minReceiptTokenAmount,skipUserFundAccountCreation,skipUserRewardAccountCreation,applyP
     │   │   ├── requestWithdrawal                             TransactionTemplate args=assetMint,receiptTokenAmount,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userRequestedWithdrawa
     │   │   ├── cancelWithdrawalRequest                       TransactionTemplate args=assetMint,requestId,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userCanceledWithdrawalRequestFr
     │   │   ├── withdraw                                      TransactionTemplate args=assetMint,requestId,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userWithdrewFromFund,userCreate
@@ -460,7 +460,7 @@ test('can traverse context graph', async () => {
     │   │   ├── wrappedToken                                  TokenAccount address=undefined, amount=undefined, mint=undefined
     │   │   ├── supportedTokens                               IterativeAccount length=undefined, types=undefined
     │   │   ├── rewardTokens                                  IterativeAccount length=undefined, types=undefined
-    │   │   ├── deposit                                       TransactionTemplate args=assetMint,assetAmount,metadata,skipUserFundAccountCreation,skipUserRewardAccountCreation,applyPresetComputeUnitLimit,
+    │   │   ├── deposit                                       TransactionTemplate args=assetMint,assetAmount,metadata,minReceiptTokenAmount,skipUserFundAccountCreation,skipUserRewardAccountCreation,applyP
     │   │   ├── requestWithdrawal                             TransactionTemplate args=assetMint,receiptTokenAmount,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userRequestedWithdrawa
     │   │   ├── cancelWithdrawalRequest                       TransactionTemplate args=assetMint,requestId,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userCanceledWithdrawalRequestFr
     │   │   ├── withdraw                                      TransactionTemplate args=assetMint,requestId,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userWithdrewFromFund,userCreate
@@ -550,7 +550,7 @@ test('can traverse context graph', async () => {
     │   │   ├── wrappedToken                                  TokenAccount address=undefined, amount=undefined, mint=undefined
     │   │   ├── supportedTokens                               IterativeAccount length=undefined, types=undefined
     │   │   ├── rewardTokens                                  IterativeAccount length=undefined, types=undefined
-    │   │   ├── deposit                                       TransactionTemplate args=assetMint,assetAmount,metadata,skipUserFundAccountCreation,skipUserRewardAccountCreation,applyPresetComputeUnitLimit,
+    │   │   ├── deposit                                       TransactionTemplate args=assetMint,assetAmount,metadata,minReceiptTokenAmount,skipUserFundAccountCreation,skipUserRewardAccountCreation,applyP
     │   │   ├── requestWithdrawal                             TransactionTemplate args=assetMint,receiptTokenAmount,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userRequestedWithdrawa
     │   │   ├── cancelWithdrawalRequest                       TransactionTemplate args=assetMint,requestId,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userCanceledWithdrawalRequestFr
     │   │   ├── withdraw                                      TransactionTemplate args=assetMint,requestId,skipUserRewardAccountCreation,applyPresetComputeUnitLimit, events=userWithdrewFromFund,userCreate
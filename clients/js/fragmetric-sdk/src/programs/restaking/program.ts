@@ -37,6 +37,13 @@ export class RestakingProgram extends ProgramContext {
           ? '5UpLTLA7Wjqp7qdfjuTtPcUw3aVtbqFA5Mgm34mxPNg2'
           : '5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx'
     ),
+    programRevenue: address(
+      this.runtime.cluster === 'mainnet'
+        ? 'XEhpR3UauMkARQ8ztwaU9Kbv16jEpBbXs9ftELka9wj'
+        : this.runtime.cluster === 'devnet'
+          ? 'SRCMj3B7cYjvwTtqJxUSptgJPWkL8bHLrQme6q4zHn7'
+          : 'GuSruSKKCmAGuWMeMsiw3mbNhjeiRtNhnh9Eatgz33NA'
+    ),
     fragSOL: address(
       this.runtime.cluster != 'local'
         ? 'FRAGSEthVFL7fdqM8hxfxkfCZzUvmg21cqPJVvC1qdbo'
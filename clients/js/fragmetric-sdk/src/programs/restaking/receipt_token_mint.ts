@@ -358,7 +358,7 @@ export class RestakingReceiptTokenMintAccountContext extends TokenMintAccountCon
                 programAddress: this.program.address,
               }
             );
-          const extraAccountMetaList = ix.accounts[4].address;
+          const extraAccountMetaList = ix.accounts[5].address;
           const extraAccountMetaListAccount = await this.runtime.fetchAccount(
             extraAccountMetaList,
             true
@@ -222,6 +222,9 @@ export class JitoVaultAccountContext extends AccountContext<
           const ix =
             await restaking.getFundManagerInitializeFundRestakingVaultDelegationInstructionAsync(
               {
+                fundManager: createNoopSigner(
+                  (this.program as RestakingProgram).knownAddresses.fundManager
+                ),
                 vaultOperatorDelegation,
                 receiptTokenMint: vault,
                 vaultAccount: vault,
@@ -229,7 +232,7 @@ export class JitoVaultAccountContext extends AccountContext<
                 program: this.program.address,
               }
             );
-          return ix.accounts[5].address;
+          return ix.accounts[6].address;
         })
       )) as Address[];
     },
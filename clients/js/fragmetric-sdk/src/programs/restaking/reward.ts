@@ -2,6 +2,7 @@ import { getSetComputeUnitLimitInstruction } from '@solana-program/compute-budge
 import * as token from '@solana-program/token';
 import {
   Account,
+  AccountRole,
   Address,
   createNoopSigner,
   EncodedAccount,
@@ -494,21 +495,29 @@ export class RestakingRewardAccountContext extends AccountContext<
       description: 'claim remaining reward to program revenue account',
       instructions: [
         async (parent, args, overrides) => {
-          const [receiptTokenMint, rewardAccount, operator] = await Promise.all(
-            [
+          const [receiptTokenMint, rewardAccount, fundAccount, operator] =
+            await Promise.all([
               parent.parent.resolveAddress(),
               parent.resolveAccount(true),
+              parent.parent.fund.resolveAccount(true),
               transformAddressResolverVariant(
                 overrides.feePayer ??
                   this.runtime.options.transaction.feePayer ??
                   (() => Promise.resolve(null))
               )(parent),
-            ]
-          );
+            ]);
           const reward = rewardAccount?.data.rewards1
             .slice(0, rewardAccount.data.numRewards)
             .find((r) => r.mint == args.mint);
-          if (!(receiptTokenMint && rewardAccount && reward && operator))
+          if (
+            !(
+              receiptTokenMint &&
+              rewardAccount &&
+              fundAccount &&
+              reward &&
+              operator
+            )
+          )
             throw new Error('invalid context');
 
           const ix =
@@ -525,14 +534,42 @@ export class RestakingRewardAccountContext extends AccountContext<
               }
             );
 
+          const revenueSplit = fundAccount.data.revenueSplit;
+          const revenueRecipients =
+            revenueSplit.numRecipients > 0
+              ? revenueSplit.recipients
+                  .slice(0, revenueSplit.numRecipients)
+                  .map((item) => item.recipient)
+              : [
+                  (this.program as RestakingProgram).knownAddresses
+                    .programRevenue,
+                ];
+          const revenueRecipientRewardTokenAccounts = await Promise.all(
+            revenueRecipients.map((owner) =>
+              TokenAccountContext.findAssociatedTokenAccountAddress({
+                owner,
+                mint: reward.mint,
+                tokenProgram: reward.program,
+              })
+            )
+          );
+          for (const address of revenueRecipientRewardTokenAccounts) {
+            ix.accounts.push({
+              address,
+              role: AccountRole.WRITABLE,
+            });
+          }
+
           return Promise.all([
-            token.getCreateAssociatedTokenIdempotentInstructionAsync({
-              payer: createNoopSigner(operator as Address),
-              mint: reward.mint,
-              owner: ix.accounts[4].address,
-              tokenProgram: reward.program,
-              ata: ix.accounts[8].address,
-            }),
+            ...revenueRecipients.map((owner, i) =>
+              token.getCreateAssociatedTokenIdempotentInstructionAsync({
+                payer: createNoopSigner(operator as Address),
+                mint: reward.mint,
+                owner,
+                tokenProgram: reward.program,
+                ata: revenueRecipientRewardTokenAccounts[i],
+              })
+            ),
             ix,
           ]);
         },
@@ -19,7 +19,7 @@ export class RestakingRewardReserveAccountContext extends BaseAccountContext<Res
           programAddress: this.program.address,
         }
       );
-      return ix.accounts[3].address;
+      return ix.accounts[4].address;
     });
   }
 
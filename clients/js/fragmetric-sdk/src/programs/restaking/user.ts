@@ -4,7 +4,14 @@ import * as token2022 from '@solana-program/token-2022';
 import {
   AccountRole,
   Address,
+  Base58EncodedBytes,
+  Base64EncodedBytes,
   createNoopSigner,
+  getAddressEncoder,
+  getBase64Decoder,
+  getBytesEncoder,
+  getProgramDerivedAddress,
+  getU64Encoder,
   isSome,
   none,
   some,
@@ -22,6 +29,7 @@ import {
   signMessageWithEd25519Keypair,
 } from './ed25519';
 import { getRestakingAnchorEventDecoders } from './events';
+import { RestakingFundWithdrawalRequestAccountContext } from './fund_withdrawal_request';
 import { RestakingReceiptTokenMintAccountContext } from './receipt_token_mint';
 import { RestakingUserFundAccountContext } from './user_fund';
 import { RestakingUserRewardAccountContext } from './user_reward';
@@ -45,6 +53,7 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
           userReceiptToken,
           userWrappedToken,
           userSupportedTokens,
+          userWithdrawalRequests,
         ] = await Promise.all([
           this.parent.fund.resolveAccount(noCache),
           this.resolveAccount(noCache),
@@ -52,6 +61,7 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
           this.receiptToken.resolveAccount(noCache),
           this.wrappedToken.resolveAccount(noCache),
           this.supportedTokens.resolveAccountTree(noCache),
+          this.withdrawalRequests.resolveAccountTree(noCache),
         ]);
         if (!(fund && user)) return null;
 
@@ -91,8 +101,19 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
           })
         );
 
-        const withdrawalRequests = (userFund?.data.withdrawalRequests.map(
-          (v) => {
+        // requests not migrated yet are still stored in the user fund account
+        const withdrawalRequests = (userFund?.data.withdrawalRequests ?? [])
+          .concat(
+            (userWithdrawalRequests ?? [])
+              .filter((v) => !!v)
+              .map((v) => v!.data.request)
+          )
+          .sort((a, b) =>
+            a.createdAt != b.createdAt
+              ? Number(a.createdAt - b.createdAt)
+              : Number(a.requestId - b.requestId)
+          )
+          .map((v) => {
             const assetMint = isSome(v.supportedTokenMint)
               ? v.supportedTokenMint.value
               : null;
@@ -115,8 +136,7 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
                   ? 'claimable'
                   : 'processing',
             };
-          }
-        ) ?? []) as {
+          }) as {
           requestId: bigint;
           batchId: bigint;
           receiptTokenAmount: bigint;
@@ -245,6 +265,106 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
     }
   );
 
+  readonly withdrawalRequests = new IterativeAccountContext(
+    this,
+    async (parent) => {
+      const [self, receiptTokenMint] = await Promise.all([
+        parent.resolveAddress(),
+        parent.parent.resolveAddress(),
+      ]);
+      if (!self || !receiptTokenMint) return null;
+      const accounts = await parent.runtime.rpc
+        .getProgramAccounts(parent.program.address, {
+          encoding: 'base64',
+          dataSlice: { offset: 0, length: 0 },
+          filters: [
+            {
+              memcmp: {
+                offset: 0n,
+                bytes: getBase64Decoder().decode(
+                  restaking.FUND_WITHDRAWAL_REQUEST_ACCOUNT_DISCRIMINATOR
+                ) as Base64EncodedBytes,
+                encoding: 'base64',
+              },
+            },
+            {
+              memcmp: {
+                offset: 11n, // receipt token mint
+                bytes: receiptTokenMint as string as Base58EncodedBytes,
+                encoding: 'base58',
+              },
+            },
+            {
+              memcmp: {
+                offset: 43n, // user
+                bytes: self as string as Base58EncodedBytes,
+                encoding: 'base58',
+              },
+            },
+          ],
+        })
+        .send();
+      return accounts.map((item) => item.pubkey).sort();
+    },
+    async (parent, address) => {
+      return new RestakingFundWithdrawalRequestAccountContext(parent, address);
+    }
+  );
+
+  async __getWithdrawalRequestAddress(
+    assetMint: string | null,
+    requestId: bigint
+  ): Promise<Address> {
+    const [address, _] = await getProgramDerivedAddress({
+      programAddress: this.program.address,
+      seeds: [
+        getBytesEncoder().encode(Buffer.from('withdrawal_request')),
+        getAddressEncoder().encode((await this.parent.resolveAddress())!), // receipt token mint
+        assetMint
+          ? getAddressEncoder().encode(assetMint as Address) // supported token mint
+          : new Uint8Array(32), // SOL
+        getU64Encoder().encode(requestId),
+      ],
+    });
+    return address as Address;
+  }
+
+  async __getMigrateWithdrawalRequestsInstruction(
+    user: Address,
+    receiptTokenMint: Address
+  ) {
+    const userFund = await this.fund.resolveAccount(true);
+    const legacyRequests = userFund?.data.withdrawalRequests ?? [];
+    if (!legacyRequests.length) return null;
+
+    const ix =
+      await restaking.getUserMigrateWithdrawalRequestsInstructionAsync(
+        {
+          user: createNoopSigner(user),
+          receiptTokenMint,
+          program: this.program.address,
+        },
+        {
+          programAddress: this.program.address,
+        }
+      );
+
+    // new request accounts in the same order with the requests stored in the user fund account
+    for (const request of legacyRequests) {
+      ix.accounts.push({
+        address: await this.__getWithdrawalRequestAddress(
+          isSome(request.supportedTokenMint)
+            ? request.supportedTokenMint.value
+            : null,
+          request.requestId
+        ),
+        role: AccountRole.WRITABLE,
+      });
+    }
+
+    return ix;
+  }
+
   private __resolveAddressLookupTable = (parent: this) =>
     parent.parent
       .resolve(true)
@@ -296,6 +416,12 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
         ),
         v.description('extra authorization is required to add deposit metadata')
       ),
+      minReceiptTokenAmount: v.pipe(
+        v.nullish(v.bigint(), null),
+        v.description(
+          'minimum receipt token amount to mint, null to skip slippage check'
+        )
+      ),
       skipUserFundAccountCreation: v.pipe(
         v.nullish(v.boolean(), false),
         v.description('skip user fund account creation')
@@ -417,6 +543,7 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
                       supportedTokenMint: args.assetMint as Address,
                       amount: args.assetAmount!,
                       metadata: args.metadata,
+                      minReceiptTokenAmount: args.minReceiptTokenAmount,
                     },
                     {
                       programAddress: self.program.address,
@@ -447,6 +574,7 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
                         ),
                       vaultReceiptTokenMint: args.assetMint as Address,
                       metadata: args.metadata,
+                      minReceiptTokenAmount: args.minReceiptTokenAmount,
                     },
                     {
                       programAddress: self.program.address,
@@ -460,6 +588,7 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
                       program: self.program.address,
                       amount: args.assetAmount!,
                       metadata: args.metadata,
+                      minReceiptTokenAmount: args.minReceiptTokenAmount,
                     },
                     {
                       programAddress: self.program.address,
@@ -497,6 +626,12 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
         v.bigint(),
         v.description('receipt token amount to withdraw')
       ),
+      minAssetAmount: v.pipe(
+        v.nullish(v.bigint(), null),
+        v.description(
+          'minimum asset amount to receive at the current price after fee, null to skip the check'
+        )
+      ),
       skipUserRewardAccountCreation: v.pipe(
         v.nullish(v.boolean(), false),
         v.description('skip user reward account creation')
@@ -517,11 +652,19 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
       addressLookupTables: [this.__resolveAddressLookupTable],
       instructions: [
         async (parent, args) => {
-          const [data, user] = await Promise.all([
+          const [data, fund, user] = await Promise.all([
             parent.parent.resolve(true),
+            parent.parent.fund.resolveAccount(true),
             parent.resolveAddress(),
           ]);
-          if (!(data && user)) throw new Error('invalid context');
+          if (!(data && fund && user)) throw new Error('invalid context');
+
+          const asset = args.assetMint
+            ? fund.data.supportedTokens
+                .slice(0, fund.data.numSupportedTokens)
+                .find((v) => v.mint == args.assetMint)?.token
+            : fund.data.sol;
+          if (!asset) throw new Error('invalid context: asset not found');
 
           return Promise.all([
             args.applyPresetComputeUnitLimit
@@ -560,10 +703,19 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
                     user: createNoopSigner(user),
                     receiptTokenMint: data.receiptTokenMint,
                     program: this.program.address,
+                    fundWithdrawalRequestAccount:
+                      await parent.__getWithdrawalRequestAddress(
+                        args.assetMint,
+                        asset.withdrawalLastCreatedRequestId + 1n
+                      ),
                     supportedTokenMint: args.assetMint
                       ? some(args.assetMint as Address)
                       : none(),
                     receiptTokenAmount: args.receiptTokenAmount,
+                    minAssetAmount:
+                      args.minAssetAmount != null
+                        ? some(args.minAssetAmount)
+                        : none(),
                   },
                   {
                     programAddress: this.program.address,
@@ -605,6 +757,7 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
       description: 'cancel a pending withdrawal request',
       anchorEventDecoders: getRestakingAnchorEventDecoders(
         'userCanceledWithdrawalRequestFromFund',
+        'userMigratedFundWithdrawalRequests',
         'userCreatedOrUpdatedFundAccount',
         'userCreatedOrUpdatedRewardAccount'
       ),
@@ -653,6 +806,10 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
                   }
                 )
               : null,
+            parent.__getMigrateWithdrawalRequestsInstruction(
+              user,
+              data.receiptTokenMint
+            ),
             (async () => {
               const ix =
                 await restaking.getUserCancelWithdrawalRequestInstructionAsync(
@@ -660,6 +817,11 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
                     user: createNoopSigner(user),
                     receiptTokenMint: data.receiptTokenMint,
                     program: this.program.address,
+                    fundWithdrawalRequestAccount:
+                      await parent.__getWithdrawalRequestAddress(
+                        args.assetMint,
+                        args.requestId
+                      ),
                     supportedTokenMint: args.assetMint
                       ? some(args.assetMint as Address)
                       : none(),
@@ -705,6 +867,7 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
       description: 'claim redeemed assets from a processed withdrawal request',
       anchorEventDecoders: getRestakingAnchorEventDecoders(
         'userWithdrewFromFund',
+        'userMigratedFundWithdrawalRequests',
         'userCreatedOrUpdatedFundAccount',
         'userCreatedOrUpdatedRewardAccount'
       ),
@@ -762,6 +925,10 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
                   }
                 )
               : null,
+            parent.__getMigrateWithdrawalRequestsInstruction(
+              user,
+              data.receiptTokenMint
+            ),
             (async () => {
               const ix = await (args.assetMint
                 ? restaking.getUserWithdrawSupportedTokenInstructionAsync(
@@ -986,6 +1153,7 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
                 program: this.program.address,
                 amount: 0,
                 metadata: null,
+                minReceiptTokenAmount: null,
               },
               {
                 programAddress: this.program.address,
@@ -998,6 +1166,7 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
                 program: this.program.address,
                 amount: 0,
                 metadata: null,
+                minReceiptTokenAmount: null,
               },
               {
                 programAddress: this.program.address,
@@ -1016,15 +1185,15 @@ export class RestakingUserAccountContext extends BaseAccountContext<RestakingRec
               }
             ),
           ]);
-          const src = ixSrc.accounts[5].address;
-          const fund = ixSrc.accounts[6].address;
-          const srcFund = ixSrc.accounts[8].address;
-          const reward = ixSrc.accounts[9].address;
-          const srcReward = ixSrc.accounts[10].address;
-          const eventAuthority = ixSrc.accounts[12].address;
-          const dst = ixDst.accounts[5].address;
-          const dstFund = ixDst.accounts[8].address;
-          const dstReward = ixDst.accounts[10].address;
+          const src = ixSrc.accounts[6].address;
+          const fund = ixSrc.accounts[7].address;
+          const srcFund = ixSrc.accounts[9].address;
+          const reward = ixSrc.accounts[10].address;
+          const srcReward = ixSrc.accounts[11].address;
+          const eventAuthority = ixSrc.accounts[13].address;
+          const dst = ixDst.accounts[6].address;
+          const dstFund = ixDst.accounts[9].address;
+          const dstReward = ixDst.accounts[11].address;
           const extraAccountMetaList = ixHook.accounts[4].address;
           const extraAccounts = [
             [fund, true],
@@ -104,6 +104,36 @@ export class RestakingUserFundAccountContext extends AccountContext<
     }
   );
 
+  readonly migrateWithdrawalRequests = new TransactionTemplateContext(
+    this,
+    null,
+    {
+      description:
+        'move withdrawal requests stored in user fund account to withdrawal request accounts',
+      anchorEventDecoders: getRestakingAnchorEventDecoders(
+        'userMigratedFundWithdrawalRequests'
+      ),
+      instructions: [
+        async (parent, args) => {
+          const [receiptTokenMint, user] = await Promise.all([
+            parent.parent.parent.resolveAddress(true),
+            parent.parent.resolveAddress(true),
+          ]);
+          if (!(receiptTokenMint && user)) throw new Error('invalid context');
+
+          const ix =
+            await parent.parent.__getMigrateWithdrawalRequestsInstruction(
+              user,
+              receiptTokenMint
+            );
+          if (!ix) throw new Error('invalid context: no requests to migrate');
+
+          return Promise.all([ix]);
+        },
+      ],
+    }
+  );
+
   readonly closeAccount = new TransactionTemplateContext(this, null, {
     description: 'close user fund account',
     anchorEventDecoders: getRestakingAnchorEventDecoders(
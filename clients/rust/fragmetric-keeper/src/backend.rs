@@ -0,0 +1,104 @@
+use anyhow::{anyhow, Context};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_sdk::account::from_account;
+use solana_sdk::clock::Clock;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::sysvar;
+use solana_sdk::transaction::VersionedTransaction;
+
+/// Cluster access of the keeper, so that it can run against an RPC node or an in-process SVM.
+pub trait KeeperBackend {
+    fn get_account_data(&self, address: &Pubkey) -> anyhow::Result<Option<Vec<u8>>>;
+
+    fn get_latest_blockhash(&self) -> anyhow::Result<Hash>;
+
+    /// unix timestamp of the cluster clock, which is used to expire operation commands.
+    fn get_unix_timestamp(&self) -> anyhow::Result<i64>;
+
+    /// returns the return data of the transaction even if it fails.
+    fn simulate_transaction(
+        &self,
+        transaction: &VersionedTransaction,
+    ) -> anyhow::Result<Option<Vec<u8>>>;
+
+    fn send_transaction(&self, transaction: &VersionedTransaction) -> anyhow::Result<Signature>;
+}
+
+/// So that a keeper can borrow a backend shared with others, e.g. a test harness.
+impl<B: KeeperBackend + ?Sized> KeeperBackend for &B {
+    fn get_account_data(&self, address: &Pubkey) -> anyhow::Result<Option<Vec<u8>>> {
+        (**self).get_account_data(address)
+    }
+
+    fn get_latest_blockhash(&self) -> anyhow::Result<Hash> {
+        (**self).get_latest_blockhash()
+    }
+
+    fn get_unix_timestamp(&self) -> anyhow::Result<i64> {
+        (**self).get_unix_timestamp()
+    }
+
+    fn simulate_transaction(
+        &self,
+        transaction: &VersionedTransaction,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        (**self).simulate_transaction(transaction)
+    }
+
+    fn send_transaction(&self, transaction: &VersionedTransaction) -> anyhow::Result<Signature> {
+        (**self).send_transaction(transaction)
+    }
+}
+
+impl KeeperBackend for RpcClient {
+    fn get_account_data(&self, address: &Pubkey) -> anyhow::Result<Option<Vec<u8>>> {
+        Ok(self
+            .get_account_with_commitment(address, self.commitment())?
+            .value
+            .map(|account| account.data))
+    }
+
+    fn get_latest_blockhash(&self) -> anyhow::Result<Hash> {
+        Ok(RpcClient::get_latest_blockhash(self)?)
+    }
+
+    fn get_unix_timestamp(&self) -> anyhow::Result<i64> {
+        let account = self.get_account(&sysvar::clock::ID)?;
+        let clock: Clock = from_account(&account).context("invalid clock sysvar")?;
+        Ok(clock.unix_timestamp)
+    }
+
+    fn simulate_transaction(
+        &self,
+        transaction: &VersionedTransaction,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let result = self.simulate_transaction_with_config(
+            transaction,
+            RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                commitment: Some(CommitmentConfig::confirmed()),
+                ..Default::default()
+            },
+        )?;
+
+        result
+            .value
+            .return_data
+            .map(|return_data| {
+                BASE64_STANDARD
+                    .decode(&return_data.data.0)
+                    .map_err(|err| anyhow!("invalid return data: {err}"))
+            })
+            .transpose()
+    }
+
+    fn send_transaction(&self, transaction: &VersionedTransaction) -> anyhow::Result<Signature> {
+        Ok(self.send_and_confirm_transaction(transaction)?)
+    }
+}
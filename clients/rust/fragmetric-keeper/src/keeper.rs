@@ -0,0 +1,382 @@
+use anchor_lang::AnchorDeserialize;
+use anyhow::{anyhow, bail};
+use fragmetric_sdk::modules::fund::commands::{
+    OperationCommandAccountMeta, OperationCommandEntry, OperationCommandPlan,
+};
+use fragmetric_sdk::modules::fund::FundAccount;
+use fragmetric_sdk::{
+    build_operator_initialize_fund_operation_history_account_instruction,
+    build_operator_plan_fund_commands_instruction, build_operator_run_fund_command_instruction,
+    decode_fund_account, find_fund_operation_history_account_address, get_next_operation_command,
+    FundAddresses,
+};
+use solana_sdk::address_lookup_table::state::AddressLookupTable;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::message::{v0, AddressLookupTableAccount, VersionedMessage};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::VersionedTransaction;
+
+use crate::KeeperBackend;
+
+#[derive(Clone, Debug)]
+pub struct KeeperConfig {
+    /// max number of chained commands to run in a transaction.
+    pub max_steps_per_transaction: u8,
+    /// max number of transactions to run in a cycle, to stop a cycle which does not complete.
+    pub max_transactions_per_cycle: usize,
+    /// chained commands are not added if the transaction would lock more accounts than this.
+    pub max_accounts_per_transaction: usize,
+    pub compute_unit_limit: u32,
+    pub compute_unit_price_micro_lamports: u64,
+}
+
+impl Default for KeeperConfig {
+    fn default() -> Self {
+        Self {
+            max_steps_per_transaction: 4,
+            max_transactions_per_cycle: 64,
+            max_accounts_per_transaction: 64,
+            compute_unit_limit: 1_400_000,
+            compute_unit_price_micro_lamports: 0,
+        }
+    }
+}
+
+/// Result of a keeper transaction.
+#[derive(Clone, Debug)]
+pub struct KeeperRun {
+    pub signature: Signature,
+    /// sequence and command discriminant of the first command of the transaction.
+    pub sequence: u16,
+    pub command: u8,
+    /// the operation cycle has been completed by the transaction.
+    pub completed: bool,
+}
+
+/// Drives the operation cycle of a fund by running `operator_run_fund_command` transactions.
+pub struct Keeper<B: KeeperBackend> {
+    backend: B,
+    operator: Keypair,
+    fund: FundAddresses,
+    config: KeeperConfig,
+}
+
+impl<B: KeeperBackend> Keeper<B> {
+    pub fn new(
+        backend: B,
+        operator: Keypair,
+        receipt_token_mint: Pubkey,
+        config: KeeperConfig,
+    ) -> Self {
+        Self {
+            backend,
+            operator,
+            fund: FundAddresses::new(&receipt_token_mint),
+            config,
+        }
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    pub fn fetch_fund_account(&self) -> anyhow::Result<Box<FundAccount>> {
+        let data = self
+            .backend
+            .get_account_data(&self.fund.fund_account)?
+            .ok_or_else(|| anyhow!("fund account not found: {}", self.fund.fund_account))?;
+        Ok(decode_fund_account(&data)?)
+    }
+
+    /// Runs transactions until the operation cycle is completed.
+    pub fn run_cycle(&self) -> anyhow::Result<Vec<KeeperRun>> {
+        let mut runs = Vec::new();
+        while runs.len() < self.config.max_transactions_per_cycle {
+            let run = self.run_once()?;
+            let completed = run.completed;
+            runs.push(run);
+            if completed {
+                return Ok(runs);
+            }
+        }
+
+        bail!(
+            "operation cycle is not completed in {} transactions",
+            self.config.max_transactions_per_cycle
+        )
+    }
+
+    /// Runs the next command and chained commands as many as their accounts fit in a transaction.
+    pub fn run_once(&self) -> anyhow::Result<KeeperRun> {
+        let fund_account = self.fetch_fund_account()?;
+        if !fund_account.is_operation_enabled() {
+            bail!("operation is disabled: {}", self.fund.fund_account);
+        }
+
+        let (sequence, entry) =
+            get_next_operation_command(&fund_account, self.backend.get_unix_timestamp()?)?;
+        let address_lookup_tables = self.fetch_address_lookup_tables(&fund_account)?;
+
+        // initialize the next page of the operation history ahead, before the current page gets full.
+        if let Some(page_index) = fund_account.get_operation_history_page_index_to_initialize() {
+            let instruction = build_operator_initialize_fund_operation_history_account_instruction(
+                &self.operator.pubkey(),
+                &self.fund.receipt_token_mint,
+                page_index,
+            );
+            self.backend
+                .send_transaction(&self.build_transaction(instruction, &address_lookup_tables)?)?;
+        }
+
+        let remaining_accounts =
+            self.resolve_remaining_accounts(&fund_account, &entry, &address_lookup_tables)?;
+
+        let instruction = build_operator_run_fund_command_instruction(
+            &self.operator.pubkey(),
+            &fund_account,
+            Some(self.config.max_steps_per_transaction),
+            remaining_accounts,
+        );
+        let signature = self
+            .backend
+            .send_transaction(&self.build_transaction(instruction, &address_lookup_tables)?)?;
+
+        let (_, next_command) = self.fetch_fund_account()?.get_operation_next_command()?;
+        Ok(KeeperRun {
+            signature,
+            sequence,
+            command: entry.command.discriminant(),
+            completed: next_command.is_none(),
+        })
+    }
+
+    /// returns required accounts of the next command and the following commands, up to the transaction limits.
+    /// Pricing sources are appended by the instruction builder.
+    fn resolve_remaining_accounts(
+        &self,
+        fund_account: &FundAccount,
+        entry: &OperationCommandEntry,
+        address_lookup_tables: &[AddressLookupTableAccount],
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        let mut remaining_accounts = Vec::new();
+        // chained commands may fill the current page of the operation history and move to the next page.
+        if let Some(page_index) = fund_account.get_operation_history_page_index() {
+            remaining_accounts.extend([page_index, page_index + 1].map(|page_index| {
+                AccountMeta::new(
+                    find_fund_operation_history_account_address(
+                        &self.fund.receipt_token_mint,
+                        page_index,
+                    ),
+                    false,
+                )
+            }));
+        }
+        extend_account_metas(&mut remaining_accounts, &entry.required_accounts);
+
+        // plan the chained commands by simulation, then add their required accounts as many as they fit.
+        let instruction = build_operator_plan_fund_commands_instruction(
+            &self.operator.pubkey(),
+            &self.fund.receipt_token_mint,
+            self.config.max_steps_per_transaction,
+        );
+        let Some(return_data) = self
+            .backend
+            .simulate_transaction(&self.build_transaction(instruction, address_lookup_tables)?)?
+        else {
+            return Ok(remaining_accounts);
+        };
+        let plan = OperationCommandPlan::try_from_slice(&return_data)?;
+        for step in plan.steps.iter().skip(1) {
+            let mut planned_accounts = remaining_accounts.clone();
+            extend_account_metas(&mut planned_accounts, &step.required_accounts);
+            if planned_accounts.len() + fund_account.get_pricing_source_addresses().len()
+                > self.config.max_accounts_per_transaction
+            {
+                break;
+            }
+            remaining_accounts = planned_accounts;
+        }
+
+        Ok(remaining_accounts)
+    }
+
+    fn fetch_address_lookup_tables(
+        &self,
+        fund_account: &FundAccount,
+    ) -> anyhow::Result<Vec<AddressLookupTableAccount>> {
+        let Some(address) = fund_account.get_address_lookup_table_account() else {
+            return Ok(Vec::new());
+        };
+        let data = self
+            .backend
+            .get_account_data(&address)?
+            .ok_or_else(|| anyhow!("address lookup table not found: {address}"))?;
+        let table = AddressLookupTable::deserialize(&data)?;
+
+        Ok(vec![AddressLookupTableAccount {
+            key: address,
+            addresses: table.addresses.to_vec(),
+        }])
+    }
+
+    fn build_transaction(
+        &self,
+        instruction: Instruction,
+        address_lookup_tables: &[AddressLookupTableAccount],
+    ) -> anyhow::Result<VersionedTransaction> {
+        let mut instructions = vec![ComputeBudgetInstruction::set_compute_unit_limit(
+            self.config.compute_unit_limit,
+        )];
+        if self.config.compute_unit_price_micro_lamports > 0 {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+                self.config.compute_unit_price_micro_lamports,
+            ));
+        }
+        instructions.push(instruction);
+
+        let message = v0::Message::try_compile(
+            &self.operator.pubkey(),
+            &instructions,
+            address_lookup_tables,
+            self.backend.get_latest_blockhash()?,
+        )?;
+        Ok(VersionedTransaction::try_new(
+            VersionedMessage::V0(message),
+            &[&self.operator],
+        )?)
+    }
+}
+
+/// Appends accounts which are not included yet, promoting to writable if required.
+fn extend_account_metas(
+    account_metas: &mut Vec<AccountMeta>,
+    required_accounts: &[OperationCommandAccountMeta],
+) {
+    for required_account in required_accounts {
+        match account_metas
+            .iter_mut()
+            .find(|account_meta| account_meta.pubkey == required_account.pubkey)
+        {
+            Some(account_meta) => account_meta.is_writable |= required_account.is_writable,
+            None => account_metas.push(if required_account.is_writable {
+                AccountMeta::new(required_account.pubkey, false)
+            } else {
+                AccountMeta::new_readonly(required_account.pubkey, false)
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::RefCell;
+
+    use anchor_lang::Discriminator;
+    use bytemuck::Zeroable;
+    use solana_sdk::hash::Hash;
+
+    use super::*;
+
+    struct MockBackend {
+        fund_account: Pubkey,
+        fund_account_data: Vec<u8>,
+        unix_timestamp: i64,
+        sent_transactions: RefCell<Vec<VersionedTransaction>>,
+    }
+
+    impl KeeperBackend for MockBackend {
+        fn get_account_data(&self, address: &Pubkey) -> anyhow::Result<Option<Vec<u8>>> {
+            Ok((*address == self.fund_account).then(|| self.fund_account_data.clone()))
+        }
+
+        fn get_latest_blockhash(&self) -> anyhow::Result<Hash> {
+            Ok(Hash::new_unique())
+        }
+
+        fn get_unix_timestamp(&self) -> anyhow::Result<i64> {
+            Ok(self.unix_timestamp)
+        }
+
+        fn simulate_transaction(
+            &self,
+            _transaction: &VersionedTransaction,
+        ) -> anyhow::Result<Option<Vec<u8>>> {
+            Ok(None)
+        }
+
+        fn send_transaction(
+            &self,
+            transaction: &VersionedTransaction,
+        ) -> anyhow::Result<Signature> {
+            self.sent_transactions
+                .borrow_mut()
+                .push(transaction.clone());
+            Ok(transaction.signatures[0])
+        }
+    }
+
+    fn create_keeper() -> Keeper<MockBackend> {
+        let receipt_token_mint = Pubkey::new_unique();
+        let mut fund_account = FundAccount::zeroed();
+        fund_account.receipt_token_mint = receipt_token_mint;
+        let mut fund_account_data = FundAccount::DISCRIMINATOR.to_vec();
+        fund_account_data.extend_from_slice(bytemuck::bytes_of(&fund_account));
+
+        Keeper::new(
+            MockBackend {
+                fund_account: FundAddresses::new(&receipt_token_mint).fund_account,
+                fund_account_data,
+                unix_timestamp: 1_000,
+                sent_transactions: RefCell::new(Vec::new()),
+            },
+            Keypair::new(),
+            receipt_token_mint,
+            KeeperConfig::default(),
+        )
+    }
+
+    #[test]
+    fn test_get_next_command_resets_expired_command() {
+        let keeper = create_keeper();
+        let fund_account = keeper.fetch_fund_account().unwrap();
+
+        let (sequence, entry) = get_next_operation_command(&fund_account, 1_000).unwrap();
+        assert_eq!(sequence, 0);
+        assert_eq!(entry.command.discriminant(), 1);
+        assert!(entry.required_accounts.is_empty());
+
+        keeper.run_once().unwrap_err();
+        assert!(keeper.backend().sent_transactions.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_extend_account_metas() {
+        let account1 = Pubkey::new_unique();
+        let account2 = Pubkey::new_unique();
+        let mut account_metas = vec![AccountMeta::new_readonly(account1, false)];
+
+        extend_account_metas(
+            &mut account_metas,
+            &[
+                OperationCommandAccountMeta {
+                    pubkey: account1,
+                    is_writable: true,
+                },
+                OperationCommandAccountMeta {
+                    pubkey: account2,
+                    is_writable: false,
+                },
+            ],
+        );
+
+        assert_eq!(
+            account_metas,
+            vec![
+                AccountMeta::new(account1, false),
+                AccountMeta::new_readonly(account2, false),
+            ],
+        );
+    }
+}
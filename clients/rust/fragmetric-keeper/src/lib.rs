@@ -0,0 +1,15 @@
+//! Keeper which drives the operation cycle of Fragmetric funds.
+//!
+//! It reads the next operation command of the fund account, resolves the required accounts of the command
+//! and the chained commands by simulating `operator_plan_fund_commands`, then runs them with
+//! `operator_run_fund_command` through a [`KeeperBackend`], which is an RPC node or an in-process SVM.
+
+mod backend;
+mod keeper;
+#[cfg(feature = "litesvm")]
+mod litesvm;
+
+pub use backend::*;
+pub use keeper::*;
+#[cfg(feature = "litesvm")]
+pub use litesvm::*;
@@ -0,0 +1,69 @@
+use core::cell::RefCell;
+
+use anyhow::anyhow;
+use litesvm::LiteSVM;
+use solana_sdk::clock::Clock;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::VersionedTransaction;
+
+use crate::KeeperBackend;
+
+/// In-process SVM backend to test the keeper with the program's `.so`.
+pub struct LiteSVMBackend {
+    svm: RefCell<LiteSVM>,
+}
+
+impl LiteSVMBackend {
+    pub fn new(svm: LiteSVM) -> Self {
+        Self {
+            svm: RefCell::new(svm),
+        }
+    }
+
+    /// Access to the SVM to set up accounts or warp the clock between keeper runs.
+    pub fn svm_mut(&self) -> core::cell::RefMut<'_, LiteSVM> {
+        self.svm.borrow_mut()
+    }
+}
+
+impl KeeperBackend for LiteSVMBackend {
+    fn get_account_data(&self, address: &Pubkey) -> anyhow::Result<Option<Vec<u8>>> {
+        Ok(self
+            .svm
+            .borrow()
+            .get_account(address)
+            .map(|account| account.data))
+    }
+
+    fn get_latest_blockhash(&self) -> anyhow::Result<Hash> {
+        Ok(self.svm.borrow().latest_blockhash())
+    }
+
+    fn get_unix_timestamp(&self) -> anyhow::Result<i64> {
+        Ok(self.svm.borrow().get_sysvar::<Clock>().unix_timestamp)
+    }
+
+    fn simulate_transaction(
+        &self,
+        transaction: &VersionedTransaction,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let meta = match self.svm.borrow().simulate_transaction(transaction.clone()) {
+            Ok(info) => info.meta,
+            Err(failed) => failed.meta,
+        };
+
+        Ok((!meta.return_data.data.is_empty()).then_some(meta.return_data.data))
+    }
+
+    fn send_transaction(&self, transaction: &VersionedTransaction) -> anyhow::Result<Signature> {
+        let mut svm = self.svm.borrow_mut();
+        let result = svm.send_transaction(transaction.clone());
+        svm.expire_blockhash();
+
+        result
+            .map(|meta| meta.signature)
+            .map_err(|failed| anyhow!("{}\n{}", failed.err, failed.meta.logs.join("\n")))
+    }
+}
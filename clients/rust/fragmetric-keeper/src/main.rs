@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use fragmetric_keeper::{Keeper, KeeperConfig};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::read_keypair_file;
+
+const USAGE: &str =
+    "usage: fragmetric-keeper <rpc_url> <operator_keypair_path> <receipt_token_mint> [interval_seconds]";
+
+/// Runs an operation cycle, then repeats it every `interval_seconds` if given.
+fn main() -> anyhow::Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let [rpc_url, operator_keypair_path, receipt_token_mint, rest @ ..] = args.as_slice() else {
+        return Err(anyhow!(USAGE));
+    };
+    let interval = rest
+        .first()
+        .map(|interval_seconds| interval_seconds.parse().map(Duration::from_secs))
+        .transpose()
+        .context(USAGE)?;
+
+    let keeper = Keeper::new(
+        RpcClient::new_with_commitment(rpc_url.clone(), CommitmentConfig::confirmed()),
+        read_keypair_file(operator_keypair_path)
+            .map_err(|err| anyhow!("failed to read operator keypair: {err}"))?,
+        receipt_token_mint.parse::<Pubkey>().context(USAGE)?,
+        KeeperConfig::default(),
+    );
+
+    loop {
+        match keeper.run_cycle() {
+            Ok(runs) => {
+                for run in runs {
+                    println!(
+                        "COMMAND#{} ({}) ran: {}{}",
+                        run.sequence,
+                        run.command,
+                        run.signature,
+                        if run.completed {
+                            ", cycle completed"
+                        } else {
+                            ""
+                        },
+                    );
+                }
+            }
+            Err(err) => eprintln!("operation cycle failed: {err:#}"),
+        }
+
+        let Some(interval) = interval else {
+            return Ok(());
+        };
+        std::thread::sleep(interval);
+    }
+}
@@ -0,0 +1,346 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use anchor_spl::{token, token_2022};
+use restaking::modules::role::Role;
+use restaking::{accounts, instruction, ID};
+
+use super::build_instruction;
+use crate::{
+    find_event_authority_address, find_fund_operation_history_account_address,
+    find_normalized_token_pool_account_address, find_role_account_address, FundAddresses,
+};
+
+pub fn get_admin_fund_context(
+    admin: &Pubkey,
+    receipt_token_mint: &Pubkey,
+) -> accounts::AdminFundContext {
+    accounts::AdminFundContext {
+        admin: *admin,
+        role_account: find_role_account_address(),
+        fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+        receipt_token_mint: *receipt_token_mint,
+        event_authority: find_event_authority_address(),
+        program: ID,
+    }
+}
+
+/// The admin must be the one hardcoded in the program.
+pub fn build_admin_initialize_role_account_instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    fund_manager: &Pubkey,
+    operator: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::AdminRoleAccountInitialContext {
+            payer: *payer,
+            admin: *admin,
+            system_program: system_program::ID,
+            role_account: find_role_account_address(),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::AdminInitializeRoleAccount {
+            fund_manager: *fund_manager,
+            operator: *operator,
+        },
+        vec![],
+    )
+}
+
+/// The new authority accepts the role by [`build_role_authority_accept_role_transfer_instruction`].
+pub fn build_admin_propose_role_transfer_instruction(
+    admin: &Pubkey,
+    role: Role,
+    new_authority: Option<Pubkey>,
+) -> Instruction {
+    build_instruction(
+        accounts::AdminRoleContext {
+            admin: *admin,
+            role_account: find_role_account_address(),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::AdminProposeRoleTransfer {
+            role,
+            new_authority,
+        },
+        vec![],
+    )
+}
+
+pub fn build_role_authority_accept_role_transfer_instruction(
+    new_authority: &Pubkey,
+    role: Role,
+) -> Instruction {
+    build_instruction(
+        accounts::RoleAuthorityRoleContext {
+            new_authority: *new_authority,
+            role_account: find_role_account_address(),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::RoleAuthorityAcceptRoleTransfer { role },
+        vec![],
+    )
+}
+
+/// The fund account must be updated by [`build_admin_update_fund_account_if_needed_instruction`]
+/// until it reaches the latest version.
+pub fn build_admin_initialize_fund_account_instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    receipt_token_mint: &Pubkey,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::AdminFundAccountInitialContext {
+            payer: *payer,
+            admin: *admin,
+            role_account: find_role_account_address(),
+            system_program: system_program::ID,
+            receipt_token_mint: fund.receipt_token_mint,
+            receipt_token_program: token_2022::ID,
+            fund_account: fund.fund_account,
+            fund_receipt_token_lock_account: fund.fund_receipt_token_lock_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            fund_treasury_account: fund.fund_treasury_account,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::AdminInitializeFundAccount {},
+        vec![],
+    )
+}
+
+pub fn build_admin_update_fund_account_if_needed_instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    desired_account_size: Option<u32>,
+) -> Instruction {
+    build_instruction(
+        accounts::AdminFundAccountUpdateContext {
+            payer: *payer,
+            admin: *admin,
+            role_account: find_role_account_address(),
+            system_program: system_program::ID,
+            receipt_token_mint: *receipt_token_mint,
+            fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::AdminUpdateFundAccountIfNeeded {
+            desired_account_size,
+        },
+        vec![],
+    )
+}
+
+pub fn build_admin_set_address_lookup_table_account_instruction(
+    admin: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    address_lookup_table_account: Option<Pubkey>,
+) -> Instruction {
+    build_instruction(
+        get_admin_fund_context(admin, receipt_token_mint),
+        instruction::AdminSetAddressLookupTableAccount {
+            address_lookup_table_account,
+        },
+        vec![],
+    )
+}
+
+pub fn build_admin_close_fund_operation_history_account_instruction(
+    admin: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    page_index: u64,
+) -> Instruction {
+    build_instruction(
+        accounts::AdminFundOperationHistoryContext {
+            admin: *admin,
+            role_account: find_role_account_address(),
+            receipt_token_mint: *receipt_token_mint,
+            fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+            fund_operation_history_account: find_fund_operation_history_account_address(
+                receipt_token_mint,
+                page_index,
+            ),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::AdminCloseFundOperationHistoryAccount {
+            _page_index: page_index,
+        },
+        vec![],
+    )
+}
+
+/// The mint authority of the normalized token is handed over to the pool.
+pub fn build_admin_initialize_normalized_token_pool_account_instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    normalized_token_mint: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::AdminNormalizedTokenPoolInitialContext {
+            payer: *payer,
+            admin: *admin,
+            role_account: find_role_account_address(),
+            system_program: system_program::ID,
+            normalized_token_program: token::ID,
+            normalized_token_mint: *normalized_token_mint,
+            normalized_token_pool_account: find_normalized_token_pool_account_address(
+                normalized_token_mint,
+            ),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::AdminInitializeNormalizedTokenPoolAccount {},
+        vec![],
+    )
+}
+
+pub fn build_admin_update_normalized_token_pool_account_if_needed_instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    normalized_token_mint: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::AdminNormalizedTokenPoolUpdateContext {
+            payer: *payer,
+            admin: *admin,
+            role_account: find_role_account_address(),
+            system_program: system_program::ID,
+            normalized_token_program: token::ID,
+            normalized_token_mint: *normalized_token_mint,
+            normalized_token_pool_account: find_normalized_token_pool_account_address(
+                normalized_token_mint,
+            ),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::AdminUpdateNormalizedTokenPoolAccountIfNeeded {},
+        vec![],
+    )
+}
+
+pub fn build_admin_initialize_extra_account_meta_list_instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    receipt_token_mint: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::AdminReceiptTokenMintExtraAccountMetaListInitialContext {
+            payer: *payer,
+            admin: *admin,
+            role_account: find_role_account_address(),
+            system_program: system_program::ID,
+            receipt_token_mint: *receipt_token_mint,
+            extra_account_meta_list: FundAddresses::new(receipt_token_mint).extra_account_meta_list,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::AdminInitializeExtraAccountMetaList {},
+        vec![],
+    )
+}
+
+pub fn build_admin_update_extra_account_meta_list_if_needed_instruction(
+    admin: &Pubkey,
+    receipt_token_mint: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::AdminReceiptTokenMintExtraAccountMetaListUpdateContext {
+            admin: *admin,
+            role_account: find_role_account_address(),
+            receipt_token_mint: *receipt_token_mint,
+            extra_account_meta_list: FundAddresses::new(receipt_token_mint).extra_account_meta_list,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::AdminUpdateExtraAccountMetaListIfNeeded {},
+        vec![],
+    )
+}
+
+/// The reward account must be updated by [`build_admin_update_reward_account_if_needed_instruction`]
+/// until it reaches the latest version.
+pub fn build_admin_initialize_reward_account_instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    receipt_token_mint: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::AdminRewardAccountInitialContext {
+            payer: *payer,
+            admin: *admin,
+            role_account: find_role_account_address(),
+            system_program: system_program::ID,
+            receipt_token_mint: *receipt_token_mint,
+            reward_account: FundAddresses::new(receipt_token_mint).reward_account,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::AdminInitializeRewardAccount {},
+        vec![],
+    )
+}
+
+pub fn build_admin_update_reward_account_if_needed_instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    desired_account_size: Option<u32>,
+) -> Instruction {
+    build_instruction(
+        accounts::AdminRewardAccountUpdateContext {
+            payer: *payer,
+            admin: *admin,
+            role_account: find_role_account_address(),
+            system_program: system_program::ID,
+            receipt_token_mint: *receipt_token_mint,
+            reward_account: FundAddresses::new(receipt_token_mint).reward_account,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::AdminUpdateRewardAccountIfNeeded {
+            desired_account_size,
+        },
+        vec![],
+    )
+}
+
+/// Creates or updates the reward account of a user, e.g. a token account which holds the receipt token,
+/// whose receipt token account must exist.
+pub fn build_admin_create_user_reward_account_idempotent_instruction(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    desired_account_size: Option<u32>,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::AdminUserRewardAccountInitOrUpdateContext {
+            payer: *payer,
+            admin: *admin,
+            role_account: find_role_account_address(),
+            user: *user,
+            receipt_token_mint: fund.receipt_token_mint,
+            user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            system_program: system_program::ID,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::AdminCreateUserRewardAccountIdempotent {
+            desired_account_size,
+        },
+        vec![],
+    )
+}
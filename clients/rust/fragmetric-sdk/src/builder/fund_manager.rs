@@ -0,0 +1,1126 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::{token, token_2022};
+use restaking::modules::fund::{FundAccount, FundConfigurationChange, FundRevenueRecipient};
+use restaking::modules::pricing::TokenPricingSource;
+use restaking::modules::swap::TokenSwapSource;
+use restaking::{accounts, instruction, ID};
+
+use super::build_instruction;
+use crate::{
+    find_event_authority_address, find_fund_configuration_change_account_address,
+    find_normalized_token_pool_account_address, find_role_account_address,
+    find_user_reward_account_address, get_pricing_source_account_metas,
+    get_pricing_source_account_metas_including, FundAddresses,
+};
+
+pub fn get_fund_manager_fund_context(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+) -> accounts::FundManagerFundContext {
+    accounts::FundManagerFundContext {
+        fund_manager: *fund_manager,
+        role_account: find_role_account_address(),
+        receipt_token_mint: *receipt_token_mint,
+        fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+        event_authority: find_event_authority_address(),
+        program: ID,
+    }
+}
+
+/// `reward_token` is (mint, program) of a claimable reward token,
+/// which is held by the associated token account of the reward reserve account.
+pub fn get_fund_manager_reward_context(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    reward_token: Option<(&Pubkey, &Pubkey)>,
+) -> accounts::FundManagerRewardContext {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    accounts::FundManagerRewardContext {
+        fund_manager: *fund_manager,
+        role_account: find_role_account_address(),
+        receipt_token_mint: fund.receipt_token_mint,
+        reward_account: fund.reward_account,
+        reward_reserve_account: fund.reward_reserve_account,
+        reward_token_mint: reward_token.map(|(mint, _)| *mint),
+        reward_token_program: reward_token.map(|(_, program)| *program),
+        reward_token_reserve_account: reward_token.map(|(mint, program)| {
+            get_associated_token_address_with_program_id(
+                &fund.reward_reserve_account,
+                mint,
+                program,
+            )
+        }),
+        event_authority: find_event_authority_address(),
+        program: ID,
+    }
+}
+
+pub fn get_fund_manager_fund_restaking_vault_reward_context(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    reward_token_mint: &Pubkey,
+) -> accounts::FundManagerFundRestakingVaultRewardContext {
+    accounts::FundManagerFundRestakingVaultRewardContext {
+        fund_manager: *fund_manager,
+        role_account: find_role_account_address(),
+        receipt_token_mint: *receipt_token_mint,
+        fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+        reward_token_mint: *reward_token_mint,
+        event_authority: find_event_authority_address(),
+        program: ID,
+    }
+}
+
+pub fn get_fund_manager_fund_token_swap_strategy_context(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    from_token_mint: &Pubkey,
+    to_token_mint: &Pubkey,
+    swap_source_account: &Pubkey,
+) -> accounts::FundManagerFundTokenSwapStrategyContext {
+    accounts::FundManagerFundTokenSwapStrategyContext {
+        fund_manager: *fund_manager,
+        role_account: find_role_account_address(),
+        receipt_token_mint: *receipt_token_mint,
+        fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+        from_token_mint: *from_token_mint,
+        to_token_mint: *to_token_mint,
+        swap_source_account: *swap_source_account,
+        event_authority: find_event_authority_address(),
+        program: ID,
+    }
+}
+
+pub fn get_fund_manager_normalized_token_pool_supported_token_context(
+    fund_manager: &Pubkey,
+    normalized_token_mint: &Pubkey,
+    supported_token_mint: &Pubkey,
+    supported_token_program: &Pubkey,
+) -> accounts::FundManagerNormalizedTokenPoolSupportedTokenContext {
+    let normalized_token_pool_account =
+        find_normalized_token_pool_account_address(normalized_token_mint);
+
+    accounts::FundManagerNormalizedTokenPoolSupportedTokenContext {
+        fund_manager: *fund_manager,
+        role_account: find_role_account_address(),
+        normalized_token_mint: *normalized_token_mint,
+        normalized_token_pool_account,
+        normalized_token_program: token::ID,
+        supported_token_mint: *supported_token_mint,
+        supported_token_program: *supported_token_program,
+        normalized_token_pool_supported_token_account: get_associated_token_address_with_program_id(
+            &normalized_token_pool_account,
+            supported_token_mint,
+            supported_token_program,
+        ),
+        event_authority: find_event_authority_address(),
+        program: ID,
+    }
+}
+
+/// `wrapped_token_holder` is a token account of the wrapped token, whose reward account is created by
+/// [`build_admin_create_user_reward_account_idempotent_instruction`](crate::build_admin_create_user_reward_account_idempotent_instruction).
+pub fn get_fund_manager_fund_wrapped_token_holder_context(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    wrapped_token_mint: &Pubkey,
+    wrapped_token_holder: &Pubkey,
+) -> accounts::FundManagerFundWrappedTokenHolderContext {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    accounts::FundManagerFundWrappedTokenHolderContext {
+        fund_manager: *fund_manager,
+        role_account: find_role_account_address(),
+        receipt_token_mint: fund.receipt_token_mint,
+        wrapped_token_mint: *wrapped_token_mint,
+        fund_account: fund.fund_account,
+        fund_wrap_account: fund.fund_wrap_account,
+        wrapped_token_holder: *wrapped_token_holder,
+        reward_account: fund.reward_account,
+        fund_wrap_account_reward_account: fund
+            .find_user_reward_account_address(&fund.fund_wrap_account),
+        wrapped_token_holder_reward_account: fund
+            .find_user_reward_account_address(wrapped_token_holder),
+        event_authority: find_event_authority_address(),
+        program: ID,
+    }
+}
+
+pub fn build_fund_manager_update_fund_strategy_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    deposit_enabled: bool,
+    donation_enabled: bool,
+    withdrawal_enabled: bool,
+    transfer_enabled: bool,
+    operation_enabled: bool,
+    withdrawal_fee_rate_bps: u16,
+    withdrawal_batch_threshold_seconds: i64,
+    performance_fee_rate_bps: u16,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateFundStrategy {
+            deposit_enabled,
+            donation_enabled,
+            withdrawal_enabled,
+            transfer_enabled,
+            operation_enabled,
+            withdrawal_fee_rate_bps,
+            withdrawal_batch_threshold_seconds,
+            performance_fee_rate_bps,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_management_fee_rate_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    management_fee_rate_bps: u16,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateManagementFeeRate {
+            management_fee_rate_bps,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_revenue_split_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    recipients: Vec<FundRevenueRecipient>,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateRevenueSplit { recipients },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_operation_fallback_command_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    command: u8,
+    fallback_command: Option<u8>,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateOperationFallbackCommand {
+            command,
+            fallback_command,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_operation_command_policy_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    command: u8,
+    enabled: bool,
+    min_interval_seconds: i64,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateOperationCommandPolicy {
+            command,
+            enabled,
+            min_interval_seconds,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_max_price_age_slots_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    max_price_age_slots: u64,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateMaxPriceAgeSlots {
+            max_price_age_slots,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_price_deviation_threshold_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    max_receipt_token_price_deviation_bps: u16,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdatePriceDeviationThreshold {
+            max_receipt_token_price_deviation_bps,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_supported_token_price_deviation_threshold_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    token_mint: &Pubkey,
+    max_price_deviation_bps: u16,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateSupportedTokenPriceDeviationThreshold {
+            token_mint: *token_mint,
+            max_price_deviation_bps,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_acknowledge_price_deviation_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerAcknowledgePriceDeviation {},
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_reconciliation_threshold_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    max_shortfall_bps: u16,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateReconciliationThreshold { max_shortfall_bps },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_instant_withdrawal_strategy_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    enabled: bool,
+    min_fee_rate_bps: u16,
+    max_fee_rate_bps: u16,
+    fee_curve_exponent: u8,
+    liquidity_target_rate_bps: u16,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateInstantWithdrawalStrategy {
+            enabled,
+            min_fee_rate_bps,
+            max_fee_rate_bps,
+            fee_curve_exponent,
+            liquidity_target_rate_bps,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_in_kind_withdrawal_strategy_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    enabled: bool,
+    fee_rate_bps: u16,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateInKindWithdrawalStrategy {
+            enabled,
+            fee_rate_bps,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_sol_strategy_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    sol_depositable: bool,
+    sol_accumulated_deposit_capacity_amount: u64,
+    sol_accumulated_deposit_amount: Option<u64>,
+    sol_withdrawable: bool,
+    sol_withdrawal_normal_reserve_rate_bps: u16,
+    sol_withdrawal_normal_reserve_max_amount: u64,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateSolStrategy {
+            sol_depositable,
+            sol_accumulated_deposit_capacity_amount,
+            sol_accumulated_deposit_amount,
+            sol_withdrawable,
+            sol_withdrawal_normal_reserve_rate_bps,
+            sol_withdrawal_normal_reserve_max_amount,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_supported_token_strategy_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    token_mint: &Pubkey,
+    token_depositable: bool,
+    token_accumulated_deposit_capacity_amount: u64,
+    token_accumulated_deposit_amount: Option<u64>,
+    token_withdrawable: bool,
+    token_withdrawal_normal_reserve_rate_bps: u16,
+    token_withdrawal_normal_reserve_max_amount: u64,
+    sol_allocation_weight: u64,
+    sol_allocation_capacity_amount: u64,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateSupportedTokenStrategy {
+            token_mint: *token_mint,
+            token_depositable,
+            token_accumulated_deposit_capacity_amount,
+            token_accumulated_deposit_amount,
+            token_withdrawable,
+            token_withdrawal_normal_reserve_rate_bps,
+            token_withdrawal_normal_reserve_max_amount,
+            sol_allocation_weight,
+            sol_allocation_capacity_amount,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_restaking_vault_strategy_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    vault: &Pubkey,
+    sol_allocation_weight: u64,
+    sol_allocation_capacity_amount: u64,
+    reward_commission_rate_bps: u16,
+    vault_receipt_token_depositable: bool,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateRestakingVaultStrategy {
+            vault: *vault,
+            sol_allocation_weight,
+            sol_allocation_capacity_amount,
+            reward_commission_rate_bps,
+            vault_receipt_token_depositable,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_restaking_vault_delegation_strategy_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    token_allocation_weight: u64,
+    token_allocation_capacity_amount: u64,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateRestakingVaultDelegationStrategy {
+            vault: *vault,
+            operator: *operator,
+            token_allocation_weight,
+            token_allocation_capacity_amount,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_update_restaking_vault_reward_token_harvest_threshold_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    vault: &Pubkey,
+    reward_token_mint: &Pubkey,
+    harvest_threshold_min_amount: u64,
+    harvest_threshold_max_amount: u64,
+    harvest_threshold_interval_seconds: i64,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerUpdateRestakingVaultRewardTokenHarvestThreshold {
+            vault: *vault,
+            reward_token_mint: *reward_token_mint,
+            harvest_threshold_min_amount,
+            harvest_threshold_max_amount,
+            harvest_threshold_interval_seconds,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_set_operator_allowlist_enabled_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    enabled: bool,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerSetOperatorAllowlistEnabled { enabled },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_add_operator_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    operator: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerAddOperator {
+            operator: *operator,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_remove_operator_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    operator: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_context(fund_manager, receipt_token_mint),
+        instruction::FundManagerRemoveOperator {
+            operator: *operator,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_add_restaking_vault_compounding_reward_token_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    vault: &Pubkey,
+    reward_token_mint: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_restaking_vault_reward_context(
+            fund_manager,
+            receipt_token_mint,
+            reward_token_mint,
+        ),
+        instruction::FundManagerAddRestakingVaultCompoundingRewardToken { vault: *vault },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_remove_restaking_vault_compounding_reward_token_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    vault: &Pubkey,
+    reward_token_mint: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_restaking_vault_reward_context(
+            fund_manager,
+            receipt_token_mint,
+            reward_token_mint,
+        ),
+        instruction::FundManagerRemoveRestakingVaultCompoundingRewardToken { vault: *vault },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_add_restaking_vault_distributing_reward_token_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    vault: &Pubkey,
+    reward_token_mint: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_restaking_vault_reward_context(
+            fund_manager,
+            receipt_token_mint,
+            reward_token_mint,
+        ),
+        instruction::FundManagerAddRestakingVaultDistributingRewardToken { vault: *vault },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_remove_restaking_vault_distributing_reward_token_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    vault: &Pubkey,
+    reward_token_mint: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_restaking_vault_reward_context(
+            fund_manager,
+            receipt_token_mint,
+            reward_token_mint,
+        ),
+        instruction::FundManagerRemoveRestakingVaultDistributingRewardToken { vault: *vault },
+        vec![],
+    )
+}
+
+/// `swap_source_account` is the address of the swap source.
+pub fn build_fund_manager_add_token_swap_strategy_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    from_token_mint: &Pubkey,
+    to_token_mint: &Pubkey,
+    swap_source_account: &Pubkey,
+    swap_source: TokenSwapSource,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_token_swap_strategy_context(
+            fund_manager,
+            receipt_token_mint,
+            from_token_mint,
+            to_token_mint,
+            swap_source_account,
+        ),
+        instruction::FundManagerAddTokenSwapStrategy { swap_source },
+        vec![],
+    )
+}
+
+/// Pricing sources of the fund are appended along with `from_token_pricing_source` if given.
+pub fn build_fund_manager_update_token_swap_strategy_instruction(
+    fund_manager: &Pubkey,
+    fund_account: &FundAccount,
+    from_token_mint: &Pubkey,
+    to_token_mint: &Pubkey,
+    swap_source_account: &Pubkey,
+    from_token_pricing_source: Option<TokenPricingSource>,
+    max_slippage_bps: u16,
+) -> Instruction {
+    let remaining_accounts = match &from_token_pricing_source {
+        Some(pricing_source) => {
+            get_pricing_source_account_metas_including(fund_account, pricing_source)
+        }
+        None => get_pricing_source_account_metas(fund_account),
+    };
+
+    build_instruction(
+        get_fund_manager_fund_token_swap_strategy_context(
+            fund_manager,
+            &fund_account.receipt_token_mint,
+            from_token_mint,
+            to_token_mint,
+            swap_source_account,
+        ),
+        instruction::FundManagerUpdateTokenSwapStrategy {
+            from_token_pricing_source,
+            max_slippage_bps,
+        },
+        remaining_accounts,
+    )
+}
+
+pub fn build_fund_manager_remove_token_swap_strategy_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    from_token_mint: &Pubkey,
+    to_token_mint: &Pubkey,
+    swap_source_account: &Pubkey,
+    swap_source: TokenSwapSource,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_token_swap_strategy_context(
+            fund_manager,
+            receipt_token_mint,
+            from_token_mint,
+            to_token_mint,
+            swap_source_account,
+        ),
+        instruction::FundManagerRemoveTokenSwapStrategy { swap_source },
+        vec![],
+    )
+}
+
+/// The change is executed by [`build_fund_manager_execute_fund_configuration_change_instruction`]
+/// once the delay has passed, unless the guardian cancels it.
+pub fn build_fund_manager_queue_fund_configuration_change_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    change_id: u64,
+    change: FundConfigurationChange,
+) -> Instruction {
+    build_instruction(
+        accounts::FundManagerFundConfigurationChangeInitialContext {
+            fund_manager: *fund_manager,
+            role_account: find_role_account_address(),
+            system_program: system_program::ID,
+            receipt_token_mint: *receipt_token_mint,
+            fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+            fund_configuration_change_account: find_fund_configuration_change_account_address(
+                receipt_token_mint,
+                change_id,
+            ),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::FundManagerQueueFundConfigurationChange { change_id, change },
+        vec![],
+    )
+}
+
+/// `remaining_accounts` are the required accounts of the change, see [`FundConfigurationChange`].
+pub fn build_fund_manager_execute_fund_configuration_change_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    change_id: u64,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    build_instruction(
+        accounts::FundManagerFundConfigurationChangeContext {
+            fund_manager: *fund_manager,
+            role_account: find_role_account_address(),
+            receipt_token_mint: *receipt_token_mint,
+            fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+            fund_configuration_change_account: find_fund_configuration_change_account_address(
+                receipt_token_mint,
+                change_id,
+            ),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::FundManagerExecuteFundConfigurationChange {
+            _change_id: change_id,
+        },
+        remaining_accounts,
+    )
+}
+
+/// The normalized token reserve account of the fund must exist.
+pub fn build_fund_manager_initialize_fund_normalized_token_instruction(
+    fund_manager: &Pubkey,
+    fund_account: &FundAccount,
+    normalized_token_mint: &Pubkey,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+    let normalized_token_pool_account =
+        find_normalized_token_pool_account_address(normalized_token_mint);
+
+    build_instruction(
+        accounts::FundManagerFundNormalizedTokenInitialContext {
+            fund_manager: *fund_manager,
+            role_account: find_role_account_address(),
+            system_program: system_program::ID,
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            receipt_token_mint: fund.receipt_token_mint,
+            normalized_token_mint: *normalized_token_mint,
+            fund_normalized_token_reserve_account: get_associated_token_address_with_program_id(
+                &fund.fund_reserve_account,
+                normalized_token_mint,
+                &token::ID,
+            ),
+            normalized_token_pool_account,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::FundManagerInitializeFundNormalizedToken {},
+        get_pricing_source_account_metas_including(
+            fund_account,
+            &TokenPricingSource::FragmetricNormalizedTokenPool {
+                address: normalized_token_pool_account,
+            },
+        ),
+    )
+}
+
+/// The admin and the fund manager set the wrapped token, whose mint authority is handed over to the fund account.
+/// The receipt token wrap account and the reward account of the fund wrap account must exist.
+pub fn build_fund_manager_initialize_fund_wrapped_token_instruction(
+    admin: &Pubkey,
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    wrapped_token_mint: &Pubkey,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::FundManagerFundWrappedTokenInitialContext {
+            admin: *admin,
+            fund_manager: *fund_manager,
+            role_account: find_role_account_address(),
+            fund_wrap_account: fund.fund_wrap_account,
+            receipt_token_mint: fund.receipt_token_mint,
+            receipt_token_wrap_account: get_associated_token_address_with_program_id(
+                &fund.fund_wrap_account,
+                receipt_token_mint,
+                &token_2022::ID,
+            ),
+            wrapped_token_mint: *wrapped_token_mint,
+            wrapped_token_program: token::ID,
+            fund_account: fund.fund_account,
+            reward_account: fund.reward_account,
+            fund_wrap_account_reward_account: fund
+                .find_user_reward_account_address(&fund.fund_wrap_account),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::FundManagerInitializeFundWrappedToken {},
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_reset_fund_wrap_account_reward_account_delegate_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::FundManagerDelegateFundWrapAccountRewardAccount {
+            fund_manager: *fund_manager,
+            role_account: find_role_account_address(),
+            receipt_token_mint: fund.receipt_token_mint,
+            fund_wrap_account: fund.fund_wrap_account,
+            fund_wrap_account_reward_account: find_user_reward_account_address(
+                receipt_token_mint,
+                &fund.fund_wrap_account,
+            ),
+        },
+        instruction::FundManagerResetFundWrapAccountRewardAccountDelegate {},
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_add_wrapped_token_holder_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    wrapped_token_mint: &Pubkey,
+    wrapped_token_holder: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_wrapped_token_holder_context(
+            fund_manager,
+            receipt_token_mint,
+            wrapped_token_mint,
+            wrapped_token_holder,
+        ),
+        instruction::FundManagerAddWrappedTokenHolder {},
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_remove_wrapped_token_holder_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    wrapped_token_mint: &Pubkey,
+    wrapped_token_holder: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_wrapped_token_holder_context(
+            fund_manager,
+            receipt_token_mint,
+            wrapped_token_mint,
+            wrapped_token_holder,
+        ),
+        instruction::FundManagerRemoveWrappedTokenHolder {},
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_reset_wrapped_token_holder_reward_account_delegate_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    wrapped_token_mint: &Pubkey,
+    wrapped_token_holder: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_fund_wrapped_token_holder_context(
+            fund_manager,
+            receipt_token_mint,
+            wrapped_token_mint,
+            wrapped_token_holder,
+        ),
+        instruction::FundManagerResetWrappedTokenHolderRewardAccountDelegate {},
+        vec![],
+    )
+}
+
+/// Token accounts of the vault tokens held by the fund reserve account and the vault must exist.
+pub fn build_fund_manager_initialize_fund_restaking_vault_instruction(
+    fund_manager: &Pubkey,
+    fund_account: &FundAccount,
+    vault: &Pubkey,
+    vault_supported_token_mint: &Pubkey,
+    vault_receipt_token_mint: &Pubkey,
+    pricing_source: TokenPricingSource,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+    let remaining_accounts =
+        get_pricing_source_account_metas_including(fund_account, &pricing_source);
+
+    build_instruction(
+        accounts::FundManagerFundRestakingVaultInitialContext {
+            fund_manager: *fund_manager,
+            role_account: find_role_account_address(),
+            system_program: system_program::ID,
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            receipt_token_mint: fund.receipt_token_mint,
+            vault_account: *vault,
+            vault_receipt_token_mint: *vault_receipt_token_mint,
+            vault_supported_token_mint: *vault_supported_token_mint,
+            fund_vault_receipt_token_account: get_associated_token_address_with_program_id(
+                &fund.fund_reserve_account,
+                vault_receipt_token_mint,
+                &token::ID,
+            ),
+            fund_vault_supported_token_account: get_associated_token_address_with_program_id(
+                &fund.fund_reserve_account,
+                vault_supported_token_mint,
+                &token::ID,
+            ),
+            vault_vault_supported_token_account: get_associated_token_address_with_program_id(
+                vault,
+                vault_supported_token_mint,
+                &token::ID,
+            ),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::FundManagerInitializeFundRestakingVault { pricing_source },
+        remaining_accounts,
+    )
+}
+
+/// `vault_operator_delegation` is the delegation account of the restaking protocol.
+pub fn build_fund_manager_initialize_fund_restaking_vault_delegation_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::FundManagerFundRestakingVaultDelegationInitialContext {
+            fund_manager: *fund_manager,
+            role_account: find_role_account_address(),
+            fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+            receipt_token_mint: *receipt_token_mint,
+            vault_account: *vault,
+            operator_account: *operator,
+            vault_operator_delegation: *vault_operator_delegation,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::FundManagerInitializeFundRestakingVaultDelegation {},
+        vec![],
+    )
+}
+
+/// The reserve and treasury token accounts of the fund must exist.
+pub fn build_fund_manager_add_supported_token_instruction(
+    fund_manager: &Pubkey,
+    fund_account: &FundAccount,
+    supported_token_mint: &Pubkey,
+    supported_token_program: &Pubkey,
+    pricing_source: TokenPricingSource,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+    let remaining_accounts =
+        get_pricing_source_account_metas_including(fund_account, &pricing_source);
+
+    build_instruction(
+        accounts::FundManagerFundSupportedTokenContext {
+            fund_manager: *fund_manager,
+            role_account: find_role_account_address(),
+            receipt_token_mint: fund.receipt_token_mint,
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            fund_treasury_account: fund.fund_treasury_account,
+            supported_token_mint: *supported_token_mint,
+            supported_token_program: *supported_token_program,
+            supported_token_reserve_account: fund
+                .find_fund_supported_token_reserve_account_address(
+                    supported_token_mint,
+                    supported_token_program,
+                ),
+            supported_token_treasury_account: get_associated_token_address_with_program_id(
+                &fund.fund_treasury_account,
+                supported_token_mint,
+                supported_token_program,
+            ),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::FundManagerAddSupportedToken { pricing_source },
+        remaining_accounts,
+    )
+}
+
+pub fn build_fund_manager_remove_supported_token_instruction(
+    fund_manager: &Pubkey,
+    fund_account: &FundAccount,
+    supported_token_mint: &Pubkey,
+) -> Instruction {
+    let normalized_token_mint = fund_account
+        .get_normalized_token_mint()
+        .map(|(normalized_token_mint, _)| normalized_token_mint);
+
+    build_instruction(
+        accounts::FundManagerFundSupportedTokenRemoveContext {
+            fund_manager: *fund_manager,
+            role_account: find_role_account_address(),
+            receipt_token_mint: fund_account.receipt_token_mint,
+            fund_account: FundAddresses::new(&fund_account.receipt_token_mint).fund_account,
+            normalized_token_mint,
+            normalized_token_pool_account: normalized_token_mint
+                .as_ref()
+                .map(find_normalized_token_pool_account_address),
+            supported_token_mint: *supported_token_mint,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::FundManagerRemoveSupportedToken {},
+        get_pricing_source_account_metas(fund_account),
+    )
+}
+
+/// `pricing_sources` are of every supported token of the pool including the new one,
+/// e.g. pricing sources of the fund which has added the token.
+pub fn build_fund_manager_add_normalized_token_pool_supported_token_instruction(
+    fund_manager: &Pubkey,
+    normalized_token_mint: &Pubkey,
+    supported_token_mint: &Pubkey,
+    supported_token_program: &Pubkey,
+    pricing_source: TokenPricingSource,
+    pricing_sources: Vec<AccountMeta>,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_normalized_token_pool_supported_token_context(
+            fund_manager,
+            normalized_token_mint,
+            supported_token_mint,
+            supported_token_program,
+        ),
+        instruction::FundManagerAddNormalizedTokenPoolSupportedToken { pricing_source },
+        pricing_sources,
+    )
+}
+
+/// `pricing_sources` are of every supported token of the pool.
+pub fn build_fund_manager_remove_normalized_token_pool_supported_token_instruction(
+    fund_manager: &Pubkey,
+    normalized_token_mint: &Pubkey,
+    supported_token_mint: &Pubkey,
+    supported_token_program: &Pubkey,
+    pricing_sources: Vec<AccountMeta>,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_normalized_token_pool_supported_token_context(
+            fund_manager,
+            normalized_token_mint,
+            supported_token_mint,
+            supported_token_program,
+        ),
+        instruction::FundManagerRemoveNormalizedTokenPoolSupportedToken {},
+        pricing_sources,
+    )
+}
+
+/// A claimable reward requires the token account of the reward reserve account,
+/// see [`get_fund_manager_reward_context`].
+pub fn build_fund_manager_add_reward_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    name: String,
+    description: String,
+    mint: &Pubkey,
+    program: &Pubkey,
+    decimals: u8,
+    claimable: bool,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_reward_context(
+            fund_manager,
+            receipt_token_mint,
+            claimable.then_some((mint, program)),
+        ),
+        instruction::FundManagerAddReward {
+            name,
+            description,
+            mint: *mint,
+            program: *program,
+            decimals,
+            claimable,
+        },
+        vec![],
+    )
+}
+
+/// `reward_token` is (mint, program) of the reward token after the update if it is claimable.
+pub fn build_fund_manager_update_reward_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    reward_token: Option<(&Pubkey, &Pubkey)>,
+    mint: &Pubkey,
+    new_mint: Option<Pubkey>,
+    new_program: Option<Pubkey>,
+    new_decimals: Option<u8>,
+    claimable: bool,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_reward_context(fund_manager, receipt_token_mint, reward_token),
+        instruction::FundManagerUpdateReward {
+            mint: *mint,
+            new_mint,
+            new_program,
+            new_decimals,
+            claimable,
+        },
+        vec![],
+    )
+}
+
+/// `reward_token` is (mint, program) of a claimable reward token, which the reward reserve account must hold.
+pub fn build_fund_manager_settle_reward_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    reward_token: Option<(&Pubkey, &Pubkey)>,
+    reward_token_mint: &Pubkey,
+    is_bonus_pool: bool,
+    amount: u64,
+) -> Instruction {
+    build_instruction(
+        get_fund_manager_reward_context(fund_manager, receipt_token_mint, reward_token),
+        instruction::FundManagerSettleReward {
+            reward_token_mint: *reward_token_mint,
+            is_bonus_pool,
+            amount,
+        },
+        vec![],
+    )
+}
+
+pub fn build_fund_manager_revoke_fund_reward_token_mint_authority_instruction(
+    fund_manager: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    reward_token_mint: &Pubkey,
+    reward_token_program: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::FundManagerFundRewardTokenContext {
+            fund_manager: *fund_manager,
+            role_account: find_role_account_address(),
+            receipt_token_mint: *receipt_token_mint,
+            fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+            reward_token_mint: *reward_token_mint,
+            reward_token_program: *reward_token_program,
+        },
+        instruction::FundManagerRevokeFundRewardTokenMintAuthority {},
+        vec![],
+    )
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use restaking::{accounts, instruction, ID};
+
+use super::build_instruction;
+use crate::{
+    find_event_authority_address, find_fund_configuration_change_account_address,
+    find_role_account_address, FundAddresses,
+};
+
+/// `payer` is the payer of the queued change, who receives its rent back.
+pub fn build_guardian_cancel_fund_configuration_change_instruction(
+    guardian: &Pubkey,
+    payer: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    change_id: u64,
+) -> Instruction {
+    build_instruction(
+        accounts::GuardianFundConfigurationChangeContext {
+            guardian: *guardian,
+            payer: *payer,
+            role_account: find_role_account_address(),
+            receipt_token_mint: *receipt_token_mint,
+            fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+            fund_configuration_change_account: find_fund_configuration_change_account_address(
+                receipt_token_mint,
+                change_id,
+            ),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::GuardianCancelFundConfigurationChange {
+            _change_id: change_id,
+        },
+        vec![],
+    )
+}
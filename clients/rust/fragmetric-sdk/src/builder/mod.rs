@@ -0,0 +1,43 @@
+//! Typed instruction builders of every program entrypoint but `token_transfer_hook`,
+//! which is invoked by the token-2022 program, see [`get_receipt_token_transfer_hook_extra_account_metas`](crate::get_receipt_token_transfer_hook_extra_account_metas).
+//!
+//! Builders named `build_<entrypoint>_instruction` derive the instruction accounts from the signers and the receipt token mint,
+//! and take the decoded [`FundAccount`](restaking::modules::fund::FundAccount) instead
+//! if the entrypoint requires its pricing sources or token accounts as remaining accounts.
+
+mod admin;
+mod fund_manager;
+mod guardian;
+mod operator;
+mod quote;
+mod slasher;
+mod user;
+
+pub use admin::*;
+pub use fund_manager::*;
+pub use guardian::*;
+pub use operator::*;
+pub use quote::*;
+pub use slasher::*;
+pub use user::*;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::InstructionData;
+use restaking::ID;
+
+/// Builds an instruction of any entrypoint from its accounts and arguments.
+pub fn build_instruction(
+    accounts: impl ToAccountMetas,
+    args: impl InstructionData,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(remaining_accounts);
+
+    Instruction {
+        program_id: ID,
+        accounts: account_metas,
+        data: args.data(),
+    }
+}
@@ -0,0 +1,285 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token;
+use restaking::modules::fund::FundAccount;
+use restaking::{accounts, instruction, ID};
+
+use super::build_instruction;
+use crate::{
+    find_accounts_to_reconcile, find_event_authority_address,
+    find_fund_operation_history_account_address, find_normalized_token_pool_account_address,
+    find_role_account_address, get_pricing_source_account_metas, FundAddresses,
+};
+
+pub fn get_operator_fund_context(
+    operator: &Pubkey,
+    receipt_token_mint: &Pubkey,
+) -> accounts::OperatorFundContext {
+    accounts::OperatorFundContext {
+        operator: *operator,
+        role_account: find_role_account_address(),
+        system_program: system_program::ID,
+        receipt_token_mint: *receipt_token_mint,
+        fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+        event_authority: find_event_authority_address(),
+        program: ID,
+    }
+}
+
+pub fn build_operator_update_fund_prices_instruction(
+    operator: &Pubkey,
+    fund_account: &FundAccount,
+) -> Instruction {
+    build_instruction(
+        get_operator_fund_context(operator, &fund_account.receipt_token_mint),
+        instruction::OperatorUpdateFundPrices {},
+        get_pricing_source_account_metas(fund_account),
+    )
+}
+
+/// `remaining_accounts` are the required accounts of the commands to run, pricing sources are appended.
+pub fn build_operator_run_fund_command_instruction(
+    operator: &Pubkey,
+    fund_account: &FundAccount,
+    max_steps: Option<u8>,
+    mut remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    remaining_accounts.extend(get_pricing_source_account_metas(fund_account));
+
+    build_instruction(
+        get_operator_fund_context(operator, &fund_account.receipt_token_mint),
+        instruction::OperatorRunFundCommand {
+            force_reset_command: None,
+            max_steps,
+        },
+        remaining_accounts,
+    )
+}
+
+/// Simulate it and read [`OperationCommandPlan`](restaking::modules::fund::commands::OperationCommandPlan)
+/// from the return data.
+pub fn build_operator_plan_fund_commands_instruction(
+    operator: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    max_steps: u8,
+) -> Instruction {
+    build_instruction(
+        get_operator_fund_context(operator, receipt_token_mint),
+        instruction::OperatorPlanFundCommands { max_steps },
+        vec![],
+    )
+}
+
+/// The page index must be the next page index of the fund account.
+pub fn build_operator_initialize_fund_operation_history_account_instruction(
+    operator: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    page_index: u64,
+) -> Instruction {
+    build_instruction(
+        accounts::OperatorFundOperationHistoryInitialContext {
+            operator: *operator,
+            system_program: system_program::ID,
+            receipt_token_mint: *receipt_token_mint,
+            fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+            fund_operation_history_account: find_fund_operation_history_account_address(
+                receipt_token_mint,
+                page_index,
+            ),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::OperatorInitializeFundOperationHistoryAccount { page_index },
+        vec![],
+    )
+}
+
+pub fn build_operator_reconcile_fund_instruction(
+    operator: &Pubkey,
+    fund_account: &FundAccount,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+
+    build_instruction(
+        accounts::OperatorFundReconciliationContext {
+            operator: *operator,
+            receipt_token_mint: fund.receipt_token_mint,
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            fund_treasury_account: fund.fund_treasury_account,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::OperatorReconcileFund {},
+        find_accounts_to_reconcile(fund_account)
+            .into_iter()
+            .map(|address| AccountMeta::new_readonly(address, false))
+            .collect(),
+    )
+}
+
+pub fn build_operator_donate_sol_to_fund_instruction(
+    operator: &Pubkey,
+    fund_account: &FundAccount,
+    amount: u64,
+    offset_receivable: bool,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+
+    build_instruction(
+        accounts::OperatorFundDonationContext {
+            operator: *operator,
+            system_program: system_program::ID,
+            receipt_token_mint: fund.receipt_token_mint,
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::OperatorDonateSolToFund {
+            amount,
+            offset_receivable,
+        },
+        get_pricing_source_account_metas(fund_account),
+    )
+}
+
+/// The operator donates from its associated token account.
+pub fn build_operator_donate_supported_token_to_fund_instruction(
+    operator: &Pubkey,
+    fund_account: &FundAccount,
+    supported_token_mint: &Pubkey,
+    supported_token_program: &Pubkey,
+    amount: u64,
+    offset_receivable: bool,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+
+    build_instruction(
+        accounts::OperatorFundSupportedTokenDonationContext {
+            operator: *operator,
+            supported_token_program: *supported_token_program,
+            receipt_token_mint: fund.receipt_token_mint,
+            supported_token_mint: *supported_token_mint,
+            fund_supported_token_reserve_account: fund
+                .find_fund_supported_token_reserve_account_address(
+                    supported_token_mint,
+                    supported_token_program,
+                ),
+            operator_supported_token_account: get_associated_token_address_with_program_id(
+                operator,
+                supported_token_mint,
+                supported_token_program,
+            ),
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::OperatorDonateSupportedTokenToFund {
+            amount,
+            offset_receivable,
+        },
+        get_pricing_source_account_metas(fund_account),
+    )
+}
+
+pub fn build_operator_update_reward_pools_instruction(
+    operator: &Pubkey,
+    receipt_token_mint: &Pubkey,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::OperatorRewardContext {
+            operator: *operator,
+            system_program: system_program::ID,
+            receipt_token_mint: fund.receipt_token_mint,
+            fund_account: fund.fund_account,
+            reward_account: fund.reward_account,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::OperatorUpdateRewardPools {},
+        vec![],
+    )
+}
+
+/// Each revenue recipient of the fund receives its share to its associated token account, which must exist.
+pub fn build_operator_claim_remaining_reward_instruction(
+    operator: &Pubkey,
+    fund_account: &FundAccount,
+    reward_token_mint: &Pubkey,
+    reward_token_program: &Pubkey,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+
+    build_instruction(
+        accounts::OperatorRewardClaimContext {
+            operator: *operator,
+            receipt_token_mint: fund.receipt_token_mint,
+            fund_account: fund.fund_account,
+            reward_account: fund.reward_account,
+            reward_reserve_account: fund.reward_reserve_account,
+            reward_token_mint: *reward_token_mint,
+            reward_token_program: *reward_token_program,
+            reward_token_reserve_account: get_associated_token_address_with_program_id(
+                &fund.reward_reserve_account,
+                reward_token_mint,
+                reward_token_program,
+            ),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::OperatorClaimRemainingReward {},
+        fund_account
+            .get_revenue_recipients_iter()
+            .map(|(recipient, _)| {
+                AccountMeta::new(
+                    get_associated_token_address_with_program_id(
+                        &recipient,
+                        reward_token_mint,
+                        reward_token_program,
+                    ),
+                    false,
+                )
+            })
+            .collect(),
+    )
+}
+
+/// `pricing_sources` are of every supported token of the pool.
+pub fn build_operator_update_normalized_token_pool_prices_instruction(
+    operator: &Pubkey,
+    normalized_token_mint: &Pubkey,
+    pricing_sources: Vec<AccountMeta>,
+) -> Instruction {
+    build_instruction(
+        accounts::OperatorNormalizedTokenPoolContext {
+            operator: *operator,
+            normalized_token_mint: *normalized_token_mint,
+            normalized_token_pool_account: find_normalized_token_pool_account_address(
+                normalized_token_mint,
+            ),
+            normalized_token_program: token::ID,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::OperatorUpdateNormalizedTokenPoolPrices {},
+        pricing_sources,
+    )
+}
+
+/// Logs the message through the event authority, so indexers can annotate a transaction.
+pub fn build_operator_log_message_instruction(message: String) -> Instruction {
+    build_instruction(
+        accounts::OperatorEmptyContext {
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::OperatorLogMessage { message },
+        vec![],
+    )
+}
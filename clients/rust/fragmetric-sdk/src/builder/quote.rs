@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use restaking::modules::fund::FundAccount;
+use restaking::{accounts, instruction};
+
+use super::build_instruction;
+use crate::{get_pricing_source_account_metas, FundAddresses};
+
+/// Quote instructions are simulated to read the quoted amount from the return data.
+pub fn get_fund_quote_context(receipt_token_mint: &Pubkey) -> accounts::FundQuoteContext {
+    accounts::FundQuoteContext {
+        receipt_token_mint: *receipt_token_mint,
+        fund_account: FundAddresses::new(receipt_token_mint).fund_account,
+    }
+}
+
+/// Quotes the receipt token amount minted by depositing SOL, or the supported token if given.
+pub fn build_quote_deposit_instruction(
+    fund_account: &FundAccount,
+    supported_token_mint: Option<Pubkey>,
+    amount: u64,
+) -> Instruction {
+    build_instruction(
+        get_fund_quote_context(&fund_account.receipt_token_mint),
+        instruction::QuoteDeposit {
+            supported_token_mint,
+            amount,
+        },
+        get_pricing_source_account_metas(fund_account),
+    )
+}
+
+/// Quotes the SOL amount, or the supported token amount if given, withdrawn for the receipt token amount.
+pub fn build_quote_withdrawal_instruction(
+    fund_account: &FundAccount,
+    supported_token_mint: Option<Pubkey>,
+    receipt_token_amount: u64,
+) -> Instruction {
+    build_instruction(
+        get_fund_quote_context(&fund_account.receipt_token_mint),
+        instruction::QuoteWithdrawal {
+            supported_token_mint,
+            receipt_token_amount,
+        },
+        get_pricing_source_account_metas(fund_account),
+    )
+}
+
+pub fn build_quote_wrap_instruction(
+    receipt_token_mint: &Pubkey,
+    receipt_token_amount: u64,
+) -> Instruction {
+    build_instruction(
+        get_fund_quote_context(receipt_token_mint),
+        instruction::QuoteWrap {
+            receipt_token_amount,
+        },
+        vec![],
+    )
+}
+
+pub fn build_quote_receipt_token_twap_instruction(
+    receipt_token_mint: &Pubkey,
+    window_seconds: i64,
+) -> Instruction {
+    build_instruction(
+        get_fund_quote_context(receipt_token_mint),
+        instruction::QuoteReceiptTokenTwap { window_seconds },
+        vec![],
+    )
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token;
+use restaking::{accounts, instruction, ID};
+
+use super::build_instruction;
+use crate::{
+    find_event_authority_address, find_normalized_token_pool_account_address,
+    find_normalized_token_withdrawal_account_address,
+};
+
+/// The slasher burns the normalized token of `slasher_normalized_token_account`.
+/// `pricing_sources` are of every supported token of the pool.
+pub fn build_slasher_initialize_normalized_token_withdrawal_account_instruction(
+    payer: &Pubkey,
+    slasher: &Pubkey,
+    normalized_token_mint: &Pubkey,
+    slasher_normalized_token_account: &Pubkey,
+    pricing_sources: Vec<AccountMeta>,
+) -> Instruction {
+    build_instruction(
+        accounts::SlasherNormalizedTokenWithdrawalAccountInitialContext {
+            payer: *payer,
+            slasher: *slasher,
+            normalized_token_mint: *normalized_token_mint,
+            normalized_token_pool_account: find_normalized_token_pool_account_address(
+                normalized_token_mint,
+            ),
+            normalized_token_program: token::ID,
+            slasher_normalized_token_withdrawal_ticket_account:
+                find_normalized_token_withdrawal_account_address(normalized_token_mint, slasher),
+            slasher_normalized_token_account: *slasher_normalized_token_account,
+            system_program: system_program::ID,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::SlasherInitializeNormalizedTokenWithdrawalAccount {},
+        pricing_sources,
+    )
+}
+
+/// Withdraws a supported token of the withdrawal ticket,
+/// which is closed to `destination_rent_lamports_account` after the last one.
+pub fn build_slasher_withdraw_normalized_token_instruction(
+    slasher: &Pubkey,
+    normalized_token_mint: &Pubkey,
+    supported_token_mint: &Pubkey,
+    supported_token_program: &Pubkey,
+    destination_supported_token_account: &Pubkey,
+    destination_rent_lamports_account: &Pubkey,
+) -> Instruction {
+    let normalized_token_pool_account =
+        find_normalized_token_pool_account_address(normalized_token_mint);
+
+    build_instruction(
+        accounts::SlasherNormalizedTokenWithdrawContext {
+            slasher: *slasher,
+            normalized_token_mint: *normalized_token_mint,
+            normalized_token_pool_account,
+            normalized_token_program: token::ID,
+            slasher_normalized_token_withdrawal_ticket_account:
+                find_normalized_token_withdrawal_account_address(normalized_token_mint, slasher),
+            supported_token_mint: *supported_token_mint,
+            supported_token_program: *supported_token_program,
+            normalized_token_pool_supported_token_reserve_account:
+                get_associated_token_address_with_program_id(
+                    &normalized_token_pool_account,
+                    supported_token_mint,
+                    supported_token_program,
+                ),
+            destination_supported_token_account: *destination_supported_token_account,
+            destination_rent_lamports_account: *destination_rent_lamports_account,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::SlasherWithdrawNormalizedToken {},
+        vec![],
+    )
+}
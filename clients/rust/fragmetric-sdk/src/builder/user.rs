@@ -0,0 +1,801 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::{token, token_2022};
+use restaking::modules::fund::{DepositMetadata, FundAccount, UserFundAccount};
+use restaking::{accounts, instruction, ID};
+
+use super::build_instruction;
+use crate::{
+    find_event_authority_address, find_fund_withdrawal_batch_account_address,
+    find_fund_withdrawal_request_account_address, find_in_kind_withdrawal_token_accounts,
+    find_next_withdrawal_request_account_address, find_role_account_address,
+    get_pricing_source_account_metas, FundAddresses,
+};
+
+/// The receipt token account of the user must exist.
+pub fn build_user_create_fund_account_idempotent_instruction(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    desired_account_size: Option<u32>,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::UserFundAccountInitOrUpdateContext {
+            user: *user,
+            system_program: system_program::ID,
+            receipt_token_mint: fund.receipt_token_mint,
+            receipt_token_program: token_2022::ID,
+            user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+            user_fund_account: fund.find_user_fund_account_address(user),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserCreateFundAccountIdempotent {
+            desired_account_size,
+        },
+        vec![],
+    )
+}
+
+/// The deposit fails if fewer than `min_receipt_token_amount` receipt tokens are minted.
+pub fn build_user_deposit_sol_instruction(
+    user: &Pubkey,
+    fund_account: &FundAccount,
+    amount: u64,
+    metadata: Option<DepositMetadata>,
+    min_receipt_token_amount: Option<u64>,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+
+    build_instruction(
+        accounts::UserFundDepositSolContext {
+            user: *user,
+            role_account: find_role_account_address(),
+            system_program: system_program::ID,
+            receipt_token_program: token_2022::ID,
+            receipt_token_mint: fund.receipt_token_mint,
+            receipt_token_lock_account: fund.fund_receipt_token_lock_account,
+            user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            user_fund_account: fund.find_user_fund_account_address(user),
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            instructions_sysvar: instructions_sysvar::ID,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserDepositSol {
+            amount,
+            metadata,
+            min_receipt_token_amount,
+        },
+        get_pricing_source_account_metas(fund_account),
+    )
+}
+
+/// The user deposits from its associated token account.
+pub fn build_user_deposit_supported_token_instruction(
+    user: &Pubkey,
+    fund_account: &FundAccount,
+    supported_token_mint: &Pubkey,
+    supported_token_program: &Pubkey,
+    amount: u64,
+    metadata: Option<DepositMetadata>,
+    min_receipt_token_amount: Option<u64>,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+
+    build_instruction(
+        accounts::UserFundDepositSupportedTokenContext {
+            user: *user,
+            role_account: find_role_account_address(),
+            receipt_token_program: token_2022::ID,
+            supported_token_program: *supported_token_program,
+            receipt_token_mint: fund.receipt_token_mint,
+            user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+            supported_token_mint: *supported_token_mint,
+            fund_reserve_account: fund.fund_reserve_account,
+            fund_supported_token_reserve_account: fund
+                .find_fund_supported_token_reserve_account_address(
+                    supported_token_mint,
+                    supported_token_program,
+                ),
+            user_supported_token_account: get_associated_token_address_with_program_id(
+                user,
+                supported_token_mint,
+                supported_token_program,
+            ),
+            fund_account: fund.fund_account,
+            user_fund_account: fund.find_user_fund_account_address(user),
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            instructions_sysvar: instructions_sysvar::ID,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserDepositSupportedToken {
+            amount,
+            metadata,
+            min_receipt_token_amount,
+        },
+        get_pricing_source_account_metas(fund_account),
+    )
+}
+
+/// The user deposits the whole balance of its associated vault receipt token account.
+pub fn build_user_deposit_vault_receipt_token_instruction(
+    user: &Pubkey,
+    fund_account: &FundAccount,
+    vault_receipt_token_mint: &Pubkey,
+    metadata: Option<DepositMetadata>,
+    min_receipt_token_amount: Option<u64>,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+
+    build_instruction(
+        accounts::UserFundVaultReceiptTokenContext {
+            user: *user,
+            role_account: find_role_account_address(),
+            receipt_token_program: token_2022::ID,
+            vault_receipt_token_program: token::ID,
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            user_fund_account: fund.find_user_fund_account_address(user),
+            receipt_token_mint: fund.receipt_token_mint,
+            vault_receipt_token_mint: *vault_receipt_token_mint,
+            user_vault_receipt_token_account: get_associated_token_address_with_program_id(
+                user,
+                vault_receipt_token_mint,
+                &token::ID,
+            ),
+            fund_vault_receipt_token_reserve_account: get_associated_token_address_with_program_id(
+                &fund.fund_reserve_account,
+                vault_receipt_token_mint,
+                &token::ID,
+            ),
+            user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            instructions_sysvar: instructions_sysvar::ID,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserDepositVaultReceiptToken {
+            metadata,
+            min_receipt_token_amount,
+        },
+        get_pricing_source_account_metas(fund_account),
+    )
+}
+
+fn get_user_fund_request_withdrawal_context(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    fund_withdrawal_request_account: Pubkey,
+) -> accounts::UserFundRequestWithdrawalContext {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    accounts::UserFundRequestWithdrawalContext {
+        user: *user,
+        system_program: system_program::ID,
+        receipt_token_program: token_2022::ID,
+        receipt_token_mint: fund.receipt_token_mint,
+        receipt_token_lock_account: fund.fund_receipt_token_lock_account,
+        user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+        fund_account: fund.fund_account,
+        fund_reserve_account: fund.fund_reserve_account,
+        user_fund_account: fund.find_user_fund_account_address(user),
+        fund_withdrawal_request_account,
+        reward_account: fund.reward_account,
+        user_reward_account: fund.find_user_reward_account_address(user),
+        instructions_sysvar: instructions_sysvar::ID,
+        event_authority: find_event_authority_address(),
+        program: ID,
+    }
+}
+
+/// Requests withdrawal of SOL, or the supported token if given.
+/// The request account is derived from the last created request id of the fund account,
+/// so a concurrent request makes the instruction fail.
+pub fn build_user_request_withdrawal_instruction(
+    user: &Pubkey,
+    fund_account: &FundAccount,
+    receipt_token_amount: u64,
+    supported_token_mint: Option<Pubkey>,
+    min_asset_amount: Option<u64>,
+) -> Result<Instruction> {
+    Ok(build_instruction(
+        get_user_fund_request_withdrawal_context(
+            user,
+            &fund_account.receipt_token_mint,
+            find_next_withdrawal_request_account_address(fund_account, supported_token_mint)?,
+        ),
+        instruction::UserRequestWithdrawal {
+            receipt_token_amount,
+            supported_token_mint,
+            min_asset_amount,
+        },
+        get_pricing_source_account_metas(fund_account),
+    ))
+}
+
+pub fn build_user_cancel_withdrawal_request_instruction(
+    user: &Pubkey,
+    fund_account: &FundAccount,
+    request_id: u64,
+    supported_token_mint: Option<Pubkey>,
+) -> Instruction {
+    build_instruction(
+        get_user_fund_request_withdrawal_context(
+            user,
+            &fund_account.receipt_token_mint,
+            find_fund_withdrawal_request_account_address(
+                &fund_account.receipt_token_mint,
+                supported_token_mint.as_ref(),
+                request_id,
+            ),
+        ),
+        instruction::UserCancelWithdrawalRequest {
+            request_id,
+            supported_token_mint,
+        },
+        get_pricing_source_account_metas(fund_account),
+    )
+}
+
+/// Withdraws SOL of a request whose batch has been processed.
+pub fn build_user_withdraw_sol_instruction(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    batch_id: u64,
+    request_id: u64,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::UserFundWithdrawSolContext {
+            user: *user,
+            system_program: system_program::ID,
+            receipt_token_program: token_2022::ID,
+            receipt_token_mint: fund.receipt_token_mint,
+            user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            fund_withdrawal_batch_account: find_fund_withdrawal_batch_account_address(
+                receipt_token_mint,
+                None,
+                batch_id,
+            ),
+            fund_withdrawal_request_account: find_fund_withdrawal_request_account_address(
+                receipt_token_mint,
+                None,
+                request_id,
+            ),
+            fund_treasury_account: fund.fund_treasury_account,
+            user_fund_account: fund.find_user_fund_account_address(user),
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserWithdrawSol {
+            _batch_id: batch_id,
+            _request_id: request_id,
+        },
+        vec![],
+    )
+}
+
+/// Withdraws the supported token of a request whose batch has been processed,
+/// to the associated token account of the user.
+pub fn build_user_withdraw_supported_token_instruction(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    supported_token_mint: &Pubkey,
+    supported_token_program: &Pubkey,
+    batch_id: u64,
+    request_id: u64,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::UserFundWithdrawSupportedTokenContext {
+            user: *user,
+            system_program: system_program::ID,
+            receipt_token_program: token_2022::ID,
+            supported_token_program: *supported_token_program,
+            receipt_token_mint: fund.receipt_token_mint,
+            user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+            supported_token_mint: *supported_token_mint,
+            user_supported_token_account: get_associated_token_address_with_program_id(
+                user,
+                supported_token_mint,
+                supported_token_program,
+            ),
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            fund_withdrawal_batch_account: find_fund_withdrawal_batch_account_address(
+                receipt_token_mint,
+                Some(supported_token_mint),
+                batch_id,
+            ),
+            fund_withdrawal_request_account: find_fund_withdrawal_request_account_address(
+                receipt_token_mint,
+                Some(supported_token_mint),
+                request_id,
+            ),
+            fund_supported_token_reserve_account: fund
+                .find_fund_supported_token_reserve_account_address(
+                    supported_token_mint,
+                    supported_token_program,
+                ),
+            fund_treasury_account: fund.fund_treasury_account,
+            user_fund_account: fund.find_user_fund_account_address(user),
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            instructions_sysvar: instructions_sysvar::ID,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserWithdrawSupportedToken {
+            _batch_id: batch_id,
+            _request_id: request_id,
+        },
+        vec![],
+    )
+}
+
+pub fn build_user_withdraw_instantly_instruction(
+    user: &Pubkey,
+    fund_account: &FundAccount,
+    receipt_token_amount: u64,
+    min_asset_amount: Option<u64>,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+
+    build_instruction(
+        accounts::UserFundInstantWithdrawSolContext {
+            user: *user,
+            system_program: system_program::ID,
+            receipt_token_program: token_2022::ID,
+            receipt_token_mint: fund.receipt_token_mint,
+            user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            user_fund_account: fund.find_user_fund_account_address(user),
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserWithdrawInstantly {
+            receipt_token_amount,
+            min_asset_amount,
+        },
+        get_pricing_source_account_metas(fund_account),
+    )
+}
+
+/// The user receives the supported token to its associated token account.
+pub fn build_user_withdraw_supported_token_instantly_instruction(
+    user: &Pubkey,
+    fund_account: &FundAccount,
+    supported_token_mint: &Pubkey,
+    supported_token_program: &Pubkey,
+    receipt_token_amount: u64,
+    min_asset_amount: Option<u64>,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+
+    build_instruction(
+        accounts::UserFundInstantWithdrawSupportedTokenContext {
+            user: *user,
+            system_program: system_program::ID,
+            receipt_token_program: token_2022::ID,
+            supported_token_program: *supported_token_program,
+            receipt_token_mint: fund.receipt_token_mint,
+            user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+            supported_token_mint: *supported_token_mint,
+            user_supported_token_account: get_associated_token_address_with_program_id(
+                user,
+                supported_token_mint,
+                supported_token_program,
+            ),
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            fund_supported_token_reserve_account: fund
+                .find_fund_supported_token_reserve_account_address(
+                    supported_token_mint,
+                    supported_token_program,
+                ),
+            user_fund_account: fund.find_user_fund_account_address(user),
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserWithdrawSupportedTokenInstantly {
+            receipt_token_amount,
+            min_asset_amount,
+        },
+        get_pricing_source_account_metas(fund_account),
+    )
+}
+
+/// The user receives each token of [`find_in_kind_withdrawal_token_accounts`]
+/// to its associated token account, which must exist.
+/// `min_asset_amounts` are the minimum withdrawn amounts of SOL followed by each of those tokens.
+pub fn build_user_withdraw_in_kind_instruction(
+    user: &Pubkey,
+    fund_account: &FundAccount,
+    receipt_token_amount: u64,
+    min_asset_amounts: Option<Vec<u64>>,
+) -> Instruction {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+    let mut remaining_accounts = Vec::new();
+    for (token_mint, token_program, fund_reserve_token_account) in
+        find_in_kind_withdrawal_token_accounts(fund_account)
+    {
+        remaining_accounts.extend([
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new(fund_reserve_token_account, false),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(user, &token_mint, &token_program),
+                false,
+            ),
+        ]);
+    }
+    remaining_accounts.extend(get_pricing_source_account_metas(fund_account));
+
+    build_instruction(
+        accounts::UserFundInKindWithdrawContext {
+            user: *user,
+            system_program: system_program::ID,
+            receipt_token_program: token_2022::ID,
+            receipt_token_mint: fund.receipt_token_mint,
+            user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+            fund_account: fund.fund_account,
+            fund_reserve_account: fund.fund_reserve_account,
+            user_fund_account: fund.find_user_fund_account_address(user),
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserWithdrawInKind {
+            receipt_token_amount,
+            min_asset_amounts,
+        },
+        remaining_accounts,
+    )
+}
+
+/// The wrapped token account of the user is its associated token account, which must exist.
+pub fn get_user_fund_wrapped_token_context(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    wrapped_token_mint: &Pubkey,
+) -> accounts::UserFundWrappedTokenContext {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    accounts::UserFundWrappedTokenContext {
+        user: *user,
+        fund_wrap_account: fund.fund_wrap_account,
+        receipt_token_program: token_2022::ID,
+        wrapped_token_program: token::ID,
+        receipt_token_mint: fund.receipt_token_mint,
+        wrapped_token_mint: *wrapped_token_mint,
+        user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+        receipt_token_wrap_account: get_associated_token_address_with_program_id(
+            &fund.fund_wrap_account,
+            receipt_token_mint,
+            &token_2022::ID,
+        ),
+        user_wrapped_token_account: get_associated_token_address_with_program_id(
+            user,
+            wrapped_token_mint,
+            &token::ID,
+        ),
+        fund_account: fund.fund_account,
+        user_fund_account: fund.find_user_fund_account_address(user),
+        reward_account: fund.reward_account,
+        user_reward_account: fund.find_user_reward_account_address(user),
+        fund_wrap_account_reward_account: fund
+            .find_user_reward_account_address(&fund.fund_wrap_account),
+        event_authority: find_event_authority_address(),
+        program: ID,
+    }
+}
+
+pub fn build_user_wrap_receipt_token_instruction(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    wrapped_token_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build_instruction(
+        get_user_fund_wrapped_token_context(user, receipt_token_mint, wrapped_token_mint),
+        instruction::UserWrapReceiptToken { amount },
+        vec![],
+    )
+}
+
+/// Wraps the receipt token until the wrapped token balance of the user reaches `target_balance`.
+pub fn build_user_wrap_receipt_token_if_needed_instruction(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    wrapped_token_mint: &Pubkey,
+    target_balance: u64,
+) -> Instruction {
+    build_instruction(
+        get_user_fund_wrapped_token_context(user, receipt_token_mint, wrapped_token_mint),
+        instruction::UserWrapReceiptTokenIfNeeded { target_balance },
+        vec![],
+    )
+}
+
+pub fn build_user_unwrap_receipt_token_instruction(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    wrapped_token_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build_instruction(
+        get_user_fund_wrapped_token_context(user, receipt_token_mint, wrapped_token_mint),
+        instruction::UserUnwrapReceiptToken { amount },
+        vec![],
+    )
+}
+
+/// Migrates every withdrawal request of the user fund account into its own request account.
+pub fn build_user_migrate_withdrawal_requests_instruction(
+    user_fund_account: &UserFundAccount,
+) -> Instruction {
+    let fund = FundAddresses::new(&user_fund_account.receipt_token_mint);
+
+    build_instruction(
+        accounts::UserFundWithdrawalRequestMigrationContext {
+            user: user_fund_account.user,
+            system_program: system_program::ID,
+            receipt_token_mint: fund.receipt_token_mint,
+            user_fund_account: fund.find_user_fund_account_address(&user_fund_account.user),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserMigrateWithdrawalRequests {},
+        user_fund_account
+            .get_withdrawal_requests_iter()
+            .map(|request| {
+                AccountMeta::new(
+                    find_fund_withdrawal_request_account_address(
+                        &fund.receipt_token_mint,
+                        request.supported_token_mint.as_ref(),
+                        request.request_id,
+                    ),
+                    false,
+                )
+            })
+            .collect(),
+    )
+}
+
+pub fn build_user_transfer_withdrawal_request_instruction(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    request_id: u64,
+    supported_token_mint: Option<Pubkey>,
+    new_user: &Pubkey,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::UserFundWithdrawalRequestTransferContext {
+            user: *user,
+            receipt_token_mint: fund.receipt_token_mint,
+            user_fund_account: fund.find_user_fund_account_address(user),
+            fund_withdrawal_request_account: find_fund_withdrawal_request_account_address(
+                receipt_token_mint,
+                supported_token_mint.as_ref(),
+                request_id,
+            ),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserTransferWithdrawalRequest {
+            new_user: *new_user,
+        },
+        vec![],
+    )
+}
+
+pub fn build_user_close_fund_account_instruction(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::UserFundAccountCloseContext {
+            user: *user,
+            receipt_token_mint: *receipt_token_mint,
+            user_fund_account: FundAddresses::new(receipt_token_mint)
+                .find_user_fund_account_address(user),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserCloseFundAccount {},
+        vec![],
+    )
+}
+
+/// The receipt token account of the user must exist.
+pub fn build_user_create_reward_account_idempotent_instruction(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    desired_account_size: Option<u32>,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::UserRewardAccountInitOrUpdateContext {
+            user: *user,
+            system_program: system_program::ID,
+            receipt_token_mint: fund.receipt_token_mint,
+            receipt_token_program: token_2022::ID,
+            user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserCreateRewardAccountIdempotent {
+            desired_account_size,
+        },
+        vec![],
+    )
+}
+
+/// Anyone can update the reward pools of a user.
+pub fn build_user_update_reward_pools_instruction(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    num_blocks_to_settle: Option<u16>,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::UserRewardContext {
+            user: *user,
+            receipt_token_mint: fund.receipt_token_mint,
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserUpdateRewardPools {
+            num_blocks_to_settle,
+        },
+        vec![],
+    )
+}
+
+/// `claim_authority` is the user or the delegate of its reward account.
+/// Claims the whole claimable amount unless `amount` is given.
+pub fn build_user_claim_reward_instruction(
+    claim_authority: &Pubkey,
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    reward_token_mint: &Pubkey,
+    reward_token_program: &Pubkey,
+    destination_reward_token_account: &Pubkey,
+    is_bonus_pool: bool,
+    amount: Option<u64>,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::UserRewardClaimContext {
+            claim_authority: *claim_authority,
+            user: *user,
+            receipt_token_mint: fund.receipt_token_mint,
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            reward_reserve_account: fund.reward_reserve_account,
+            reward_token_mint: *reward_token_mint,
+            reward_token_program: *reward_token_program,
+            reward_token_reserve_account: get_associated_token_address_with_program_id(
+                &fund.reward_reserve_account,
+                reward_token_mint,
+                reward_token_program,
+            ),
+            destination_reward_token_account: *destination_reward_token_account,
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserClaimReward {
+            is_bonus_pool,
+            amount,
+        },
+        vec![],
+    )
+}
+
+/// `delegate_authority` is the user or the current delegate of its reward account.
+pub fn build_user_delegate_reward_account_instruction(
+    delegate_authority: &Pubkey,
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    delegate: Option<Pubkey>,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::UserRewardAccountDelegateContext {
+            delegate_authority: *delegate_authority,
+            user: *user,
+            receipt_token_mint: fund.receipt_token_mint,
+            user_receipt_token_account: fund.find_user_receipt_token_account_address(user),
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserDelegateRewardAccount { delegate },
+        vec![],
+    )
+}
+
+pub fn build_user_close_reward_account_instruction(
+    user: &Pubkey,
+    receipt_token_mint: &Pubkey,
+    ignore_unclaimed_rewards: bool,
+) -> Instruction {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    build_instruction(
+        accounts::UserRewardAccountCloseContext {
+            user: *user,
+            receipt_token_mint: fund.receipt_token_mint,
+            reward_account: fund.reward_account,
+            user_reward_account: fund.find_user_reward_account_address(user),
+            event_authority: find_event_authority_address(),
+            program: ID,
+        },
+        instruction::UserCloseRewardAccount {
+            ignore_unclaimed_rewards,
+        },
+        vec![],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Discriminator;
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    #[test]
+    fn test_build_user_deposit_sol_instruction() {
+        let user = Pubkey::new_unique();
+        let mut fund_account = FundAccount::zeroed();
+        fund_account.receipt_token_mint = Pubkey::new_unique();
+        let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+
+        let ix = build_user_deposit_sol_instruction(&user, &fund_account, 1_000, None, Some(900));
+        assert_eq!(ix.program_id, ID);
+        assert_eq!(ix.accounts[0], AccountMeta::new_readonly(user, true));
+        assert!(ix
+            .accounts
+            .iter()
+            .any(|meta| meta.pubkey == fund.find_user_fund_account_address(&user)));
+        assert_eq!(ix.accounts.last().unwrap().pubkey, ID);
+        assert!(ix
+            .data
+            .starts_with(instruction::UserDepositSol::DISCRIMINATOR));
+    }
+}
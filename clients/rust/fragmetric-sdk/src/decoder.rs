@@ -0,0 +1,69 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::{AccountDeserialize, ZeroCopy};
+use restaking::modules::fund::{FundAccount, FundWithdrawalRequestAccount, UserFundAccount};
+use restaking::modules::reward::{RewardAccount, UserRewardAccount};
+
+/// Decodes zero-copy account data fetched from RPC.
+///
+/// The data is copied into a new allocation since the given buffer is not aligned.
+/// An account which has been allocated partially yet (e.g. [`FundAccount`] under reallocation) is rejected.
+pub fn decode_zero_copy_account<T: ZeroCopy + Owner>(data: &[u8]) -> Result<Box<T>> {
+    let discriminator = T::DISCRIMINATOR;
+    if !data.starts_with(discriminator) {
+        return err!(ErrorCode::AccountDiscriminatorMismatch);
+    }
+
+    let data = data[discriminator.len()..]
+        .get(..core::mem::size_of::<T>())
+        .ok_or_else(|| error!(ErrorCode::AccountDidNotDeserialize))?;
+    let mut account: Box<T> = bytemuck::allocation::zeroed_box();
+    bytemuck::bytes_of_mut(account.as_mut()).copy_from_slice(data);
+
+    Ok(account)
+}
+
+pub fn decode_fund_account(data: &[u8]) -> Result<Box<FundAccount>> {
+    decode_zero_copy_account(data)
+}
+
+pub fn decode_reward_account(data: &[u8]) -> Result<Box<RewardAccount>> {
+    decode_zero_copy_account(data)
+}
+
+pub fn decode_user_reward_account(data: &[u8]) -> Result<Box<UserRewardAccount>> {
+    decode_zero_copy_account(data)
+}
+
+pub fn decode_user_fund_account(mut data: &[u8]) -> Result<UserFundAccount> {
+    UserFundAccount::try_deserialize(&mut data)
+}
+
+pub fn decode_fund_withdrawal_request_account(
+    mut data: &[u8],
+) -> Result<FundWithdrawalRequestAccount> {
+    FundWithdrawalRequestAccount::try_deserialize(&mut data)
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Discriminator;
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_zero_copy_account() {
+        let mut data = FundAccount::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&FundAccount::zeroed()));
+
+        let fund_account = decode_fund_account(&data).unwrap();
+        assert_eq!(fund_account.receipt_token_mint, Pubkey::default());
+        assert!(fund_account.get_pricing_source_addresses().is_empty());
+
+        // not enough data
+        decode_fund_account(&data[..data.len() - 1]).unwrap_err();
+        // wrong discriminator
+        decode_reward_account(&data).unwrap_err();
+    }
+}
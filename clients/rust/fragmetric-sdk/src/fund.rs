@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use restaking::modules::fund::commands::{InitializeCommand, OperationCommandEntry};
+use restaking::modules::fund::FundAccount;
+
+use crate::{find_fund_withdrawal_request_account_address, FundAddresses};
+
+/// returns (next_sequence, next_command) of the operation cycle at `unix_timestamp`.
+///
+/// The program resets the operation cycle to the initial command if the command has expired
+/// (see `FUND_ACCOUNT_OPERATION_COMMAND_EXPIRATION_SECONDS`) or the previous cycle is completed.
+pub fn get_next_operation_command(
+    fund_account: &FundAccount,
+    unix_timestamp: i64,
+) -> Result<(u16, OperationCommandEntry)> {
+    let (sequence, next_command) = fund_account.get_operation_next_command()?;
+    let expired_at = fund_account.get_operation_expired_at();
+    Ok(
+        match next_command.filter(|_| unix_timestamp <= expired_at) {
+            Some(entry) => (sequence, entry),
+            None => (
+                0,
+                OperationCommandEntry {
+                    command: InitializeCommand::default().into(),
+                    required_accounts: Vec::new(),
+                },
+            ),
+        },
+    )
+}
+
+/// Addresses of token accounts required to reconcile the fund, in order:
+/// pairs of supported token reserve and treasury account, normalized token reserve account if set,
+/// then receipt token reserve account of each restaking vault.
+pub fn find_accounts_to_reconcile(fund_account: &FundAccount) -> Vec<Pubkey> {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+    let mut accounts = Vec::new();
+    for (token_mint, token_program) in fund_account.get_supported_token_mints_iter() {
+        accounts.extend([
+            fund.find_fund_supported_token_reserve_account_address(&token_mint, &token_program),
+            get_associated_token_address_with_program_id(
+                &fund.fund_treasury_account,
+                &token_mint,
+                &token_program,
+            ),
+        ]);
+    }
+    if let Some((token_mint, token_program)) = fund_account.get_normalized_token_mint() {
+        accounts.push(get_associated_token_address_with_program_id(
+            &fund.fund_reserve_account,
+            &token_mint,
+            &token_program,
+        ));
+    }
+    for (_, receipt_token_mint, receipt_token_program) in
+        fund_account.get_restaking_vault_receipt_token_mints_iter()
+    {
+        accounts.push(get_associated_token_address_with_program_id(
+            &fund.fund_reserve_account,
+            &receipt_token_mint,
+            &receipt_token_program,
+        ));
+    }
+
+    accounts
+}
+
+/// Tokens paid out by in-kind withdrawal along with SOL, in order:
+/// supported tokens, normalized token if set, then receipt token of each restaking vault.
+/// returns [(token_mint, token_program, reserve_account)]
+pub fn find_in_kind_withdrawal_token_accounts(
+    fund_account: &FundAccount,
+) -> Vec<(Pubkey, Pubkey, Pubkey)> {
+    let fund = FundAddresses::new(&fund_account.receipt_token_mint);
+    fund_account
+        .get_supported_token_mints_iter()
+        .chain(fund_account.get_normalized_token_mint())
+        .chain(
+            fund_account
+                .get_restaking_vault_receipt_token_mints_iter()
+                .map(|(_, receipt_token_mint, receipt_token_program)| {
+                    (receipt_token_mint, receipt_token_program)
+                }),
+        )
+        .map(|(token_mint, token_program)| {
+            (
+                token_mint,
+                token_program,
+                get_associated_token_address_with_program_id(
+                    &fund.fund_reserve_account,
+                    &token_mint,
+                    &token_program,
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Address of the withdrawal request account to be created by the next `user_request_withdrawal`.
+pub fn find_next_withdrawal_request_account_address(
+    fund_account: &FundAccount,
+    supported_token_mint: Option<Pubkey>,
+) -> Result<Pubkey> {
+    let request_id = fund_account.get_withdrawal_last_created_request_id(supported_token_mint)? + 1;
+
+    Ok(find_fund_withdrawal_request_account_address(
+        &fund_account.receipt_token_mint,
+        supported_token_mint.as_ref(),
+        request_id,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    #[test]
+    fn test_get_next_operation_command_resets_expired_command() {
+        let fund_account = FundAccount::zeroed();
+
+        let (sequence, entry) = get_next_operation_command(&fund_account, 1_000).unwrap();
+        assert_eq!(sequence, 0);
+        assert_eq!(entry.command.discriminant(), 1);
+        assert!(entry.required_accounts.is_empty());
+    }
+
+    #[test]
+    fn test_find_accounts_of_fund_without_tokens() {
+        let mut fund_account = FundAccount::zeroed();
+        fund_account.receipt_token_mint = Pubkey::new_unique();
+
+        assert!(find_accounts_to_reconcile(&fund_account).is_empty());
+        assert!(find_in_kind_withdrawal_token_accounts(&fund_account).is_empty());
+        assert_eq!(
+            find_next_withdrawal_request_account_address(&fund_account, None).unwrap(),
+            find_fund_withdrawal_request_account_address(&fund_account.receipt_token_mint, None, 1,),
+        );
+    }
+}
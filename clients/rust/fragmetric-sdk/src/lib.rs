@@ -0,0 +1,30 @@
+//! Rust client SDK for the Fragmetric Liquid Restaking Program.
+//!
+//! Instruction accounts and arguments of every program entrypoint are re-exported from the program crate
+//! as [`accounts`] and [`instruction`], and [`build_instruction`] assembles them into an [`Instruction`].
+//! Typed builders named `build_<entrypoint>_instruction` derive the accounts for you.
+//!
+//! ```ignore
+//! let ix = build_fund_manager_update_max_price_age_slots_instruction(
+//!     &fund_manager,
+//!     &receipt_token_mint,
+//!     150,
+//! );
+//! ```
+
+mod builder;
+mod decoder;
+mod fund;
+mod pda;
+mod pricing;
+mod transfer_hook;
+
+pub use builder::*;
+pub use decoder::*;
+pub use fund::*;
+pub use pda::*;
+pub use pricing::*;
+pub use transfer_hook::*;
+
+pub use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+pub use restaking::{accounts, instruction, modules, ID};
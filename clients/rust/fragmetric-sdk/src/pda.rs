@@ -0,0 +1,257 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_2022;
+use restaking::modules::fund::{
+    FundAccount, FundConfigurationChangeAccount, FundOperationHistoryAccount,
+    FundWithdrawalBatchAccount, FundWithdrawalRequestAccount,
+};
+use restaking::modules::reward::RewardAccount;
+use restaking::ID;
+
+// seeds of accounts which are not exposed by the program crate.
+const FUND_ACCOUNT_SEED: &[u8] = b"fund";
+const USER_FUND_ACCOUNT_SEED: &[u8] = b"user_fund";
+const REWARD_ACCOUNT_SEED: &[u8] = b"reward";
+const USER_REWARD_ACCOUNT_SEED: &[u8] = b"user_reward";
+const NORMALIZED_TOKEN_POOL_ACCOUNT_SEED: &[u8] = b"nt_pool";
+const NORMALIZED_TOKEN_WITHDRAWAL_ACCOUNT_SEED: &[u8] = b"nt_withdrawal";
+const ROLE_ACCOUNT_SEED: &[u8] = b"role";
+const VIRTUAL_VAULT_SEED: &[u8] = b"virtual_vault";
+const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+const EXTRA_ACCOUNT_META_LIST_SEED: &[u8] = b"extra-account-metas";
+
+pub fn find_role_account_address() -> Pubkey {
+    Pubkey::find_program_address(&[ROLE_ACCOUNT_SEED], &ID).0
+}
+
+/// Required by every instruction with `#[event_cpi]`.
+pub fn find_event_authority_address() -> Pubkey {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], &ID).0
+}
+
+pub fn find_user_fund_account_address(receipt_token_mint: &Pubkey, user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            USER_FUND_ACCOUNT_SEED,
+            receipt_token_mint.as_ref(),
+            user.as_ref(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+pub fn find_user_reward_account_address(receipt_token_mint: &Pubkey, user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            USER_REWARD_ACCOUNT_SEED,
+            receipt_token_mint.as_ref(),
+            user.as_ref(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+/// `supported_token_mint` is `None` for SOL withdrawal batch.
+pub fn find_fund_withdrawal_batch_account_address(
+    receipt_token_mint: &Pubkey,
+    supported_token_mint: Option<&Pubkey>,
+    batch_id: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            FundWithdrawalBatchAccount::SEED,
+            receipt_token_mint.as_ref(),
+            supported_token_mint.unwrap_or(&Pubkey::default()).as_ref(),
+            &batch_id.to_le_bytes(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+/// `supported_token_mint` is `None` for SOL withdrawal request.
+pub fn find_fund_withdrawal_request_account_address(
+    receipt_token_mint: &Pubkey,
+    supported_token_mint: Option<&Pubkey>,
+    request_id: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            FundWithdrawalRequestAccount::SEED,
+            receipt_token_mint.as_ref(),
+            supported_token_mint.unwrap_or(&Pubkey::default()).as_ref(),
+            &request_id.to_le_bytes(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+pub fn find_fund_configuration_change_account_address(
+    receipt_token_mint: &Pubkey,
+    change_id: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            FundConfigurationChangeAccount::SEED,
+            receipt_token_mint.as_ref(),
+            &change_id.to_le_bytes(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+pub fn find_fund_operation_history_account_address(
+    receipt_token_mint: &Pubkey,
+    page_index: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            FundOperationHistoryAccount::SEED,
+            receipt_token_mint.as_ref(),
+            &page_index.to_le_bytes(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+pub fn find_normalized_token_pool_account_address(normalized_token_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            NORMALIZED_TOKEN_POOL_ACCOUNT_SEED,
+            normalized_token_mint.as_ref(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+pub fn find_normalized_token_withdrawal_account_address(
+    normalized_token_mint: &Pubkey,
+    withdrawal_authority: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            NORMALIZED_TOKEN_WITHDRAWAL_ACCOUNT_SEED,
+            normalized_token_mint.as_ref(),
+            withdrawal_authority.as_ref(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+/// Address of the virtual vault which holds a vault receipt token of the fund,
+/// for restaking vaults which are not backed by a restaking protocol.
+pub fn find_virtual_vault_address(
+    vault_receipt_token_mint: &Pubkey,
+    fund_account: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            VIRTUAL_VAULT_SEED,
+            vault_receipt_token_mint.as_ref(),
+            fund_account.as_ref(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+/// Addresses of accounts derived from a receipt token mint.
+#[derive(Clone, Debug)]
+pub struct FundAddresses {
+    pub receipt_token_mint: Pubkey,
+    pub fund_account: Pubkey,
+    pub fund_reserve_account: Pubkey,
+    pub fund_treasury_account: Pubkey,
+    pub fund_wrap_account: Pubkey,
+    pub fund_receipt_token_lock_account: Pubkey,
+    pub reward_account: Pubkey,
+    pub reward_reserve_account: Pubkey,
+    pub extra_account_meta_list: Pubkey,
+}
+
+impl FundAddresses {
+    pub fn new(receipt_token_mint: &Pubkey) -> Self {
+        let find_address =
+            |seed: &[u8]| Pubkey::find_program_address(&[seed, receipt_token_mint.as_ref()], &ID).0;
+        let fund_account = find_address(FUND_ACCOUNT_SEED);
+
+        Self {
+            receipt_token_mint: *receipt_token_mint,
+            fund_account,
+            fund_reserve_account: find_address(FundAccount::RESERVE_SEED),
+            fund_treasury_account: find_address(FundAccount::TREASURY_SEED),
+            fund_wrap_account: find_address(FundAccount::WRAP_SEED),
+            fund_receipt_token_lock_account: get_associated_token_address_with_program_id(
+                &fund_account,
+                receipt_token_mint,
+                &token_2022::ID,
+            ),
+            reward_account: find_address(REWARD_ACCOUNT_SEED),
+            reward_reserve_account: find_address(RewardAccount::RESERVE_SEED),
+            extra_account_meta_list: find_address(EXTRA_ACCOUNT_META_LIST_SEED),
+        }
+    }
+
+    pub fn find_user_fund_account_address(&self, user: &Pubkey) -> Pubkey {
+        find_user_fund_account_address(&self.receipt_token_mint, user)
+    }
+
+    pub fn find_user_reward_account_address(&self, user: &Pubkey) -> Pubkey {
+        find_user_reward_account_address(&self.receipt_token_mint, user)
+    }
+
+    pub fn find_user_receipt_token_account_address(&self, user: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(
+            user,
+            &self.receipt_token_mint,
+            &token_2022::ID,
+        )
+    }
+
+    /// Associated token account of the fund reserve account, which holds the supported token.
+    pub fn find_fund_supported_token_reserve_account_address(
+        &self,
+        supported_token_mint: &Pubkey,
+        supported_token_program: &Pubkey,
+    ) -> Pubkey {
+        get_associated_token_address_with_program_id(
+            &self.fund_reserve_account,
+            supported_token_mint,
+            supported_token_program,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use restaking::modules::reward::UserRewardAccount;
+
+    use super::*;
+
+    #[test]
+    fn test_addresses_match_program() {
+        let receipt_token_mint = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let fund = FundAddresses::new(&receipt_token_mint);
+
+        assert_eq!(
+            fund.reward_account,
+            RewardAccount::find_account_address(&receipt_token_mint),
+        );
+        assert_eq!(
+            fund.find_user_reward_account_address(&user),
+            UserRewardAccount::find_account_address(&receipt_token_mint, &user),
+        );
+        assert_eq!(
+            find_fund_withdrawal_request_account_address(&receipt_token_mint, None, 1),
+            FundWithdrawalRequestAccount::find_account_address(&receipt_token_mint, None, 1).0,
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use restaking::modules::fund::FundAccount;
+use restaking::modules::pricing::TokenPricingSource;
+
+/// Remaining accounts to price the fund assets, required by instructions which update the fund prices,
+/// e.g. deposit, withdrawal request and operation commands.
+pub fn get_pricing_source_account_metas(fund_account: &FundAccount) -> Vec<AccountMeta> {
+    fund_account
+        .get_pricing_source_addresses()
+        .iter()
+        .map(|address| AccountMeta::new_readonly(*address, false))
+        .collect()
+}
+
+/// Account to read the price from, a pegged token has none.
+pub fn get_pricing_source_address(pricing_source: &TokenPricingSource) -> Option<Pubkey> {
+    match pricing_source {
+        TokenPricingSource::SPLStakePool { address }
+        | TokenPricingSource::MarinadeStakePool { address }
+        | TokenPricingSource::JitoRestakingVault { address }
+        | TokenPricingSource::FragmetricNormalizedTokenPool { address }
+        | TokenPricingSource::FragmetricRestakingFund { address }
+        | TokenPricingSource::OrcaDEXLiquidityPool { address }
+        | TokenPricingSource::SanctumSingleValidatorSPLStakePool { address }
+        | TokenPricingSource::SolvBTCVault { address }
+        | TokenPricingSource::SanctumMultiValidatorSPLStakePool { address }
+        | TokenPricingSource::VirtualVault { address } => Some(*address),
+        TokenPricingSource::PeggedToken { .. } => None,
+    }
+}
+
+/// Pricing sources of the fund followed by the pricing source of an asset being added to the fund.
+pub fn get_pricing_source_account_metas_including(
+    fund_account: &FundAccount,
+    pricing_source: &TokenPricingSource,
+) -> Vec<AccountMeta> {
+    let mut account_metas = get_pricing_source_account_metas(fund_account);
+    account_metas.extend(
+        get_pricing_source_address(pricing_source)
+            .map(|address| AccountMeta::new_readonly(address, false)),
+    );
+
+    account_metas
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_error::ProgramError;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_2022;
+use restaking::modules::fund::receipt_token_extra_account_metas;
+use restaking::ID;
+
+use crate::FundAddresses;
+
+/// Extra accounts to append to a receipt token `transfer_checked` instruction of the token-2022 program.
+///
+/// It resolves the extra account meta list of the receipt token mint offline,
+/// followed by the transfer hook program and the extra account meta list itself.
+pub fn get_receipt_token_transfer_hook_extra_account_metas(
+    receipt_token_mint: &Pubkey,
+    source_owner: &Pubkey,
+    destination_owner: &Pubkey,
+) -> Result<Vec<AccountMeta>> {
+    let fund = FundAddresses::new(receipt_token_mint);
+
+    // accounts of `transfer_checked`, token account data is given up to the owner which seeds refer to.
+    let token_account_data =
+        |owner: &Pubkey| [receipt_token_mint.as_ref(), owner.as_ref()].concat();
+    let source_token_account_data = token_account_data(source_owner);
+    let destination_token_account_data = token_account_data(destination_owner);
+    let accounts: [(Pubkey, Option<&[u8]>); 5] = [
+        (
+            get_associated_token_address_with_program_id(
+                source_owner,
+                receipt_token_mint,
+                &token_2022::ID,
+            ),
+            Some(source_token_account_data.as_slice()),
+        ),
+        (*receipt_token_mint, None),
+        (
+            get_associated_token_address_with_program_id(
+                destination_owner,
+                receipt_token_mint,
+                &token_2022::ID,
+            ),
+            Some(destination_token_account_data.as_slice()),
+        ),
+        (*source_owner, None),
+        (fund.extra_account_meta_list, None),
+    ];
+
+    let mut extra_account_metas = receipt_token_extra_account_metas()?
+        .iter()
+        .map(|extra_account_meta| {
+            extra_account_meta.resolve(&[], &ID, |index| {
+                accounts.get(index).map(|(address, data)| (address, *data))
+            })
+        })
+        .collect::<core::result::Result<Vec<_>, ProgramError>>()?;
+
+    // transfer hook program and validation account
+    extra_account_metas.push(AccountMeta::new_readonly(ID, false));
+    extra_account_metas.push(AccountMeta::new_readonly(
+        fund.extra_account_meta_list,
+        false,
+    ));
+
+    Ok(extra_account_metas)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::find_event_authority_address;
+
+    use super::*;
+
+    #[test]
+    fn test_get_receipt_token_transfer_hook_extra_account_metas() {
+        let receipt_token_mint = Pubkey::new_unique();
+        let source_owner = Pubkey::new_unique();
+        let destination_owner = Pubkey::new_unique();
+        let fund = FundAddresses::new(&receipt_token_mint);
+
+        let extra_account_metas = get_receipt_token_transfer_hook_extra_account_metas(
+            &receipt_token_mint,
+            &source_owner,
+            &destination_owner,
+        )
+        .unwrap();
+        assert_eq!(
+            extra_account_metas,
+            vec![
+                AccountMeta::new(fund.fund_account, false),
+                AccountMeta::new(fund.reward_account, false),
+                AccountMeta::new(fund.find_user_fund_account_address(&source_owner), false),
+                AccountMeta::new(fund.find_user_reward_account_address(&source_owner), false),
+                AccountMeta::new(
+                    fund.find_user_fund_account_address(&destination_owner),
+                    false,
+                ),
+                AccountMeta::new(
+                    fund.find_user_reward_account_address(&destination_owner),
+                    false,
+                ),
+                AccountMeta::new_readonly(find_event_authority_address(), false),
+                AccountMeta::new_readonly(ID, false),
+                AccountMeta::new_readonly(ID, false),
+                AccountMeta::new_readonly(fund.extra_account_meta_list, false),
+            ],
+        );
+    }
+}
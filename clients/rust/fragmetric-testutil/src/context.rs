@@ -0,0 +1,262 @@
+use core::cell::RefMut;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+use anyhow::{anyhow, bail};
+use fragmetric_keeper::{Keeper, KeeperConfig, LiteSVMBackend};
+use fragmetric_sdk::{accounts, build_instruction, find_event_authority_address, instruction};
+use litesvm::types::TransactionMetadata;
+use litesvm::LiteSVM;
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+use crate::fixture;
+
+const INITIAL_BALANCE: u64 = 1_000_000 * 1_000_000_000;
+
+/// An in-process SVM loaded with the programs and account snapshots,
+/// whose role account is initialized with the local admin and fund manager keypairs.
+pub struct TestContext {
+    backend: LiteSVMBackend,
+    pub payer: Keypair,
+    pub admin: Keypair,
+    pub fund_manager: Keypair,
+    pub operator: Keypair,
+}
+
+impl TestContext {
+    pub fn new() -> anyhow::Result<Self> {
+        if !fixture::programs_exist() {
+            bail!("the programs must be built by `anchor build` in advance");
+        }
+
+        let mut svm = LiteSVM::new();
+        fixture::load_programs(&mut svm)?;
+        fixture::load_accounts(&mut svm)?;
+
+        let ctx = Self {
+            backend: LiteSVMBackend::new(svm),
+            payer: fixture::read_keypair(fixture::WALLET_KEYPAIR)?,
+            admin: fixture::read_keypair(fixture::ADMIN_KEYPAIR)?,
+            fund_manager: fixture::read_keypair(fixture::FUND_MANAGER_KEYPAIR)?,
+            operator: Keypair::new(),
+        };
+        for keypair in [&ctx.payer, &ctx.admin, &ctx.fund_manager, &ctx.operator] {
+            ctx.airdrop(&keypair.pubkey(), INITIAL_BALANCE)?;
+        }
+
+        ctx.send_transaction(
+            &[build_instruction(
+                accounts::AdminRoleAccountInitialContext {
+                    payer: ctx.payer.pubkey(),
+                    admin: ctx.admin.pubkey(),
+                    system_program: system_program::ID,
+                    role_account: fragmetric_sdk::find_role_account_address(),
+                    event_authority: find_event_authority_address(),
+                    program: fragmetric_sdk::ID,
+                },
+                instruction::AdminInitializeRoleAccount {
+                    fund_manager: ctx.fund_manager.pubkey(),
+                    operator: ctx.operator.pubkey(),
+                },
+                vec![],
+            )],
+            &[&ctx.admin],
+        )?;
+
+        Ok(ctx)
+    }
+
+    pub fn backend(&self) -> &LiteSVMBackend {
+        &self.backend
+    }
+
+    pub fn svm_mut(&self) -> RefMut<'_, LiteSVM> {
+        self.backend.svm_mut()
+    }
+
+    /// Keeper which runs the operation cycle of the fund with the operator of the role account.
+    pub fn create_keeper(&self, receipt_token_mint: &Pubkey) -> Keeper<&LiteSVMBackend> {
+        Keeper::new(
+            &self.backend,
+            self.operator.insecure_clone(),
+            *receipt_token_mint,
+            KeeperConfig::default(),
+        )
+    }
+
+    /// Sends a transaction paid by the payer, the blockhash is expired afterward
+    /// so that the same instructions can be sent again.
+    pub fn send_transaction(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> anyhow::Result<TransactionMetadata> {
+        let mut svm = self.svm_mut();
+        let mut all_instructions =
+            vec![ComputeBudgetInstruction::set_compute_unit_limit(1_400_000)];
+        all_instructions.extend_from_slice(instructions);
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+
+        let transaction = Transaction::new_signed_with_payer(
+            &all_instructions,
+            Some(&self.payer.pubkey()),
+            &all_signers,
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        svm.expire_blockhash();
+
+        result.map_err(|failed| anyhow!("{}\n{}", failed.err, failed.meta.logs.join("\n")))
+    }
+
+    /// Simulates a transaction paid by the payer, without committing its state changes.
+    pub fn simulate_transaction(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> anyhow::Result<TransactionMetadata> {
+        let svm = self.svm_mut();
+        let mut all_instructions =
+            vec![ComputeBudgetInstruction::set_compute_unit_limit(1_400_000)];
+        all_instructions.extend_from_slice(instructions);
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+
+        let transaction = Transaction::new_signed_with_payer(
+            &all_instructions,
+            Some(&self.payer.pubkey()),
+            &all_signers,
+            svm.latest_blockhash(),
+        );
+
+        svm.simulate_transaction(transaction)
+            .map(|info| info.meta)
+            .map_err(|failed| anyhow!("{}\n{}", failed.err, failed.meta.logs.join("\n")))
+    }
+
+    pub fn airdrop(&self, address: &Pubkey, lamports: u64) -> anyhow::Result<()> {
+        self.svm_mut()
+            .airdrop(address, lamports)
+            .map_err(|failed| anyhow!("failed to airdrop to {address}: {}", failed.err))?;
+
+        Ok(())
+    }
+
+    pub fn create_user(&self) -> anyhow::Result<Keypair> {
+        let user = Keypair::new();
+        self.airdrop(&user.pubkey(), INITIAL_BALANCE)?;
+
+        Ok(user)
+    }
+
+    pub fn get_account(&self, address: &Pubkey) -> Option<Account> {
+        self.svm_mut().get_account(address)
+    }
+
+    pub fn get_balance(&self, address: &Pubkey) -> u64 {
+        self.get_account(address)
+            .map(|account| account.lamports)
+            .unwrap_or_default()
+    }
+
+    /// Amount of a token account of either token program, zero if the account does not exist.
+    pub fn get_token_amount(&self, token_account: &Pubkey) -> anyhow::Result<u64> {
+        let Some(account) = self.get_account(token_account) else {
+            return Ok(0);
+        };
+        let state = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)?;
+
+        Ok(state.base.amount)
+    }
+
+    /// Supply of a mint of either token program.
+    pub fn get_mint_supply(&self, mint: &Pubkey) -> anyhow::Result<u64> {
+        let account = self
+            .get_account(mint)
+            .ok_or_else(|| anyhow!("mint not found: {mint}"))?;
+        let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&account.data)?;
+
+        Ok(state.base.supply)
+    }
+
+    /// Writes an SPL token mint without mint authority.
+    pub fn set_mint(&self, mint: &Pubkey, decimals: u8, supply: u64) -> anyhow::Result<()> {
+        let state = spl_token::state::Mint {
+            mint_authority: COption::None,
+            supply,
+            decimals,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        self.set_packed_account(mint, &state, spl_token::ID)
+    }
+
+    /// Writes an associated token account of the SPL token program holding `amount`, regardless of the mint supply.
+    pub fn set_token_account(
+        &self,
+        mint: &Pubkey,
+        owner: &Pubkey,
+        amount: u64,
+    ) -> anyhow::Result<Pubkey> {
+        let address = get_associated_token_address_with_program_id(owner, mint, &spl_token::ID);
+        let state = spl_token::state::Account {
+            mint: *mint,
+            owner: *owner,
+            amount,
+            delegate: COption::None,
+            state: spl_token::state::AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+        self.set_packed_account(&address, &state, spl_token::ID)?;
+
+        Ok(address)
+    }
+
+    fn set_packed_account<T: Pack>(
+        &self,
+        address: &Pubkey,
+        state: &T,
+        owner: Pubkey,
+    ) -> anyhow::Result<()> {
+        let mut data = vec![0; T::LEN];
+        T::pack_into_slice(state, &mut data);
+
+        let mut svm = self.svm_mut();
+        let account = Account {
+            lamports: svm.minimum_balance_for_rent_exemption(T::LEN),
+            data,
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        };
+        svm.set_account(*address, account)
+            .map_err(|err| anyhow!("failed to set account {address}: {err:?}"))
+    }
+
+    pub fn get_clock(&self) -> Clock {
+        self.svm_mut().get_sysvar::<Clock>()
+    }
+
+    /// Advances the clock by `seconds`, with a slot per 400ms.
+    pub fn warp_clock(&self, seconds: i64) {
+        let mut svm = self.svm_mut();
+        let mut clock = svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        clock.slot += (seconds.max(0) as u64 * 5).div_ceil(2);
+        svm.set_sysvar(&clock);
+    }
+}
@@ -0,0 +1,283 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anchor_lang::prelude::*;
+use anyhow::{anyhow, Context};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use fragmetric_sdk::modules::pricing::TokenPricingSource;
+use litesvm::LiteSVM;
+use serde::Deserialize;
+use solana_sdk::account::Account;
+use solana_sdk::signature::{read_keypair_file, Keypair};
+
+/// Programs to load as (program id, path relative to the workspace root).
+/// The restaking and solv programs must be built by `anchor build` in advance.
+const PROGRAMS: [(Pubkey, &str); 11] = [
+    (fragmetric_sdk::ID, "target/deploy/restaking.so"),
+    (
+        pubkey!("9beGuWXNoKPKCApT6xJUm5435Fz8EMGzoTTXgkcf3zAz"),
+        "target/deploy/solv.so",
+    ),
+    (
+        pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"),
+        "programs/restaking/tests/mocks/metaplex.so",
+    ),
+    (
+        pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy"),
+        "programs/restaking/tests/mocks/spl_stake_pool.so",
+    ),
+    (
+        pubkey!("MarBmsSgKXdrN1egZf5sqe1TMai9K1rChYNDJgjq7aD"),
+        "programs/restaking/tests/mocks/marinade_stake_pool.so",
+    ),
+    (
+        pubkey!("SP12tWFxD9oJsVWNavTTBZvMbA6gkAmxtVgxdqvyvhY"),
+        "programs/restaking/tests/mocks/sanctum_single_validator_stake_pool.so",
+    ),
+    (
+        pubkey!("SPMBzsVUuoHA4Jm6KunbsotaahvVikZs1JyTW6iJvbn"),
+        "programs/restaking/tests/mocks/sanctum_multi_validator_stake_pool.so",
+    ),
+    (
+        JITO_VAULT_PROGRAM_ID,
+        "programs/restaking/tests/mocks/jito_vault.so",
+    ),
+    (
+        pubkey!("RestkWeAVL8fRGgzhfeoqFhsqKRchg6aa1XrcH96z4Q"),
+        "programs/restaking/tests/mocks/jito_restaking.so",
+    ),
+    (
+        pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc"),
+        "programs/restaking/tests/mocks/orca_whirlpool.so",
+    ),
+    // overrides the builtin, same as the TypeScript test suites.
+    (
+        anchor_spl::token_2022::ID,
+        "programs/restaking/tests/mocks/spl_token_2022.so",
+    ),
+];
+
+/// Directories of account snapshots (`solana account --output json`),
+/// e.g. SPL stake pools, Marinade, Jito vaults and Orca whirlpools.
+const ACCOUNT_DIRS: [&str; 2] = [
+    "programs/restaking/tests/mocks",
+    "programs/solv/tests/mocks",
+];
+
+pub(crate) const WALLET_KEYPAIR: &str =
+    "keypairs/shared_wallet_GiDkDCZjVC8Nk1Fd457qGSV2g3MQX62n7cV5CvgFyGfF.json";
+pub(crate) const ADMIN_KEYPAIR: &str =
+    "keypairs/restaking/shared_local_admin_9b2RSMDYskVvjVbwF4cVwEhZUaaaUgyYSxvESmnoS4LL.json";
+pub(crate) const FUND_MANAGER_KEYPAIR: &str =
+    "keypairs/restaking/shared_local_fund_manager_5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx.json";
+pub(crate) const NSOL_MINT_KEYPAIR: &str =
+    "keypairs/restaking/shared_local_nsol_mint_4noNmx2RpxK4zdr68Fq1CYM5VhN4yjgGZEFyuB7t2pBX.json";
+
+pub(crate) fn resolve_path(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../..")
+        .join(path)
+}
+
+pub(crate) fn read_keypair(path: &str) -> anyhow::Result<Keypair> {
+    read_keypair_file(resolve_path(path))
+        .map_err(|err| anyhow!("failed to read keypair {path}: {err}"))
+}
+
+/// Whether every program exists, i.e. the restaking and solv programs have been built.
+pub(crate) fn programs_exist() -> bool {
+    PROGRAMS.iter().all(|(_, path)| resolve_path(path).exists())
+}
+
+pub(crate) fn load_programs(svm: &mut LiteSVM) -> anyhow::Result<()> {
+    for (program_id, path) in PROGRAMS {
+        let path = resolve_path(path);
+        svm.add_program_from_file(program_id, &path)
+            .with_context(|| format!("failed to load program: {}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn load_accounts(svm: &mut LiteSVM) -> anyhow::Result<()> {
+    for dir in ACCOUNT_DIRS {
+        for entry in fs::read_dir(resolve_path(dir))? {
+            let path = entry?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                let (address, account) = read_account_file(&path)
+                    .with_context(|| format!("invalid account file: {}", path.display()))?;
+                svm.set_account(address, account)
+                    .map_err(|err| anyhow!("failed to set account {address}: {err:?}"))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct AccountFile {
+    pubkey: String,
+    account: AccountFileData,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountFileData {
+    lamports: u64,
+    /// (data, encoding)
+    data: (String, String),
+    owner: String,
+    executable: bool,
+    rent_epoch: u64,
+}
+
+fn read_account_file(path: &Path) -> anyhow::Result<(Pubkey, Account)> {
+    let file: AccountFile = serde_json::from_slice(&fs::read(path)?)?;
+    let (data, encoding) = file.account.data;
+    if encoding != "base64" {
+        anyhow::bail!("unsupported encoding: {encoding}");
+    }
+
+    Ok((
+        file.pubkey.parse()?,
+        Account {
+            lamports: file.account.lamports,
+            data: BASE64_STANDARD.decode(data)?,
+            owner: file.account.owner.parse()?,
+            executable: file.account.executable,
+            rent_epoch: file.account.rent_epoch,
+        },
+    ))
+}
+
+/// A liquid staking token of the account snapshots which can be added to a fund as a supported token.
+#[derive(Clone)]
+pub struct SupportedTokenFixture {
+    pub mint: Pubkey,
+    pub pricing_source: TokenPricingSource,
+    pub pricing_source_address: Pubkey,
+}
+
+impl SupportedTokenFixture {
+    const fn spl_stake_pool(mint: Pubkey, address: Pubkey) -> Self {
+        Self {
+            mint,
+            pricing_source: TokenPricingSource::SPLStakePool { address },
+            pricing_source_address: address,
+        }
+    }
+
+    const fn marinade_stake_pool(mint: Pubkey, address: Pubkey) -> Self {
+        Self {
+            mint,
+            pricing_source: TokenPricingSource::MarinadeStakePool { address },
+            pricing_source_address: address,
+        }
+    }
+
+    const fn sanctum_single_validator_spl_stake_pool(mint: Pubkey, address: Pubkey) -> Self {
+        Self {
+            mint,
+            pricing_source: TokenPricingSource::SanctumSingleValidatorSPLStakePool { address },
+            pricing_source_address: address,
+        }
+    }
+
+    const fn sanctum_multi_validator_spl_stake_pool(mint: Pubkey, address: Pubkey) -> Self {
+        Self {
+            mint,
+            pricing_source: TokenPricingSource::SanctumMultiValidatorSPLStakePool { address },
+            pricing_source_address: address,
+        }
+    }
+}
+
+/// Supported tokens of the account snapshots, all of them use the SPL token program.
+pub const SUPPORTED_TOKEN_FIXTURES: [SupportedTokenFixture; 6] = [
+    // jitoSOL
+    SupportedTokenFixture::spl_stake_pool(
+        pubkey!("J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn"),
+        pubkey!("Jito4APyf642JPZPx3hGc6WWJ8zPKtRbRs4P815Awbb"),
+    ),
+    // mSOL
+    SupportedTokenFixture::marinade_stake_pool(
+        pubkey!("mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So"),
+        pubkey!("8szGkuLTAux9XMgZ2vtY39jVSowEcpBfFfD8hXSEqdGC"),
+    ),
+    // BNSOL
+    SupportedTokenFixture::spl_stake_pool(
+        pubkey!("BNso1VUJnh4zcfpZa6986Ea66P6TCp59hvtNJ8b1X85"),
+        pubkey!("Hr9pzexrBge3vgmBNRR8u42CNQgBXdHm4UkUN2DH4a7r"),
+    ),
+    // bbSOL
+    SupportedTokenFixture::sanctum_single_validator_spl_stake_pool(
+        pubkey!("Bybit2vBJGhPF52GBdNaQfUJ6ZpThSgHBobjWZpLPb4B"),
+        pubkey!("2aMLkB5p5gVvCwKkdSo5eZAL1WwhZbxezQr1wxiynRhq"),
+    ),
+    // jupSOL
+    SupportedTokenFixture::sanctum_multi_validator_spl_stake_pool(
+        pubkey!("jupSoLaHXQiZZTSfEWMTRRgpnyFm8f6sZdosWBjx93v"),
+        pubkey!("8VpRhuxa7sUUepdY3kQiTmX9rS5vx4WgaXiAnXq4KCtr"),
+    ),
+    // bSOL
+    SupportedTokenFixture::spl_stake_pool(
+        pubkey!("bSo13r4TkiE4KumL71LsHTPpL2euBYLFx6h9HP3piy1"),
+        pubkey!("stk9ApL5HeVAwPLr3TLhDXdZS8ptVu7zp6ov8HFDuMi"),
+    ),
+];
+
+pub(crate) const JITO_VAULT_PROGRAM_ID: Pubkey =
+    pubkey!("Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8");
+pub(crate) const JITO_VAULT_CONFIG: Pubkey = pubkey!("UwuSgAq4zByffCGCrWH87DsjfsewYjuqHfJEpzw1Jq3");
+pub(crate) const JITO_VAULT_PROGRAM_FEE_WALLET: Pubkey =
+    pubkey!("5eosrve6LktMZgVNszYzebgmmC7BjLK8NoWyRQtcmGTF");
+
+/// A Jito vault of the account snapshots, whose admins are the local fund manager.
+#[derive(Clone)]
+pub struct JitoVaultFixture {
+    pub vault: Pubkey,
+    pub vault_receipt_token_mint: Pubkey,
+    pub vault_supported_token_mint: Pubkey,
+    pub vault_fee_wallet: Pubkey,
+}
+
+/// Jito vault of nSOL, the normalized token of the local nSOL mint keypair.
+pub const NSOL_JITO_VAULT_FIXTURE: JitoVaultFixture = JitoVaultFixture {
+    vault: pubkey!("HR1ANmDHjaEhknvsTaK48M5xZtbBiwNdXM5NTiWhAb4S"),
+    vault_receipt_token_mint: pubkey!("CkXLPfDG3cDawtUvnztq99HdGoQWhJceBZxqKYL2TUrg"),
+    vault_supported_token_mint: pubkey!("4noNmx2RpxK4zdr68Fq1CYM5VhN4yjgGZEFyuB7t2pBX"),
+    vault_fee_wallet: pubkey!("5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx"),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_account_files() {
+        let mut addresses = Vec::new();
+        for dir in ACCOUNT_DIRS {
+            for entry in fs::read_dir(resolve_path(dir)).unwrap() {
+                let path = entry.unwrap().path();
+                if path
+                    .extension()
+                    .is_some_and(|extension| extension == "json")
+                {
+                    addresses.push(read_account_file(&path).unwrap().0);
+                }
+            }
+        }
+
+        for fixture in SUPPORTED_TOKEN_FIXTURES {
+            assert!(addresses.contains(&fixture.mint));
+            assert!(addresses.contains(&fixture.pricing_source_address));
+        }
+        assert!(addresses.contains(&NSOL_JITO_VAULT_FIXTURE.vault));
+        assert!(addresses.contains(&NSOL_JITO_VAULT_FIXTURE.vault_receipt_token_mint));
+        assert!(addresses.contains(&JITO_VAULT_CONFIG));
+    }
+}
@@ -0,0 +1,1337 @@
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_lang::system_program;
+use anchor_lang::InstructionData;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::associated_token::spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::{transfer_hook, ExtensionType};
+use anyhow::bail;
+use fragmetric_keeper::KeeperRun;
+use fragmetric_sdk::modules::fund::{
+    DepositQuote, FundAccount, FundReconciliationDiscrepancy, UserFundAccount, WithdrawalQuote,
+    WithdrawalRequest,
+};
+use fragmetric_sdk::modules::pricing::TokenPricingSource;
+use fragmetric_sdk::{
+    accounts, build_instruction, build_operator_reconcile_fund_instruction,
+    build_operator_update_fund_prices_instruction, build_quote_deposit_instruction,
+    build_quote_withdrawal_instruction, build_user_withdraw_in_kind_instruction,
+    decode_fund_account, decode_fund_withdrawal_request_account, decode_reward_account,
+    decode_user_fund_account, decode_user_reward_account, find_event_authority_address,
+    find_fund_withdrawal_batch_account_address, find_fund_withdrawal_request_account_address,
+    find_in_kind_withdrawal_token_accounts, find_next_withdrawal_request_account_address,
+    find_normalized_token_pool_account_address, find_role_account_address,
+    find_virtual_vault_address, get_pricing_source_account_metas, instruction, FundAddresses,
+};
+use jito_vault_sdk::instruction::VaultAdminRole;
+use litesvm::types::TransactionMetadata;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixture;
+use crate::{
+    JitoVaultFixture, SupportedTokenFixture, TestContext, NSOL_JITO_VAULT_FIXTURE,
+    SUPPORTED_TOKEN_FIXTURES,
+};
+
+/// Accounts larger than `MAX_PERMITTED_DATA_INCREASE` are reallocated over multiple transactions
+/// until they reach the latest version.
+const MAX_ACCOUNT_UPDATES: usize = 32;
+
+/// Builds a fund of a new receipt token mint with the given supported tokens and restaking vaults,
+/// configured like the TypeScript test suites: every feature is enabled and capacities are unlimited.
+pub struct FundBuilder<'a> {
+    ctx: &'a TestContext,
+    receipt_token_mint: Keypair,
+    receipt_token_decimals: u8,
+    supported_tokens: Vec<SupportedTokenFixture>,
+    num_virtual_vaults: usize,
+    jito_vault: Option<JitoVaultFixture>,
+    withdrawal_fee_rate_bps: u16,
+    withdrawal_batch_threshold_seconds: i64,
+}
+
+impl<'a> FundBuilder<'a> {
+    pub fn new(ctx: &'a TestContext) -> Self {
+        Self {
+            ctx,
+            receipt_token_mint: Keypair::new(),
+            receipt_token_decimals: 9,
+            supported_tokens: Vec::new(),
+            num_virtual_vaults: 0,
+            jito_vault: None,
+            withdrawal_fee_rate_bps: 10,
+            withdrawal_batch_threshold_seconds: 1,
+        }
+    }
+
+    pub fn with_receipt_token_mint(mut self, receipt_token_mint: Keypair) -> Self {
+        self.receipt_token_mint = receipt_token_mint;
+        self
+    }
+
+    /// Adds the first `n` tokens of [`SUPPORTED_TOKEN_FIXTURES`].
+    pub fn with_supported_tokens(mut self, n: usize) -> Self {
+        self.supported_tokens
+            .extend(SUPPORTED_TOKEN_FIXTURES.into_iter().take(n));
+        self
+    }
+
+    pub fn with_supported_token(mut self, supported_token: SupportedTokenFixture) -> Self {
+        self.supported_tokens.push(supported_token);
+        self
+    }
+
+    /// Adds `m` virtual vaults, each of which has a new vault receipt token mint.
+    pub fn with_virtual_vaults(mut self, m: usize) -> Self {
+        self.num_virtual_vaults = m;
+        self
+    }
+
+    /// Adds the Jito vault of nSOL, after setting up the nSOL normalized token pool of the supported tokens.
+    /// The nSOL mint is of a fixed keypair, so only a single fund of a context can have it.
+    pub fn with_nsol_jito_vault(mut self) -> Self {
+        self.jito_vault = Some(NSOL_JITO_VAULT_FIXTURE);
+        self
+    }
+
+    pub fn with_withdrawal_fee_rate_bps(mut self, withdrawal_fee_rate_bps: u16) -> Self {
+        self.withdrawal_fee_rate_bps = withdrawal_fee_rate_bps;
+        self
+    }
+
+    pub fn with_withdrawal_batch_threshold_seconds(mut self, seconds: i64) -> Self {
+        self.withdrawal_batch_threshold_seconds = seconds;
+        self
+    }
+
+    pub fn build(self) -> anyhow::Result<TestFund<'a>> {
+        let ctx = self.ctx;
+        let mut fund = TestFund {
+            ctx,
+            addresses: FundAddresses::new(&self.receipt_token_mint.pubkey()),
+            supported_tokens: self.supported_tokens.clone(),
+            normalized_token_mint: None,
+            restaking_vaults: Vec::new(),
+        };
+
+        self.initialize_receipt_token_mint(&fund)?;
+        self.initialize_fund_account(&fund)?;
+        self.initialize_reward_account(&fund)?;
+
+        ctx.send_transaction(
+            &[
+                fund.build_fund_manager_instruction(instruction::FundManagerUpdateFundStrategy {
+                    deposit_enabled: true,
+                    donation_enabled: true,
+                    withdrawal_enabled: true,
+                    transfer_enabled: true,
+                    operation_enabled: true,
+                    withdrawal_fee_rate_bps: self.withdrawal_fee_rate_bps,
+                    withdrawal_batch_threshold_seconds: self.withdrawal_batch_threshold_seconds,
+                    performance_fee_rate_bps: 0,
+                }),
+                fund.build_fund_manager_instruction(instruction::FundManagerUpdateSolStrategy {
+                    sol_depositable: true,
+                    sol_accumulated_deposit_capacity_amount: u64::MAX,
+                    sol_accumulated_deposit_amount: None,
+                    sol_withdrawable: true,
+                    sol_withdrawal_normal_reserve_rate_bps: 0,
+                    sol_withdrawal_normal_reserve_max_amount: u64::MAX,
+                }),
+            ],
+            &[&ctx.fund_manager],
+        )?;
+
+        for supported_token in &self.supported_tokens {
+            self.add_supported_token(&fund, supported_token)?;
+        }
+
+        if let Some(jito_vault) = &self.jito_vault {
+            fund.normalized_token_mint = Some(self.initialize_normalized_token(&fund)?);
+            self.add_jito_vault(&fund, jito_vault)?;
+            fund.restaking_vaults.push(jito_vault.vault);
+        }
+
+        for _ in 0..self.num_virtual_vaults {
+            let vault = self.add_virtual_vault(&fund)?;
+            fund.restaking_vaults.push(vault);
+        }
+
+        Ok(fund)
+    }
+
+    fn initialize_receipt_token_mint(&self, fund: &TestFund) -> anyhow::Result<()> {
+        let ctx = self.ctx;
+        let receipt_token_mint = &fund.addresses.receipt_token_mint;
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::TransferHook,
+        ])?;
+        let lamports = ctx.svm_mut().minimum_balance_for_rent_exemption(space);
+
+        ctx.send_transaction(
+            &[
+                solana_system_interface::instruction::create_account(
+                    &ctx.payer.pubkey(),
+                    receipt_token_mint,
+                    lamports,
+                    space as u64,
+                    &spl_token_2022::ID,
+                ),
+                transfer_hook::instruction::initialize(
+                    &spl_token_2022::ID,
+                    receipt_token_mint,
+                    Some(ctx.admin.pubkey()),
+                    Some(fragmetric_sdk::ID),
+                )?,
+                spl_token_2022::instruction::initialize_mint2(
+                    &spl_token_2022::ID,
+                    receipt_token_mint,
+                    &ctx.admin.pubkey(),
+                    None,
+                    self.receipt_token_decimals,
+                )?,
+            ],
+            &[&self.receipt_token_mint],
+        )?;
+
+        ctx.send_transaction(
+            &[build_instruction(
+                accounts::AdminReceiptTokenMintExtraAccountMetaListInitialContext {
+                    payer: ctx.payer.pubkey(),
+                    admin: ctx.admin.pubkey(),
+                    role_account: find_role_account_address(),
+                    system_program: system_program::ID,
+                    receipt_token_mint: *receipt_token_mint,
+                    extra_account_meta_list: fund.addresses.extra_account_meta_list,
+                    event_authority: find_event_authority_address(),
+                    program: fragmetric_sdk::ID,
+                },
+                instruction::AdminInitializeExtraAccountMetaList,
+                vec![],
+            )],
+            &[&ctx.admin],
+        )?;
+
+        Ok(())
+    }
+
+    fn initialize_fund_account(&self, fund: &TestFund) -> anyhow::Result<()> {
+        let ctx = self.ctx;
+        let receipt_token_mint = fund.addresses.receipt_token_mint;
+
+        ctx.send_transaction(
+            &[
+                create_associated_token_account_idempotent(
+                    &ctx.payer.pubkey(),
+                    &fund.addresses.fund_account,
+                    &receipt_token_mint,
+                    &spl_token_2022::ID,
+                ),
+                build_instruction(
+                    accounts::AdminFundAccountInitialContext {
+                        payer: ctx.payer.pubkey(),
+                        admin: ctx.admin.pubkey(),
+                        role_account: find_role_account_address(),
+                        system_program: system_program::ID,
+                        receipt_token_mint,
+                        receipt_token_program: spl_token_2022::ID,
+                        fund_account: fund.addresses.fund_account,
+                        fund_receipt_token_lock_account: fund
+                            .addresses
+                            .fund_receipt_token_lock_account,
+                        fund_reserve_account: fund.addresses.fund_reserve_account,
+                        fund_treasury_account: fund.addresses.fund_treasury_account,
+                        event_authority: find_event_authority_address(),
+                        program: fragmetric_sdk::ID,
+                    },
+                    instruction::AdminInitializeFundAccount,
+                    vec![],
+                ),
+            ],
+            &[&ctx.admin],
+        )?;
+
+        update_until(
+            ctx,
+            || {
+                build_instruction(
+                    accounts::AdminFundAccountUpdateContext {
+                        payer: ctx.payer.pubkey(),
+                        admin: ctx.admin.pubkey(),
+                        role_account: find_role_account_address(),
+                        system_program: system_program::ID,
+                        receipt_token_mint,
+                        fund_account: fund.addresses.fund_account,
+                        event_authority: find_event_authority_address(),
+                        program: fragmetric_sdk::ID,
+                    },
+                    instruction::AdminUpdateFundAccountIfNeeded {
+                        desired_account_size: None,
+                    },
+                    vec![],
+                )
+            },
+            &[&ctx.admin],
+            || {
+                fund.fetch_fund_account()
+                    .is_ok_and(|fund_account| fund_account.is_latest_version())
+            },
+        )
+    }
+
+    fn initialize_reward_account(&self, fund: &TestFund) -> anyhow::Result<()> {
+        let ctx = self.ctx;
+        let receipt_token_mint = fund.addresses.receipt_token_mint;
+
+        ctx.send_transaction(
+            &[build_instruction(
+                accounts::AdminRewardAccountInitialContext {
+                    payer: ctx.payer.pubkey(),
+                    admin: ctx.admin.pubkey(),
+                    role_account: find_role_account_address(),
+                    system_program: system_program::ID,
+                    receipt_token_mint,
+                    reward_account: fund.addresses.reward_account,
+                    event_authority: find_event_authority_address(),
+                    program: fragmetric_sdk::ID,
+                },
+                instruction::AdminInitializeRewardAccount,
+                vec![],
+            )],
+            &[&ctx.admin],
+        )?;
+
+        update_until(
+            ctx,
+            || {
+                build_instruction(
+                    accounts::AdminRewardAccountUpdateContext {
+                        payer: ctx.payer.pubkey(),
+                        admin: ctx.admin.pubkey(),
+                        role_account: find_role_account_address(),
+                        system_program: system_program::ID,
+                        receipt_token_mint,
+                        reward_account: fund.addresses.reward_account,
+                        event_authority: find_event_authority_address(),
+                        program: fragmetric_sdk::ID,
+                    },
+                    instruction::AdminUpdateRewardAccountIfNeeded {
+                        desired_account_size: None,
+                    },
+                    vec![],
+                )
+            },
+            &[&ctx.admin],
+            || {
+                ctx.get_account(&fund.addresses.reward_account)
+                    .and_then(|account| decode_reward_account(&account.data).ok())
+                    .is_some_and(|reward_account| reward_account.is_latest_version())
+            },
+        )
+    }
+
+    fn add_supported_token(
+        &self,
+        fund: &TestFund,
+        supported_token: &SupportedTokenFixture,
+    ) -> anyhow::Result<()> {
+        let ctx = self.ctx;
+        let mut pricing_sources = get_pricing_source_account_metas(&*fund.fetch_fund_account()?);
+        pricing_sources.push(AccountMeta::new_readonly(
+            supported_token.pricing_source_address,
+            false,
+        ));
+
+        ctx.send_transaction(
+            &[
+                create_associated_token_account_idempotent(
+                    &ctx.payer.pubkey(),
+                    &fund.addresses.fund_reserve_account,
+                    &supported_token.mint,
+                    &spl_token::ID,
+                ),
+                create_associated_token_account_idempotent(
+                    &ctx.payer.pubkey(),
+                    &fund.addresses.fund_treasury_account,
+                    &supported_token.mint,
+                    &spl_token::ID,
+                ),
+                build_instruction(
+                    accounts::FundManagerFundSupportedTokenContext {
+                        fund_manager: ctx.fund_manager.pubkey(),
+                        role_account: find_role_account_address(),
+                        receipt_token_mint: fund.addresses.receipt_token_mint,
+                        fund_account: fund.addresses.fund_account,
+                        fund_reserve_account: fund.addresses.fund_reserve_account,
+                        fund_treasury_account: fund.addresses.fund_treasury_account,
+                        supported_token_mint: supported_token.mint,
+                        supported_token_program: spl_token::ID,
+                        supported_token_reserve_account: fund
+                            .addresses
+                            .find_fund_supported_token_reserve_account_address(
+                                &supported_token.mint,
+                                &spl_token::ID,
+                            ),
+                        supported_token_treasury_account:
+                            get_associated_token_address_with_program_id(
+                                &fund.addresses.fund_treasury_account,
+                                &supported_token.mint,
+                                &spl_token::ID,
+                            ),
+                        event_authority: find_event_authority_address(),
+                        program: fragmetric_sdk::ID,
+                    },
+                    instruction::FundManagerAddSupportedToken {
+                        pricing_source: supported_token.pricing_source.clone(),
+                    },
+                    pricing_sources,
+                ),
+                fund.build_fund_manager_instruction(
+                    instruction::FundManagerUpdateSupportedTokenStrategy {
+                        token_mint: supported_token.mint,
+                        token_depositable: true,
+                        token_accumulated_deposit_capacity_amount: u64::MAX,
+                        token_accumulated_deposit_amount: None,
+                        token_withdrawable: false,
+                        token_withdrawal_normal_reserve_rate_bps: 0,
+                        token_withdrawal_normal_reserve_max_amount: u64::MAX,
+                        sol_allocation_weight: 1,
+                        sol_allocation_capacity_amount: u64::MAX,
+                    },
+                ),
+            ],
+            &[&ctx.fund_manager],
+        )?;
+
+        Ok(())
+    }
+
+    /// Initializes the nSOL normalized token pool of the supported tokens, then sets it to the fund.
+    fn initialize_normalized_token(&self, fund: &TestFund) -> anyhow::Result<Pubkey> {
+        let ctx = self.ctx;
+        let normalized_token_mint_keypair = fixture::read_keypair(fixture::NSOL_MINT_KEYPAIR)?;
+        let normalized_token_mint = normalized_token_mint_keypair.pubkey();
+        let normalized_token_pool_account =
+            find_normalized_token_pool_account_address(&normalized_token_mint);
+        let lamports = ctx
+            .svm_mut()
+            .minimum_balance_for_rent_exemption(spl_token::state::Mint::LEN);
+
+        ctx.send_transaction(
+            &[
+                solana_system_interface::instruction::create_account(
+                    &ctx.payer.pubkey(),
+                    &normalized_token_mint,
+                    lamports,
+                    spl_token::state::Mint::LEN as u64,
+                    &spl_token::ID,
+                ),
+                spl_token::instruction::initialize_mint2(
+                    &spl_token::ID,
+                    &normalized_token_mint,
+                    &ctx.admin.pubkey(),
+                    None,
+                    9,
+                )?,
+                // the mint authority is handed over to the pool.
+                build_instruction(
+                    accounts::AdminNormalizedTokenPoolInitialContext {
+                        payer: ctx.payer.pubkey(),
+                        admin: ctx.admin.pubkey(),
+                        role_account: find_role_account_address(),
+                        system_program: system_program::ID,
+                        normalized_token_program: spl_token::ID,
+                        normalized_token_mint,
+                        normalized_token_pool_account,
+                        event_authority: find_event_authority_address(),
+                        program: fragmetric_sdk::ID,
+                    },
+                    instruction::AdminInitializeNormalizedTokenPoolAccount,
+                    vec![],
+                ),
+            ],
+            &[&normalized_token_mint_keypair, &ctx.admin],
+        )?;
+
+        for supported_token in &self.supported_tokens {
+            ctx.send_transaction(
+                &[
+                    create_associated_token_account_idempotent(
+                        &ctx.payer.pubkey(),
+                        &normalized_token_pool_account,
+                        &supported_token.mint,
+                        &spl_token::ID,
+                    ),
+                    build_instruction(
+                        accounts::FundManagerNormalizedTokenPoolSupportedTokenContext {
+                            fund_manager: ctx.fund_manager.pubkey(),
+                            role_account: find_role_account_address(),
+                            normalized_token_mint,
+                            normalized_token_pool_account,
+                            normalized_token_program: spl_token::ID,
+                            supported_token_mint: supported_token.mint,
+                            supported_token_program: spl_token::ID,
+                            normalized_token_pool_supported_token_account:
+                                get_associated_token_address_with_program_id(
+                                    &normalized_token_pool_account,
+                                    &supported_token.mint,
+                                    &spl_token::ID,
+                                ),
+                            event_authority: find_event_authority_address(),
+                            program: fragmetric_sdk::ID,
+                        },
+                        instruction::FundManagerAddNormalizedTokenPoolSupportedToken {
+                            pricing_source: supported_token.pricing_source.clone(),
+                        },
+                        // pricing sources of the fund cover every supported token of the pool.
+                        get_pricing_source_account_metas(&*fund.fetch_fund_account()?),
+                    ),
+                ],
+                &[&ctx.fund_manager],
+            )?;
+        }
+
+        let mut pricing_sources = get_pricing_source_account_metas(&*fund.fetch_fund_account()?);
+        pricing_sources.push(AccountMeta::new_readonly(
+            normalized_token_pool_account,
+            false,
+        ));
+
+        ctx.send_transaction(
+            &[
+                create_associated_token_account_idempotent(
+                    &ctx.payer.pubkey(),
+                    &fund.addresses.fund_reserve_account,
+                    &normalized_token_mint,
+                    &spl_token::ID,
+                ),
+                build_instruction(
+                    accounts::FundManagerFundNormalizedTokenInitialContext {
+                        fund_manager: ctx.fund_manager.pubkey(),
+                        role_account: find_role_account_address(),
+                        system_program: system_program::ID,
+                        fund_account: fund.addresses.fund_account,
+                        fund_reserve_account: fund.addresses.fund_reserve_account,
+                        receipt_token_mint: fund.addresses.receipt_token_mint,
+                        normalized_token_mint,
+                        fund_normalized_token_reserve_account:
+                            get_associated_token_address_with_program_id(
+                                &fund.addresses.fund_reserve_account,
+                                &normalized_token_mint,
+                                &spl_token::ID,
+                            ),
+                        normalized_token_pool_account,
+                        event_authority: find_event_authority_address(),
+                        program: fragmetric_sdk::ID,
+                    },
+                    instruction::FundManagerInitializeFundNormalizedToken,
+                    pricing_sources,
+                ),
+            ],
+            &[&ctx.fund_manager],
+        )?;
+
+        Ok(normalized_token_mint)
+    }
+
+    /// Adds a Jito vault of the account snapshots, whose delegation admin is handed over to the fund
+    /// by the fund manager as the vault admin.
+    fn add_jito_vault(&self, fund: &TestFund, jito_vault: &JitoVaultFixture) -> anyhow::Result<()> {
+        let ctx = self.ctx;
+        let vault = jito_vault.vault;
+        let vault_receipt_token_mint = jito_vault.vault_receipt_token_mint;
+        let vault_supported_token_mint = jito_vault.vault_supported_token_mint;
+
+        let mut pricing_sources = get_pricing_source_account_metas(&*fund.fetch_fund_account()?);
+        pricing_sources.push(AccountMeta::new_readonly(vault, false));
+
+        let mut instructions = [
+            &fund.addresses.fund_reserve_account,
+            &jito_vault.vault_fee_wallet,
+            &fixture::JITO_VAULT_PROGRAM_FEE_WALLET,
+        ]
+        .into_iter()
+        .map(|owner| {
+            create_associated_token_account_idempotent(
+                &ctx.payer.pubkey(),
+                owner,
+                &vault_receipt_token_mint,
+                &spl_token::ID,
+            )
+        })
+        .collect::<Vec<_>>();
+        instructions.extend([
+            create_associated_token_account_idempotent(
+                &ctx.payer.pubkey(),
+                &vault,
+                &vault_supported_token_mint,
+                &spl_token::ID,
+            ),
+            jito_vault_sdk::sdk::set_secondary_admin(
+                &fixture::JITO_VAULT_PROGRAM_ID,
+                &fixture::JITO_VAULT_CONFIG,
+                &vault,
+                &ctx.fund_manager.pubkey(),
+                &fund.addresses.fund_account,
+                VaultAdminRole::DelegationAdmin,
+            ),
+            build_instruction(
+                accounts::FundManagerFundRestakingVaultInitialContext {
+                    fund_manager: ctx.fund_manager.pubkey(),
+                    role_account: find_role_account_address(),
+                    system_program: system_program::ID,
+                    fund_account: fund.addresses.fund_account,
+                    fund_reserve_account: fund.addresses.fund_reserve_account,
+                    receipt_token_mint: fund.addresses.receipt_token_mint,
+                    vault_account: vault,
+                    vault_receipt_token_mint,
+                    vault_supported_token_mint,
+                    fund_vault_receipt_token_account: get_associated_token_address_with_program_id(
+                        &fund.addresses.fund_reserve_account,
+                        &vault_receipt_token_mint,
+                        &spl_token::ID,
+                    ),
+                    fund_vault_supported_token_account:
+                        get_associated_token_address_with_program_id(
+                            &fund.addresses.fund_reserve_account,
+                            &vault_supported_token_mint,
+                            &spl_token::ID,
+                        ),
+                    vault_vault_supported_token_account:
+                        get_associated_token_address_with_program_id(
+                            &vault,
+                            &vault_supported_token_mint,
+                            &spl_token::ID,
+                        ),
+                    event_authority: find_event_authority_address(),
+                    program: fragmetric_sdk::ID,
+                },
+                instruction::FundManagerInitializeFundRestakingVault {
+                    pricing_source: TokenPricingSource::JitoRestakingVault { address: vault },
+                },
+                pricing_sources,
+            ),
+            fund.build_fund_manager_instruction(
+                instruction::FundManagerUpdateRestakingVaultStrategy {
+                    vault,
+                    sol_allocation_weight: 1,
+                    sol_allocation_capacity_amount: u64::MAX,
+                    reward_commission_rate_bps: 0,
+                    vault_receipt_token_depositable: false,
+                },
+            ),
+        ]);
+
+        ctx.send_transaction(&instructions, &[&ctx.fund_manager])?;
+
+        Ok(())
+    }
+
+    fn add_virtual_vault(&self, fund: &TestFund) -> anyhow::Result<Pubkey> {
+        let ctx = self.ctx;
+        let vault_receipt_token_mint = Pubkey::new_unique();
+        let vault =
+            find_virtual_vault_address(&vault_receipt_token_mint, &fund.addresses.fund_account);
+        ctx.set_mint(&vault_receipt_token_mint, 9, 0)?;
+
+        let mut pricing_sources = get_pricing_source_account_metas(&*fund.fetch_fund_account()?);
+        pricing_sources.push(AccountMeta::new_readonly(vault, false));
+
+        let fund_vault_receipt_token_account = get_associated_token_address_with_program_id(
+            &fund.addresses.fund_reserve_account,
+            &vault_receipt_token_mint,
+            &spl_token::ID,
+        );
+        ctx.send_transaction(
+            &[
+                create_associated_token_account_idempotent(
+                    &ctx.payer.pubkey(),
+                    &fund.addresses.fund_reserve_account,
+                    &vault_receipt_token_mint,
+                    &spl_token::ID,
+                ),
+                create_associated_token_account_idempotent(
+                    &ctx.payer.pubkey(),
+                    &vault,
+                    &vault_receipt_token_mint,
+                    &spl_token::ID,
+                ),
+                build_instruction(
+                    accounts::FundManagerFundRestakingVaultInitialContext {
+                        fund_manager: ctx.fund_manager.pubkey(),
+                        role_account: find_role_account_address(),
+                        system_program: system_program::ID,
+                        fund_account: fund.addresses.fund_account,
+                        fund_reserve_account: fund.addresses.fund_reserve_account,
+                        receipt_token_mint: fund.addresses.receipt_token_mint,
+                        vault_account: vault,
+                        vault_receipt_token_mint,
+                        // a virtual vault supports its own receipt token.
+                        vault_supported_token_mint: vault_receipt_token_mint,
+                        fund_vault_receipt_token_account,
+                        fund_vault_supported_token_account: fund_vault_receipt_token_account,
+                        vault_vault_supported_token_account:
+                            get_associated_token_address_with_program_id(
+                                &vault,
+                                &vault_receipt_token_mint,
+                                &spl_token::ID,
+                            ),
+                        event_authority: find_event_authority_address(),
+                        program: fragmetric_sdk::ID,
+                    },
+                    instruction::FundManagerInitializeFundRestakingVault {
+                        pricing_source: TokenPricingSource::VirtualVault { address: vault },
+                    },
+                    pricing_sources,
+                ),
+                fund.build_fund_manager_instruction(
+                    instruction::FundManagerUpdateRestakingVaultStrategy {
+                        vault,
+                        sol_allocation_weight: 0,
+                        sol_allocation_capacity_amount: u64::MAX,
+                        reward_commission_rate_bps: 0,
+                        vault_receipt_token_depositable: false,
+                    },
+                ),
+            ],
+            &[&ctx.fund_manager],
+        )?;
+
+        Ok(vault)
+    }
+}
+
+/// Sends the instruction until the account reaches the latest version.
+fn update_until(
+    ctx: &TestContext,
+    build_instruction: impl Fn() -> Instruction,
+    signers: &[&Keypair],
+    is_latest_version: impl Fn() -> bool,
+) -> anyhow::Result<()> {
+    for _ in 0..MAX_ACCOUNT_UPDATES {
+        if is_latest_version() {
+            return Ok(());
+        }
+        ctx.send_transaction(&[build_instruction()], signers)?;
+    }
+
+    bail!("account is not updated to the latest version in {MAX_ACCOUNT_UPDATES} transactions")
+}
+
+/// A fund built by [`FundBuilder`], with scenario steps of users and the operator.
+pub struct TestFund<'a> {
+    ctx: &'a TestContext,
+    pub addresses: FundAddresses,
+    pub supported_tokens: Vec<SupportedTokenFixture>,
+    pub normalized_token_mint: Option<Pubkey>,
+    pub restaking_vaults: Vec<Pubkey>,
+}
+
+impl TestFund<'_> {
+    pub fn receipt_token_mint(&self) -> Pubkey {
+        self.addresses.receipt_token_mint
+    }
+
+    pub fn fetch_fund_account(&self) -> anyhow::Result<Box<FundAccount>> {
+        let account = self
+            .ctx
+            .get_account(&self.addresses.fund_account)
+            .ok_or_else(|| anyhow::anyhow!("fund account not found"))?;
+
+        Ok(decode_fund_account(&account.data)?)
+    }
+
+    pub fn fetch_user_fund_account(&self, user: &Pubkey) -> anyhow::Result<UserFundAccount> {
+        let account = self
+            .ctx
+            .get_account(&self.addresses.find_user_fund_account_address(user))
+            .ok_or_else(|| anyhow::anyhow!("user fund account not found: {user}"))?;
+
+        Ok(decode_user_fund_account(&account.data)?)
+    }
+
+    pub fn get_user_receipt_token_amount(&self, user: &Pubkey) -> anyhow::Result<u64> {
+        self.ctx
+            .get_token_amount(&self.addresses.find_user_receipt_token_account_address(user))
+    }
+
+    /// Runs the operation cycle to the end with the keeper.
+    pub fn run_operation_cycle(&self) -> anyhow::Result<Vec<KeeperRun>> {
+        self.ctx
+            .create_keeper(&self.addresses.receipt_token_mint)
+            .run_cycle()
+    }
+
+    /// Creates the receipt token account, fund account and reward account of the user.
+    pub fn create_user_accounts(&self, user: &Keypair) -> anyhow::Result<()> {
+        let ctx = self.ctx;
+        let receipt_token_mint = self.addresses.receipt_token_mint;
+        let user_receipt_token_account = self
+            .addresses
+            .find_user_receipt_token_account_address(&user.pubkey());
+        let user_reward_account = self
+            .addresses
+            .find_user_reward_account_address(&user.pubkey());
+
+        ctx.send_transaction(
+            &[
+                create_associated_token_account_idempotent(
+                    &ctx.payer.pubkey(),
+                    &user.pubkey(),
+                    &receipt_token_mint,
+                    &spl_token_2022::ID,
+                ),
+                build_instruction(
+                    accounts::UserFundAccountInitOrUpdateContext {
+                        user: user.pubkey(),
+                        system_program: system_program::ID,
+                        receipt_token_mint,
+                        receipt_token_program: spl_token_2022::ID,
+                        user_receipt_token_account,
+                        user_fund_account: self
+                            .addresses
+                            .find_user_fund_account_address(&user.pubkey()),
+                        event_authority: find_event_authority_address(),
+                        program: fragmetric_sdk::ID,
+                    },
+                    instruction::UserCreateFundAccountIdempotent {
+                        desired_account_size: None,
+                    },
+                    vec![],
+                ),
+            ],
+            &[user],
+        )?;
+
+        update_until(
+            ctx,
+            || {
+                build_instruction(
+                    accounts::UserRewardAccountInitOrUpdateContext {
+                        user: user.pubkey(),
+                        system_program: system_program::ID,
+                        receipt_token_mint,
+                        receipt_token_program: spl_token_2022::ID,
+                        user_receipt_token_account,
+                        reward_account: self.addresses.reward_account,
+                        user_reward_account,
+                        event_authority: find_event_authority_address(),
+                        program: fragmetric_sdk::ID,
+                    },
+                    instruction::UserCreateRewardAccountIdempotent {
+                        desired_account_size: None,
+                    },
+                    vec![],
+                )
+            },
+            &[user],
+            || {
+                ctx.get_account(&user_reward_account)
+                    .and_then(|account| decode_user_reward_account(&account.data).ok())
+                    .is_some_and(|user_reward_account| user_reward_account.is_latest_version())
+            },
+        )
+    }
+
+    pub fn deposit_sol(&self, user: &Keypair, amount: u64) -> anyhow::Result<TransactionMetadata> {
+        self.ctx.send_transaction(
+            &[build_instruction(
+                accounts::UserFundDepositSolContext {
+                    user: user.pubkey(),
+                    role_account: find_role_account_address(),
+                    system_program: system_program::ID,
+                    receipt_token_program: spl_token_2022::ID,
+                    receipt_token_mint: self.addresses.receipt_token_mint,
+                    receipt_token_lock_account: self.addresses.fund_receipt_token_lock_account,
+                    user_receipt_token_account: self
+                        .addresses
+                        .find_user_receipt_token_account_address(&user.pubkey()),
+                    fund_account: self.addresses.fund_account,
+                    fund_reserve_account: self.addresses.fund_reserve_account,
+                    user_fund_account: self
+                        .addresses
+                        .find_user_fund_account_address(&user.pubkey()),
+                    reward_account: self.addresses.reward_account,
+                    user_reward_account: self
+                        .addresses
+                        .find_user_reward_account_address(&user.pubkey()),
+                    instructions_sysvar: instructions_sysvar::ID,
+                    event_authority: find_event_authority_address(),
+                    program: fragmetric_sdk::ID,
+                },
+                instruction::UserDepositSol {
+                    amount,
+                    metadata: None,
+                    min_receipt_token_amount: None,
+                },
+                get_pricing_source_account_metas(&*self.fetch_fund_account()?),
+            )],
+            &[user],
+        )
+    }
+
+    /// Deposits a supported token which is given to the user in advance.
+    pub fn deposit_supported_token(
+        &self,
+        user: &Keypair,
+        supported_token_mint: &Pubkey,
+        amount: u64,
+    ) -> anyhow::Result<TransactionMetadata> {
+        let user_supported_token_account =
+            self.ctx
+                .set_token_account(supported_token_mint, &user.pubkey(), amount)?;
+
+        self.ctx.send_transaction(
+            &[build_instruction(
+                accounts::UserFundDepositSupportedTokenContext {
+                    user: user.pubkey(),
+                    role_account: find_role_account_address(),
+                    receipt_token_program: spl_token_2022::ID,
+                    supported_token_program: spl_token::ID,
+                    receipt_token_mint: self.addresses.receipt_token_mint,
+                    user_receipt_token_account: self
+                        .addresses
+                        .find_user_receipt_token_account_address(&user.pubkey()),
+                    supported_token_mint: *supported_token_mint,
+                    fund_reserve_account: self.addresses.fund_reserve_account,
+                    fund_supported_token_reserve_account: self
+                        .addresses
+                        .find_fund_supported_token_reserve_account_address(
+                            supported_token_mint,
+                            &spl_token::ID,
+                        ),
+                    user_supported_token_account,
+                    fund_account: self.addresses.fund_account,
+                    user_fund_account: self
+                        .addresses
+                        .find_user_fund_account_address(&user.pubkey()),
+                    reward_account: self.addresses.reward_account,
+                    user_reward_account: self
+                        .addresses
+                        .find_user_reward_account_address(&user.pubkey()),
+                    instructions_sysvar: instructions_sysvar::ID,
+                    event_authority: find_event_authority_address(),
+                    program: fragmetric_sdk::ID,
+                },
+                instruction::UserDepositSupportedToken {
+                    amount,
+                    metadata: None,
+                    min_receipt_token_amount: None,
+                },
+                get_pricing_source_account_metas(&*self.fetch_fund_account()?),
+            )],
+            &[user],
+        )
+    }
+
+    /// Requests withdrawal of SOL and returns the new request.
+    pub fn request_withdrawal(
+        &self,
+        user: &Keypair,
+        receipt_token_amount: u64,
+    ) -> anyhow::Result<WithdrawalRequest> {
+        let fund_account = self.fetch_fund_account()?;
+        let fund_withdrawal_request_account =
+            find_next_withdrawal_request_account_address(&fund_account, None)?;
+        self.ctx.send_transaction(
+            &[build_instruction(
+                accounts::UserFundRequestWithdrawalContext {
+                    user: user.pubkey(),
+                    system_program: system_program::ID,
+                    receipt_token_program: spl_token_2022::ID,
+                    receipt_token_mint: self.addresses.receipt_token_mint,
+                    receipt_token_lock_account: self.addresses.fund_receipt_token_lock_account,
+                    user_receipt_token_account: self
+                        .addresses
+                        .find_user_receipt_token_account_address(&user.pubkey()),
+                    fund_account: self.addresses.fund_account,
+                    fund_reserve_account: self.addresses.fund_reserve_account,
+                    user_fund_account: self
+                        .addresses
+                        .find_user_fund_account_address(&user.pubkey()),
+                    fund_withdrawal_request_account,
+                    reward_account: self.addresses.reward_account,
+                    user_reward_account: self
+                        .addresses
+                        .find_user_reward_account_address(&user.pubkey()),
+                    instructions_sysvar: instructions_sysvar::ID,
+                    event_authority: find_event_authority_address(),
+                    program: fragmetric_sdk::ID,
+                },
+                instruction::UserRequestWithdrawal {
+                    receipt_token_amount,
+                    supported_token_mint: None,
+                    min_asset_amount: None,
+                },
+                get_pricing_source_account_metas(&fund_account),
+            )],
+            &[user],
+        )?;
+
+        let account = self
+            .ctx
+            .get_account(&fund_withdrawal_request_account)
+            .ok_or_else(|| anyhow::anyhow!("withdrawal request not found"))?;
+
+        Ok(decode_fund_withdrawal_request_account(&account.data)?
+            .get_request()
+            .clone())
+    }
+
+    /// Withdraws SOL of a request whose batch has been processed by the operation cycle.
+    pub fn withdraw_sol(
+        &self,
+        user: &Keypair,
+        request: &WithdrawalRequest,
+    ) -> anyhow::Result<TransactionMetadata> {
+        self.ctx.send_transaction(
+            &[build_instruction(
+                accounts::UserFundWithdrawSolContext {
+                    user: user.pubkey(),
+                    system_program: system_program::ID,
+                    receipt_token_program: spl_token_2022::ID,
+                    receipt_token_mint: self.addresses.receipt_token_mint,
+                    user_receipt_token_account: self
+                        .addresses
+                        .find_user_receipt_token_account_address(&user.pubkey()),
+                    fund_account: self.addresses.fund_account,
+                    fund_reserve_account: self.addresses.fund_reserve_account,
+                    fund_withdrawal_batch_account: find_fund_withdrawal_batch_account_address(
+                        &self.addresses.receipt_token_mint,
+                        None,
+                        request.batch_id,
+                    ),
+                    fund_withdrawal_request_account: find_fund_withdrawal_request_account_address(
+                        &self.addresses.receipt_token_mint,
+                        None,
+                        request.request_id,
+                    ),
+                    fund_treasury_account: self.addresses.fund_treasury_account,
+                    user_fund_account: self
+                        .addresses
+                        .find_user_fund_account_address(&user.pubkey()),
+                    reward_account: self.addresses.reward_account,
+                    user_reward_account: self
+                        .addresses
+                        .find_user_reward_account_address(&user.pubkey()),
+                    event_authority: find_event_authority_address(),
+                    program: fragmetric_sdk::ID,
+                },
+                instruction::UserWithdrawSol {
+                    _batch_id: request.batch_id,
+                    _request_id: request.request_id,
+                },
+                vec![],
+            )],
+            &[user],
+        )
+    }
+
+    /// Transfers a withdrawal request of SOL to another user, who withdraws it afterward.
+    pub fn transfer_withdrawal_request(
+        &self,
+        user: &Keypair,
+        request: &WithdrawalRequest,
+        new_user: &Pubkey,
+    ) -> anyhow::Result<TransactionMetadata> {
+        self.ctx.send_transaction(
+            &[build_instruction(
+                accounts::UserFundWithdrawalRequestTransferContext {
+                    user: user.pubkey(),
+                    receipt_token_mint: self.addresses.receipt_token_mint,
+                    user_fund_account: self
+                        .addresses
+                        .find_user_fund_account_address(&user.pubkey()),
+                    fund_withdrawal_request_account: find_fund_withdrawal_request_account_address(
+                        &self.addresses.receipt_token_mint,
+                        None,
+                        request.request_id,
+                    ),
+                    event_authority: find_event_authority_address(),
+                    program: fragmetric_sdk::ID,
+                },
+                instruction::UserTransferWithdrawalRequest {
+                    new_user: *new_user,
+                },
+                vec![],
+            )],
+            &[user],
+        )
+    }
+
+    pub fn update_instant_withdrawal_strategy(
+        &self,
+        enabled: bool,
+        min_fee_rate_bps: u16,
+        max_fee_rate_bps: u16,
+        fee_curve_exponent: u8,
+        liquidity_target_rate_bps: u16,
+    ) -> anyhow::Result<TransactionMetadata> {
+        self.ctx.send_transaction(
+            &[self.build_fund_manager_instruction(
+                instruction::FundManagerUpdateInstantWithdrawalStrategy {
+                    enabled,
+                    min_fee_rate_bps,
+                    max_fee_rate_bps,
+                    fee_curve_exponent,
+                    liquidity_target_rate_bps,
+                },
+            )],
+            &[&self.ctx.fund_manager],
+        )
+    }
+
+    /// Withdraws SOL instantly from the fund reserve.
+    pub fn withdraw_instantly(
+        &self,
+        user: &Keypair,
+        receipt_token_amount: u64,
+    ) -> anyhow::Result<TransactionMetadata> {
+        self.ctx.send_transaction(
+            &[build_instruction(
+                accounts::UserFundInstantWithdrawSolContext {
+                    user: user.pubkey(),
+                    system_program: system_program::ID,
+                    receipt_token_program: spl_token_2022::ID,
+                    receipt_token_mint: self.addresses.receipt_token_mint,
+                    user_receipt_token_account: self
+                        .addresses
+                        .find_user_receipt_token_account_address(&user.pubkey()),
+                    fund_account: self.addresses.fund_account,
+                    fund_reserve_account: self.addresses.fund_reserve_account,
+                    user_fund_account: self
+                        .addresses
+                        .find_user_fund_account_address(&user.pubkey()),
+                    reward_account: self.addresses.reward_account,
+                    user_reward_account: self
+                        .addresses
+                        .find_user_reward_account_address(&user.pubkey()),
+                    event_authority: find_event_authority_address(),
+                    program: fragmetric_sdk::ID,
+                },
+                instruction::UserWithdrawInstantly {
+                    receipt_token_amount,
+                    min_asset_amount: None,
+                },
+                get_pricing_source_account_metas(&*self.fetch_fund_account()?),
+            )],
+            &[user],
+        )
+    }
+
+    pub fn update_in_kind_withdrawal_strategy(
+        &self,
+        enabled: bool,
+        fee_rate_bps: u16,
+    ) -> anyhow::Result<TransactionMetadata> {
+        self.ctx.send_transaction(
+            &[self.build_fund_manager_instruction(
+                instruction::FundManagerUpdateInKindWithdrawalStrategy {
+                    enabled,
+                    fee_rate_bps,
+                },
+            )],
+            &[&self.ctx.fund_manager],
+        )
+    }
+
+    /// Withdraws a pro-rata slice of the assets held by the fund reserve,
+    /// creating the user token accounts of the withdrawn tokens beforehand.
+    pub fn withdraw_in_kind(
+        &self,
+        user: &Keypair,
+        receipt_token_amount: u64,
+        min_asset_amounts: Option<Vec<u64>>,
+    ) -> anyhow::Result<TransactionMetadata> {
+        let fund_account = self.fetch_fund_account()?;
+        let mut instructions = find_in_kind_withdrawal_token_accounts(&fund_account)
+            .into_iter()
+            .map(|(token_mint, token_program, _)| {
+                create_associated_token_account_idempotent(
+                    &self.ctx.payer.pubkey(),
+                    &user.pubkey(),
+                    &token_mint,
+                    &token_program,
+                )
+            })
+            .collect::<Vec<_>>();
+        instructions.push(build_user_withdraw_in_kind_instruction(
+            &user.pubkey(),
+            &fund_account,
+            receipt_token_amount,
+            min_asset_amounts,
+        ));
+
+        self.ctx.send_transaction(&instructions, &[user])
+    }
+
+    /// Donates SOL to the fund by the operator, offsetting the receivable first if requested.
+    pub fn donate_sol(
+        &self,
+        amount: u64,
+        offset_receivable: bool,
+    ) -> anyhow::Result<TransactionMetadata> {
+        self.ctx.send_transaction(
+            &[build_instruction(
+                accounts::OperatorFundDonationContext {
+                    operator: self.ctx.operator.pubkey(),
+                    system_program: system_program::ID,
+                    receipt_token_mint: self.addresses.receipt_token_mint,
+                    fund_account: self.addresses.fund_account,
+                    fund_reserve_account: self.addresses.fund_reserve_account,
+                    event_authority: find_event_authority_address(),
+                    program: fragmetric_sdk::ID,
+                },
+                instruction::OperatorDonateSolToFund {
+                    amount,
+                    offset_receivable,
+                },
+                get_pricing_source_account_metas(&*self.fetch_fund_account()?),
+            )],
+            &[&self.ctx.operator],
+        )
+    }
+
+    pub fn update_operation_fallback_command(
+        &self,
+        command: u8,
+        fallback_command: Option<u8>,
+    ) -> anyhow::Result<TransactionMetadata> {
+        self.ctx.send_transaction(
+            &[self.build_fund_manager_instruction(
+                instruction::FundManagerUpdateOperationFallbackCommand {
+                    command,
+                    fallback_command,
+                },
+            )],
+            &[&self.ctx.fund_manager],
+        )
+    }
+
+    /// Pauses or resumes the Jito vault by its admin, the fund manager.
+    pub fn set_jito_vault_paused(
+        &self,
+        jito_vault: &JitoVaultFixture,
+        is_paused: bool,
+    ) -> anyhow::Result<TransactionMetadata> {
+        self.ctx.send_transaction(
+            &[jito_vault_sdk::sdk::set_is_paused(
+                &fixture::JITO_VAULT_PROGRAM_ID,
+                &fixture::JITO_VAULT_CONFIG,
+                &jito_vault.vault,
+                &self.ctx.fund_manager.pubkey(),
+                is_paused,
+            )],
+            &[&self.ctx.fund_manager],
+        )
+    }
+
+    /// Reconciles the fund by the operator, which disables operation if the reserve accounts fall short.
+    pub fn reconcile(&self) -> anyhow::Result<TransactionMetadata> {
+        let fund_account = self.fetch_fund_account()?;
+        self.ctx.send_transaction(
+            &[build_operator_reconcile_fund_instruction(
+                &self.ctx.operator.pubkey(),
+                &fund_account,
+            )],
+            &[&self.ctx.operator],
+        )
+    }
+
+    /// Quotes a SOL deposit at the prices refreshed by the operator, without committing the refresh.
+    pub fn quote_deposit_sol(&self, amount: u64) -> anyhow::Result<DepositQuote> {
+        let fund_account = self.fetch_fund_account()?;
+        let meta = self.ctx.simulate_transaction(
+            &[
+                build_operator_update_fund_prices_instruction(
+                    &self.ctx.operator.pubkey(),
+                    &fund_account,
+                ),
+                build_quote_deposit_instruction(&fund_account, None, amount),
+            ],
+            &[&self.ctx.operator],
+        )?;
+
+        Ok(DepositQuote::try_from_slice(&meta.return_data.data)?)
+    }
+
+    /// Quotes a withdrawal request of SOL at the prices refreshed by the operator, without committing the refresh.
+    pub fn quote_withdrawal_sol(
+        &self,
+        receipt_token_amount: u64,
+    ) -> anyhow::Result<WithdrawalQuote> {
+        let fund_account = self.fetch_fund_account()?;
+        let meta = self.ctx.simulate_transaction(
+            &[
+                build_operator_update_fund_prices_instruction(
+                    &self.ctx.operator.pubkey(),
+                    &fund_account,
+                ),
+                build_quote_withdrawal_instruction(&fund_account, None, receipt_token_amount),
+            ],
+            &[&self.ctx.operator],
+        )?;
+
+        Ok(WithdrawalQuote::try_from_slice(&meta.return_data.data)?)
+    }
+
+    /// Simulates the reconciliation by the operator,
+    /// and returns the reserve accounts whose balance does not match the bookkeeping.
+    pub fn simulate_reconcile(&self) -> anyhow::Result<Vec<FundReconciliationDiscrepancy>> {
+        /// `OperatorReconciledFund` event emitted by self CPI.
+        #[derive(AnchorDeserialize)]
+        struct OperatorReconciledFund {
+            _receipt_token_mint: Pubkey,
+            _fund_account: Pubkey,
+            _num_reconciled_accounts: u8,
+            discrepancies: Vec<FundReconciliationDiscrepancy>,
+            _operation_disabled: bool,
+        }
+
+        let fund_account = self.fetch_fund_account()?;
+        let meta = self.ctx.simulate_transaction(
+            &[build_operator_reconcile_fund_instruction(
+                &self.ctx.operator.pubkey(),
+                &fund_account,
+            )],
+            &[&self.ctx.operator],
+        )?;
+
+        let discriminator = hash(b"event:OperatorReconciledFund").to_bytes();
+        let event_data = meta
+            .inner_instructions
+            .iter()
+            .flatten()
+            .find_map(|inner_instruction| {
+                inner_instruction
+                    .instruction
+                    .data
+                    .strip_prefix(EVENT_IX_TAG_LE)?
+                    .strip_prefix(&discriminator[..8])
+            })
+            .ok_or_else(|| anyhow::anyhow!("reconciliation event not found"))?;
+
+        Ok(OperatorReconciledFund::try_from_slice(event_data)?.discrepancies)
+    }
+
+    fn build_fund_manager_instruction(&self, args: impl InstructionData) -> Instruction {
+        build_instruction(
+            accounts::FundManagerFundContext {
+                fund_manager: self.ctx.fund_manager.pubkey(),
+                role_account: find_role_account_address(),
+                receipt_token_mint: self.addresses.receipt_token_mint,
+                fund_account: self.addresses.fund_account,
+                event_authority: find_event_authority_address(),
+                program: fragmetric_sdk::ID,
+            },
+            args,
+            vec![],
+        )
+    }
+}
@@ -0,0 +1,32 @@
+//! End-to-end test harness of the Fragmetric programs over an in-process SVM.
+//!
+//! [`TestContext`] loads the restaking and solv programs with the account snapshots of the TypeScript test suites,
+//! and [`FundBuilder`] sets up a fund with supported tokens, a normalized token and restaking vaults,
+//! whose operation cycle is driven by the keeper as on a cluster.
+//!
+//! ```ignore
+//! let ctx = TestContext::new()?;
+//! let fund = FundBuilder::new(&ctx)
+//!     .with_supported_tokens(2)
+//!     .with_nsol_jito_vault()
+//!     .with_virtual_vaults(1)
+//!     .build()?;
+//!
+//! let user = ctx.create_user()?;
+//! fund.create_user_accounts(&user)?;
+//! fund.deposit_sol(&user, 1_000_000_000)?;
+//! fund.run_operation_cycle()?;
+//! ```
+//!
+//! The programs must be built in advance, so the scenarios are ignored by default:
+//! `anchor build && cargo test -p fragmetric-testutil -- --ignored`.
+
+mod context;
+mod fixture;
+mod fund;
+
+pub use context::*;
+pub use fixture::{
+    JitoVaultFixture, SupportedTokenFixture, NSOL_JITO_VAULT_FIXTURE, SUPPORTED_TOKEN_FIXTURES,
+};
+pub use fund::*;
@@ -0,0 +1,224 @@
+use fragmetric_sdk::find_fund_withdrawal_batch_account_address;
+use fragmetric_sdk::modules::fund::{WithdrawalRequest, FUND_WITHDRAWAL_FEE_RATE_BPS_LIMIT};
+use fragmetric_testutil::{FundBuilder, TestContext, TestFund};
+use proptest::prelude::*;
+use solana_sdk::signature::{Keypair, Signer};
+
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+const NUM_USERS: usize = 3;
+
+/// Steps of users and the operator against a fund of the SOL asset only.
+#[derive(Clone, Debug)]
+enum FundAction {
+    Deposit {
+        user_index: usize,
+        amount: u64,
+    },
+    RequestWithdrawal {
+        user_index: usize,
+        receipt_token_amount_bps: u16,
+    },
+    /// withdraws every request whose batch has been processed.
+    WithdrawProcessedRequests,
+    Donate {
+        amount: u64,
+        offset_receivable: bool,
+    },
+    RunOperationCycle,
+    ElapseSeconds(i64),
+}
+
+impl FundAction {
+    fn is_user_action(&self) -> bool {
+        matches!(
+            self,
+            Self::Deposit { .. }
+                | Self::RequestWithdrawal { .. }
+                | Self::WithdrawProcessedRequests
+                | Self::Donate { .. }
+        )
+    }
+}
+
+/// Drives the program with the actions and verifies the accounting of the fund
+/// against the balances of the reserve account, the receipt token lock account and the users.
+struct FundHarness<'a> {
+    ctx: &'a TestContext,
+    fund: TestFund<'a>,
+    users: Vec<Keypair>,
+    requests: Vec<(usize, WithdrawalRequest)>,
+}
+
+impl<'a> FundHarness<'a> {
+    fn new(ctx: &'a TestContext, withdrawal_fee_rate_bps: u16) -> anyhow::Result<Self> {
+        let fund = FundBuilder::new(ctx)
+            .with_withdrawal_fee_rate_bps(withdrawal_fee_rate_bps)
+            .build()?;
+        let users = (0..NUM_USERS)
+            .map(|_| {
+                let user = ctx.create_user()?;
+                fund.create_user_accounts(&user)?;
+                Ok(user)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            ctx,
+            fund,
+            users,
+            requests: Vec::new(),
+        })
+    }
+
+    fn apply(&mut self, action: &FundAction) -> anyhow::Result<()> {
+        match *action {
+            FundAction::Deposit { user_index, amount } => {
+                self.fund.deposit_sol(&self.users[user_index], amount)?;
+            }
+            FundAction::RequestWithdrawal {
+                user_index,
+                receipt_token_amount_bps,
+            } => {
+                let user = &self.users[user_index];
+                let receipt_token_amount =
+                    (self.fund.get_user_receipt_token_amount(&user.pubkey())? as u128
+                        * receipt_token_amount_bps as u128
+                        / 10_000) as u64;
+                if receipt_token_amount > 0 {
+                    let request = self.fund.request_withdrawal(user, receipt_token_amount)?;
+                    self.requests.push((user_index, request));
+                }
+            }
+            FundAction::WithdrawProcessedRequests => {
+                let (processed_requests, requests): (Vec<_>, Vec<_>) =
+                    core::mem::take(&mut self.requests)
+                        .into_iter()
+                        .partition(|(_, request)| self.is_processed(request));
+                self.requests = requests;
+                for (user_index, request) in processed_requests {
+                    self.fund.withdraw_sol(&self.users[user_index], &request)?;
+                }
+            }
+            FundAction::Donate {
+                amount,
+                offset_receivable,
+            } => {
+                self.fund.donate_sol(amount, offset_receivable)?;
+            }
+            FundAction::RunOperationCycle => {
+                self.fund.run_operation_cycle()?;
+            }
+            FundAction::ElapseSeconds(seconds) => self.ctx.warp_clock(seconds),
+        }
+
+        Ok(())
+    }
+
+    /// the withdrawal batch account is created once the batch is processed.
+    fn is_processed(&self, request: &WithdrawalRequest) -> bool {
+        self.ctx
+            .get_account(&find_fund_withdrawal_batch_account_address(
+                &self.fund.addresses.receipt_token_mint,
+                None,
+                request.batch_id,
+            ))
+            .is_some()
+    }
+
+    fn get_receipt_token_supply(&self) -> anyhow::Result<u64> {
+        self.ctx
+            .get_mint_supply(&self.fund.addresses.receipt_token_mint)
+    }
+
+    /// receipt token amount of a deposit of 1,000 SOL, which does not increase unless the receipt token price decreases.
+    fn quote_receipt_token_amount(&self) -> anyhow::Result<u64> {
+        Ok(self
+            .fund
+            .quote_deposit_sol(1_000 * LAMPORTS_PER_SOL)?
+            .receipt_token_amount)
+    }
+
+    fn assert_invariants(&self) -> anyhow::Result<()> {
+        // the reserve account holds the operation reserved and the withdrawal user reserved amount,
+        // so withdrawal_user_reserved_amount is fully backed.
+        assert_eq!(self.fund.simulate_reconcile()?, vec![]);
+
+        // receipt tokens of unprocessed requests are locked, the others are burnt.
+        let receipt_token_lock_amount = self
+            .ctx
+            .get_token_amount(&self.fund.addresses.fund_receipt_token_lock_account)?;
+        assert_eq!(
+            receipt_token_lock_amount,
+            self.requests
+                .iter()
+                .filter(|(_, request)| !self.is_processed(request))
+                .map(|(_, request)| request.receipt_token_amount)
+                .sum::<u64>(),
+        );
+
+        // receipt tokens are held by users or locked for withdrawal requests.
+        let user_receipt_token_amount = self
+            .users
+            .iter()
+            .map(|user| self.fund.get_user_receipt_token_amount(&user.pubkey()))
+            .sum::<anyhow::Result<u64>>()?;
+        assert_eq!(
+            self.get_receipt_token_supply()?,
+            user_receipt_token_amount + receipt_token_lock_amount,
+        );
+
+        Ok(())
+    }
+}
+
+fn fund_action() -> impl Strategy<Value = FundAction> {
+    prop_oneof![
+        3 => (0..NUM_USERS, LAMPORTS_PER_SOL / 1_000..=1_000 * LAMPORTS_PER_SOL)
+            .prop_map(|(user_index, amount)| FundAction::Deposit { user_index, amount }),
+        3 => (0..NUM_USERS, 1..=10_000u16).prop_map(|(user_index, receipt_token_amount_bps)| {
+            FundAction::RequestWithdrawal {
+                user_index,
+                receipt_token_amount_bps,
+            }
+        }),
+        2 => Just(FundAction::WithdrawProcessedRequests),
+        1 => (1..=LAMPORTS_PER_SOL, any::<bool>()).prop_map(|(amount, offset_receivable)| {
+            FundAction::Donate {
+                amount,
+                offset_receivable,
+            }
+        }),
+        2 => Just(FundAction::RunOperationCycle),
+        2 => (1..=86_400i64).prop_map(FundAction::ElapseSeconds),
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    #[ignore = "requires anchor build"]
+    fn test_fund_accounting_invariants_across_operation_cycle(
+        withdrawal_fee_rate_bps in 0..=FUND_WITHDRAWAL_FEE_RATE_BPS_LIMIT,
+        actions in prop::collection::vec(fund_action(), 1..32),
+    ) {
+        let ctx = TestContext::new().unwrap();
+        let mut harness = FundHarness::new(&ctx, withdrawal_fee_rate_bps).unwrap();
+
+        for action in &actions {
+            let old_supply = harness.get_receipt_token_supply().unwrap();
+            let old_receipt_token_amount = harness.quote_receipt_token_amount().unwrap();
+
+            harness.apply(action).unwrap();
+
+            harness.assert_invariants().unwrap();
+            if action.is_user_action() && old_supply > 0 {
+                let receipt_token_amount = harness.quote_receipt_token_amount().unwrap();
+                assert!(
+                    receipt_token_amount <= old_receipt_token_amount,
+                    "receipt token price decreased: {old_receipt_token_amount} -> {receipt_token_amount} per 1,000 SOL",
+                );
+            }
+        }
+    }
+}
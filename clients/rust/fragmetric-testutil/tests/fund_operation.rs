@@ -0,0 +1,327 @@
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token::spl_token;
+use fragmetric_sdk::find_fund_withdrawal_request_account_address;
+use fragmetric_testutil::{FundBuilder, TestContext, NSOL_JITO_VAULT_FIXTURE};
+use solana_sdk::signature::Signer;
+
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+#[test]
+#[ignore = "requires anchor build"]
+fn test_deposit_and_withdraw_sol() -> anyhow::Result<()> {
+    let ctx = TestContext::new()?;
+    let fund = FundBuilder::new(&ctx)
+        .with_withdrawal_fee_rate_bps(10)
+        .build()?;
+
+    let user = ctx.create_user()?;
+    fund.create_user_accounts(&user)?;
+    fund.deposit_sol(&user, 10 * LAMPORTS_PER_SOL)?;
+    assert_eq!(
+        fund.get_user_receipt_token_amount(&user.pubkey())?,
+        10 * LAMPORTS_PER_SOL
+    );
+    assert!(fund.run_operation_cycle()?.last().unwrap().completed);
+
+    let request = fund.request_withdrawal(&user, 5 * LAMPORTS_PER_SOL)?;
+    assert_eq!(
+        fund.get_user_receipt_token_amount(&user.pubkey())?,
+        5 * LAMPORTS_PER_SOL
+    );
+
+    // the withdrawal batch is processed by the next cycle after the threshold.
+    ctx.warp_clock(10);
+    assert!(fund.run_operation_cycle()?.last().unwrap().completed);
+
+    let balance = ctx.get_balance(&user.pubkey());
+    fund.withdraw_sol(&user, &request)?;
+    let withdrawn_amount = ctx.get_balance(&user.pubkey()) - balance;
+    let fee_amount = 5 * LAMPORTS_PER_SOL * 10 / 10_000;
+    assert!(withdrawn_amount.abs_diff(5 * LAMPORTS_PER_SOL - fee_amount) <= 1);
+    // the withdrawal request account is closed with the rent refunded.
+    assert!(ctx
+        .get_account(&find_fund_withdrawal_request_account_address(
+            &fund.addresses.receipt_token_mint,
+            None,
+            request.request_id,
+        ))
+        .is_none());
+
+    Ok(())
+}
+
+#[test]
+#[ignore = "requires anchor build"]
+fn test_quote_equals_executed_amount() -> anyhow::Result<()> {
+    let ctx = TestContext::new()?;
+    let fund = FundBuilder::new(&ctx)
+        .with_withdrawal_fee_rate_bps(10)
+        .build()?;
+
+    let user = ctx.create_user()?;
+    fund.create_user_accounts(&user)?;
+    fund.deposit_sol(&user, 10 * LAMPORTS_PER_SOL)?;
+    // the donation raises the receipt token price above 1 SOL.
+    fund.donate_sol(LAMPORTS_PER_SOL, false)?;
+    assert!(fund.run_operation_cycle()?.last().unwrap().completed);
+
+    let quote = fund.quote_deposit_sol(3 * LAMPORTS_PER_SOL)?;
+    let receipt_token_amount = fund.get_user_receipt_token_amount(&user.pubkey())?;
+    fund.deposit_sol(&user, 3 * LAMPORTS_PER_SOL)?;
+    assert!(quote.receipt_token_amount < 3 * LAMPORTS_PER_SOL);
+    assert_eq!(
+        fund.get_user_receipt_token_amount(&user.pubkey())? - receipt_token_amount,
+        quote.receipt_token_amount,
+    );
+
+    let quote = fund.quote_withdrawal_sol(5 * LAMPORTS_PER_SOL)?;
+    let request = fund.request_withdrawal(&user, 5 * LAMPORTS_PER_SOL)?;
+    assert_eq!(request.receipt_token_amount, quote.receipt_token_amount);
+
+    ctx.warp_clock(10);
+    assert!(fund.run_operation_cycle()?.last().unwrap().completed);
+
+    let balance = ctx.get_balance(&user.pubkey());
+    fund.withdraw_sol(&user, &request)?;
+    let withdrawn_amount = ctx.get_balance(&user.pubkey()) - balance;
+    assert!(withdrawn_amount.abs_diff(quote.asset_user_amount) <= 1);
+
+    Ok(())
+}
+
+#[test]
+#[ignore = "requires anchor build"]
+fn test_transfer_withdrawal_request() -> anyhow::Result<()> {
+    let ctx = TestContext::new()?;
+    let fund = FundBuilder::new(&ctx).build()?;
+
+    let user = ctx.create_user()?;
+    fund.create_user_accounts(&user)?;
+    fund.deposit_sol(&user, 10 * LAMPORTS_PER_SOL)?;
+    assert!(fund.run_operation_cycle()?.last().unwrap().completed);
+
+    let request = fund.request_withdrawal(&user, 5 * LAMPORTS_PER_SOL)?;
+    let holder = ctx.create_user()?;
+    fund.create_user_accounts(&holder)?;
+    fund.transfer_withdrawal_request(&user, &request, &holder.pubkey())?;
+    // the request cannot be transferred again by the previous owner.
+    assert!(fund
+        .transfer_withdrawal_request(&user, &request, &user.pubkey())
+        .is_err());
+
+    ctx.warp_clock(10);
+    assert!(fund.run_operation_cycle()?.last().unwrap().completed);
+
+    // only the holder of the request can withdraw.
+    assert!(fund.withdraw_sol(&user, &request).is_err());
+    let balance = ctx.get_balance(&holder.pubkey());
+    fund.withdraw_sol(&holder, &request)?;
+    let fee_amount = 5 * LAMPORTS_PER_SOL * 10 / 10_000;
+    assert!(ctx.get_balance(&holder.pubkey()) - balance >= 5 * LAMPORTS_PER_SOL - fee_amount - 1);
+
+    Ok(())
+}
+
+#[test]
+#[ignore = "requires anchor build"]
+fn test_withdraw_instantly() -> anyhow::Result<()> {
+    let ctx = TestContext::new()?;
+    let fund = FundBuilder::new(&ctx).build()?;
+
+    let user = ctx.create_user()?;
+    fund.create_user_accounts(&user)?;
+    fund.deposit_sol(&user, 10 * LAMPORTS_PER_SOL)?;
+    assert!(fund.withdraw_instantly(&user, LAMPORTS_PER_SOL).is_err());
+
+    // the min fee rate cannot undercut the 0.1% withdrawal fee rate of the batched withdrawal.
+    assert!(fund
+        .update_instant_withdrawal_strategy(true, 5, 110, 1, 10_000)
+        .is_err());
+
+    // the fee rate rises linearly from 0.1% to 1.1% as the reserve depletes below 100% of the fund.
+    fund.update_instant_withdrawal_strategy(true, 10, 110, 1, 10_000)?;
+
+    let balance = ctx.get_balance(&user.pubkey());
+    fund.withdraw_instantly(&user, 5 * LAMPORTS_PER_SOL)?;
+    let withdrawn_amount = ctx.get_balance(&user.pubkey()) - balance;
+    let fee_amount = 5 * LAMPORTS_PER_SOL * 60 / 10_000;
+    assert!(withdrawn_amount.abs_diff(5 * LAMPORTS_PER_SOL - fee_amount) <= 1);
+    assert_eq!(
+        fund.get_user_receipt_token_amount(&user.pubkey())?,
+        5 * LAMPORTS_PER_SOL
+    );
+
+    Ok(())
+}
+
+#[test]
+#[ignore = "requires anchor build"]
+fn test_withdraw_in_kind() -> anyhow::Result<()> {
+    let ctx = TestContext::new()?;
+    let fund = FundBuilder::new(&ctx).with_supported_tokens(2).build()?;
+
+    let user = ctx.create_user()?;
+    fund.create_user_accounts(&user)?;
+    fund.deposit_sol(&user, 10 * LAMPORTS_PER_SOL)?;
+    for supported_token in &fund.supported_tokens {
+        fund.deposit_supported_token(&user, &supported_token.mint, LAMPORTS_PER_SOL)?;
+    }
+    let receipt_token_amount = fund.get_user_receipt_token_amount(&user.pubkey())?;
+    assert!(fund
+        .withdraw_in_kind(&user, receipt_token_amount / 2, None)
+        .is_err());
+
+    fund.update_in_kind_withdrawal_strategy(true, 10)?;
+
+    // the withdrawal fails if any asset falls short of its minimum amount, or the minimums do not cover every asset.
+    assert!(fund
+        .withdraw_in_kind(
+            &user,
+            receipt_token_amount / 2,
+            Some(vec![5 * LAMPORTS_PER_SOL, 0, 0]),
+        )
+        .is_err());
+    assert!(fund
+        .withdraw_in_kind(&user, receipt_token_amount / 2, Some(vec![0]))
+        .is_err());
+
+    // the user holding the whole supply receives a half of every asset held by the fund reserve.
+    let balance = ctx.get_balance(&user.pubkey());
+    fund.withdraw_in_kind(
+        &user,
+        receipt_token_amount / 2,
+        Some(vec![
+            5 * LAMPORTS_PER_SOL * 99 / 100,
+            LAMPORTS_PER_SOL / 2 * 99 / 100,
+            LAMPORTS_PER_SOL / 2 * 99 / 100,
+        ]),
+    )?;
+    let withdrawn_amount = ctx.get_balance(&user.pubkey()) - balance;
+    let fee_amount = 5 * LAMPORTS_PER_SOL * 10 / 10_000;
+    assert!(withdrawn_amount.abs_diff(5 * LAMPORTS_PER_SOL - fee_amount) <= 10);
+    for supported_token in &fund.supported_tokens {
+        let withdrawn_amount =
+            ctx.get_token_amount(&get_associated_token_address_with_program_id(
+                &user.pubkey(),
+                &supported_token.mint,
+                &spl_token::ID,
+            ))?;
+        let fee_amount = LAMPORTS_PER_SOL / 2 * 10 / 10_000;
+        assert!(withdrawn_amount.abs_diff(LAMPORTS_PER_SOL / 2 - fee_amount) <= 10);
+    }
+    assert_eq!(
+        fund.get_user_receipt_token_amount(&user.pubkey())?,
+        receipt_token_amount - receipt_token_amount / 2
+    );
+
+    Ok(())
+}
+
+#[test]
+#[ignore = "requires anchor build"]
+fn test_operation_cycle_with_supported_tokens_and_vaults() -> anyhow::Result<()> {
+    let ctx = TestContext::new()?;
+    let fund = FundBuilder::new(&ctx)
+        .with_supported_tokens(2)
+        .with_virtual_vaults(1)
+        .build()?;
+    assert_eq!(fund.supported_tokens.len(), 2);
+    assert_eq!(fund.restaking_vaults.len(), 1);
+
+    let user = ctx.create_user()?;
+    fund.create_user_accounts(&user)?;
+    fund.deposit_sol(&user, 10 * LAMPORTS_PER_SOL)?;
+    for supported_token in &fund.supported_tokens {
+        fund.deposit_supported_token(&user, &supported_token.mint, LAMPORTS_PER_SOL)?;
+    }
+    // supported tokens are worth more than SOL.
+    assert!(fund.get_user_receipt_token_amount(&user.pubkey())? > 12 * LAMPORTS_PER_SOL);
+
+    let runs = fund.run_operation_cycle()?;
+    assert!(runs.last().unwrap().completed);
+
+    // the next cycle starts over.
+    ctx.warp_clock(10);
+    assert!(fund.run_operation_cycle()?.last().unwrap().completed);
+
+    // the reserve accounts are backing the bookkeeping.
+    fund.reconcile()?;
+    assert!(fund.fetch_fund_account()?.is_operation_enabled());
+
+    Ok(())
+}
+
+#[test]
+#[ignore = "requires anchor build"]
+fn test_operation_cycle_with_jito_vault() -> anyhow::Result<()> {
+    let ctx = TestContext::new()?;
+    let fund = FundBuilder::new(&ctx)
+        .with_supported_tokens(2)
+        .with_nsol_jito_vault()
+        .build()?;
+    assert_eq!(
+        fund.normalized_token_mint,
+        Some(NSOL_JITO_VAULT_FIXTURE.vault_supported_token_mint)
+    );
+    assert_eq!(fund.restaking_vaults, vec![NSOL_JITO_VAULT_FIXTURE.vault]);
+
+    let user = ctx.create_user()?;
+    fund.create_user_accounts(&user)?;
+    for supported_token in &fund.supported_tokens {
+        fund.deposit_supported_token(&user, &supported_token.mint, 10 * LAMPORTS_PER_SOL)?;
+    }
+    assert!(fund.run_operation_cycle()?.last().unwrap().completed);
+
+    // the supported tokens are normalized into nSOL and restaked to the Jito vault.
+    let fund_vault_receipt_token_amount =
+        ctx.get_token_amount(&get_associated_token_address_with_program_id(
+            &fund.addresses.fund_reserve_account,
+            &NSOL_JITO_VAULT_FIXTURE.vault_receipt_token_mint,
+            &spl_token::ID,
+        ))?;
+    assert!(fund_vault_receipt_token_amount > 0);
+
+    fund.reconcile()?;
+    assert!(fund.fetch_fund_account()?.is_operation_enabled());
+
+    Ok(())
+}
+
+#[test]
+#[ignore = "requires anchor build"]
+fn test_operation_cycle_falls_back_from_paused_jito_vault() -> anyhow::Result<()> {
+    let ctx = TestContext::new()?;
+    let fund = FundBuilder::new(&ctx)
+        .with_supported_tokens(2)
+        .with_nsol_jito_vault()
+        .build()?;
+
+    let user = ctx.create_user()?;
+    fund.create_user_accounts(&user)?;
+    for supported_token in &fund.supported_tokens {
+        fund.deposit_supported_token(&user, &supported_token.mint, 10 * LAMPORTS_PER_SOL)?;
+    }
+    fund.set_jito_vault_paused(&NSOL_JITO_VAULT_FIXTURE, true)?;
+
+    // the pre-flight check of the paused vault fails the cycle without fallback.
+    assert!(fund.run_operation_cycle().is_err());
+
+    // initialize (1) and restake_vst (14) fall back to the following commands.
+    fund.update_operation_fallback_command(1, Some(2))?;
+    fund.update_operation_fallback_command(14, Some(15))?;
+    assert!(fund.run_operation_cycle()?.last().unwrap().completed);
+
+    // nothing is restaked to the paused vault.
+    let fund_vault_receipt_token_amount =
+        ctx.get_token_amount(&get_associated_token_address_with_program_id(
+            &fund.addresses.fund_reserve_account,
+            &NSOL_JITO_VAULT_FIXTURE.vault_receipt_token_mint,
+            &spl_token::ID,
+        ))?;
+    assert_eq!(fund_vault_receipt_token_amount, 0);
+
+    fund.reconcile()?;
+    assert!(fund.fetch_fund_account()?.is_operation_enabled());
+
+    Ok(())
+}
@@ -324,4 +324,100 @@ pub enum ErrorCode {
 
     #[msg("reward: settlement block not found")]
     RewardSettlementBlockNotFoundError,
+
+    #[msg("swap: swapped amount is below the minimum expected amount")]
+    TokenSwapSlippageExceededError,
+
+    #[msg("fund: invalid token swap strategy max slippage")]
+    FundInvalidTokenSwapStrategyMaxSlippageError,
+
+    #[msg("role: signer is not authorized for the role")]
+    RoleUnauthorizedError,
+
+    #[msg("role: invalid role authority")]
+    RoleInvalidAuthorityError,
+
+    #[msg("fund: configuration change must be queued and executed after the timelock")]
+    FundConfigurationChangeTimelockedError,
+
+    #[msg("fund: configuration change is not executable yet")]
+    FundConfigurationChangeNotExecutableYetError,
+
+    #[msg("fund: invalid configuration change")]
+    FundInvalidConfigurationChangeError,
+
+    #[msg("fund: operator is not allowed to operate the fund")]
+    FundOperatorUnauthorizedError,
+
+    #[msg("fund: operator already registered")]
+    FundOperatorAlreadyRegisteredError,
+
+    #[msg("fund: operator not found")]
+    FundOperatorNotFoundError,
+
+    #[msg("fund: exceeded max operators")]
+    FundExceededMaxOperatorsError,
+
+    #[msg("fund: deposit receipt token amount is below the minimum")]
+    FundDepositReceiptTokenAmountBelowMinimumError,
+
+    #[msg("fund: withdrawal asset amount is below the minimum")]
+    FundWithdrawalAssetAmountBelowMinimumError,
+
+    #[msg("fund: invalid management fee rate")]
+    FundInvalidManagementFeeRateError,
+
+    #[msg("fund: exceeded max revenue recipients")]
+    FundExceededMaxRevenueRecipientsError,
+
+    #[msg("fund: invalid revenue split")]
+    FundInvalidRevenueSplitError,
+
+    #[msg("fund: receipt token price history is insufficient for the window")]
+    FundReceiptTokenPriceHistoryInsufficientError,
+
+    #[msg("fund: price deviation circuit breaker is tripped")]
+    FundPriceDeviationCircuitBreakerTrippedError,
+
+    #[msg("fund: token prices are stale")]
+    FundPriceStaleError,
+
+    #[msg("fund: invalid operation fallback command")]
+    FundInvalidOperationFallbackCommandError,
+
+    #[msg("fund: invalid operation history account")]
+    FundInvalidOperationHistoryAccountError,
+
+    #[msg("fund: operation history account not found")]
+    FundOperationHistoryAccountNotFoundError,
+
+    #[msg("fund: invalid operation command policy")]
+    FundInvalidOperationCommandPolicyError,
+
+    #[msg("fund: instant withdrawal is disabled")]
+    FundInstantWithdrawalDisabledError,
+
+    #[msg("fund: instant withdrawal exceeds the reserve above the floor")]
+    FundInstantWithdrawalLiquidityExceededError,
+
+    #[msg("fund: in-kind withdrawal is disabled")]
+    FundInKindWithdrawalDisabledError,
+
+    #[msg("fund: in-kind withdrawal exceeds the assets held by the fund reserve")]
+    FundInKindWithdrawalLiquidityExceededError,
+
+    #[msg("fund: in-kind withdrawal minimum asset amounts do not match the withdrawn assets")]
+    FundInvalidInKindWithdrawalMinAssetAmountsError,
+
+    #[msg("restaking: vault is paused")]
+    RestakingVaultPausedError,
+
+    #[msg("restaking: vault deposit capacity is exceeded")]
+    RestakingVaultDepositCapacityExceededError,
+
+    #[msg("staking: spl stake pool does not permit SOL deposit")]
+    StakingSPLSolDepositNotPermittedError,
+
+    #[msg("fund: configuration change delay requires the guardian to cancel changes")]
+    FundConfigurationChangeGuardianNotSetError,
 }
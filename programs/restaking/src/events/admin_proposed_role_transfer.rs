@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::modules::role::Role;
+
+#[event]
+pub struct AdminProposedRoleTransfer {
+    pub role_account: Pubkey,
+    pub role: Role,
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+}
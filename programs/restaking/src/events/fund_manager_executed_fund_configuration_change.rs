@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::modules::fund::FundConfigurationChange;
+
+#[event]
+pub struct FundManagerExecutedFundConfigurationChange {
+    pub receipt_token_mint: Pubkey,
+    pub fund_account: Pubkey,
+    pub fund_configuration_change_account: Pubkey,
+    pub change_id: u64,
+    pub change: FundConfigurationChange,
+}
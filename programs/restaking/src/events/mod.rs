@@ -1,10 +1,18 @@
+mod admin_proposed_role_transfer;
+mod fund_manager_executed_fund_configuration_change;
+mod fund_manager_queued_fund_configuration_change;
 mod fund_manager_updated_fund;
 mod fund_manager_updated_reward_pool;
+mod guardian_canceled_fund_configuration_change;
+mod operator_claimed_remaining_reward;
 mod operator_donated_to_fund;
 mod operator_ran_fund_command;
+mod operator_reconciled_fund;
+mod operator_tripped_fund_price_circuit_breaker;
 mod operator_updated_fund_prices;
 mod operator_updated_normalized_token_pool_prices;
 mod operator_updated_reward_pools;
+mod role_authority_accepted_role_transfer;
 mod user_canceled_withdrawal_request_from_fund;
 mod user_claimed_reward;
 mod user_closed_fund_account;
@@ -14,20 +22,32 @@ mod user_created_or_updated_reward_account;
 mod user_delegated_reward_account;
 mod user_deposited_to_fund;
 mod user_deposited_to_vault;
+mod user_migrated_fund_withdrawal_requests;
 mod user_requested_withdrawal_from_fund;
+mod user_transferred_fund_withdrawal_request;
 mod user_transferred_receipt_token;
 mod user_unwrapped_receipt_token;
 mod user_updated_reward_pool;
 mod user_withdrew_from_fund;
+mod user_withdrew_in_kind_from_fund;
+mod user_withdrew_instantly_from_fund;
 mod user_wrapped_receipt_token;
 
+pub use admin_proposed_role_transfer::*;
+pub use fund_manager_executed_fund_configuration_change::*;
+pub use fund_manager_queued_fund_configuration_change::*;
 pub use fund_manager_updated_fund::*;
 pub use fund_manager_updated_reward_pool::*;
+pub use guardian_canceled_fund_configuration_change::*;
+pub use operator_claimed_remaining_reward::*;
 pub use operator_donated_to_fund::*;
 pub use operator_ran_fund_command::*;
+pub use operator_reconciled_fund::*;
+pub use operator_tripped_fund_price_circuit_breaker::*;
 pub use operator_updated_fund_prices::*;
 pub use operator_updated_normalized_token_pool_prices::*;
 pub use operator_updated_reward_pools::*;
+pub use role_authority_accepted_role_transfer::*;
 pub use user_canceled_withdrawal_request_from_fund::*;
 pub use user_claimed_reward::*;
 pub use user_closed_fund_account::*;
@@ -37,11 +57,15 @@ pub use user_created_or_updated_reward_account::*;
 pub use user_delegated_reward_account::*;
 pub use user_deposited_to_fund::*;
 pub use user_deposited_to_vault::*;
+pub use user_migrated_fund_withdrawal_requests::*;
 pub use user_requested_withdrawal_from_fund::*;
+pub use user_transferred_fund_withdrawal_request::*;
 pub use user_transferred_receipt_token::*;
 pub use user_unwrapped_receipt_token::*;
 pub use user_updated_reward_pool::*;
 pub use user_withdrew_from_fund::*;
+pub use user_withdrew_in_kind_from_fund::*;
+pub use user_withdrew_instantly_from_fund::*;
 pub use user_wrapped_receipt_token::*;
 
 use anchor_lang::prelude::*;
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::modules::fund::FundRevenueShare;
+
+#[event]
+pub struct OperatorClaimedRemainingReward {
+    pub receipt_token_mint: Pubkey,
+    pub reward_account: Pubkey,
+    pub reward_token_mint: Pubkey,
+    pub revenue_shares: Vec<FundRevenueShare>,
+}
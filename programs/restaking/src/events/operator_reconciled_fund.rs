@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::modules::fund::FundReconciliationDiscrepancy;
+
+#[event]
+pub struct OperatorReconciledFund {
+    pub receipt_token_mint: Pubkey,
+    pub fund_account: Pubkey,
+    pub num_reconciled_accounts: u8,
+    pub discrepancies: Vec<FundReconciliationDiscrepancy>,
+    pub operation_disabled: bool,
+}
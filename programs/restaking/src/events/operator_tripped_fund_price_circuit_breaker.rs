@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use crate::modules::pricing::TokenPricingSource;
+
+#[event]
+pub struct OperatorTrippedFundPriceCircuitBreaker {
+    pub receipt_token_mint: Pubkey,
+    pub fund_account: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_pricing_source: TokenPricingSource,
+    pub previous_one_token_as_sol: u64,
+    pub one_token_as_sol: u64,
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::modules::role::Role;
+
+#[event]
+pub struct RoleAuthorityAcceptedRoleTransfer {
+    pub role_account: Pubkey,
+    pub role: Role,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
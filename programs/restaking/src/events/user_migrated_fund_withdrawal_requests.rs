@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UserMigratedFundWithdrawalRequests {
+    pub receipt_token_mint: Pubkey,
+    pub user: Pubkey,
+    pub user_fund_account: Pubkey,
+    pub fund_withdrawal_request_accounts: Vec<Pubkey>,
+}
@@ -11,6 +11,7 @@ pub struct UserRequestedWithdrawalFromFund {
     pub user_receipt_token_account: Pubkey,
     pub user_fund_account: Pubkey,
 
+    pub fund_withdrawal_request_account: Pubkey,
     pub batch_id: u64,
     pub request_id: u64,
     pub requested_receipt_token_amount: u64,
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UserTransferredFundWithdrawalRequest {
+    pub receipt_token_mint: Pubkey,
+    pub supported_token_mint: Option<Pubkey>,
+
+    pub user: Pubkey,
+    pub user_fund_account: Pubkey,
+    pub new_user: Pubkey,
+
+    pub fund_withdrawal_request_account: Pubkey,
+    pub batch_id: u64,
+    pub request_id: u64,
+    pub requested_receipt_token_amount: u64,
+}
@@ -12,6 +12,7 @@ pub struct UserWithdrewFromFund {
     pub user_supported_token_account: Option<Pubkey>,
 
     pub fund_withdrawal_batch_account: Pubkey,
+    pub fund_withdrawal_request_account: Pubkey,
     pub batch_id: u64,
     pub request_id: u64,
     pub burnt_receipt_token_amount: u64,
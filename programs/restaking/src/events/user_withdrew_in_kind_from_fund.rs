@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::modules::fund::FundInKindWithdrawnAsset;
+
+#[event]
+pub struct UserWithdrewInKindFromFund {
+    pub receipt_token_mint: Pubkey,
+    pub fund_account: Pubkey,
+    pub updated_user_reward_accounts: Vec<Pubkey>,
+
+    pub user: Pubkey,
+    pub user_receipt_token_account: Pubkey,
+    pub user_fund_account: Pubkey,
+
+    pub burnt_receipt_token_amount: u64,
+    /// SOL first, then the tokens in the order of the remaining accounts.
+    pub withdrawn_assets: Vec<FundInKindWithdrawnAsset>,
+}
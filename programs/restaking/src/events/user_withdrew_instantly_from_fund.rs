@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UserWithdrewInstantlyFromFund {
+    pub receipt_token_mint: Pubkey,
+    pub fund_account: Pubkey,
+    pub supported_token_mint: Option<Pubkey>,
+    pub updated_user_reward_accounts: Vec<Pubkey>,
+
+    pub user: Pubkey,
+    pub user_receipt_token_account: Pubkey,
+    pub user_fund_account: Pubkey,
+    pub user_supported_token_account: Option<Pubkey>,
+
+    pub burnt_receipt_token_amount: u64,
+    pub withdrawn_amount: u64,
+    pub deducted_fee_amount: u64,
+}
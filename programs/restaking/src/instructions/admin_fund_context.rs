@@ -3,9 +3,9 @@ use anchor_lang::solana_program;
 use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
-use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::modules::fund::FundAccount;
+use crate::modules::fund::{FundAccount, FundOperationHistoryAccount};
+use crate::modules::role::RoleAccount;
 use crate::utils::{AccountLoaderExt, PDASeeds};
 
 // will be used only once
@@ -15,9 +15,16 @@ pub struct AdminFundAccountInitialContext<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    #[account(address = ADMIN_PUBKEY)]
+    #[account(address = role_account.admin @ ErrorCode::RoleUnauthorizedError)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub system_program: Program<'info, System>,
 
     /// Mint authority must be admin or fund account,
@@ -71,9 +78,16 @@ pub struct AdminFundAccountUpdateContext<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    #[account(address = ADMIN_PUBKEY)]
+    #[account(address = role_account.admin @ ErrorCode::RoleUnauthorizedError)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub system_program: Program<'info, System>,
 
     pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
@@ -89,9 +103,16 @@ pub struct AdminFundAccountUpdateContext<'info> {
 #[event_cpi]
 #[derive(Accounts)]
 pub struct AdminFundContext<'info> {
-    #[account(address = ADMIN_PUBKEY)]
+    #[account(address = role_account.admin @ ErrorCode::RoleUnauthorizedError)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     #[account(
         mut,
         seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
@@ -103,3 +124,42 @@ pub struct AdminFundContext<'info> {
 
     pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
 }
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(page_index: u64)]
+pub struct AdminFundOperationHistoryContext<'info> {
+    #[account(
+        mut,
+        address = role_account.admin @ ErrorCode::RoleUnauthorizedError,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
+    pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = fund_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = fund_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_account: AccountLoader<'info, FundAccount>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [FundOperationHistoryAccount::SEED, receipt_token_mint.key().as_ref(), &page_index.to_le_bytes()],
+        bump = fund_operation_history_account.load()?.get_bump(),
+        has_one = receipt_token_mint,
+        constraint = fund_operation_history_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_operation_history_account: AccountLoader<'info, FundOperationHistoryAccount>,
+}
@@ -2,8 +2,9 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::Token;
 use anchor_spl::token_interface::Mint;
 
-use crate::constants::*;
+use crate::errors::ErrorCode;
 use crate::modules::normalization::NormalizedTokenPoolAccount;
+use crate::modules::role::RoleAccount;
 use crate::utils::PDASeeds;
 
 #[event_cpi]
@@ -12,9 +13,16 @@ pub struct AdminNormalizedTokenPoolInitialContext<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    #[account(address = ADMIN_PUBKEY)]
+    #[account(address = role_account.admin @ ErrorCode::RoleUnauthorizedError)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub system_program: Program<'info, System>,
 
     pub normalized_token_program: Program<'info, Token>,
@@ -42,9 +50,16 @@ pub struct AdminNormalizedTokenPoolUpdateContext<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    #[account(address = ADMIN_PUBKEY)]
+    #[account(address = role_account.admin @ ErrorCode::RoleUnauthorizedError)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub system_program: Program<'info, System>,
 
     pub normalized_token_program: Program<'info, Token>,
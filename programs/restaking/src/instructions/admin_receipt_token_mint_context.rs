@@ -2,8 +2,10 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 use spl_tlv_account_resolution::state::ExtraAccountMetaList;
 
-use crate::constants::*;
+use crate::errors::ErrorCode;
 use crate::modules::fund::*;
+use crate::modules::role::RoleAccount;
+use crate::utils::PDASeeds;
 
 // will be used only once
 #[event_cpi]
@@ -12,9 +14,16 @@ pub struct AdminReceiptTokenMintExtraAccountMetaListInitialContext<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    #[account(address = ADMIN_PUBKEY)]
+    #[account(address = role_account.admin @ ErrorCode::RoleUnauthorizedError)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub system_program: Program<'info, System>,
 
     pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
@@ -35,9 +44,16 @@ pub struct AdminReceiptTokenMintExtraAccountMetaListInitialContext<'info> {
 #[event_cpi]
 #[derive(Accounts)]
 pub struct AdminReceiptTokenMintExtraAccountMetaListUpdateContext<'info> {
-    #[account(address = ADMIN_PUBKEY)]
+    #[account(address = role_account.admin @ ErrorCode::RoleUnauthorizedError)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// CHECK: ExtraAccountMetaList Account, must use these seeds
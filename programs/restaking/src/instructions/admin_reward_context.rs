@@ -2,8 +2,9 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
 use anchor_spl::token_interface::Mint;
 
-use crate::constants::*;
+use crate::errors::ErrorCode;
 use crate::modules::reward::*;
+use crate::modules::role::RoleAccount;
 use crate::utils::{AccountLoaderExt, PDASeeds};
 
 // will be used only once
@@ -13,9 +14,16 @@ pub struct AdminRewardAccountInitialContext<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    #[account(address = ADMIN_PUBKEY)]
+    #[account(address = role_account.admin @ ErrorCode::RoleUnauthorizedError)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub system_program: Program<'info, System>,
 
     pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
@@ -39,9 +47,16 @@ pub struct AdminRewardAccountUpdateContext<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    #[account(address = ADMIN_PUBKEY)]
+    #[account(address = role_account.admin @ ErrorCode::RoleUnauthorizedError)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub system_program: Program<'info, System>,
 
     pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
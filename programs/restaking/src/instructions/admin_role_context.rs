@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::modules::role::RoleAccount;
+use crate::utils::PDASeeds;
+
+// will be used only once, the compiled admin key bootstraps the role account.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AdminRoleAccountInitialContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(address = ADMIN_PUBKEY)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RoleAccount::INIT_SPACE,
+        seeds = [RoleAccount::SEED],
+        bump,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AdminRoleContext<'info> {
+    #[account(address = role_account.admin @ ErrorCode::RoleUnauthorizedError)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+}
@@ -2,9 +2,9 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
-use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::modules::reward::{RewardAccount, UserRewardAccount};
+use crate::modules::role::RoleAccount;
 use crate::utils::{AccountLoaderExt, PDASeeds};
 
 #[event_cpi]
@@ -13,9 +13,16 @@ pub struct AdminUserRewardAccountInitOrUpdateContext<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    #[account(address = ADMIN_PUBKEY)]
+    #[account(address = role_account.admin @ ErrorCode::RoleUnauthorizedError)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     /// CHECK: Third party account or someone else which could be pda or wallet or token account, etc.
     pub user: UncheckedAccount<'info>,
 
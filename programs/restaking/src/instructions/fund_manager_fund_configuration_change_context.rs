@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::ErrorCode;
+use crate::modules::fund::{FundAccount, FundConfigurationChangeAccount};
+use crate::modules::role::RoleAccount;
+use crate::utils::{AccountLoaderExt, PDASeeds};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(change_id: u64)]
+pub struct FundManagerFundConfigurationChangeInitialContext<'info> {
+    #[account(
+        mut,
+        address = role_account.fund_manager @ ErrorCode::RoleUnauthorizedError,
+    )]
+    pub fund_manager: Signer<'info>,
+
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = fund_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = fund_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_account: AccountLoader<'info, FundAccount>,
+
+    /// The change id must be the next configuration change id of the fund account.
+    #[account(
+        init,
+        payer = fund_manager,
+        space = 8 + FundConfigurationChangeAccount::INIT_SPACE,
+        seeds = [FundConfigurationChangeAccount::SEED, receipt_token_mint.key().as_ref(), &change_id.to_le_bytes()],
+        bump,
+    )]
+    pub fund_configuration_change_account: Box<Account<'info, FundConfigurationChangeAccount>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(change_id: u64)]
+pub struct FundManagerFundConfigurationChangeContext<'info> {
+    #[account(
+        mut,
+        address = role_account.fund_manager @ ErrorCode::RoleUnauthorizedError,
+    )]
+    pub fund_manager: Signer<'info>,
+
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
+    pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = fund_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = fund_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_account: AccountLoader<'info, FundAccount>,
+
+    #[account(
+        mut,
+        close = fund_manager,
+        seeds = [FundConfigurationChangeAccount::SEED, receipt_token_mint.key().as_ref(), &change_id.to_le_bytes()],
+        bump = fund_configuration_change_account.get_bump(),
+        has_one = receipt_token_mint,
+        constraint = fund_configuration_change_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_configuration_change_account: Box<Account<'info, FundConfigurationChangeAccount>>,
+}
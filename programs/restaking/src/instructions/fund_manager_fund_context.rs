@@ -1,17 +1,24 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 
-use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::modules::fund::FundAccount;
+use crate::modules::role::RoleAccount;
 use crate::utils::{AccountLoaderExt, PDASeeds};
 
 #[event_cpi]
 #[derive(Accounts)]
 pub struct FundManagerFundContext<'info> {
-    #[account(address = FUND_MANAGER_PUBKEY)]
+    #[account(address = role_account.fund_manager @ ErrorCode::RoleUnauthorizedError)]
     pub fund_manager: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -2,17 +2,24 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::Token;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
-use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::modules::fund::FundAccount;
+use crate::modules::role::RoleAccount;
 use crate::utils::{AccountLoaderExt, PDASeeds};
 
 #[event_cpi]
 #[derive(Accounts)]
 pub struct FundManagerFundRestakingVaultInitialContext<'info> {
-    #[account(address = FUND_MANAGER_PUBKEY)]
+    #[account(address = role_account.fund_manager @ ErrorCode::RoleUnauthorizedError)]
     pub fund_manager: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub system_program: Program<'info, System>,
 
     #[account(
@@ -64,9 +71,16 @@ pub struct FundManagerFundRestakingVaultInitialContext<'info> {
 #[event_cpi]
 #[derive(Accounts)]
 pub struct FundManagerFundRestakingVaultDelegationInitialContext<'info> {
-    #[account(address = FUND_MANAGER_PUBKEY)]
+    #[account(address = role_account.fund_manager @ ErrorCode::RoleUnauthorizedError)]
     pub fund_manager: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     #[account(
         mut,
         seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
@@ -91,9 +105,16 @@ pub struct FundManagerFundRestakingVaultDelegationInitialContext<'info> {
 #[event_cpi]
 #[derive(Accounts)]
 pub struct FundManagerFundRestakingVaultRewardContext<'info> {
-    #[account(address = FUND_MANAGER_PUBKEY)]
+    #[account(address = role_account.fund_manager @ ErrorCode::RoleUnauthorizedError)]
     pub fund_manager: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
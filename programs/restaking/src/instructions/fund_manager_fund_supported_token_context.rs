@@ -1,18 +1,25 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::modules::fund::*;
 use crate::modules::normalization::NormalizedTokenPoolAccount;
+use crate::modules::role::RoleAccount;
 use crate::utils::{AccountLoaderExt, PDASeeds};
 
 #[event_cpi]
 #[derive(Accounts)]
 pub struct FundManagerFundSupportedTokenContext<'info> {
-    #[account(address = FUND_MANAGER_PUBKEY)]
+    #[account(address = role_account.fund_manager @ ErrorCode::RoleUnauthorizedError)]
     pub fund_manager: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -58,9 +65,16 @@ pub struct FundManagerFundSupportedTokenContext<'info> {
 #[event_cpi]
 #[derive(Accounts)]
 pub struct FundManagerFundSupportedTokenRemoveContext<'info> {
-    #[account(address = FUND_MANAGER_PUBKEY)]
+    #[account(address = role_account.fund_manager @ ErrorCode::RoleUnauthorizedError)]
     pub fund_manager: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
@@ -2,17 +2,24 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::Token;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::modules::normalization::NormalizedTokenPoolAccount;
+use crate::modules::role::RoleAccount;
 use crate::utils::PDASeeds;
 
 #[event_cpi]
 #[derive(Accounts)]
 pub struct FundManagerNormalizedTokenPoolSupportedTokenContext<'info> {
-    #[account(address = FUND_MANAGER_PUBKEY)]
+    #[account(address = role_account.fund_manager @ ErrorCode::RoleUnauthorizedError)]
     pub fund_manager: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     #[account(mint::token_program = normalized_token_program)]
     pub normalized_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::ErrorCode;
+use crate::modules::fund::FundAccount;
+use crate::utils::{AccountLoaderExt, PDASeeds};
+
+/// Read-only context for quotes, anyone can simulate or CPI with pricing sources as remaining accounts.
+#[derive(Accounts)]
+pub struct FundQuoteContext<'info> {
+    pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = fund_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = fund_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_account: AccountLoader<'info, FundAccount>,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::ErrorCode;
+use crate::modules::fund::{FundAccount, FundConfigurationChangeAccount};
+use crate::modules::role::RoleAccount;
+use crate::utils::{AccountLoaderExt, PDASeeds};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(change_id: u64)]
+pub struct GuardianFundConfigurationChangeContext<'info> {
+    #[account(address = role_account.guardian @ ErrorCode::RoleUnauthorizedError)]
+    pub guardian: Signer<'info>,
+
+    /// rent of the canceled change goes back to its payer, even if the fund manager has changed since.
+    #[account(mut)]
+    pub payer: SystemAccount<'info>,
+
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
+    pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = fund_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = fund_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_account: AccountLoader<'info, FundAccount>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [FundConfigurationChangeAccount::SEED, receipt_token_mint.key().as_ref(), &change_id.to_le_bytes()],
+        bump = fund_configuration_change_account.get_bump(),
+        has_one = receipt_token_mint,
+        has_one = payer,
+    )]
+    pub fund_configuration_change_account: Box<Account<'info, FundConfigurationChangeAccount>>,
+}
@@ -2,8 +2,10 @@ mod admin_fund_context;
 mod admin_normalized_token_pool_context;
 mod admin_receipt_token_mint_context;
 mod admin_reward_context;
+mod admin_role_context;
 mod admin_user_reward_context;
 
+mod fund_manager_fund_configuration_change_context;
 mod fund_manager_fund_context;
 mod fund_manager_fund_normalized_token_context;
 mod fund_manager_fund_restaking_vault_context;
@@ -28,12 +30,20 @@ mod user_reward_context;
 
 mod slasher_normalized_token_context;
 
+mod role_authority_role_context;
+
+mod guardian_fund_configuration_change_context;
+
+mod fund_quote_context;
+
 pub use admin_fund_context::*;
 pub use admin_normalized_token_pool_context::*;
 pub use admin_receipt_token_mint_context::*;
 pub use admin_reward_context::*;
+pub use admin_role_context::*;
 pub use admin_user_reward_context::*;
 
+pub use fund_manager_fund_configuration_change_context::*;
 pub use fund_manager_fund_context::*;
 pub use fund_manager_fund_normalized_token_context::*;
 pub use fund_manager_fund_restaking_vault_context::*;
@@ -57,3 +67,9 @@ pub use user_receipt_token_transfer_context::*;
 pub use user_reward_context::*;
 
 pub use slasher_normalized_token_context::*;
+
+pub use role_authority_role_context::*;
+
+pub use guardian_fund_configuration_change_context::*;
+
+pub use fund_quote_context::*;
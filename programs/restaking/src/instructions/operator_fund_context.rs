@@ -2,15 +2,26 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::ErrorCode;
-use crate::modules::fund::FundAccount;
+use crate::modules::fund::{FundAccount, FundOperationHistoryAccount};
+use crate::modules::role::RoleAccount;
 use crate::utils::{AccountLoaderExt, PDASeeds};
 
 #[event_cpi]
 #[derive(Accounts)]
 pub struct OperatorFundContext<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = fund_account.load()?.is_operator_authorized(operator.key) @ ErrorCode::FundOperatorUnauthorizedError,
+    )]
     pub operator: Signer<'info>,
 
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub system_program: Program<'info, System>,
 
     #[account(mut)]
@@ -26,6 +37,40 @@ pub struct OperatorFundContext<'info> {
     pub fund_account: AccountLoader<'info, FundAccount>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(page_index: u64)]
+pub struct OperatorFundOperationHistoryInitialContext<'info> {
+    #[account(
+        mut,
+        constraint = fund_account.load()?.is_operator_authorized(operator.key) @ ErrorCode::FundOperatorUnauthorizedError,
+    )]
+    pub operator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = fund_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = fund_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_account: AccountLoader<'info, FundAccount>,
+
+    /// The page index must be the next page index of the fund account.
+    #[account(
+        init,
+        payer = operator,
+        space = 8 + core::mem::size_of::<FundOperationHistoryAccount>(),
+        seeds = [FundOperationHistoryAccount::SEED, receipt_token_mint.key().as_ref(), &page_index.to_le_bytes()],
+        bump,
+    )]
+    pub fund_operation_history_account: AccountLoader<'info, FundOperationHistoryAccount>,
+}
+
 #[event_cpi]
 #[derive(Accounts)]
 pub struct OperatorFundDonationContext<'info> {
@@ -95,3 +140,35 @@ pub struct OperatorFundSupportedTokenDonationContext<'info> {
     )]
     pub fund_reserve_account: SystemAccount<'info>,
 }
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct OperatorFundReconciliationContext<'info> {
+    #[account(
+        constraint = fund_account.load()?.is_operator_authorized(operator.key) @ ErrorCode::FundOperatorUnauthorizedError,
+    )]
+    pub operator: Signer<'info>,
+
+    pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = fund_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = fund_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_account: AccountLoader<'info, FundAccount>,
+
+    #[account(
+        seeds = [FundAccount::RESERVE_SEED, receipt_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub fund_reserve_account: SystemAccount<'info>,
+
+    #[account(
+        seeds = [FundAccount::TREASURY_SEED, receipt_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub fund_treasury_account: SystemAccount<'info>,
+}
@@ -1,20 +1,31 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::constants::PROGRAM_REVENUE_ADDRESS;
 use crate::errors::ErrorCode;
+use crate::modules::fund::FundAccount;
 use crate::modules::reward::*;
 use crate::utils::{AccountLoaderExt, PDASeeds};
 
 #[event_cpi]
 #[derive(Accounts)]
 pub struct OperatorRewardContext<'info> {
+    #[account(
+        constraint = fund_account.load()?.is_operator_authorized(operator.key) @ ErrorCode::FundOperatorUnauthorizedError,
+    )]
     pub operator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 
     pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    #[account(
+        seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = fund_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = fund_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_account: AccountLoader<'info, FundAccount>,
+
     #[account(
         mut,
         seeds = [RewardAccount::SEED, receipt_token_mint.key().as_ref()],
@@ -32,6 +43,14 @@ pub struct OperatorRewardClaimContext<'info> {
 
     pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    #[account(
+        seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = fund_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = fund_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_account: AccountLoader<'info, FundAccount>,
+
     #[account(
         mut,
         seeds = [RewardAccount::SEED, receipt_token_mint.key().as_ref()],
@@ -47,10 +66,6 @@ pub struct OperatorRewardClaimContext<'info> {
     )]
     pub reward_reserve_account: SystemAccount<'info>,
 
-    /// CHECK: program revenue wallet
-    #[account(address = PROGRAM_REVENUE_ADDRESS)]
-    pub program_revenue_account: UncheckedAccount<'info>,
-
     pub reward_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     pub reward_token_program: Interface<'info, TokenInterface>,
@@ -62,12 +77,4 @@ pub struct OperatorRewardClaimContext<'info> {
         associated_token::token_program = reward_token_program,
     )]
     pub reward_token_reserve_account: Box<InterfaceAccount<'info, TokenAccount>>,
-
-    #[account(
-        mut,
-        associated_token::mint = reward_token_mint,
-        associated_token::authority = program_revenue_account,
-        associated_token::token_program = reward_token_program,
-    )]
-    pub program_reward_token_revenue_account: Box<InterfaceAccount<'info, TokenAccount>>,
 }
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::modules::role::RoleAccount;
+use crate::utils::PDASeeds;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RoleAuthorityRoleContext<'info> {
+    /// must be the pending authority of the role, which will be validated by role service
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+}
@@ -4,6 +4,7 @@ use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::errors::ErrorCode;
+use crate::modules::role::RoleAccount;
 use crate::modules::{fund::*, reward::*};
 use crate::utils::{AccountLoaderExt, PDASeeds};
 
@@ -90,6 +91,11 @@ pub struct UserFundRequestWithdrawalContext<'info> {
     )]
     pub user_fund_account: Box<Account<'info, UserFundAccount>>,
 
+    /// CHECK: A withdrawal request account to create on request, or to close on cancellation,
+    /// whose address is derived from the request id and validated by the service.
+    #[account(mut)]
+    pub fund_withdrawal_request_account: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [RewardAccount::SEED, receipt_token_mint.key().as_ref()],
@@ -117,6 +123,14 @@ pub struct UserFundRequestWithdrawalContext<'info> {
 pub struct UserFundDepositSolContext<'info> {
     pub user: Signer<'info>,
 
+    /// admin signs the deposit metadata.
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub system_program: Program<'info, System>,
 
     pub receipt_token_program: Program<'info, Token2022>,
@@ -188,7 +202,7 @@ pub struct UserFundDepositSolContext<'info> {
 
 #[event_cpi]
 #[derive(Accounts)]
-#[instruction(batch_id: u64)]
+#[instruction(batch_id: u64, request_id: u64)]
 pub struct UserFundWithdrawSolContext<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
@@ -223,7 +237,7 @@ pub struct UserFundWithdrawSolContext<'info> {
     pub fund_reserve_account: SystemAccount<'info>,
 
     /// Users can derive proper account address with target batch id for each withdrawal requests.
-    /// And the batch id can be read from a user fund account which the withdrawal requests belong to.
+    /// And the batch id can be read from the withdrawal request account.
     #[account(
         mut,
         seeds = [FundWithdrawalBatchAccount::SEED, receipt_token_mint.key().as_ref(), Pubkey::default().as_ref(), &batch_id.to_le_bytes()],
@@ -232,6 +246,15 @@ pub struct UserFundWithdrawSolContext<'info> {
     )]
     pub fund_withdrawal_batch_account: Box<Account<'info, FundWithdrawalBatchAccount>>,
 
+    #[account(
+        mut,
+        seeds = [FundWithdrawalRequestAccount::SEED, receipt_token_mint.key().as_ref(), Pubkey::default().as_ref(), &request_id.to_le_bytes()],
+        bump = fund_withdrawal_request_account.get_bump(),
+        has_one = receipt_token_mint,
+        has_one = user,
+    )]
+    pub fund_withdrawal_request_account: Box<Account<'info, FundWithdrawalRequestAccount>>,
+
     #[account(
         mut,
         seeds = [FundAccount::TREASURY_SEED, receipt_token_mint.key().as_ref()],
@@ -283,3 +306,179 @@ pub struct UserFundAccountCloseContext<'info> {
     )]
     pub user_fund_account: Box<Account<'info, UserFundAccount>>,
 }
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UserFundWithdrawalRequestMigrationContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [UserFundAccount::SEED, receipt_token_mint.key().as_ref(), user.key().as_ref()],
+        bump = user_fund_account.get_bump(),
+        has_one = receipt_token_mint,
+        has_one = user,
+        constraint = user_fund_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub user_fund_account: Box<Account<'info, UserFundAccount>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UserFundWithdrawalRequestTransferContext<'info> {
+    pub user: Signer<'info>,
+
+    pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [UserFundAccount::SEED, receipt_token_mint.key().as_ref(), user.key().as_ref()],
+        bump = user_fund_account.get_bump(),
+        has_one = receipt_token_mint,
+        has_one = user,
+        constraint = user_fund_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub user_fund_account: Box<Account<'info, UserFundAccount>>,
+
+    #[account(
+        mut,
+        has_one = receipt_token_mint,
+        has_one = user,
+    )]
+    pub fund_withdrawal_request_account: Box<Account<'info, FundWithdrawalRequestAccount>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UserFundInstantWithdrawSolContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub receipt_token_program: Program<'info, Token2022>,
+
+    #[account(mut)]
+    pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = receipt_token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = receipt_token_program,
+    )]
+    pub user_receipt_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = fund_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = fund_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_account: AccountLoader<'info, FundAccount>,
+
+    #[account(
+        mut,
+        seeds = [FundAccount::RESERVE_SEED, receipt_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub fund_reserve_account: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [UserFundAccount::SEED, receipt_token_mint.key().as_ref(), user.key().as_ref()],
+        bump = user_fund_account.get_bump(),
+        has_one = receipt_token_mint,
+        has_one = user,
+        constraint = user_fund_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub user_fund_account: Box<Account<'info, UserFundAccount>>,
+
+    #[account(
+        mut,
+        seeds = [RewardAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = reward_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = reward_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub reward_account: AccountLoader<'info, RewardAccount>,
+
+    /// CHECK: user might not have reward account...
+    #[account(
+        mut,
+        seeds = [UserRewardAccount::SEED, receipt_token_mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_reward_account: UncheckedAccount<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UserFundInKindWithdrawContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub receipt_token_program: Program<'info, Token2022>,
+
+    #[account(mut)]
+    pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = receipt_token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = receipt_token_program,
+    )]
+    pub user_receipt_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = fund_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = fund_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_account: AccountLoader<'info, FundAccount>,
+
+    #[account(
+        mut,
+        seeds = [FundAccount::RESERVE_SEED, receipt_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub fund_reserve_account: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [UserFundAccount::SEED, receipt_token_mint.key().as_ref(), user.key().as_ref()],
+        bump = user_fund_account.get_bump(),
+        has_one = receipt_token_mint,
+        has_one = user,
+        constraint = user_fund_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub user_fund_account: Box<Account<'info, UserFundAccount>>,
+
+    #[account(
+        mut,
+        seeds = [RewardAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = reward_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = reward_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub reward_account: AccountLoader<'info, RewardAccount>,
+
+    /// CHECK: user might not have reward account...
+    #[account(
+        mut,
+        seeds = [UserRewardAccount::SEED, receipt_token_mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_reward_account: UncheckedAccount<'info>,
+}
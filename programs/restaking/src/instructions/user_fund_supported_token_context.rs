@@ -3,6 +3,7 @@ use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::ErrorCode;
+use crate::modules::role::RoleAccount;
 use crate::modules::{fund::*, reward::*};
 use crate::utils::{AccountLoaderExt, PDASeeds};
 
@@ -11,6 +12,14 @@ use crate::utils::{AccountLoaderExt, PDASeeds};
 pub struct UserFundDepositSupportedTokenContext<'info> {
     pub user: Signer<'info>,
 
+    /// admin signs the deposit metadata.
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub receipt_token_program: Program<'info, Token2022>,
 
     pub supported_token_program: Interface<'info, TokenInterface>,
@@ -91,8 +100,9 @@ pub struct UserFundDepositSupportedTokenContext<'info> {
 
 #[event_cpi]
 #[derive(Accounts)]
-#[instruction(batch_id: u64)]
+#[instruction(batch_id: u64, request_id: u64)]
 pub struct UserFundWithdrawSupportedTokenContext<'info> {
+    #[account(mut)]
     pub user: Signer<'info>,
 
     pub system_program: Program<'info, System>,
@@ -136,7 +146,7 @@ pub struct UserFundWithdrawSupportedTokenContext<'info> {
     pub fund_reserve_account: SystemAccount<'info>,
 
     /// Users can derive proper account address with target batch id for each withdrawal requests.
-    /// And the batch id can be read from a user fund account which the withdrawal requests belong to.
+    /// And the batch id can be read from the withdrawal request account.
     #[account(
         mut,
         seeds = [FundWithdrawalBatchAccount::SEED, receipt_token_mint.key().as_ref(), supported_token_mint.key().as_ref(), &batch_id.to_le_bytes()],
@@ -145,6 +155,15 @@ pub struct UserFundWithdrawSupportedTokenContext<'info> {
     )]
     pub fund_withdrawal_batch_account: Box<Account<'info, FundWithdrawalBatchAccount>>,
 
+    #[account(
+        mut,
+        seeds = [FundWithdrawalRequestAccount::SEED, receipt_token_mint.key().as_ref(), supported_token_mint.key().as_ref(), &request_id.to_le_bytes()],
+        bump = fund_withdrawal_request_account.get_bump(),
+        has_one = receipt_token_mint,
+        has_one = user,
+    )]
+    pub fund_withdrawal_request_account: Box<Account<'info, FundWithdrawalRequestAccount>>,
+
     #[account(
         mut,
         associated_token::mint = supported_token_mint,
@@ -189,3 +208,86 @@ pub struct UserFundWithdrawSupportedTokenContext<'info> {
     #[account(address = instructions_sysvar::ID)]
     pub instructions_sysvar: UncheckedAccount<'info>,
 }
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UserFundInstantWithdrawSupportedTokenContext<'info> {
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub receipt_token_program: Program<'info, Token2022>,
+
+    pub supported_token_program: Interface<'info, TokenInterface>,
+
+    #[account(mut)]
+    pub receipt_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = receipt_token_mint,
+        associated_token::token_program = receipt_token_program,
+        associated_token::authority = user,
+    )]
+    pub user_receipt_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub supported_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = supported_token_mint,
+        token::authority = user,
+        token::token_program = supported_token_program,
+    )]
+    pub user_supported_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [FundAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = fund_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = fund_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub fund_account: AccountLoader<'info, FundAccount>,
+
+    #[account(
+        seeds = [FundAccount::RESERVE_SEED, receipt_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub fund_reserve_account: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = supported_token_mint,
+        associated_token::authority = fund_reserve_account,
+        associated_token::token_program = supported_token_program,
+    )]
+    pub fund_supported_token_reserve_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [UserFundAccount::SEED, receipt_token_mint.key().as_ref(), user.key().as_ref()],
+        bump = user_fund_account.get_bump(),
+        has_one = receipt_token_mint,
+        has_one = user,
+        constraint = user_fund_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub user_fund_account: Box<Account<'info, UserFundAccount>>,
+
+    #[account(
+        mut,
+        seeds = [RewardAccount::SEED, receipt_token_mint.key().as_ref()],
+        bump = reward_account.get_bump()?,
+        has_one = receipt_token_mint,
+        constraint = reward_account.load()?.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub reward_account: AccountLoader<'info, RewardAccount>,
+
+    /// CHECK: user might not have reward account...
+    #[account(
+        mut,
+        seeds = [UserRewardAccount::SEED, receipt_token_mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_reward_account: UncheckedAccount<'info>,
+}
@@ -7,6 +7,7 @@ use anchor_spl::token_interface::{Mint, TokenAccount};
 use crate::errors::ErrorCode;
 use crate::modules::fund::{FundAccount, UserFundAccount};
 use crate::modules::reward::{RewardAccount, UserRewardAccount};
+use crate::modules::role::RoleAccount;
 use crate::utils::{AccountLoaderExt, PDASeeds};
 
 #[event_cpi]
@@ -14,6 +15,14 @@ use crate::utils::{AccountLoaderExt, PDASeeds};
 pub struct UserFundVaultReceiptTokenContext<'info> {
     pub user: Signer<'info>,
 
+    /// admin signs the deposit metadata.
+    #[account(
+        seeds = [RoleAccount::SEED],
+        bump = role_account.get_bump(),
+        constraint = role_account.is_latest_version() @ ErrorCode::InvalidAccountDataVersionError,
+    )]
+    pub role_account: Box<Account<'info, RoleAccount>>,
+
     pub receipt_token_program: Program<'info, Token2022>,
 
     pub vault_receipt_token_program: Program<'info, Token>,
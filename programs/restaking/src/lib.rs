@@ -12,7 +12,7 @@ mod utils;
 
 mod instructions;
 
-use constants::*;
+pub use constants::ID;
 use instructions::*;
 use utils::AsAccountInfo;
 
@@ -76,6 +76,21 @@ pub mod restaking {
         .process_set_address_lookup_table_account(address_lookup_table_account)
     }
 
+    ////////////////////////////////////////////
+    // AdminFundOperationHistoryContext
+    ////////////////////////////////////////////
+
+    pub fn admin_close_fund_operation_history_account(
+        ctx: Context<AdminFundOperationHistoryContext>,
+        _page_index: u64,
+    ) -> Result<()> {
+        modules::fund::FundService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_close_operation_history_account(&ctx.accounts.fund_operation_history_account)
+    }
+
     ////////////////////////////////////////////
     // AdminNormalizedTokenPoolInitialContext
     ////////////////////////////////////////////
@@ -169,6 +184,57 @@ pub mod restaking {
         )
     }
 
+    ////////////////////////////////////////////
+    // AdminRoleAccountInitialContext
+    ////////////////////////////////////////////
+
+    pub fn admin_initialize_role_account(
+        ctx: Context<AdminRoleAccountInitialContext>,
+        fund_manager: Pubkey,
+        operator: Pubkey,
+    ) -> Result<()> {
+        modules::role::RoleConfigurationService::new(&mut ctx.accounts.role_account)?
+            .process_initialize_role_account(
+                ctx.bumps.role_account,
+                ctx.accounts.admin.key(),
+                fund_manager,
+                operator,
+            )
+    }
+
+    ////////////////////////////////////////////
+    // AdminRoleContext
+    ////////////////////////////////////////////
+
+    pub fn admin_propose_role_transfer(
+        ctx: Context<AdminRoleContext>,
+        role: modules::role::Role,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        emit_cpi!(
+            modules::role::RoleConfigurationService::new(&mut ctx.accounts.role_account)?
+                .process_propose_role_transfer(role, new_authority)?
+        );
+
+        Ok(())
+    }
+
+    ////////////////////////////////////////////
+    // RoleAuthorityRoleContext
+    ////////////////////////////////////////////
+
+    pub fn role_authority_accept_role_transfer(
+        ctx: Context<RoleAuthorityRoleContext>,
+        role: modules::role::Role,
+    ) -> Result<()> {
+        emit_cpi!(
+            modules::role::RoleConfigurationService::new(&mut ctx.accounts.role_account)?
+                .process_accept_role_transfer(role, &ctx.accounts.new_authority)?
+        );
+
+        Ok(())
+    }
+
     ////////////////////////////////////////////
     // AdminUserRewardAccountInitOrUpdateContext
     ////////////////////////////////////////////
@@ -188,7 +254,7 @@ pub mod restaking {
             &ctx.accounts.user,
             &ctx.accounts.user_receipt_token_account,
             ctx.bumps.user_reward_account,
-            Some(FUND_MANAGER_PUBKEY),
+            Some(ctx.accounts.role_account.fund_manager),
             desired_account_size,
         )?;
 
@@ -232,6 +298,170 @@ pub mod restaking {
         Ok(())
     }
 
+    pub fn fund_manager_update_management_fee_rate(
+        ctx: Context<FundManagerFundContext>,
+        management_fee_rate_bps: u16,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_update_management_fee_rate(management_fee_rate_bps)?);
+
+        Ok(())
+    }
+
+    pub fn fund_manager_update_revenue_split(
+        ctx: Context<FundManagerFundContext>,
+        recipients: Vec<modules::fund::FundRevenueRecipient>,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_update_revenue_split(&recipients)?);
+
+        Ok(())
+    }
+
+    pub fn fund_manager_update_operation_fallback_command(
+        ctx: Context<FundManagerFundContext>,
+        command: u8,
+        fallback_command: Option<u8>,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_update_operation_fallback_command(command, fallback_command)?);
+
+        Ok(())
+    }
+
+    pub fn fund_manager_update_operation_command_policy(
+        ctx: Context<FundManagerFundContext>,
+        command: u8,
+        enabled: bool,
+        min_interval_seconds: i64,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_update_operation_command_policy(
+            command,
+            enabled,
+            min_interval_seconds
+        )?);
+
+        Ok(())
+    }
+
+    pub fn fund_manager_update_max_price_age_slots(
+        ctx: Context<FundManagerFundContext>,
+        max_price_age_slots: u64,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_update_max_price_age_slots(max_price_age_slots)?);
+
+        Ok(())
+    }
+
+    pub fn fund_manager_update_price_deviation_threshold(
+        ctx: Context<FundManagerFundContext>,
+        max_receipt_token_price_deviation_bps: u16,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_update_price_deviation_threshold(max_receipt_token_price_deviation_bps)?);
+
+        Ok(())
+    }
+
+    pub fn fund_manager_update_supported_token_price_deviation_threshold(
+        ctx: Context<FundManagerFundContext>,
+        token_mint: Pubkey,
+        max_price_deviation_bps: u16,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_update_supported_token_price_deviation_threshold(
+            &token_mint,
+            max_price_deviation_bps,
+        )?);
+
+        Ok(())
+    }
+
+    pub fn fund_manager_acknowledge_price_deviation(
+        ctx: Context<FundManagerFundContext>,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_acknowledge_price_deviation()?);
+
+        Ok(())
+    }
+
+    pub fn fund_manager_update_reconciliation_threshold(
+        ctx: Context<FundManagerFundContext>,
+        max_shortfall_bps: u16,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_update_reconciliation_threshold(max_shortfall_bps)?);
+
+        Ok(())
+    }
+
+    pub fn fund_manager_update_instant_withdrawal_strategy(
+        ctx: Context<FundManagerFundContext>,
+        enabled: bool,
+        min_fee_rate_bps: u16,
+        max_fee_rate_bps: u16,
+        fee_curve_exponent: u8,
+        liquidity_target_rate_bps: u16,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_update_instant_withdrawal_strategy(
+            enabled,
+            min_fee_rate_bps,
+            max_fee_rate_bps,
+            fee_curve_exponent,
+            liquidity_target_rate_bps,
+        )?);
+
+        Ok(())
+    }
+
+    pub fn fund_manager_update_in_kind_withdrawal_strategy(
+        ctx: Context<FundManagerFundContext>,
+        enabled: bool,
+        fee_rate_bps: u16,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_update_in_kind_withdrawal_strategy(enabled, fee_rate_bps)?);
+
+        Ok(())
+    }
+
     pub fn fund_manager_update_sol_strategy(
         ctx: Context<FundManagerFundContext>,
         sol_depositable: bool,
@@ -355,6 +585,45 @@ pub mod restaking {
         Ok(())
     }
 
+    pub fn fund_manager_set_operator_allowlist_enabled(
+        ctx: Context<FundManagerFundContext>,
+        enabled: bool,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_set_operator_allowlist_enabled(enabled)?);
+
+        Ok(())
+    }
+
+    pub fn fund_manager_add_operator(
+        ctx: Context<FundManagerFundContext>,
+        operator: Pubkey,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_add_operator(operator)?);
+
+        Ok(())
+    }
+
+    pub fn fund_manager_remove_operator(
+        ctx: Context<FundManagerFundContext>,
+        operator: Pubkey,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_remove_operator(&operator)?);
+
+        Ok(())
+    }
+
     ////////////////////////////////////////////
     // FundManagerFundRestakingVaultRewardContext
     ////////////////////////////////////////////
@@ -445,6 +714,32 @@ pub mod restaking {
         Ok(())
     }
 
+    pub fn fund_manager_update_token_swap_strategy<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FundManagerFundTokenSwapStrategyContext<'info>>,
+        from_token_pricing_source: Option<modules::pricing::TokenPricingSource>,
+        max_slippage_bps: u16,
+    ) -> Result<()> {
+        let (event, tripped_event) = modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_update_token_swap_strategy(
+            &ctx.accounts.from_token_mint,
+            &ctx.accounts.to_token_mint,
+            ctx.accounts.swap_source_account.as_account_info(),
+            from_token_pricing_source,
+            max_slippage_bps,
+            ctx.remaining_accounts,
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
+
+        Ok(())
+    }
+
     pub fn fund_manager_remove_token_swap_strategy(
         ctx: Context<FundManagerFundTokenSwapStrategyContext>,
         swap_source: modules::swap::TokenSwapSource,
@@ -462,6 +757,57 @@ pub mod restaking {
         Ok(())
     }
 
+    ////////////////////////////////////////////
+    // FundManagerFundConfigurationChangeInitialContext
+    ////////////////////////////////////////////
+
+    pub fn fund_manager_queue_fund_configuration_change(
+        ctx: Context<FundManagerFundConfigurationChangeInitialContext>,
+        change_id: u64,
+        change: modules::fund::FundConfigurationChange,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_queue_configuration_change(
+            &ctx.accounts.fund_manager,
+            &mut ctx.accounts.fund_configuration_change_account,
+            ctx.bumps.fund_configuration_change_account,
+            &ctx.accounts.role_account.guardian,
+            change_id,
+            change,
+        )?);
+
+        Ok(())
+    }
+
+    ////////////////////////////////////////////
+    // FundManagerFundConfigurationChangeContext
+    ////////////////////////////////////////////
+
+    pub fn fund_manager_execute_fund_configuration_change<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FundManagerFundConfigurationChangeContext<'info>>,
+        _change_id: u64,
+    ) -> Result<()> {
+        let (event, tripped_event) = modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_execute_configuration_change(
+            &ctx.accounts.fund_configuration_change_account,
+            &ctx.accounts.role_account.guardian,
+            ctx.remaining_accounts,
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
+
+        Ok(())
+    }
+
     ////////////////////////////////////////////
     // FundManagerFundNormalizedTokenInitialContext
     ////////////////////////////////////////////
@@ -469,7 +815,7 @@ pub mod restaking {
     pub fn fund_manager_initialize_fund_normalized_token<'info>(
         ctx: Context<'_, '_, 'info, 'info, FundManagerFundNormalizedTokenInitialContext<'info>>,
     ) -> Result<()> {
-        emit_cpi!(modules::fund::FundConfigurationService::new(
+        let (event, tripped_event) = modules::fund::FundConfigurationService::new(
             &mut ctx.accounts.receipt_token_mint,
             &mut ctx.accounts.fund_account,
         )?
@@ -478,7 +824,12 @@ pub mod restaking {
             &ctx.accounts.normalized_token_mint,
             &ctx.accounts.normalized_token_pool_account,
             ctx.remaining_accounts,
-        )?);
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
 
         Ok(())
     }
@@ -517,7 +868,7 @@ pub mod restaking {
         ctx.accounts
             .fund_wrap_account_reward_account
             .load_mut()?
-            .set_delegate_unchecked(FUND_MANAGER_PUBKEY);
+            .set_delegate_unchecked(ctx.accounts.fund_manager.key());
 
         Ok(())
     }
@@ -566,7 +917,7 @@ pub mod restaking {
         ctx.accounts
             .wrapped_token_holder_reward_account
             .load_mut()?
-            .set_delegate_unchecked(FUND_MANAGER_PUBKEY);
+            .set_delegate_unchecked(ctx.accounts.fund_manager.key());
 
         Ok(())
     }
@@ -579,7 +930,7 @@ pub mod restaking {
         ctx: Context<'_, '_, 'info, 'info, FundManagerFundRestakingVaultInitialContext<'info>>,
         pricing_source: modules::pricing::TokenPricingSource,
     ) -> Result<()> {
-        emit_cpi!(modules::fund::FundConfigurationService::new(
+        let (event, tripped_event) = modules::fund::FundConfigurationService::new(
             &mut ctx.accounts.receipt_token_mint,
             &mut ctx.accounts.fund_account,
         )?
@@ -590,7 +941,12 @@ pub mod restaking {
             &ctx.accounts.vault_receipt_token_mint,
             pricing_source,
             ctx.remaining_accounts,
-        )?);
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
 
         Ok(())
     }
@@ -623,7 +979,7 @@ pub mod restaking {
         ctx: Context<'_, '_, 'info, 'info, FundManagerFundSupportedTokenContext<'info>>,
         pricing_source: modules::pricing::TokenPricingSource,
     ) -> Result<()> {
-        emit_cpi!(modules::fund::FundConfigurationService::new(
+        let (event, tripped_event) = modules::fund::FundConfigurationService::new(
             &mut ctx.accounts.receipt_token_mint,
             &mut ctx.accounts.fund_account,
         )?
@@ -632,7 +988,12 @@ pub mod restaking {
             &ctx.accounts.supported_token_mint,
             pricing_source,
             ctx.remaining_accounts,
-        )?);
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
 
         Ok(())
     }
@@ -640,7 +1001,7 @@ pub mod restaking {
     pub fn fund_manager_remove_supported_token<'info>(
         ctx: Context<'_, '_, 'info, 'info, FundManagerFundSupportedTokenRemoveContext<'info>>,
     ) -> Result<()> {
-        emit_cpi!(modules::fund::FundConfigurationService::new(
+        let (event, tripped_event) = modules::fund::FundConfigurationService::new(
             &mut ctx.accounts.receipt_token_mint,
             &mut ctx.accounts.fund_account,
         )?
@@ -649,7 +1010,12 @@ pub mod restaking {
             ctx.accounts.normalized_token_mint.as_deref(),
             ctx.accounts.normalized_token_pool_account.as_deref(),
             ctx.remaining_accounts,
-        )?);
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
 
         Ok(())
     }
@@ -800,9 +1166,27 @@ pub mod restaking {
         .process_revoke_reward_token_mint_authority(
             &ctx.accounts.reward_token_mint,
             &ctx.accounts.reward_token_program,
+            ctx.accounts.fund_manager.key,
         )
     }
 
+    ////////////////////////////////////////////
+    // GuardianFundConfigurationChangeContext
+    ////////////////////////////////////////////
+
+    pub fn guardian_cancel_fund_configuration_change(
+        ctx: Context<GuardianFundConfigurationChangeContext>,
+        _change_id: u64,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundConfigurationService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_cancel_configuration_change(&ctx.accounts.fund_configuration_change_account)?);
+
+        Ok(())
+    }
+
     ////////////////////////////////////////////
     // OperatorEmptyContext
     ////////////////////////////////////////////
@@ -822,20 +1206,23 @@ pub mod restaking {
     pub fn operator_run_fund_command<'info>(
         ctx: Context<'_, '_, 'info, 'info, OperatorFundContext<'info>>,
         force_reset_command: Option<modules::fund::commands::OperationCommandEntry>,
+        max_steps: Option<u8>,
     ) -> Result<()> {
         // check force reset command is authorized
         if let Some(command_entry) = &force_reset_command {
             // fund manager can reset the operation state anytime.
-            // and admin can reset the operation state only if the command is safe.
-            if !(ctx.accounts.operator.key() == FUND_MANAGER_PUBKEY
-                || ctx.accounts.operator.key() == ADMIN_PUBKEY
+            // and admin or operator can reset the operation state only if the command is safe.
+            let role_account = &ctx.accounts.role_account;
+            let operator = ctx.accounts.operator.key;
+            if !(*operator == role_account.fund_manager
+                || (*operator == role_account.admin || *operator == role_account.operator)
                     && command_entry.command.is_safe_with_unchecked_params())
             {
                 err!(errors::ErrorCode::FundOperationUnauthorizedCommandError)?;
             }
         }
 
-        emit_cpi!(modules::fund::FundService::new(
+        let (events, tripped_event) = modules::fund::FundService::new(
             &mut ctx.accounts.receipt_token_mint,
             &mut ctx.accounts.fund_account,
         )?
@@ -844,19 +1231,49 @@ pub mod restaking {
             &ctx.accounts.system_program,
             ctx.remaining_accounts,
             force_reset_command,
-        )?);
+            max_steps,
+        )?;
+
+        for event in events {
+            emit_cpi!(event);
+        }
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
 
         Ok(())
     }
 
+    /// Predicts the operation commands without executing them.
+    /// Use it with transaction simulation to read the plan from the return data.
+    pub fn operator_plan_fund_commands<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OperatorFundContext<'info>>,
+        max_steps: u8,
+    ) -> Result<modules::fund::commands::OperationCommandPlan> {
+        modules::fund::FundService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_plan_commands(
+            &ctx.accounts.operator,
+            &ctx.accounts.system_program,
+            max_steps,
+        )
+    }
+
     pub fn operator_update_fund_prices<'info>(
         ctx: Context<'_, '_, 'info, 'info, OperatorFundContext<'info>>,
     ) -> Result<()> {
-        emit_cpi!(modules::fund::FundService::new(
+        let (event, tripped_event) = modules::fund::FundService::new(
             &mut ctx.accounts.receipt_token_mint,
             &mut ctx.accounts.fund_account,
         )?
-        .process_update_prices(ctx.remaining_accounts)?);
+        .process_update_prices(ctx.remaining_accounts)?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
 
         Ok(())
     }
@@ -866,7 +1283,7 @@ pub mod restaking {
         amount: u64,
         offset_receivable: bool,
     ) -> Result<()> {
-        emit_cpi!(modules::fund::FundService::new(
+        let (event, tripped_event) = modules::fund::FundService::new(
             &mut ctx.accounts.receipt_token_mint,
             &mut ctx.accounts.fund_account,
         )?
@@ -877,7 +1294,12 @@ pub mod restaking {
             ctx.remaining_accounts,
             amount,
             offset_receivable,
-        )?);
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
 
         Ok(())
     }
@@ -887,7 +1309,7 @@ pub mod restaking {
         amount: u64,
         offset_receivable: bool,
     ) -> Result<()> {
-        emit_cpi!(modules::fund::FundService::new(
+        let (event, tripped_event) = modules::fund::FundService::new(
             &mut ctx.accounts.receipt_token_mint,
             &mut ctx.accounts.fund_account,
         )?
@@ -900,11 +1322,55 @@ pub mod restaking {
             ctx.remaining_accounts,
             amount,
             offset_receivable,
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
+
+        Ok(())
+    }
+
+    ////////////////////////////////////////////
+    // OperatorFundReconciliationContext
+    ////////////////////////////////////////////
+
+    pub fn operator_reconcile_fund<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OperatorFundReconciliationContext<'info>>,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::FundService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_reconcile(
+            &ctx.accounts.fund_reserve_account,
+            &ctx.accounts.fund_treasury_account,
+            ctx.remaining_accounts,
         )?);
 
         Ok(())
     }
 
+    ////////////////////////////////////////////
+    // OperatorFundOperationHistoryInitialContext
+    ////////////////////////////////////////////
+
+    pub fn operator_initialize_fund_operation_history_account(
+        ctx: Context<OperatorFundOperationHistoryInitialContext>,
+        page_index: u64,
+    ) -> Result<()> {
+        modules::fund::FundService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_initialize_operation_history_account(
+            &ctx.accounts.fund_operation_history_account,
+            ctx.bumps.fund_operation_history_account,
+            page_index,
+        )
+    }
+
     ////////////////////////////////////////////
     // OperatorRewardContext
     ////////////////////////////////////////////
@@ -923,18 +1389,21 @@ pub mod restaking {
     // OperatorRewardClaimContext
     ////////////////////////////////////////////
 
-    pub fn operator_claim_remaining_reward(ctx: Context<OperatorRewardClaimContext>) -> Result<()> {
-        modules::reward::RewardService::new(
+    pub fn operator_claim_remaining_reward<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OperatorRewardClaimContext<'info>>,
+    ) -> Result<()> {
+        emit_cpi!(modules::reward::RewardService::new(
             &ctx.accounts.receipt_token_mint,
             &ctx.accounts.reward_account,
         )?
         .process_claim_remaining_reward(
+            &ctx.accounts.fund_account,
             &ctx.accounts.reward_token_mint,
             &ctx.accounts.reward_token_program,
             &ctx.accounts.reward_reserve_account,
             &ctx.accounts.reward_token_reserve_account,
-            &ctx.accounts.program_reward_token_revenue_account,
-        )?;
+            ctx.remaining_accounts,
+        )?);
 
         Ok(())
     }
@@ -1047,8 +1516,9 @@ pub mod restaking {
         ctx: Context<'_, '_, 'info, 'info, UserFundDepositSolContext<'info>>,
         amount: u64,
         metadata: Option<modules::fund::DepositMetadata>,
+        min_receipt_token_amount: Option<u64>,
     ) -> Result<()> {
-        emit_cpi!(modules::fund::UserFundDepositService::new(
+        let (event, tripped_event) = modules::fund::UserFundDepositService::new(
             &mut ctx.accounts.receipt_token_mint,
             &ctx.accounts.receipt_token_program,
             &mut ctx.accounts.fund_account,
@@ -1064,9 +1534,15 @@ pub mod restaking {
             &ctx.accounts.instructions_sysvar,
             ctx.remaining_accounts,
             amount,
+            min_receipt_token_amount,
             metadata,
-            &ADMIN_PUBKEY,
-        )?);
+            &ctx.accounts.role_account.admin,
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
 
         Ok(())
     }
@@ -1079,6 +1555,7 @@ pub mod restaking {
         ctx: Context<'_, '_, 'info, 'info, UserFundRequestWithdrawalContext<'info>>,
         receipt_token_amount: u64,
         supported_token_mint: Option<Pubkey>,
+        min_asset_amount: Option<u64>,
     ) -> Result<()> {
         emit_cpi!(modules::fund::UserFundWithdrawService::new(
             &mut ctx.accounts.receipt_token_mint,
@@ -1091,10 +1568,13 @@ pub mod restaking {
             &mut ctx.accounts.user_reward_account,
         )?
         .process_request_withdrawal(
+            &ctx.accounts.system_program,
             &mut ctx.accounts.receipt_token_lock_account,
+            &ctx.accounts.fund_withdrawal_request_account,
             supported_token_mint,
             ctx.remaining_accounts,
             receipt_token_amount,
+            min_asset_amount,
         )?);
 
         Ok(())
@@ -1105,7 +1585,7 @@ pub mod restaking {
         request_id: u64,
         supported_token_mint: Option<Pubkey>,
     ) -> Result<()> {
-        emit_cpi!(modules::fund::UserFundWithdrawService::new(
+        let (event, tripped_event) = modules::fund::UserFundWithdrawService::new(
             &mut ctx.accounts.receipt_token_mint,
             &ctx.accounts.receipt_token_program,
             &mut ctx.accounts.fund_account,
@@ -1117,10 +1597,16 @@ pub mod restaking {
         )?
         .process_cancel_withdrawal_request(
             &mut ctx.accounts.receipt_token_lock_account,
+            &ctx.accounts.fund_withdrawal_request_account,
             ctx.remaining_accounts,
             request_id,
             supported_token_mint,
-        )?);
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
 
         Ok(())
     }
@@ -1132,7 +1618,7 @@ pub mod restaking {
     pub fn user_withdraw_sol(
         ctx: Context<UserFundWithdrawSolContext>,
         _batch_id: u64,
-        request_id: u64,
+        _request_id: u64,
     ) -> Result<()> {
         emit_cpi!(modules::fund::UserFundWithdrawService::new(
             &mut ctx.accounts.receipt_token_mint,
@@ -1147,14 +1633,84 @@ pub mod restaking {
         .process_withdraw_sol(
             &ctx.accounts.system_program,
             &mut ctx.accounts.fund_withdrawal_batch_account,
+            &ctx.accounts.fund_withdrawal_request_account,
             &ctx.accounts.fund_reserve_account,
             &ctx.accounts.fund_treasury_account,
-            request_id,
         )?);
 
         Ok(())
     }
 
+    ////////////////////////////////////////////
+    // UserFundInstantWithdrawSolContext
+    ////////////////////////////////////////////
+
+    pub fn user_withdraw_instantly<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UserFundInstantWithdrawSolContext<'info>>,
+        receipt_token_amount: u64,
+        min_asset_amount: Option<u64>,
+    ) -> Result<()> {
+        let (event, tripped_event) = modules::fund::UserFundWithdrawService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &ctx.accounts.receipt_token_program,
+            &mut ctx.accounts.fund_account,
+            &mut ctx.accounts.reward_account,
+            &ctx.accounts.user,
+            &mut ctx.accounts.user_receipt_token_account,
+            &mut ctx.accounts.user_fund_account,
+            &mut ctx.accounts.user_reward_account,
+        )?
+        .process_withdraw_sol_instantly(
+            &ctx.accounts.system_program,
+            &ctx.accounts.fund_reserve_account,
+            ctx.remaining_accounts,
+            receipt_token_amount,
+            min_asset_amount,
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
+
+        Ok(())
+    }
+
+    ////////////////////////////////////////////
+    // UserFundInKindWithdrawContext
+    ////////////////////////////////////////////
+
+    pub fn user_withdraw_in_kind<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UserFundInKindWithdrawContext<'info>>,
+        receipt_token_amount: u64,
+        min_asset_amounts: Option<Vec<u64>>,
+    ) -> Result<()> {
+        let (event, tripped_event) = modules::fund::UserFundWithdrawService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &ctx.accounts.receipt_token_program,
+            &mut ctx.accounts.fund_account,
+            &mut ctx.accounts.reward_account,
+            &ctx.accounts.user,
+            &mut ctx.accounts.user_receipt_token_account,
+            &mut ctx.accounts.user_fund_account,
+            &mut ctx.accounts.user_reward_account,
+        )?
+        .process_withdraw_in_kind(
+            &ctx.accounts.system_program,
+            &ctx.accounts.fund_reserve_account,
+            ctx.remaining_accounts,
+            receipt_token_amount,
+            min_asset_amounts,
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
+
+        Ok(())
+    }
+
     ////////////////////////////////////////////
     // UserFundDepositSupportedTokenContext
     ////////////////////////////////////////////
@@ -1163,8 +1719,9 @@ pub mod restaking {
         ctx: Context<'_, '_, 'info, 'info, UserFundDepositSupportedTokenContext<'info>>,
         amount: u64,
         metadata: Option<modules::fund::DepositMetadata>,
+        min_receipt_token_amount: Option<u64>,
     ) -> Result<()> {
-        emit_cpi!(modules::fund::UserFundDepositService::new(
+        let (event, tripped_event) = modules::fund::UserFundDepositService::new(
             &mut ctx.accounts.receipt_token_mint,
             &ctx.accounts.receipt_token_program,
             &mut ctx.accounts.fund_account,
@@ -1182,9 +1739,15 @@ pub mod restaking {
             &ctx.accounts.instructions_sysvar,
             ctx.remaining_accounts,
             amount,
+            min_receipt_token_amount,
             metadata,
-            &ADMIN_PUBKEY,
-        )?);
+            &ctx.accounts.role_account.admin,
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
 
         Ok(())
     }
@@ -1196,7 +1759,7 @@ pub mod restaking {
     pub fn user_withdraw_supported_token(
         ctx: Context<UserFundWithdrawSupportedTokenContext>,
         _batch_id: u64,
-        request_id: u64,
+        _request_id: u64,
     ) -> Result<()> {
         emit_cpi!(modules::fund::UserFundWithdrawService::new(
             &mut ctx.accounts.receipt_token_mint,
@@ -1215,14 +1778,53 @@ pub mod restaking {
             &ctx.accounts.fund_supported_token_reserve_account,
             &ctx.accounts.user_supported_token_account,
             &mut ctx.accounts.fund_withdrawal_batch_account,
+            &ctx.accounts.fund_withdrawal_request_account,
             &ctx.accounts.fund_reserve_account,
             &ctx.accounts.fund_treasury_account,
-            request_id,
         )?);
 
         Ok(())
     }
 
+    ////////////////////////////////////////////
+    // UserFundInstantWithdrawSupportedTokenContext
+    ////////////////////////////////////////////
+
+    pub fn user_withdraw_supported_token_instantly<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UserFundInstantWithdrawSupportedTokenContext<'info>>,
+        receipt_token_amount: u64,
+        min_asset_amount: Option<u64>,
+    ) -> Result<()> {
+        let (event, tripped_event) = modules::fund::UserFundWithdrawService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &ctx.accounts.receipt_token_program,
+            &mut ctx.accounts.fund_account,
+            &mut ctx.accounts.reward_account,
+            &ctx.accounts.user,
+            &mut ctx.accounts.user_receipt_token_account,
+            &mut ctx.accounts.user_fund_account,
+            &mut ctx.accounts.user_reward_account,
+        )?
+        .process_withdraw_supported_token_instantly(
+            &ctx.accounts.system_program,
+            &ctx.accounts.supported_token_program,
+            &ctx.accounts.supported_token_mint,
+            &ctx.accounts.fund_supported_token_reserve_account,
+            &ctx.accounts.user_supported_token_account,
+            &ctx.accounts.fund_reserve_account,
+            ctx.remaining_accounts,
+            receipt_token_amount,
+            min_asset_amount,
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
+
+        Ok(())
+    }
+
     ////////////////////////////////////////////
     // UserFundVaultReceiptTokenContext
     ////////////////////////////////////////////
@@ -1230,8 +1832,9 @@ pub mod restaking {
     pub fn user_deposit_vault_receipt_token<'info>(
         ctx: Context<'_, '_, 'info, 'info, UserFundVaultReceiptTokenContext<'info>>,
         metadata: Option<modules::fund::DepositMetadata>,
+        min_receipt_token_amount: Option<u64>,
     ) -> Result<()> {
-        emit_cpi!(modules::fund::UserFundDepositService::new(
+        let (event, tripped_event) = modules::fund::UserFundDepositService::new(
             &mut ctx.accounts.receipt_token_mint,
             &ctx.accounts.receipt_token_program,
             &mut ctx.accounts.fund_account,
@@ -1248,9 +1851,15 @@ pub mod restaking {
             &ctx.accounts.user_vault_receipt_token_account,
             &ctx.accounts.instructions_sysvar,
             ctx.remaining_accounts,
+            min_receipt_token_amount,
             metadata,
-            &ADMIN_PUBKEY,
-        )?);
+            &ctx.accounts.role_account.admin,
+        )?;
+
+        emit_cpi!(event);
+        if let Some(tripped_event) = tripped_event {
+            emit_cpi!(tripped_event);
+        }
 
         Ok(())
     }
@@ -1338,6 +1947,47 @@ pub mod restaking {
         Ok(())
     }
 
+    ////////////////////////////////////////////
+    // UserFundWithdrawalRequestMigrationContext
+    ////////////////////////////////////////////
+
+    pub fn user_migrate_withdrawal_requests<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UserFundWithdrawalRequestMigrationContext<'info>>,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::UserFundConfigurationService::new(
+            &ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.user_fund_account
+        )?
+        .process_migrate_withdrawal_requests(
+            &ctx.accounts.system_program,
+            &ctx.accounts.user,
+            ctx.remaining_accounts,
+        )?);
+
+        Ok(())
+    }
+
+    ////////////////////////////////////////////
+    // UserFundWithdrawalRequestTransferContext
+    ////////////////////////////////////////////
+
+    pub fn user_transfer_withdrawal_request(
+        ctx: Context<UserFundWithdrawalRequestTransferContext>,
+        new_user: Pubkey,
+    ) -> Result<()> {
+        emit_cpi!(modules::fund::UserFundConfigurationService::new(
+            &ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.user_fund_account
+        )?
+        .process_transfer_withdrawal_request(
+            &ctx.accounts.user,
+            &mut ctx.accounts.fund_withdrawal_request_account,
+            new_user,
+        )?);
+
+        Ok(())
+    }
+
     ////////////////////////////////////////////
     // UserFundAccountCloseContext
     ////////////////////////////////////////////
@@ -1470,6 +2120,60 @@ pub mod restaking {
         Ok(())
     }
 
+    ////////////////////////////////////////////
+    // FundQuoteContext
+    ////////////////////////////////////////////
+
+    pub fn quote_deposit<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FundQuoteContext<'info>>,
+        supported_token_mint: Option<Pubkey>,
+        amount: u64,
+    ) -> Result<modules::fund::DepositQuote> {
+        modules::fund::FundService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_quote_deposit(ctx.remaining_accounts, supported_token_mint, amount)
+    }
+
+    pub fn quote_withdrawal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FundQuoteContext<'info>>,
+        supported_token_mint: Option<Pubkey>,
+        receipt_token_amount: u64,
+    ) -> Result<modules::fund::WithdrawalQuote> {
+        modules::fund::FundService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_quote_withdrawal(
+            ctx.remaining_accounts,
+            supported_token_mint,
+            receipt_token_amount,
+        )
+    }
+
+    pub fn quote_wrap(
+        ctx: Context<FundQuoteContext>,
+        receipt_token_amount: u64,
+    ) -> Result<modules::fund::WrapQuote> {
+        modules::fund::FundService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_quote_wrap(receipt_token_amount)
+    }
+
+    pub fn quote_receipt_token_twap(
+        ctx: Context<FundQuoteContext>,
+        window_seconds: i64,
+    ) -> Result<modules::fund::ReceiptTokenTWAPQuote> {
+        modules::fund::FundService::new(
+            &mut ctx.accounts.receipt_token_mint,
+            &mut ctx.accounts.fund_account,
+        )?
+        .process_quote_receipt_token_twap(window_seconds)
+    }
+
     ////////////////////////////////////////////
     // UserReceiptTokenTransferContext
     ////////////////////////////////////////////
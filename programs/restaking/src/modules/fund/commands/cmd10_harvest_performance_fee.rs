@@ -2,7 +2,6 @@ use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
 use spl_stake_pool::solana_program::native_token::LAMPORTS_PER_SOL;
 
-use crate::constants::PROGRAM_REVENUE_ADDRESS;
 use crate::errors::ErrorCode;
 use crate::modules::pricing::TokenPricingSource;
 use crate::modules::reward::{RewardAccount, RewardService, UserRewardAccount};
@@ -27,12 +26,16 @@ pub enum HarvestPerformanceFeeState {
 
 use HarvestPerformanceFeeState::*;
 
+/// minimum of performance fee and management fee in total
 const MINIMUM_PERFORMANCE_FEE_LAMPORTS: u64 = 1_000_000_000;
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct HarvestPerformanceFeeCommandResult {
     pub receipt_token_mint: Pubkey,
     pub receipt_token_minted_amount: u64,
+    pub performance_fee_receipt_token_minted_amount: u64,
+    pub management_fee_receipt_token_minted_amount: u64,
+    pub receipt_token_minted_shares: Vec<FundRevenueShare>,
     pub one_receipt_token_as_sol_before_performance_fee_harvested: u64,
     pub one_receipt_token_as_sol_after_performance_fee_harvested: u64,
 }
@@ -49,12 +52,11 @@ impl SelfExecutable for HarvestPerformanceFeeCommand {
             Unused { .. } => err!(ErrorCode::FundOperationCommandExecutionFailedException)?,
         };
 
-        Ok((
-            result,
-            entry.or_else(|| {
-                Some(HarvestRestakingYieldCommand::default().without_required_accounts())
-            }),
-        ))
+        Ok((result, entry.or_else(|| self.next_command_of_cycle())))
+    }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(HarvestRestakingYieldCommand::default().without_required_accounts())
     }
 }
 
@@ -64,35 +66,24 @@ impl HarvestPerformanceFeeCommand {
         ctx: &mut OperationCommandContext<'info, '_>,
         accounts: &[&'info AccountInfo<'info>],
     ) -> ExecutionResult {
-        if !&self.is_performance_fee_harvestable(ctx, accounts)? {
+        let (performance_fee_in_sol_amount, management_fee_in_sol_amount) =
+            self.get_fee_amounts_as_sol(ctx, accounts)?;
+        if performance_fee_in_sol_amount + management_fee_in_sol_amount
+            < MINIMUM_PERFORMANCE_FEE_LAMPORTS
+        {
             return Ok((None, None));
         }
 
         // * (0) receipt token program
-        // * (1) program revenue account
-        // * (2) program revenue receipt token account
-        // * (3) program revenue user reward account
-        // * (4) reward account
-        // * (5) associated token program
-        // * (6) system program
+        // * (1) reward account
+        // * (2) associated token program
+        // * (3) system program
+        // * (4..) for each revenue recipient:
+        //     * revenue recipient account
+        //     * revenue recipient receipt token account
+        //     * revenue recipient user reward account
         let required_accounts = [
             (anchor_spl::token_2022::ID, false),
-            (PROGRAM_REVENUE_ADDRESS, false),
-            (
-                associated_token::get_associated_token_address_with_program_id(
-                    &PROGRAM_REVENUE_ADDRESS,
-                    &ctx.receipt_token_mint.key(),
-                    &anchor_spl::token_2022::ID,
-                ),
-                true,
-            ),
-            (
-                UserRewardAccount::find_account_address(
-                    &ctx.receipt_token_mint.key(),
-                    &PROGRAM_REVENUE_ADDRESS,
-                ),
-                true,
-            ),
             (
                 RewardAccount::find_account_address(&ctx.receipt_token_mint.key()),
                 true,
@@ -100,7 +91,33 @@ impl HarvestPerformanceFeeCommand {
             (anchor_spl::associated_token::ID, false),
             (system_program::ID, false),
         ]
-        .into_iter();
+        .into_iter()
+        .chain(
+            ctx.fund_account
+                .load()?
+                .get_revenue_recipients_iter()
+                .flat_map(|(revenue_recipient, _)| {
+                    [
+                        (revenue_recipient, false),
+                        (
+                            associated_token::get_associated_token_address_with_program_id(
+                                &revenue_recipient,
+                                &ctx.receipt_token_mint.key(),
+                                &anchor_spl::token_2022::ID,
+                            ),
+                            true,
+                        ),
+                        (
+                            UserRewardAccount::find_account_address(
+                                &ctx.receipt_token_mint.key(),
+                                &revenue_recipient,
+                            ),
+                            true,
+                        ),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        );
 
         let command = Self { state: Execute };
         let entry = command.with_required_accounts(required_accounts);
@@ -113,65 +130,58 @@ impl HarvestPerformanceFeeCommand {
         ctx: &mut OperationCommandContext<'info, '_>,
         mut accounts: &[&'info AccountInfo<'info>],
     ) -> ExecutionResult {
-        if !&self.is_performance_fee_harvestable(ctx, accounts)? {
+        let (performance_fee_in_sol_amount, management_fee_in_sol_amount) =
+            self.get_fee_amounts_as_sol(ctx, accounts)?;
+        if performance_fee_in_sol_amount + management_fee_in_sol_amount
+            < MINIMUM_PERFORMANCE_FEE_LAMPORTS
+        {
             return Ok((None, None));
         }
 
-        let [receipt_token_program, program_revenue_account, program_revenue_receipt_token_account, program_revenue_user_reward_account, reward_account, associated_token_program, system_program, remaining_accounts @ ..] =
+        let [receipt_token_program, reward_account, associated_token_program, system_program, remaining_accounts @ ..] =
             accounts
         else {
             err!(error::ErrorCode::AccountNotEnoughKeys)?
         };
+        let num_revenue_recipient_accounts =
+            ctx.fund_account.load()?.get_num_revenue_recipients() * 3;
+        if remaining_accounts.len() < num_revenue_recipient_accounts {
+            err!(error::ErrorCode::AccountNotEnoughKeys)?;
+        }
+        let (revenue_recipient_accounts, remaining_accounts) =
+            remaining_accounts.split_at(num_revenue_recipient_accounts);
         accounts = remaining_accounts;
 
         // validation
         require_keys_eq!(receipt_token_program.key(), anchor_spl::token_2022::ID);
-        require_keys_eq!(program_revenue_account.key(), PROGRAM_REVENUE_ADDRESS);
-        require_keys_eq!(
-            program_revenue_receipt_token_account.key(),
-            associated_token::get_associated_token_address_with_program_id(
-                &PROGRAM_REVENUE_ADDRESS,
-                &ctx.receipt_token_mint.key(),
-                &anchor_spl::token_2022::ID,
-            )
-        );
-        require_keys_eq!(
-            program_revenue_user_reward_account.key(),
-            UserRewardAccount::find_account_address(
-                &ctx.receipt_token_mint.key(),
-                &PROGRAM_REVENUE_ADDRESS,
-            )
-        );
         require_keys_eq!(
             reward_account.key(),
             RewardAccount::find_account_address(&ctx.receipt_token_mint.key()),
         );
-
-        let fund_account = ctx.fund_account.load()?;
-
-        let one_receipt_token_as_sol_before_performance_fee_harvested =
-            fund_account.one_receipt_token_as_sol;
-        let fee_harvested_one_receipt_token_as_sol =
-            fund_account.fee_harvested_one_receipt_token_as_sol;
-
-        let performance_gain_in_sol_amount = crate::utils::get_proportional_amount_u64(
-            ctx.receipt_token_mint.supply,
-            one_receipt_token_as_sol_before_performance_fee_harvested
-                - fee_harvested_one_receipt_token_as_sol,
-            LAMPORTS_PER_SOL,
-        )?;
-
-        let performance_fee_in_sol_amount = crate::utils::get_proportional_amount_u64(
-            performance_gain_in_sol_amount,
-            fund_account.performance_fee_rate_bps as u64,
-            10_000,
-        )?;
-
-        if performance_fee_in_sol_amount < MINIMUM_PERFORMANCE_FEE_LAMPORTS {
-            return Ok((None, None));
+        for (recipient_accounts, (revenue_recipient, _)) in revenue_recipient_accounts
+            .chunks_exact(3)
+            .zip(ctx.fund_account.load()?.get_revenue_recipients_iter())
+        {
+            require_keys_eq!(recipient_accounts[0].key(), revenue_recipient);
+            require_keys_eq!(
+                recipient_accounts[1].key(),
+                associated_token::get_associated_token_address_with_program_id(
+                    &revenue_recipient,
+                    &ctx.receipt_token_mint.key(),
+                    &anchor_spl::token_2022::ID,
+                )
+            );
+            require_keys_eq!(
+                recipient_accounts[2].key(),
+                UserRewardAccount::find_account_address(
+                    &ctx.receipt_token_mint.key(),
+                    &revenue_recipient,
+                )
+            );
         }
 
-        drop(fund_account);
+        let one_receipt_token_as_sol_before_performance_fee_harvested =
+            ctx.fund_account.load()?.one_receipt_token_as_sol;
 
         let mut pricing_service = FundService::new(ctx.receipt_token_mint, ctx.fund_account)?
             .new_pricing_service(accounts.iter().copied(), false)?;
@@ -180,63 +190,95 @@ impl HarvestPerformanceFeeCommand {
             &ctx.receipt_token_mint.key(),
             performance_fee_in_sol_amount,
         )?;
+        let management_fee_in_receipt_token_amount = pricing_service
+            .get_sol_amount_as_token(&ctx.receipt_token_mint.key(), management_fee_in_sol_amount)?;
+        let fee_in_receipt_token_amount =
+            performance_fee_in_receipt_token_amount + management_fee_in_receipt_token_amount;
 
-        let result = if performance_fee_in_receipt_token_amount > 0 {
-            // update high-water mark
+        let result = if fee_in_receipt_token_amount > 0 {
+            // update high-water mark only if performance fee is charged
             let mut fund_account = ctx.fund_account.load_mut()?;
-            fund_account.fee_harvested_one_receipt_token_as_sol =
-                one_receipt_token_as_sol_before_performance_fee_harvested;
+            if performance_fee_in_receipt_token_amount > 0 {
+                fund_account.fee_harvested_one_receipt_token_as_sol =
+                    one_receipt_token_as_sol_before_performance_fee_harvested;
+            }
             fund_account.performance_fee_last_harvested_at = Clock::get()?.unix_timestamp;
+            fund_account.management_fee_accrued_amount_as_sol = 0;
             drop(fund_account);
 
-            // create program revenue receipt token account if not initialized
-            if !program_revenue_receipt_token_account.is_initialized() {
-                anchor_spl::associated_token::create(CpiContext::new(
-                    associated_token_program.to_account_info(),
-                    anchor_spl::associated_token::Create {
-                        payer: ctx.operator.to_account_info(),
-                        associated_token: program_revenue_receipt_token_account.to_account_info(),
-                        authority: program_revenue_account.to_account_info(),
-                        mint: ctx.receipt_token_mint.to_account_info(),
-                        system_program: system_program.to_account_info(),
-                        token_program: receipt_token_program.to_account_info(),
-                    },
-                ))?;
-            }
+            let receipt_token_minted_shares = ctx
+                .fund_account
+                .load()?
+                .split_revenue(fee_in_receipt_token_amount)?;
+            let reward_account = AccountLoader::<RewardAccount>::try_from(reward_account)?;
 
-            // mint receipt token to revenue account
-            anchor_spl::token_2022::mint_to(
-                CpiContext::new_with_signer(
-                    receipt_token_program.to_account_info(),
-                    anchor_spl::token_2022::MintTo {
-                        mint: ctx.receipt_token_mint.to_account_info(),
-                        to: program_revenue_receipt_token_account.to_account_info(),
-                        authority: ctx.fund_account.to_account_info(),
-                    },
-                    &[ctx.fund_account.load()?.get_seeds().as_ref()],
-                ),
-                performance_fee_in_receipt_token_amount,
-            )?;
+            for (revenue_share, recipient_accounts) in receipt_token_minted_shares
+                .iter()
+                .zip(revenue_recipient_accounts.chunks_exact(3))
+            {
+                if revenue_share.amount == 0 {
+                    continue;
+                }
 
-            // update reward pool
-            let reward_account = AccountLoader::<RewardAccount>::try_from(reward_account)?;
-            let program_revenue_user_reward_account =
-                if program_revenue_user_reward_account.is_initialized() {
-                    Some(AccountLoader::<UserRewardAccount>::try_from(
-                        program_revenue_user_reward_account,
-                    )?)
-                } else {
-                    None
-                };
-
-            RewardService::new(ctx.receipt_token_mint, &reward_account)?
-                .update_reward_pools_token_allocation(
-                    None,
-                    program_revenue_user_reward_account.as_ref(),
-                    performance_fee_in_receipt_token_amount,
-                    None,
+                let (
+                    revenue_recipient,
+                    revenue_recipient_receipt_token_account,
+                    revenue_recipient_user_reward_account,
+                ) = (
+                    recipient_accounts[0],
+                    recipient_accounts[1],
+                    recipient_accounts[2],
+                );
+
+                // create revenue recipient receipt token account if not initialized
+                if !revenue_recipient_receipt_token_account.is_initialized() {
+                    anchor_spl::associated_token::create(CpiContext::new(
+                        associated_token_program.to_account_info(),
+                        anchor_spl::associated_token::Create {
+                            payer: ctx.operator.to_account_info(),
+                            associated_token: revenue_recipient_receipt_token_account
+                                .to_account_info(),
+                            authority: revenue_recipient.to_account_info(),
+                            mint: ctx.receipt_token_mint.to_account_info(),
+                            system_program: system_program.to_account_info(),
+                            token_program: receipt_token_program.to_account_info(),
+                        },
+                    ))?;
+                }
+
+                // mint receipt token to revenue recipient
+                anchor_spl::token_2022::mint_to(
+                    CpiContext::new_with_signer(
+                        receipt_token_program.to_account_info(),
+                        anchor_spl::token_2022::MintTo {
+                            mint: ctx.receipt_token_mint.to_account_info(),
+                            to: revenue_recipient_receipt_token_account.to_account_info(),
+                            authority: ctx.fund_account.to_account_info(),
+                        },
+                        &[ctx.fund_account.load()?.get_seeds().as_ref()],
+                    ),
+                    revenue_share.amount,
                 )?;
 
+                // update reward pool
+                let revenue_recipient_user_reward_account =
+                    if revenue_recipient_user_reward_account.is_initialized() {
+                        Some(AccountLoader::<UserRewardAccount>::try_from(
+                            revenue_recipient_user_reward_account,
+                        )?)
+                    } else {
+                        None
+                    };
+
+                RewardService::new(ctx.receipt_token_mint, &reward_account)?
+                    .update_reward_pools_token_allocation(
+                        None,
+                        revenue_recipient_user_reward_account.as_ref(),
+                        revenue_share.amount,
+                        None,
+                    )?;
+            }
+
             // get updated receipt token price
             let mut fund_account = ctx.fund_account.load_mut()?;
             fund_account.reload_receipt_token_supply(ctx.receipt_token_mint)?;
@@ -257,7 +299,12 @@ impl HarvestPerformanceFeeCommand {
             Some(
                 HarvestPerformanceFeeCommandResult {
                     receipt_token_mint: ctx.receipt_token_mint.key(),
-                    receipt_token_minted_amount: performance_fee_in_receipt_token_amount,
+                    receipt_token_minted_amount: fee_in_receipt_token_amount,
+                    performance_fee_receipt_token_minted_amount:
+                        performance_fee_in_receipt_token_amount,
+                    management_fee_receipt_token_minted_amount:
+                        management_fee_in_receipt_token_amount,
+                    receipt_token_minted_shares,
                     one_receipt_token_as_sol_before_performance_fee_harvested,
                     one_receipt_token_as_sol_after_performance_fee_harvested,
                 }
@@ -270,11 +317,12 @@ impl HarvestPerformanceFeeCommand {
         Ok((result, None))
     }
 
-    fn is_performance_fee_harvestable<'info>(
+    /// returns [performance_fee_in_sol_amount, management_fee_in_sol_amount]
+    fn get_fee_amounts_as_sol<'info>(
         &self,
         ctx: &mut OperationCommandContext<'info, '_>,
         accounts: &[&'info AccountInfo<'info>],
-    ) -> Result<bool> {
+    ) -> Result<(u64, u64)> {
         let fund_account = ctx.fund_account.load()?;
 
         let is_sol_depositable = fund_account.sol.depositable == 1;
@@ -309,8 +357,13 @@ impl HarvestPerformanceFeeCommand {
             }
         }
 
-        if !(is_sol_depositable || is_all_supported_tokens_stakable) {
-            return Ok(false);
+        // performance fee is charged only on staking yield
+        let is_performance_fee_applicable = is_sol_depositable || is_all_supported_tokens_stakable;
+        let is_management_fee_applicable = fund_account.management_fee_rate_bps > 0
+            || fund_account.management_fee_accrued_amount_as_sol > 0;
+
+        if !(is_performance_fee_applicable || is_management_fee_applicable) {
+            return Ok((0, 0));
         }
 
         drop(fund_account);
@@ -319,17 +372,36 @@ impl HarvestPerformanceFeeCommand {
             .new_pricing_service(accounts.iter().copied(), true)?;
 
         let mut fund_account = ctx.fund_account.load_mut()?;
+        // fee must not be harvested at the deviated price, until the deviation is acknowledged.
+        if fund_account.is_price_deviation_circuit_breaker_tripped() {
+            return Ok((0, 0));
+        }
         let one_receipt_token_as_sol = fund_account.one_receipt_token_as_sol;
         if fund_account.performance_fee_rate_bps == 0 {
             fund_account.fee_harvested_one_receipt_token_as_sol = one_receipt_token_as_sol;
         }
 
-        if fund_account.fee_harvested_one_receipt_token_as_sol
-            >= fund_account.one_receipt_token_as_sol
+        let performance_fee_in_sol_amount = if is_performance_fee_applicable
+            && fund_account.fee_harvested_one_receipt_token_as_sol < one_receipt_token_as_sol
         {
-            return Ok(false);
-        }
+            let performance_gain_in_sol_amount = crate::utils::get_proportional_amount_u64(
+                ctx.receipt_token_mint.supply,
+                one_receipt_token_as_sol - fund_account.fee_harvested_one_receipt_token_as_sol,
+                LAMPORTS_PER_SOL,
+            )?;
+
+            crate::utils::get_proportional_amount_u64(
+                performance_gain_in_sol_amount,
+                fund_account.performance_fee_rate_bps as u64,
+                10_000,
+            )?
+        } else {
+            0
+        };
+
+        let management_fee_in_sol_amount =
+            fund_account.get_management_fee_amount_as_sol(Clock::get()?.unix_timestamp)?;
 
-        Ok(true)
+        Ok((performance_fee_in_sol_amount, management_fee_in_sol_amount))
     }
 }
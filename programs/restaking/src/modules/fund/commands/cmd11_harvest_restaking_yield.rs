@@ -2,9 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
 use anchor_spl::token_interface::TokenAccount;
 
-use crate::constants::PROGRAM_REVENUE_ADDRESS;
 use crate::errors::ErrorCode;
-use crate::modules::pricing::TokenPricingSource;
+use crate::modules::pricing::{PricingService, TokenPricingSource};
 use crate::modules::restaking::{
     JitoRestakingVaultService, SolvBTCVaultService, VirtualVaultService,
 };
@@ -151,6 +150,7 @@ pub struct HarvestRestakingYieldCommandResult {
     pub yield_token_mint: Pubkey,
     pub yield_token_total_harvested_amount: i128,
     pub yield_token_commission_amount: u64,
+    pub yield_token_commission_shares: Vec<FundRevenueShare>,
     pub fund_supported_token_compounded_amount: u64,
     pub swapped_token_mint: Option<Pubkey>,
     pub reward_token_distributed_amount: u64,
@@ -158,6 +158,15 @@ pub struct HarvestRestakingYieldCommandResult {
     pub distributing_reward_settlement_block_slot_and_contribution:
         Option<RewardSettlementBlockSlotAndContribution>,
     pub vault_supported_token_compounded_amount: i128,
+    pub swap_rejected_reason: Option<TokenSwapRejectedReason>,
+}
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum TokenSwapRejectedReason {
+    /// Expected output cannot be quoted since the pricing source of reward token is not configured.
+    QuoteUnavailable,
+    /// Pool price is already worse than the max slippage of the token swap strategy allows.
+    SlippageExceeded,
 }
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
@@ -242,10 +251,20 @@ impl SelfExecutable for HarvestRestakingYieldCommand {
             }
         };
 
-        Ok((
-            result,
-            entry.or_else(|| Some(StakeSOLCommand::default().without_required_accounts())),
-        ))
+        Ok((result, entry.or_else(|| self.next_command_of_cycle())))
+    }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(StakeSOLCommand::default().without_required_accounts())
+    }
+
+    fn plan(&self, ctx: &OperationCommandContext) -> PlanResult {
+        let entry = match &self.state {
+            New | NewCompoundReward => self.execute_new_compound_reward_command(ctx, None, None)?.1,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(entry.or_else(|| self.next_command_of_cycle())))
     }
 }
 
@@ -674,7 +693,10 @@ impl HarvestRestakingYieldCommand {
                 vault_reward_token_account,
                 &vault_reward_token_account_signer,
             )
-            .chain(CommissionAccounts::find_accounts(reward_token_mint))
+            .chain(CommissionAccounts::find_accounts(
+                &fund_account,
+                reward_token_mint,
+            ))
             .chain([(fund_supported_token_reserve_account, true)]);
 
             let command = Self {
@@ -723,13 +745,22 @@ impl HarvestRestakingYieldCommand {
                     from_reward_token_account,
                     from_reward_token_account_signer.key,
                 )
-                .chain(CommissionAccounts::find_accounts(reward_token_mint))
+                .chain(CommissionAccounts::find_accounts(
+                    &fund_account,
+                    reward_token_mint,
+                ))
                 .chain([(fund_supported_token_reserve_account, true)])
                 .chain(OrcaDEXLiquidityPoolService::find_accounts_to_swap(
                     pool_account,
                     reward_token_mint,
                     supported_token_mint,
-                )?);
+                )?)
+                .chain(
+                    swap_strategy
+                        .from_token_pricing_source
+                        .address()
+                        .map(|address| (address, false)),
+                );
 
                 let command = Self {
                     state: ExecuteSwap {
@@ -886,25 +917,37 @@ impl HarvestRestakingYieldCommand {
             }
         }
 
-        let program_reward_token_revenue_account =
-            anchor_spl::associated_token::get_associated_token_address_with_program_id(
-                &PROGRAM_REVENUE_ADDRESS,
-                reward_token_mint.key,
-                reward_token_mint.owner,
-            );
+        // to claim remaining reward
+        let fund_account = ctx.fund_account.load()?;
+        let revenue_recipient_reward_token_accounts = fund_account
+            .get_revenue_recipients_iter()
+            .map(|(revenue_recipient, _)| {
+                (
+                    anchor_spl::associated_token::get_associated_token_address_with_program_id(
+                        &revenue_recipient,
+                        reward_token_mint.key,
+                        reward_token_mint.owner,
+                    ),
+                    true,
+                )
+            })
+            .collect::<Vec<_>>();
 
         let required_accounts = CommonAccounts::find_accounts(
             reward_token_mint,
             vault_reward_token_account,
             &vault_reward_token_account_signer,
         )
-        .chain(CommissionAccounts::find_accounts(reward_token_mint))
+        .chain(CommissionAccounts::find_accounts(
+            &fund_account,
+            reward_token_mint,
+        ))
         .chain([
             (reward_token_reserve_account.key(), true),
             (reward_account.key(), true),
             (reward_reserve_account, false),
-            (program_reward_token_revenue_account, true),
-        ]);
+        ])
+        .chain(revenue_recipient_reward_token_accounts);
 
         let command = Self {
             state: ExecuteDistributeReward {
@@ -996,6 +1039,10 @@ impl HarvestRestakingYieldCommand {
                 .new_pricing_service(accounts.iter().copied(), true)?;
 
             let fund_account = ctx.fund_account.load()?;
+            // yield must not be harvested at the deviated price, until the deviation is acknowledged.
+            if fund_account.is_price_deviation_circuit_breaker_tripped() {
+                return Ok((None, None));
+            }
             let one_receipt_token_as_sol_before_token_compounded =
                 fund_account.one_receipt_token_as_sol;
 
@@ -1090,12 +1137,15 @@ impl HarvestRestakingYieldCommand {
                             + token_compounded_amount)
                             as i128,
                         yield_token_commission_amount: token_commission_amount,
+                        yield_token_commission_shares: fund_account
+                            .split_revenue(token_commission_amount)?,
                         fund_supported_token_compounded_amount: token_compounded_amount,
                         swapped_token_mint: None,
                         reward_token_distributed_amount: 0,
                         updated_reward_account: None,
                         distributing_reward_settlement_block_slot_and_contribution: None,
                         vault_supported_token_compounded_amount: 0,
+                        swap_rejected_reason: None,
                     }
                     .into(),
                 )
@@ -1149,6 +1199,10 @@ impl HarvestRestakingYieldCommand {
                 .new_pricing_service(accounts.iter().copied(), true)?;
 
             let fund_account = ctx.fund_account.load()?;
+            // yield must not be harvested at the deviated price, until the deviation is acknowledged.
+            if fund_account.is_price_deviation_circuit_breaker_tripped() {
+                return Ok((None, None));
+            }
             let one_receipt_token_as_sol_before_token_compounded =
                 fund_account.one_receipt_token_as_sol;
 
@@ -1163,50 +1217,56 @@ impl HarvestRestakingYieldCommand {
                 .receipt_token_pricing_source
                 .try_deserialize()?;
 
-            let (token_commission_amount, reward_token_amount, token_compounded_amount) =
-                match receipt_token_pricing_source {
-                    Some(TokenPricingSource::JitoRestakingVault { .. })
-                    | Some(TokenPricingSource::SolvBTCVault { .. }) => self
-                        .apply_commission_and_swap_reward(
-                            ctx,
-                            &mut accounts,
-                            &common_accounts,
-                            vault,
-                            &reward_token_mints[0],
-                            &fund_account.get_seeds(),
-                            &fund_supported_token_reserve_account_address,
-                            available_reward_token_amount_to_harvest,
-                        )?,
-                    Some(TokenPricingSource::VirtualVault { .. }) => self
-                        .apply_commission_and_swap_reward(
-                            ctx,
-                            &mut accounts,
-                            &common_accounts,
-                            vault,
-                            &reward_token_mints[0],
-                            &VirtualVaultService::find_vault_address(
-                                &restaking_vault.receipt_token_mint,
-                                &ctx.fund_account.key(),
-                            )
-                            .get_seeds(),
-                            &fund_supported_token_reserve_account_address,
-                            available_reward_token_amount_to_harvest,
-                        )?,
-                    // otherwise fails
-                    Some(TokenPricingSource::SPLStakePool { .. })
-                    | Some(TokenPricingSource::MarinadeStakePool { .. })
-                    | Some(TokenPricingSource::SanctumSingleValidatorSPLStakePool { .. })
-                    | Some(TokenPricingSource::SanctumMultiValidatorSPLStakePool { .. })
-                    | Some(TokenPricingSource::FragmetricNormalizedTokenPool { .. })
-                    | Some(TokenPricingSource::FragmetricRestakingFund { .. })
-                    | Some(TokenPricingSource::OrcaDEXLiquidityPool { .. })
-                    | Some(TokenPricingSource::PeggedToken { .. })
-                    | None => err!(ErrorCode::FundOperationCommandExecutionFailedException)?,
-                    #[cfg(all(test, not(feature = "idl-build")))]
-                    Some(TokenPricingSource::Mock { .. }) => {
-                        err!(ErrorCode::FundOperationCommandExecutionFailedException)?
-                    }
-                };
+            let (
+                token_commission_amount,
+                reward_token_amount,
+                token_compounded_amount,
+                swap_rejected_reason,
+            ) = match receipt_token_pricing_source {
+                Some(TokenPricingSource::JitoRestakingVault { .. })
+                | Some(TokenPricingSource::SolvBTCVault { .. }) => self
+                    .apply_commission_and_swap_reward(
+                        ctx,
+                        &mut accounts,
+                        &common_accounts,
+                        &mut pricing_service,
+                        vault,
+                        &reward_token_mints[0],
+                        &fund_account.get_seeds(),
+                        &fund_supported_token_reserve_account_address,
+                        available_reward_token_amount_to_harvest,
+                    )?,
+                Some(TokenPricingSource::VirtualVault { .. }) => self
+                    .apply_commission_and_swap_reward(
+                        ctx,
+                        &mut accounts,
+                        &common_accounts,
+                        &mut pricing_service,
+                        vault,
+                        &reward_token_mints[0],
+                        &VirtualVaultService::find_vault_address(
+                            &restaking_vault.receipt_token_mint,
+                            &ctx.fund_account.key(),
+                        )
+                        .get_seeds(),
+                        &fund_supported_token_reserve_account_address,
+                        available_reward_token_amount_to_harvest,
+                    )?,
+                // otherwise fails
+                Some(TokenPricingSource::SPLStakePool { .. })
+                | Some(TokenPricingSource::MarinadeStakePool { .. })
+                | Some(TokenPricingSource::SanctumSingleValidatorSPLStakePool { .. })
+                | Some(TokenPricingSource::SanctumMultiValidatorSPLStakePool { .. })
+                | Some(TokenPricingSource::FragmetricNormalizedTokenPool { .. })
+                | Some(TokenPricingSource::FragmetricRestakingFund { .. })
+                | Some(TokenPricingSource::OrcaDEXLiquidityPool { .. })
+                | Some(TokenPricingSource::PeggedToken { .. })
+                | None => err!(ErrorCode::FundOperationCommandExecutionFailedException)?,
+                #[cfg(all(test, not(feature = "idl-build")))]
+                Some(TokenPricingSource::Mock { .. }) => {
+                    err!(ErrorCode::FundOperationCommandExecutionFailedException)?
+                }
+            };
 
             drop(fund_account);
 
@@ -1249,12 +1309,34 @@ impl HarvestRestakingYieldCommand {
                             + reward_token_amount)
                             as i128,
                         yield_token_commission_amount: token_commission_amount,
+                        yield_token_commission_shares: fund_account
+                            .split_revenue(token_commission_amount)?,
                         swapped_token_mint: Some(supported_token_mint),
                         fund_supported_token_compounded_amount: token_compounded_amount,
                         reward_token_distributed_amount: 0,
                         updated_reward_account: None,
                         distributing_reward_settlement_block_slot_and_contribution: None,
                         vault_supported_token_compounded_amount: 0,
+                        swap_rejected_reason,
+                    }
+                    .into(),
+                )
+            } else if swap_rejected_reason.is_some() {
+                // report rejected swap so that operator can adjust the strategy
+                Some(
+                    HarvestRestakingYieldCommandResult {
+                        vault: *vault,
+                        yield_token_mint: reward_token_mints[0],
+                        yield_token_total_harvested_amount: 0,
+                        yield_token_commission_amount: 0,
+                        yield_token_commission_shares: vec![],
+                        swapped_token_mint: Some(supported_token_mint),
+                        fund_supported_token_compounded_amount: 0,
+                        reward_token_distributed_amount: 0,
+                        updated_reward_account: None,
+                        distributing_reward_settlement_block_slot_and_contribution: None,
+                        vault_supported_token_compounded_amount: 0,
+                        swap_rejected_reason,
                     }
                     .into(),
                 )
@@ -1340,8 +1422,10 @@ impl HarvestRestakingYieldCommand {
         let result = (|| {
             if available_reward_token_amount_to_harvest > 0 {
                 // get reward related accounts in advance for validation
-                let [_, _, _, _, reward_token_reserve_account, reward_account, reward_reserve_account, ..] =
-                    accounts
+                let num_commission_accounts =
+                    2 + ctx.fund_account.load()?.get_num_revenue_recipients() * 2;
+                let [reward_token_reserve_account, reward_account, reward_reserve_account, ..] =
+                    accounts.get(num_commission_accounts..).unwrap_or_default()
                 else {
                     err!(error::ErrorCode::AccountNotEnoughKeys)?
                 };
@@ -1420,22 +1504,38 @@ impl HarvestRestakingYieldCommand {
                 drop(fund_account);
 
                 Ok(if token_distributed_amount > 0 {
-                    let [_, _, program_reward_token_revenue_account, ..] = accounts else {
+                    let [_, _, revenue_recipient_reward_token_accounts @ ..] = accounts else {
                         return err!(error::ErrorCode::AccountNotEnoughKeys)?;
                     };
 
-                    require_keys_eq!(
-                        program_reward_token_revenue_account.key(),
-                        anchor_spl::associated_token::get_associated_token_address_with_program_id(
-                            &PROGRAM_REVENUE_ADDRESS,
-                            common_accounts.reward_token_mint.key,
-                            common_accounts.reward_token_program.key,
-                        )
+                    let revenue_reward_token_accounts = revenue_recipient_reward_token_accounts
+                        .iter()
+                        .zip(ctx.fund_account.load()?.get_revenue_recipients_iter())
+                        .map(|(revenue_recipient_reward_token_account, (revenue_recipient, share_bps))| {
+                            require_keys_eq!(
+                                revenue_recipient_reward_token_account.key(),
+                                anchor_spl::associated_token::get_associated_token_address_with_program_id(
+                                    &revenue_recipient,
+                                    common_accounts.reward_token_mint.key,
+                                    common_accounts.reward_token_program.key,
+                                )
+                            );
+
+                            Ok((
+                                InterfaceAccount::<TokenAccount>::try_from(
+                                    *revenue_recipient_reward_token_account,
+                                )?,
+                                share_bps,
+                            ))
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    require_eq!(
+                        revenue_reward_token_accounts.len(),
+                        ctx.fund_account.load()?.get_num_revenue_recipients(),
+                        error::ErrorCode::AccountNotEnoughKeys,
                     );
 
                     let reward_reserve_account = SystemAccount::try_from(reward_reserve_account)?;
-                    let program_reward_token_revenue_account =
-                        InterfaceAccount::try_from(program_reward_token_revenue_account)?;
                     let reward_token_mint =
                         InterfaceAccount::try_from(common_accounts.reward_token_mint)?;
                     let reward_token_reserve_account =
@@ -1471,7 +1571,7 @@ impl HarvestRestakingYieldCommand {
                         &reward_token_program,
                         &reward_reserve_account,
                         &reward_token_reserve_account,
-                        &program_reward_token_revenue_account,
+                        &revenue_reward_token_accounts,
                     )?;
 
                     ctx.fund_account
@@ -1488,6 +1588,10 @@ impl HarvestRestakingYieldCommand {
                                 + token_distributed_amount)
                                 as i128,
                             yield_token_commission_amount: token_commission_amount,
+                            yield_token_commission_shares: ctx
+                                .fund_account
+                                .load()?
+                                .split_revenue(token_commission_amount)?,
                             fund_supported_token_compounded_amount: 0,
                             swapped_token_mint: None,
                             reward_token_distributed_amount: token_distributed_amount,
@@ -1503,6 +1607,7 @@ impl HarvestRestakingYieldCommand {
                                 },
                             ),
                             vault_supported_token_compounded_amount: 0,
+                            swap_rejected_reason: None,
                         }
                         .into(),
                     )
@@ -1616,12 +1721,14 @@ impl HarvestRestakingYieldCommand {
                     yield_token_mint: supported_token_mint,
                     yield_token_total_harvested_amount: vault_supported_token_compounded_amount,
                     yield_token_commission_amount: 0,
+                    yield_token_commission_shares: vec![],
                     fund_supported_token_compounded_amount: 0,
                     swapped_token_mint: None,
                     reward_token_distributed_amount: 0,
                     updated_reward_account: None,
                     distributing_reward_settlement_block_slot_and_contribution: None,
                     vault_supported_token_compounded_amount,
+                    swap_rejected_reason: None,
                 }
                 .into(),
             )
@@ -1730,38 +1837,64 @@ impl HarvestRestakingYieldCommand {
         Ok((deducted_amount, transferred_amount))
     }
 
-    /// returns [deducted_amount, reward_token_amount, swapped_token_amount]
+    /// The swap might be rejected or shrunk by the slippage protection,
+    /// so commission is only taken for the portion of reward actually swapped.
+    ///
+    /// returns [deducted_amount, reward_token_amount, swapped_token_amount, swap_rejected_reason]
     fn apply_commission_and_swap_reward<'info>(
         &self,
         ctx: &OperationCommandContext<'info, '_>,
         accounts: &mut &[&'info AccountInfo<'info>],
         common_accounts: &CommonAccounts<'info>,
+        pricing_service: &mut PricingService<'info>,
         vault: &Pubkey,
         mint: &Pubkey,
         from_reward_token_account_signer_seeds: &[&[u8]],
         to_supported_token_account_address: &Pubkey,
         reward_token_amount: u64,
-    ) -> Result<(u64, u64, u64)> {
-        let deducted_amount = self.apply_commission(
-            ctx,
+    ) -> Result<(u64, u64, u64, Option<TokenSwapRejectedReason>)> {
+        let commission_accounts = CommissionAccounts::pop_from(
             accounts,
-            common_accounts,
-            vault,
-            from_reward_token_account_signer_seeds,
-            reward_token_amount,
+            &ctx.fund_account.load()?,
+            common_accounts.reward_token_mint,
         )?;
+        let reward_commission_amount = ctx
+            .fund_account
+            .load()?
+            .get_restaking_vault(vault)?
+            .get_reward_commission_amount(reward_token_amount)?;
+        let reward_token_amount_to_swap = reward_token_amount - reward_commission_amount;
 
-        let (reward_token_amount, swapped_token_amount) = self.swap_reward(
+        let (reward_token_amount, swapped_token_amount, swap_rejected_reason) = self.swap_reward(
             ctx,
             accounts,
             common_accounts,
+            pricing_service,
             mint,
             from_reward_token_account_signer_seeds,
             to_supported_token_account_address,
-            reward_token_amount - deducted_amount,
+            reward_token_amount_to_swap,
+        )?;
+
+        let deducted_amount = crate::utils::get_proportional_amount_u64(
+            reward_commission_amount,
+            reward_token_amount,
+            reward_token_amount_to_swap,
+        )?;
+        self.transfer_commission(
+            ctx,
+            &commission_accounts,
+            common_accounts,
+            from_reward_token_account_signer_seeds,
+            deducted_amount,
         )?;
 
-        Ok((deducted_amount, reward_token_amount, swapped_token_amount))
+        Ok((
+            deducted_amount,
+            reward_token_amount,
+            swapped_token_amount,
+            swap_rejected_reason,
+        ))
     }
 
     /// returns deducted_amount
@@ -1774,62 +1907,89 @@ impl HarvestRestakingYieldCommand {
         from_reward_token_account_signer_seeds: &[&[u8]],
         reward_token_amount: u64,
     ) -> Result<u64> {
-        let commission_accounts =
-            CommissionAccounts::pop_from(accounts, common_accounts.reward_token_mint)?;
+        let commission_accounts = CommissionAccounts::pop_from(
+            accounts,
+            &ctx.fund_account.load()?,
+            common_accounts.reward_token_mint,
+        )?;
+
+        let reward_commission_amount = ctx
+            .fund_account
+            .load()?
+            .get_restaking_vault(vault)?
+            .get_reward_commission_amount(reward_token_amount)?;
+
+        self.transfer_commission(
+            ctx,
+            &commission_accounts,
+            common_accounts,
+            from_reward_token_account_signer_seeds,
+            reward_commission_amount,
+        )?;
+
+        Ok(reward_commission_amount)
+    }
 
-        if !commission_accounts
-            .program_reward_token_revenue_account
-            .is_initialized()
+    fn transfer_commission<'info>(
+        &self,
+        ctx: &OperationCommandContext<'info, '_>,
+        commission_accounts: &CommissionAccounts<'info>,
+        common_accounts: &CommonAccounts<'info>,
+        from_reward_token_account_signer_seeds: &[&[u8]],
+        reward_commission_amount: u64,
+    ) -> Result<()> {
+        let reward_commission_shares = ctx
+            .fund_account
+            .load()?
+            .split_revenue(reward_commission_amount)?;
+
+        for (
+            reward_commission_share,
+            (revenue_recipient_account, revenue_recipient_reward_token_account),
+        ) in reward_commission_shares
+            .iter()
+            .zip(&commission_accounts.revenue_recipient_accounts)
         {
-            anchor_spl::associated_token::create(CpiContext::new(
-                commission_accounts
-                    .associated_token_program
-                    .to_account_info(),
-                anchor_spl::associated_token::Create {
-                    payer: ctx.operator.to_account_info(),
-                    associated_token: commission_accounts
-                        .program_reward_token_revenue_account
-                        .to_account_info(),
-                    authority: commission_accounts
-                        .program_revenue_account
+            if !revenue_recipient_reward_token_account.is_initialized() {
+                anchor_spl::associated_token::create(CpiContext::new(
+                    commission_accounts
+                        .associated_token_program
                         .to_account_info(),
-                    mint: common_accounts.reward_token_mint.to_account_info(),
-                    system_program: commission_accounts.system_program.to_account_info(),
-                    token_program: common_accounts.reward_token_program.to_account_info(),
-                },
-            ))?;
-        }
-
-        let fund_account = ctx.fund_account.load()?;
-        let restaking_vault = fund_account.get_restaking_vault(vault)?;
-        let reward_commission_amount =
-            restaking_vault.get_reward_commission_amount(reward_token_amount)?;
-
-        if reward_commission_amount > 0 {
-            let reward_token_mint =
-                InterfaceAccount::<Mint>::try_from(common_accounts.reward_token_mint)?;
-
-            anchor_spl::token_interface::transfer_checked(
-                CpiContext::new_with_signer(
-                    common_accounts.reward_token_program.to_account_info(),
-                    anchor_spl::token_interface::TransferChecked {
-                        from: common_accounts.from_reward_token_account.to_account_info(),
-                        mint: reward_token_mint.to_account_info(),
-                        to: commission_accounts
-                            .program_reward_token_revenue_account
-                            .to_account_info(),
-                        authority: common_accounts
-                            .from_reward_token_account_signer
-                            .to_account_info(),
+                    anchor_spl::associated_token::Create {
+                        payer: ctx.operator.to_account_info(),
+                        associated_token: revenue_recipient_reward_token_account.to_account_info(),
+                        authority: revenue_recipient_account.to_account_info(),
+                        mint: common_accounts.reward_token_mint.to_account_info(),
+                        system_program: commission_accounts.system_program.to_account_info(),
+                        token_program: common_accounts.reward_token_program.to_account_info(),
                     },
-                    &[from_reward_token_account_signer_seeds],
-                ),
-                reward_commission_amount,
-                reward_token_mint.decimals,
-            )?;
+                ))?;
+            }
+
+            if reward_commission_share.amount > 0 {
+                let reward_token_mint =
+                    InterfaceAccount::<Mint>::try_from(common_accounts.reward_token_mint)?;
+
+                anchor_spl::token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        common_accounts.reward_token_program.to_account_info(),
+                        anchor_spl::token_interface::TransferChecked {
+                            from: common_accounts.from_reward_token_account.to_account_info(),
+                            mint: reward_token_mint.to_account_info(),
+                            to: revenue_recipient_reward_token_account.to_account_info(),
+                            authority: common_accounts
+                                .from_reward_token_account_signer
+                                .to_account_info(),
+                        },
+                        &[from_reward_token_account_signer_seeds],
+                    ),
+                    reward_commission_share.amount,
+                    reward_token_mint.decimals,
+                )?;
+            }
         }
 
-        Ok(reward_commission_amount)
+        Ok(())
     }
 
     /// returns transferred_token_amount
@@ -1875,22 +2035,37 @@ impl HarvestRestakingYieldCommand {
         Ok(amount_to_transfer)
     }
 
-    /// returns [reward_token_amount, swapped_token_amount]
+    /// returns [reward_token_amount, swapped_token_amount, swap_rejected_reason]
     fn swap_reward<'info>(
         &self,
         ctx: &OperationCommandContext<'info, '_>,
         accounts: &mut &[&'info AccountInfo<'info>],
         common_accounts: &CommonAccounts<'info>,
+        pricing_service: &mut PricingService<'info>,
         mint: &Pubkey,
         from_reward_token_account_signer_seeds: &[&[u8]],
         to_supported_token_account_address: &Pubkey,
 
         amount_to_swap: u64,
-    ) -> Result<(u64, u64)> {
+    ) -> Result<(u64, u64, Option<TokenSwapRejectedReason>)> {
         let fund_account = ctx.fund_account.load()?;
         let swap_strategy = fund_account.get_token_swap_strategy(mint)?;
         let swap_source = swap_strategy.swap_source.try_deserialize()?;
 
+        // quote expected output with fund's token values, not with the swap source itself.
+        let min_to_token_amount = match swap_strategy.from_token_pricing_source.try_deserialize()? {
+            Some(from_token_pricing_source) => {
+                pricing_service.resolve_token_pricing_source(mint, &from_token_pricing_source)?;
+                let expected_to_token_amount = pricing_service.get_token_amount_as_token(
+                    mint,
+                    amount_to_swap,
+                    &swap_strategy.to_token_mint,
+                )?;
+                Some(swap_strategy.get_min_to_token_amount(expected_to_token_amount)?)
+            }
+            None => None,
+        };
+
         match swap_source {
             TokenSwapSource::OrcaDEXLiquidityPool { address } => {
                 let [to_supported_token_account, pool_program, pool_account, token_mint_a, token_vault_a, token_program_a, token_mint_b, token_vault_b, token_program_b, memo_program, oracle, tick_array_0, tick_array_1, tick_array_2, remaining_accounts @ ..] =
@@ -1906,6 +2081,10 @@ impl HarvestRestakingYieldCommand {
                     *to_supported_token_account_address,
                 );
 
+                let Some(min_to_token_amount) = min_to_token_amount else {
+                    return Ok((0, 0, Some(TokenSwapRejectedReason::QuoteUnavailable)));
+                };
+
                 let (from_reward_token_swapped_amount, to_supported_token_swapped_amount) =
                     OrcaDEXLiquidityPoolService::new(
                         pool_program,
@@ -1928,11 +2107,17 @@ impl HarvestRestakingYieldCommand {
                         common_accounts.from_reward_token_account_signer,
                         &[from_reward_token_account_signer_seeds],
                         amount_to_swap,
+                        min_to_token_amount,
                     )?;
 
+                let swap_rejected_reason = (amount_to_swap > 0
+                    && from_reward_token_swapped_amount == 0)
+                    .then_some(TokenSwapRejectedReason::SlippageExceeded);
+
                 Ok((
                     from_reward_token_swapped_amount,
                     to_supported_token_swapped_amount,
+                    swap_rejected_reason,
                 ))
             }
         }
@@ -2046,59 +2231,90 @@ impl<'info> CommonAccounts<'info> {
 
 /// manages additionally needed accounts for applying commission fee
 struct CommissionAccounts<'info> {
-    program_revenue_account: &'info AccountInfo<'info>,
-    program_reward_token_revenue_account: &'info AccountInfo<'info>,
     system_program: &'info AccountInfo<'info>,
     associated_token_program: &'info AccountInfo<'info>,
+    /// pairs of revenue recipient and its reward token account
+    revenue_recipient_accounts: Vec<(&'info AccountInfo<'info>, &'info AccountInfo<'info>)>,
 }
 
 impl<'info> CommissionAccounts<'info> {
-    /// * (0) program revenue account
-    /// * (1) program revenue reward token account
-    /// * (2) system program
-    /// * (3) associated token program
-    fn find_accounts(reward_token_mint: &AccountInfo) -> impl Iterator<Item = (Pubkey, bool)> {
+    /// * (0) system program
+    /// * (1) associated token program
+    /// * (2..) for each revenue recipient:
+    ///     * revenue recipient account
+    ///     * revenue recipient reward token account
+    fn find_accounts(
+        fund_account: &FundAccount,
+        reward_token_mint: &AccountInfo,
+    ) -> impl Iterator<Item = (Pubkey, bool)> {
         let required_accounts = [
-            (PROGRAM_REVENUE_ADDRESS, false),
-            (
-                associated_token::get_associated_token_address_with_program_id(
-                    &PROGRAM_REVENUE_ADDRESS,
-                    reward_token_mint.key,
-                    reward_token_mint.owner,
-                ),
-                true,
-            ),
             (System::id(), false),
             (anchor_spl::associated_token::ID, false),
         ]
-        .into_iter();
+        .into_iter()
+        .chain(
+            fund_account
+                .get_revenue_recipients_iter()
+                .flat_map(|(revenue_recipient, _)| {
+                    [
+                        (revenue_recipient, false),
+                        (
+                            associated_token::get_associated_token_address_with_program_id(
+                                &revenue_recipient,
+                                reward_token_mint.key,
+                                reward_token_mint.owner,
+                            ),
+                            true,
+                        ),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        );
 
         required_accounts
     }
 
-    fn pop_from(accounts: &mut &[&'info AccountInfo<'info>], mint: &AccountInfo) -> Result<Self> {
-        let [program_revenue_account, program_reward_token_revenue_account, system_program, associated_token_program, remaining_accounts @ ..] =
-            accounts
-        else {
+    fn pop_from(
+        accounts: &mut &[&'info AccountInfo<'info>],
+        fund_account: &FundAccount,
+        mint: &AccountInfo,
+    ) -> Result<Self> {
+        let [system_program, associated_token_program, remaining_accounts @ ..] = accounts else {
             err!(error::ErrorCode::AccountNotEnoughKeys)?
         };
         *accounts = remaining_accounts;
 
-        require_keys_eq!(program_revenue_account.key(), PROGRAM_REVENUE_ADDRESS);
-        require_keys_eq!(
-            program_reward_token_revenue_account.key(),
-            anchor_spl::associated_token::get_associated_token_address_with_program_id(
-                &PROGRAM_REVENUE_ADDRESS,
-                mint.key,
-                mint.owner,
-            )
-        );
+        let revenue_recipient_accounts = fund_account
+            .get_revenue_recipients_iter()
+            .map(|revenue_recipient| {
+                let [revenue_recipient_account, revenue_recipient_reward_token_account, remaining_accounts @ ..] =
+                    accounts
+                else {
+                    err!(error::ErrorCode::AccountNotEnoughKeys)?
+                };
+                *accounts = remaining_accounts;
+
+                require_keys_eq!(revenue_recipient_account.key(), revenue_recipient.0);
+                require_keys_eq!(
+                    revenue_recipient_reward_token_account.key(),
+                    anchor_spl::associated_token::get_associated_token_address_with_program_id(
+                        &revenue_recipient.0,
+                        mint.key,
+                        mint.owner,
+                    )
+                );
+
+                Ok((
+                    *revenue_recipient_account,
+                    *revenue_recipient_reward_token_account,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         Ok(Self {
-            program_revenue_account,
-            program_reward_token_revenue_account,
             system_program,
             associated_token_program,
+            revenue_recipient_accounts,
         })
     }
 }
@@ -94,10 +94,11 @@ impl SelfExecutable for StakeSOLCommand {
             }
         };
 
-        Ok((
-            result,
-            entry.or_else(|| Some(NormalizeSTCommand::default().without_required_accounts())),
-        ))
+        Ok((result, entry.or_else(|| self.next_command_of_cycle())))
+    }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(NormalizeSTCommand::default().without_required_accounts())
     }
 }
 
@@ -396,6 +397,7 @@ impl StakeSOLCommand {
             pool_token_mint,
             pool_token_program,
         )?;
+        spl_stake_pool_service.require_sol_depositable()?;
 
         let (
             to_pool_token_account_amount,
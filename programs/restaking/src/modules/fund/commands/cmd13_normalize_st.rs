@@ -96,10 +96,11 @@ impl SelfExecutable for NormalizeSTCommand {
             }
         };
 
-        Ok((
-            result,
-            entry.or_else(|| Some(RestakeVSTCommand::default().without_required_accounts())),
-        ))
+        Ok((result, entry.or_else(|| self.next_command_of_cycle())))
+    }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(RestakeVSTCommand::default().without_required_accounts())
     }
 }
 
@@ -304,6 +304,7 @@ impl SelfExecutable for RestakeVSTCommand {
                                 vault_config,
                                 vault_account,
                             )?;
+                            vault_service.require_vault_depositable()?;
 
                             let mut fund_account = ctx.fund_account.load_mut()?;
                             let restaking_vault =
@@ -522,9 +523,13 @@ impl SelfExecutable for RestakeVSTCommand {
         let remaining_items = remaining_items.unwrap_or_default();
         let entry = self
             .create_prepare_command(ctx, remaining_items)?
-            .unwrap_or_else(|| DelegateVSTCommand::default().without_required_accounts());
+            .or_else(|| self.next_command_of_cycle());
 
-        Ok((result, Some(entry)))
+        Ok((result, entry))
+    }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(DelegateVSTCommand::default().without_required_accounts())
     }
 }
 
@@ -69,6 +69,19 @@ impl SelfExecutable for DelegateVSTCommand {
 
         Ok((result, entry))
     }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        None
+    }
+
+    fn plan(&self, ctx: &OperationCommandContext) -> PlanResult {
+        let entry = match &self.state {
+            New => self.execute_new(ctx)?.1,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(entry.or_else(|| self.next_command_of_cycle())))
+    }
 }
 
 impl DelegateVSTCommand {
@@ -279,6 +292,7 @@ impl DelegateVSTCommand {
 
                 let vault_service =
                     JitoRestakingVaultService::new(vault_program, vault_config, vault_account)?;
+                vault_service.require_vault_not_paused()?;
 
                 for (i, item) in items.iter().take(batch_size).enumerate() {
                     let vault_operator_delegation = accounts_to_delegate[2 * i];
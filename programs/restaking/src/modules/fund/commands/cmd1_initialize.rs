@@ -180,12 +180,23 @@ impl SelfExecutable for InitializeCommand {
             )?,
         };
 
-        Ok((
-            result,
-            entry.or_else(|| {
-                Some(EnqueueWithdrawalBatchCommand::default().without_required_accounts())
-            }),
-        ))
+        Ok((result, entry.or_else(|| self.next_command_of_cycle())))
+    }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(EnqueueWithdrawalBatchCommand::default().without_required_accounts())
+    }
+
+    fn plan(&self, ctx: &OperationCommandContext) -> PlanResult {
+        let entry = match &self.state {
+            New | NewRestakingVaultUpdate => {
+                self.execute_new_restaking_vault_update_command(ctx, None, None)?
+                    .1
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(entry.or_else(|| self.next_command_of_cycle())))
     }
 }
 
@@ -355,6 +366,7 @@ impl InitializeCommand {
 
                 let vault_service =
                     JitoRestakingVaultService::new(vault_program, vault_config, vault_account)?;
+                vault_service.require_vault_not_paused()?;
 
                 vault_service.initialize_vault_update_state_tracker_if_needed(
                     ctx.system_program,
@@ -48,7 +48,11 @@ impl SelfExecutable for EnqueueWithdrawalBatchCommand {
             } else {
                 None
             },
-            Some(ClaimUnrestakedVSTCommand::default().without_required_accounts()),
+            self.next_command_of_cycle(),
         ))
     }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(ClaimUnrestakedVSTCommand::default().without_required_accounts())
+    }
 }
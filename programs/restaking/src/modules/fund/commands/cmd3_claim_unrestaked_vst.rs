@@ -62,10 +62,20 @@ impl SelfExecutable for ClaimUnrestakedVSTCommand {
             }
         };
 
-        Ok((
-            result,
-            entry.or_else(|| Some(DenormalizeNTCommand::default().without_required_accounts())),
-        ))
+        Ok((result, entry.or_else(|| self.next_command_of_cycle())))
+    }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(DenormalizeNTCommand::default().without_required_accounts())
+    }
+
+    fn plan(&self, ctx: &OperationCommandContext) -> PlanResult {
+        let entry = match &self.state {
+            ClaimUnrestakedVSTCommandState::New => self.execute_new(ctx, None, None)?.1,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(entry.or_else(|| self.next_command_of_cycle())))
     }
 }
 
@@ -302,6 +312,7 @@ impl ClaimUnrestakedVSTCommand {
 
                 let vault_service =
                     JitoRestakingVaultService::new(vault_program, vault_config, vault_account)?;
+                vault_service.require_vault_not_paused()?;
 
                 let mut claimable_withdrawal_ticket_indices = vec![];
                 for i in 0..5 {
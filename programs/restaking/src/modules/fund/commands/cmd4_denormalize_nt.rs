@@ -61,10 +61,11 @@ impl SelfExecutable for DenormalizeNTCommand {
             }
         };
 
-        Ok((
-            result,
-            entry.or_else(|| Some(ClaimUnstakedSOLCommand::default().without_required_accounts())),
-        ))
+        Ok((result, entry.or_else(|| self.next_command_of_cycle())))
+    }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(ClaimUnstakedSOLCommand::default().without_required_accounts())
     }
 }
 
@@ -118,12 +118,11 @@ impl SelfExecutable for ClaimUnstakedSOLCommand {
             )?,
         };
 
-        Ok((
-            result,
-            entry.or_else(|| {
-                Some(ProcessWithdrawalBatchCommand::default().without_required_accounts())
-            }),
-        ))
+        Ok((result, entry.or_else(|| self.next_command_of_cycle())))
+    }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(ProcessWithdrawalBatchCommand::default().without_required_accounts())
     }
 }
 
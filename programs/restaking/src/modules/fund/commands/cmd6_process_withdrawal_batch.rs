@@ -1,7 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::spl_associated_token_account;
 
-use crate::constants::PROGRAM_REVENUE_ADDRESS;
 use crate::errors;
 use crate::modules::pricing::TokenPricingSource;
 use crate::modules::restaking::{JitoRestakingVaultService, SolvBTCVaultService};
@@ -46,6 +45,7 @@ pub struct ProcessWithdrawalBatchCommandResult {
     pub deducted_asset_fee_amount: u64,
     pub offset_asset_receivables: Vec<ProcessWithdrawalBatchCommandResultAssetReceivable>,
     pub transferred_asset_revenue_amount: u64,
+    pub transferred_asset_revenue_shares: Vec<FundRevenueShare>,
     pub withdrawal_fee_rate_bps: u16,
 }
 
@@ -76,30 +76,31 @@ impl SelfExecutable for ProcessWithdrawalBatchCommand {
                 let requested_receipt_token_amount = fund_account
                     .get_asset_receipt_token_withdrawal_obligated_amount(asset_token_mint)?;
 
-                // to harvest program revenue (prepended)
-                required_accounts.insert(0, (PROGRAM_REVENUE_ADDRESS, true));
-                required_accounts.insert(
-                    1,
-                    asset_token_mint
-                        .map(|mint| {
-                            let supported_token = fund_account.get_supported_token(&mint)?;
-                            Ok::<(Pubkey, bool), Error>((
-                        spl_associated_token_account::get_associated_token_address_with_program_id(
-                            &PROGRAM_REVENUE_ADDRESS,
-                            &supported_token.mint,
-                            &supported_token.program,
-                        ),
-                        true,
-                    ))
-                        })
-                        .unwrap_or_else(|| Ok((Pubkey::default(), false)))?,
-                );
-                required_accounts.insert(
-                    2,
-                    asset_token_mint
-                        .map(|_| (spl_associated_token_account::ID, false))
-                        .unwrap_or_else(|| (Pubkey::default(), false)),
-                );
+                // to harvest revenue (prepended)
+                let supported_token = asset_token_mint
+                    .map(|mint| fund_account.get_supported_token(&mint))
+                    .transpose()?;
+                let mut revenue_accounts = vec![supported_token
+                    .map(|_| (spl_associated_token_account::ID, false))
+                    .unwrap_or_else(|| (Pubkey::default(), false))];
+                for (revenue_recipient, _) in fund_account.get_revenue_recipients_iter() {
+                    revenue_accounts.push((revenue_recipient, true));
+                    revenue_accounts.push(
+                        supported_token
+                            .map(|supported_token| {
+                                (
+                                    spl_associated_token_account::get_associated_token_address_with_program_id(
+                                        &revenue_recipient,
+                                        &supported_token.mint,
+                                        &supported_token.program,
+                                    ),
+                                    true,
+                                )
+                            })
+                            .unwrap_or_else(|| (Pubkey::default(), false)),
+                    );
+                }
+                required_accounts.splice(0..0, revenue_accounts);
 
                 // to calculate LST cycle fee (appended)
                 for supported_token in fund_account.get_supported_tokens_iter() {
@@ -185,8 +186,20 @@ impl SelfExecutable for ProcessWithdrawalBatchCommand {
                 num_processing_batches,
                 receipt_token_amount: requested_receipt_token_amount,
             } => {
-                let [program_revenue_account, program_supported_token_revenue_account, optional_associated_token_account_program, receipt_token_program, receipt_token_lock_account, fund_reserve_account, fund_treasury_account, optional_supported_token_mint, optional_supported_token_program, optional_fund_supported_token_reserve_account, optional_fund_supported_token_treasury_account, remaining_accounts @ ..] =
-                    accounts
+                let [optional_associated_token_account_program, remaining_accounts @ ..] = accounts
+                else {
+                    err!(error::ErrorCode::AccountNotEnoughKeys)?
+                };
+                let num_revenue_recipient_accounts =
+                    ctx.fund_account.load()?.get_num_revenue_recipients() * 2;
+                if remaining_accounts.len() < num_revenue_recipient_accounts {
+                    err!(error::ErrorCode::AccountNotEnoughKeys)?;
+                }
+                let (revenue_recipient_accounts, remaining_accounts) =
+                    remaining_accounts.split_at(num_revenue_recipient_accounts);
+
+                let [receipt_token_program, receipt_token_lock_account, fund_reserve_account, fund_treasury_account, optional_supported_token_mint, optional_supported_token_program, optional_fund_supported_token_reserve_account, optional_fund_supported_token_treasury_account, remaining_accounts @ ..] =
+                    remaining_accounts
                 else {
                     err!(error::ErrorCode::AccountNotEnoughKeys)?
                 };
@@ -421,7 +434,7 @@ impl SelfExecutable for ProcessWithdrawalBatchCommand {
                     reserved_asset_user_amount,
                     deducted_asset_fee_amount,
                     offset_asset_receivables,
-                    transferred_asset_revenue_amount,
+                    transferred_asset_revenue_shares,
                 ) = {
                     let mut fund_service =
                         FundService::new(ctx.receipt_token_mint, ctx.fund_account)?;
@@ -455,20 +468,19 @@ impl SelfExecutable for ProcessWithdrawalBatchCommand {
                     )?;
 
                     // harvest revenue from temporary treasury only if current pending batch processed
-                    let transferred_asset_revenue_amount = if processed_receipt_token_amount > 0 {
+                    let transferred_asset_revenue_shares = if processed_receipt_token_amount > 0 {
                         fund_service.harvest_from_treasury_account(
                             ctx.operator,
                             ctx.system_program,
                             fund_treasury_account,
-                            program_revenue_account,
+                            revenue_recipient_accounts,
                             optional_associated_token_account_program.to_option(),
                             optional_supported_token_mint.to_option(),
                             optional_supported_token_program.to_option(),
                             optional_fund_supported_token_treasury_account.to_option(),
-                            program_supported_token_revenue_account.to_option(),
                         )?
                     } else {
-                        0
+                        vec![]
                     };
 
                     fund_service.update_asset_values(&mut pricing_service, true)?;
@@ -479,7 +491,7 @@ impl SelfExecutable for ProcessWithdrawalBatchCommand {
                         reserved_asset_user_amount,
                         deducted_asset_fee_amount,
                         offset_asset_receivables,
-                        transferred_asset_revenue_amount,
+                        transferred_asset_revenue_shares,
                     )
                 };
 
@@ -501,7 +513,11 @@ impl SelfExecutable for ProcessWithdrawalBatchCommand {
                                 }
                             })
                             .collect::<Vec<_>>(),
-                        transferred_asset_revenue_amount,
+                        transferred_asset_revenue_amount: transferred_asset_revenue_shares
+                            .iter()
+                            .map(|revenue_share| revenue_share.amount)
+                            .sum(),
+                        transferred_asset_revenue_shares,
                         withdrawal_fee_rate_bps: ctx.fund_account.load()?.withdrawal_fee_rate_bps,
                     }
                     .into(),
@@ -550,11 +566,12 @@ impl SelfExecutable for ProcessWithdrawalBatchCommand {
                         .1,
                     )
                 }
-                None => (
-                    result,
-                    Some(UnstakeLSTCommand::default().without_required_accounts()),
-                ),
+                None => (result, self.next_command_of_cycle()),
             }
         })
     }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(UnstakeLSTCommand::default().without_required_accounts())
+    }
 }
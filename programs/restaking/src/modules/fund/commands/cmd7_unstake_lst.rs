@@ -152,10 +152,11 @@ impl SelfExecutable for UnstakeLSTCommand {
             )?,
         };
 
-        Ok((
-            result,
-            entry.or_else(|| Some(UnrestakeVRTCommand::default().without_required_accounts())),
-        ))
+        Ok((result, entry.or_else(|| self.next_command_of_cycle())))
+    }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(UnrestakeVRTCommand::default().without_required_accounts())
     }
 }
 
@@ -66,10 +66,11 @@ impl SelfExecutable for UnrestakeVRTCommand {
             }
         };
 
-        Ok((
-            result,
-            entry.or_else(|| Some(UndelegateVSTCommand::default().without_required_accounts())),
-        ))
+        Ok((result, entry.or_else(|| self.next_command_of_cycle())))
+    }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(UndelegateVSTCommand::default().without_required_accounts())
     }
 }
 
@@ -616,6 +617,7 @@ impl UnrestakeVRTCommand {
                 {
                     let vault_service =
                         JitoRestakingVaultService::new(vault_program, vault_config, vault_account)?;
+                    vault_service.require_vault_not_paused()?;
 
                     let mut fund_account = ctx.fund_account.load_mut()?;
                     let restaking_vault = fund_account.get_restaking_vault_mut(&item.vault)?;
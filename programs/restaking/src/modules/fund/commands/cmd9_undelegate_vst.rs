@@ -65,12 +65,20 @@ impl SelfExecutable for UndelegateVSTCommand {
             Execute { vaults, items } => self.execute_execute(ctx, accounts, vaults, items)?,
         };
 
-        Ok((
-            result,
-            entry.or_else(|| {
-                Some(HarvestPerformanceFeeCommand::default().without_required_accounts())
-            }),
-        ))
+        Ok((result, entry.or_else(|| self.next_command_of_cycle())))
+    }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        Some(HarvestPerformanceFeeCommand::default().without_required_accounts())
+    }
+
+    fn plan(&self, ctx: &OperationCommandContext) -> PlanResult {
+        let entry = match &self.state {
+            New => self.execute_new(ctx)?.1,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(entry.or_else(|| self.next_command_of_cycle())))
     }
 }
 
@@ -272,6 +280,7 @@ impl UndelegateVSTCommand {
 
                 let vault_service =
                     JitoRestakingVaultService::new(vault_program, vault_config, vault_account)?;
+                vault_service.require_vault_not_paused()?;
 
                 for (i, item) in items.iter().take(batch_size).enumerate() {
                     let vault_operator_delegation = accounts_to_undelegate[2 * i];
@@ -347,6 +347,28 @@ impl OperationCommand {
         }
     }
 
+    /// returns the command in its initial state.
+    pub fn try_default_from_discriminant(discriminant: u8) -> Option<Self> {
+        Some(match discriminant {
+            1 => InitializeCommand::default().into(),
+            2 => EnqueueWithdrawalBatchCommand::default().into(),
+            3 => ClaimUnrestakedVSTCommand::default().into(),
+            4 => DenormalizeNTCommand::default().into(),
+            5 => ClaimUnstakedSOLCommand::default().into(),
+            6 => ProcessWithdrawalBatchCommand::default().into(),
+            7 => UnstakeLSTCommand::default().into(),
+            8 => UnrestakeVRTCommand::default().into(),
+            9 => UndelegateVSTCommand::default().into(),
+            10 => HarvestPerformanceFeeCommand::default().into(),
+            11 => HarvestRestakingYieldCommand::default().into(),
+            12 => StakeSOLCommand::default().into(),
+            13 => NormalizeSTCommand::default().into(),
+            14 => RestakeVSTCommand::default().into(),
+            15 => DelegateVSTCommand::default().into(),
+            _ => return None,
+        })
+    }
+
     pub fn is_safe_with_unchecked_params(&self) -> bool {
         matches!(
             self,
@@ -362,6 +384,168 @@ impl OperationCommand {
     }
 }
 
+impl OperationCommandResult {
+    /// Compact summary of the result to be recorded in the operation history.
+    ///
+    /// returns (subject, key_amounts) where subject is the main token mint or vault of the command.
+    pub fn get_summary(&self) -> (Pubkey, [u64; 3]) {
+        match self {
+            Self::Initialize(result) => {
+                if let Some(vault) = &result.restaking_vault_updated {
+                    let (delegated_amount, undelegating_amount) = vault.delegations.iter().fold(
+                        (0u64, 0u64),
+                        |(delegated, undelegating), delegation| {
+                            (
+                                delegated.saturating_add(delegation.delegated_amount),
+                                undelegating.saturating_add(delegation.undelegating_amount),
+                            )
+                        },
+                    );
+                    (
+                        vault.vault,
+                        [vault.epoch, delegated_amount, undelegating_amount],
+                    )
+                } else if let Some(wrapped_token) = &result.wrapped_token_updated {
+                    let wrapped_token_amount = wrapped_token
+                        .wrapped_token_holders
+                        .iter()
+                        .fold(0u64, |sum, holder| {
+                            sum.saturating_add(holder.wrapped_token_amount)
+                        });
+                    (
+                        wrapped_token.receipt_token_mint,
+                        [
+                            wrapped_token.wrapped_token_holders.len() as u64,
+                            wrapped_token_amount,
+                            0,
+                        ],
+                    )
+                } else {
+                    (Pubkey::default(), [0; 3])
+                }
+            }
+            Self::EnqueueWithdrawalBatch(result) => (
+                Pubkey::default(),
+                [
+                    result.enqueued_receipt_token_amount,
+                    result.total_queued_receipt_token_amount,
+                    0,
+                ],
+            ),
+            Self::ClaimUnrestakedVST(result) => (
+                result.vault,
+                [
+                    result.unrestaked_receipt_token_amount,
+                    result.claimed_supported_token_amount,
+                    result.transferred_supported_token_revenue_amount,
+                ],
+            ),
+            Self::DenormalizeNT(result) => (
+                result.supported_token_mint,
+                [
+                    result.burnt_normalized_token_amount,
+                    result.denormalized_supported_token_amount,
+                    0,
+                ],
+            ),
+            Self::ClaimUnstakedSOL(result) => (
+                result.token_mint,
+                [
+                    result.claimed_sol_amount,
+                    result.transferred_sol_revenue_amount,
+                    result.total_unstaking_sol_amount,
+                ],
+            ),
+            Self::ProcessWithdrawalBatch(result) => (
+                result.asset_token_mint.unwrap_or_default(),
+                [
+                    result.processed_receipt_token_amount,
+                    result.reserved_asset_user_amount,
+                    result.deducted_asset_fee_amount,
+                ],
+            ),
+            Self::UnstakeLST(result) => (
+                result.token_mint,
+                [
+                    result.burnt_token_amount,
+                    result.unstaked_sol_amount,
+                    result.unstaking_sol_amount,
+                ],
+            ),
+            Self::UnrestakeVRT(result) => (
+                result.vault,
+                [
+                    result.unrestaking_token_amount,
+                    result.total_unrestaking_token_amount,
+                    0,
+                ],
+            ),
+            Self::UndelegateVST(result) => (
+                result.vault,
+                [
+                    result.undelegations.iter().fold(0u64, |sum, undelegation| {
+                        sum.saturating_add(undelegation.undelegation_requested_token_amount)
+                    }),
+                    0,
+                    0,
+                ],
+            ),
+            Self::HarvestPerformanceFee(result) => (
+                result.receipt_token_mint,
+                [
+                    result.receipt_token_minted_amount,
+                    result.performance_fee_receipt_token_minted_amount,
+                    result.management_fee_receipt_token_minted_amount,
+                ],
+            ),
+            Self::HarvestRestakingYield(result) => (
+                result.yield_token_mint,
+                [
+                    result
+                        .yield_token_total_harvested_amount
+                        .clamp(0, u64::MAX as i128) as u64,
+                    result.yield_token_commission_amount,
+                    result.reward_token_distributed_amount,
+                ],
+            ),
+            Self::StakeSOL(result) => (
+                result.token_mint,
+                [
+                    result.staked_sol_amount,
+                    result.minted_token_amount,
+                    result.deducted_sol_fee_amount,
+                ],
+            ),
+            Self::NormalizeST(result) => (
+                result.supported_token_mint,
+                [
+                    result.normalized_supported_token_amount,
+                    result.minted_token_amount,
+                    0,
+                ],
+            ),
+            Self::RestakeVST(result) => (
+                result.supported_token_mint,
+                [
+                    result.deposited_supported_token_amount,
+                    result.minted_token_amount,
+                    result.deducted_supported_token_fee_amount,
+                ],
+            ),
+            Self::DelegateVST(result) => (
+                result.vault,
+                [
+                    result.delegations.iter().fold(0u64, |sum, delegation| {
+                        sum.saturating_add(delegation.delegated_token_amount)
+                    }),
+                    0,
+                    0,
+                ],
+            ),
+        }
+    }
+}
+
 const FUND_ACCOUNT_OPERATION_COMMAND_BUFFER_SIZE: usize = 3126;
 
 /// Pod type of `Option<OperationCommand>`
@@ -484,6 +668,24 @@ impl OperationCommandEntry {
     }
 }
 
+/// Predicted sequence of operation commands, returned by `operator_plan_fund_commands`.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug)]
+pub struct OperationCommandPlan {
+    pub receipt_token_mint: Pubkey,
+    /// The entry following the last step depends on the execution of the last step, unless completed.
+    pub steps: Vec<OperationCommandPlanStep>,
+    /// Whether the plan reaches the end of the operation cycle.
+    pub completed: bool,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug)]
+pub struct OperationCommandPlanStep {
+    pub sequence: u16,
+    /// discriminant of [`OperationCommand`]
+    pub command: u8,
+    pub required_accounts: Vec<OperationCommandAccountMeta>,
+}
+
 /// Pod type of `Option<OperationCommandEntry>`
 #[zero_copy]
 pub struct OperationCommandEntryPod {
@@ -548,6 +750,46 @@ impl SelfExecutable for OperationCommand {
             OperationCommand::DelegateVST(command) => command.execute(ctx, accounts),
         }
     }
+
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry> {
+        match self {
+            OperationCommand::Initialize(command) => command.next_command_of_cycle(),
+            OperationCommand::EnqueueWithdrawalBatch(command) => command.next_command_of_cycle(),
+            OperationCommand::ClaimUnrestakedVST(command) => command.next_command_of_cycle(),
+            OperationCommand::DenormalizeNT(command) => command.next_command_of_cycle(),
+            OperationCommand::ClaimUnstakedSOL(command) => command.next_command_of_cycle(),
+            OperationCommand::ProcessWithdrawalBatch(command) => command.next_command_of_cycle(),
+            OperationCommand::UnstakeLST(command) => command.next_command_of_cycle(),
+            OperationCommand::UnrestakeVRT(command) => command.next_command_of_cycle(),
+            OperationCommand::UndelegateVST(command) => command.next_command_of_cycle(),
+            OperationCommand::HarvestPerformanceFee(command) => command.next_command_of_cycle(),
+            OperationCommand::HarvestRestakingYield(command) => command.next_command_of_cycle(),
+            OperationCommand::StakeSOL(command) => command.next_command_of_cycle(),
+            OperationCommand::NormalizeST(command) => command.next_command_of_cycle(),
+            OperationCommand::RestakeVST(command) => command.next_command_of_cycle(),
+            OperationCommand::DelegateVST(command) => command.next_command_of_cycle(),
+        }
+    }
+
+    fn plan(&self, ctx: &OperationCommandContext) -> PlanResult {
+        match self {
+            OperationCommand::Initialize(command) => command.plan(ctx),
+            OperationCommand::EnqueueWithdrawalBatch(command) => command.plan(ctx),
+            OperationCommand::ClaimUnrestakedVST(command) => command.plan(ctx),
+            OperationCommand::DenormalizeNT(command) => command.plan(ctx),
+            OperationCommand::ClaimUnstakedSOL(command) => command.plan(ctx),
+            OperationCommand::ProcessWithdrawalBatch(command) => command.plan(ctx),
+            OperationCommand::UnstakeLST(command) => command.plan(ctx),
+            OperationCommand::UnrestakeVRT(command) => command.plan(ctx),
+            OperationCommand::UndelegateVST(command) => command.plan(ctx),
+            OperationCommand::HarvestPerformanceFee(command) => command.plan(ctx),
+            OperationCommand::HarvestRestakingYield(command) => command.plan(ctx),
+            OperationCommand::StakeSOL(command) => command.plan(ctx),
+            OperationCommand::NormalizeST(command) => command.plan(ctx),
+            OperationCommand::RestakeVST(command) => command.plan(ctx),
+            OperationCommand::DelegateVST(command) => command.plan(ctx),
+        }
+    }
 }
 
 type ExecutionResult = Result<(
@@ -555,6 +797,8 @@ type ExecutionResult = Result<(
     Option<OperationCommandEntry>,
 )>;
 
+type PlanResult = Result<Option<Option<OperationCommandEntry>>>;
+
 pub(super) trait SelfExecutable: Into<OperationCommand> {
     fn execute<'info>(
         &self,
@@ -562,6 +806,15 @@ pub(super) trait SelfExecutable: Into<OperationCommand> {
         accounts: &[&'info AccountInfo<'info>],
     ) -> ExecutionResult;
 
+    /// The entry of the next command type in the operation cycle, which this command transitions to after it's done.
+    fn next_command_of_cycle(&self) -> Option<OperationCommandEntry>;
+
+    /// Finds the entry this command transitions to without executing it, to plan the operation commands.
+    /// It is `None` if the entry depends on the execution, which is the default.
+    fn plan(&self, _ctx: &OperationCommandContext) -> PlanResult {
+        Ok(None)
+    }
+
     fn with_required_accounts(
         self,
         required_accounts: impl IntoIterator<Item = (Pubkey, bool)>,
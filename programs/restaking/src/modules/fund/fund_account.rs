@@ -7,10 +7,13 @@ use anchor_spl::token_interface::Mint;
 use bytemuck::Zeroable;
 
 use crate::errors::ErrorCode;
-use crate::modules::pricing::{PricingService, TokenPricingSource, TokenValuePod};
+use crate::modules::pricing::{
+    PricingService, TokenPricingSource, TokenPricingSourcePod, TokenValuePod,
+};
 use crate::modules::swap::TokenSwapSource;
 use crate::utils::*;
 
+use super::commands::OperationCommandEntry;
 use super::*;
 
 #[constant]
@@ -23,10 +26,13 @@ pub const FUND_ACCOUNT_CURRENT_VERSION: u16 = 19;
 
 pub const FUND_WITHDRAWAL_FEE_RATE_BPS_LIMIT: u16 = 500;
 pub const FUND_PERFORMANCE_FEE_RATE_BPS_LIMIT: u16 = 10_000;
+/// annual rate
+pub const FUND_MANAGEMENT_FEE_RATE_BPS_LIMIT: u16 = 500;
 pub const FUND_ACCOUNT_MAX_SUPPORTED_TOKENS: usize = 16;
 pub const FUND_ACCOUNT_MAX_RESTAKING_VAULTS: usize = 16;
 pub const FUND_ACCOUNT_MAX_PRICING_SOURCE_ADDRESSES: usize = 33;
 pub const FUND_ACCOUNT_MAX_TOKEN_SWAP_STRATEGIES: usize = 30;
+pub const FUND_ACCOUNT_MAX_CONFIGURATION_CHANGE_DELAY_SECONDS: i64 = 30 * 24 * 60 * 60;
 
 #[account(zero_copy)]
 #[repr(C)]
@@ -115,7 +121,60 @@ pub struct FundAccount {
     pub(super) performance_fee_last_harvested_at: i64,
     pub(super) performance_fee_rate_bps: u16,
 
-    _reserved1: [u8; 3590],
+    /// Timelock for material configuration changes, see [`FundConfigurationChangeAccount`].
+    /// - `configuration_change_delay_seconds`: delay between queueing and executing a change (0 = disabled)
+    /// - `next_configuration_change_id`: id of the next change to queue
+    _padding7: [u8; 6],
+    configuration_change_delay_seconds: i64,
+    next_configuration_change_id: u64,
+
+    /// optional allowlist of operators and their operation stats
+    pub(super) operator_allowlist: OperatorAllowlist,
+
+    /// Management fee rate in annual basis points (bps), prorated by `performance_fee_last_harvested_at`
+    pub(super) management_fee_rate_bps: u16,
+    _padding8: [u8; 6],
+
+    /// recipients of fund revenue, see [`RevenueSplit`]
+    pub(super) revenue_split: RevenueSplit,
+
+    /// receipt token price samples for TWAP, see [`ReceiptTokenPriceHistory`]
+    pub(super) receipt_token_price_history: ReceiptTokenPriceHistory,
+
+    /// circuit breaker against bad token prices, see [`PriceDeviationCircuitBreaker`]
+    pub(super) price_deviation_circuit_breaker: PriceDeviationCircuitBreaker,
+
+    /// Price staleness bound for user instructions.
+    /// - `max_price_age_slots`: max age of refreshed token prices (0 = disabled)
+    /// - `token_prices_updated_slot`: slot of the last token prices refresh
+    max_price_age_slots: u64,
+    pub(super) token_prices_updated_slot: u64,
+
+    /// soft failure of operation commands, see [`OperationFailureState`]
+    pub(super) operation_failure: OperationFailureState,
+
+    /// Paginated history of operation commands, see [`FundOperationHistoryAccount`].
+    /// - `operation_history_num_pages`: number of initialized pages (0 = disabled)
+    /// - `operation_history_num_entries`: number of recorded entries of all pages
+    operation_history_num_pages: u64,
+    operation_history_num_entries: u64,
+
+    /// scheduling policy of operation commands, see [`OperationPolicyState`]
+    pub(super) operation_policy: OperationPolicyState,
+
+    /// reconciliation of the bookkeeping against the reserve accounts, see [`ReconciliationState`]
+    pub(super) reconciliation: ReconciliationState,
+
+    /// instant withdrawal from the operation reserve, see [`InstantWithdrawalState`]
+    pub(super) instant_withdrawal: InstantWithdrawalState,
+
+    /// in-kind withdrawal of the assets held by the fund reserve, see [`InKindWithdrawalState`]
+    pub(super) in_kind_withdrawal: InKindWithdrawalState,
+
+    /// Management fee settled at previous rates on rate change, charged on the next fee harvest
+    pub(super) management_fee_accrued_amount_as_sol: u64,
+
+    _reserved1: [u8; 384],
 }
 
 impl PDASeeds<3> for FundAccount {
@@ -480,6 +539,10 @@ impl FundAccount {
         }
     }
 
+    pub fn get_address_lookup_table_account(&self) -> Option<Pubkey> {
+        (self.address_lookup_table_enabled == 1).then_some(self.address_lookup_table_account)
+    }
+
     #[inline(always)]
     pub(super) fn get_withdrawal_fee_amount(&self, amount: u64) -> Result<u64> {
         get_proportional_amount_u64(amount, self.withdrawal_fee_rate_bps as u64, 10_000)
@@ -548,6 +611,232 @@ impl FundAccount {
         Ok(self)
     }
 
+    /// Management fee accrued at the previous rate is settled first,
+    /// so the new rate applies from now on and is not applied retroactively.
+    pub(super) fn set_management_fee_rate_bps(
+        &mut self,
+        management_fee_rate_bps: u16,
+        current_timestamp: i64,
+    ) -> Result<&mut Self> {
+        require_gte!(
+            FUND_MANAGEMENT_FEE_RATE_BPS_LIMIT,
+            management_fee_rate_bps,
+            ErrorCode::FundInvalidManagementFeeRateError
+        );
+
+        if self.management_fee_rate_bps == management_fee_rate_bps {
+            return Ok(self);
+        }
+
+        self.management_fee_accrued_amount_as_sol =
+            self.get_management_fee_amount_as_sol(current_timestamp)?;
+        self.performance_fee_last_harvested_at = current_timestamp;
+        self.management_fee_rate_bps = management_fee_rate_bps;
+
+        Ok(self)
+    }
+
+    /// Management fee accrued since the last fee harvest, based on the current receipt token value.
+    pub(super) fn get_management_fee_amount_as_sol(&self, current_timestamp: i64) -> Result<u64> {
+        const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+        let elapsed_seconds = current_timestamp
+            .saturating_sub(self.performance_fee_last_harvested_at)
+            .max(0) as u64;
+        if self.management_fee_rate_bps == 0 || elapsed_seconds == 0 {
+            return Ok(self.management_fee_accrued_amount_as_sol);
+        }
+
+        let receipt_token_value_as_sol = get_proportional_amount_u64(
+            self.receipt_token_supply_amount,
+            self.one_receipt_token_as_sol,
+            10u64.pow(self.receipt_token_decimals as u32),
+        )?;
+
+        Ok(self.management_fee_accrued_amount_as_sol
+            + get_proportional_amount_u64(
+                receipt_token_value_as_sol,
+                self.management_fee_rate_bps as u64 * elapsed_seconds,
+                10_000 * SECONDS_PER_YEAR,
+            )?)
+    }
+
+    #[inline(always)]
+    pub(super) fn get_configuration_change_delay_seconds(&self) -> i64 {
+        self.configuration_change_delay_seconds
+    }
+
+    /// Material configuration changes must be queued and executed after the delay.
+    #[inline(always)]
+    pub(super) fn is_configuration_change_timelocked(&self) -> bool {
+        self.configuration_change_delay_seconds > 0
+    }
+
+    pub(super) fn set_configuration_change_delay_seconds(
+        &mut self,
+        delay_seconds: i64,
+    ) -> Result<()> {
+        require!(
+            (0..=FUND_ACCOUNT_MAX_CONFIGURATION_CHANGE_DELAY_SECONDS).contains(&delay_seconds),
+            ErrorCode::FundInvalidConfigurationChangeError
+        );
+
+        self.configuration_change_delay_seconds = delay_seconds;
+
+        Ok(())
+    }
+
+    pub(super) fn issue_configuration_change_id(&mut self) -> u64 {
+        let change_id = self.next_configuration_change_id;
+        self.next_configuration_change_id += 1;
+        change_id
+    }
+
+    pub(super) fn issue_operation_history_page_index(&mut self) -> u64 {
+        let page_index = self.operation_history_num_pages;
+        self.operation_history_num_pages += 1;
+        page_index
+    }
+
+    /// returns the page index to record the next entry, if the operation history is enabled.
+    pub fn get_operation_history_page_index(&self) -> Option<u64> {
+        (self.operation_history_num_pages > 0).then_some(
+            self.operation_history_num_entries / FUND_OPERATION_HISTORY_ACCOUNT_MAX_ENTRIES as u64,
+        )
+    }
+
+    /// returns the page index to initialize ahead, if the page after the current page is not initialized yet.
+    pub fn get_operation_history_page_index_to_initialize(&self) -> Option<u64> {
+        let page_index = self.get_operation_history_page_index()?;
+        (self.operation_history_num_pages <= page_index + 1)
+            .then_some(self.operation_history_num_pages)
+    }
+
+    pub(super) fn record_operation_history_entry(&mut self) {
+        self.operation_history_num_entries += 1;
+    }
+
+    #[inline(always)]
+    pub fn is_operation_enabled(&self) -> bool {
+        self.operation_enabled == 1
+    }
+
+    /// returns (next_sequence, next_command) as recorded by the last operation,
+    /// which the program resets to the initial command once expired.
+    pub fn get_operation_next_command(&self) -> Result<(u16, Option<OperationCommandEntry>)> {
+        Ok((
+            self.operation.next_sequence,
+            self.operation.get_next_command()?,
+        ))
+    }
+
+    #[inline(always)]
+    pub fn get_operation_expired_at(&self) -> i64 {
+        self.operation.get_expired_at()
+    }
+
+    #[inline(always)]
+    pub fn is_operator_authorized(&self, operator: &Pubkey) -> bool {
+        self.operator_allowlist.is_operator_authorized(operator)
+    }
+
+    /// Addresses of pricing source accounts required to price the fund assets.
+    pub fn get_pricing_source_addresses(&self) -> &[Pubkey] {
+        &self.pricing_source_addresses[..self.num_pricing_source_addresses as usize]
+    }
+
+    /// returns [(token_mint, token_program)] of the supported tokens.
+    pub fn get_supported_token_mints_iter(&self) -> impl Iterator<Item = (Pubkey, Pubkey)> + '_ {
+        self.get_supported_tokens_iter()
+            .map(|supported_token| (supported_token.mint, supported_token.program))
+    }
+
+    /// returns (token_mint, token_program) of the normalized token, if set.
+    pub fn get_normalized_token_mint(&self) -> Option<(Pubkey, Pubkey)> {
+        self.get_normalized_token()
+            .map(|normalized_token| (normalized_token.mint, normalized_token.program))
+    }
+
+    /// returns [(vault, receipt_token_mint, receipt_token_program)] of the restaking vaults.
+    pub fn get_restaking_vault_receipt_token_mints_iter(
+        &self,
+    ) -> impl Iterator<Item = (Pubkey, Pubkey, Pubkey)> + '_ {
+        self.get_restaking_vaults_iter().map(|restaking_vault| {
+            (
+                restaking_vault.vault,
+                restaking_vault.receipt_token_mint,
+                restaking_vault.receipt_token_program,
+            )
+        })
+    }
+
+    pub fn get_withdrawal_last_created_request_id(
+        &self,
+        supported_token_mint: Option<Pubkey>,
+    ) -> Result<u64> {
+        Ok(self
+            .get_asset_state(supported_token_mint)?
+            .withdrawal_last_created_request_id)
+    }
+
+    /// returns [(recipient, share_bps)]
+    pub fn get_revenue_recipients_iter(&self) -> impl Iterator<Item = (Pubkey, u16)> + '_ {
+        self.revenue_split.get_recipients_iter()
+    }
+
+    #[inline(always)]
+    pub(super) fn get_num_revenue_recipients(&self) -> usize {
+        self.revenue_split.get_recipients_iter().count()
+    }
+
+    pub(super) fn split_revenue(&self, amount: u64) -> Result<Vec<FundRevenueShare>> {
+        self.revenue_split.split(amount)
+    }
+
+    pub(super) fn set_max_price_age_slots(&mut self, max_price_age_slots: u64) {
+        self.max_price_age_slots = max_price_age_slots;
+    }
+
+    pub(super) fn is_price_stale(&self, current_slot: u64) -> bool {
+        self.max_price_age_slots > 0
+            && current_slot.saturating_sub(self.token_prices_updated_slot)
+                > self.max_price_age_slots
+    }
+
+    pub(super) fn require_price_not_stale(&self, current_slot: u64) -> Result<()> {
+        require!(
+            !self.is_price_stale(current_slot),
+            ErrorCode::FundPriceStaleError
+        );
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub(super) fn is_price_deviation_circuit_breaker_tripped(&self) -> bool {
+        self.price_deviation_circuit_breaker.is_tripped()
+    }
+
+    /// Deposit and withdrawal are disabled until the fund manager acknowledges the deviation.
+    pub(super) fn trip_price_deviation_circuit_breaker(
+        &mut self,
+        slot: u64,
+        token_mint: Pubkey,
+        token_pricing_source: &TokenPricingSourcePod,
+        previous_one_token_as_sol: u64,
+        one_token_as_sol: u64,
+    ) {
+        self.set_deposit_enabled(false)
+            .set_withdrawal_enabled(false);
+        self.price_deviation_circuit_breaker.trip(
+            slot,
+            token_mint,
+            token_pricing_source,
+            previous_one_token_as_sol,
+            one_token_as_sol,
+        );
+    }
+
     pub(super) fn add_supported_token(
         &mut self,
         mint: Pubkey,
@@ -767,6 +1056,16 @@ impl FundAccount {
             .ok_or_else(|| error!(ErrorCode::FundTokenSwapStrategyNotFoundError))
     }
 
+    pub(super) fn get_token_swap_strategy_mut(
+        &mut self,
+        from_token_mint: &Pubkey,
+    ) -> Result<&mut TokenSwapStrategy> {
+        self.token_swap_strategies[..self.num_token_swap_strategies as usize]
+            .iter_mut()
+            .find(|strategy| strategy.from_token_mint == *from_token_mint)
+            .ok_or_else(|| error!(ErrorCode::FundTokenSwapStrategyNotFoundError))
+    }
+
     pub(super) fn add_token_swap_strategy(
         &mut self,
         from_token_mint: Pubkey,
@@ -920,20 +1219,154 @@ impl FundAccount {
     pub(super) fn create_withdrawal_request(
         &mut self,
         supported_token_mint: Option<Pubkey>,
-        mut receipt_token_amount: u64,
+        receipt_token_amount: u64,
         current_timestamp: i64,
     ) -> Result<WithdrawalRequest> {
+        let receipt_token_amount =
+            self.get_requestable_receipt_token_amount(supported_token_mint, receipt_token_amount)?;
+        self.get_asset_state_mut(supported_token_mint)?
+            .create_withdrawal_request(receipt_token_amount, current_timestamp)
+    }
+
+    /// requested receipt_token_amount is reduced to the withdrawable value of the asset.
+    pub(super) fn get_requestable_receipt_token_amount(
+        &self,
+        supported_token_mint: Option<Pubkey>,
+        receipt_token_amount: u64,
+    ) -> Result<u64> {
         if self.withdrawal_enabled == 0 {
             err!(ErrorCode::FundWithdrawalDisabledError)?
         }
 
-        let asset = self.get_asset_state_mut(supported_token_mint)?;
+        let asset = self.get_asset_state(supported_token_mint)?;
         if asset.withdrawable_value_as_receipt_token_amount == 0 {
             err!(ErrorCode::FundWithdrawalReserveExhaustedSupportedAsset)?
         }
-        receipt_token_amount =
-            receipt_token_amount.min(asset.withdrawable_value_as_receipt_token_amount);
-        asset.create_withdrawal_request(receipt_token_amount, current_timestamp)
+
+        Ok(receipt_token_amount.min(asset.withdrawable_value_as_receipt_token_amount))
+    }
+
+    /// asset value should be updated after call this to estimate fresh withdrawable_value_as_receipt_token_amount.
+    /// returns [asset_user_amount, asset_fee_amount]
+    pub(super) fn withdraw_instantly(
+        &mut self,
+        supported_token_mint: Option<Pubkey>,
+        receipt_token_amount: u64,
+        pricing_service: &PricingService,
+    ) -> Result<(u64, u64)> {
+        if self.withdrawal_enabled == 0 {
+            err!(ErrorCode::FundWithdrawalDisabledError)?
+        }
+
+        let asset_amount = pricing_service.get_token_amount_as_asset(
+            &self.receipt_token_mint,
+            receipt_token_amount,
+            supported_token_mint.as_ref(),
+        )?;
+        let asset = self.get_asset_state(supported_token_mint)?;
+        let asset_fee_amount = self.instant_withdrawal.get_fee_amount(
+            asset_amount,
+            asset.get_instant_withdrawable_amount(
+                &self.receipt_token_mint,
+                &self.receipt_token_value,
+                pricing_service,
+            )?,
+            asset.get_total_amount(&self.receipt_token_value),
+            self.withdrawal_fee_rate_bps,
+        )?;
+        let asset_user_amount = asset_amount - asset_fee_amount;
+
+        self.get_asset_state_mut(supported_token_mint)?
+            .withdraw_instantly(asset_user_amount)?;
+
+        Ok((asset_user_amount, asset_fee_amount))
+    }
+
+    /// pays out a pro-rata slice of the assets held by the fund reserve, the fee remains in the reserve.
+    /// asset value should be updated after call this.
+    /// returns the withdrawn assets, SOL first then supported tokens, normalized token if set and receipt token of each restaking vault.
+    pub(super) fn withdraw_in_kind(
+        &mut self,
+        receipt_token_amount: u64,
+        pricing_service: &PricingService,
+    ) -> Result<Vec<FundInKindWithdrawnAsset>> {
+        if self.withdrawal_enabled == 0 {
+            err!(ErrorCode::FundWithdrawalDisabledError)?
+        }
+
+        // (token_mint, held_amount), the asset amount for the requested withdrawals is not held for in-kind withdrawal.
+        let mut held_assets = Vec::with_capacity(
+            2 + self.num_supported_tokens as usize + self.num_restaking_vaults as usize,
+        );
+        for asset in self.get_asset_states_iter() {
+            let (token_mint, _) = asset.get_token_mint_and_program().unzip();
+            held_assets.push((
+                token_mint,
+                asset.get_unrequested_operation_reserved_amount(
+                    &self.receipt_token_mint,
+                    pricing_service,
+                )?,
+            ));
+        }
+        if let Some(normalized_token) = self.get_normalized_token() {
+            held_assets.push((
+                Some(normalized_token.mint),
+                normalized_token.operation_reserved_amount,
+            ));
+        }
+        for restaking_vault in self.get_restaking_vaults_iter() {
+            held_assets.push((
+                Some(restaking_vault.receipt_token_mint),
+                restaking_vault.receipt_token_operation_reserved_amount,
+            ));
+        }
+
+        let mut total_held_value_as_sol = 0;
+        for (token_mint, held_amount) in &held_assets {
+            total_held_value_as_sol += match token_mint {
+                Some(token_mint) => {
+                    pricing_service.get_token_amount_as_sol(token_mint, *held_amount)?
+                }
+                None => *held_amount,
+            };
+        }
+        let withdrawal_value_as_sol = pricing_service
+            .get_token_amount_as_sol(&self.receipt_token_mint, receipt_token_amount)?;
+
+        let withdrawn_assets = held_assets
+            .into_iter()
+            .map(|(token_mint, held_amount)| {
+                let (asset_user_amount, asset_fee_amount) =
+                    self.in_kind_withdrawal.get_asset_amounts(
+                        held_amount,
+                        withdrawal_value_as_sol,
+                        total_held_value_as_sol,
+                    )?;
+                Ok(FundInKindWithdrawnAsset {
+                    token_mint,
+                    withdrawn_amount: asset_user_amount,
+                    deducted_fee_amount: asset_fee_amount,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // withdrawn amounts are in the same order of the held assets.
+        let mut withdrawn_amounts = withdrawn_assets.iter().map(|asset| asset.withdrawn_amount);
+        for (asset, withdrawn_amount) in
+            self.get_asset_states_iter_mut().zip(&mut withdrawn_amounts)
+        {
+            asset.operation_reserved_amount -= withdrawn_amount;
+        }
+        if let Some(normalized_token) = self.get_normalized_token_mut() {
+            normalized_token.operation_reserved_amount -= withdrawn_amounts.next().unwrap();
+        }
+        for (restaking_vault, withdrawn_amount) in
+            self.get_restaking_vaults_iter_mut().zip(withdrawn_amounts)
+        {
+            restaking_vault.receipt_token_operation_reserved_amount -= withdrawn_amount;
+        }
+
+        Ok(withdrawn_assets)
     }
 
     /// asset value should be updated after call this to estimate fresh withdrawable_value_as_receipt_token_amount.
@@ -1403,4 +1836,137 @@ mod tests {
 
         fund.supported_tokens[0].token.deposit(1_000).unwrap_err();
     }
+
+    #[test]
+    fn test_management_fee() {
+        let mut fund = create_initialized_fund_account();
+        fund.receipt_token_supply_amount = 1_000_000_000_000;
+        fund.one_receipt_token_as_sol = 1_000_000_000;
+
+        fund.set_management_fee_rate_bps(FUND_MANAGEMENT_FEE_RATE_BPS_LIMIT + 1, 0)
+            .unwrap_err();
+        assert_eq!(fund.get_management_fee_amount_as_sol(86_400).unwrap(), 0);
+
+        // 1% annual fee on 1,000 SOL
+        fund.set_management_fee_rate_bps(100, 1_000).unwrap();
+        assert_eq!(fund.get_management_fee_amount_as_sol(1_000).unwrap(), 0);
+        assert_eq!(
+            fund.get_management_fee_amount_as_sol(1_000 + 365 * 24 * 60 * 60)
+                .unwrap(),
+            10_000_000_000
+        );
+        assert_eq!(
+            fund.get_management_fee_amount_as_sol(1_000 + 73 * 24 * 60 * 60)
+                .unwrap(),
+            2_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_management_fee_accrual_survives_rate_change() {
+        const DAY: i64 = 24 * 60 * 60;
+
+        let mut fund = create_initialized_fund_account();
+        fund.receipt_token_supply_amount = 1_000_000_000_000;
+        fund.one_receipt_token_as_sol = 1_000_000_000;
+
+        // 1% annual fee on 1,000 SOL for 73 days
+        fund.set_management_fee_rate_bps(100, 0).unwrap();
+        assert_eq!(
+            fund.get_management_fee_amount_as_sol(73 * DAY).unwrap(),
+            2_000_000_000
+        );
+
+        // setting the same rate doesn't touch the accrual
+        fund.set_management_fee_rate_bps(100, 73 * DAY).unwrap();
+        assert_eq!(fund.management_fee_accrued_amount_as_sol, 0);
+        assert_eq!(fund.performance_fee_last_harvested_at, 0);
+
+        // accrual at 1% is kept, then 2% applies from now on
+        fund.set_management_fee_rate_bps(200, 73 * DAY).unwrap();
+        assert_eq!(
+            fund.get_management_fee_amount_as_sol(73 * DAY).unwrap(),
+            2_000_000_000
+        );
+        assert_eq!(
+            fund.get_management_fee_amount_as_sol(146 * DAY).unwrap(),
+            6_000_000_000
+        );
+
+        // disabling the fee keeps the accrual until harvested
+        fund.set_management_fee_rate_bps(0, 146 * DAY).unwrap();
+        assert_eq!(
+            fund.get_management_fee_amount_as_sol(365 * DAY).unwrap(),
+            6_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_configuration_change_timelock() {
+        let mut fund = create_initialized_fund_account();
+        assert!(!fund.is_configuration_change_timelocked());
+
+        fund.set_configuration_change_delay_seconds(-1).unwrap_err();
+        fund.set_configuration_change_delay_seconds(
+            FUND_ACCOUNT_MAX_CONFIGURATION_CHANGE_DELAY_SECONDS + 1,
+        )
+        .unwrap_err();
+        fund.set_configuration_change_delay_seconds(86_400).unwrap();
+        assert!(fund.is_configuration_change_timelocked());
+        assert_eq!(fund.get_configuration_change_delay_seconds(), 86_400);
+
+        assert_eq!(fund.issue_configuration_change_id(), 0);
+        assert_eq!(fund.issue_configuration_change_id(), 1);
+        assert_eq!(fund.next_configuration_change_id, 2);
+    }
+
+    #[test]
+    fn test_price_staleness() {
+        let mut fund = create_initialized_fund_account();
+        fund.token_prices_updated_slot = 100;
+        assert!(!fund.is_price_stale(1_000));
+
+        fund.set_max_price_age_slots(150);
+        assert!(!fund.is_price_stale(250));
+        assert!(fund.is_price_stale(251));
+        fund.require_price_not_stale(251).unwrap_err();
+
+        fund.token_prices_updated_slot = 251;
+        fund.require_price_not_stale(251).unwrap();
+    }
+
+    #[test]
+    fn test_operation_history_page_index() {
+        let mut fund = create_initialized_fund_account();
+        assert_eq!(fund.get_operation_history_page_index(), None);
+        assert_eq!(fund.get_operation_history_page_index_to_initialize(), None);
+
+        assert_eq!(fund.issue_operation_history_page_index(), 0);
+        assert_eq!(
+            fund.get_operation_history_page_index_to_initialize(),
+            Some(1)
+        );
+        assert_eq!(fund.issue_operation_history_page_index(), 1);
+        assert_eq!(fund.get_operation_history_page_index(), Some(0));
+        assert_eq!(fund.get_operation_history_page_index_to_initialize(), None);
+
+        for _ in 0..FUND_OPERATION_HISTORY_ACCOUNT_MAX_ENTRIES {
+            fund.record_operation_history_entry();
+        }
+        assert_eq!(fund.get_operation_history_page_index(), Some(1));
+        assert_eq!(
+            fund.get_operation_history_page_index_to_initialize(),
+            Some(2)
+        );
+
+        // the current page has not been initialized in time.
+        for _ in 0..FUND_OPERATION_HISTORY_ACCOUNT_MAX_ENTRIES {
+            fund.record_operation_history_entry();
+        }
+        assert_eq!(fund.get_operation_history_page_index(), Some(2));
+        assert_eq!(
+            fund.get_operation_history_page_index_to_initialize(),
+            Some(2)
+        );
+    }
 }
@@ -259,6 +259,17 @@ impl AssetState {
         Ok(processing_batches)
     }
 
+    /// pays out asset from the operation reserve without a withdrawal batch, the fee remains in the reserve.
+    pub fn withdraw_instantly(&mut self, asset_user_amount: u64) -> Result<()> {
+        if self.withdrawable == 0 {
+            err!(ErrorCode::FundWithdrawalNotSupportedAsset)?
+        }
+
+        self.operation_reserved_amount -= asset_user_amount;
+
+        Ok(())
+    }
+
     fn get_queued_withdrawal_batches_iter(&self) -> impl Iterator<Item = &WithdrawalBatch> {
         self.withdrawal_queued_batches[..self.withdrawal_num_queued_batches as usize].iter()
     }
@@ -343,6 +354,36 @@ impl AssetState {
             })
     }
 
+    /// asset amount of the operation reserve above the floor, which is the asset amount for the requested withdrawals and the normal reserve.
+    pub fn get_instant_withdrawable_amount(
+        &self,
+        receipt_token_mint: &Pubkey,
+        receipt_token_value: &TokenValuePod,
+        pricing_service: &PricingService,
+    ) -> Result<u64> {
+        Ok(self
+            .get_unrequested_operation_reserved_amount(receipt_token_mint, pricing_service)?
+            .saturating_sub(self.get_withdrawal_normal_reserve_amount(receipt_token_value)?))
+    }
+
+    /// asset amount of the operation reserve except the asset amount for the requested withdrawals.
+    pub fn get_unrequested_operation_reserved_amount(
+        &self,
+        receipt_token_mint: &Pubkey,
+        pricing_service: &PricingService,
+    ) -> Result<u64> {
+        let (supported_token_mint, _) = self.get_token_mint_and_program().unzip();
+        let asset_withdrawal_requested_amount = pricing_service.get_token_amount_as_asset(
+            receipt_token_mint,
+            self.get_receipt_token_withdrawal_requested_amount(),
+            supported_token_mint.as_ref(),
+        )?;
+
+        Ok(self
+            .operation_reserved_amount
+            .saturating_sub(asset_withdrawal_requested_amount))
+    }
+
     /// represents the surplus or shortage amount after fulfilling the withdrawal obligations for the given asset.
     pub fn get_net_operation_reserved_amount(
         &self,
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::utils::get_proportional_amount_u64;
+
+use super::FUND_WITHDRAWAL_FEE_RATE_BPS_LIMIT;
+
+/// In-kind withdrawal pays out a pro-rata slice of every asset held by the fund reserve immediately,
+/// SOL, supported tokens, the normalized token and vault receipt tokens, skipping unstaking and unrestaking.
+///
+/// The slice is the withdrawal value over the total value of the held assets, except the asset amount of requested withdrawals.
+/// The fee is charged separately from the normal withdrawal fee and remains in the reserve.
+#[zero_copy]
+pub(super) struct InKindWithdrawalState {
+    enabled: u8,
+    _padding: u8,
+    /// configuration: fee rate of each withdrawn asset.
+    fee_rate_bps: u16,
+    _padding2: [u8; 4],
+
+    _reserved: [u8; 32],
+}
+
+impl InKindWithdrawalState {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled == 1
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled as u8;
+    }
+
+    pub fn set_fee_rate_bps(&mut self, fee_rate_bps: u16) -> Result<()> {
+        require_gte!(
+            FUND_WITHDRAWAL_FEE_RATE_BPS_LIMIT,
+            fee_rate_bps,
+            ErrorCode::FundInvalidConfigurationUpdateError
+        );
+
+        self.fee_rate_bps = fee_rate_bps;
+
+        Ok(())
+    }
+
+    /// returns [asset_user_amount, asset_fee_amount]
+    pub fn get_asset_amounts(
+        &self,
+        held_asset_amount: u64,
+        withdrawal_value_as_sol: u64,
+        total_held_value_as_sol: u64,
+    ) -> Result<(u64, u64)> {
+        require!(
+            self.is_enabled(),
+            ErrorCode::FundInKindWithdrawalDisabledError
+        );
+        require_gt!(withdrawal_value_as_sol, 0);
+        require_gte!(
+            total_held_value_as_sol,
+            withdrawal_value_as_sol,
+            ErrorCode::FundInKindWithdrawalLiquidityExceededError
+        );
+
+        let asset_amount = get_proportional_amount_u64(
+            held_asset_amount,
+            withdrawal_value_as_sol,
+            total_held_value_as_sol,
+        )?;
+        let asset_fee_amount =
+            get_proportional_amount_u64(asset_amount, self.fee_rate_bps as u64, 10_000)?;
+
+        Ok((asset_amount - asset_fee_amount, asset_fee_amount))
+    }
+}
+
+/// Asset paid out by in-kind withdrawal, reported by `user_withdraw_in_kind`.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub struct FundInKindWithdrawnAsset {
+    /// `None` for SOL.
+    pub token_mint: Option<Pubkey>,
+    pub withdrawn_amount: u64,
+    pub deducted_fee_amount: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    #[test]
+    fn test_set_fee_rate_bps() {
+        let mut state = InKindWithdrawalState::zeroed();
+        state.set_fee_rate_bps(10).unwrap();
+        state
+            .set_fee_rate_bps(FUND_WITHDRAWAL_FEE_RATE_BPS_LIMIT + 1)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_get_asset_amounts() {
+        let mut state = InKindWithdrawalState::zeroed();
+        state.get_asset_amounts(1_000, 100, 1_000).unwrap_err();
+
+        state.set_enabled(true);
+        state.set_fee_rate_bps(100).unwrap();
+
+        // a quarter of the held value
+        assert_eq!(
+            state.get_asset_amounts(4_000, 2_500, 10_000).unwrap(),
+            (990, 10)
+        );
+        assert_eq!(state.get_asset_amounts(0, 2_500, 10_000).unwrap(), (0, 0));
+        // the whole held value
+        assert_eq!(
+            state.get_asset_amounts(4_000, 10_000, 10_000).unwrap(),
+            (3_960, 40)
+        );
+
+        state.get_asset_amounts(4_000, 10_001, 10_000).unwrap_err();
+        state.get_asset_amounts(0, 1, 0).unwrap_err();
+        state.get_asset_amounts(4_000, 0, 0).unwrap_err();
+    }
+}
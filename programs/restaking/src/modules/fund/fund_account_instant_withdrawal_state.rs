@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::utils::get_proportional_amount_u64;
+
+pub const FUND_INSTANT_WITHDRAWAL_FEE_RATE_BPS_LIMIT: u16 = 1_000;
+const FUND_INSTANT_WITHDRAWAL_MAX_FEE_CURVE_EXPONENT: u8 = 4;
+
+/// Instant withdrawal pays out from the operation reserve immediately, instead of a withdrawal batch.
+///
+/// Only the reserve above the floor, the asset amount of requested withdrawals and the normal reserve, can be paid out.
+/// The fee rate rises from the min to the max as the remaining reserve above the floor depletes below the target,
+/// following the curve of the exponent. The fee remains in the reserve, so it accretes to the receipt token value.
+/// The fee rate never goes below the withdrawal fee rate of the batched withdrawal, so instant withdrawal can't undercut it.
+#[zero_copy]
+pub(super) struct InstantWithdrawalState {
+    enabled: u8,
+    /// configuration: 1 = linear, 2 = quadratic, ... up to 4.
+    fee_curve_exponent: u8,
+    /// configuration: fee rate while the remaining reserve above the floor is not below the target.
+    min_fee_rate_bps: u16,
+    /// configuration: fee rate when the reserve above the floor is depleted.
+    max_fee_rate_bps: u16,
+    /// configuration: target of the remaining reserve above the floor in basis points of the total asset amount.
+    liquidity_target_rate_bps: u16,
+
+    _reserved: [u8; 32],
+}
+
+impl InstantWithdrawalState {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled == 1
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled as u8;
+    }
+
+    pub fn set_fee_curve(
+        &mut self,
+        min_fee_rate_bps: u16,
+        max_fee_rate_bps: u16,
+        fee_curve_exponent: u8,
+        liquidity_target_rate_bps: u16,
+        withdrawal_fee_rate_bps: u16,
+    ) -> Result<()> {
+        require_gte!(
+            min_fee_rate_bps,
+            withdrawal_fee_rate_bps,
+            ErrorCode::FundInvalidConfigurationUpdateError
+        );
+        require_gte!(
+            max_fee_rate_bps,
+            min_fee_rate_bps,
+            ErrorCode::FundInvalidConfigurationUpdateError
+        );
+        require_gte!(
+            FUND_INSTANT_WITHDRAWAL_FEE_RATE_BPS_LIMIT,
+            max_fee_rate_bps,
+            ErrorCode::FundInvalidConfigurationUpdateError
+        );
+        require!(
+            (1..=FUND_INSTANT_WITHDRAWAL_MAX_FEE_CURVE_EXPONENT).contains(&fee_curve_exponent),
+            ErrorCode::FundInvalidConfigurationUpdateError
+        );
+        require_gte!(
+            10_000,
+            liquidity_target_rate_bps,
+            ErrorCode::FundInvalidConfigurationUpdateError
+        );
+
+        self.min_fee_rate_bps = min_fee_rate_bps;
+        self.max_fee_rate_bps = max_fee_rate_bps;
+        self.fee_curve_exponent = fee_curve_exponent;
+        self.liquidity_target_rate_bps = liquidity_target_rate_bps;
+
+        Ok(())
+    }
+
+    /// returns [asset_fee_amount]
+    pub fn get_fee_amount(
+        &self,
+        asset_amount: u64,
+        instant_withdrawable_amount: u64,
+        total_asset_amount: u64,
+        withdrawal_fee_rate_bps: u16,
+    ) -> Result<u64> {
+        require!(
+            self.is_enabled(),
+            ErrorCode::FundInstantWithdrawalDisabledError
+        );
+        require_gte!(
+            instant_withdrawable_amount,
+            asset_amount,
+            ErrorCode::FundInstantWithdrawalLiquidityExceededError
+        );
+
+        let liquidity_target_amount = get_proportional_amount_u64(
+            total_asset_amount,
+            self.liquidity_target_rate_bps as u64,
+            10_000,
+        )?;
+        let fee_rate_bps = self
+            .get_fee_rate_bps(
+                instant_withdrawable_amount - asset_amount,
+                liquidity_target_amount,
+            )
+            .max(withdrawal_fee_rate_bps as u64);
+
+        get_proportional_amount_u64(asset_amount, fee_rate_bps, 10_000)
+    }
+
+    fn get_fee_rate_bps(&self, remaining_amount: u64, liquidity_target_amount: u64) -> u64 {
+        if remaining_amount >= liquidity_target_amount {
+            return self.min_fee_rate_bps as u64;
+        }
+
+        let depletion_bps = (liquidity_target_amount - remaining_amount) as u128 * 10_000
+            / liquidity_target_amount as u128;
+        let curve_bps = (1..self.fee_curve_exponent).fold(depletion_bps, |curve_bps, _| {
+            curve_bps * depletion_bps / 10_000
+        });
+        let fee_rate_range_bps = (self.max_fee_rate_bps - self.min_fee_rate_bps) as u128;
+
+        self.min_fee_rate_bps as u64 + (fee_rate_range_bps * curve_bps / 10_000) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    fn create_state(exponent: u8) -> InstantWithdrawalState {
+        let mut state = InstantWithdrawalState::zeroed();
+        state.set_enabled(true);
+        state.set_fee_curve(10, 110, exponent, 1_000, 10).unwrap();
+        state
+    }
+
+    #[test]
+    fn test_set_fee_curve() {
+        let mut state = InstantWithdrawalState::zeroed();
+        state.set_fee_curve(10, 110, 2, 1_000, 10).unwrap();
+
+        state.set_fee_curve(110, 10, 2, 1_000, 10).unwrap_err();
+        state
+            .set_fee_curve(
+                10,
+                FUND_INSTANT_WITHDRAWAL_FEE_RATE_BPS_LIMIT + 1,
+                2,
+                1_000,
+                10,
+            )
+            .unwrap_err();
+        state.set_fee_curve(10, 110, 0, 1_000, 10).unwrap_err();
+        state.set_fee_curve(10, 110, 5, 1_000, 10).unwrap_err();
+        state.set_fee_curve(10, 110, 2, 10_001, 10).unwrap_err();
+
+        // min fee rate below the withdrawal fee rate
+        state.set_fee_curve(10, 110, 2, 1_000, 11).unwrap_err();
+    }
+
+    #[test]
+    fn test_get_fee_amount() {
+        let state = create_state(1);
+
+        // remaining 2_000 >= target 1_000 of total 10_000
+        assert_eq!(state.get_fee_amount(1_000, 3_000, 10_000, 10).unwrap(), 1);
+        // remaining 500 of target 1_000
+        assert_eq!(
+            state.get_fee_amount(10_000, 10_500, 10_000, 10).unwrap(),
+            60
+        );
+        // depleted
+        assert_eq!(
+            state.get_fee_amount(10_000, 10_000, 10_000, 10).unwrap(),
+            110
+        );
+
+        state.get_fee_amount(1_001, 1_000, 10_000, 10).unwrap_err();
+        InstantWithdrawalState::zeroed()
+            .get_fee_amount(1_000, 3_000, 10_000, 10)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_fee_rate_not_below_withdrawal_fee_rate() {
+        let state = create_state(1);
+
+        // withdrawal fee rate raised above the min fee rate after the curve was set
+        assert_eq!(
+            state.get_fee_amount(10_000, 30_000, 10_000, 50).unwrap(),
+            50
+        );
+        assert_eq!(
+            state.get_fee_amount(10_000, 10_500, 10_000, 50).unwrap(),
+            60
+        );
+    }
+
+    #[test]
+    fn test_fee_rate_rises_along_curve() {
+        let linear = create_state(1);
+        let quadratic = create_state(2);
+
+        assert_eq!(linear.get_fee_rate_bps(1_000, 1_000), 10);
+        assert_eq!(linear.get_fee_rate_bps(500, 1_000), 60);
+        assert_eq!(quadratic.get_fee_rate_bps(500, 1_000), 35);
+        assert_eq!(quadratic.get_fee_rate_bps(0, 1_000), 110);
+
+        // no target
+        assert_eq!(quadratic.get_fee_rate_bps(0, 0), 10);
+
+        let mut previous_fee_rate_bps = 0;
+        for remaining_amount in (0..=1_000).rev().step_by(100) {
+            let fee_rate_bps = quadratic.get_fee_rate_bps(remaining_amount, 1_000);
+            assert!(fee_rate_bps >= previous_fee_rate_bps);
+            previous_fee_rate_bps = fee_rate_bps;
+        }
+    }
+}
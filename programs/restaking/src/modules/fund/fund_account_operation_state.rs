@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::ErrorCode;
+
 use super::commands::*;
 
 const FUND_ACCOUNT_OPERATION_COMMAND_EXPIRATION_SECONDS: i64 = 600;
@@ -94,4 +96,308 @@ impl OperationState {
     pub fn get_next_command(&self) -> Result<Option<OperationCommandEntry>> {
         self.next_command.try_deserialize()
     }
+
+    /// Returns (next_sequence, next_command) as `initialize_command_if_needed` without reset command would leave them.
+    pub fn peek_next_command(
+        &self,
+        current_timestamp: i64,
+    ) -> Result<(u16, Option<OperationCommandEntry>)> {
+        if self.next_command.is_none() || current_timestamp > self.expired_at {
+            return Ok((1, Some(Default::default())));
+        }
+        Ok((self.next_sequence, self.get_next_command()?))
+    }
+
+    #[inline(always)]
+    pub fn get_expired_at(&self) -> i64 {
+        self.expired_at
+    }
+}
+
+pub const FUND_ACCOUNT_MAX_OPERATION_COMMAND_FAILURES: usize = 8;
+const FUND_ACCOUNT_OPERATION_COMMAND_DISCRIMINANTS_SIZE: usize = 16;
+
+/// Soft failure of operation commands.
+///
+/// A failed command which has not changed any state transitions to its fallback command
+/// instead of reverting the transaction, so that one stuck command does not block the whole operation cycle.
+#[zero_copy]
+pub(super) struct OperationFailureState {
+    /// configuration: fallback command discriminant indexed by the failed command discriminant (0 = no fallback).
+    fallback_commands: [u8; FUND_ACCOUNT_OPERATION_COMMAND_DISCRIMINANTS_SIZE],
+
+    num_failures: u8,
+    next_failure_index: u8,
+    _padding: [u8; 6],
+    /// ring buffer of recent soft failures.
+    failures: [OperationCommandFailure; FUND_ACCOUNT_MAX_OPERATION_COMMAND_FAILURES],
+}
+
+impl OperationFailureState {
+    pub fn get_fallback_command(
+        &self,
+        command: &OperationCommand,
+    ) -> Option<OperationCommandEntry> {
+        let fallback_command = self.fallback_commands[command.discriminant() as usize];
+        OperationCommand::try_default_from_discriminant(fallback_command).map(|command| {
+            OperationCommandEntry {
+                command,
+                required_accounts: Vec::with_capacity(0),
+            }
+        })
+    }
+
+    pub fn set_fallback_command(
+        &mut self,
+        command: u8,
+        fallback_command: Option<u8>,
+    ) -> Result<()> {
+        require!(
+            OperationCommand::try_default_from_discriminant(command).is_some(),
+            ErrorCode::FundInvalidOperationFallbackCommandError
+        );
+        if let Some(fallback_command) = fallback_command {
+            require!(
+                fallback_command != command
+                    && OperationCommand::try_default_from_discriminant(fallback_command).is_some(),
+                ErrorCode::FundInvalidOperationFallbackCommandError
+            );
+        }
+
+        self.fallback_commands[command as usize] = fallback_command.unwrap_or_default();
+
+        Ok(())
+    }
+
+    pub fn record_failure(&mut self, slot: u64, sequence: u16, command: u8, error_code: u64) {
+        self.failures[self.next_failure_index as usize] = OperationCommandFailure {
+            slot,
+            error_code,
+            sequence,
+            command,
+            _padding: [0; 5],
+        };
+        self.next_failure_index = ((self.next_failure_index as usize + 1)
+            % FUND_ACCOUNT_MAX_OPERATION_COMMAND_FAILURES) as u8;
+        if (self.num_failures as usize) < FUND_ACCOUNT_MAX_OPERATION_COMMAND_FAILURES {
+            self.num_failures += 1;
+        }
+    }
+}
+
+#[zero_copy]
+pub(super) struct OperationCommandFailure {
+    pub slot: u64,
+    pub error_code: u64,
+    pub sequence: u16,
+    /// discriminant of the failed command
+    pub command: u8,
+    _padding: [u8; 5],
+}
+
+/// Scheduling policy of operation commands.
+///
+/// When the operation cycle transitions to another command, a disabled command or a command
+/// executed within its min interval is skipped to the next command of the cycle.
+/// The first command of the cycle ([`InitializeCommand`]) is always executed.
+#[zero_copy]
+pub(super) struct OperationPolicyState {
+    /// indexed by command discriminant.
+    commands: [OperationCommandPolicy; FUND_ACCOUNT_OPERATION_COMMAND_DISCRIMINANTS_SIZE],
+}
+
+impl OperationPolicyState {
+    fn is_command_runnable(&self, command: u8, current_timestamp: i64) -> bool {
+        let policy = &self.commands[command as usize];
+        policy.disabled == 0
+            && (policy.last_executed_at == 0
+                || current_timestamp - policy.last_executed_at >= policy.min_interval_seconds)
+    }
+
+    pub fn set_command_policy(
+        &mut self,
+        command: u8,
+        enabled: bool,
+        min_interval_seconds: i64,
+    ) -> Result<()> {
+        require!(
+            command != OperationCommand::from(InitializeCommand::default()).discriminant()
+                && OperationCommand::try_default_from_discriminant(command).is_some(),
+            ErrorCode::FundInvalidOperationCommandPolicyError
+        );
+        require_gte!(
+            min_interval_seconds,
+            0,
+            ErrorCode::FundInvalidOperationCommandPolicyError
+        );
+
+        let policy = &mut self.commands[command as usize];
+        policy.disabled = (!enabled) as u8;
+        policy.min_interval_seconds = min_interval_seconds;
+
+        Ok(())
+    }
+
+    pub fn record_execution(&mut self, command: u8, current_timestamp: i64) {
+        self.commands[command as usize].last_executed_at = current_timestamp;
+    }
+
+    /// Skips the next command to the command it transitions to in the cycle until a runnable one is found.
+    pub fn get_next_runnable_command(
+        &self,
+        mut next_command: Option<OperationCommandEntry>,
+        current_timestamp: i64,
+    ) -> Option<OperationCommandEntry> {
+        while let Some(entry) = &next_command {
+            let command = entry.command.discriminant();
+            if self.is_command_runnable(command, current_timestamp) {
+                break;
+            }
+
+            msg!("COMMAND: {} skipped by policy", entry.command.type_name());
+            next_command = entry.command.next_command_of_cycle();
+        }
+
+        next_command
+    }
+}
+
+#[zero_copy]
+pub(super) struct OperationCommandPolicy {
+    disabled: u8,
+    _padding: [u8; 7],
+    /// min interval between executions (0 = no limit).
+    min_interval_seconds: i64,
+    /// last execution time including soft failures.
+    last_executed_at: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    #[test]
+    fn test_peek_next_command() {
+        let mut state = OperationState::zeroed();
+        let (sequence, next_command) = state.peek_next_command(0).unwrap();
+        assert_eq!(sequence, 1);
+        assert_eq!(next_command.unwrap().command.discriminant(), 1);
+
+        state
+            .set_command(
+                Some(UnstakeLSTCommand::default().without_required_accounts()),
+                0,
+                10,
+            )
+            .unwrap();
+        let (sequence, next_command) = state.peek_next_command(10).unwrap();
+        assert_eq!(sequence, 1);
+        assert_eq!(next_command.unwrap().command.discriminant(), 7);
+
+        // reset to the initial command once expired
+        let expired_at = state.get_expired_at();
+        let (_, next_command) = state.peek_next_command(expired_at + 1).unwrap();
+        assert_eq!(next_command.unwrap().command.discriminant(), 1);
+
+        // peek does not change the state
+        assert_eq!(
+            state
+                .get_next_command()
+                .unwrap()
+                .unwrap()
+                .command
+                .discriminant(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_fallback_command() {
+        let mut state = OperationFailureState::zeroed();
+        let command: OperationCommand = UnrestakeVRTCommand::default().into();
+        assert!(state.get_fallback_command(&command).is_none());
+
+        state.set_fallback_command(0, Some(10)).unwrap_err();
+        state.set_fallback_command(8, Some(8)).unwrap_err();
+        state.set_fallback_command(8, Some(16)).unwrap_err();
+
+        state.set_fallback_command(8, Some(10)).unwrap();
+        let fallback_command = state.get_fallback_command(&command).unwrap();
+        assert_eq!(fallback_command.command.discriminant(), 10);
+        assert!(fallback_command.required_accounts.is_empty());
+
+        state.set_fallback_command(8, None).unwrap();
+        assert!(state.get_fallback_command(&command).is_none());
+    }
+
+    #[test]
+    fn test_record_failure() {
+        let mut state = OperationFailureState::zeroed();
+        for i in 0..FUND_ACCOUNT_MAX_OPERATION_COMMAND_FAILURES as u64 + 3 {
+            state.record_failure(i, i as u16, 8, 6000 + i);
+        }
+
+        // the oldest failures are overwritten
+        assert_eq!(
+            state.num_failures as usize,
+            FUND_ACCOUNT_MAX_OPERATION_COMMAND_FAILURES
+        );
+        assert_eq!(state.next_failure_index, 3);
+        assert_eq!(state.failures[2].error_code, 6010);
+        assert_eq!(state.failures[3].slot, 3);
+    }
+
+    #[test]
+    fn test_command_policy() {
+        let mut state = OperationPolicyState::zeroed();
+        state.set_command_policy(1, false, 0).unwrap_err();
+        state.set_command_policy(16, false, 0).unwrap_err();
+        state.set_command_policy(11, true, -1).unwrap_err();
+
+        // harvest restaking yield at most once per 100 seconds, and unstake LST disabled
+        state.set_command_policy(11, true, 100).unwrap();
+        state.set_command_policy(7, false, 0).unwrap();
+        assert_eq!(state.commands[7].disabled, 1);
+        assert_eq!(state.commands[11].min_interval_seconds, 100);
+
+        let next_command = |command: OperationCommand| {
+            Some(OperationCommandEntry {
+                command,
+                required_accounts: Vec::with_capacity(0),
+            })
+        };
+        let get_next_runnable_command = |state: &OperationPolicyState, command, timestamp| {
+            state
+                .get_next_runnable_command(next_command(command), timestamp)
+                .map(|entry| entry.command.discriminant())
+        };
+
+        assert_eq!(
+            get_next_runnable_command(&state, UnstakeLSTCommand::default().into(), 10),
+            Some(8)
+        );
+        assert_eq!(
+            get_next_runnable_command(&state, HarvestRestakingYieldCommand::default().into(), 10),
+            Some(11)
+        );
+
+        state.record_execution(11, 10);
+        assert_eq!(
+            get_next_runnable_command(&state, HarvestRestakingYieldCommand::default().into(), 109),
+            Some(12)
+        );
+        assert_eq!(
+            get_next_runnable_command(&state, HarvestRestakingYieldCommand::default().into(), 110),
+            Some(11)
+        );
+
+        // the end of the cycle
+        state.set_command_policy(15, false, 0).unwrap();
+        assert_eq!(
+            get_next_runnable_command(&state, DelegateVSTCommand::default().into(), 10),
+            None
+        );
+    }
 }
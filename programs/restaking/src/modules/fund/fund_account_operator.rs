@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+
+use crate::errors::ErrorCode;
+
+pub const FUND_ACCOUNT_MAX_OPERATORS: usize = 8;
+
+/// Optional set of operators which are allowed to operate the fund.
+///
+/// Operation is permissionless unless the allowlist is enabled,
+/// but registered operators are accounted regardless of the mode.
+#[zero_copy]
+pub(super) struct OperatorAllowlist {
+    enabled: u8,
+    num_operators: u8,
+    _padding: [u8; 6],
+    operators: [FundOperator; FUND_ACCOUNT_MAX_OPERATORS],
+}
+
+impl OperatorAllowlist {
+    #[inline(always)]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled == 1
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) -> Result<()> {
+        // prevent to lock out every operator.
+        if enabled {
+            require_gt!(self.num_operators, 0, ErrorCode::FundOperatorNotFoundError);
+        }
+
+        self.enabled = enabled as u8;
+
+        Ok(())
+    }
+
+    pub fn get_operators_iter(&self) -> impl Iterator<Item = &FundOperator> {
+        self.operators[..self.num_operators as usize].iter()
+    }
+
+    fn get_operators_iter_mut(&mut self) -> impl Iterator<Item = &mut FundOperator> {
+        self.operators[..self.num_operators as usize].iter_mut()
+    }
+
+    pub fn is_operator_authorized(&self, operator: &Pubkey) -> bool {
+        !self.is_enabled()
+            || self
+                .get_operators_iter()
+                .any(|fund_operator| fund_operator.operator == *operator)
+    }
+
+    pub fn add_operator(&mut self, operator: Pubkey) -> Result<()> {
+        if self
+            .get_operators_iter()
+            .any(|fund_operator| fund_operator.operator == operator)
+        {
+            err!(ErrorCode::FundOperatorAlreadyRegisteredError)?
+        }
+
+        require_gt!(
+            FUND_ACCOUNT_MAX_OPERATORS,
+            self.num_operators as usize,
+            ErrorCode::FundExceededMaxOperatorsError,
+        );
+
+        self.operators[self.num_operators as usize].initialize(operator);
+        self.num_operators += 1;
+
+        Ok(())
+    }
+
+    pub fn remove_operator(&mut self, operator: &Pubkey) -> Result<()> {
+        let idx = self
+            .get_operators_iter()
+            .position(|fund_operator| fund_operator.operator == *operator)
+            .ok_or_else(|| error!(ErrorCode::FundOperatorNotFoundError))?;
+
+        // prevent to lock out every operator.
+        if self.is_enabled() {
+            require_gt!(self.num_operators, 1, ErrorCode::FundOperatorNotFoundError);
+        }
+
+        // list of operators need not preserve the order
+        self.num_operators -= 1;
+        self.operators.swap(idx, self.num_operators as usize);
+        self.operators[self.num_operators as usize] = Zeroable::zeroed();
+
+        Ok(())
+    }
+
+    /// Unregistered operator is not accounted, which is possible in permissionless mode.
+    pub fn record_operation(&mut self, operator: &Pubkey, current_slot: u64) {
+        if let Some(fund_operator) = self
+            .get_operators_iter_mut()
+            .find(|fund_operator| fund_operator.operator == *operator)
+        {
+            fund_operator.num_operated += 1;
+            fund_operator.last_operated_slot = current_slot;
+        }
+    }
+}
+
+#[zero_copy]
+pub(super) struct FundOperator {
+    pub operator: Pubkey,
+    pub num_operated: u64,
+    pub last_operated_slot: u64,
+    _reserved: [u8; 16],
+}
+
+impl FundOperator {
+    fn initialize(&mut self, operator: Pubkey) {
+        *self = Zeroable::zeroed();
+
+        self.operator = operator;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operator_allowlist() {
+        let mut allowlist = OperatorAllowlist::zeroed();
+        let operator1 = Pubkey::new_unique();
+        let operator2 = Pubkey::new_unique();
+
+        // permissionless by default
+        assert!(allowlist.is_operator_authorized(&operator1));
+        allowlist.set_enabled(true).unwrap_err();
+
+        allowlist.add_operator(operator1).unwrap();
+        allowlist.add_operator(operator1).unwrap_err();
+        allowlist.set_enabled(true).unwrap();
+        assert!(allowlist.is_operator_authorized(&operator1));
+        assert!(!allowlist.is_operator_authorized(&operator2));
+
+        // cannot remove the last operator while enabled
+        allowlist.remove_operator(&operator1).unwrap_err();
+        allowlist.add_operator(operator2).unwrap();
+        allowlist.remove_operator(&operator1).unwrap();
+        assert!(!allowlist.is_operator_authorized(&operator1));
+        assert!(allowlist.is_operator_authorized(&operator2));
+
+        for _ in 1..FUND_ACCOUNT_MAX_OPERATORS {
+            allowlist.add_operator(Pubkey::new_unique()).unwrap();
+        }
+        allowlist.add_operator(Pubkey::new_unique()).unwrap_err();
+    }
+
+    #[test]
+    fn test_record_operation() {
+        let mut allowlist = OperatorAllowlist::zeroed();
+        let operator = Pubkey::new_unique();
+        allowlist.add_operator(operator).unwrap();
+
+        allowlist.record_operation(&operator, 10);
+        allowlist.record_operation(&operator, 12);
+        allowlist.record_operation(&Pubkey::new_unique(), 13);
+
+        let fund_operator = allowlist.get_operators_iter().next().unwrap();
+        assert_eq!(fund_operator.num_operated, 2);
+        assert_eq!(fund_operator.last_operated_slot, 12);
+    }
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+
+use crate::modules::pricing::TokenPricingSourcePod;
+
+/// Circuit breaker against a bad value reported by a pricing source.
+///
+/// It is tripped when a token price deviates more than the threshold between consecutive price updates,
+/// then deposit and withdrawal stay disabled until the fund manager acknowledges it.
+#[zero_copy]
+pub(super) struct PriceDeviationCircuitBreaker {
+    /// configuration: max deviation of receipt token price in basis points (0 = disabled).
+    max_receipt_token_price_deviation_bps: u16,
+    tripped: u8,
+    _padding: [u8; 5],
+
+    /// the first price deviation detected since the last acknowledgement.
+    tripped_slot: u64,
+    tripped_token_mint: Pubkey,
+    tripped_token_pricing_source: TokenPricingSourcePod,
+    tripped_previous_one_token_as_sol: u64,
+    tripped_one_token_as_sol: u64,
+
+    _reserved: [u8; 32],
+}
+
+impl PriceDeviationCircuitBreaker {
+    pub fn get_max_receipt_token_price_deviation_bps(&self) -> u16 {
+        self.max_receipt_token_price_deviation_bps
+    }
+
+    pub fn set_max_receipt_token_price_deviation_bps(&mut self, max_deviation_bps: u16) {
+        self.max_receipt_token_price_deviation_bps = max_deviation_bps;
+    }
+
+    pub fn is_tripped(&self) -> bool {
+        self.tripped == 1
+    }
+
+    /// returns (slot, token_mint, token_pricing_source, previous_one_token_as_sol, one_token_as_sol) if tripped.
+    pub fn get_tripped_deviation(&self) -> Option<(u64, Pubkey, &TokenPricingSourcePod, u64, u64)> {
+        self.is_tripped().then_some((
+            self.tripped_slot,
+            self.tripped_token_mint,
+            &self.tripped_token_pricing_source,
+            self.tripped_previous_one_token_as_sol,
+            self.tripped_one_token_as_sol,
+        ))
+    }
+
+    /// Only the first deviation is kept until acknowledged.
+    pub fn trip(
+        &mut self,
+        slot: u64,
+        token_mint: Pubkey,
+        token_pricing_source: &TokenPricingSourcePod,
+        previous_one_token_as_sol: u64,
+        one_token_as_sol: u64,
+    ) {
+        if self.is_tripped() {
+            return;
+        }
+
+        self.tripped = 1;
+        self.tripped_slot = slot;
+        self.tripped_token_mint = token_mint;
+        self.tripped_token_pricing_source = *token_pricing_source;
+        self.tripped_previous_one_token_as_sol = previous_one_token_as_sol;
+        self.tripped_one_token_as_sol = one_token_as_sol;
+    }
+
+    pub fn reset(&mut self) {
+        let max_receipt_token_price_deviation_bps = self.max_receipt_token_price_deviation_bps;
+
+        *self = Zeroable::zeroed();
+        self.max_receipt_token_price_deviation_bps = max_receipt_token_price_deviation_bps;
+    }
+}
+
+/// Deviation is not checked if the threshold is not configured or the token has never been priced.
+pub(super) fn is_price_deviation_exceeded(
+    max_deviation_bps: u16,
+    previous_one_token_as_sol: u64,
+    one_token_as_sol: u64,
+) -> bool {
+    if max_deviation_bps == 0 || previous_one_token_as_sol == 0 {
+        return false;
+    }
+
+    let deviation = previous_one_token_as_sol.abs_diff(one_token_as_sol) as u128;
+    deviation * 10_000 > previous_one_token_as_sol as u128 * max_deviation_bps as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::modules::pricing::TokenPricingSource;
+
+    use super::*;
+
+    #[test]
+    fn test_is_price_deviation_exceeded() {
+        assert!(!is_price_deviation_exceeded(0, 1_000, 2_000));
+        assert!(!is_price_deviation_exceeded(100, 0, 2_000));
+
+        assert!(!is_price_deviation_exceeded(100, 1_000, 1_010));
+        assert!(!is_price_deviation_exceeded(100, 1_000, 990));
+        assert!(is_price_deviation_exceeded(100, 1_000, 1_011));
+        assert!(is_price_deviation_exceeded(100, 1_000, 989));
+        assert!(is_price_deviation_exceeded(100, 1_000, 0));
+    }
+
+    #[test]
+    fn test_trip_and_reset() {
+        let mut circuit_breaker = PriceDeviationCircuitBreaker::zeroed();
+        circuit_breaker.set_max_receipt_token_price_deviation_bps(100);
+        assert!(circuit_breaker.get_tripped_deviation().is_none());
+
+        let token_mint = Pubkey::new_unique();
+        let mut token_pricing_source = TokenPricingSourcePod::zeroed();
+        TokenPricingSource::SPLStakePool {
+            address: Pubkey::new_unique(),
+        }
+        .serialize_as_pod(&mut token_pricing_source);
+
+        circuit_breaker.trip(1, token_mint, &token_pricing_source, 1_000, 2_000);
+        circuit_breaker.trip(2, Pubkey::new_unique(), &token_pricing_source, 1_000, 3_000);
+
+        // the first deviation is kept
+        let (slot, tripped_token_mint, _, previous_one_token_as_sol, one_token_as_sol) =
+            circuit_breaker.get_tripped_deviation().unwrap();
+        assert_eq!(slot, 1);
+        assert_eq!(tripped_token_mint, token_mint);
+        assert_eq!(previous_one_token_as_sol, 1_000);
+        assert_eq!(one_token_as_sol, 2_000);
+
+        circuit_breaker.reset();
+        assert!(!circuit_breaker.is_tripped());
+        assert_eq!(
+            circuit_breaker.get_max_receipt_token_price_deviation_bps(),
+            100
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+
+use crate::errors::ErrorCode;
+
+pub const FUND_ACCOUNT_MAX_RECEIPT_TOKEN_PRICE_SAMPLES: usize = 64;
+/// A new sample is recorded only after this interval, so the history covers about 5 hours.
+pub const FUND_RECEIPT_TOKEN_PRICE_SAMPLE_INTERVAL_SECONDS: i64 = 300;
+
+/// Ring buffer of receipt token price samples to provide manipulation-resistant TWAP.
+#[zero_copy]
+pub(super) struct ReceiptTokenPriceHistory {
+    num_samples: u8,
+    next_sample_index: u8,
+    _padding: [u8; 6],
+    samples: [ReceiptTokenPriceSample; FUND_ACCOUNT_MAX_RECEIPT_TOKEN_PRICE_SAMPLES],
+}
+
+impl ReceiptTokenPriceHistory {
+    /// returns samples from the oldest to the latest.
+    pub fn get_samples_iter(&self) -> impl Iterator<Item = &ReceiptTokenPriceSample> {
+        let oldest_sample_index = if (self.num_samples as usize) < self.samples.len() {
+            0
+        } else {
+            self.next_sample_index as usize
+        };
+
+        (0..self.num_samples as usize).map(move |i| {
+            &self.samples[(oldest_sample_index + i) % FUND_ACCOUNT_MAX_RECEIPT_TOKEN_PRICE_SAMPLES]
+        })
+    }
+
+    fn get_latest_sample(&self) -> Option<&ReceiptTokenPriceSample> {
+        self.get_samples_iter().last()
+    }
+
+    /// Sample is skipped if the price is not available yet or the sample interval has not passed.
+    pub fn record_sample(&mut self, slot: u64, timestamp: i64, one_receipt_token_as_sol: u64) {
+        if one_receipt_token_as_sol == 0 {
+            return;
+        }
+        if self.get_latest_sample().is_some_and(|sample| {
+            timestamp - sample.timestamp < FUND_RECEIPT_TOKEN_PRICE_SAMPLE_INTERVAL_SECONDS
+        }) {
+            return;
+        }
+
+        self.samples[self.next_sample_index as usize] = ReceiptTokenPriceSample {
+            slot,
+            timestamp,
+            one_receipt_token_as_sol,
+        };
+        self.next_sample_index = ((self.next_sample_index as usize + 1)
+            % FUND_ACCOUNT_MAX_RECEIPT_TOKEN_PRICE_SAMPLES) as u8;
+        if (self.num_samples as usize) < FUND_ACCOUNT_MAX_RECEIPT_TOKEN_PRICE_SAMPLES {
+            self.num_samples += 1;
+        }
+    }
+
+    /// Each sample's price is held until the next sample, and the latest one is held until now.
+    ///
+    /// returns (one_receipt_token_as_sol_twap, num_samples_in_window)
+    pub fn get_twap(&self, current_timestamp: i64, window_seconds: i64) -> Result<(u64, u8)> {
+        require_gt!(
+            window_seconds,
+            0,
+            ErrorCode::FundReceiptTokenPriceHistoryInsufficientError
+        );
+
+        let window_start = current_timestamp - window_seconds;
+        let oldest_sample = self
+            .get_samples_iter()
+            .next()
+            .ok_or_else(|| error!(ErrorCode::FundReceiptTokenPriceHistoryInsufficientError))?;
+        require_gte!(
+            window_start,
+            oldest_sample.timestamp,
+            ErrorCode::FundReceiptTokenPriceHistoryInsufficientError
+        );
+
+        let mut weighted_sum = 0u128;
+        let mut num_samples_in_window = 0u8;
+        let next_sample_timestamps = self
+            .get_samples_iter()
+            .skip(1)
+            .map(|sample| sample.timestamp)
+            .chain([current_timestamp]);
+        for (sample, next_sample_timestamp) in self.get_samples_iter().zip(next_sample_timestamps) {
+            let duration =
+                next_sample_timestamp.min(current_timestamp) - sample.timestamp.max(window_start);
+            if duration > 0 {
+                weighted_sum += sample.one_receipt_token_as_sol as u128 * duration as u128;
+                num_samples_in_window += 1;
+            }
+        }
+
+        let twap = u64::try_from(weighted_sum / window_seconds as u128)
+            .map_err(|_| error!(ErrorCode::CalculationArithmeticException))?;
+
+        Ok((twap, num_samples_in_window))
+    }
+}
+
+#[zero_copy]
+pub(super) struct ReceiptTokenPriceSample {
+    pub slot: u64,
+    pub timestamp: i64,
+    pub one_receipt_token_as_sol: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_sample() {
+        let mut history = ReceiptTokenPriceHistory::zeroed();
+
+        history.record_sample(1, 0, 0);
+        assert_eq!(history.get_samples_iter().count(), 0);
+
+        history.record_sample(1, 0, 1_000);
+        history.record_sample(
+            2,
+            FUND_RECEIPT_TOKEN_PRICE_SAMPLE_INTERVAL_SECONDS - 1,
+            1_001,
+        );
+        assert_eq!(history.get_samples_iter().count(), 1);
+
+        for i in 1..FUND_ACCOUNT_MAX_RECEIPT_TOKEN_PRICE_SAMPLES as i64 + 10 {
+            history.record_sample(
+                i as u64,
+                i * FUND_RECEIPT_TOKEN_PRICE_SAMPLE_INTERVAL_SECONDS,
+                1_000 + i as u64,
+            );
+        }
+
+        // the oldest samples are overwritten
+        let samples = history.get_samples_iter().collect::<Vec<_>>();
+        assert_eq!(samples.len(), FUND_ACCOUNT_MAX_RECEIPT_TOKEN_PRICE_SAMPLES);
+        assert_eq!(samples[0].one_receipt_token_as_sol, 1_010);
+        assert!(samples
+            .windows(2)
+            .all(|pair| pair[0].timestamp < pair[1].timestamp));
+        assert_eq!(
+            history
+                .get_latest_sample()
+                .unwrap()
+                .one_receipt_token_as_sol,
+            1_000 + FUND_ACCOUNT_MAX_RECEIPT_TOKEN_PRICE_SAMPLES as u64 + 9,
+        );
+    }
+
+    #[test]
+    fn test_get_twap() {
+        let mut history = ReceiptTokenPriceHistory::zeroed();
+        history.get_twap(0, 100).unwrap_err();
+
+        history.record_sample(1, 1_000, 1_000);
+        history.record_sample(2, 1_300, 2_000);
+        history.record_sample(3, 1_600, 4_000);
+
+        // not enough history
+        history.get_twap(1_700, 701).unwrap_err();
+        history.get_twap(1_700, 0).unwrap_err();
+
+        // (1_000 * 300 + 2_000 * 300 + 4_000 * 100) / 700
+        assert_eq!(history.get_twap(1_700, 700).unwrap(), (1_857, 3));
+        // (2_000 * 100 + 4_000 * 100) / 200
+        assert_eq!(history.get_twap(1_700, 200).unwrap(), (3_000, 2));
+        assert_eq!(history.get_twap(1_700, 50).unwrap(), (4_000, 1));
+    }
+}
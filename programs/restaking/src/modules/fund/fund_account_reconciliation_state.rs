@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+/// Reconciliation of the bookkeeping against the balances of the fund reserve accounts.
+///
+/// Operation is disabled when a reserve account falls short of its reserved amount more than the threshold,
+/// then it stays disabled until the fund manager re-enables it.
+#[zero_copy]
+pub(super) struct ReconciliationState {
+    /// configuration: max shortfall of a reserve account in basis points of its reserved amount (0 = disabled).
+    max_shortfall_bps: u16,
+    _padding: [u8; 6],
+
+    last_reconciled_slot: u64,
+    /// number of reserve accounts which did not match the bookkeeping at the last reconciliation.
+    last_num_discrepancies: u64,
+
+    _reserved: [u8; 32],
+}
+
+impl ReconciliationState {
+    pub fn get_max_shortfall_bps(&self) -> u16 {
+        self.max_shortfall_bps
+    }
+
+    pub fn set_max_shortfall_bps(&mut self, max_shortfall_bps: u16) -> Result<()> {
+        require_gte!(10_000, max_shortfall_bps);
+
+        self.max_shortfall_bps = max_shortfall_bps;
+
+        Ok(())
+    }
+
+    pub fn record_reconciliation(&mut self, slot: u64, num_discrepancies: usize) {
+        self.last_reconciled_slot = slot;
+        self.last_num_discrepancies = num_discrepancies as u64;
+    }
+}
+
+/// Balance of a fund reserve account compared to the bookkeeping, reported by `operator_reconcile_fund`.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub struct FundReconciliationDiscrepancy {
+    /// `None` for SOL.
+    pub token_mint: Option<Pubkey>,
+    pub reserve_account: Pubkey,
+    /// reserved amount of the bookkeeping.
+    pub expected_amount: u64,
+    /// balance of the reserve account, excluding rent-exempt lamports for SOL.
+    pub actual_amount: u64,
+    /// balance of the treasury account which can cover the shortfall, `None` if the token has no treasury account.
+    pub treasury_amount: Option<u64>,
+}
+
+/// Surplus is not a discrepancy to block operation, e.g. direct transfer to the reserve account.
+pub(super) fn is_shortfall_exceeded(
+    max_shortfall_bps: u16,
+    expected_amount: u64,
+    actual_amount: u64,
+) -> bool {
+    if max_shortfall_bps == 0 || actual_amount >= expected_amount {
+        return false;
+    }
+
+    let shortfall = (expected_amount - actual_amount) as u128;
+    shortfall * 10_000 > expected_amount as u128 * max_shortfall_bps as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    #[test]
+    fn test_is_shortfall_exceeded() {
+        assert!(!is_shortfall_exceeded(0, 1_000, 0));
+        assert!(!is_shortfall_exceeded(100, 1_000, 2_000));
+        assert!(!is_shortfall_exceeded(100, 0, 0));
+
+        assert!(!is_shortfall_exceeded(100, 1_000, 990));
+        assert!(is_shortfall_exceeded(100, 1_000, 989));
+        assert!(is_shortfall_exceeded(100, 1_000, 0));
+        assert!(is_shortfall_exceeded(1, u64::MAX, u64::MAX / 2));
+    }
+
+    #[test]
+    fn test_set_max_shortfall_bps() {
+        let mut state = ReconciliationState::zeroed();
+        state.set_max_shortfall_bps(10_000).unwrap();
+        assert_eq!(state.get_max_shortfall_bps(), 10_000);
+
+        state.set_max_shortfall_bps(10_001).unwrap_err();
+        assert_eq!(state.get_max_shortfall_bps(), 10_000);
+    }
+}
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+
+use crate::constants::PROGRAM_REVENUE_ADDRESS;
+use crate::errors::ErrorCode;
+use crate::utils::get_split_amounts_u64;
+
+pub const FUND_ACCOUNT_MAX_REVENUE_RECIPIENTS: usize = 4;
+
+/// Split of fund revenue (withdrawal fee, performance and management fee,
+/// reward commission and remaining reward) among recipients.
+///
+/// Whole revenue goes to the program revenue address unless recipients are configured.
+#[zero_copy]
+pub(super) struct RevenueSplit {
+    num_recipients: u8,
+    _padding: [u8; 7],
+    recipients: [RevenueRecipient; FUND_ACCOUNT_MAX_REVENUE_RECIPIENTS],
+}
+
+impl RevenueSplit {
+    /// returns [(recipient, share_bps)]
+    pub fn get_recipients_iter(&self) -> impl Iterator<Item = (Pubkey, u16)> + '_ {
+        let default_recipient =
+            (self.num_recipients == 0).then_some((PROGRAM_REVENUE_ADDRESS, 10_000));
+
+        self.recipients[..self.num_recipients as usize]
+            .iter()
+            .map(|recipient| (recipient.recipient, recipient.share_bps))
+            .chain(default_recipient)
+    }
+
+    /// Empty list of recipients resets to the program revenue address.
+    pub fn set_recipients(&mut self, recipients: &[FundRevenueRecipient]) -> Result<()> {
+        require_gte!(
+            FUND_ACCOUNT_MAX_REVENUE_RECIPIENTS,
+            recipients.len(),
+            ErrorCode::FundExceededMaxRevenueRecipientsError,
+        );
+
+        if !recipients.is_empty() {
+            require_eq!(
+                recipients
+                    .iter()
+                    .map(|recipient| recipient.share_bps as u32)
+                    .sum::<u32>(),
+                10_000,
+                ErrorCode::FundInvalidRevenueSplitError,
+            );
+        }
+
+        for (i, recipient) in recipients.iter().enumerate() {
+            require_gt!(
+                recipient.share_bps,
+                0,
+                ErrorCode::FundInvalidRevenueSplitError
+            );
+            if recipients[..i]
+                .iter()
+                .any(|other| other.recipient == recipient.recipient)
+            {
+                err!(ErrorCode::FundInvalidRevenueSplitError)?
+            }
+        }
+
+        *self = Zeroable::zeroed();
+        for (i, recipient) in recipients.iter().enumerate() {
+            self.recipients[i].initialize(recipient.recipient, recipient.share_bps);
+        }
+        self.num_recipients = recipients.len() as u8;
+
+        Ok(())
+    }
+
+    /// returns [(recipient, amount)]
+    pub fn split(&self, amount: u64) -> Result<Vec<FundRevenueShare>> {
+        let (recipients, shares_bps): (Vec<_>, Vec<_>) = self.get_recipients_iter().unzip();
+
+        Ok(recipients
+            .into_iter()
+            .zip(get_split_amounts_u64(amount, &shares_bps)?)
+            .map(|(recipient, amount)| FundRevenueShare { recipient, amount })
+            .collect())
+    }
+}
+
+#[zero_copy]
+pub(super) struct RevenueRecipient {
+    recipient: Pubkey,
+    share_bps: u16,
+    _padding: [u8; 6],
+    _reserved: [u8; 8],
+}
+
+impl RevenueRecipient {
+    fn initialize(&mut self, recipient: Pubkey, share_bps: u16) {
+        *self = Zeroable::zeroed();
+
+        self.recipient = recipient;
+        self.share_bps = share_bps;
+    }
+}
+
+#[derive(Clone, InitSpace, AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub struct FundRevenueRecipient {
+    pub recipient: Pubkey,
+    pub share_bps: u16,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug)]
+pub struct FundRevenueShare {
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_revenue_split() {
+        let mut revenue_split = RevenueSplit::zeroed();
+        let recipient1 = Pubkey::new_unique();
+        let recipient2 = Pubkey::new_unique();
+
+        // program revenue by default
+        let shares = revenue_split.split(1_000).unwrap();
+        assert_eq!(shares.len(), 1);
+        assert_eq!(shares[0].recipient, PROGRAM_REVENUE_ADDRESS);
+        assert_eq!(shares[0].amount, 1_000);
+
+        // shares must sum up to 100%
+        revenue_split
+            .set_recipients(&[FundRevenueRecipient {
+                recipient: recipient1,
+                share_bps: 9_000,
+            }])
+            .unwrap_err();
+        revenue_split
+            .set_recipients(&[
+                FundRevenueRecipient {
+                    recipient: recipient1,
+                    share_bps: 5_000,
+                },
+                FundRevenueRecipient {
+                    recipient: recipient1,
+                    share_bps: 5_000,
+                },
+            ])
+            .unwrap_err();
+
+        revenue_split
+            .set_recipients(&[
+                FundRevenueRecipient {
+                    recipient: recipient1,
+                    share_bps: 3_333,
+                },
+                FundRevenueRecipient {
+                    recipient: recipient2,
+                    share_bps: 6_667,
+                },
+            ])
+            .unwrap();
+
+        // remainder goes to the first recipient
+        let shares = revenue_split.split(1_000).unwrap();
+        assert_eq!(shares.len(), 2);
+        assert_eq!(shares[0].recipient, recipient1);
+        assert_eq!(shares[0].amount, 334);
+        assert_eq!(shares[1].recipient, recipient2);
+        assert_eq!(shares[1].amount, 666);
+
+        revenue_split.set_recipients(&[]).unwrap();
+        assert_eq!(
+            revenue_split.get_recipients_iter().collect::<Vec<_>>(),
+            vec![(PROGRAM_REVENUE_ADDRESS, 10_000)],
+        );
+    }
+}
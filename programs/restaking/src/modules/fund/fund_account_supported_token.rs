@@ -32,7 +32,11 @@ pub(super) struct SupportedToken {
     /// informative
     pub one_token_as_receipt_token: u64,
 
-    _reserved: [u8; 48],
+    /// configuration: max deviation of `one_token_as_sol` between price updates in basis points (0 = disabled).
+    pub max_price_deviation_bps: u16,
+    _padding3: [u8; 6],
+
+    _reserved: [u8; 40],
 }
 
 impl SupportedToken {
@@ -85,4 +89,8 @@ impl SupportedToken {
 
         Ok(())
     }
+
+    pub fn set_max_price_deviation_bps(&mut self, max_deviation_bps: u16) {
+        self.max_price_deviation_bps = max_deviation_bps;
+    }
 }
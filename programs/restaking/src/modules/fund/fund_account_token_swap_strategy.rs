@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
 use bytemuck::Zeroable;
 
+use crate::errors::ErrorCode;
+use crate::modules::pricing::{TokenPricingSource, TokenPricingSourcePod};
 use crate::modules::swap::{TokenSwapSource, TokenSwapSourcePod};
 
+pub const FUND_ACCOUNT_MAX_TOKEN_SWAP_SLIPPAGE_BPS: u16 = 1_000;
+
 /// A strategy to swap `from_token` to `to_token`.
 ///
 /// There are two restrictions that will be loosen in the future.
@@ -18,12 +22,21 @@ use crate::modules::swap::{TokenSwapSource, TokenSwapSourcePod};
 /// In the future, operation cycle will support multiple-hop swap, for example, A -> B -> C.
 /// In this case `to_token` need not be one of fund's supported token.
 /// However, to prevent endless swap, token swap strategies must form DAG(vertex = token, edge = strategy).
+///
+/// The expected output of a swap is quoted by the fund's pricing service,
+/// using `from_token_pricing_source` for `from_token`.
+/// A swap is rejected or shrunk so that the realized output does not fall below
+/// the expected output by more than `max_slippage_bps`.
+/// Swaps are rejected until the pricing source is configured.
 #[zero_copy]
 pub(super) struct TokenSwapStrategy {
     pub from_token_mint: Pubkey,
     pub to_token_mint: Pubkey,
     pub swap_source: TokenSwapSourcePod,
-    _reserved: [u8; 128],
+    pub from_token_pricing_source: TokenPricingSourcePod,
+    pub max_slippage_bps: u16,
+    _padding: [u8; 6],
+    _reserved: [u8; 80],
 }
 
 impl TokenSwapStrategy {
@@ -39,4 +52,35 @@ impl TokenSwapStrategy {
         self.to_token_mint = to_token_mint;
         swap_source.serialize_as_pod(&mut self.swap_source);
     }
+
+    pub fn set_slippage_protection(
+        &mut self,
+        from_token_pricing_source: Option<TokenPricingSource>,
+        max_slippage_bps: u16,
+    ) -> Result<()> {
+        require_gte!(
+            FUND_ACCOUNT_MAX_TOKEN_SWAP_SLIPPAGE_BPS,
+            max_slippage_bps,
+            ErrorCode::FundInvalidTokenSwapStrategyMaxSlippageError
+        );
+
+        match from_token_pricing_source {
+            Some(pricing_source) => {
+                pricing_source.serialize_as_pod(&mut self.from_token_pricing_source)
+            }
+            None => self.from_token_pricing_source.set_none(),
+        }
+        self.max_slippage_bps = max_slippage_bps;
+
+        Ok(())
+    }
+
+    /// returns minimum acceptable amount of `to_token` for the expected amount.
+    pub fn get_min_to_token_amount(&self, expected_to_token_amount: u64) -> Result<u64> {
+        crate::utils::get_proportional_amount_u64(
+            expected_to_token_amount,
+            (10_000 - self.max_slippage_bps) as u64,
+            10_000,
+        )
+    }
 }
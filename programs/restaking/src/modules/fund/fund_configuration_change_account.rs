@@ -0,0 +1,257 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::modules::pricing::TokenPricingSource;
+use crate::modules::swap::TokenSwapSource;
+
+use super::*;
+
+#[constant]
+/// ## Version History
+/// * v1: Initial Version
+pub const FUND_CONFIGURATION_CHANGE_ACCOUNT_CURRENT_VERSION: u16 = 1;
+
+/// Material fund configuration change which must go through the timelock queue
+/// once the fund has a configuration change delay.
+#[derive(Clone, InitSpace, AnchorSerialize, AnchorDeserialize, PartialEq)]
+#[cfg_attr(test, derive(Debug))]
+pub enum FundConfigurationChange {
+    UpdateFeeRates {
+        withdrawal_fee_rate_bps: u16,
+        performance_fee_rate_bps: u16,
+    },
+    UpdateRestakingVaultRewardCommissionRate {
+        vault: Pubkey,
+        reward_commission_rate_bps: u16,
+    },
+    /// remaining accounts: `[supported_token_mint, fund_supported_token_reserve_account, ...pricing_sources]`
+    AddSupportedToken {
+        supported_token_mint: Pubkey,
+        pricing_source: TokenPricingSource,
+    },
+    /// remaining accounts: `[from_token_mint, to_token_mint, swap_source_account]`
+    AddTokenSwapStrategy {
+        from_token_mint: Pubkey,
+        to_token_mint: Pubkey,
+        swap_source: TokenSwapSource,
+    },
+    /// remaining accounts: `[from_token_mint, to_token_mint, swap_source_account, ...pricing_sources]`
+    UpdateTokenSwapStrategy {
+        from_token_mint: Pubkey,
+        from_token_pricing_source: Option<TokenPricingSource>,
+        max_slippage_bps: u16,
+    },
+    UpdateConfigurationChangeDelay {
+        delay_seconds: i64,
+    },
+    UpdateManagementFeeRate {
+        management_fee_rate_bps: u16,
+    },
+    UpdateRevenueSplit {
+        #[max_len(FUND_ACCOUNT_MAX_REVENUE_RECIPIENTS)]
+        recipients: Vec<FundRevenueRecipient>,
+    },
+    RemoveTokenSwapStrategy {
+        from_token_mint: Pubkey,
+        to_token_mint: Pubkey,
+        swap_source: TokenSwapSource,
+    },
+    /// remaining accounts: `[normalized_token_mint, fund_normalized_token_reserve_account, normalized_token_pool_account, ...pricing_sources]`
+    SetNormalizedToken {
+        normalized_token_mint: Pubkey,
+    },
+    /// remaining accounts: `[vault, vault_supported_token_mint, vault_receipt_token_mint, fund_vault_receipt_token_account, ...pricing_sources]`
+    AddRestakingVault {
+        vault: Pubkey,
+        pricing_source: TokenPricingSource,
+    },
+    /// remaining accounts: `[vault, operator, vault_operator_delegation]`
+    AddRestakingVaultDelegation {
+        vault: Pubkey,
+        operator: Pubkey,
+    },
+    UpdateInstantWithdrawalStrategy {
+        enabled: bool,
+        min_fee_rate_bps: u16,
+        max_fee_rate_bps: u16,
+        fee_curve_exponent: u8,
+        liquidity_target_rate_bps: u16,
+    },
+    UpdateInKindWithdrawalStrategy {
+        enabled: bool,
+        fee_rate_bps: u16,
+    },
+    UpdateSolStrategy {
+        sol_depositable: bool,
+        sol_accumulated_deposit_capacity_amount: u64,
+        sol_accumulated_deposit_amount: Option<u64>,
+        sol_withdrawable: bool,
+        sol_withdrawal_normal_reserve_rate_bps: u16,
+        sol_withdrawal_normal_reserve_max_amount: u64,
+    },
+    UpdateSupportedTokenStrategy {
+        token_mint: Pubkey,
+        token_depositable: bool,
+        token_accumulated_deposit_capacity_amount: u64,
+        token_accumulated_deposit_amount: Option<u64>,
+        token_withdrawable: bool,
+        token_withdrawal_normal_reserve_rate_bps: u16,
+        token_withdrawal_normal_reserve_max_amount: u64,
+        sol_allocation_weight: u64,
+        sol_allocation_capacity_amount: u64,
+    },
+    UpdateMaxPriceAgeSlots {
+        max_price_age_slots: u64,
+    },
+    UpdatePriceDeviationThreshold {
+        max_receipt_token_price_deviation_bps: u16,
+    },
+    UpdateSupportedTokenPriceDeviationThreshold {
+        token_mint: Pubkey,
+        max_price_deviation_bps: u16,
+    },
+    UpdateReconciliationThreshold {
+        max_shortfall_bps: u16,
+    },
+    SetOperatorAllowlistEnabled {
+        enabled: bool,
+    },
+    AddOperator {
+        operator: Pubkey,
+    },
+    RemoveOperator {
+        operator: Pubkey,
+    },
+}
+
+/// Queued configuration change of a fund.
+///
+/// The fund manager queues a change, then it becomes executable after the fund's
+/// configuration change delay. Until then, the guardian can cancel it,
+/// which refunds the rent to the payer of the change.
+#[account]
+#[derive(InitSpace)]
+pub struct FundConfigurationChangeAccount {
+    data_version: u16,
+    bump: u8,
+    pub receipt_token_mint: Pubkey,
+    pub change_id: u64,
+    change: FundConfigurationChange,
+    queued_at: i64,
+    executable_at: i64,
+    pub payer: Pubkey,
+
+    _reserved: [u8; 32],
+}
+
+impl FundConfigurationChangeAccount {
+    pub const SEED: &'static [u8] = b"fund_configuration_change";
+
+    pub fn get_bump(&self) -> u8 {
+        self.bump
+    }
+
+    #[inline(always)]
+    pub fn is_latest_version(&self) -> bool {
+        self.data_version == FUND_CONFIGURATION_CHANGE_ACCOUNT_CURRENT_VERSION
+    }
+
+    pub fn find_account_address(receipt_token_mint: &Pubkey, change_id: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                Self::SEED,
+                receipt_token_mint.as_ref(),
+                &change_id.to_le_bytes(),
+            ],
+            &crate::ID,
+        )
+        .0
+    }
+
+    pub(super) fn initialize(
+        &mut self,
+        bump: u8,
+        receipt_token_mint: Pubkey,
+        change_id: u64,
+        change: FundConfigurationChange,
+        queued_at: i64,
+        delay_seconds: i64,
+        payer: Pubkey,
+    ) {
+        self.data_version = FUND_CONFIGURATION_CHANGE_ACCOUNT_CURRENT_VERSION;
+        self.bump = bump;
+        self.receipt_token_mint = receipt_token_mint;
+        self.change_id = change_id;
+        self.change = change;
+        self.queued_at = queued_at;
+        self.executable_at = queued_at + delay_seconds;
+        self.payer = payer;
+    }
+
+    #[inline(always)]
+    pub(super) fn get_change(&self) -> &FundConfigurationChange {
+        &self.change
+    }
+
+    #[inline(always)]
+    pub(super) fn get_executable_at(&self) -> i64 {
+        self.executable_at
+    }
+
+    /// The change is executable once both the delay at the time of queueing and
+    /// the current delay have passed, so raising the delay also holds back pending changes.
+    pub(super) fn validate_executable(&self, current_delay_seconds: i64, now: i64) -> Result<()> {
+        require_gte!(
+            now,
+            self.executable_at
+                .max(self.queued_at + current_delay_seconds),
+            ErrorCode::FundConfigurationChangeNotExecutableYetError
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_executable() {
+        let mut change_account = FundConfigurationChangeAccount {
+            data_version: 0,
+            bump: 0,
+            receipt_token_mint: Pubkey::default(),
+            change_id: 0,
+            change: FundConfigurationChange::UpdateConfigurationChangeDelay { delay_seconds: 0 },
+            queued_at: 0,
+            executable_at: 0,
+            payer: Pubkey::default(),
+            _reserved: [0; 32],
+        };
+        change_account.initialize(
+            255,
+            Pubkey::new_unique(),
+            3,
+            FundConfigurationChange::UpdateFeeRates {
+                withdrawal_fee_rate_bps: 10,
+                performance_fee_rate_bps: 1_000,
+            },
+            1_000,
+            100,
+            Pubkey::new_unique(),
+        );
+        assert!(change_account.is_latest_version());
+        assert_eq!(change_account.get_executable_at(), 1_100);
+
+        change_account.validate_executable(100, 1_099).unwrap_err();
+        change_account.validate_executable(100, 1_100).unwrap();
+
+        // raised delay also applies to the pending change
+        change_account.validate_executable(200, 1_100).unwrap_err();
+        change_account.validate_executable(200, 1_200).unwrap();
+
+        // lowered delay does not shorten the pending change
+        change_account.validate_executable(0, 1_099).unwrap_err();
+    }
+}
@@ -5,7 +5,6 @@ use anchor_spl::token_2022::spl_token_2022;
 use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::constants::FUND_MANAGER_PUBKEY;
 use crate::errors::ErrorCode;
 use crate::events;
 use crate::modules::normalization;
@@ -148,6 +147,36 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         supported_token_mint: &InterfaceAccount<Mint>,
         pricing_source: TokenPricingSource,
         pricing_sources: &'info [AccountInfo<'info>],
+    ) -> Result<(
+        events::FundManagerUpdatedFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
+        self.require_configuration_change_not_timelocked()?;
+
+        let was_price_deviation_circuit_breaker_tripped = self
+            .fund_account
+            .load()?
+            .is_price_deviation_circuit_breaker_tripped();
+
+        let event = self.add_supported_token(
+            fund_supported_token_reserve_account,
+            supported_token_mint,
+            pricing_source,
+            pricing_sources,
+        )?;
+        let tripped_event = self.create_operator_tripped_fund_price_circuit_breaker_event(
+            was_price_deviation_circuit_breaker_tripped,
+        )?;
+
+        Ok((event, tripped_event))
+    }
+
+    fn add_supported_token(
+        &mut self,
+        fund_supported_token_reserve_account: &InterfaceAccount<TokenAccount>,
+        supported_token_mint: &InterfaceAccount<Mint>,
+        pricing_source: TokenPricingSource,
+        pricing_sources: &'info [AccountInfo<'info>],
     ) -> Result<events::FundManagerUpdatedFund> {
         require_keys_eq!(
             fund_supported_token_reserve_account.owner,
@@ -289,7 +318,15 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         normalized_token_mint: Option<&InterfaceAccount<Mint>>,
         normalized_token_pool_account: Option<&Account<normalization::NormalizedTokenPoolAccount>>,
         pricing_sources: &'info [AccountInfo<'info>],
-    ) -> Result<events::FundManagerUpdatedFund> {
+    ) -> Result<(
+        events::FundManagerUpdatedFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
+        let was_price_deviation_circuit_breaker_tripped = self
+            .fund_account
+            .load()?
+            .is_price_deviation_circuit_breaker_tripped();
+
         let mut fund_account = self.fund_account.load_mut()?;
 
         // NSOL must not support this token
@@ -325,7 +362,12 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
 
         require_gte!(new_receipt_token_price, old_receipt_token_price);
 
-        self.create_fund_manager_updated_fund_event()
+        let event = self.create_fund_manager_updated_fund_event()?;
+        let tripped_event = self.create_operator_tripped_fund_price_circuit_breaker_event(
+            was_price_deviation_circuit_breaker_tripped,
+        )?;
+
+        Ok((event, tripped_event))
     }
 
     pub fn process_set_normalized_token(
@@ -334,6 +376,36 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         normalized_token_mint: &InterfaceAccount<Mint>,
         normalized_token_pool: &Account<NormalizedTokenPoolAccount>,
         pricing_sources: &'info [AccountInfo<'info>],
+    ) -> Result<(
+        events::FundManagerUpdatedFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
+        self.require_configuration_change_not_timelocked()?;
+
+        let was_price_deviation_circuit_breaker_tripped = self
+            .fund_account
+            .load()?
+            .is_price_deviation_circuit_breaker_tripped();
+
+        let event = self.set_normalized_token(
+            fund_normalized_token_reserve_account,
+            normalized_token_mint,
+            normalized_token_pool,
+            pricing_sources,
+        )?;
+        let tripped_event = self.create_operator_tripped_fund_price_circuit_breaker_event(
+            was_price_deviation_circuit_breaker_tripped,
+        )?;
+
+        Ok((event, tripped_event))
+    }
+
+    fn set_normalized_token(
+        &mut self,
+        fund_normalized_token_reserve_account: &InterfaceAccount<TokenAccount>,
+        normalized_token_mint: &InterfaceAccount<Mint>,
+        normalized_token_pool: &Account<NormalizedTokenPoolAccount>,
+        pricing_sources: &'info [AccountInfo<'info>],
     ) -> Result<events::FundManagerUpdatedFund> {
         require_keys_eq!(
             fund_normalized_token_reserve_account.owner,
@@ -376,9 +448,53 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         vault_supported_token_mint: &InterfaceAccount<'info, Mint>,
         vault_receipt_token_mint: &InterfaceAccount<'info, Mint>,
 
+        pricing_source: TokenPricingSource,
+        pricing_sources: &'info [AccountInfo<'info>],
+    ) -> Result<(
+        events::FundManagerUpdatedFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
+        self.require_configuration_change_not_timelocked()?;
+
+        let was_price_deviation_circuit_breaker_tripped = self
+            .fund_account
+            .load()?
+            .is_price_deviation_circuit_breaker_tripped();
+
+        let event = self.add_restaking_vault(
+            fund_vault_receipt_token_account,
+            vault,
+            vault_supported_token_mint,
+            vault_receipt_token_mint,
+            pricing_source,
+            pricing_sources,
+        )?;
+        let tripped_event = self.create_operator_tripped_fund_price_circuit_breaker_event(
+            was_price_deviation_circuit_breaker_tripped,
+        )?;
+
+        Ok((event, tripped_event))
+    }
+
+    fn add_restaking_vault(
+        &mut self,
+        fund_vault_receipt_token_account: &InterfaceAccount<TokenAccount>,
+        vault: &UncheckedAccount<'info>,
+        vault_supported_token_mint: &InterfaceAccount<'info, Mint>,
+        vault_receipt_token_mint: &InterfaceAccount<'info, Mint>,
+
         pricing_source: TokenPricingSource,
         pricing_sources: &'info [AccountInfo<'info>],
     ) -> Result<events::FundManagerUpdatedFund> {
+        require_keys_eq!(
+            fund_vault_receipt_token_account.owner,
+            self.fund_account.load()?.get_reserve_account_address()?
+        );
+        require_keys_eq!(
+            fund_vault_receipt_token_account.mint,
+            vault_receipt_token_mint.key()
+        );
+
         restaking::validate_pricing_source(
             &pricing_source,
             vault.as_account_info(),
@@ -413,6 +529,17 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         vault_operator_delegation: &UncheckedAccount,
         vault: &UncheckedAccount,
         operator: &UncheckedAccount,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.add_restaking_vault_delegation(vault_operator_delegation, vault, operator)
+    }
+
+    fn add_restaking_vault_delegation(
+        &mut self,
+        vault_operator_delegation: &UncheckedAccount,
+        vault: &UncheckedAccount,
+        operator: &UncheckedAccount,
     ) -> Result<events::FundManagerUpdatedFund> {
         let (delegation_index, delegated_amount, undelegating_amount) =
             restaking::validate_vault_operator_delegation(
@@ -511,6 +638,25 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         withdrawal_batch_threshold_interval_seconds: i64,
         performance_fee_rate_bps: u16,
     ) -> Result<events::FundManagerUpdatedFund> {
+        let fund_account = self.fund_account.load()?;
+        let fee_rates_changed = fund_account.withdrawal_fee_rate_bps != withdrawal_fee_rate_bps
+            || fund_account.performance_fee_rate_bps != performance_fee_rate_bps;
+        let fund_account_is_price_deviation_circuit_breaker_tripped =
+            fund_account.is_price_deviation_circuit_breaker_tripped();
+        drop(fund_account);
+
+        if fee_rates_changed {
+            self.require_configuration_change_not_timelocked()?;
+        }
+
+        // price deviation must be acknowledged first.
+        if deposit_enabled || withdrawal_enabled {
+            require!(
+                !fund_account_is_price_deviation_circuit_breaker_tripped,
+                ErrorCode::FundPriceDeviationCircuitBreakerTrippedError
+            );
+        }
+
         self.fund_account
             .load_mut()?
             .set_deposit_enabled(deposit_enabled)
@@ -525,6 +671,21 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         self.create_fund_manager_updated_fund_event()
     }
 
+    pub fn process_update_management_fee_rate(
+        &mut self,
+        management_fee_rate_bps: u16,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        if self.fund_account.load()?.management_fee_rate_bps != management_fee_rate_bps {
+            self.require_configuration_change_not_timelocked()?;
+        }
+
+        self.fund_account
+            .load_mut()?
+            .set_management_fee_rate_bps(management_fee_rate_bps, Clock::get()?.unix_timestamp)?;
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
     pub fn process_update_sol_strategy(
         &mut self,
         sol_depositable: bool,
@@ -533,6 +694,27 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         sol_withdrawable: bool,
         sol_withdrawal_normal_reserve_rate_bps: u16,
         sol_withdrawal_normal_reserve_max_amount: u64,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.update_sol_strategy(
+            sol_depositable,
+            sol_accumulated_deposit_capacity_amount,
+            sol_accumulated_deposit_amount,
+            sol_withdrawable,
+            sol_withdrawal_normal_reserve_rate_bps,
+            sol_withdrawal_normal_reserve_max_amount,
+        )
+    }
+
+    fn update_sol_strategy(
+        &mut self,
+        sol_depositable: bool,
+        sol_accumulated_deposit_capacity_amount: u64,
+        sol_accumulated_deposit_amount: Option<u64>,
+        sol_withdrawable: bool,
+        sol_withdrawal_normal_reserve_rate_bps: u16,
+        sol_withdrawal_normal_reserve_max_amount: u64,
     ) -> Result<events::FundManagerUpdatedFund> {
         // sol shouldn't be depositable if registered supported token uses manipulatable pricing source (ex. OrcaDEXLiquidityPool)
         if sol_depositable {
@@ -603,6 +785,33 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         token_withdrawal_normal_reserve_max_amount: u64,
         sol_allocation_weight: u64,
         sol_allocation_capacity_amount: u64,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.update_supported_token_strategy(
+            token_mint,
+            token_depositable,
+            token_accumulated_deposit_capacity_amount,
+            token_accumulated_deposit_amount,
+            token_withdrawable,
+            token_withdrawal_normal_reserve_rate_bps,
+            token_withdrawal_normal_reserve_max_amount,
+            sol_allocation_weight,
+            sol_allocation_capacity_amount,
+        )
+    }
+
+    fn update_supported_token_strategy(
+        &mut self,
+        token_mint: &Pubkey,
+        token_depositable: bool,
+        token_accumulated_deposit_capacity_amount: u64,
+        token_accumulated_deposit_amount: Option<u64>,
+        token_withdrawable: bool,
+        token_withdrawal_normal_reserve_rate_bps: u16,
+        token_withdrawal_normal_reserve_max_amount: u64,
+        sol_allocation_weight: u64,
+        sol_allocation_capacity_amount: u64,
     ) -> Result<events::FundManagerUpdatedFund> {
         let mut fund_account = self.fund_account.load_mut()?;
         let sol_withdrawable = fund_account.sol.withdrawable == 1;
@@ -641,6 +850,16 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         reward_commission_rate_bps: u16,
         vault_receipt_token_depositable: bool,
     ) -> Result<events::FundManagerUpdatedFund> {
+        if self
+            .fund_account
+            .load()?
+            .get_restaking_vault(vault)?
+            .reward_commission_rate_bps
+            != reward_commission_rate_bps
+        {
+            self.require_configuration_change_not_timelocked()?;
+        }
+
         self.fund_account
             .load_mut()?
             .get_restaking_vault_mut(vault)?
@@ -769,6 +988,7 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         &self,
         reward_token_mint: &InterfaceAccount<'info, Mint>,
         reward_token_program: &Interface<'info, TokenInterface>,
+        new_mint_authority: &Pubkey,
     ) -> Result<()> {
         // check if reward token mint is NOT wrapped token mint
         let fund_account = self.fund_account.load()?;
@@ -786,7 +1006,7 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
                 &[&fund_account.get_seeds()],
             ),
             anchor_spl::token::spl_token::instruction::AuthorityType::MintTokens,
-            Some(FUND_MANAGER_PUBKEY),
+            Some(*new_mint_authority),
         )?;
 
         Ok(())
@@ -879,6 +1099,23 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         to_token_mint: &InterfaceAccount<Mint>,
         swap_source: swap::TokenSwapSource,
         swap_source_account: &'info AccountInfo<'info>,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.add_token_swap_strategy(
+            from_token_mint,
+            to_token_mint,
+            swap_source,
+            swap_source_account,
+        )
+    }
+
+    fn add_token_swap_strategy(
+        &mut self,
+        from_token_mint: &InterfaceAccount<Mint>,
+        to_token_mint: &InterfaceAccount<Mint>,
+        swap_source: swap::TokenSwapSource,
+        swap_source_account: &'info AccountInfo<'info>,
     ) -> Result<events::FundManagerUpdatedFund> {
         swap::validate_swap_source(
             &swap_source,
@@ -896,21 +1133,823 @@ impl<'a, 'info> FundConfigurationService<'a, 'info> {
         self.create_fund_manager_updated_fund_event()
     }
 
+    pub fn process_update_token_swap_strategy(
+        &mut self,
+        from_token_mint: &InterfaceAccount<Mint>,
+        to_token_mint: &InterfaceAccount<Mint>,
+        swap_source_account: &AccountInfo,
+        from_token_pricing_source: Option<TokenPricingSource>,
+        max_slippage_bps: u16,
+        pricing_sources: &'info [AccountInfo<'info>],
+    ) -> Result<(
+        events::FundManagerUpdatedFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
+        self.require_configuration_change_not_timelocked()?;
+
+        let was_price_deviation_circuit_breaker_tripped = self
+            .fund_account
+            .load()?
+            .is_price_deviation_circuit_breaker_tripped();
+
+        let event = self.update_token_swap_strategy(
+            from_token_mint,
+            to_token_mint,
+            swap_source_account,
+            from_token_pricing_source,
+            max_slippage_bps,
+            pricing_sources,
+        )?;
+        let tripped_event = self.create_operator_tripped_fund_price_circuit_breaker_event(
+            was_price_deviation_circuit_breaker_tripped,
+        )?;
+
+        Ok((event, tripped_event))
+    }
+
+    fn update_token_swap_strategy(
+        &mut self,
+        from_token_mint: &InterfaceAccount<Mint>,
+        to_token_mint: &InterfaceAccount<Mint>,
+        swap_source_account: &AccountInfo,
+        from_token_pricing_source: Option<TokenPricingSource>,
+        max_slippage_bps: u16,
+        pricing_sources: &'info [AccountInfo<'info>],
+    ) -> Result<events::FundManagerUpdatedFund> {
+        let mut fund_account = self.fund_account.load_mut()?;
+        let strategy = fund_account.get_token_swap_strategy_mut(&from_token_mint.key())?;
+
+        require_keys_eq!(strategy.to_token_mint, to_token_mint.key());
+        match strategy.swap_source.try_deserialize()? {
+            swap::TokenSwapSource::OrcaDEXLiquidityPool { address } => {
+                require_keys_eq!(address, swap_source_account.key());
+            }
+        }
+
+        // the swap source itself is manipulatable during the swap, so it cannot be used as a quote.
+        if let Some(pricing_source) = &from_token_pricing_source {
+            match pricing_source {
+                TokenPricingSource::SPLStakePool { .. }
+                | TokenPricingSource::MarinadeStakePool { .. }
+                | TokenPricingSource::SanctumSingleValidatorSPLStakePool { .. }
+                | TokenPricingSource::SanctumMultiValidatorSPLStakePool { .. }
+                | TokenPricingSource::PeggedToken { .. } => {}
+                TokenPricingSource::OrcaDEXLiquidityPool { address } => {
+                    require_keys_neq!(
+                        *address,
+                        swap_source_account.key(),
+                        ErrorCode::UnexpectedPricingSourceError
+                    );
+                }
+                TokenPricingSource::JitoRestakingVault { .. }
+                | TokenPricingSource::FragmetricNormalizedTokenPool { .. }
+                | TokenPricingSource::FragmetricRestakingFund { .. }
+                | TokenPricingSource::SolvBTCVault { .. }
+                | TokenPricingSource::VirtualVault { .. } => {
+                    err!(ErrorCode::UnexpectedPricingSourceError)?
+                }
+                #[cfg(all(test, not(feature = "idl-build")))]
+                TokenPricingSource::Mock { .. } => err!(ErrorCode::UnexpectedPricingSourceError)?,
+            }
+        }
+
+        strategy.set_slippage_protection(from_token_pricing_source.clone(), max_slippage_bps)?;
+        drop(fund_account);
+
+        // validate pricing source by quoting one from_token
+        if let Some(pricing_source) = &from_token_pricing_source {
+            let mut pricing_service = FundService::new(self.receipt_token_mint, self.fund_account)?
+                .new_pricing_service(pricing_sources, true)?;
+            pricing_service.resolve_token_pricing_source(&from_token_mint.key(), pricing_source)?;
+
+            let one_from_token_as_to_token = pricing_service.get_token_amount_as_token(
+                &from_token_mint.key(),
+                10u64.pow(from_token_mint.decimals as u32),
+                &to_token_mint.key(),
+            )?;
+            require_gt!(
+                one_from_token_as_to_token,
+                0,
+                ErrorCode::UnexpectedPricingSourceError
+            );
+        }
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
     pub fn process_remove_token_swap_strategy(
         &mut self,
         from_token_mint: &InterfaceAccount<Mint>,
         to_token_mint: &InterfaceAccount<Mint>,
         swap_source: swap::TokenSwapSource,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.remove_token_swap_strategy(from_token_mint.key(), to_token_mint.key(), swap_source)
+    }
+
+    fn remove_token_swap_strategy(
+        &mut self,
+        from_token_mint: Pubkey,
+        to_token_mint: Pubkey,
+        swap_source: swap::TokenSwapSource,
     ) -> Result<events::FundManagerUpdatedFund> {
         self.fund_account.load_mut()?.remove_token_swap_strategy(
-            from_token_mint.key(),
-            to_token_mint.key(),
+            from_token_mint,
+            to_token_mint,
             swap_source,
         )?;
 
         self.create_fund_manager_updated_fund_event()
     }
 
+    pub fn process_set_operator_allowlist_enabled(
+        &mut self,
+        enabled: bool,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.set_operator_allowlist_enabled(enabled)
+    }
+
+    fn set_operator_allowlist_enabled(
+        &mut self,
+        enabled: bool,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.fund_account
+            .load_mut()?
+            .operator_allowlist
+            .set_enabled(enabled)?;
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    pub fn process_add_operator(
+        &mut self,
+        operator: Pubkey,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.add_operator(operator)
+    }
+
+    fn add_operator(&mut self, operator: Pubkey) -> Result<events::FundManagerUpdatedFund> {
+        self.fund_account
+            .load_mut()?
+            .operator_allowlist
+            .add_operator(operator)?;
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    pub fn process_remove_operator(
+        &mut self,
+        operator: &Pubkey,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.remove_operator(operator)
+    }
+
+    fn remove_operator(&mut self, operator: &Pubkey) -> Result<events::FundManagerUpdatedFund> {
+        self.fund_account
+            .load_mut()?
+            .operator_allowlist
+            .remove_operator(operator)?;
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    pub fn process_update_revenue_split(
+        &mut self,
+        recipients: &[FundRevenueRecipient],
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.fund_account
+            .load_mut()?
+            .revenue_split
+            .set_recipients(recipients)?;
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    /// Configures a fallback command for the failed command of given discriminant, `None` to disable the soft failure.
+    pub fn process_update_operation_fallback_command(
+        &mut self,
+        command: u8,
+        fallback_command: Option<u8>,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.fund_account
+            .load_mut()?
+            .operation_failure
+            .set_fallback_command(command, fallback_command)?;
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    pub fn process_update_operation_command_policy(
+        &mut self,
+        command: u8,
+        enabled: bool,
+        min_interval_seconds: i64,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.fund_account
+            .load_mut()?
+            .operation_policy
+            .set_command_policy(command, enabled, min_interval_seconds)?;
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    pub fn process_update_max_price_age_slots(
+        &mut self,
+        max_price_age_slots: u64,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.update_max_price_age_slots(max_price_age_slots)
+    }
+
+    fn update_max_price_age_slots(
+        &mut self,
+        max_price_age_slots: u64,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.fund_account
+            .load_mut()?
+            .set_max_price_age_slots(max_price_age_slots);
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    pub fn process_update_price_deviation_threshold(
+        &mut self,
+        max_receipt_token_price_deviation_bps: u16,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.update_price_deviation_threshold(max_receipt_token_price_deviation_bps)
+    }
+
+    fn update_price_deviation_threshold(
+        &mut self,
+        max_receipt_token_price_deviation_bps: u16,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.fund_account
+            .load_mut()?
+            .price_deviation_circuit_breaker
+            .set_max_receipt_token_price_deviation_bps(max_receipt_token_price_deviation_bps);
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    pub fn process_update_supported_token_price_deviation_threshold(
+        &mut self,
+        token_mint: &Pubkey,
+        max_price_deviation_bps: u16,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.update_supported_token_price_deviation_threshold(token_mint, max_price_deviation_bps)
+    }
+
+    fn update_supported_token_price_deviation_threshold(
+        &mut self,
+        token_mint: &Pubkey,
+        max_price_deviation_bps: u16,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.fund_account
+            .load_mut()?
+            .get_supported_token_mut(token_mint)?
+            .set_max_price_deviation_bps(max_price_deviation_bps);
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    /// Deposit and withdrawal remain disabled until the fund manager re-enables them.
+    pub fn process_acknowledge_price_deviation(
+        &mut self,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.fund_account
+            .load_mut()?
+            .price_deviation_circuit_breaker
+            .reset();
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    pub fn process_update_reconciliation_threshold(
+        &mut self,
+        max_shortfall_bps: u16,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.update_reconciliation_threshold(max_shortfall_bps)
+    }
+
+    fn update_reconciliation_threshold(
+        &mut self,
+        max_shortfall_bps: u16,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.fund_account
+            .load_mut()?
+            .reconciliation
+            .set_max_shortfall_bps(max_shortfall_bps)?;
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    pub fn process_update_instant_withdrawal_strategy(
+        &mut self,
+        enabled: bool,
+        min_fee_rate_bps: u16,
+        max_fee_rate_bps: u16,
+        fee_curve_exponent: u8,
+        liquidity_target_rate_bps: u16,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.update_instant_withdrawal_strategy(
+            enabled,
+            min_fee_rate_bps,
+            max_fee_rate_bps,
+            fee_curve_exponent,
+            liquidity_target_rate_bps,
+        )
+    }
+
+    fn update_instant_withdrawal_strategy(
+        &mut self,
+        enabled: bool,
+        min_fee_rate_bps: u16,
+        max_fee_rate_bps: u16,
+        fee_curve_exponent: u8,
+        liquidity_target_rate_bps: u16,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        let mut fund_account = self.fund_account.load_mut()?;
+        let withdrawal_fee_rate_bps = fund_account.withdrawal_fee_rate_bps;
+        fund_account.instant_withdrawal.set_fee_curve(
+            min_fee_rate_bps,
+            max_fee_rate_bps,
+            fee_curve_exponent,
+            liquidity_target_rate_bps,
+            withdrawal_fee_rate_bps,
+        )?;
+        fund_account.instant_withdrawal.set_enabled(enabled);
+        drop(fund_account);
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    pub fn process_update_in_kind_withdrawal_strategy(
+        &mut self,
+        enabled: bool,
+        fee_rate_bps: u16,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        self.require_configuration_change_not_timelocked()?;
+
+        self.update_in_kind_withdrawal_strategy(enabled, fee_rate_bps)
+    }
+
+    fn update_in_kind_withdrawal_strategy(
+        &mut self,
+        enabled: bool,
+        fee_rate_bps: u16,
+    ) -> Result<events::FundManagerUpdatedFund> {
+        let mut fund_account = self.fund_account.load_mut()?;
+        fund_account
+            .in_kind_withdrawal
+            .set_fee_rate_bps(fee_rate_bps)?;
+        fund_account.in_kind_withdrawal.set_enabled(enabled);
+        drop(fund_account);
+
+        self.create_fund_manager_updated_fund_event()
+    }
+
+    fn require_configuration_change_not_timelocked(&self) -> Result<()> {
+        require!(
+            !self
+                .fund_account
+                .load()?
+                .is_configuration_change_timelocked(),
+            ErrorCode::FundConfigurationChangeTimelockedError
+        );
+
+        Ok(())
+    }
+
+    /// Queued changes are only held back if the guardian can cancel them.
+    fn require_guardian_for_configuration_change_delay(
+        guardian: &Pubkey,
+        delay_seconds: i64,
+    ) -> Result<()> {
+        require!(
+            delay_seconds == 0 || *guardian != Pubkey::default(),
+            ErrorCode::FundConfigurationChangeGuardianNotSetError
+        );
+
+        Ok(())
+    }
+
+    pub fn process_queue_configuration_change(
+        &mut self,
+        fund_manager: &Signer<'info>,
+        fund_configuration_change_account: &mut Account<'info, FundConfigurationChangeAccount>,
+        fund_configuration_change_account_bump: u8,
+        guardian: &Pubkey,
+        change_id: u64,
+        change: FundConfigurationChange,
+    ) -> Result<events::FundManagerQueuedFundConfigurationChange> {
+        if let FundConfigurationChange::UpdateConfigurationChangeDelay { delay_seconds } = &change {
+            Self::require_guardian_for_configuration_change_delay(guardian, *delay_seconds)?;
+        }
+
+        let mut fund_account = self.fund_account.load_mut()?;
+        require_eq!(
+            fund_account.issue_configuration_change_id(),
+            change_id,
+            ErrorCode::FundInvalidConfigurationChangeError
+        );
+        let delay_seconds = fund_account.get_configuration_change_delay_seconds();
+        drop(fund_account);
+
+        fund_configuration_change_account.initialize(
+            fund_configuration_change_account_bump,
+            self.receipt_token_mint.key(),
+            change_id,
+            change.clone(),
+            Clock::get()?.unix_timestamp,
+            delay_seconds,
+            fund_manager.key(),
+        );
+
+        Ok(events::FundManagerQueuedFundConfigurationChange {
+            receipt_token_mint: self.receipt_token_mint.key(),
+            fund_account: self.fund_account.key(),
+            fund_configuration_change_account: fund_configuration_change_account.key(),
+            change_id,
+            change,
+            executable_at: fund_configuration_change_account.get_executable_at(),
+        })
+    }
+
+    /// Applies the queued change bypassing the timelock check of the direct instructions.
+    /// Required accounts of each change are given as remaining accounts, see [`FundConfigurationChange`].
+    pub fn process_execute_configuration_change(
+        &mut self,
+        fund_configuration_change_account: &Account<'info, FundConfigurationChangeAccount>,
+        guardian: &Pubkey,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<(
+        events::FundManagerExecutedFundConfigurationChange,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
+        fund_configuration_change_account.validate_executable(
+            self.fund_account
+                .load()?
+                .get_configuration_change_delay_seconds(),
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        let was_price_deviation_circuit_breaker_tripped = self
+            .fund_account
+            .load()?
+            .is_price_deviation_circuit_breaker_tripped();
+
+        let change = fund_configuration_change_account.get_change().clone();
+        match &change {
+            FundConfigurationChange::UpdateFeeRates {
+                withdrawal_fee_rate_bps,
+                performance_fee_rate_bps,
+            } => {
+                self.fund_account
+                    .load_mut()?
+                    .set_withdrawal_fee_rate_bps(*withdrawal_fee_rate_bps)?
+                    .set_performance_fee_rate_bps(*performance_fee_rate_bps)?;
+            }
+            FundConfigurationChange::UpdateRestakingVaultRewardCommissionRate {
+                vault,
+                reward_commission_rate_bps,
+            } => {
+                self.fund_account
+                    .load_mut()?
+                    .get_restaking_vault_mut(vault)?
+                    .set_reward_commission_rate_bps(*reward_commission_rate_bps)?;
+            }
+            FundConfigurationChange::AddSupportedToken {
+                supported_token_mint,
+                pricing_source,
+            } => {
+                let [supported_token_mint_account, fund_supported_token_reserve_account, pricing_sources @ ..] =
+                    remaining_accounts
+                else {
+                    err!(error::ErrorCode::AccountNotEnoughKeys)?
+                };
+                require_keys_eq!(
+                    supported_token_mint_account.key(),
+                    *supported_token_mint,
+                    ErrorCode::FundInvalidConfigurationChangeError
+                );
+
+                self.add_supported_token(
+                    &InterfaceAccount::<TokenAccount>::try_from(
+                        fund_supported_token_reserve_account,
+                    )?,
+                    &InterfaceAccount::<Mint>::try_from(supported_token_mint_account)?,
+                    pricing_source.clone(),
+                    pricing_sources,
+                )?;
+            }
+            FundConfigurationChange::AddTokenSwapStrategy {
+                from_token_mint,
+                to_token_mint,
+                swap_source,
+            } => {
+                let [from_token_mint_account, to_token_mint_account, swap_source_account, ..] =
+                    remaining_accounts
+                else {
+                    err!(error::ErrorCode::AccountNotEnoughKeys)?
+                };
+                require_keys_eq!(
+                    from_token_mint_account.key(),
+                    *from_token_mint,
+                    ErrorCode::FundInvalidConfigurationChangeError
+                );
+                require_keys_eq!(
+                    to_token_mint_account.key(),
+                    *to_token_mint,
+                    ErrorCode::FundInvalidConfigurationChangeError
+                );
+
+                self.add_token_swap_strategy(
+                    &InterfaceAccount::<Mint>::try_from(from_token_mint_account)?,
+                    &InterfaceAccount::<Mint>::try_from(to_token_mint_account)?,
+                    swap_source.clone(),
+                    swap_source_account,
+                )?;
+            }
+            FundConfigurationChange::UpdateTokenSwapStrategy {
+                from_token_mint,
+                from_token_pricing_source,
+                max_slippage_bps,
+            } => {
+                let [from_token_mint_account, to_token_mint_account, swap_source_account, pricing_sources @ ..] =
+                    remaining_accounts
+                else {
+                    err!(error::ErrorCode::AccountNotEnoughKeys)?
+                };
+                require_keys_eq!(
+                    from_token_mint_account.key(),
+                    *from_token_mint,
+                    ErrorCode::FundInvalidConfigurationChangeError
+                );
+
+                self.update_token_swap_strategy(
+                    &InterfaceAccount::<Mint>::try_from(from_token_mint_account)?,
+                    &InterfaceAccount::<Mint>::try_from(to_token_mint_account)?,
+                    swap_source_account,
+                    from_token_pricing_source.clone(),
+                    *max_slippage_bps,
+                    pricing_sources,
+                )?;
+            }
+            FundConfigurationChange::RemoveTokenSwapStrategy {
+                from_token_mint,
+                to_token_mint,
+                swap_source,
+            } => {
+                self.remove_token_swap_strategy(
+                    *from_token_mint,
+                    *to_token_mint,
+                    swap_source.clone(),
+                )?;
+            }
+            FundConfigurationChange::SetNormalizedToken {
+                normalized_token_mint,
+            } => {
+                let [normalized_token_mint_account, fund_normalized_token_reserve_account, normalized_token_pool_account, pricing_sources @ ..] =
+                    remaining_accounts
+                else {
+                    err!(error::ErrorCode::AccountNotEnoughKeys)?
+                };
+                require_keys_eq!(
+                    normalized_token_mint_account.key(),
+                    *normalized_token_mint,
+                    ErrorCode::FundInvalidConfigurationChangeError
+                );
+
+                self.set_normalized_token(
+                    &InterfaceAccount::<TokenAccount>::try_from(
+                        fund_normalized_token_reserve_account,
+                    )?,
+                    &InterfaceAccount::<Mint>::try_from(normalized_token_mint_account)?,
+                    &Account::<NormalizedTokenPoolAccount>::try_from(
+                        normalized_token_pool_account,
+                    )?,
+                    pricing_sources,
+                )?;
+            }
+            FundConfigurationChange::AddRestakingVault {
+                vault,
+                pricing_source,
+            } => {
+                let [vault_account, vault_supported_token_mint_account, vault_receipt_token_mint_account, fund_vault_receipt_token_account, pricing_sources @ ..] =
+                    remaining_accounts
+                else {
+                    err!(error::ErrorCode::AccountNotEnoughKeys)?
+                };
+                require_keys_eq!(
+                    vault_account.key(),
+                    *vault,
+                    ErrorCode::FundInvalidConfigurationChangeError
+                );
+
+                self.add_restaking_vault(
+                    &InterfaceAccount::<TokenAccount>::try_from(fund_vault_receipt_token_account)?,
+                    &UncheckedAccount::try_from(vault_account),
+                    &InterfaceAccount::<Mint>::try_from(vault_supported_token_mint_account)?,
+                    &InterfaceAccount::<Mint>::try_from(vault_receipt_token_mint_account)?,
+                    pricing_source.clone(),
+                    pricing_sources,
+                )?;
+            }
+            FundConfigurationChange::AddRestakingVaultDelegation { vault, operator } => {
+                let [vault_account, operator_account, vault_operator_delegation, ..] =
+                    remaining_accounts
+                else {
+                    err!(error::ErrorCode::AccountNotEnoughKeys)?
+                };
+                require_keys_eq!(
+                    vault_account.key(),
+                    *vault,
+                    ErrorCode::FundInvalidConfigurationChangeError
+                );
+                require_keys_eq!(
+                    operator_account.key(),
+                    *operator,
+                    ErrorCode::FundInvalidConfigurationChangeError
+                );
+
+                self.add_restaking_vault_delegation(
+                    &UncheckedAccount::try_from(vault_operator_delegation),
+                    &UncheckedAccount::try_from(vault_account),
+                    &UncheckedAccount::try_from(operator_account),
+                )?;
+            }
+            FundConfigurationChange::UpdateInstantWithdrawalStrategy {
+                enabled,
+                min_fee_rate_bps,
+                max_fee_rate_bps,
+                fee_curve_exponent,
+                liquidity_target_rate_bps,
+            } => {
+                self.update_instant_withdrawal_strategy(
+                    *enabled,
+                    *min_fee_rate_bps,
+                    *max_fee_rate_bps,
+                    *fee_curve_exponent,
+                    *liquidity_target_rate_bps,
+                )?;
+            }
+            FundConfigurationChange::UpdateInKindWithdrawalStrategy {
+                enabled,
+                fee_rate_bps,
+            } => {
+                self.update_in_kind_withdrawal_strategy(*enabled, *fee_rate_bps)?;
+            }
+            FundConfigurationChange::UpdateConfigurationChangeDelay { delay_seconds } => {
+                Self::require_guardian_for_configuration_change_delay(guardian, *delay_seconds)?;
+
+                self.fund_account
+                    .load_mut()?
+                    .set_configuration_change_delay_seconds(*delay_seconds)?;
+            }
+            FundConfigurationChange::UpdateManagementFeeRate {
+                management_fee_rate_bps,
+            } => {
+                self.fund_account.load_mut()?.set_management_fee_rate_bps(
+                    *management_fee_rate_bps,
+                    Clock::get()?.unix_timestamp,
+                )?;
+            }
+            FundConfigurationChange::UpdateRevenueSplit { recipients } => {
+                self.fund_account
+                    .load_mut()?
+                    .revenue_split
+                    .set_recipients(recipients)?;
+            }
+            FundConfigurationChange::UpdateSolStrategy {
+                sol_depositable,
+                sol_accumulated_deposit_capacity_amount,
+                sol_accumulated_deposit_amount,
+                sol_withdrawable,
+                sol_withdrawal_normal_reserve_rate_bps,
+                sol_withdrawal_normal_reserve_max_amount,
+            } => {
+                self.update_sol_strategy(
+                    *sol_depositable,
+                    *sol_accumulated_deposit_capacity_amount,
+                    *sol_accumulated_deposit_amount,
+                    *sol_withdrawable,
+                    *sol_withdrawal_normal_reserve_rate_bps,
+                    *sol_withdrawal_normal_reserve_max_amount,
+                )?;
+            }
+            FundConfigurationChange::UpdateSupportedTokenStrategy {
+                token_mint,
+                token_depositable,
+                token_accumulated_deposit_capacity_amount,
+                token_accumulated_deposit_amount,
+                token_withdrawable,
+                token_withdrawal_normal_reserve_rate_bps,
+                token_withdrawal_normal_reserve_max_amount,
+                sol_allocation_weight,
+                sol_allocation_capacity_amount,
+            } => {
+                self.update_supported_token_strategy(
+                    token_mint,
+                    *token_depositable,
+                    *token_accumulated_deposit_capacity_amount,
+                    *token_accumulated_deposit_amount,
+                    *token_withdrawable,
+                    *token_withdrawal_normal_reserve_rate_bps,
+                    *token_withdrawal_normal_reserve_max_amount,
+                    *sol_allocation_weight,
+                    *sol_allocation_capacity_amount,
+                )?;
+            }
+            FundConfigurationChange::UpdateMaxPriceAgeSlots {
+                max_price_age_slots,
+            } => {
+                self.update_max_price_age_slots(*max_price_age_slots)?;
+            }
+            FundConfigurationChange::UpdatePriceDeviationThreshold {
+                max_receipt_token_price_deviation_bps,
+            } => {
+                self.update_price_deviation_threshold(*max_receipt_token_price_deviation_bps)?;
+            }
+            FundConfigurationChange::UpdateSupportedTokenPriceDeviationThreshold {
+                token_mint,
+                max_price_deviation_bps,
+            } => {
+                self.update_supported_token_price_deviation_threshold(
+                    token_mint,
+                    *max_price_deviation_bps,
+                )?;
+            }
+            FundConfigurationChange::UpdateReconciliationThreshold { max_shortfall_bps } => {
+                self.update_reconciliation_threshold(*max_shortfall_bps)?;
+            }
+            FundConfigurationChange::SetOperatorAllowlistEnabled { enabled } => {
+                self.set_operator_allowlist_enabled(*enabled)?;
+            }
+            FundConfigurationChange::AddOperator { operator } => {
+                self.add_operator(*operator)?;
+            }
+            FundConfigurationChange::RemoveOperator { operator } => {
+                self.remove_operator(operator)?;
+            }
+        }
+
+        let tripped_event = self.create_operator_tripped_fund_price_circuit_breaker_event(
+            was_price_deviation_circuit_breaker_tripped,
+        )?;
+
+        Ok((
+            events::FundManagerExecutedFundConfigurationChange {
+                receipt_token_mint: self.receipt_token_mint.key(),
+                fund_account: self.fund_account.key(),
+                fund_configuration_change_account: fund_configuration_change_account.key(),
+                change_id: fund_configuration_change_account.change_id,
+                change,
+            },
+            tripped_event,
+        ))
+    }
+
+    pub fn process_cancel_configuration_change(
+        &self,
+        fund_configuration_change_account: &Account<'info, FundConfigurationChangeAccount>,
+    ) -> Result<events::GuardianCanceledFundConfigurationChange> {
+        Ok(events::GuardianCanceledFundConfigurationChange {
+            receipt_token_mint: self.receipt_token_mint.key(),
+            fund_account: self.fund_account.key(),
+            fund_configuration_change_account: fund_configuration_change_account.key(),
+            change_id: fund_configuration_change_account.change_id,
+            change: fund_configuration_change_account.get_change().clone(),
+        })
+    }
+
+    fn create_operator_tripped_fund_price_circuit_breaker_event(
+        &mut self,
+        was_price_deviation_circuit_breaker_tripped: bool,
+    ) -> Result<Option<events::OperatorTrippedFundPriceCircuitBreaker>> {
+        FundService::new(self.receipt_token_mint, self.fund_account)?
+            .create_operator_tripped_fund_price_circuit_breaker_event(
+                was_price_deviation_circuit_breaker_tripped,
+            )
+    }
+
     fn create_fund_manager_updated_fund_event(&self) -> Result<events::FundManagerUpdatedFund> {
         Ok(events::FundManagerUpdatedFund {
             receipt_token_mint: self.receipt_token_mint.key(),
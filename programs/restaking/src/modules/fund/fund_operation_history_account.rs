@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+
+use crate::errors::ErrorCode;
+
+#[constant]
+/// ## Version History
+/// * v1: Initial Version
+pub const FUND_OPERATION_HISTORY_ACCOUNT_CURRENT_VERSION: u16 = 1;
+
+pub const FUND_OPERATION_HISTORY_ACCOUNT_MAX_ENTRIES: usize = 64;
+
+/// A page of the append-only operation history of a fund.
+///
+/// Every executed operation command is summarized into the current page,
+/// and the admin can close full pages to reclaim rent once they have been archived.
+#[account(zero_copy)]
+#[repr(C)]
+pub struct FundOperationHistoryAccount {
+    data_version: u16,
+    bump: u8,
+    _padding: [u8; 5],
+    pub receipt_token_mint: Pubkey,
+    pub page_index: u64,
+    num_entries: u64,
+    entries: [OperationHistoryEntry; FUND_OPERATION_HISTORY_ACCOUNT_MAX_ENTRIES],
+    _reserved: [u8; 64],
+}
+
+impl FundOperationHistoryAccount {
+    pub const SEED: &'static [u8] = b"fund_operation_history";
+
+    pub fn get_bump(&self) -> u8 {
+        self.bump
+    }
+
+    pub(super) fn initialize(&mut self, bump: u8, receipt_token_mint: Pubkey, page_index: u64) {
+        *self = Zeroable::zeroed();
+
+        self.data_version = FUND_OPERATION_HISTORY_ACCOUNT_CURRENT_VERSION;
+        self.bump = bump;
+        self.receipt_token_mint = receipt_token_mint;
+        self.page_index = page_index;
+    }
+
+    #[inline(always)]
+    pub fn is_latest_version(&self) -> bool {
+        self.data_version == FUND_OPERATION_HISTORY_ACCOUNT_CURRENT_VERSION
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.num_entries as usize == FUND_OPERATION_HISTORY_ACCOUNT_MAX_ENTRIES
+    }
+
+    pub fn get_entries_iter(&self) -> impl Iterator<Item = &OperationHistoryEntry> {
+        self.entries[..self.num_entries as usize].iter()
+    }
+
+    pub(super) fn push_entry(&mut self, entry: OperationHistoryEntry) -> Result<()> {
+        require_gt!(
+            FUND_OPERATION_HISTORY_ACCOUNT_MAX_ENTRIES,
+            self.num_entries as usize,
+            ErrorCode::FundInvalidOperationHistoryAccountError,
+        );
+
+        self.entries[self.num_entries as usize] = entry;
+        self.num_entries += 1;
+
+        Ok(())
+    }
+}
+
+/// Compact summary of an executed operation command.
+#[zero_copy]
+pub struct OperationHistoryEntry {
+    pub slot: u64,
+    pub timestamp: i64,
+    pub sequence: u16,
+    /// discriminant of the command.
+    pub command: u8,
+    /// 1 if the command has fallen back to another command due to a soft failure.
+    pub failed: u8,
+    _padding: [u8; 4],
+    /// main token mint or vault of the command.
+    pub subject: Pubkey,
+    /// key amounts of the command, see [`OperationCommandResult::get_summary`](super::commands::OperationCommandResult::get_summary).
+    pub amounts: [u64; 3],
+}
+
+impl OperationHistoryEntry {
+    pub(super) fn new(
+        slot: u64,
+        timestamp: i64,
+        sequence: u16,
+        command: u8,
+        failed: bool,
+        subject: Pubkey,
+        amounts: [u64; 3],
+    ) -> Self {
+        Self {
+            slot,
+            timestamp,
+            sequence,
+            command,
+            failed: failed as u8,
+            _padding: Default::default(),
+            subject,
+            amounts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_entry() {
+        let mut history = FundOperationHistoryAccount::zeroed();
+        let receipt_token_mint = Pubkey::new_unique();
+        history.initialize(255, receipt_token_mint, 3);
+        assert!(history.is_latest_version());
+        assert_eq!(history.get_entries_iter().count(), 0);
+
+        for i in 0..FUND_OPERATION_HISTORY_ACCOUNT_MAX_ENTRIES {
+            assert!(!history.is_full());
+            history
+                .push_entry(OperationHistoryEntry::new(
+                    i as u64,
+                    i as i64,
+                    i as u16,
+                    12,
+                    false,
+                    Pubkey::default(),
+                    [i as u64, 0, 0],
+                ))
+                .unwrap();
+        }
+        assert!(history.is_full());
+        history
+            .push_entry(OperationHistoryEntry::zeroed())
+            .unwrap_err();
+
+        let entries = history.get_entries_iter().collect::<Vec<_>>();
+        assert_eq!(entries.len(), FUND_OPERATION_HISTORY_ACCOUNT_MAX_ENTRIES);
+        assert!(entries
+            .iter()
+            .enumerate()
+            .all(|(i, entry)| entry.sequence == i as u16 && entry.amounts[0] == i as u64));
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+/// Expected result of a deposit at the current fund state, returned by `quote_deposit`.
+///
+/// It fails if the prices are older than the fund's `max_price_age_slots`,
+/// or the price deviation circuit breaker is tripped.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub struct DepositQuote {
+    pub receipt_token_mint: Pubkey,
+    /// `None` for SOL.
+    pub supported_token_mint: Option<Pubkey>,
+    pub asset_amount: u64,
+    pub receipt_token_amount: u64,
+}
+
+/// Expected result of a withdrawal at the current fund state, returned by `quote_withdrawal`.
+///
+/// It fails if the prices are older than the fund's `max_price_age_slots`,
+/// since the withdrawal request refreshes them.
+/// Withdrawal requests are settled later in a batch,
+/// so the actual amount can differ if the receipt token price changes in the meantime.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub struct WithdrawalQuote {
+    pub receipt_token_mint: Pubkey,
+    /// `None` for SOL.
+    pub supported_token_mint: Option<Pubkey>,
+    /// requested amount reduced to the withdrawable value of the asset.
+    pub receipt_token_amount: u64,
+    pub asset_amount: u64,
+    pub asset_fee_amount: u64,
+    pub asset_user_amount: u64,
+}
+
+/// Expected result of wrapping receipt token, returned by `quote_wrap`.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub struct WrapQuote {
+    pub receipt_token_mint: Pubkey,
+    pub wrapped_token_mint: Pubkey,
+    pub receipt_token_amount: u64,
+    pub wrapped_token_amount: u64,
+}
+
+/// Time-weighted average receipt token price over the window, returned by `quote_receipt_token_twap`.
+///
+/// It fails if the prices are older than the fund's `max_price_age_slots`.
+///
+/// Price samples are recorded at most every 5 minutes on price update,
+/// so the window can cover up to about 5 hours.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub struct ReceiptTokenTWAPQuote {
+    pub receipt_token_mint: Pubkey,
+    pub window_seconds: i64,
+    /// latest price at the last price update.
+    pub one_receipt_token_as_sol: u64,
+    pub one_receipt_token_as_sol_twap: u64,
+    /// number of price samples within the window.
+    pub num_samples: u8,
+}
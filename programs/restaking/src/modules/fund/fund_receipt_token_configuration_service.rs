@@ -41,7 +41,8 @@ pub(crate) fn receipt_token_extra_account_metas_len() -> Result<usize> {
     Ok(receipt_token_extra_account_metas()?.len())
 }
 
-fn receipt_token_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+/// Extra accounts of the receipt token transfer hook, following the accounts of `transfer_checked` from index 5.
+pub fn receipt_token_extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
     let extra_account_metas = vec![
         // index 5, fund account
         ExtraAccountMeta::new_with_seeds(
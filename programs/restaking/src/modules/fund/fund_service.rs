@@ -1,18 +1,30 @@
 use core::ops::Neg;
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::compute_units::sol_remaining_compute_units;
+use anchor_lang::solana_program::hash::{Hash, Hasher};
+use anchor_lang::solana_program::program::MAX_RETURN_DATA;
 use anchor_spl::associated_token::spl_associated_token_account;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use bytemuck::Zeroable;
 
 use crate::errors::ErrorCode;
 use crate::events;
-use crate::modules::pricing::{AssetPod, PricingService, TokenPricingSource};
+use crate::modules::pricing::{
+    AssetPod, PricingService, TokenPricingSource, TokenPricingSourcePod,
+};
 use crate::modules::reward;
 use crate::utils::*;
 
-use super::commands::{OperationCommandContext, OperationCommandEntry, SelfExecutable};
+use super::commands::{
+    OperationCommand, OperationCommandContext, OperationCommandEntry, OperationCommandPlan,
+    OperationCommandPlanStep, OperationCommandResult, SelfExecutable,
+};
 use super::*;
 
+/// Chained command is not executed if the remaining compute units are less than this.
+const FUND_OPERATION_COMMAND_MIN_REMAINING_COMPUTE_UNITS: u64 = 400_000;
+
 pub struct FundService<'a, 'info> {
     receipt_token_mint: &'a mut InterfaceAccount<'info, Mint>,
     fund_account: &'a mut AccountLoader<'info, FundAccount>,
@@ -50,7 +62,37 @@ impl<'a, 'info> FundService<'a, 'info> {
         I: IntoIterator<Item = &'info AccountInfo<'info>> + Clone,
         I::IntoIter: ExactSizeIterator,
     {
-        let mut pricing_service = if pricing_sources
+        let mut pricing_service = self.create_pricing_service(pricing_sources);
+
+        // try to update current underlying assets' price
+        self.update_asset_values(&mut pricing_service, refresh_token_price)?;
+
+        Ok(pricing_service)
+    }
+
+    // create a pricing service which resolves receipt token value without writing fund account
+    fn new_readonly_pricing_service<I>(&self, pricing_sources: I) -> Result<PricingService<'info>>
+    where
+        I: IntoIterator<Item = &'info AccountInfo<'info>> + Clone,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut pricing_service = self.create_pricing_service(pricing_sources);
+        pricing_service.resolve_token_pricing_source(
+            &self.receipt_token_mint.key(),
+            &TokenPricingSource::FragmetricRestakingFund {
+                address: self.fund_account.key(),
+            },
+        )?;
+
+        Ok(pricing_service)
+    }
+
+    fn create_pricing_service<I>(&self, pricing_sources: I) -> PricingService<'info>
+    where
+        I: IntoIterator<Item = &'info AccountInfo<'info>> + Clone,
+        I::IntoIter: ExactSizeIterator,
+    {
+        if pricing_sources
             .clone()
             .into_iter()
             .any(|source| source.key() == self.fund_account.key())
@@ -62,27 +104,199 @@ impl<'a, 'info> FundService<'a, 'info> {
                     .into_iter()
                     .chain([self.fund_account.as_account_info()]),
             )
+        }
+    }
+
+    pub fn process_quote_deposit(
+        &self,
+        pricing_sources: &'info [AccountInfo<'info>],
+        supported_token_mint: Option<Pubkey>,
+        asset_amount: u64,
+    ) -> Result<DepositQuote> {
+        // the deposit refreshes stale prices, which cannot be written here.
+        self.fund_account
+            .load()?
+            .require_price_not_stale(self.current_slot)?;
+        let pricing_service = self.new_readonly_pricing_service(pricing_sources)?;
+
+        require!(
+            !self
+                .fund_account
+                .load()?
+                .is_price_deviation_circuit_breaker_tripped(),
+            ErrorCode::FundPriceDeviationCircuitBreakerTrippedError
+        );
+
+        // same as the deposit, but the residual is not carried over.
+        let receipt_token_amount = if self.receipt_token_mint.supply == 0 {
+            asset_amount
+        } else {
+            let mut deposit_residual_micro_receipt_token_amount = self
+                .fund_account
+                .load()?
+                .deposit_residual_micro_receipt_token_amount;
+            pricing_service.convert_asset_amount(
+                supported_token_mint.as_ref(),
+                asset_amount,
+                Some(&self.receipt_token_mint.key()),
+                &mut deposit_residual_micro_receipt_token_amount,
+            )?
         };
 
-        // try to update current underlying assets' price
-        self.update_asset_values(&mut pricing_service, refresh_token_price)?;
+        Ok(DepositQuote {
+            receipt_token_mint: self.receipt_token_mint.key(),
+            supported_token_mint,
+            asset_amount,
+            receipt_token_amount,
+        })
+    }
 
-        Ok(pricing_service)
+    pub fn process_quote_withdrawal(
+        &self,
+        pricing_sources: &'info [AccountInfo<'info>],
+        supported_token_mint: Option<Pubkey>,
+        receipt_token_amount: u64,
+    ) -> Result<WithdrawalQuote> {
+        // the withdrawal request always refreshes prices, so they must be fresh to quote.
+        let fund_account = self.fund_account.load()?;
+        fund_account.require_price_not_stale(self.current_slot)?;
+        let receipt_token_amount = fund_account
+            .get_requestable_receipt_token_amount(supported_token_mint, receipt_token_amount)?;
+        drop(fund_account);
+
+        let pricing_service = self.new_readonly_pricing_service(pricing_sources)?;
+        let asset_amount = pricing_service.get_token_amount_as_asset(
+            &self.receipt_token_mint.key(),
+            receipt_token_amount,
+            supported_token_mint.as_ref(),
+        )?;
+        let asset_fee_amount = self
+            .fund_account
+            .load()?
+            .get_withdrawal_fee_amount(asset_amount)?;
+
+        Ok(WithdrawalQuote {
+            receipt_token_mint: self.receipt_token_mint.key(),
+            supported_token_mint,
+            receipt_token_amount,
+            asset_amount,
+            asset_fee_amount,
+            asset_user_amount: asset_amount - asset_fee_amount,
+        })
+    }
+
+    pub fn process_quote_wrap(&self, receipt_token_amount: u64) -> Result<WrapQuote> {
+        let wrapped_token_mint = self
+            .fund_account
+            .load()?
+            .get_wrapped_token()
+            .ok_or_else(|| error!(ErrorCode::FundWrappedTokenNotSetError))?
+            .mint;
+
+        // receipt token is wrapped 1:1
+        Ok(WrapQuote {
+            receipt_token_mint: self.receipt_token_mint.key(),
+            wrapped_token_mint,
+            receipt_token_amount,
+            wrapped_token_amount: receipt_token_amount,
+        })
+    }
+
+    pub fn process_quote_receipt_token_twap(
+        &self,
+        window_seconds: i64,
+    ) -> Result<ReceiptTokenTWAPQuote> {
+        let fund_account = self.fund_account.load()?;
+        fund_account.require_price_not_stale(self.current_slot)?;
+
+        let (one_receipt_token_as_sol_twap, num_samples) = fund_account
+            .receipt_token_price_history
+            .get_twap(self.current_timestamp, window_seconds)?;
+
+        Ok(ReceiptTokenTWAPQuote {
+            receipt_token_mint: self.receipt_token_mint.key(),
+            window_seconds,
+            one_receipt_token_as_sol: fund_account.one_receipt_token_as_sol,
+            one_receipt_token_as_sol_twap,
+            num_samples,
+        })
     }
 
     pub fn process_update_prices(
         &mut self,
         pricing_sources: &'info [AccountInfo<'info>],
-    ) -> Result<events::OperatorUpdatedFundPrices> {
+    ) -> Result<(
+        events::OperatorUpdatedFundPrices,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
         let mut fund_account = self.fund_account.load_mut()?;
         fund_account.update_pricing_source_addresses()?;
+        let was_price_deviation_circuit_breaker_tripped =
+            fund_account.is_price_deviation_circuit_breaker_tripped();
         drop(fund_account);
 
         self.new_pricing_service(pricing_sources, true)?;
-        Ok(events::OperatorUpdatedFundPrices {
+
+        let mut fund_account = self.fund_account.load_mut()?;
+
+        // price is not recorded during the deviation.
+        if !fund_account.is_price_deviation_circuit_breaker_tripped() {
+            let one_receipt_token_as_sol = fund_account.one_receipt_token_as_sol;
+            fund_account.receipt_token_price_history.record_sample(
+                self.current_slot,
+                self.current_timestamp,
+                one_receipt_token_as_sol,
+            );
+        }
+        drop(fund_account);
+
+        let tripped_event = self.create_operator_tripped_fund_price_circuit_breaker_event(
+            was_price_deviation_circuit_breaker_tripped,
+        )?;
+
+        Ok((
+            events::OperatorUpdatedFundPrices {
+                receipt_token_mint: self.receipt_token_mint.key(),
+                fund_account: self.fund_account.key(),
+            },
+            tripped_event,
+        ))
+    }
+
+    /// Returns the event if the price deviation circuit breaker, which was not tripped before the instruction,
+    /// has been tripped by any pricing refresh during the instruction.
+    pub(super) fn create_operator_tripped_fund_price_circuit_breaker_event(
+        &self,
+        was_price_deviation_circuit_breaker_tripped: bool,
+    ) -> Result<Option<events::OperatorTrippedFundPriceCircuitBreaker>> {
+        if was_price_deviation_circuit_breaker_tripped {
+            return Ok(None);
+        }
+
+        let fund_account = self.fund_account.load()?;
+        let Some((
+            _,
+            token_mint,
+            token_pricing_source,
+            previous_one_token_as_sol,
+            one_token_as_sol,
+        )) = fund_account
+            .price_deviation_circuit_breaker
+            .get_tripped_deviation()
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(events::OperatorTrippedFundPriceCircuitBreaker {
             receipt_token_mint: self.receipt_token_mint.key(),
             fund_account: self.fund_account.key(),
-        })
+            token_mint,
+            token_pricing_source: token_pricing_source
+                .try_deserialize()?
+                .ok_or_else(|| error!(ErrorCode::TokenPricingSourceAccountNotFoundError))?,
+            previous_one_token_as_sol,
+            one_token_as_sol,
+        }))
     }
 
     fn get_pricing_source_infos(
@@ -168,13 +382,33 @@ impl<'a, 'info> FundService<'a, 'info> {
             fund_account.receipt_token_value_updated_slot = self.current_slot;
 
             if refresh_token_values {
+                fund_account.token_prices_updated_slot = self.current_slot;
+
+                // (token_mint, token_pricing_source, previous_one_token_as_sol, one_token_as_sol)
+                let mut price_deviation = None;
+
                 for supported_token in fund_account.get_supported_tokens_iter_mut() {
+                    let previous_one_token_as_sol = supported_token.one_token_as_sol;
                     supported_token.one_token_as_sol = pricing_service
                         .get_one_token_amount_as_sol(
                             &supported_token.mint,
                             supported_token.decimals,
                         )?
                         .unwrap_or_default();
+                    if price_deviation.is_none()
+                        && is_price_deviation_exceeded(
+                            supported_token.max_price_deviation_bps,
+                            previous_one_token_as_sol,
+                            supported_token.one_token_as_sol,
+                        )
+                    {
+                        price_deviation = Some((
+                            supported_token.mint,
+                            supported_token.pricing_source,
+                            previous_one_token_as_sol,
+                            supported_token.one_token_as_sol,
+                        ));
+                    }
                     supported_token.one_token_as_receipt_token = pricing_service
                         .get_one_token_amount_as_token(
                             &supported_token.mint,
@@ -202,12 +436,50 @@ impl<'a, 'info> FundService<'a, 'info> {
                         .unwrap_or_default();
                 }
 
+                let previous_one_receipt_token_as_sol = fund_account.one_receipt_token_as_sol;
                 fund_account.one_receipt_token_as_sol = pricing_service
                     .get_one_token_amount_as_sol(
                         &self.receipt_token_mint.key(),
                         self.receipt_token_mint.decimals,
                     )?
                     .unwrap_or_default();
+                if price_deviation.is_none()
+                    && is_price_deviation_exceeded(
+                        fund_account
+                            .price_deviation_circuit_breaker
+                            .get_max_receipt_token_price_deviation_bps(),
+                        previous_one_receipt_token_as_sol,
+                        fund_account.one_receipt_token_as_sol,
+                    )
+                {
+                    let mut token_pricing_source = TokenPricingSourcePod::zeroed();
+                    TokenPricingSource::FragmetricRestakingFund {
+                        address: self.fund_account.key(),
+                    }
+                    .serialize_as_pod(&mut token_pricing_source);
+                    price_deviation = Some((
+                        self.receipt_token_mint.key(),
+                        token_pricing_source,
+                        previous_one_receipt_token_as_sol,
+                        fund_account.one_receipt_token_as_sol,
+                    ));
+                }
+
+                if let Some((
+                    token_mint,
+                    token_pricing_source,
+                    previous_one_token_as_sol,
+                    one_token_as_sol,
+                )) = price_deviation
+                {
+                    fund_account.trip_price_deviation_circuit_breaker(
+                        self.current_slot,
+                        token_mint,
+                        &token_pricing_source,
+                        previous_one_token_as_sol,
+                        one_token_as_sol,
+                    );
+                }
 
                 // now estimate withdrawal-request acceptable amount for each assets.
                 let mut total_withdrawal_requested_receipt_token_amount = 0;
@@ -361,19 +633,33 @@ impl<'a, 'info> FundService<'a, 'info> {
         })
     }
 
+    /// Executes up to `max_steps` (default 1) chained commands.
+    /// Commands after the first one are executed only if their required accounts are given
+    /// and enough compute budget remains, otherwise it stops there.
+    /// Commands are refused while the price deviation circuit breaker is tripped,
+    /// and chaining stops at the command which trips it.
     pub fn process_run_command(
         &mut self,
         operator: &Signer<'info>,
         system_program: &Program<'info, System>,
         remaining_accounts: &'info [AccountInfo<'info>],
         reset_command: Option<OperationCommandEntry>,
-    ) -> Result<events::OperatorRanFundCommand> {
+        max_steps: Option<u8>,
+    ) -> Result<(
+        Vec<events::OperatorRanFundCommand>,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
         let pricing_source_accounts = self.get_pricing_source_infos(remaining_accounts)?;
         let mut fund_account = self.fund_account.load_mut()?;
 
         if fund_account.operation_enabled == 0 {
             err!(ErrorCode::FundOperationDisabledError)?;
         }
+        // price deviation must be acknowledged first.
+        require!(
+            !fund_account.is_price_deviation_circuit_breaker_tripped(),
+            ErrorCode::FundPriceDeviationCircuitBreakerTrippedError
+        );
 
         // First reset command
         fund_account.operation.initialize_command_if_needed(
@@ -381,6 +667,194 @@ impl<'a, 'info> FundService<'a, 'info> {
             self.current_slot,
             self.current_timestamp,
         )?;
+        drop(fund_account);
+
+        let max_steps = max_steps.unwrap_or(1).max(1);
+        let mut events = Vec::with_capacity(max_steps as usize);
+        for step in 0..max_steps {
+            if step > 0 && !self.is_next_command_runnable(remaining_accounts)? {
+                break;
+            }
+
+            events.push(self.run_next_command(
+                operator,
+                system_program,
+                remaining_accounts,
+                &pricing_source_accounts,
+            )?);
+
+            // stops chaining once a command trips the circuit breaker, to commit the trip.
+            if self
+                .fund_account
+                .load()?
+                .is_price_deviation_circuit_breaker_tripped()
+            {
+                break;
+            }
+        }
+
+        let tripped_event = self.create_operator_tripped_fund_price_circuit_breaker_event(false)?;
+
+        Ok((events, tripped_event))
+    }
+
+    /// Walks the operation commands forward without executing them, to predict up to `max_steps` commands and their required accounts.
+    /// It stops at the command whose following entry depends on its execution,
+    /// or when the plan does not fit in the return data.
+    pub fn process_plan_commands(
+        &mut self,
+        operator: &Signer<'info>,
+        system_program: &Program<'info, System>,
+        max_steps: u8,
+    ) -> Result<OperationCommandPlan> {
+        let fund_account = self.fund_account.load()?;
+        if fund_account.operation_enabled == 0 {
+            err!(ErrorCode::FundOperationDisabledError)?;
+        }
+        let (mut sequence, mut next_command) = fund_account
+            .operation
+            .peek_next_command(self.current_timestamp)?;
+        drop(fund_account);
+
+        let mut plan = OperationCommandPlan {
+            receipt_token_mint: self.receipt_token_mint.key(),
+            steps: Vec::with_capacity(max_steps as usize),
+            completed: false,
+        };
+        let mut plan_size = plan.try_to_vec()?.len();
+        let ctx = OperationCommandContext {
+            operator,
+            receipt_token_mint: self.receipt_token_mint,
+            fund_account: self.fund_account,
+            system_program,
+        };
+        loop {
+            let Some(OperationCommandEntry {
+                command,
+                required_accounts,
+            }) = next_command
+            else {
+                plan.completed = true;
+                break;
+            };
+            if plan.steps.len() == max_steps as usize {
+                break;
+            }
+
+            let step = OperationCommandPlanStep {
+                sequence,
+                command: command.discriminant(),
+                required_accounts,
+            };
+            plan_size += step.try_to_vec()?.len();
+            if plan_size > MAX_RETURN_DATA {
+                break;
+            }
+            plan.steps.push(step);
+
+            let Some(planned_command) = command.plan(&ctx)? else {
+                break;
+            };
+            // the policy is applied only when transitioning to another command.
+            next_command = if planned_command.as_ref().is_some_and(|planned_command| {
+                planned_command.command.discriminant() != command.discriminant()
+            }) {
+                ctx.fund_account
+                    .load()?
+                    .operation_policy
+                    .get_next_runnable_command(planned_command, self.current_timestamp)
+            } else {
+                planned_command
+            };
+            sequence += 1;
+        }
+
+        Ok(plan)
+    }
+
+    /// Operation history is enabled once the first page is initialized.
+    /// Then the page to record entries must be given as a remaining account to run commands,
+    /// otherwise commands fail and are not chained.
+    /// So the next page should be initialized before the current page gets full.
+    pub fn process_initialize_operation_history_account(
+        &self,
+        fund_operation_history_account: &AccountLoader<'info, FundOperationHistoryAccount>,
+        fund_operation_history_account_bump: u8,
+        page_index: u64,
+    ) -> Result<()> {
+        require_eq!(
+            self.fund_account
+                .load_mut()?
+                .issue_operation_history_page_index(),
+            page_index,
+            ErrorCode::FundInvalidOperationHistoryAccountError
+        );
+
+        fund_operation_history_account.load_init()?.initialize(
+            fund_operation_history_account_bump,
+            self.receipt_token_mint.key(),
+            page_index,
+        );
+
+        Ok(())
+    }
+
+    /// Only full pages can be closed.
+    pub fn process_close_operation_history_account(
+        &self,
+        fund_operation_history_account: &AccountLoader<'info, FundOperationHistoryAccount>,
+    ) -> Result<()> {
+        require!(
+            fund_operation_history_account.load()?.is_full(),
+            ErrorCode::FundInvalidOperationHistoryAccountError
+        );
+
+        Ok(())
+    }
+
+    fn is_next_command_runnable(
+        &self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<bool> {
+        let fund_account = self.fund_account.load()?;
+        let Some(OperationCommandEntry {
+            required_accounts: required_account_metas,
+            ..
+        }) = fund_account.operation.get_next_command()?
+        else {
+            // the operation cycle is done.
+            return Ok(false);
+        };
+
+        if sol_remaining_compute_units() < FUND_OPERATION_COMMAND_MIN_REMAINING_COMPUTE_UNITS {
+            return Ok(false);
+        }
+
+        // the page to record the next entry must be given, as it moves to the next page once the current page gets full.
+        if let Some(page_index) = fund_account.get_operation_history_page_index() {
+            if self
+                .find_operation_history_account(remaining_accounts, page_index)
+                .is_none()
+            {
+                return Ok(false);
+            }
+        }
+
+        Ok(required_account_metas.iter().all(|required_account_meta| {
+            remaining_accounts
+                .iter()
+                .any(|remaining_account| required_account_meta.pubkey == *remaining_account.key)
+        }))
+    }
+
+    fn run_next_command(
+        &mut self,
+        operator: &Signer<'info>,
+        system_program: &Program<'info, System>,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        pricing_source_accounts: &[&'info AccountInfo<'info>],
+    ) -> Result<events::OperatorRanFundCommand> {
+        let fund_account = self.fund_account.load()?;
         let operation_sequence = fund_account.operation.next_sequence;
 
         let OperationCommandEntry {
@@ -390,6 +864,11 @@ impl<'a, 'info> FundService<'a, 'info> {
             .operation
             .get_next_command()?
             .ok_or_else(|| error!(ErrorCode::FundOperationCommandExecutionFailedException))?;
+        let fallback_command = fund_account
+            .operation_failure
+            .get_fallback_command(&command);
+        drop(fund_account);
+
         // rearrange given accounts in required order
         // accounts = [...required_accounts, ...pricing_sources]
         let mut operation_command_accounts = Vec::with_capacity(
@@ -408,13 +887,25 @@ impl<'a, 'info> FundService<'a, 'info> {
                     command,
                     required_account_meta.pubkey,
                 );
-                return err!(ErrorCode::FundOperationCommandAccountComputationException);
+                return self.fail_command(
+                    operator,
+                    remaining_accounts,
+                    operation_sequence,
+                    command,
+                    fallback_command,
+                    error!(ErrorCode::FundOperationCommandAccountComputationException),
+                );
             }
         }
         operation_command_accounts.extend(pricing_source_accounts);
 
+        // to check whether the command has changed any state when it fails.
+        let state_digest = fallback_command
+            .is_some()
+            .then(|| self.get_state_digest(&operation_command_accounts))
+            .transpose()?;
+
         // execute the command
-        drop(fund_account);
         let mut ctx = OperationCommandContext {
             operator,
             receipt_token_mint: self.receipt_token_mint,
@@ -432,22 +923,175 @@ impl<'a, 'info> FundService<'a, 'info> {
             }
             Err(err) => {
                 msg!("COMMAND#{}: {:?} failed", operation_sequence, command);
-                Err(err)?
+                // state changed by the failed command must not be committed.
+                if let Some(state_digest) = state_digest {
+                    if state_digest != self.get_state_digest(&operation_command_accounts)? {
+                        return Err(err);
+                    }
+                }
+                return self.fail_command(
+                    operator,
+                    remaining_accounts,
+                    operation_sequence,
+                    command,
+                    fallback_command,
+                    err,
+                );
             }
         };
 
-        let mut fund_account = ctx.fund_account.load_mut()?;
+        self.record_operation_history(
+            remaining_accounts,
+            operation_sequence,
+            &command,
+            result.as_ref(),
+            false,
+        )?;
+        self.complete_command(operator, command, result, next_command)
+    }
+
+    /// Records the failure and transitions to the fallback command, otherwise the error is propagated.
+    fn fail_command(
+        &mut self,
+        operator: &Signer<'info>,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        operation_sequence: u16,
+        command: OperationCommand,
+        fallback_command: Option<OperationCommandEntry>,
+        err: Error,
+    ) -> Result<events::OperatorRanFundCommand> {
+        let Some(fallback_command) = fallback_command else {
+            return Err(err);
+        };
+
+        let error_code = match &err {
+            Error::AnchorError(err) => err.error_code_number as u64,
+            Error::ProgramError(err) => u64::from(err.program_error.clone()),
+        };
+        msg!(
+            "COMMAND#{}: {} falls back to {} (error code: {})",
+            operation_sequence,
+            command.type_name(),
+            fallback_command.command.type_name(),
+            error_code,
+        );
+        self.fund_account
+            .load_mut()?
+            .operation_failure
+            .record_failure(
+                self.current_slot,
+                operation_sequence,
+                command.discriminant(),
+                error_code,
+            );
+        self.record_operation_history(
+            remaining_accounts,
+            operation_sequence,
+            &command,
+            None,
+            true,
+        )?;
+
+        self.complete_command(operator, command, None, Some(fallback_command))
+    }
+
+    /// Appends the summary of the command to the current page of the operation history if it is enabled.
+    /// The page must be given, so that every executed command is recorded.
+    fn record_operation_history(
+        &self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        operation_sequence: u16,
+        command: &OperationCommand,
+        result: Option<&OperationCommandResult>,
+        failed: bool,
+    ) -> Result<()> {
+        let Some(page_index) = self.fund_account.load()?.get_operation_history_page_index() else {
+            return Ok(());
+        };
+
+        let fund_operation_history_account = self
+            .find_operation_history_account(remaining_accounts, page_index)
+            .ok_or_else(|| {
+                msg!(
+                    "COMMAND#{}: operation history page#{} not found",
+                    operation_sequence,
+                    page_index,
+                );
+                error!(ErrorCode::FundOperationHistoryAccountNotFoundError)
+            })?;
+
+        let (subject, amounts) = result
+            .map(OperationCommandResult::get_summary)
+            .unwrap_or_default();
+        fund_operation_history_account
+            .load_mut()?
+            .push_entry(OperationHistoryEntry::new(
+                self.current_slot,
+                self.current_timestamp,
+                operation_sequence,
+                command.discriminant(),
+                failed,
+                subject,
+                amounts,
+            ))?;
+        self.fund_account
+            .load_mut()?
+            .record_operation_history_entry();
+
+        Ok(())
+    }
+
+    fn find_operation_history_account(
+        &self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        page_index: u64,
+    ) -> Option<AccountLoader<'info, FundOperationHistoryAccount>> {
+        remaining_accounts.iter().find_map(|account| {
+            let loader = AccountLoader::<FundOperationHistoryAccount>::try_from(account).ok()?;
+            let is_page = {
+                let history = loader.load().ok()?;
+                history.receipt_token_mint == self.receipt_token_mint.key()
+                    && history.page_index == page_index
+            };
+            is_page.then_some(loader)
+        })
+    }
+
+    fn complete_command(
+        &mut self,
+        operator: &Signer<'info>,
+        command: OperationCommand,
+        result: Option<OperationCommandResult>,
+        next_command: Option<OperationCommandEntry>,
+    ) -> Result<events::OperatorRanFundCommand> {
+        let mut fund_account = self.fund_account.load_mut()?;
+        fund_account
+            .operation_policy
+            .record_execution(command.discriminant(), self.current_timestamp);
+        // the policy is applied only when transitioning to another command.
+        let next_command = if next_command.as_ref().is_some_and(|next_command| {
+            next_command.command.discriminant() != command.discriminant()
+        }) {
+            fund_account
+                .operation_policy
+                .get_next_runnable_command(next_command, self.current_timestamp)
+        } else {
+            next_command
+        };
         fund_account.operation.set_command(
             next_command,
             self.current_slot,
             self.current_timestamp,
         )?;
+        fund_account
+            .operator_allowlist
+            .record_operation(operator.key, self.current_slot);
         let next_sequence = fund_account.operation.next_sequence;
         let num_operated = fund_account.operation.num_operated;
 
         Ok(events::OperatorRanFundCommand {
-            receipt_token_mint: ctx.receipt_token_mint.key(),
-            fund_account: ctx.fund_account.key(),
+            receipt_token_mint: self.receipt_token_mint.key(),
+            fund_account: self.fund_account.key(),
             next_sequence,
             num_operated,
             command,
@@ -455,6 +1099,28 @@ impl<'a, 'info> FundService<'a, 'info> {
         })
     }
 
+    /// digest of the fund account, receipt token mint and writable accounts.
+    fn get_state_digest(&self, accounts: &[&'info AccountInfo<'info>]) -> Result<Hash> {
+        let mut hasher = Hasher::default();
+        for account in [
+            self.fund_account.as_account_info(),
+            self.receipt_token_mint.as_account_info(),
+        ]
+        .into_iter()
+        .chain(
+            accounts
+                .iter()
+                .copied()
+                .filter(|account| account.is_writable),
+        ) {
+            hasher.hash(account.key.as_ref());
+            hasher.hash(&account.lamports().to_le_bytes());
+            hasher.hash(&account.try_borrow_data()?);
+        }
+
+        Ok(hasher.result())
+    }
+
     /// returns [enqueued_receipt_token_amount]
     pub(super) fn enqueue_withdrawal_batches(&mut self, forced: bool) -> Result<u64> {
         let withdrawal_batch_threshold_interval_seconds = self
@@ -1185,7 +1851,7 @@ impl<'a, 'info> FundService<'a, 'info> {
         ))
     }
 
-    /// returns (transferred_asset_revenue_amount)
+    /// returns [(revenue_recipient, transferred_asset_revenue_amount)]
     #[inline(never)]
     pub(super) fn harvest_from_treasury_account(
         &mut self,
@@ -1194,17 +1860,41 @@ impl<'a, 'info> FundService<'a, 'info> {
 
         // for SOL and supported token
         fund_treasury_account: &AccountInfo<'info>,
-        program_revenue_account: &'info AccountInfo<'info>,
+        // pairs of revenue recipient and its supported token account (for supported token)
+        revenue_recipient_accounts: &[&'info AccountInfo<'info>],
 
         // for supported token
         associated_token_account_program: Option<&'info AccountInfo<'info>>,
         supported_token_mint: Option<&'info AccountInfo<'info>>,
         supported_token_program: Option<&'info AccountInfo<'info>>,
         fund_supported_token_treasury_account: Option<&'info AccountInfo<'info>>,
-        program_supported_token_revenue_account: Option<&'info AccountInfo<'info>>,
-    ) -> Result<u64> {
+    ) -> Result<Vec<FundRevenueShare>> {
         let fund_account = self.fund_account.load()?;
 
+        require_eq!(
+            revenue_recipient_accounts.len(),
+            fund_account.get_num_revenue_recipients() * 2,
+            error::ErrorCode::AccountNotEnoughKeys,
+        );
+        for (recipient_accounts, (recipient, _)) in revenue_recipient_accounts
+            .chunks_exact(2)
+            .zip(fund_account.get_revenue_recipients_iter())
+        {
+            require_keys_eq!(recipient_accounts[0].key(), recipient);
+            if let (Some(supported_token_mint), Some(supported_token_program)) =
+                (supported_token_mint, supported_token_program)
+            {
+                require_keys_eq!(
+                    recipient_accounts[1].key(),
+                    spl_associated_token_account::get_associated_token_address_with_program_id(
+                        &recipient,
+                        supported_token_mint.key,
+                        supported_token_program.key,
+                    ),
+                );
+            }
+        }
+
         match supported_token_mint {
             Some(supported_token_mint) => {
                 let supported_token_mint =
@@ -1215,24 +1905,36 @@ impl<'a, 'info> FundService<'a, 'info> {
                     )?;
 
                 if fund_supported_token_treasury_account.amount == 0 {
-                    Ok(0)
-                } else {
-                    // create program_supported_token_revenue_account if not exists
-                    let program_supported_token_revenue_account =
-                        program_supported_token_revenue_account.unwrap();
-                    let supported_token_program = supported_token_program.unwrap();
-                    if !program_supported_token_revenue_account.is_initialized() {
+                    return Ok(vec![]);
+                }
+
+                let supported_token_program = supported_token_program.unwrap();
+                let revenue_shares =
+                    fund_account.split_revenue(fund_supported_token_treasury_account.amount)?;
+
+                for (revenue_share, recipient_accounts) in revenue_shares
+                    .iter()
+                    .zip(revenue_recipient_accounts.chunks_exact(2))
+                {
+                    if revenue_share.amount == 0 {
+                        continue;
+                    }
+
+                    // create revenue_recipient_supported_token_account if not exists
+                    let (revenue_recipient, revenue_recipient_supported_token_account) =
+                        (recipient_accounts[0], recipient_accounts[1]);
+                    if !revenue_recipient_supported_token_account.is_initialized() {
                         anchor_lang::solana_program::program::invoke(
                             &spl_associated_token_account::instruction::create_associated_token_account(
                                 &payer.key(),
-                                &program_revenue_account.key(),
+                                &revenue_recipient.key(),
                                 &supported_token_mint.key(),
                                 &supported_token_program.key(),
                             ),
                             &[
                                 payer.to_account_info(),
-                                program_supported_token_revenue_account.to_account_info(),
-                                program_revenue_account.to_account_info(),
+                                revenue_recipient_supported_token_account.to_account_info(),
+                                revenue_recipient.to_account_info(),
                                 supported_token_mint.to_account_info(),
                                 system_program.to_account_info(),
                                 supported_token_program.to_account_info(),
@@ -1246,18 +1948,18 @@ impl<'a, 'info> FundService<'a, 'info> {
                             supported_token_program.to_account_info(),
                             anchor_spl::token_interface::TransferChecked {
                                 from: fund_supported_token_treasury_account.to_account_info(),
-                                to: program_supported_token_revenue_account.to_account_info(),
+                                to: revenue_recipient_supported_token_account.to_account_info(),
                                 mint: supported_token_mint.to_account_info(),
                                 authority: fund_treasury_account.to_account_info(),
                             },
                             &[&fund_account.get_treasury_account_seeds()],
                         ),
-                        fund_supported_token_treasury_account.amount,
+                        revenue_share.amount,
                         supported_token_mint.decimals,
                     )?;
-
-                    Ok(fund_supported_token_treasury_account.amount)
                 }
+
+                Ok(revenue_shares)
             }
             None => {
                 let rent = Rent::get()?;
@@ -1265,23 +1967,34 @@ impl<'a, 'info> FundService<'a, 'info> {
                 let treasury_account_lamports = fund_treasury_account
                     .lamports()
                     .saturating_sub(min_lamports_for_system_account);
-                if treasury_account_lamports < min_lamports_for_system_account {
-                    Ok(0)
-                } else {
+                let revenue_shares = fund_account.split_revenue(treasury_account_lamports)?;
+
+                // each recipient must be able to receive at least rent-exempt lamports
+                if revenue_shares
+                    .iter()
+                    .any(|revenue_share| revenue_share.amount < min_lamports_for_system_account)
+                {
+                    return Ok(vec![]);
+                }
+
+                for (revenue_share, recipient_accounts) in revenue_shares
+                    .iter()
+                    .zip(revenue_recipient_accounts.chunks_exact(2))
+                {
                     anchor_lang::system_program::transfer(
                         CpiContext::new_with_signer(
                             system_program.to_account_info(),
                             anchor_lang::system_program::Transfer {
                                 from: fund_treasury_account.to_account_info(),
-                                to: program_revenue_account.to_account_info(),
+                                to: recipient_accounts[0].to_account_info(),
                             },
                             &[&fund_account.get_treasury_account_seeds()],
                         ),
-                        treasury_account_lamports,
+                        revenue_share.amount,
                     )?;
-
-                    Ok(treasury_account_lamports)
                 }
+
+                Ok(revenue_shares)
             }
         }
     }
@@ -1374,6 +2087,128 @@ impl<'a, 'info> FundService<'a, 'info> {
         Ok(())
     }
 
+    /// Compares the reserved amounts of the bookkeeping against the balances of the reserve accounts,
+    /// and disables operation if any shortfall exceeds the threshold.
+    ///
+    /// `remaining_accounts` are the token accounts to reconcile, in order:
+    /// pairs of supported token reserve and treasury account, normalized token reserve account if set,
+    /// then receipt token reserve account of each restaking vault.
+    pub fn process_reconcile(
+        &mut self,
+        fund_reserve_account: &SystemAccount<'info>,
+        fund_treasury_account: &SystemAccount<'info>,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<events::OperatorReconciledFund> {
+        let fund_account = self.fund_account.load()?;
+        let mut remaining_accounts = remaining_accounts.iter();
+        let mut next_token_account = |address: Pubkey| {
+            let account = remaining_accounts
+                .next()
+                .ok_or_else(|| error!(error::ErrorCode::AccountNotEnoughKeys))?;
+            require_keys_eq!(account.key(), address);
+            InterfaceAccount::<TokenAccount>::try_from(account)
+        };
+
+        // SOL accounts hold rent-exempt lamports besides the bookkeeping.
+        let min_lamports_for_system_account = Rent::get()?.minimum_balance(0);
+
+        // (token_mint, reserve_account, expected_amount, actual_amount, treasury_amount)
+        let mut balances = Vec::new();
+        balances.push((
+            None,
+            fund_reserve_account.key(),
+            fund_account.sol.get_total_reserved_amount(),
+            fund_reserve_account
+                .lamports()
+                .saturating_sub(min_lamports_for_system_account),
+            Some(
+                fund_treasury_account
+                    .lamports()
+                    .saturating_sub(min_lamports_for_system_account),
+            ),
+        ));
+        for supported_token in fund_account.get_supported_tokens_iter() {
+            let reserve_account = next_token_account(
+                fund_account.find_supported_token_reserve_account_address(&supported_token.mint)?,
+            )?;
+            let treasury_account = next_token_account(
+                fund_account
+                    .find_supported_token_treasury_account_address(&supported_token.mint)?,
+            )?;
+            balances.push((
+                Some(supported_token.mint),
+                reserve_account.key(),
+                supported_token.token.get_total_reserved_amount(),
+                reserve_account.amount,
+                Some(treasury_account.amount),
+            ));
+        }
+        if let Some(normalized_token) = fund_account.get_normalized_token() {
+            let reserve_account =
+                next_token_account(fund_account.find_normalized_token_reserve_account_address()?)?;
+            balances.push((
+                Some(normalized_token.mint),
+                reserve_account.key(),
+                normalized_token.operation_reserved_amount,
+                reserve_account.amount,
+                None,
+            ));
+        }
+        for restaking_vault in fund_account.get_restaking_vaults_iter() {
+            let reserve_account = next_token_account(
+                fund_account
+                    .find_vault_receipt_token_reserve_account_address(&restaking_vault.vault)?,
+            )?;
+            balances.push((
+                Some(restaking_vault.receipt_token_mint),
+                reserve_account.key(),
+                restaking_vault.receipt_token_operation_reserved_amount,
+                reserve_account.amount,
+                None,
+            ));
+        }
+
+        let max_shortfall_bps = fund_account.reconciliation.get_max_shortfall_bps();
+        let num_reconciled_accounts = balances.len() as u8;
+        let shortfall_exceeded = balances
+            .iter()
+            .any(|balance| is_shortfall_exceeded(max_shortfall_bps, balance.2, balance.3));
+        let discrepancies = balances
+            .into_iter()
+            .filter(|(_, _, expected_amount, actual_amount, _)| expected_amount != actual_amount)
+            .map(
+                |(token_mint, reserve_account, expected_amount, actual_amount, treasury_amount)| {
+                    FundReconciliationDiscrepancy {
+                        token_mint,
+                        reserve_account,
+                        expected_amount,
+                        actual_amount,
+                        treasury_amount,
+                    }
+                },
+            )
+            .collect::<Vec<_>>();
+        let operation_disabled = shortfall_exceeded && fund_account.is_operation_enabled();
+        drop(fund_account);
+
+        let mut fund_account = self.fund_account.load_mut()?;
+        fund_account
+            .reconciliation
+            .record_reconciliation(self.current_slot, discrepancies.len());
+        if operation_disabled {
+            fund_account.set_operation_enabled(false);
+        }
+        drop(fund_account);
+
+        Ok(events::OperatorReconciledFund {
+            receipt_token_mint: self.receipt_token_mint.key(),
+            fund_account: self.fund_account.key(),
+            num_reconciled_accounts,
+            discrepancies,
+            operation_disabled,
+        })
+    }
+
     pub fn process_donate_sol(
         &mut self,
         operator: &Signer<'info>,
@@ -1385,7 +2220,10 @@ impl<'a, 'info> FundService<'a, 'info> {
 
         asset_amount: u64,
         offset_receivable: bool,
-    ) -> Result<events::OperatorDonatedToFund> {
+    ) -> Result<(
+        events::OperatorDonatedToFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
         self.process_donate(
             operator,
             Some(system_program),
@@ -1413,7 +2251,10 @@ impl<'a, 'info> FundService<'a, 'info> {
 
         asset_amount: u64,
         offset_receivable: bool,
-    ) -> Result<events::OperatorDonatedToFund> {
+    ) -> Result<(
+        events::OperatorDonatedToFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
         self.process_donate(
             operator,
             None,
@@ -1447,7 +2288,10 @@ impl<'a, 'info> FundService<'a, 'info> {
 
         asset_amount: u64,
         offset_receivable: bool,
-    ) -> Result<events::OperatorDonatedToFund> {
+    ) -> Result<(
+        events::OperatorDonatedToFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
         let supported_token_mint_key = supported_token_mint.map(|mint| mint.key());
 
         // validate operator asset balance
@@ -1463,6 +2307,11 @@ impl<'a, 'info> FundService<'a, 'info> {
             }
         }
 
+        let was_price_deviation_circuit_breaker_tripped = self
+            .fund_account
+            .load()?
+            .is_price_deviation_circuit_breaker_tripped();
+
         // transfer operator asset to the fund
         let (deposited_amount, offset_receivable_amount) = self.fund_account.load_mut()?.donate(
             supported_token_mint_key,
@@ -1505,16 +2354,22 @@ impl<'a, 'info> FundService<'a, 'info> {
         }
 
         // update asset value
-        FundService::new(self.receipt_token_mint, self.fund_account)?
-            .new_pricing_service(pricing_sources, true)?;
+        self.new_pricing_service(pricing_sources, true)?;
 
-        Ok(events::OperatorDonatedToFund {
-            receipt_token_mint: self.receipt_token_mint.key(),
-            fund_account: self.fund_account.key(),
-            supported_token_mint: supported_token_mint_key,
-            donated_amount,
-            deposited_amount,
-            offset_receivable_amount,
-        })
+        let tripped_event = self.create_operator_tripped_fund_price_circuit_breaker_event(
+            was_price_deviation_circuit_breaker_tripped,
+        )?;
+
+        Ok((
+            events::OperatorDonatedToFund {
+                receipt_token_mint: self.receipt_token_mint.key(),
+                fund_account: self.fund_account.key(),
+                supported_token_mint: supported_token_mint_key,
+                donated_amount,
+                deposited_amount,
+                offset_receivable_amount,
+            },
+            tripped_event,
+        ))
     }
 }
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use crate::utils::SystemProgramExt;
+
+use super::*;
+
+/// A withdrawal request of a user, created by `user_request_withdrawal` and closed on withdrawal or cancellation
+/// with the rent refunded to the user.
+///
+/// The request works as a ticket which can be transferred to another user by `user_transfer_withdrawal_request`,
+/// then the holder at claim time withdraws the assets and takes the rent.
+#[account]
+#[derive(InitSpace)]
+pub struct FundWithdrawalRequestAccount {
+    data_version: u16,
+    bump: u8,
+    pub receipt_token_mint: Pubkey,
+    pub user: Pubkey,
+    request: WithdrawalRequest,
+    _reserved: [u8; 32],
+}
+
+impl FundWithdrawalRequestAccount {
+    pub const SEED: &'static [u8] = b"withdrawal_request";
+
+    pub fn get_bump(&self) -> u8 {
+        self.bump
+    }
+
+    #[inline(always)]
+    fn get_seed_phrase(
+        receipt_token_mint: &Pubkey,
+        supported_token_mint: Option<&Pubkey>,
+        request_id: u64,
+    ) -> [Vec<u8>; 4] {
+        [
+            Self::SEED.to_vec(),
+            receipt_token_mint.as_ref().to_vec(),
+            if let Some(supported_token_mint) = supported_token_mint {
+                supported_token_mint.as_ref().to_vec()
+            } else {
+                Pubkey::default().as_ref().to_vec()
+            },
+            request_id.to_le_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_account_address(
+        receipt_token_mint: &Pubkey,
+        supported_token_mint: Option<&Pubkey>,
+        request_id: u64,
+    ) -> (Pubkey, u8) {
+        let seed_phrase =
+            Self::get_seed_phrase(receipt_token_mint, supported_token_mint, request_id);
+        Pubkey::find_program_address(
+            &core::array::from_fn::<_, 4, _>(|i| seed_phrase[i].as_slice()),
+            &crate::ID,
+        )
+    }
+
+    fn migrate(
+        &mut self,
+        bump: u8,
+        receipt_token_mint: Pubkey,
+        user: Pubkey,
+        request: WithdrawalRequest,
+    ) {
+        if self.data_version == 0 {
+            self.bump = bump;
+            self.receipt_token_mint = receipt_token_mint;
+            self.user = user;
+            self.request = request;
+            self._reserved = Default::default();
+            self.data_version = 1;
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn initialize(
+        &mut self,
+        bump: u8,
+        receipt_token_mint: Pubkey,
+        user: Pubkey,
+        request: WithdrawalRequest,
+    ) {
+        self.migrate(bump, receipt_token_mint, user, request)
+    }
+
+    /// Creates the account of the request at its address, paid by the user.
+    pub(super) fn create<'info>(
+        system_program: &Program<'info, System>,
+        user: &Signer<'info>,
+        receipt_token_mint: Pubkey,
+        uninitialized_account: &'info AccountInfo<'info>,
+        request: WithdrawalRequest,
+    ) -> Result<Account<'info, Self>> {
+        let (address, bump) = Self::find_account_address(
+            &receipt_token_mint,
+            request.supported_token_mint.as_ref(),
+            request.request_id,
+        );
+        require_keys_eq!(uninitialized_account.key(), address);
+
+        let seed_phrase = Self::get_seed_phrase(
+            &receipt_token_mint,
+            request.supported_token_mint.as_ref(),
+            request.request_id,
+        );
+        let bump_seed = [bump];
+        let seeds = seed_phrase
+            .iter()
+            .map(Vec::as_slice)
+            .chain([bump_seed.as_slice()])
+            .collect::<Vec<_>>();
+        system_program.initialize_account(
+            uninitialized_account,
+            user,
+            &[seeds.as_slice()],
+            8 + Self::INIT_SPACE,
+            None,
+            &crate::ID,
+        )?;
+
+        let mut account = Account::<Self>::try_from_unchecked(uninitialized_account)?;
+        account.initialize(bump, receipt_token_mint, user.key(), request);
+        account.exit(&crate::ID)?;
+
+        Ok(account)
+    }
+
+    pub fn get_request(&self) -> &WithdrawalRequest {
+        &self.request
+    }
+
+    pub(super) fn set_user(&mut self, user: Pubkey) {
+        self.user = user;
+    }
+}
@@ -1,18 +1,29 @@
-pub(crate) mod commands;
+pub mod commands;
 mod deposit_metadata;
 mod fund_account;
 mod fund_account_asset_state;
+mod fund_account_in_kind_withdrawal_state;
+mod fund_account_instant_withdrawal_state;
 mod fund_account_normalized_token;
 mod fund_account_operation_state;
+mod fund_account_operator;
+mod fund_account_price_deviation_circuit_breaker;
+mod fund_account_receipt_token_price_history;
+mod fund_account_reconciliation_state;
 mod fund_account_restaking_vault;
+mod fund_account_revenue_split;
 mod fund_account_supported_token;
 mod fund_account_token_swap_strategy;
 mod fund_account_wrapped_token;
+mod fund_configuration_change_account;
 mod fund_configuration_service;
+mod fund_operation_history_account;
+mod fund_quote;
 mod fund_receipt_token_configuration_service;
 mod fund_receipt_token_value_provider;
 mod fund_service;
 mod fund_withdrawal_batch_account;
+mod fund_withdrawal_request_account;
 mod user_fund_account;
 mod user_fund_configuration_service;
 mod user_fund_deposit_service;
@@ -23,17 +34,28 @@ mod weighted_allocation_strategy;
 pub use deposit_metadata::*;
 pub use fund_account::*;
 pub use fund_account_asset_state::*;
+pub use fund_account_in_kind_withdrawal_state::*;
+pub use fund_account_instant_withdrawal_state::*;
 pub use fund_account_normalized_token::*;
 pub use fund_account_operation_state::*;
+pub use fund_account_operator::*;
+pub use fund_account_price_deviation_circuit_breaker::*;
+pub use fund_account_receipt_token_price_history::*;
+pub use fund_account_reconciliation_state::*;
 pub use fund_account_restaking_vault::*;
+pub use fund_account_revenue_split::*;
 pub use fund_account_supported_token::*;
 pub use fund_account_token_swap_strategy::*;
 pub use fund_account_wrapped_token::*;
+pub use fund_configuration_change_account::*;
 pub use fund_configuration_service::*;
+pub use fund_operation_history_account::*;
+pub use fund_quote::*;
 pub use fund_receipt_token_configuration_service::*;
 pub use fund_receipt_token_value_provider::*;
 pub use fund_service::*;
 pub use fund_withdrawal_batch_account::*;
+pub use fund_withdrawal_request_account::*;
 pub use user_fund_account::*;
 pub use user_fund_configuration_service::*;
 pub use user_fund_deposit_service::*;
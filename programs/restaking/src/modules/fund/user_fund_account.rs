@@ -1,7 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
-use crate::errors::ErrorCode;
 use crate::utils::PDASeeds;
 
 #[constant]
@@ -22,6 +21,8 @@ pub struct UserFundAccount {
     pub(super) receipt_token_amount: u64,
     _reserved: [u8; 32],
 
+    /// deprecated: withdrawal requests are stored as `FundWithdrawalRequestAccount`s,
+    /// the requests created before are kept until migrated.
     #[max_len(USER_FUND_ACCOUNT_MAX_WITHDRAWAL_REQUESTS_SIZE)]
     withdrawal_requests: Vec<WithdrawalRequest>,
 }
@@ -114,38 +115,18 @@ impl UserFundAccount {
         Ok(())
     }
 
-    pub(super) fn push_withdrawal_request(&mut self, request: WithdrawalRequest) -> Result<()> {
-        require_gt!(
-            USER_FUND_ACCOUNT_MAX_WITHDRAWAL_REQUESTS_SIZE,
-            self.withdrawal_requests.len(),
-            ErrorCode::FundExceededMaxWithdrawalRequestError
-        );
-
-        self.withdrawal_requests.push(request);
-        Ok(())
-    }
-
-    pub(super) fn pop_withdrawal_request(
-        &mut self,
-        request_id: u64,
-        supported_token_mint: Option<Pubkey>,
-    ) -> Result<WithdrawalRequest> {
-        let index = self
-            .withdrawal_requests
-            .iter()
-            .enumerate()
-            .find_map(|(index, request)| {
-                (request.request_id == request_id
-                    && request.supported_token_mint == supported_token_mint)
-                    .then_some(index)
-            })
-            .ok_or_else(|| error!(ErrorCode::FundWithdrawalRequestNotFoundError))?;
-        Ok(self.withdrawal_requests.remove(index))
+    /// Takes the withdrawal requests to migrate them to `FundWithdrawalRequestAccount`s.
+    pub(super) fn take_withdrawal_requests(&mut self) -> Vec<WithdrawalRequest> {
+        core::mem::take(&mut self.withdrawal_requests)
     }
 
     pub(super) fn is_withdrawal_requests_empty(&self) -> bool {
         self.withdrawal_requests.is_empty()
     }
+
+    pub fn get_withdrawal_requests_iter(&self) -> impl Iterator<Item = &WithdrawalRequest> {
+        self.withdrawal_requests.iter()
+    }
 }
 
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone)]
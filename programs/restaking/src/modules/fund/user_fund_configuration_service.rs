@@ -145,6 +145,70 @@ impl<'a, 'info> UserFundConfigurationService<'a, 'info> {
         }
     }
 
+    /// Moves the withdrawal requests kept in the user fund account to their own accounts, paid by the user.
+    ///
+    /// `uninitialized_fund_withdrawal_request_accounts` are the addresses of the requests in order.
+    pub fn process_migrate_withdrawal_requests(
+        &mut self,
+        system_program: &Program<'info, System>,
+        user: &Signer<'info>,
+        uninitialized_fund_withdrawal_request_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<events::UserMigratedFundWithdrawalRequests> {
+        let withdrawal_requests = self.user_fund_account.take_withdrawal_requests();
+        require_gte!(
+            uninitialized_fund_withdrawal_request_accounts.len(),
+            withdrawal_requests.len(),
+            error::ErrorCode::AccountNotEnoughKeys,
+        );
+
+        let fund_withdrawal_request_accounts = uninitialized_fund_withdrawal_request_accounts
+            .iter()
+            .zip(withdrawal_requests)
+            .map(|(uninitialized_account, withdrawal_request)| {
+                Ok(FundWithdrawalRequestAccount::create(
+                    system_program,
+                    user,
+                    self.receipt_token_mint.key(),
+                    uninitialized_account,
+                    withdrawal_request,
+                )?
+                .key())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(events::UserMigratedFundWithdrawalRequests {
+            receipt_token_mint: self.receipt_token_mint.key(),
+            user: user.key(),
+            user_fund_account: self.user_fund_account.key(),
+            fund_withdrawal_request_accounts,
+        })
+    }
+
+    /// Transfers the withdrawal request to another user, who can withdraw or cancel it afterward.
+    pub fn process_transfer_withdrawal_request(
+        &self,
+        user: &Signer<'info>,
+        fund_withdrawal_request_account: &mut Account<'info, FundWithdrawalRequestAccount>,
+        new_user: Pubkey,
+    ) -> Result<events::UserTransferredFundWithdrawalRequest> {
+        fund_withdrawal_request_account.set_user(new_user);
+
+        let withdrawal_request = fund_withdrawal_request_account.get_request();
+        Ok(events::UserTransferredFundWithdrawalRequest {
+            receipt_token_mint: self.receipt_token_mint.key(),
+            supported_token_mint: withdrawal_request.supported_token_mint,
+
+            user: user.key(),
+            user_fund_account: self.user_fund_account.key(),
+            new_user,
+
+            fund_withdrawal_request_account: fund_withdrawal_request_account.key(),
+            batch_id: withdrawal_request.batch_id,
+            request_id: withdrawal_request.request_id,
+            requested_receipt_token_amount: withdrawal_request.receipt_token_amount,
+        })
+    }
+
     pub fn process_close_user_fund_account(
         &self,
         user: &Signer<'info>,
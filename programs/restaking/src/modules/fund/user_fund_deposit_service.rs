@@ -20,7 +20,7 @@ pub struct UserFundDepositService<'a, 'info> {
     user_fund_account: &'a mut UncheckedAccount<'info>,
     user_reward_account: &'a mut UncheckedAccount<'info>,
 
-    _current_slot: u64,
+    current_slot: u64,
     current_timestamp: i64,
 }
 
@@ -51,7 +51,7 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
             user_receipt_token_account,
             user_fund_account,
             user_reward_account,
-            _current_slot: clock.slot,
+            current_slot: clock.slot,
             current_timestamp: clock.unix_timestamp,
         })
     }
@@ -72,9 +72,13 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
         pricing_sources: &'info [AccountInfo<'info>],
 
         asset_amount: u64,
+        min_receipt_token_amount: Option<u64>,
         metadata: Option<DepositMetadata>,
         metadata_signer_key: &Pubkey,
-    ) -> Result<events::UserDepositedToFund> {
+    ) -> Result<(
+        events::UserDepositedToFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
         let supported_token_mint_key = supported_token_mint.map(|mint| mint.key());
 
         // validate user asset balance
@@ -100,9 +104,19 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
             .transpose()?
             .unzip();
 
-        // mint receipt token
+        // mint receipt token, refresh prices if stale
+        let refresh_token_values = self.fund_account.load()?.is_price_stale(self.current_slot);
         let mut pricing_service = FundService::new(self.receipt_token_mint, self.fund_account)?
-            .new_pricing_service(pricing_sources, false)?;
+            .new_pricing_service(pricing_sources, refresh_token_values)?;
+
+        // receipt token must not be minted at the deviated price, until the deviation is acknowledged.
+        require!(
+            !self
+                .fund_account
+                .load()?
+                .is_price_deviation_circuit_breaker_tripped(),
+            errors::ErrorCode::FundPriceDeviationCircuitBreakerTrippedError
+        );
 
         let mut deposit_residual_micro_receipt_token_amount = self
             .fund_account
@@ -120,6 +134,14 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
             )?
         };
 
+        if let Some(min_receipt_token_amount) = min_receipt_token_amount {
+            require_gte!(
+                receipt_token_mint_amount,
+                min_receipt_token_amount,
+                errors::ErrorCode::FundDepositReceiptTokenAmountBelowMinimumError
+            );
+        }
+
         token_2022::mint_to(
             CpiContext::new_with_signer(
                 self.receipt_token_program.to_account_info(),
@@ -228,27 +250,33 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
         }
 
         // update asset value again
-        FundService::new(self.receipt_token_mint, self.fund_account)?
-            .update_asset_values(&mut pricing_service, true)?;
-
-        Ok(events::UserDepositedToFund {
-            receipt_token_mint: self.receipt_token_mint.key(),
-            fund_account: self.fund_account.key(),
-            supported_token_mint: supported_token_mint_key,
-            updated_user_reward_accounts,
-
-            user: self.user.key(),
-            user_receipt_token_account: self.user_receipt_token_account.key(),
-            user_fund_account: user_fund_account_option
-                .map_or(Pubkey::default(), |account| account.key()),
-            user_supported_token_account: user_supported_token_account
-                .map(|token_account| token_account.key()),
-
-            wallet_provider,
-            contribution_accrual_rate,
-            deposited_amount,
-            minted_receipt_token_amount: receipt_token_mint_amount,
-        })
+        let mut fund_service = FundService::new(self.receipt_token_mint, self.fund_account)?;
+        fund_service.update_asset_values(&mut pricing_service, true)?;
+        let tripped_event =
+            fund_service.create_operator_tripped_fund_price_circuit_breaker_event(false)?;
+        drop(fund_service);
+
+        Ok((
+            events::UserDepositedToFund {
+                receipt_token_mint: self.receipt_token_mint.key(),
+                fund_account: self.fund_account.key(),
+                supported_token_mint: supported_token_mint_key,
+                updated_user_reward_accounts,
+
+                user: self.user.key(),
+                user_receipt_token_account: self.user_receipt_token_account.key(),
+                user_fund_account: user_fund_account_option
+                    .map_or(Pubkey::default(), |account| account.key()),
+                user_supported_token_account: user_supported_token_account
+                    .map(|token_account| token_account.key()),
+
+                wallet_provider,
+                contribution_accrual_rate,
+                deposited_amount,
+                minted_receipt_token_amount: receipt_token_mint_amount,
+            },
+            tripped_event,
+        ))
     }
 
     pub fn process_deposit_sol(
@@ -258,9 +286,13 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
         instructions_sysvar: &AccountInfo,
         pricing_sources: &'info [AccountInfo<'info>],
         sol_amount: u64,
+        min_receipt_token_amount: Option<u64>,
         metadata: Option<DepositMetadata>,
         metadata_signer_key: &Pubkey,
-    ) -> Result<events::UserDepositedToFund> {
+    ) -> Result<(
+        events::UserDepositedToFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
         self.process_deposit_asset(
             Some(system_program),
             Some(fund_reserve_account),
@@ -271,6 +303,7 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
             instructions_sysvar,
             pricing_sources,
             sol_amount,
+            min_receipt_token_amount,
             metadata,
             metadata_signer_key,
         )
@@ -285,9 +318,13 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
         instructions_sysvar: &AccountInfo,
         pricing_sources: &'info [AccountInfo<'info>],
         supported_token_amount: u64,
+        min_receipt_token_amount: Option<u64>,
         metadata: Option<DepositMetadata>,
         metadata_signer_key: &Pubkey,
-    ) -> Result<events::UserDepositedToFund> {
+    ) -> Result<(
+        events::UserDepositedToFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
         self.process_deposit_asset(
             None,
             None,
@@ -298,6 +335,7 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
             instructions_sysvar,
             pricing_sources,
             supported_token_amount,
+            min_receipt_token_amount,
             metadata,
             metadata_signer_key,
         )
@@ -311,9 +349,13 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
         user_vault_receipt_token_account: &InterfaceAccount<'info, TokenAccount>,
         instructions_sysvar: &AccountInfo,
         pricing_sources: &'info [AccountInfo<'info>],
+        min_receipt_token_amount: Option<u64>,
         metadata: Option<DepositMetadata>,
         metadata_signer_key: &Pubkey,
-    ) -> Result<events::UserDepositedToVault> {
+    ) -> Result<(
+        events::UserDepositedToVault,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
         // validate deposit metadata
         let (wallet_provider, contribution_accrual_rate) = metadata
             .map(|metadata| {
@@ -327,9 +369,19 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
             .transpose()?
             .unzip();
 
-        // mint receipt token
+        // mint receipt token, refresh prices if stale
+        let refresh_token_values = self.fund_account.load()?.is_price_stale(self.current_slot);
         let mut pricing_service = FundService::new(self.receipt_token_mint, self.fund_account)?
-            .new_pricing_service(pricing_sources, false)?;
+            .new_pricing_service(pricing_sources, refresh_token_values)?;
+
+        // receipt token must not be minted at the deviated price, until the deviation is acknowledged.
+        require!(
+            !self
+                .fund_account
+                .load()?
+                .is_price_deviation_circuit_breaker_tripped(),
+            errors::ErrorCode::FundPriceDeviationCircuitBreakerTrippedError
+        );
 
         let mut deposit_residual_micro_receipt_token_amount = self
             .fund_account
@@ -346,6 +398,14 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
             )?
         };
 
+        if let Some(min_receipt_token_amount) = min_receipt_token_amount {
+            require_gte!(
+                receipt_token_mint_amount,
+                min_receipt_token_amount,
+                errors::ErrorCode::FundDepositReceiptTokenAmountBelowMinimumError
+            );
+        }
+
         token_2022::mint_to(
             CpiContext::new_with_signer(
                 self.receipt_token_program.to_account_info(),
@@ -439,8 +499,11 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
         )?;
 
         // update asset value again
-        FundService::new(self.receipt_token_mint, self.fund_account)?
-            .update_asset_values(&mut pricing_service, true)?;
+        let mut fund_service = FundService::new(self.receipt_token_mint, self.fund_account)?;
+        fund_service.update_asset_values(&mut pricing_service, true)?;
+        let tripped_event =
+            fund_service.create_operator_tripped_fund_price_circuit_breaker_event(false)?;
+        drop(fund_service);
 
         let vault_key = self
             .fund_account
@@ -452,21 +515,24 @@ impl<'a, 'info> UserFundDepositService<'a, 'info> {
             .ok_or_else(|| error!(errors::ErrorCode::FundRestakingVaultNotFoundError))?
             .vault;
 
-        Ok(events::UserDepositedToVault {
-            receipt_token_mint: self.receipt_token_mint.key(),
-            fund_account: self.fund_account.key(),
-            vault_account: vault_key,
-            vault_receipt_token_mint: vault_receipt_token_mint.key(),
-            updated_user_reward_accounts,
-            user: self.user.key(),
-            user_receipt_token_account: self.user_receipt_token_account.key(),
-            user_fund_account: user_fund_account_option
-                .map_or(Pubkey::default(), |account| account.key()),
-            user_vault_receipt_token_account: user_vault_receipt_token_account.key(),
-            wallet_provider,
-            contribution_accrual_rate,
-            deposited_amount,
-            minted_receipt_token_amount: receipt_token_mint_amount,
-        })
+        Ok((
+            events::UserDepositedToVault {
+                receipt_token_mint: self.receipt_token_mint.key(),
+                fund_account: self.fund_account.key(),
+                vault_account: vault_key,
+                vault_receipt_token_mint: vault_receipt_token_mint.key(),
+                updated_user_reward_accounts,
+                user: self.user.key(),
+                user_receipt_token_account: self.user_receipt_token_account.key(),
+                user_fund_account: user_fund_account_option
+                    .map_or(Pubkey::default(), |account| account.key()),
+                user_vault_receipt_token_account: user_vault_receipt_token_account.key(),
+                wallet_provider,
+                contribution_accrual_rate,
+                deposited_amount,
+                minted_receipt_token_amount: receipt_token_mint_amount,
+            },
+            tripped_event,
+        ))
     }
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::spl_associated_token_account;
 use anchor_spl::token::Token;
 use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
@@ -9,7 +10,7 @@ use crate::modules::reward::{RewardAccount, RewardService, UserRewardAccount};
 use crate::utils::{AccountInfoExt, AsAccountInfo, PDASeeds};
 use crate::{errors, events};
 
-use super::FundWithdrawalBatchAccount;
+use super::{FundWithdrawalBatchAccount, FundWithdrawalRequestAccount};
 
 pub struct UserFundWithdrawService<'a, 'info> {
     receipt_token_mint: &'a mut InterfaceAccount<'info, Mint>,
@@ -61,17 +62,20 @@ impl<'a, 'info> UserFundWithdrawService<'a, 'info> {
 
     pub fn process_request_withdrawal(
         &mut self,
+        system_program: &Program<'info, System>,
         receipt_token_lock_account: &mut InterfaceAccount<'info, TokenAccount>,
+        fund_withdrawal_request_account: &UncheckedAccount<'info>,
         supported_token_mint: Option<Pubkey>,
         pricing_sources: &'info [AccountInfo<'info>],
         receipt_token_amount: u64,
+        min_asset_amount: Option<u64>,
     ) -> Result<events::UserRequestedWithdrawalFromFund> {
         // validate user receipt token account balance
         require_gte!(self.user_receipt_token_account.amount, receipt_token_amount);
         require_gt!(receipt_token_amount, 0);
 
         // update fund value before processing request
-        FundService::new(self.receipt_token_mint, self.fund_account)?
+        let pricing_service = FundService::new(self.receipt_token_mint, self.fund_account)?
             .new_pricing_service(pricing_sources, true)?;
 
         // create a user withdrawal request
@@ -87,11 +91,35 @@ impl<'a, 'info> UserFundWithdrawService<'a, 'info> {
             withdrawal_request.receipt_token_amount
         );
         let receipt_token_amount = withdrawal_request.receipt_token_amount;
+
+        // the request is settled later in a batch, so bound the asset amount expected at the current price.
+        if let Some(min_asset_amount) = min_asset_amount {
+            let asset_amount = pricing_service.get_token_amount_as_asset(
+                &self.receipt_token_mint.key(),
+                receipt_token_amount,
+                supported_token_mint.as_ref(),
+            )?;
+            let asset_fee_amount = self
+                .fund_account
+                .load()?
+                .get_withdrawal_fee_amount(asset_amount)?;
+            require_gte!(
+                asset_amount - asset_fee_amount,
+                min_asset_amount,
+                errors::ErrorCode::FundWithdrawalAssetAmountBelowMinimumError
+            );
+        }
+
         let batch_id = withdrawal_request.batch_id;
         let request_id = withdrawal_request.request_id;
 
-        self.user_fund_account
-            .push_withdrawal_request(withdrawal_request)?;
+        FundWithdrawalRequestAccount::create(
+            system_program,
+            self.user,
+            self.receipt_token_mint.key(),
+            fund_withdrawal_request_account.as_account_info(),
+            withdrawal_request,
+        )?;
 
         // lock requested user receipt token amount
         // first, burn user receipt token (use burn/mint instead of transfer to avoid circular CPI through transfer hook)
@@ -169,6 +197,7 @@ impl<'a, 'info> UserFundWithdrawService<'a, 'info> {
             user_receipt_token_account: self.user_receipt_token_account.key(),
             user_fund_account: self.user_fund_account.key(),
 
+            fund_withdrawal_request_account: fund_withdrawal_request_account.key(),
             batch_id,
             request_id,
             requested_receipt_token_amount: receipt_token_amount,
@@ -178,22 +207,51 @@ impl<'a, 'info> UserFundWithdrawService<'a, 'info> {
     pub fn process_cancel_withdrawal_request(
         &mut self,
         receipt_token_lock_account: &mut InterfaceAccount<'info, TokenAccount>,
+        fund_withdrawal_request_account: &UncheckedAccount<'info>,
         pricing_sources: &'info [AccountInfo<'info>],
         request_id: u64,
         supported_token_mint: Option<Pubkey>,
-    ) -> Result<events::UserCanceledWithdrawalRequestFromFund> {
-        // clear pending amount from both user fund account and global fund account
-        let withdrawal_request = self
-            .user_fund_account
-            .pop_withdrawal_request(request_id, supported_token_mint)?;
+    ) -> Result<(
+        events::UserCanceledWithdrawalRequestFromFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
+        // Constraint check
+        // seeds = [FundWithdrawalRequestAccount::SEED, receipt_token_mint, supported_token_mint, request_id]
+        // has_one = user
+        require_keys_eq!(
+            fund_withdrawal_request_account.key(),
+            FundWithdrawalRequestAccount::find_account_address(
+                &self.receipt_token_mint.key(),
+                supported_token_mint.as_ref(),
+                request_id,
+            )
+            .0,
+            errors::ErrorCode::FundWithdrawalRequestNotFoundError,
+        );
+        let fund_withdrawal_request_account = Account::<FundWithdrawalRequestAccount>::try_from(
+            fund_withdrawal_request_account.as_account_info(),
+        )?;
+        require_keys_eq!(fund_withdrawal_request_account.user, self.user.key());
+
+        let was_price_deviation_circuit_breaker_tripped = self
+            .fund_account
+            .load()?
+            .is_price_deviation_circuit_breaker_tripped();
+
+        // clear pending amount from global fund account
+        let withdrawal_request = fund_withdrawal_request_account.get_request().clone();
         let receipt_token_amount = withdrawal_request.receipt_token_amount;
         self.fund_account
             .load_mut()?
             .cancel_withdrawal_request(&withdrawal_request)?;
 
         // update fund value after processing request
-        FundService::new(self.receipt_token_mint, self.fund_account)?
-            .new_pricing_service(pricing_sources, true)?;
+        let mut fund_service = FundService::new(self.receipt_token_mint, self.fund_account)?;
+        fund_service.new_pricing_service(pricing_sources, true)?;
+        let tripped_event = fund_service.create_operator_tripped_fund_price_circuit_breaker_event(
+            was_price_deviation_circuit_breaker_tripped,
+        )?;
+        drop(fund_service);
 
         // unlock requested user receipt token amount
         // first, burn locked receipt token (use burn/mint instead of transfer to avoid circular CPI through transfer hook)
@@ -261,21 +319,441 @@ impl<'a, 'info> UserFundWithdrawService<'a, 'info> {
                     None,
                 )?;
 
+        // close the request to refund rent
+        fund_withdrawal_request_account.close(self.user.to_account_info())?;
+
         // log withdrawal request canceled event
-        Ok(events::UserCanceledWithdrawalRequestFromFund {
-            receipt_token_mint: self.receipt_token_mint.key(),
-            fund_account: self.fund_account.key(),
-            supported_token_mint: withdrawal_request.supported_token_mint,
-            updated_user_reward_accounts,
+        Ok((
+            events::UserCanceledWithdrawalRequestFromFund {
+                receipt_token_mint: self.receipt_token_mint.key(),
+                fund_account: self.fund_account.key(),
+                supported_token_mint: withdrawal_request.supported_token_mint,
+                updated_user_reward_accounts,
+
+                user: self.user.key(),
+                user_receipt_token_account: self.user_receipt_token_account.key(),
+                user_fund_account: self.user_fund_account.key(),
+
+                fund_withdrawal_request_account: fund_withdrawal_request_account.key(),
+                batch_id: withdrawal_request.batch_id,
+                request_id: withdrawal_request.request_id,
+                requested_receipt_token_amount: receipt_token_amount,
+            },
+            tripped_event,
+        ))
+    }
 
-            user: self.user.key(),
-            user_receipt_token_account: self.user_receipt_token_account.key(),
-            user_fund_account: self.user_fund_account.key(),
+    fn process_withdraw_instantly(
+        &mut self,
+        system_program: &Program<'info, System>,
 
-            batch_id: withdrawal_request.batch_id,
-            request_id: withdrawal_request.request_id,
-            requested_receipt_token_amount: receipt_token_amount,
-        })
+        // for supported token
+        supported_token_program: Option<&Interface<'info, TokenInterface>>,
+        supported_token_mint: Option<&InterfaceAccount<'info, Mint>>,
+        fund_supported_token_reserve_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+        user_supported_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+
+        // for SOL
+        fund_reserve_account: &SystemAccount<'info>,
+
+        pricing_sources: &'info [AccountInfo<'info>],
+        receipt_token_amount: u64,
+        min_asset_amount: Option<u64>,
+    ) -> Result<(
+        events::UserWithdrewInstantlyFromFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
+        // validate user receipt token account balance
+        require_gte!(self.user_receipt_token_account.amount, receipt_token_amount);
+        require_gt!(receipt_token_amount, 0);
+
+        let was_price_deviation_circuit_breaker_tripped = self
+            .fund_account
+            .load()?
+            .is_price_deviation_circuit_breaker_tripped();
+
+        // update fund value before processing withdrawal
+        let supported_token_mint_key = supported_token_mint.map(|mint| mint.key());
+        let pricing_service = FundService::new(self.receipt_token_mint, self.fund_account)?
+            .new_pricing_service(pricing_sources, true)?;
+
+        // pay out from the operation reserve, the fee remains in the reserve.
+        let (asset_user_amount, asset_fee_amount) =
+            self.fund_account.load_mut()?.withdraw_instantly(
+                supported_token_mint_key,
+                receipt_token_amount,
+                &pricing_service,
+            )?;
+        if let Some(min_asset_amount) = min_asset_amount {
+            require_gte!(
+                asset_user_amount,
+                min_asset_amount,
+                errors::ErrorCode::FundWithdrawalAssetAmountBelowMinimumError
+            );
+        }
+
+        // burn user receipt token
+        token_2022::burn(
+            CpiContext::new(
+                self.receipt_token_program.to_account_info(),
+                token_2022::Burn {
+                    mint: self.receipt_token_mint.to_account_info(),
+                    from: self.user_receipt_token_account.to_account_info(),
+                    authority: self.user.to_account_info(),
+                },
+            ),
+            receipt_token_amount,
+        )?;
+
+        // transfer either SOL or token to user account
+        {
+            let fund_account = self.fund_account.load()?;
+            match supported_token_mint {
+                Some(supported_token_mint) => {
+                    token_interface::transfer_checked(
+                        CpiContext::new_with_signer(
+                            supported_token_program.unwrap().to_account_info(),
+                            token_interface::TransferChecked {
+                                from: fund_supported_token_reserve_account
+                                    .unwrap()
+                                    .to_account_info(),
+                                to: user_supported_token_account.unwrap().to_account_info(),
+                                mint: supported_token_mint.to_account_info(),
+                                authority: fund_reserve_account.to_account_info(),
+                            },
+                            &[&fund_account.get_reserve_account_seeds()],
+                        ),
+                        asset_user_amount,
+                        supported_token_mint.decimals,
+                    )?;
+                }
+                None => {
+                    anchor_lang::system_program::transfer(
+                        CpiContext::new_with_signer(
+                            system_program.to_account_info(),
+                            anchor_lang::system_program::Transfer {
+                                from: fund_reserve_account.to_account_info(),
+                                to: self.user.to_account_info(),
+                            },
+                            &[&fund_account.get_reserve_account_seeds()],
+                        ),
+                        asset_user_amount,
+                    )?;
+                }
+            };
+        }
+
+        self.fund_account
+            .load_mut()?
+            .reload_receipt_token_supply(self.receipt_token_mint)?;
+
+        self.user_fund_account
+            .reload_receipt_token_amount(self.user_receipt_token_account)?;
+
+        // update fund value after processing withdrawal
+        let mut fund_service = FundService::new(self.receipt_token_mint, self.fund_account)?;
+        fund_service.new_pricing_service(pricing_sources, true)?;
+        let tripped_event = fund_service.create_operator_tripped_fund_price_circuit_breaker_event(
+            was_price_deviation_circuit_breaker_tripped,
+        )?;
+        drop(fund_service);
+
+        let user_reward_account_option = self
+            .user_reward_account
+            .as_account_info()
+            .parse_optional_account_loader::<UserRewardAccount>()?;
+
+        // validation
+        if let Some(user_reward_account) = user_reward_account_option.as_ref() {
+            let user_reward_account = user_reward_account.load()?;
+
+            require_keys_eq!(
+                user_reward_account.receipt_token_mint,
+                self.receipt_token_mint.key()
+            );
+            require_keys_eq!(user_reward_account.user, self.user.key());
+            require!(
+                user_reward_account.is_latest_version(),
+                errors::ErrorCode::InvalidAccountDataVersionError
+            );
+        }
+
+        // reduce user's reward accrual rate
+        let updated_user_reward_accounts =
+            RewardService::new(self.receipt_token_mint, self.reward_account)?
+                .update_reward_pools_token_allocation(
+                    user_reward_account_option.as_ref(),
+                    None,
+                    receipt_token_amount,
+                    None,
+                )?;
+
+        Ok((
+            events::UserWithdrewInstantlyFromFund {
+                receipt_token_mint: self.receipt_token_mint.key(),
+                fund_account: self.fund_account.key(),
+                supported_token_mint: supported_token_mint_key,
+                updated_user_reward_accounts,
+
+                user: self.user.key(),
+                user_receipt_token_account: self.user_receipt_token_account.key(),
+                user_fund_account: self.user_fund_account.key(),
+                user_supported_token_account: user_supported_token_account
+                    .map(|token_account| token_account.key()),
+
+                burnt_receipt_token_amount: receipt_token_amount,
+                withdrawn_amount: asset_user_amount,
+                deducted_fee_amount: asset_fee_amount,
+            },
+            tripped_event,
+        ))
+    }
+
+    pub fn process_withdraw_sol_instantly(
+        &mut self,
+        system_program: &Program<'info, System>,
+        fund_reserve_account: &SystemAccount<'info>,
+        pricing_sources: &'info [AccountInfo<'info>],
+        receipt_token_amount: u64,
+        min_asset_amount: Option<u64>,
+    ) -> Result<(
+        events::UserWithdrewInstantlyFromFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
+        self.process_withdraw_instantly(
+            system_program,
+            None,
+            None,
+            None,
+            None,
+            fund_reserve_account,
+            pricing_sources,
+            receipt_token_amount,
+            min_asset_amount,
+        )
+    }
+
+    pub fn process_withdraw_supported_token_instantly(
+        &mut self,
+        system_program: &Program<'info, System>,
+        supported_token_program: &Interface<'info, TokenInterface>,
+        supported_token_mint: &InterfaceAccount<'info, Mint>,
+        fund_supported_token_reserve_account: &InterfaceAccount<'info, TokenAccount>,
+        user_supported_token_account: &InterfaceAccount<'info, TokenAccount>,
+        fund_reserve_account: &SystemAccount<'info>,
+        pricing_sources: &'info [AccountInfo<'info>],
+        receipt_token_amount: u64,
+        min_asset_amount: Option<u64>,
+    ) -> Result<(
+        events::UserWithdrewInstantlyFromFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
+        self.process_withdraw_instantly(
+            system_program,
+            Some(supported_token_program),
+            Some(supported_token_mint),
+            Some(fund_supported_token_reserve_account),
+            Some(user_supported_token_account),
+            fund_reserve_account,
+            pricing_sources,
+            receipt_token_amount,
+            min_asset_amount,
+        )
+    }
+
+    /// `remaining_accounts` are [token_mint, token_program, fund_reserve_token_account, user_token_account]
+    /// for each token paid out along with SOL, followed by pricing sources.
+    /// The tokens are supported tokens, normalized token if set, then receipt token of each restaking vault, in order.
+    ///
+    /// `min_asset_amounts` are the minimum withdrawn amounts of SOL followed by each token in the same order.
+    pub fn process_withdraw_in_kind(
+        &mut self,
+        system_program: &Program<'info, System>,
+        fund_reserve_account: &SystemAccount<'info>,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        receipt_token_amount: u64,
+        min_asset_amounts: Option<Vec<u64>>,
+    ) -> Result<(
+        events::UserWithdrewInKindFromFund,
+        Option<events::OperatorTrippedFundPriceCircuitBreaker>,
+    )> {
+        // validate user receipt token account balance
+        require_gte!(self.user_receipt_token_account.amount, receipt_token_amount);
+        require_gt!(receipt_token_amount, 0);
+
+        let num_in_kind_withdrawal_tokens = {
+            let fund_account = self.fund_account.load()?;
+            fund_account.get_supported_tokens_iter().count()
+                + fund_account.get_normalized_token().iter().count()
+                + fund_account.get_restaking_vaults_iter().count()
+        };
+        require_gte!(
+            remaining_accounts.len(),
+            num_in_kind_withdrawal_tokens * 4,
+            error::ErrorCode::AccountNotEnoughKeys,
+        );
+        let (token_accounts, pricing_sources) =
+            remaining_accounts.split_at(num_in_kind_withdrawal_tokens * 4);
+
+        let was_price_deviation_circuit_breaker_tripped = self
+            .fund_account
+            .load()?
+            .is_price_deviation_circuit_breaker_tripped();
+
+        // update fund value before processing withdrawal
+        let pricing_service = FundService::new(self.receipt_token_mint, self.fund_account)?
+            .new_pricing_service(pricing_sources, true)?;
+
+        // pay out a pro-rata slice of the held assets, the fee remains in the reserve.
+        let withdrawn_assets = self
+            .fund_account
+            .load_mut()?
+            .withdraw_in_kind(receipt_token_amount, &pricing_service)?;
+        if let Some(min_asset_amounts) = min_asset_amounts {
+            require_eq!(
+                min_asset_amounts.len(),
+                withdrawn_assets.len(),
+                errors::ErrorCode::FundInvalidInKindWithdrawalMinAssetAmountsError
+            );
+            for (withdrawn_asset, min_asset_amount) in
+                withdrawn_assets.iter().zip(min_asset_amounts)
+            {
+                require_gte!(
+                    withdrawn_asset.withdrawn_amount,
+                    min_asset_amount,
+                    errors::ErrorCode::FundWithdrawalAssetAmountBelowMinimumError
+                );
+            }
+        }
+
+        // burn user receipt token
+        token_2022::burn(
+            CpiContext::new(
+                self.receipt_token_program.to_account_info(),
+                token_2022::Burn {
+                    mint: self.receipt_token_mint.to_account_info(),
+                    from: self.user_receipt_token_account.to_account_info(),
+                    authority: self.user.to_account_info(),
+                },
+            ),
+            receipt_token_amount,
+        )?;
+
+        // transfer SOL and each token to user accounts
+        {
+            let fund_account = self.fund_account.load()?;
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: fund_reserve_account.to_account_info(),
+                        to: self.user.to_account_info(),
+                    },
+                    &[&fund_account.get_reserve_account_seeds()],
+                ),
+                withdrawn_assets[0].withdrawn_amount,
+            )?;
+
+            for (accounts, withdrawn_asset) in
+                token_accounts.chunks_exact(4).zip(&withdrawn_assets[1..])
+            {
+                // withdrawn assets are SOL followed by the tokens.
+                let token_mint = withdrawn_asset.token_mint.unwrap_or_default();
+                require_keys_eq!(accounts[0].key(), token_mint);
+                require_keys_eq!(*accounts[0].owner, accounts[1].key());
+                require_keys_eq!(
+                    accounts[2].key(),
+                    spl_associated_token_account::get_associated_token_address_with_program_id(
+                        &fund_reserve_account.key(),
+                        &token_mint,
+                        &accounts[1].key(),
+                    )
+                );
+                let mint = InterfaceAccount::<Mint>::try_from(&accounts[0])?;
+                let user_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[3])?;
+                require_keys_eq!(user_token_account.mint, mint.key());
+                require_keys_eq!(user_token_account.owner, self.user.key());
+
+                if withdrawn_asset.withdrawn_amount == 0 {
+                    continue;
+                }
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        accounts[1].clone(),
+                        token_interface::TransferChecked {
+                            from: accounts[2].clone(),
+                            to: accounts[3].clone(),
+                            mint: accounts[0].clone(),
+                            authority: fund_reserve_account.to_account_info(),
+                        },
+                        &[&fund_account.get_reserve_account_seeds()],
+                    ),
+                    withdrawn_asset.withdrawn_amount,
+                    mint.decimals,
+                )?;
+            }
+        }
+
+        self.fund_account
+            .load_mut()?
+            .reload_receipt_token_supply(self.receipt_token_mint)?;
+
+        self.user_fund_account
+            .reload_receipt_token_amount(self.user_receipt_token_account)?;
+
+        // update fund value after processing withdrawal
+        let mut fund_service = FundService::new(self.receipt_token_mint, self.fund_account)?;
+        fund_service.new_pricing_service(pricing_sources, true)?;
+        let tripped_event = fund_service.create_operator_tripped_fund_price_circuit_breaker_event(
+            was_price_deviation_circuit_breaker_tripped,
+        )?;
+        drop(fund_service);
+
+        let user_reward_account_option = self
+            .user_reward_account
+            .as_account_info()
+            .parse_optional_account_loader::<UserRewardAccount>()?;
+
+        // validation
+        if let Some(user_reward_account) = user_reward_account_option.as_ref() {
+            let user_reward_account = user_reward_account.load()?;
+
+            require_keys_eq!(
+                user_reward_account.receipt_token_mint,
+                self.receipt_token_mint.key()
+            );
+            require_keys_eq!(user_reward_account.user, self.user.key());
+            require!(
+                user_reward_account.is_latest_version(),
+                errors::ErrorCode::InvalidAccountDataVersionError
+            );
+        }
+
+        // reduce user's reward accrual rate
+        let updated_user_reward_accounts =
+            RewardService::new(self.receipt_token_mint, self.reward_account)?
+                .update_reward_pools_token_allocation(
+                    user_reward_account_option.as_ref(),
+                    None,
+                    receipt_token_amount,
+                    None,
+                )?;
+
+        Ok((
+            events::UserWithdrewInKindFromFund {
+                receipt_token_mint: self.receipt_token_mint.key(),
+                fund_account: self.fund_account.key(),
+                updated_user_reward_accounts,
+
+                user: self.user.key(),
+                user_receipt_token_account: self.user_receipt_token_account.key(),
+                user_fund_account: self.user_fund_account.key(),
+
+                burnt_receipt_token_amount: receipt_token_amount,
+                withdrawn_assets,
+            },
+            tripped_event,
+        ))
     }
 
     fn process_withdraw(
@@ -293,13 +771,11 @@ impl<'a, 'info> UserFundWithdrawService<'a, 'info> {
 
         fund_treasury_account: &SystemAccount<'info>,
         fund_withdrawal_batch_account: &mut Account<'info, FundWithdrawalBatchAccount>,
-        request_id: u64,
+        fund_withdrawal_request_account: &Account<'info, FundWithdrawalRequestAccount>,
     ) -> Result<events::UserWithdrewFromFund> {
         // calculate asset amounts and mark withdrawal request as claimed withdrawal fee is already paid.
         let supported_token_mint_key = supported_token_mint.map(|mint| mint.key());
-        let withdrawal_request = self
-            .user_fund_account
-            .pop_withdrawal_request(request_id, supported_token_mint_key)?;
+        let withdrawal_request = fund_withdrawal_request_account.get_request().clone();
 
         let (asset_user_amount, asset_fee_amount, receipt_token_amount) =
             fund_withdrawal_batch_account.settle_withdrawal_request(&withdrawal_request)?;
@@ -364,6 +840,9 @@ impl<'a, 'info> UserFundWithdrawService<'a, 'info> {
             fund_withdrawal_batch_account.close(fund_treasury_account.to_account_info())?;
         }
 
+        // close the request to refund rent
+        fund_withdrawal_request_account.close(self.user.to_account_info())?;
+
         let fund_account = self.fund_account.load()?;
         Ok(events::UserWithdrewFromFund {
             receipt_token_mint: fund_account.receipt_token_mint,
@@ -377,6 +856,7 @@ impl<'a, 'info> UserFundWithdrawService<'a, 'info> {
                 .map(|token_account| token_account.key()),
 
             fund_withdrawal_batch_account: fund_withdrawal_batch_account.key(),
+            fund_withdrawal_request_account: fund_withdrawal_request_account.key(),
             batch_id: withdrawal_request.batch_id,
             request_id: withdrawal_request.request_id,
             burnt_receipt_token_amount: receipt_token_amount,
@@ -390,9 +870,9 @@ impl<'a, 'info> UserFundWithdrawService<'a, 'info> {
         &mut self,
         system_program: &Program<'info, System>,
         fund_withdrawal_batch_account: &mut Account<'info, FundWithdrawalBatchAccount>,
+        fund_withdrawal_request_account: &Account<'info, FundWithdrawalRequestAccount>,
         fund_reserve_account: &SystemAccount<'info>,
         fund_treasury_account: &SystemAccount<'info>,
-        request_id: u64,
     ) -> Result<events::UserWithdrewFromFund> {
         self.process_withdraw(
             system_program,
@@ -403,7 +883,7 @@ impl<'a, 'info> UserFundWithdrawService<'a, 'info> {
             fund_reserve_account,
             fund_treasury_account,
             fund_withdrawal_batch_account,
-            request_id,
+            fund_withdrawal_request_account,
         )
     }
 
@@ -415,9 +895,9 @@ impl<'a, 'info> UserFundWithdrawService<'a, 'info> {
         fund_supported_token_reserve_account: &InterfaceAccount<'info, TokenAccount>,
         user_supported_token_account: &InterfaceAccount<'info, TokenAccount>,
         fund_withdrawal_batch_account: &mut Account<'info, FundWithdrawalBatchAccount>,
+        fund_withdrawal_request_account: &Account<'info, FundWithdrawalRequestAccount>,
         fund_reserve_account: &SystemAccount<'info>,
         fund_treasury_account: &SystemAccount<'info>,
-        request_id: u64,
     ) -> Result<events::UserWithdrewFromFund> {
         self.process_withdraw(
             system_program,
@@ -428,7 +908,7 @@ impl<'a, 'info> UserFundWithdrawService<'a, 'info> {
             fund_reserve_account,
             fund_treasury_account,
             fund_withdrawal_batch_account,
-            request_id,
+            fund_withdrawal_request_account,
         )
     }
 }
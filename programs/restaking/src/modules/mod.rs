@@ -5,5 +5,6 @@ pub mod normalization;
 pub mod pricing;
 pub mod restaking;
 pub mod reward;
+pub mod role;
 pub mod staking;
 pub mod swap;
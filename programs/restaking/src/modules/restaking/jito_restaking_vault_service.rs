@@ -81,6 +81,31 @@ impl<'info> JitoRestakingVaultService<'info> {
         })
     }
 
+    /// Pre-flight check of the vault before CPI.
+    /// A failed CPI fails the whole transaction, while this error lets the command fall back.
+    pub fn require_vault_not_paused(&self) -> Result<()> {
+        let data = &Self::borrow_account_data(self.vault_account)?;
+        let vault = Self::deserialize_account_data::<Vault>(data)?;
+        require!(!vault.is_paused(), ErrorCode::RestakingVaultPausedError);
+
+        Ok(())
+    }
+
+    /// Pre-flight check of the vault before depositing, in addition to [`Self::require_vault_not_paused`].
+    pub fn require_vault_depositable(&self) -> Result<()> {
+        self.require_vault_not_paused()?;
+
+        let data = &Self::borrow_account_data(self.vault_account)?;
+        let vault = Self::deserialize_account_data::<Vault>(data)?;
+        require_gt!(
+            vault.deposit_capacity(),
+            vault.tokens_deposited(),
+            ErrorCode::RestakingVaultDepositCapacityExceededError
+        );
+
+        Ok(())
+    }
+
     /// returns [delegation_index, staked_amount, enqueued_for_cooldown_amount, cooling_down_amount]
     /// in other words [delegation_index, delegated_amount, undelegation_requested_amount, undelegating_amount]
     pub fn validate_vault_operator_delegation(
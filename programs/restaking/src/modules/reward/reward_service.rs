@@ -3,6 +3,8 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::ErrorCode;
 use crate::events;
+use crate::modules::fund::{FundAccount, FundRevenueShare};
+use crate::utils::get_split_amounts_u64;
 
 use super::*;
 
@@ -207,51 +209,117 @@ impl<'a, 'info> RewardService<'a, 'info> {
         }))
     }
 
+    /// remaining accounts: reward token accounts of each fund revenue recipient
     pub fn process_claim_remaining_reward(
         &self,
+        fund_account: &AccountLoader<'info, FundAccount>,
         reward_token_mint: &InterfaceAccount<'info, Mint>,
         reward_token_program: &Interface<'info, TokenInterface>,
         reward_reserve_account: &SystemAccount<'info>,
         reward_token_reserve_account: &InterfaceAccount<'info, TokenAccount>,
-        program_reward_token_revenue_account: &InterfaceAccount<'info, TokenAccount>,
-    ) -> Result<()> {
-        self.claim_remaining_reward(
+        revenue_recipient_reward_token_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<events::OperatorClaimedRemainingReward> {
+        let revenue_recipients = fund_account
+            .load()?
+            .get_revenue_recipients_iter()
+            .collect::<Vec<_>>();
+        require_eq!(
+            revenue_recipient_reward_token_accounts.len(),
+            revenue_recipients.len(),
+            error::ErrorCode::AccountNotEnoughKeys,
+        );
+
+        let revenue_reward_token_accounts = revenue_recipient_reward_token_accounts
+            .iter()
+            .zip(&revenue_recipients)
+            .map(
+                |(revenue_recipient_reward_token_account, (revenue_recipient, share_bps))| {
+                    require_keys_eq!(
+                        revenue_recipient_reward_token_account.key(),
+                        anchor_spl::associated_token::get_associated_token_address_with_program_id(
+                            revenue_recipient,
+                            &reward_token_mint.key(),
+                            &reward_token_program.key(),
+                        ),
+                    );
+
+                    Ok((
+                        InterfaceAccount::<TokenAccount>::try_from(
+                            revenue_recipient_reward_token_account,
+                        )?,
+                        *share_bps,
+                    ))
+                },
+            )
+            .collect::<Result<Vec<_>>>()?;
+
+        let claimed_amounts = self.claim_remaining_reward(
             reward_token_mint,
             reward_token_program,
             reward_reserve_account,
             reward_token_reserve_account,
-            program_reward_token_revenue_account,
-        )
+            &revenue_reward_token_accounts,
+        )?;
+
+        Ok(events::OperatorClaimedRemainingReward {
+            receipt_token_mint: self.receipt_token_mint.key(),
+            reward_account: self.reward_account.key(),
+            reward_token_mint: reward_token_mint.key(),
+            revenue_shares: revenue_recipients
+                .into_iter()
+                .zip(claimed_amounts)
+                .map(|((recipient, _), amount)| FundRevenueShare { recipient, amount })
+                .collect(),
+        })
     }
 
+    /// Remaining reward is split among revenue reward token accounts by their share.
+    ///
+    /// returns claimed amount of each revenue reward token account
     pub(in crate::modules) fn claim_remaining_reward(
         &self,
         reward_token_mint: &InterfaceAccount<'info, Mint>,
         reward_token_program: &Interface<'info, TokenInterface>,
         reward_reserve_account: &SystemAccount<'info>,
         reward_token_reserve_account: &InterfaceAccount<'info, TokenAccount>,
-        program_reward_token_revenue_account: &InterfaceAccount<'info, TokenAccount>,
-    ) -> Result<()> {
+        revenue_reward_token_accounts: &[(InterfaceAccount<'info, TokenAccount>, u16)],
+    ) -> Result<Vec<u64>> {
+        require!(
+            !revenue_reward_token_accounts.is_empty(),
+            error::ErrorCode::AccountNotEnoughKeys,
+        );
+
         let mut reward_account = self.reward_account.load_mut()?;
         let reward_id = reward_account.get_reward_id(&reward_token_mint.key())?;
 
         let claimed_amount = reward_account.claim_remaining_reward(reward_id, self.current_slot)?;
-
-        anchor_spl::token_interface::transfer_checked(
-            CpiContext::new_with_signer(
-                reward_token_program.to_account_info(),
-                anchor_spl::token_interface::TransferChecked {
-                    from: reward_token_reserve_account.to_account_info(),
-                    mint: reward_token_mint.to_account_info(),
-                    to: program_reward_token_revenue_account.to_account_info(),
-                    authority: reward_reserve_account.to_account_info(),
-                },
-                &[&reward_account.get_reserve_account_seeds()],
-            ),
+        let claimed_amounts = get_split_amounts_u64(
             claimed_amount,
-            reward_token_mint.decimals,
+            &revenue_reward_token_accounts
+                .iter()
+                .map(|(_, share_bps)| *share_bps)
+                .collect::<Vec<_>>(),
         )?;
 
+        for ((revenue_reward_token_account, _), amount) in
+            revenue_reward_token_accounts.iter().zip(&claimed_amounts)
+        {
+            anchor_spl::token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    reward_token_program.to_account_info(),
+                    anchor_spl::token_interface::TransferChecked {
+                        from: reward_token_reserve_account.to_account_info(),
+                        mint: reward_token_mint.to_account_info(),
+                        to: revenue_reward_token_account.to_account_info(),
+                        authority: reward_reserve_account.to_account_info(),
+                    },
+                    &[&reward_account.get_reserve_account_seeds()],
+                ),
+                *amount,
+                reward_token_mint.decimals,
+            )?;
+        }
+
         drop(reward_account);
 
         self.validate_reward_token_reserve_account(
@@ -261,7 +329,7 @@ impl<'a, 'info> RewardService<'a, 'info> {
             reward_id,
         )?;
 
-        Ok(())
+        Ok(claimed_amounts)
     }
 
     pub(super) fn validate_reward_token_reserve_account(
@@ -0,0 +1,5 @@
+mod role_account;
+mod role_configuration_service;
+
+pub use role_account::*;
+pub use role_configuration_service::*;
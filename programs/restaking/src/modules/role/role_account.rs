@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::utils::PDASeeds;
+
+#[constant]
+/// ## Version History
+/// * v1: Initial Version
+pub const ROLE_ACCOUNT_CURRENT_VERSION: u16 = 1;
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub enum Role {
+    Admin,
+    FundManager,
+    Operator,
+    Guardian,
+}
+
+/// Program-wide registry of privileged keys.
+///
+/// Each role is transferred in two steps: admin proposes a new authority,
+/// then the new authority accepts it by signing, so a typo cannot lock the role out.
+#[account]
+#[derive(InitSpace)]
+pub struct RoleAccount {
+    data_version: u16,
+    bump: u8,
+    pub(crate) admin: Pubkey,
+    pub(crate) fund_manager: Pubkey,
+    pub(crate) operator: Pubkey,
+    pending_admin: Option<Pubkey>,
+    pending_fund_manager: Option<Pubkey>,
+    pending_operator: Option<Pubkey>,
+    /// can cancel queued fund configuration changes, unset by default.
+    pub(crate) guardian: Pubkey,
+    pending_guardian: Option<Pubkey>,
+
+    _reserved: [u8; 63],
+}
+
+impl PDASeeds<2> for RoleAccount {
+    const SEED: &'static [u8] = b"role";
+
+    fn get_bump(&self) -> u8 {
+        self.bump
+    }
+
+    fn get_seeds(&self) -> [&[u8]; 2] {
+        [Self::SEED, core::slice::from_ref(&self.bump)]
+    }
+}
+
+impl RoleAccount {
+    fn migrate(
+        &mut self,
+        bump: u8,
+        admin: Pubkey,
+        fund_manager: Pubkey,
+        operator: Pubkey,
+    ) -> Result<()> {
+        if self.data_version == 0 {
+            self.bump = bump;
+            self.admin = admin;
+            self.fund_manager = fund_manager;
+            self.operator = operator;
+            self.data_version = 1;
+        }
+
+        require_eq!(self.data_version, ROLE_ACCOUNT_CURRENT_VERSION);
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub(super) fn initialize(
+        &mut self,
+        bump: u8,
+        admin: Pubkey,
+        fund_manager: Pubkey,
+        operator: Pubkey,
+    ) -> Result<()> {
+        self.migrate(bump, admin, fund_manager, operator)
+    }
+
+    #[inline(always)]
+    pub fn is_latest_version(&self) -> bool {
+        self.data_version == ROLE_ACCOUNT_CURRENT_VERSION
+    }
+
+    pub fn find_account_address() -> Pubkey {
+        Pubkey::find_program_address(&[RoleAccount::SEED], &crate::ID).0
+    }
+
+    pub(crate) fn get_authority(&self, role: Role) -> &Pubkey {
+        match role {
+            Role::Admin => &self.admin,
+            Role::FundManager => &self.fund_manager,
+            Role::Operator => &self.operator,
+            Role::Guardian => &self.guardian,
+        }
+    }
+
+    fn get_authority_mut(&mut self, role: Role) -> &mut Pubkey {
+        match role {
+            Role::Admin => &mut self.admin,
+            Role::FundManager => &mut self.fund_manager,
+            Role::Operator => &mut self.operator,
+            Role::Guardian => &mut self.guardian,
+        }
+    }
+
+    pub(crate) fn get_pending_authority(&self, role: Role) -> Option<&Pubkey> {
+        match role {
+            Role::Admin => self.pending_admin.as_ref(),
+            Role::FundManager => self.pending_fund_manager.as_ref(),
+            Role::Operator => self.pending_operator.as_ref(),
+            Role::Guardian => self.pending_guardian.as_ref(),
+        }
+    }
+
+    fn get_pending_authority_mut(&mut self, role: Role) -> &mut Option<Pubkey> {
+        match role {
+            Role::Admin => &mut self.pending_admin,
+            Role::FundManager => &mut self.pending_fund_manager,
+            Role::Operator => &mut self.pending_operator,
+            Role::Guardian => &mut self.pending_guardian,
+        }
+    }
+
+    /// Proposes new authority of the role, or cancels the pending proposal if `None`.
+    pub(super) fn propose_authority(&mut self, role: Role, new_authority: Option<Pubkey>) {
+        *self.get_pending_authority_mut(role) = new_authority;
+    }
+
+    pub(super) fn accept_authority(&mut self, role: Role, new_authority: &Pubkey) -> Result<()> {
+        require!(
+            self.get_pending_authority(role) == Some(new_authority),
+            ErrorCode::RoleUnauthorizedError
+        );
+
+        *self.get_authority_mut(role) = *new_authority;
+        *self.get_pending_authority_mut(role) = None;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_initialized_role_account() -> RoleAccount {
+        let mut role_account = RoleAccount {
+            data_version: 0,
+            bump: 0,
+            admin: Pubkey::default(),
+            fund_manager: Pubkey::default(),
+            operator: Pubkey::default(),
+            pending_admin: None,
+            pending_fund_manager: None,
+            pending_operator: None,
+            guardian: Pubkey::default(),
+            pending_guardian: None,
+            _reserved: [0; 63],
+        };
+        role_account
+            .initialize(
+                255,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            )
+            .unwrap();
+        role_account
+    }
+
+    #[test]
+    fn test_two_step_role_transfer() {
+        let mut role_account = create_initialized_role_account();
+        let old_fund_manager = role_account.fund_manager;
+        let new_fund_manager = Pubkey::new_unique();
+
+        // nothing is proposed yet
+        role_account
+            .accept_authority(Role::FundManager, &new_fund_manager)
+            .unwrap_err();
+
+        role_account.propose_authority(Role::FundManager, Some(new_fund_manager));
+        assert_eq!(role_account.fund_manager, old_fund_manager);
+
+        // only the proposed authority can accept
+        role_account
+            .accept_authority(Role::FundManager, &Pubkey::new_unique())
+            .unwrap_err();
+        role_account
+            .accept_authority(Role::Admin, &new_fund_manager)
+            .unwrap_err();
+
+        role_account
+            .accept_authority(Role::FundManager, &new_fund_manager)
+            .unwrap();
+        assert_eq!(role_account.fund_manager, new_fund_manager);
+        assert!(role_account
+            .get_pending_authority(Role::FundManager)
+            .is_none());
+    }
+
+    #[test]
+    fn test_cancel_role_transfer() {
+        let mut role_account = create_initialized_role_account();
+        let new_admin = Pubkey::new_unique();
+
+        role_account.propose_authority(Role::Admin, Some(new_admin));
+        role_account.propose_authority(Role::Admin, None);
+
+        role_account
+            .accept_authority(Role::Admin, &new_admin)
+            .unwrap_err();
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events;
+
+use super::*;
+
+pub struct RoleConfigurationService<'a, 'info> {
+    role_account: &'a mut Account<'info, RoleAccount>,
+}
+
+impl<'a, 'info> RoleConfigurationService<'a, 'info> {
+    pub fn new(role_account: &'a mut Account<'info, RoleAccount>) -> Result<Self> {
+        Ok(Self { role_account })
+    }
+
+    pub fn process_initialize_role_account(
+        &mut self,
+        role_account_bump: u8,
+        admin: Pubkey,
+        fund_manager: Pubkey,
+        operator: Pubkey,
+    ) -> Result<()> {
+        self.role_account
+            .initialize(role_account_bump, admin, fund_manager, operator)
+    }
+
+    pub fn process_propose_role_transfer(
+        &mut self,
+        role: Role,
+        new_authority: Option<Pubkey>,
+    ) -> Result<events::AdminProposedRoleTransfer> {
+        if let Some(new_authority) = &new_authority {
+            require_keys_neq!(
+                *new_authority,
+                Pubkey::default(),
+                ErrorCode::RoleInvalidAuthorityError
+            );
+        }
+
+        self.role_account.propose_authority(role, new_authority);
+
+        Ok(events::AdminProposedRoleTransfer {
+            role_account: self.role_account.key(),
+            role,
+            authority: *self.role_account.get_authority(role),
+            pending_authority: new_authority,
+        })
+    }
+
+    pub fn process_accept_role_transfer(
+        &mut self,
+        role: Role,
+        new_authority: &Signer,
+    ) -> Result<events::RoleAuthorityAcceptedRoleTransfer> {
+        let old_authority = *self.role_account.get_authority(role);
+        self.role_account
+            .accept_authority(role, new_authority.key)?;
+
+        Ok(events::RoleAuthorityAcceptedRoleTransfer {
+            role_account: self.role_account.key(),
+            role,
+            old_authority,
+            new_authority: new_authority.key(),
+        })
+    }
+}
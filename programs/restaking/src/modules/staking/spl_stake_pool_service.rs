@@ -222,6 +222,18 @@ impl<'info, T: SPLStakePoolInterface> SPLStakePoolService<'info, T> {
         .into_iter())
     }
 
+    /// Pre-flight check of the pool before depositing SOL.
+    /// A failed CPI fails the whole transaction, while this error lets the command fall back.
+    pub fn require_sol_depositable(&self) -> Result<()> {
+        let pool_account_data = self.get_pool_account_data()?;
+        require!(
+            pool_account_data.sol_deposit_authority.is_none(),
+            ErrorCode::StakingSPLSolDepositNotPermittedError
+        );
+
+        Ok(())
+    }
+
     /// returns [to_pool_token_account_amount, minted_pool_token_amount, deducted_pool_token_fee_amount]
     #[inline(never)]
     pub fn deposit_sol(
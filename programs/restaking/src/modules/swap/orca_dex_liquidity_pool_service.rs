@@ -1,9 +1,18 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::TokenAccount;
+use spl_math::uint::U256;
 use whirlpool_cpi::whirlpool::accounts::Whirlpool;
 
+use crate::errors::ErrorCode;
+
 use super::ValidateLiquidityPool;
 
+/// Bounds of whirlpool sqrt price (Q64.64), which correspond to tick index ±443636.
+const ORCA_MIN_SQRT_PRICE_X64: u128 = 4295048016;
+const ORCA_MAX_SQRT_PRICE_X64: u128 = 79226673515401279992447579055;
+/// Whirlpool fee rate is represented in hundredths of a basis point.
+const ORCA_FEE_RATE_DENOMINATOR: u128 = 1_000_000;
+
 pub(in crate::modules) struct OrcaDEXLiquidityPoolService<'info> {
     whirlpool_program: &'info AccountInfo<'info>,
     pool_account: &'info AccountInfo<'info>,
@@ -219,6 +228,60 @@ impl<'info> OrcaDEXLiquidityPoolService<'info> {
         Ok(accounts)
     }
 
+    /// Calculates the sqrt price (Q64.64) at which the marginal swap rate, net of pool fee,
+    /// reaches `min_to_token_amount / from_token_amount`.
+    /// The swap must stop at this price to keep the realized rate above the minimum rate.
+    fn calculate_sqrt_price_limit(
+        pool_account: &Whirlpool,
+        a_to_b: bool,
+        from_token_amount: u64,
+        min_to_token_amount: u64,
+    ) -> Result<u128> {
+        let fee_rate_complement = ORCA_FEE_RATE_DENOMINATOR - pool_account.fee_rate as u128;
+
+        // price = token_b / token_a, so
+        //   a -> b: price * (1 - fee) >= min_rate, so the price must not go below min_rate / (1 - fee).
+        //   b -> a: (1 - fee) / price >= min_rate, so the price must not go above (1 - fee) / min_rate.
+        let (numerator, denominator) = if a_to_b {
+            (
+                min_to_token_amount as u128 * ORCA_FEE_RATE_DENOMINATOR,
+                from_token_amount as u128 * fee_rate_complement,
+            )
+        } else {
+            (
+                from_token_amount as u128 * fee_rate_complement,
+                min_to_token_amount as u128 * ORCA_FEE_RATE_DENOMINATOR,
+            )
+        };
+
+        if denominator == 0 {
+            return Ok(if a_to_b {
+                ORCA_MIN_SQRT_PRICE_X64
+            } else {
+                ORCA_MAX_SQRT_PRICE_X64
+            });
+        }
+
+        let price_x128 = (U256::from(numerator) << 128)
+            .checked_div(U256::from(denominator))
+            .ok_or_else(|| error!(ErrorCode::CalculationArithmeticException))?;
+        let mut sqrt_price_x64 = price_x128.integer_sqrt();
+        if a_to_b {
+            // round up the lower bound
+            sqrt_price_x64 += U256::one();
+        }
+
+        Ok(if sqrt_price_x64 > U256::from(ORCA_MAX_SQRT_PRICE_X64) {
+            ORCA_MAX_SQRT_PRICE_X64
+        } else {
+            sqrt_price_x64.as_u128().max(ORCA_MIN_SQRT_PRICE_X64)
+        })
+    }
+
+    /// The swap stops early when the pool price reaches the rate implied by `min_to_token_amount`,
+    /// so `from_token_swapped_amount` might be less than `from_token_amount`.
+    /// If the pool price is already beyond that rate, it returns zero amounts without swap.
+    ///
     /// returns [from_token_swapped_amount, to_token_swapped_amount]
     #[inline(never)]
     pub fn swap(
@@ -237,6 +300,7 @@ impl<'info> OrcaDEXLiquidityPoolService<'info> {
         token_account_signer_seeds: &[&[&[u8]]],
 
         from_token_amount: u64,
+        min_to_token_amount: u64,
     ) -> Result<(u64, u64)> {
         let pool_account = &Self::deserialize_pool_account(self.pool_account)?;
         let mut from_token_account =
@@ -259,6 +323,28 @@ impl<'info> OrcaDEXLiquidityPoolService<'info> {
 
         require_gte!(from_token_account_amount_before, from_token_amount);
 
+        let sqrt_price_limit = Self::calculate_sqrt_price_limit(
+            pool_account,
+            a_to_b,
+            from_token_amount,
+            min_to_token_amount,
+        )?;
+        let is_price_within_limit = if a_to_b {
+            pool_account.sqrt_price > sqrt_price_limit
+        } else {
+            pool_account.sqrt_price < sqrt_price_limit
+        };
+        if !is_price_within_limit {
+            msg!(
+                "SWAP#orca: pool price exceeded limit, from_token_mint={}, to_token_mint={}, sqrt_price={}, sqrt_price_limit={}",
+                from_token_account.mint,
+                to_token_account.mint,
+                pool_account.sqrt_price,
+                sqrt_price_limit,
+            );
+            return Ok((0, 0));
+        }
+
         whirlpool_cpi::whirlpool::cpi::swap_v2(
             CpiContext::new_with_signer(
                 self.whirlpool_program.to_account_info(),
@@ -283,7 +369,7 @@ impl<'info> OrcaDEXLiquidityPoolService<'info> {
             ),
             from_token_amount,
             0,
-            0,
+            sqrt_price_limit,
             true,
             a_to_b,
             None,
@@ -307,6 +393,13 @@ impl<'info> OrcaDEXLiquidityPoolService<'info> {
             to_token_swapped_amount,
         );
 
+        // the price limit guarantees the minimum rate, but double check the realized amount.
+        require_gte!(
+            to_token_swapped_amount as u128 * from_token_amount as u128,
+            min_to_token_amount as u128 * from_token_swapped_amount as u128,
+            ErrorCode::TokenSwapSlippageExceededError
+        );
+
         Ok((from_token_swapped_amount, to_token_swapped_amount))
     }
 }
@@ -129,6 +129,24 @@ pub fn get_proportional_amount_u128(
         .ok_or_else(|| error!(errors::ErrorCode::CalculationArithmeticException))
 }
 
+/// splits `amount` by shares in basis points which sum up to 10_000.
+/// sub-decimal remainders go to the first share.
+pub fn get_split_amounts_u64(amount: u64, shares_bps: &[u16]) -> Result<Vec<u64>> {
+    let mut amounts = shares_bps
+        .iter()
+        .map(|share_bps| get_proportional_amount_u64(amount, *share_bps as u64, 10_000))
+        .collect::<Result<Vec<_>>>()?;
+
+    let split_amount = amounts.iter().sum::<u64>();
+    if let Some(first) = amounts.first_mut() {
+        *first += amount
+            .checked_sub(split_amount)
+            .ok_or_else(|| error!(errors::ErrorCode::CalculationArithmeticException))?;
+    }
+
+    Ok(amounts)
+}
+
 #[allow(dead_code)]
 pub trait AccountInfoExt<'info> {
     fn is_initialized(&self) -> bool;
@@ -471,6 +471,7 @@ describe('restaking.fragBTC test', async () => {
           "assetAmount": 100000000n,
           "assetMint": "zBTCug3er3tLyffELcvDNrKkCymbPWysGcWihESYfLg",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -769,6 +770,7 @@ describe('restaking.fragBTC test', async () => {
           "assetAmount": 100000000n,
           "assetMint": "cbbtcf3aa214zXHbiAZQwf4122FBYbraNdFqgw4iMij",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -935,6 +937,7 @@ describe('restaking.fragBTC test', async () => {
           "assetAmount": 100000000n,
           "assetMint": "3NZ9JMVBmGAqocybic2c7LQCJScmgsAZ6vQqTDzcqmJh",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -4695,6 +4698,7 @@ describe('restaking.fragBTC test', async () => {
           "assetAmount": null,
           "assetMint": "DNLsKFnrBjTBKp1eSwt8z1iNu2T2PL3MnxZFsGEEpQCf",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -363,6 +363,7 @@ describe('restaking.fragJTO test', async () => {
           "assetAmount": 100000000000n,
           "assetMint": "jtojtomepa8beP8AuQc6eXt5FriJwfFMwQx2v2f9mCL",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -440,6 +441,7 @@ describe('restaking.fragJTO test', async () => {
           "assetAmount": 200000000000n,
           "assetMint": "jtojtomepa8beP8AuQc6eXt5FriJwfFMwQx2v2f9mCL",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -517,6 +519,7 @@ describe('restaking.fragJTO test', async () => {
           "assetAmount": 300000000000n,
           "assetMint": "jtojtomepa8beP8AuQc6eXt5FriJwfFMwQx2v2f9mCL",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -739,6 +742,7 @@ describe('restaking.fragJTO test', async () => {
             "user": "FRBRUhd8Tv5ZiUB5WYg9jdAdH5btZeRMfcXagxGLtwqQ",
             "walletProvider": "STIMPACK",
           },
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -968,6 +972,7 @@ describe('restaking.fragJTO test', async () => {
           "assetAmount": 100000000000n,
           "assetMint": "jtojtomepa8beP8AuQc6eXt5FriJwfFMwQx2v2f9mCL",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -1359,6 +1364,7 @@ describe('restaking.fragJTO test', async () => {
             "user": "HFfieEFm71E1FrU7JEJbHMihh95wwyxBFTStuU8wUmie",
             "walletProvider": "TERRY",
           },
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -1523,6 +1529,7 @@ describe('restaking.fragJTO test', async () => {
             "user": "HFfieEFm71E1FrU7JEJbHMihh95wwyxBFTStuU8wUmie",
             "walletProvider": "BACKPACK",
           },
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -1674,6 +1681,7 @@ describe('restaking.fragJTO test', async () => {
           "assetAmount": 1000000000n,
           "assetMint": "jtojtomepa8beP8AuQc6eXt5FriJwfFMwQx2v2f9mCL",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -871,6 +871,7 @@ describe('restaking.fragSOL test', async () => {
           "assetAmount": 5000000000n,
           "assetMint": null,
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -1077,6 +1078,19 @@ describe('restaking.fragSOL test', async () => {
     `);
   });
 
+  test('user deposit fails if minted receipt token amount is below the minimum', async () => {
+    await expect(
+      user1.deposit.execute(
+        {
+          assetMint: null,
+          assetAmount: 1_000_000_000n,
+          minReceiptTokenAmount: 2_000_000_000n,
+        },
+        { signers: [signer1] }
+      )
+    ).rejects.toThrowError();
+  });
+
   test('user can deposit token with SPLStakePool pricing source', async () => {
     await expectMasked(
       user1.deposit.execute(
@@ -1093,6 +1107,7 @@ describe('restaking.fragSOL test', async () => {
           "assetAmount": 5000000000n,
           "assetMint": "J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -217,6 +217,7 @@ describe('restaking.fragSWTCH test', async () => {
           "assetAmount": 5000000000n,
           "assetMint": "SW1TCHLmRGTfW5xZknqQdpdarB8PD95sJYWpNp9TbFx",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
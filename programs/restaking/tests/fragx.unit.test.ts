@@ -592,6 +592,7 @@ describe('restaking.fragX unit test', async () => {
           "assetAmount": 10000000000n,
           "assetMint": "J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -692,6 +693,7 @@ describe('restaking.fragX unit test', async () => {
           "assetAmount": 1000000000000000000n,
           "assetMint": "FRAGME9aN7qzxkHPmVP22tDhG87srsR9pr5SY9XdRd9R",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -797,6 +799,7 @@ describe('restaking.fragX unit test', async () => {
         {
           "args": {
             "forceResetCommand": "EnqueueWithdrawalBatch",
+            "maxSteps": null,
             "operator": "5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx",
           },
           "events": {
@@ -847,6 +850,7 @@ describe('restaking.fragX unit test', async () => {
         {
           "args": {
             "forceResetCommand": null,
+            "maxSteps": null,
             "operator": "5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx",
           },
           "events": {
@@ -927,6 +931,7 @@ describe('restaking.fragX unit test', async () => {
         {
           "args": {
             "forceResetCommand": null,
+            "maxSteps": null,
             "operator": "5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx",
           },
           "events": {
@@ -994,6 +999,7 @@ describe('restaking.fragX unit test', async () => {
         {
           "args": {
             "forceResetCommand": null,
+            "maxSteps": null,
             "operator": "5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx",
           },
           "events": {
@@ -1174,6 +1180,7 @@ describe('restaking.fragX unit test', async () => {
           "assetAmount": 10000000000n,
           "assetMint": "J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn",
           "metadata": null,
+          "minReceiptTokenAmount": null,
           "skipUserFundAccountCreation": false,
           "skipUserRewardAccountCreation": false,
         },
@@ -1442,6 +1449,7 @@ describe('restaking.fragX unit test', async () => {
       {
         "args": {
           "forceResetCommand": null,
+          "maxSteps": null,
           "operator": "5FjrErTQ9P1ThYVdY9RamrPUCQGTMCcczUjH21iKzbwx",
         },
         "events": {
@@ -1894,12 +1902,11 @@ describe('restaking.fragX unit test', async () => {
     );
 
     const reward_2 = await ctx.reward.resolve(true);
-    const user1Fund_2 = await user1.fund.resolve(true);
     const user1Reward_2 = await user1.reward.resolve(true);
     const user1_2 = await user1.resolve(true);
 
-    // withdrawal requested receipt token amount is written at the user's fund_account
-    expect(user1Fund_2!.withdrawalRequests[0].receiptTokenAmount).toEqual(
+    // withdrawal requested receipt token amount is written at the user's withdrawal request account
+    expect(user1_2!.withdrawalRequests[0].receiptTokenAmount).toEqual(
       user1_1!.receiptTokenAmount
     );
 
@@ -1961,7 +1968,6 @@ describe('restaking.fragX unit test', async () => {
     );
 
     const reward_2 = await ctx.reward.resolve(true);
-    const user1Fund_2 = await user1.fund.resolve(true);
     const user1Reward_2 = await user1.reward.resolve(true);
     const user1_2 = await user1.resolve(true);
 
@@ -1977,7 +1983,7 @@ describe('restaking.fragX unit test', async () => {
 
     // user1 cancels withdrawal request
     await user1.cancelWithdrawalRequest.execute(
-      { requestId: user1Fund_2!.withdrawalRequests[0].requestId },
+      { requestId: user1_2!.withdrawalRequests[0].requestId },
       { signers: [signer1] }
     );
 
@@ -2036,7 +2042,7 @@ describe('restaking.fragX unit test', async () => {
     );
 
     const reward_2 = await ctx.reward.resolve(true);
-    const user1Fund_2 = await user1.fund.resolve(true);
+    const user1_2 = await user1.resolve(true);
 
     // operator runs withdrawal batch commands
     await ctx.fund.runCommand.executeChained({
@@ -2051,7 +2057,7 @@ describe('restaking.fragX unit test', async () => {
 
     // user1 withdraws
     await user1.withdraw.execute(
-      { requestId: user1Fund_2!.withdrawalRequests[0].requestId },
+      { requestId: user1_2!.withdrawalRequests[0].requestId },
       { signers: [signer1] }
     );
 
@@ -2312,9 +2318,10 @@ describe('restaking.fragX unit test', async () => {
 
     const user1Fund_2 = await user1.fund.resolve(true);
     const user1Reward_2 = await user1.reward.resolve(true);
+    const user1_2 = await user1.resolve(true);
 
     expect(user1Fund_2!.receiptTokenAmountRecorded).toEqual(0n);
-    expect(user1Fund_2!.withdrawalRequests[0].receiptTokenAmount).toEqual(
+    expect(user1_2!.withdrawalRequests[0].receiptTokenAmount).toEqual(
       user1_1!.receiptTokenAmount
     );
     expect(user1Reward_2).toEqual(null);
@@ -2345,23 +2352,24 @@ describe('restaking.fragX unit test', async () => {
       { signers: [signer1] }
     );
 
-    const user1Fund_2 = await user1.fund.resolve(true);
+    const user1_2 = await user1.resolve(true);
 
     // user1 cancels withdrawal request
     await user1.cancelWithdrawalRequest.execute(
       {
-        requestId: user1Fund_2!.withdrawalRequests[0].requestId,
+        requestId: user1_2!.withdrawalRequests[0].requestId,
         skipUserRewardAccountCreation: true,
       },
       { signers: [signer1] }
     );
 
     const user1Fund_3 = await user1.fund.resolve(true);
+    const user1_3 = await user1.resolve(true);
 
     expect(user1Fund_3!.receiptTokenAmountRecorded).toEqual(
       user1_1!.receiptTokenAmount
     );
-    expect(user1Fund_3!.withdrawalRequests.length).toEqual(0);
+    expect(user1_3!.withdrawalRequests.length).toEqual(0);
   });
 
   // 4. withdraw sol
@@ -2389,7 +2397,6 @@ describe('restaking.fragX unit test', async () => {
       { signers: [signer1] }
     );
 
-    const user1Fund_2 = await user1.fund.resolve(true);
     const user1_2 = await user1.resolve(true);
 
     // operator runs withdrawal batch commands
@@ -2406,7 +2413,7 @@ describe('restaking.fragX unit test', async () => {
     // user1 withdraws
     await user1.withdraw.execute(
       {
-        requestId: user1Fund_2!.withdrawalRequests[0].requestId,
+        requestId: user1_2!.withdrawalRequests[0].requestId,
         skipUserRewardAccountCreation: true,
       },
       { signers: [signer1] }
@@ -2466,20 +2473,21 @@ describe('restaking.fragX unit test', async () => {
       { signers: [signer1] }
     );
 
-    const user1Fund_2 = await user1.fund.resolve(true);
+    const user1_2 = await user1.resolve(true);
 
-    // transaction fails because user1 has a withdrawal request
-    await expect(
-      user1.fund.closeAccount.execute({}, { signers: [signer1] })
-    ).rejects.toThrow();
+    // withdrawal request is stored in its own account, so user1 can close fund account while it is pending
+    await user1.fund.closeAccount.execute({}, { signers: [signer1] });
+    const user1FundAccount_2 = await validator.getAccount(user1.fund.address!);
+    expect(user1FundAccount_2).toEqual(null);
 
-    // user1 cancels withdrawal request
+    // user1 cancels withdrawal request, fund account is created again
     await user1.cancelWithdrawalRequest.execute(
-      { requestId: user1Fund_2!.withdrawalRequests[0].requestId },
+      { requestId: user1_2!.withdrawalRequests[0].requestId },
       { signers: [signer1] }
     );
 
     const user1_3 = await user1.resolve(true);
+    expect(user1_3!.withdrawalRequests.length).toEqual(0);
 
     // now user1 can close fund account
     await user1.fund.closeAccount.execute({}, { signers: [signer1] });
@@ -1,4 +1,5 @@
 import { TestValidator, TestValidatorType } from '@fragmetric-labs/testutil';
+import { Address, createNoopSigner } from '@solana/kit';
 
 import * as _sdk from '@fragmetric-labs/sdk';
 declare type SDK = typeof _sdk;
@@ -184,6 +185,25 @@ export async function createTestSuiteContext(config?: {
     // debug: true,
   });
 
+  // bootstrap the program-wide role account with the compiled admin key before any fund is initialized.
+  await new sdk.TransactionTemplateContext(restaking, null, {
+    description: 'initialize role account',
+    instructions: [
+      await sdk.restakingTypes.getAdminInitializeRoleAccountInstructionAsync(
+        {
+          payer: createNoopSigner(feePayer as Address),
+          admin: createNoopSigner(restaking.knownAddresses.admin),
+          program: restaking.address,
+          fundManager: restaking.knownAddresses.fundManager,
+          operator: feePayer as Address,
+        },
+        {
+          programAddress: restaking.address,
+        }
+      ),
+    ],
+  }).execute(null);
+
   const solv = sdk.SolvBTCVaultProgram.connect(validator.runtime, {
     rpc: rpcConfig,
     transaction: transactionConfig,